@@ -206,7 +206,7 @@ mod tests {
         let app_state = create_mock_app_state(None, None, None, None, None, None).await;
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_api_keys(query, ThinData(app_state)).await;
@@ -223,7 +223,7 @@ mod tests {
             create_mock_app_state(Some(vec![api_key]), None, None, None, None, None).await;
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_api_keys(query, ThinData(app_state)).await;
@@ -287,7 +287,7 @@ mod tests {
         let app_state = create_mock_app_state(None, None, None, None, None, None).await;
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_notifications(query, ThinData(app_state)).await;
@@ -328,7 +328,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_notifications(query, ThinData(app_state)).await;
@@ -359,7 +359,7 @@ mod tests {
 
         // Create multiple test notifications
         for i in 1..=5 {
-            let notification = create_test_notification_model(&format!("test-{}", i));
+            let notification = create_test_notification_model(&format!("test-{i}"));
             app_state
                 .notification_repository
                 .create(notification)
@@ -369,7 +369,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 2,
-            per_page: 2,
+            per_page: Some(2),
         };
 
         let result = list_notifications(query, ThinData(app_state)).await;
@@ -907,7 +907,7 @@ mod tests {
             assert!(msg.contains("Stellar Relayer"));
             assert!(msg.contains("remove or reconfigure"));
         } else {
-            panic!("Expected BadRequest error, got: {:?}", error);
+            panic!("Expected BadRequest error, got: {error:?}");
         }
     }
 
@@ -258,7 +258,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let response = list_plugins(query, web::ThinData(app_state)).await;
@@ -274,7 +274,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let response = list_plugins(query, web::ThinData(app_state)).await;
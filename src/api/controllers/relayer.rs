@@ -6,6 +6,7 @@
 //! - Creating relayers
 //! - Updating relayers
 //! - Deleting relayers
+//! - Promoting canary relayers
 //! - Submitting transactions
 //! - Signing messages
 //! - JSON-RPC proxy
@@ -24,9 +25,10 @@ use crate::{
         },
         ApiError, ApiResponse, CreateRelayerRequest, DefaultAppState, NetworkRepoModel,
         NetworkTransactionRequest, NetworkType, NotificationRepoModel, PaginationMeta,
-        PaginationQuery, Relayer as RelayerDomainModel, RelayerRepoModel, RelayerRepoUpdater,
-        RelayerResponse, Signer as SignerDomainModel, SignerRepoModel, ThinDataAppState,
-        TransactionRepoModel, TransactionResponse, TransactionStatus, UpdateRelayerRequestRaw,
+        PaginationQuery, Relayer as RelayerDomainModel, RelayerError, RelayerMode,
+        RelayerRepoModel, RelayerRepoUpdater, RelayerResponse, Signer as SignerDomainModel,
+        SignerRepoModel, ThinDataAppState, TransactionRepoModel, TransactionResponse,
+        TransactionStatus, UpdateRelayerRequestRaw,
     },
     repositories::{
         ApiKeyRepositoryTrait, NetworkRepository, PluginRepositoryTrait, RelayerRepository,
@@ -189,6 +191,8 @@ where
 
     // Convert domain model to repository model
     let mut relayer_model = RelayerRepoModel::from(relayer);
+    // New relayers only take canary transactions until promoted
+    relayer_model.mode = RelayerMode::Canary;
 
     // get address from signer and set it to relayer model
     let signer_service = SignerFactory::create_signer(
@@ -342,6 +346,38 @@ where
     Ok(HttpResponse::Ok().json(ApiResponse::success("Relayer deleted successfully")))
 }
 
+/// Promotes a canary relayer to live so it accepts all transactions.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer to promote.
+/// * `state` - The application state containing the relayer repository.
+///
+/// # Returns
+///
+/// The promoted relayer.
+pub async fn promote_relayer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR>(
+    relayer_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepositoryTrait + Send + Sync + 'static,
+{
+    let relayer = state.relayer_repository.promote_to_live(relayer_id).await?;
+
+    let relayer_response: RelayerResponse = relayer.into();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(relayer_response)))
+}
+
 /// Retrieves the status of a specific relayer.
 ///
 /// # Arguments
@@ -417,18 +453,36 @@ where
 /// # Returns
 ///
 /// The response of the transaction processing.
-pub async fn send_transaction(
+pub async fn send_transaction<J, RR, TR, NR, NFR, SR, TCR, PR, AKR>(
     relayer_id: String,
     request: serde_json::Value,
-    state: web::ThinData<DefaultAppState>,
-) -> Result<HttpResponse, ApiError> {
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepositoryTrait + Send + Sync + 'static,
+{
     let relayer_repo_model = get_relayer_by_id(relayer_id, &state).await?;
     relayer_repo_model.validate_active_state()?;
 
-    let relayer = get_network_relayer(relayer_repo_model.id.clone(), &state).await?;
-
     let tx_request: NetworkTransactionRequest =
-        NetworkTransactionRequest::from_json(&relayer_repo_model.network_type, request.clone())?;
+        NetworkTransactionRequest::from_json(&relayer_repo_model.network_type, request)?;
+
+    if !relayer_repo_model.is_operational() {
+        return Err(RelayerError::RelayerNotOperational.into());
+    }
+    if !relayer_repo_model.accepts_mode(tx_request.is_canary()) {
+        return Err(RelayerError::RelayerInCanaryMode.into());
+    }
+
+    let relayer = get_network_relayer(relayer_repo_model.id.clone(), &state).await?;
 
     tx_request.validate(&relayer_repo_model)?;
 
@@ -632,6 +686,7 @@ pub async fn cancel_transaction(
 
     let transaction_to_cancel = get_tx_by_id(transaction_id, &state).await?;
 
+    // No canary gate: cancelling only withdraws a transaction the relayer already accepted
     let canceled_transaction = relayer_transaction
         .cancel_transaction(transaction_to_cancel)
         .await?;
@@ -666,6 +721,10 @@ pub async fn replace_transaction(
         NetworkTransactionRequest::from_json(&relayer.network_type, request.clone())?;
     new_tx_request.validate(&relayer)?;
 
+    if !relayer.accepts_mode(new_tx_request.is_canary()) {
+        return Err(RelayerError::RelayerInCanaryMode.into());
+    }
+
     let transaction_to_replace = state
         .transaction_repository
         .get_by_id(transaction_id)
@@ -913,9 +972,10 @@ mod tests {
     use crate::{
         domain::SignTransactionRequestStellar,
         models::{
-            ApiResponse, CreateRelayerPolicyRequest, CreateRelayerRequest, RelayerEvmPolicy,
-            RelayerNetworkPolicyResponse, RelayerNetworkType, RelayerResponse, RelayerSolanaPolicy,
-            RelayerStellarPolicy, SolanaFeePaymentStrategy, StellarFeePaymentStrategy,
+            ApiResponse, CircuitState, CreateRelayerPolicyRequest, CreateRelayerRequest,
+            GasOracleConfig, RelayerEvmPolicy, RelayerNetworkPolicyResponse, RelayerNetworkType,
+            RelayerResponse, RelayerSolanaPolicy, RelayerStellarPolicy, RevertHandling,
+            SolanaFeePaymentStrategy, StellarFeePaymentStrategy, StuckTxAction,
         },
         utils::mocks::mockutils::{
             create_mock_app_state, create_mock_network, create_mock_notification,
@@ -1087,6 +1147,23 @@ mod tests {
             whitelist_receivers: Some(vec![
                 "0x1234567890123456789012345678901234567890".to_string()
             ]),
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
         }));
 
         let result = create_relayer(request, actix_web::web::ThinData(app_state)).await;
@@ -1141,6 +1218,23 @@ mod tests {
             private_transactions: None,
             gas_limit_estimation: None,
             whitelist_receivers: None,
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
         }));
 
         let result = create_relayer(request, actix_web::web::ThinData(app_state)).await;
@@ -1199,6 +1293,10 @@ mod tests {
             allowed_accounts: None,
             disallowed_accounts: None,
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         }));
 
         let result = create_relayer(request, actix_web::web::ThinData(app_state)).await;
@@ -1271,6 +1369,10 @@ mod tests {
             slippage_percentage: None,
             fee_margin_percentage: None,
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         }));
 
         let result = create_relayer(request, actix_web::web::ThinData(app_state)).await;
@@ -1510,7 +1612,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_relayers(query, actix_web::web::ThinData(app_state)).await;
@@ -1534,7 +1636,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_relayers(query, actix_web::web::ThinData(app_state)).await;
@@ -1836,6 +1938,7 @@ mod tests {
         relayer.custom_rpc_urls = Some(vec![crate::models::RpcConfig {
             url: "https://custom-rpc.example.com".to_string(),
             weight: 50,
+            rpc_headers: None,
         }]);
         let app_state =
             create_mock_app_state(None, Some(vec![relayer]), None, None, None, None).await;
@@ -2182,6 +2285,103 @@ mod tests {
         }
     }
 
+    // PROMOTE RELAYER TESTS
+
+    #[actix_web::test]
+    async fn test_create_promote_send_transaction() {
+        let _lock = ENV_MUTEX.lock().await;
+        setup_test_env();
+        // Created relayers start in canary mode; seeded directly so that no
+        // initialization against the mock network can disable it
+        let mut relayer = create_mock_relayer("canary-relayer".to_string(), false);
+        relayer.mode = RelayerMode::Canary;
+        let app_state = create_mock_app_state(
+            None,
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer()]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+        let state = actix_web::web::ThinData(app_state);
+
+        // Missing `to` and `data`, so a request past the canary gate fails validation
+        let live_tx = serde_json::json!({ "value": 0 });
+        let canary_tx = serde_json::json!({ "value": 0, "canary": true });
+
+        let result =
+            send_transaction("canary-relayer".to_string(), live_tx.clone(), state.clone()).await;
+        assert!(
+            matches!(&result, Err(ApiError::ForbiddenError(msg)) if msg.contains("canary")),
+            "expected the canary gate to reject, got {result:?}"
+        );
+
+        let result = send_transaction("canary-relayer".to_string(), canary_tx, state.clone()).await;
+        assert!(
+            matches!(result, Err(ApiError::BadRequest(_))),
+            "expected validation to reject, got {result:?}"
+        );
+
+        let response = promote_relayer("canary-relayer".to_string(), state.clone())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let result = send_transaction("canary-relayer".to_string(), live_tx, state).await;
+        assert!(
+            matches!(result, Err(ApiError::BadRequest(_))),
+            "expected validation to reject, got {result:?}"
+        );
+        cleanup_test_env();
+    }
+
+    #[actix_web::test]
+    async fn test_send_transaction_rejects_open_circuit_as_not_operational() {
+        let _lock = ENV_MUTEX.lock().await;
+        setup_test_env();
+        let mut relayer = create_mock_relayer("tripped-relayer".to_string(), false);
+        relayer.mode = RelayerMode::Canary;
+        relayer.circuit = CircuitState::Open {
+            until: chrono::Utc::now() + chrono::Duration::hours(1),
+        };
+        let app_state = create_mock_app_state(
+            None,
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer()]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+
+        // Even a live transaction to a canary relayer is refused for the open circuit
+        let result = send_transaction(
+            "tripped-relayer".to_string(),
+            serde_json::json!({ "value": 0 }),
+            actix_web::web::ThinData(app_state),
+        )
+        .await;
+        assert!(
+            matches!(&result, Err(ApiError::ForbiddenError(msg)) if msg == "Relayer not operational"),
+            "expected the operational check to reject, got {result:?}"
+        );
+        cleanup_test_env();
+    }
+
+    #[actix_web::test]
+    async fn test_promote_relayer_nonexistent() {
+        let app_state = create_mock_app_state(None, None, None, None, None, None).await;
+
+        let result = promote_relayer(
+            "nonexistent-relayer".to_string(),
+            actix_web::web::ThinData(app_state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
     #[actix_web::test]
     async fn test_sign_transaction_success() {
         let _lock = ENV_MUTEX.lock().await;
@@ -263,6 +263,7 @@ mod tests {
         SignerRepoModel {
             id: id.to_string(),
             config,
+            allowed_chains: None,
         }
     }
 
@@ -316,7 +317,7 @@ mod tests {
         let app_state = create_mock_app_state(None, None, None, None, None, None).await;
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_signers(query, actix_web::web::ThinData(app_state)).await;
@@ -348,7 +349,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = list_signers(query, actix_web::web::ThinData(app_state)).await;
@@ -978,6 +979,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[1; 32])),
             }),
+            allowed_chains: None,
         };
 
         let response = SignerResponse::from(signer_model);
@@ -1068,7 +1070,7 @@ mod tests {
             assert!(msg.contains("Stellar Relayer"));
             assert!(msg.contains("remove or reconfigure"));
         } else {
-            panic!("Expected BadRequest error, got: {:?}", error);
+            panic!("Expected BadRequest error, got: {error:?}");
         }
     }
 
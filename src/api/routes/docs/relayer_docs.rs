@@ -363,6 +363,55 @@ fn doc_update_relayer() {}
 #[allow(dead_code)]
 fn doc_delete_relayer() {}
 
+/// Promotes a canary relayer to live so it accepts all transactions.
+#[utoipa::path(
+    post,
+    path = "/api/v1/relayers/{relayer_id}/promote",
+    tag = "Relayers",
+    operation_id = "promoteRelayer",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("relayer_id" = String, Path, description = "The unique identifier of the relayer")
+    ),
+    responses(
+        (status = 200, description = "Relayer promoted successfully", body = ApiResponse<RelayerResponse>),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer with ID relayer_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal Server Error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        )
+    )
+)]
+#[allow(dead_code)]
+fn doc_promote_relayer() {}
+
 /// Fetches the current status of a specific relayer.
 #[utoipa::path(
     get,
@@ -61,6 +61,15 @@ async fn delete_relayer(
     relayer::delete_relayer(relayer_id.into_inner(), data).await
 }
 
+/// Promotes a canary relayer to live.
+#[post("/relayers/{relayer_id}/promote")]
+async fn promote_relayer(
+    relayer_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer::promote_relayer(relayer_id.into_inner(), data).await
+}
+
 /// Fetches the current status of a specific relayer.
 #[get("/relayers/{relayer_id}/status")]
 async fn get_relayer_status(
@@ -235,6 +244,7 @@ pub fn init(cfg: &mut web::ServiceConfig) {
     cfg.service(sign_typed_data); // /relayers/{id}/sign-typed-data
     cfg.service(sign_transaction); // /relayers/{id}/sign-transaction
     cfg.service(rpc); // /relayers/{id}/rpc
+    cfg.service(promote_relayer); // /relayers/{id}/promote
     cfg.service(get_relayer); // /relayers/{id}
     cfg.service(create_relayer); // /relayers
     cfg.service(update_relayer); // /relayers/{id}
@@ -314,6 +324,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: secrets::SecretVec::new(32, |v| v.copy_from_slice(&[0u8; 32])),
             }),
+            allowed_chains: None,
         };
         signer_repo.create(test_signer).await.unwrap();
 
@@ -526,6 +537,13 @@ mod tests {
         let resp = test::call_service(&app, req).await;
         assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
 
+        // Test POST /relayers/{id}/promote
+        let req = test::TestRequest::post()
+            .uri("/relayers/test-id/promote")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
         // Test POST /relayers/{id}/transactions/sponsored/quote
         let req = test::TestRequest::post()
             .uri("/relayers/test-id/transactions/sponsored/quote")
@@ -8,7 +8,7 @@ use crate::{
     models::{AppState, DefaultAppState},
     repositories::{
         ApiKeyRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage,
-        PluginRepositoryStorage, RelayerRepositoryStorage, SignerRepositoryStorage,
+        PluginRepositoryStorage, RelayerRepositoryStorage, Repository, SignerRepositoryStorage,
         TransactionCounterRepositoryStorage, TransactionRepositoryStorage,
     },
     utils::initialize_redis_connection,
@@ -95,6 +95,13 @@ pub async fn initialize_repositories(config: &ServerConfig) -> eyre::Result<Repo
         }
     };
 
+    // Prepare the backends before any traffic is served
+    repositories.relayer.init().await?;
+    repositories.transaction.init().await?;
+    repositories.signer.init().await?;
+    repositories.notification.init().await?;
+    repositories.network.init().await?;
+
     Ok(repositories)
 }
 
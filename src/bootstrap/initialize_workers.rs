@@ -505,7 +505,7 @@ mod tests {
     fn create_test_evm_relayer(id: &str) -> RelayerRepoModel {
         RelayerRepoModel {
             id: id.to_string(),
-            name: format!("EVM Relayer {}", id),
+            name: format!("EVM Relayer {id}"),
             network: "sepolia".to_string(),
             paused: false,
             network_type: NetworkType::Evm,
@@ -523,7 +523,7 @@ mod tests {
     ) -> RelayerRepoModel {
         RelayerRepoModel {
             id: id.to_string(),
-            name: format!("Solana Relayer {}", id),
+            name: format!("Solana Relayer {id}"),
             network: "mainnet-beta".to_string(),
             paused: false,
             network_type: NetworkType::Solana,
@@ -544,6 +544,10 @@ mod tests {
                     min_balance_threshold: Some(5000000000),
                     jupiter_swap_options: None,
                 }),
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             signer_id: "test-signer".to_string(),
             address: "5zWma6gn4QxRfC6xZk6KfpXWXXgV3Xt6VzPpXMKCMYW5".to_string(),
@@ -558,7 +562,7 @@ mod tests {
     ) -> RelayerRepoModel {
         RelayerRepoModel {
             id: id.to_string(),
-            name: format!("Stellar Relayer {}", id),
+            name: format!("Stellar Relayer {id}"),
             network: "testnet".to_string(),
             paused: false,
             network_type: NetworkType::Stellar,
@@ -576,6 +580,10 @@ mod tests {
                     cron_schedule,
                     min_balance_threshold: Some(5000000000),
                 }),
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             signer_id: "test-signer".to_string(),
             address: "GABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890ABCDEFGH".to_string(),
@@ -764,7 +772,7 @@ mod tests {
     fn create_test_solana_relayer_without_swap_config(id: &str) -> RelayerRepoModel {
         RelayerRepoModel {
             id: id.to_string(),
-            name: format!("Solana Relayer {}", id),
+            name: format!("Solana Relayer {id}"),
             network: "mainnet-beta".to_string(),
             paused: false,
             network_type: NetworkType::Solana,
@@ -780,6 +788,10 @@ mod tests {
                 disallowed_accounts: None,
                 max_allowed_fee_lamports: None,
                 swap_config: None, // No swap config
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             signer_id: "test-signer".to_string(),
             address: "5zWma6gn4QxRfC6xZk6KfpXWXXgV3Xt6VzPpXMKCMYW5".to_string(),
@@ -791,7 +803,7 @@ mod tests {
     fn create_test_stellar_relayer_without_swap_config(id: &str) -> RelayerRepoModel {
         RelayerRepoModel {
             id: id.to_string(),
-            name: format!("Stellar Relayer {}", id),
+            name: format!("Stellar Relayer {id}"),
             network: "testnet".to_string(),
             paused: false,
             network_type: NetworkType::Stellar,
@@ -805,6 +817,10 @@ mod tests {
                 slippage_percentage: None,
                 fee_margin_percentage: None,
                 swap_config: None, // No swap config
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             signer_id: "test-signer".to_string(),
             address: "GABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890ABCDEFGH".to_string(),
@@ -993,8 +1009,7 @@ mod tests {
 
         assert_eq!(filtered.len(), 2, "Should only include Solana and Stellar");
 
-        let network_types: Vec<NetworkType> =
-            filtered.iter().map(|r| r.network_type.clone()).collect();
+        let network_types: Vec<NetworkType> = filtered.iter().map(|r| r.network_type).collect();
         assert!(
             network_types.contains(&NetworkType::Solana),
             "Should include Solana"
@@ -594,7 +594,7 @@ mod tests {
     #[test]
     fn test_network_config_common_debug() {
         let config = create_network_common("test-network");
-        let debug_str = format!("{:?}", config);
+        let debug_str = format!("{config:?}");
 
         assert!(debug_str.contains("NetworkConfigCommon"));
         assert!(debug_str.contains("test-network"));
@@ -138,7 +138,7 @@ pub fn create_stellar_network(network: &str) -> StellarNetworkConfig {
             tags: Some(vec!["stellar".to_string()]),
         },
         passphrase: Some("Test Network ; September 2015".to_string()),
-        horizon_url: Some(format!("https://horizon.{}.stellar.org", network)),
+        horizon_url: Some(format!("https://horizon.{network}.stellar.org")),
     }
 }
 
@@ -201,7 +201,7 @@ pub fn create_stellar_network_wrapped(network: &str) -> NetworkFileConfig {
 pub fn create_temp_file(dir: &TempDir, file_name: &str, content: &str) {
     let file_path = dir.path().join(file_name);
     let mut file = File::create(file_path).expect("Failed to create temp file");
-    write!(file, "{}", content).expect("Failed to write to temp file");
+    write!(file, "{content}").expect("Failed to write to temp file");
 }
 
 /// Creates a valid EVM network JSON for testing file loading.
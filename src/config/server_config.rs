@@ -882,8 +882,7 @@ mod tests {
                 );
                 assert_eq!(
                     actual_env_var, expected_env_var,
-                    "Env var name should be correctly formatted for worker: {}",
-                    worker_name
+                    "Env var name should be correctly formatted for worker: {worker_name}"
                 );
             }
         }
@@ -43,3 +43,6 @@ pub use network_tags::*;
 
 mod logging;
 pub use logging::*;
+
+mod pagination;
+pub use pagination::*;
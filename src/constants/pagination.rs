@@ -0,0 +1,3 @@
+/// Page size used when a paginated request omits `per_page` and the repository
+/// has no more specific default
+pub const DEFAULT_PER_PAGE: u32 = 10;
@@ -13,6 +13,19 @@ pub const DEFAULT_EVM_GAS_PRICE_CAP: u128 = 100_000_000_000;
 pub const DEFAULT_EVM_EIP1559_ENABLED: bool = true;
 /// Default gas limit estimation enabled
 pub const DEFAULT_EVM_GAS_LIMIT_ESTIMATION: bool = true;
+/// Smallest accepted calldata limit: a 4-byte function selector, below which no
+/// contract call could be relayed at all
+pub const MIN_EVM_MAX_CALLDATA_BYTES: u32 = 4;
+/// Smallest fee bump accepted for a replacement transaction, as a percentage.
+/// Nodes reject EIP-1559 replacements that raise fees by less than 10%.
+pub const MIN_EVM_GAS_BUMP_PERCENT: u8 = 10;
+/// Bounds of the factor an EVM relayer applies to estimated gas limits
+pub const MIN_EVM_GAS_ESTIMATION_MULTIPLIER: f64 = 1.0;
+pub const MAX_EVM_GAS_ESTIMATION_MULTIPLIER: f64 = 3.0;
+
+/// Bounds, in milliseconds, of a relayer's configured signer timeout
+pub const MIN_RELAYER_SIGNER_TIMEOUT_MS: u64 = 100;
+pub const MAX_RELAYER_SIGNER_TIMEOUT_MS: u64 = 60_000;
 
 // === Stellar Policy Defaults ===
 /// Default concurrent transactions enabled setting for Stellar
@@ -30,3 +43,52 @@ pub const STELLAR_SMALLEST_UNIT_NAME: &str = "stroop";
 pub const SOLANA_SMALLEST_UNIT_NAME: &str = "lamport";
 
 pub const DEFAULT_RPC_WEIGHT: u8 = 100;
+
+/// How long a processed relayer creation idempotency key is remembered
+pub const RELAYER_IDEMPOTENCY_KEY_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+/// Tenant assigned to relayers that were stored before multi-tenancy was introduced
+pub const DEFAULT_TENANT_ID: &str = "default";
+
+/// How long a relayer circuit stays open after a failed half-open probe
+pub const RELAYER_CIRCUIT_REOPEN_SECONDS: i64 = 60;
+
+/// Default number of status transitions kept in a relayer's status history
+pub const DEFAULT_RELAYER_STATUS_HISTORY_LIMIT: usize = 50;
+
+/// How many times a Redis relayer write is attempted when other writers keep
+/// changing the relayer between its read and its compare-and-set
+pub const RELAYER_WRITE_MAX_ATTEMPTS: usize = 10;
+
+/// How long a relayer's in-flight slot count lives in Redis after the last slot was
+/// taken. Slots held by an instance that died without releasing them are freed then.
+pub const RELAYER_SLOT_TTL_SECONDS: u64 = 10 * 60;
+
+/// Default time a relayer configuration write waits for outstanding config read guards
+pub const DEFAULT_RELAYER_CONFIG_LOCK_TIMEOUT_SECONDS: u64 = 5;
+
+/// Default number of operation outcomes kept per relayer for error rate tracking
+pub const DEFAULT_RELAYER_OUTCOME_HISTORY_LIMIT: usize = 100;
+
+/// Default maximum size, in bytes of serialized JSON, of a relayer's metadata
+pub const DEFAULT_RELAYER_METADATA_MAX_BYTES: usize = 16 * 1024;
+
+/// Default number of configuration versions kept per relayer
+pub const DEFAULT_RELAYER_CONFIG_VERSION_LIMIT: usize = 20;
+
+/// Default number of relayer change records kept for catch-up after a reconnect
+pub const DEFAULT_RELAYER_CHANGE_LOG_CAPACITY: usize = 1000;
+
+/// Version of the on-disk layout written by the in-memory relayer repository.
+/// Bumped only when the layout changes incompatibly; fields added to relayers
+/// deserialize with their defaults.
+pub const RELAYER_STORE_SCHEMA_VERSION: u32 = 1;
+
+/// A gas price cap below the current network gas price divided by this factor is linted
+pub const RELAYER_LINT_GAS_PRICE_CAP_FACTOR: u128 = 2;
+
+/// Length of the rolling window a relayer's `max_daily_spend` applies to
+pub const RELAYER_SPEND_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Invalidations buffered per subscriber before a slow one starts missing them
+pub const RELAYER_INVALIDATION_CHANNEL_CAPACITY: usize = 1024;
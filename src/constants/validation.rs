@@ -7,3 +7,13 @@ pub const MINIMUM_SECRET_VALUE_LENGTH: usize = 32;
 lazy_static! {
     pub static ref ID_REGEX: Regex = Regex::new(r"^[a-zA-Z0-9-_]+$").unwrap();
 }
+
+// Default rule for relayer IDs accepted by relayer repositories
+lazy_static! {
+    pub static ref RELAYER_ID_REGEX: Regex = Regex::new(r"^[A-Za-z0-9_-]{1,64}$").unwrap();
+}
+
+// Regex for validating BIP-32 derivation paths (e.g. m/44'/60'/0'/0/0)
+lazy_static! {
+    pub static ref DERIVATION_PATH_REGEX: Regex = Regex::new(r"^m(/[0-9]+'?)*$").unwrap();
+}
@@ -630,9 +630,9 @@ mod tests {
         config::{EvmNetworkConfig, NetworkConfigCommon},
         jobs::MockJobProducerTrait,
         models::{
-            EvmRpcRequest, EvmRpcResult, JsonRpcId, NetworkRepoModel, NetworkType,
-            RelayerEvmPolicy, RelayerNetworkPolicy, RepositoryError, SignerError,
-            TransactionStatus, U256,
+            EvmRpcRequest, EvmRpcResult, GasOracleConfig, JsonRpcId, NetworkRepoModel, NetworkType,
+            RelayerEvmPolicy, RelayerNetworkPolicy, RepositoryError, RevertHandling, SignerError,
+            StuckTxAction, TransactionStatus, U256,
         },
         repositories::{MockNetworkRepository, MockRelayerRepository, MockTransactionRepository},
         services::{
@@ -709,6 +709,23 @@ mod tests {
                 eip1559_pricing: Some(true),
                 private_transactions: Some(false),
                 gas_limit_estimation: Some(true),
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             }),
             network_type: NetworkType::Evm,
             custom_rpc_urls: None,
@@ -788,6 +805,7 @@ mod tests {
             max_priority_fee_per_gas: None,
             speed: None,
             valid_until: None,
+            canary: false,
         });
 
         network_repo
@@ -78,6 +78,7 @@ mod tests {
     use std::future::ready;
 
     use super::*;
+    use crate::models::{GasOracleConfig, RevertHandling, StuckTxAction};
     use crate::services::provider::evm::MockEvmProviderTrait;
     use crate::services::provider::ProviderError;
     use mockall::predicate::*;
@@ -90,6 +91,23 @@ mod tests {
             whitelist_receivers: None,
             eip1559_pricing: None,
             private_transactions: Some(false),
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
         }
     }
 
@@ -163,6 +163,7 @@ mod tests {
         SignerRepoModel {
             id: "test".to_string(),
             config: SignerConfigStorage::Local(LocalSignerConfigStorage { raw_key }),
+            allowed_chains: None,
         }
     }
 
@@ -194,7 +195,7 @@ mod tests {
         match result {
             Ok(NetworkDex::JupiterSwap { .. }) => {}
             Ok(_) => panic!("Expected JupiterSwap strategy"),
-            Err(e) => panic!("Expected Ok with JupiterSwap, but got error: {:?}", e),
+            Err(e) => panic!("Expected Ok with JupiterSwap, but got error: {e:?}"),
         }
     }
 
@@ -226,7 +227,7 @@ mod tests {
         match result {
             Ok(NetworkDex::JupiterUltra { .. }) => {}
             Ok(_) => panic!("Expected JupiterUltra strategy"),
-            Err(e) => panic!("Expected Ok with JupiterUltra, but got error: {:?}", e),
+            Err(e) => panic!("Expected Ok with JupiterUltra, but got error: {e:?}"),
         }
     }
 
@@ -258,7 +259,7 @@ mod tests {
         match result {
             Ok(NetworkDex::Noop { .. }) => {}
             Ok(_) => panic!("Expected Noop strategy"),
-            Err(e) => panic!("Expected Ok with Noop, but got error: {:?}", e),
+            Err(e) => panic!("Expected Ok with Noop, but got error: {e:?}"),
         }
     }
 
@@ -285,7 +286,7 @@ mod tests {
         match result {
             Ok(NetworkDex::Noop { .. }) => {}
             Ok(_) => panic!("Expected Noop strategy"),
-            Err(e) => panic!("Expected Ok with Noop, but got error: {:?}", e),
+            Err(e) => panic!("Expected Ok with Noop, but got error: {e:?}"),
         }
     }
 }
@@ -10,6 +10,7 @@
 //! # Parameters
 //!
 //! * `transaction` - A Base64-encoded prepared transaction that needs to be signed and submitted.
+//! * `canary` - Marks a test transaction; a relayer in canary mode rejects anything else.
 //!
 //! # Returns
 //!
@@ -26,7 +27,7 @@ use tracing::{debug, error};
 use crate::{
     models::{
         produce_solana_rpc_webhook_payload, EncodedSerializedTransaction, NetworkTransactionData,
-        NetworkTransactionRequest, SolanaFeePaymentStrategy,
+        NetworkTransactionRequest, RelayerError, SolanaFeePaymentStrategy,
         SolanaSignAndSendTransactionRequestParams, SolanaSignAndSendTransactionResult,
         SolanaTransactionData, SolanaTransactionRequest, SolanaWebhookRpcPayload,
         TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
@@ -50,6 +51,12 @@ where
         params: SolanaSignAndSendTransactionRequestParams,
     ) -> Result<SolanaSignAndSendTransactionResult, SolanaRpcError> {
         debug!("Processing sign and send transaction request");
+        if !self.relayer.accepts_mode(params.canary) {
+            return Err(SolanaRpcError::BadRequest(
+                RelayerError::RelayerInCanaryMode.to_string(),
+            ));
+        }
+
         let transaction_request = Transaction::try_from(params.transaction.clone())?;
 
         validate_sign_and_send_transaction(&transaction_request, &self.relayer, &*self.provider)
@@ -95,6 +102,7 @@ where
             transaction: Some(params.transaction.clone()),
             instructions: None,
             valid_until: None,
+            canary: params.canary,
         });
 
         let transaction =
@@ -253,6 +261,7 @@ async fn validate_sign_and_send_transaction<P: SolanaProviderTrait + Send + Sync
 mod tests {
     use crate::{
         constants::WRAPPED_SOL_MINT,
+        models::RelayerMode,
         services::{QuoteResponse, RoutePlan, SwapInfo},
         utils::mocks::mockutils::create_mock_solana_transaction,
     };
@@ -341,6 +350,7 @@ mod tests {
 
         let params = SolanaSignAndSendTransactionRequestParams {
             transaction: encoded_tx,
+            canary: false,
         };
 
         let result = rpc.sign_and_send_transaction(params).await;
@@ -514,6 +524,7 @@ mod tests {
 
         let params = SolanaSignAndSendTransactionRequestParams {
             transaction: ctx.encoded_tx,
+            canary: false,
         };
 
         let result = rpc.sign_and_send_transaction_impl(params).await;
@@ -677,6 +688,7 @@ mod tests {
 
         let params = SolanaSignAndSendTransactionRequestParams {
             transaction: ctx.encoded_tx,
+            canary: false,
         };
 
         let result = rpc.sign_and_send_transaction_impl(params).await;
@@ -689,18 +701,16 @@ mod tests {
                 assert!(
                     error_string
                         .contains("Insufficient balance for cumulative transfers: account "),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
                 assert!(
                     error_string.contains(
                         "has balance 1000000 but requires 6000000 across all instructions"
                     ),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -855,6 +865,7 @@ mod tests {
 
         let params = SolanaSignAndSendTransactionRequestParams {
             transaction: ctx.encoded_tx,
+            canary: false,
         };
 
         let result = rpc.sign_and_send_transaction_impl(params).await;
@@ -866,11 +877,10 @@ mod tests {
                 let error_string = err.to_string();
                 assert!(
                     error_string.contains("Insufficient balance:"),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -928,6 +938,7 @@ mod tests {
         let result = rpc
             .sign_and_send_transaction_impl(SolanaSignAndSendTransactionRequestParams {
                 transaction: encoded_tx,
+                canary: false,
             })
             .await;
 
@@ -937,6 +948,32 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_sign_and_send_transaction_rejected_by_canary_relayer() {
+        let (mut relayer, signer, provider, jupiter_service, encoded_tx, job_producer, network) =
+            setup_test_context();
+        relayer.mode = RelayerMode::Canary;
+
+        let rpc = SolanaRpcMethodsImpl::new_mock(
+            relayer,
+            network,
+            Arc::new(provider),
+            Arc::new(signer),
+            Arc::new(jupiter_service),
+            Arc::new(job_producer),
+            Arc::new(MockTransactionRepository::new()),
+        );
+
+        let result = rpc
+            .sign_and_send_transaction_impl(SolanaSignAndSendTransactionRequestParams {
+                transaction: encoded_tx,
+                canary: false,
+            })
+            .await;
+
+        assert!(matches!(result, Err(SolanaRpcError::BadRequest(_))));
+    }
+
     #[tokio::test]
     async fn test_sign_and_send_transaction_simulation_failure() {
         let (relayer, mut signer, mut provider, jupiter_service, encoded_tx, job_producer, network) =
@@ -974,6 +1011,7 @@ mod tests {
         let result = rpc
             .sign_and_send_transaction_impl(SolanaSignAndSendTransactionRequestParams {
                 transaction: encoded_tx,
+                canary: false,
             })
             .await;
         assert!(matches!(
@@ -1071,6 +1109,7 @@ mod tests {
 
         let params = SolanaSignAndSendTransactionRequestParams {
             transaction: encoded_tx,
+            canary: false,
         };
 
         let result = rpc.sign_and_send_transaction_impl(params).await;
@@ -459,11 +459,10 @@ mod tests {
                 let error_string = err.to_string();
                 assert!(
                     error_string.contains("Insufficient balance:"),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -620,11 +619,10 @@ mod tests {
                 let error_string = err.to_string();
                 assert!(
                     error_string.contains("Insufficient balance:"),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -721,11 +719,10 @@ mod tests {
                         "Policy violation: Transaction requires 2 signatures, which exceeds \
                          maximum allowed 0"
                     ),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -784,11 +781,10 @@ mod tests {
                     error_string.contains(
                         "Policy violation: Program 11111111111111111111111111111111 not allowed"
                     ),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -851,11 +847,10 @@ mod tests {
                     error_string.contains(
                         "Policy violation: Transaction size 311 exceeds maximum allowed 10"
                     ),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -898,11 +893,10 @@ mod tests {
                 let error_string = err.to_string();
                 assert!(
                     error_string.contains("Policy violation: Fee payer"),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -1049,11 +1043,10 @@ mod tests {
                     error_string.contains(
                         "Policy violation: Fee amount 5000 exceeds max allowed fee amount 500"
                     ),
-                    "Unexpected error message: {}",
-                    err
+                    "Unexpected error message: {err}"
                 );
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
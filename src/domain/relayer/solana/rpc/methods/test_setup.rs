@@ -82,6 +82,10 @@ pub fn setup_test_context() -> (
             disallowed_accounts: None,
             max_allowed_fee_lamports: None,
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         }),
         signer_id: "test".to_string(),
         address: payer.pubkey().to_string(),
@@ -187,6 +191,10 @@ pub fn setup_test_context_relayer_fee_strategy() -> RelayerFeeStrategyTestContex
             max_allowed_fee_lamports: None,
             max_tx_data_size: Some(1000),
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         }),
         signer_id: "test".to_string(),
         address: relayer_keypair.pubkey().to_string(),
@@ -324,6 +332,10 @@ pub fn setup_test_context_user_fee_strategy() -> UserFeeStrategyTestContext {
             max_allowed_fee_lamports: None,
             max_tx_data_size: Some(1000),
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         }),
         signer_id: "test".to_string(),
         address: relayer_keypair.pubkey().to_string(),
@@ -450,6 +462,10 @@ pub fn setup_test_context_single_tx_user_fee_strategy() -> UserFeeStrategySingle
             max_allowed_fee_lamports: None,
             max_tx_data_size: Some(1000),
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         }),
         signer_id: "test".to_string(),
         address: relayer_keypair.pubkey().to_string(),
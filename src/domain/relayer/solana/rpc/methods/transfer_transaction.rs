@@ -662,7 +662,7 @@ mod tests {
                     } else {
                         Err(
                             crate::services::provider::SolanaProviderError::InvalidAddress(
-                                format!("Invalid token address {}", pubkey),
+                                format!("Invalid token address {pubkey}"),
                             ),
                         )
                     }
@@ -770,6 +770,10 @@ mod tests {
                 disallowed_accounts: None,
                 max_allowed_fee_lamports: None,
                 swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             signer_id: "test".to_string(),
             address: Keypair::new().pubkey().to_string(),
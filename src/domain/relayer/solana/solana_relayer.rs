@@ -562,6 +562,7 @@ where
 
             let params = SolanaSignAndSendTransactionRequestParams {
                 transaction: transaction.clone(),
+                canary: solana_request.canary,
             };
 
             let result = self
@@ -1921,7 +1922,7 @@ mod tests {
             RelayerError::ProviderError(msg) => {
                 assert!(msg.contains("rpc failure"));
             }
-            other => panic!("expected ProviderError, got {:?}", other),
+            other => panic!("expected ProviderError, got {other:?}"),
         }
     }
 
@@ -2080,7 +2081,7 @@ mod tests {
             RelayerError::UnderlyingSolanaProvider(err) => {
                 assert!(err.to_string().contains("oops"));
             }
-            other => panic!("expected ProviderError, got {:?}", other),
+            other => panic!("expected ProviderError, got {other:?}"),
         }
     }
 
@@ -2134,7 +2135,7 @@ mod tests {
             RelayerError::InsufficientBalanceError(msg) => {
                 assert_eq!(msg, "Insufficient balance");
             }
-            other => panic!("expected InsufficientBalanceError, got {:?}", other),
+            other => panic!("expected InsufficientBalanceError, got {other:?}"),
         }
     }
 
@@ -2156,7 +2157,7 @@ mod tests {
             RelayerError::ProviderError(msg) => {
                 assert!(msg.contains("fail"));
             }
-            other => panic!("expected ProviderError, got {:?}", other),
+            other => panic!("expected ProviderError, got {other:?}"),
         }
     }
 
@@ -2201,11 +2202,11 @@ mod tests {
         let data = resp.result.unwrap();
         let sol_res = match data {
             NetworkRpcResult::Solana(inner) => inner,
-            other => panic!("expected Solana, got {:?}", other),
+            other => panic!("expected Solana, got {other:?}"),
         };
         let features = match sol_res {
             SolanaRpcResult::GetFeaturesEnabled(f) => f,
-            other => panic!("expected GetFeaturesEnabled, got {:?}", other),
+            other => panic!("expected GetFeaturesEnabled, got {other:?}"),
         };
         assert_eq!(features.features, vec!["gasless".to_string()]);
     }
@@ -2476,7 +2477,7 @@ mod tests {
             RelayerError::PolicyConfigurationError(msg) => {
                 assert!(msg.contains("Error while processing allowed tokens policy"));
             }
-            other => panic!("Expected PolicyConfigurationError, got {:?}", other),
+            other => panic!("Expected PolicyConfigurationError, got {other:?}"),
         }
     }
 
@@ -2616,7 +2617,7 @@ mod tests {
             RelayerError::UnderlyingSolanaProvider(err) => {
                 assert!(err.to_string().contains("RPC error"));
             }
-            other => panic!("Expected UnderlyingSolanaProvider, got {:?}", other),
+            other => panic!("Expected UnderlyingSolanaProvider, got {other:?}"),
         }
     }
 
@@ -521,14 +521,16 @@ mod tests {
 
     /// Helper function to create a test relayer with user fee payment strategy
     fn create_test_relayer_with_user_fee_strategy() -> RelayerRepoModel {
-        let mut policy = RelayerStellarPolicy::default();
-        policy.fee_payment_strategy = Some(crate::models::StellarFeePaymentStrategy::User);
-        policy.allowed_tokens = Some(vec![crate::models::StellarAllowedTokensPolicy {
-            asset: USDC_ASSET.to_string(),
-            metadata: None,
-            max_allowed_fee: None,
-            swap_config: None,
-        }]);
+        let policy = RelayerStellarPolicy {
+            fee_payment_strategy: Some(crate::models::StellarFeePaymentStrategy::User),
+            allowed_tokens: Some(vec![crate::models::StellarAllowedTokensPolicy {
+                asset: USDC_ASSET.to_string(),
+                metadata: None,
+                max_allowed_fee: None,
+                swap_config: None,
+            }]),
+            ..Default::default()
+        };
 
         RelayerRepoModel {
             id: "test-relayer-id".to_string(),
@@ -648,16 +650,13 @@ mod tests {
                 trustline_key.account_id.clone()
             } else {
                 // Fallback: try to parse TEST_PK
-                parse_account_id(TEST_PK).unwrap_or_else(|_| {
-                    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                })
+                parse_account_id(TEST_PK)
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))))
             };
 
             let issuer_id =
                 parse_account_id("GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN")
-                    .unwrap_or_else(|_| {
-                        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                    });
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))));
 
             // Create a trustline entry with sufficient balance
             let trustline_entry = TrustLineEntry {
@@ -732,7 +731,7 @@ mod tests {
 
         let result = relayer.quote_sponsored_transaction(request).await;
         if let Err(e) = &result {
-            eprintln!("Quote error: {:?}", e);
+            eprintln!("Quote error: {e:?}");
         }
         assert!(result.is_ok());
 
@@ -774,16 +773,12 @@ mod tests {
             } else {
                 // Fallback: use the source account from the test
                 parse_account_id("GCZ54QGQCUZ6U5WJF4AG5JEZCUMYTS2F6JRLUS76XF2PQMEJ2E3JISI2")
-                    .unwrap_or_else(|_| {
-                        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                    })
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))))
             };
 
             let issuer_id =
                 parse_account_id("GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN")
-                    .unwrap_or_else(|_| {
-                        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                    });
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))));
 
             // Create a trustline entry with sufficient balance
             let trustline_entry = TrustLineEntry {
@@ -867,7 +862,7 @@ mod tests {
 
         let result = relayer.quote_sponsored_transaction(request).await;
         if let Err(e) = &result {
-            eprintln!("Quote error: {:?}", e);
+            eprintln!("Quote error: {e:?}");
         }
         assert!(result.is_ok());
     }
@@ -949,16 +944,13 @@ mod tests {
                 trustline_key.account_id.clone()
             } else {
                 // Fallback: try to parse TEST_PK
-                parse_account_id(TEST_PK).unwrap_or_else(|_| {
-                    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                })
+                parse_account_id(TEST_PK)
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))))
             };
 
             let issuer_id =
                 parse_account_id("GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN")
-                    .unwrap_or_else(|_| {
-                        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                    });
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))));
 
             // Create a trustline entry with sufficient balance (10 USDC = 10000000 with 6 decimals)
             let trustline_entry = TrustLineEntry {
@@ -1078,14 +1070,10 @@ mod tests {
             // Parse account IDs - use the source account from the test
             let account_id =
                 parse_account_id("GCZ54QGQCUZ6U5WJF4AG5JEZCUMYTS2F6JRLUS76XF2PQMEJ2E3JISI2")
-                    .unwrap_or_else(|_| {
-                        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                    });
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))));
             let issuer_id =
                 parse_account_id("GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN")
-                    .unwrap_or_else(|_| {
-                        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                    });
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))));
 
             // Create a trustline entry with sufficient balance (10 USDC = 10000000 with 6 decimals)
             // The fee is 1500000 (from the quote), so 10 USDC is more than enough
@@ -1255,10 +1243,8 @@ mod tests {
         assert!(result.is_ok());
 
         // Verify the sequence number is set correctly (current + 1 = 101)
-        if let Ok(envelope) = result {
-            if let TransactionEnvelope::Tx(tx_env) = envelope {
-                assert_eq!(tx_env.tx.seq_num.0, 101);
-            }
+        if let Ok(TransactionEnvelope::Tx(tx_env)) = result {
+            assert_eq!(tx_env.tx.seq_num.0, 101);
         }
     }
 
@@ -1782,6 +1782,7 @@ mod tests {
                 transaction_xdr: Some("AAAAAgAAAACige4lTdwSB/sto4SniEdJ2kOa2X65s5bqkd40J4DjSwAAAAEAAHAkAAAADwAAAAAAAAAAAAAAAQAAAAAAAAABAAAAAKKB7iVN3BIH+y2jhKeIR0naQ5rZfrmzluqR3jQngONLAAAAAAAAAAAAD0JAAAAAAAAAAAA=".to_string()),
                 fee_bump: None,
                 max_fee: None,
+                canary: false,
             })
         }
 
@@ -1847,7 +1848,7 @@ mod tests {
 
             let result = relayer.process_transaction_request(tx_request).await;
             if let Err(e) = &result {
-                panic!("process_transaction_request failed: {}", e);
+                panic!("process_transaction_request failed: {e}");
             }
             assert!(result.is_ok());
         }
@@ -1883,8 +1884,9 @@ mod tests {
                         let now = Utc::now().timestamp();
                         let diff = scheduled_at - now;
                         // Allow some tolerance (within 2 seconds)
-                        diff >= (STELLAR_STATUS_CHECK_INITIAL_DELAY_SECONDS - 2)
-                            && diff <= (STELLAR_STATUS_CHECK_INITIAL_DELAY_SECONDS + 2)
+                        ((STELLAR_STATUS_CHECK_INITIAL_DELAY_SECONDS - 2)
+                            ..=(STELLAR_STATUS_CHECK_INITIAL_DELAY_SECONDS + 2))
+                            .contains(&diff)
                     } else {
                         false
                     }
@@ -1963,8 +1965,7 @@ mod tests {
             let err_msg = result.err().unwrap().to_string();
             assert!(
                 err_msg.contains("Database connection failed"),
-                "Error was: {}",
-                err_msg
+                "Error was: {err_msg}"
             );
         }
 
@@ -2178,8 +2179,10 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up empty allowed tokens
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![]),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let provider = MockStellarProviderTrait::new();
@@ -2221,13 +2224,16 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up allowed tokens with a classic asset (USDC)
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![crate::models::StellarAllowedTokensPolicy {
-                asset: "USDC:GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
-                metadata: None,
-                max_allowed_fee: None,
-                swap_config: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![crate::models::StellarAllowedTokensPolicy {
+                    asset: "USDC:GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+                        .to_string(),
+                    metadata: None,
+                    max_allowed_fee: None,
+                    swap_config: None,
+                }]),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let provider = MockStellarProviderTrait::new();
@@ -2300,23 +2306,25 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up multiple allowed tokens
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![
-                crate::models::StellarAllowedTokensPolicy {
-                    asset: "USDC:GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
-                        .to_string(),
-                    metadata: None,
-                    max_allowed_fee: None,
-                    swap_config: None,
-                },
-                crate::models::StellarAllowedTokensPolicy {
-                    asset: "AQUA:GAHPYWLK6YRN7CVYZOO4H3VDRZ7PVF5UJGLZCSPAEIKJE2XSWF5LAGER"
-                        .to_string(),
-                    metadata: None,
-                    max_allowed_fee: Some(1000000),
-                    swap_config: None,
-                },
-            ]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![
+                    crate::models::StellarAllowedTokensPolicy {
+                        asset: "USDC:GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+                            .to_string(),
+                        metadata: None,
+                        max_allowed_fee: None,
+                        swap_config: None,
+                    },
+                    crate::models::StellarAllowedTokensPolicy {
+                        asset: "AQUA:GAHPYWLK6YRN7CVYZOO4H3VDRZ7PVF5UJGLZCSPAEIKJE2XSWF5LAGER"
+                            .to_string(),
+                        metadata: None,
+                        max_allowed_fee: Some(1000000),
+                        swap_config: None,
+                    },
+                ]),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let provider = MockStellarProviderTrait::new();
@@ -2389,13 +2397,15 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up allowed tokens with invalid asset format
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![crate::models::StellarAllowedTokensPolicy {
-                asset: "INVALID_FORMAT".to_string(), // Missing issuer
-                metadata: None,
-                max_allowed_fee: None,
-                swap_config: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![crate::models::StellarAllowedTokensPolicy {
+                    asset: "INVALID_FORMAT".to_string(), // Missing issuer
+                    metadata: None,
+                    max_allowed_fee: None,
+                    swap_config: None,
+                }]),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let provider = MockStellarProviderTrait::new();
@@ -2481,8 +2491,10 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set fee_payment_strategy
-            let mut policy = RelayerStellarPolicy::default();
-            policy.fee_payment_strategy = Some(StellarFeePaymentStrategy::User);
+            let policy = RelayerStellarPolicy {
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::User),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let provider = MockStellarProviderTrait::new();
@@ -2673,12 +2685,14 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up swap config without threshold
-            let mut policy = RelayerStellarPolicy::default();
-            policy.swap_config = Some(RelayerStellarSwapConfig {
-                strategies: vec![],
-                min_balance_threshold: None,
-                cron_schedule: None,
-            });
+            let policy = RelayerStellarPolicy {
+                swap_config: Some(RelayerStellarSwapConfig {
+                    strategies: vec![],
+                    min_balance_threshold: None,
+                    cron_schedule: None,
+                }),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let provider = MockStellarProviderTrait::new();
@@ -2719,12 +2733,14 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up swap config with threshold
-            let mut policy = RelayerStellarPolicy::default();
-            policy.swap_config = Some(RelayerStellarSwapConfig {
-                strategies: vec![],
-                min_balance_threshold: Some(1000000), // 1 XLM
-                cron_schedule: None,
-            });
+            let policy = RelayerStellarPolicy {
+                swap_config: Some(RelayerStellarSwapConfig {
+                    strategies: vec![],
+                    min_balance_threshold: Some(1000000), // 1 XLM
+                    cron_schedule: None,
+                }),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let mut provider = MockStellarProviderTrait::new();
@@ -2783,12 +2799,14 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up swap config with threshold
-            let mut policy = RelayerStellarPolicy::default();
-            policy.swap_config = Some(RelayerStellarSwapConfig {
-                strategies: vec![],
-                min_balance_threshold: Some(1000000),
-                cron_schedule: None,
-            });
+            let policy = RelayerStellarPolicy {
+                swap_config: Some(RelayerStellarSwapConfig {
+                    strategies: vec![],
+                    min_balance_threshold: Some(1000000),
+                    cron_schedule: None,
+                }),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let mut provider = MockStellarProviderTrait::new();
@@ -2940,13 +2958,15 @@ mod tests {
             let mut relayer_model = ctx.relayer_model.clone();
 
             // Set up user fee payment strategy
-            let mut policy = RelayerStellarPolicy::default();
-            policy.fee_payment_strategy = Some(StellarFeePaymentStrategy::User);
-            policy.swap_config = Some(RelayerStellarSwapConfig {
-                strategies: vec![],
-                min_balance_threshold: Some(1000000),
-                cron_schedule: None,
-            });
+            let policy = RelayerStellarPolicy {
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::User),
+                swap_config: Some(RelayerStellarSwapConfig {
+                    strategies: vec![],
+                    min_balance_threshold: Some(1000000),
+                    cron_schedule: None,
+                }),
+                ..Default::default()
+            };
             relayer_model.policies = RelayerNetworkPolicy::Stellar(policy);
 
             let mut provider = MockStellarProviderTrait::new();
@@ -237,6 +237,7 @@ where
                         transaction_xdr: Some(xdr),
                         fee_bump: None,
                         max_fee: None,
+                        canary: false,
                     };
 
                     let network_request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -452,14 +453,11 @@ mod tests {
                 )
             } else {
                 // Fallback
-                let fallback_account = parse_account_id(TEST_PK).unwrap_or_else(|_| {
-                    AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                });
+                let fallback_account = parse_account_id(TEST_PK)
+                    .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))));
                 let fallback_issuer =
                     parse_account_id("GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN")
-                        .unwrap_or_else(|_| {
-                            AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])))
-                        });
+                        .unwrap_or(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32]))));
                 let fallback_asset = TrustLineAsset::CreditAlphanum4(soroban_rs::xdr::AlphaNum4 {
                     asset_code: soroban_rs::xdr::AssetCode4(*b"USDC"),
                     issuer: fallback_issuer,
@@ -520,24 +518,26 @@ mod tests {
 
     /// Helper function to create a test relayer with user fee payment strategy and swap config
     fn create_test_relayer_with_swap_config() -> RelayerRepoModel {
-        let mut policy = RelayerStellarPolicy::default();
-        policy.fee_payment_strategy = Some(StellarFeePaymentStrategy::User);
-        policy.swap_config = Some(RelayerStellarSwapConfig {
-            strategies: vec![StellarSwapStrategy::OrderBook],
-            min_balance_threshold: None,
-            cron_schedule: None,
-        });
-        policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-            asset: USDC_ASSET.to_string(),
-            metadata: None,
-            max_allowed_fee: None,
-            swap_config: Some(StellarAllowedTokensSwapConfig {
-                min_amount: Some(1000000),
-                max_amount: Some(100000000),
-                retain_min_amount: Some(1000000),
-                slippage_percentage: Some(1.0),
+        let policy = RelayerStellarPolicy {
+            fee_payment_strategy: Some(StellarFeePaymentStrategy::User),
+            swap_config: Some(RelayerStellarSwapConfig {
+                strategies: vec![StellarSwapStrategy::OrderBook],
+                min_balance_threshold: None,
+                cron_schedule: None,
             }),
-        }]);
+            allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                asset: USDC_ASSET.to_string(),
+                metadata: None,
+                max_allowed_fee: None,
+                swap_config: Some(StellarAllowedTokensSwapConfig {
+                    min_amount: Some(1000000),
+                    max_amount: Some(100000000),
+                    retain_min_amount: Some(1000000),
+                    slippage_percentage: Some(1.0),
+                }),
+            }]),
+            ..Default::default()
+        };
 
         RelayerRepoModel {
             id: "test-relayer-id".to_string(),
@@ -757,7 +757,7 @@ mod tests {
         assert!(!swap_result.transaction_signature.is_empty());
 
         // Verify transaction signature format (should be a UUID-like string)
-        assert!(swap_result.transaction_signature.len() > 0);
+        assert!(!swap_result.transaction_signature.is_empty());
     }
 
     #[tokio::test]
@@ -11,13 +11,13 @@ use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
 use crate::{
-    constants::{DEFAULT_EVM_GAS_LIMIT_ESTIMATION, GAS_LIMIT_BUFFER_MULTIPLIER},
+    constants::DEFAULT_EVM_GAS_LIMIT_ESTIMATION,
     domain::{
         transaction::{
             evm::{ensure_status, ensure_status_one_of, PriceCalculator, PriceCalculatorTrait},
             Transaction,
         },
-        EvmTransactionValidationError, EvmTransactionValidator,
+        EvmTransactionValidationError, EvmTransactionValidator, SignTransactionResponse,
     },
     jobs::{JobProducer, JobProducerTrait, TransactionSend, TransactionStatusCheck},
     models::{
@@ -324,6 +324,35 @@ where
         })
     }
 
+    /// Signs EVM transaction data, bounded by the relayer's signer timeout when one is set.
+    ///
+    /// A signer that does not respond in time yields `UnexpectedError`, so the job is retried.
+    async fn sign_evm_data(
+        &self,
+        evm_data: EvmTransactionData,
+    ) -> Result<SignTransactionResponse, TransactionError> {
+        let signing = self
+            .signer
+            .sign_transaction(NetworkTransactionData::Evm(evm_data));
+
+        match self.relayer().signer_timeout() {
+            Some(timeout) => tokio::time::timeout(timeout, signing)
+                .await
+                .map_err(|_| {
+                    warn!(
+                        relayer_id = %self.relayer().id,
+                        timeout_ms = timeout.as_millis(),
+                        "signer did not respond in time"
+                    );
+                    TransactionError::UnexpectedError(format!(
+                        "Signer did not respond within {timeout:?}"
+                    ))
+                })?
+                .map_err(TransactionError::from),
+            None => Ok(signing.await?),
+        }
+    }
+
     /// Estimates the gas limit for a transaction.
     ///
     /// # Arguments
@@ -351,7 +380,7 @@ where
             TransactionError::UnexpectedError(format!("Failed to estimate gas: {e}"))
         })?;
 
-        Ok(estimated_gas * GAS_LIMIT_BUFFER_MULTIPLIER / 100)
+        Ok(relayer_policy.effective_gas_limit(estimated_gas))
     }
 }
 
@@ -513,10 +542,7 @@ where
             .with_price_params(price_params.clone());
 
         // Now sign the transaction - if this fails, we still have the tx with nonce saved
-        let sig_result = self
-            .signer
-            .sign_transaction(NetworkTransactionData::Evm(updated_evm_data.clone()))
-            .await?;
+        let sig_result = self.sign_evm_data(updated_evm_data.clone()).await?;
 
         let updated_evm_data =
             updated_evm_data.with_signed_transaction_data(sig_result.into_evm()?);
@@ -728,10 +754,7 @@ where
         let updated_evm_data = evm_data.with_price_params(bumped_price_params.clone());
 
         // Sign the transaction
-        let sig_result = self
-            .signer
-            .sign_transaction(NetworkTransactionData::Evm(updated_evm_data.clone()))
-            .await?;
+        let sig_result = self.sign_evm_data(updated_evm_data.clone()).await?;
 
         let final_evm_data = updated_evm_data.with_signed_transaction_data(sig_result.into_evm()?);
 
@@ -949,10 +972,7 @@ where
             .await?;
 
         let sig_result = self
-            .signer
-            .sign_transaction(NetworkTransactionData::Evm(
-                evm_data_with_price_params.clone(),
-            ))
+            .sign_evm_data(evm_data_with_price_params.clone())
             .await?;
 
         let final_evm_data =
@@ -1081,6 +1101,23 @@ mod tests {
             whitelist_receivers: Some(vec!["0xRecipient".to_string()]),
             eip1559_pricing: Some(false),
             private_transactions: Some(false),
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: crate::models::GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: crate::models::RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: crate::models::StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
         })
     }
 
@@ -1320,7 +1357,7 @@ mod tests {
         };
 
         let result = evm_transaction.prepare_transaction(test_tx.clone()).await;
-        assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+        assert!(result.is_ok(), "Expected Ok, got: {result:?}");
 
         let updated_tx = result.unwrap();
         assert_eq!(
@@ -1719,6 +1756,7 @@ mod tests {
                 max_priority_fee_per_gas: None,
                 speed: Some(Speed::Fast),
                 valid_until: None,
+                canary: false,
             });
 
             // Call replace_transaction and verify it succeeds
@@ -1726,7 +1764,7 @@ mod tests {
                 .replace_transaction(test_tx.clone(), replacement_request)
                 .await;
             if let Err(ref e) = result {
-                eprintln!("Replace transaction failed with error: {:?}", e);
+                eprintln!("Replace transaction failed with error: {e:?}");
             }
             assert!(result.is_ok());
             let replaced_tx = result.unwrap();
@@ -1789,6 +1827,7 @@ mod tests {
                 max_priority_fee_per_gas: None,
                 speed: Some(Speed::Fast),
                 valid_until: None,
+                canary: false,
             });
 
             // Call replace_transaction and verify it fails
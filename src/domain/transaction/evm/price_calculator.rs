@@ -222,11 +222,8 @@ where
         }
 
         let network_gas_prices = self.gas_price_service.get_prices_from_json_rpc().await?;
-        let relayer_gas_price_cap = relayer
-            .policies
-            .get_evm_policy()
-            .gas_price_cap
-            .unwrap_or(u128::MAX);
+        let evm_policy = relayer.policies.get_evm_policy();
+        let relayer_gas_price_cap = evm_policy.gas_price_cap.unwrap_or(u128::MAX);
 
         // Decide EIP1559 vs Legacy based on presence of maxFeePerGas / maxPriorityFeePerGas vs gasPrice
         let bumped_price_params = match (
@@ -239,6 +236,7 @@ where
                 self.handle_eip1559_bump(
                     &network_gas_prices,
                     relayer_gas_price_cap,
+                    evm_policy.min_priority_fee_per_gas.unwrap_or(0),
                     tx_data.speed.as_ref(),
                     max_fee,
                     max_priority_fee,
@@ -271,7 +269,7 @@ where
     /// 1. Computes the minimum required fee values by increasing the previous fees by 10%.
     /// 2. Retrieves the current network market priority fee for the transaction's speed.
     /// 3. Chooses the new priority fee as either the current market fee (if it meets the 10% increase)
-    ///    or the calculated minimum bump.
+    ///    or the calculated minimum bump, raised to the relayer's priority fee floor.
     /// 4. Computes the new maximum fee using two approaches:
     ///    - Method A: Uses the current base fee, ensuring it meets the minimum bumped max fee.
     ///    - Method B: Computes a recommended max fee based on a network-specific multiplier plus the new priority fee.
@@ -284,6 +282,7 @@ where
         &self,
         network_gas_prices: &GasPrices,
         gas_price_cap: u128,
+        min_priority_fee: u128,
         maybe_speed: Option<&Speed>,
         max_fee: u128,
         max_priority_fee: u128,
@@ -306,6 +305,7 @@ where
         } else {
             min_bump_max_priority
         };
+        let bumped_priority_fee = std::cmp::max(bumped_priority_fee, min_priority_fee);
 
         // Compute the new maxFeePerGas using two methods:
         // Method A: Use the current base fee, but ensure it is not lower than the minimum bumped max fee.
@@ -546,11 +546,8 @@ where
         price_params: &mut PriceParams,
         relayer: &RelayerRepoModel,
     ) -> Result<(), TransactionError> {
-        let gas_price_cap = relayer
-            .policies
-            .get_evm_policy()
-            .gas_price_cap
-            .unwrap_or(u128::MAX);
+        let policy = relayer.policies.get_evm_policy();
+        let gas_price_cap = policy.gas_price_cap.unwrap_or(u128::MAX);
 
         if let (Some(max_fee), Some(max_priority)) = (
             price_params.max_fee_per_gas,
@@ -560,6 +557,10 @@ where
             let capped_max_fee = Self::cap_gas_price(max_fee, gas_price_cap);
             price_params.max_fee_per_gas = Some(capped_max_fee);
 
+            // Raise maxPriorityFeePerGas to the relayer's floor so it doesn't round to near-zero
+            let max_priority =
+                std::cmp::max(max_priority, policy.min_priority_fee_per_gas.unwrap_or(0));
+
             // Ensure maxPriorityFeePerGas < maxFeePerGas to avoid client errors
             price_params.max_priority_fee_per_gas =
                 Some(Self::cap_gas_price(max_priority, capped_max_fee));
@@ -581,6 +582,52 @@ where
         Ok(())
     }
 
+    /// Clamps all fee fields to the relayer's hard gas price ceiling.
+    ///
+    /// Returns true if any fee field was lowered.
+    fn apply_hard_gas_price_ceiling(
+        price_params: &mut PriceParams,
+        relayer: &RelayerRepoModel,
+    ) -> bool {
+        let mut clamped = false;
+        for fee in [
+            &mut price_params.gas_price,
+            &mut price_params.max_fee_per_gas,
+            &mut price_params.max_priority_fee_per_gas,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let ceiled = relayer.clamp_gas_price(*fee);
+            clamped |= ceiled != *fee;
+            *fee = ceiled;
+        }
+        clamped
+    }
+
+    /// Whether the fees in `price_params` are at least the minimum bump over the fees
+    /// previously submitted with `tx_data`.
+    fn meets_min_bump(tx_data: &EvmTransactionData, price_params: &PriceParams) -> bool {
+        match (
+            tx_data.max_fee_per_gas,
+            tx_data.max_priority_fee_per_gas,
+            tx_data.gas_price,
+        ) {
+            (Some(max_fee), Some(max_priority_fee), _) => {
+                price_params
+                    .max_fee_per_gas
+                    .is_some_and(|fee| fee >= calculate_min_bump(max_fee))
+                    && price_params
+                        .max_priority_fee_per_gas
+                        .is_some_and(|fee| fee >= calculate_min_bump(max_priority_fee))
+            }
+            (None, None, Some(gas_price)) => price_params
+                .gas_price
+                .is_some_and(|price| price >= calculate_min_bump(gas_price)),
+            _ => false,
+        }
+    }
+
     fn cap_gas_price(price: u128, cap: u128) -> u128 {
         std::cmp::min(price, cap)
     }
@@ -602,6 +649,16 @@ where
             self.apply_gas_price_cap_and_constraints(&mut price_params, relayer)?;
         }
 
+        // The hard ceiling is the last line of defense and applies after everything else
+        if Self::apply_hard_gas_price_ceiling(&mut price_params, relayer) {
+            price_params.total_cost = U256::ZERO;
+
+            // A bump cut by the ceiling may no longer clear the replacement minimum
+            if price_params.is_min_bumped == Some(true) {
+                price_params.is_min_bumped = Some(Self::meets_min_bump(tx_data, &price_params));
+            }
+        }
+
         if price_params.total_cost == U256::ZERO {
             price_params.total_cost = price_params.calculate_total_cost(
                 is_eip1559,
@@ -912,6 +969,126 @@ mod tests {
         assert_eq!(params.gas_price, Some(10000000000)); // Should be capped
     }
 
+    #[tokio::test]
+    async fn test_hard_gas_price_ceiling_overrides_policy_cap() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_balance()
+            .returning(|_| async { Ok(U256::from(1000000000000000000u128)) }.boxed());
+
+        let mut relayer = create_mock_relayer();
+        let gas_price_service =
+            EvmGasPriceService::new(provider, create_mock_evm_network("mainnet"), None);
+
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(50000000000),
+            ..RelayerEvmPolicy::default()
+        });
+        relayer.hard_gas_price_ceiling = Some(10000000000);
+
+        let tx_data = EvmTransactionData {
+            gas_price: Some(20000000000), // Under the policy cap but above the ceiling
+            ..Default::default()
+        };
+
+        let pc = PriceCalculator::new(gas_price_service, None);
+
+        let params = pc
+            .get_transaction_price_params(&tx_data, &relayer)
+            .await
+            .unwrap();
+        assert_eq!(params.gas_price, Some(10000000000));
+        assert_eq!(
+            params.total_cost,
+            params.calculate_total_cost(
+                false,
+                tx_data.gas_limit.unwrap_or(DEFAULT_GAS_LIMIT),
+                U256::from(tx_data.value)
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hard_gas_price_ceiling_not_reached() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_balance()
+            .returning(|_| async { Ok(U256::from(1000000000000000000u128)) }.boxed());
+
+        let mut relayer = create_mock_relayer();
+        let gas_price_service =
+            EvmGasPriceService::new(provider, create_mock_evm_network("mainnet"), None);
+        relayer.hard_gas_price_ceiling = Some(100000000000);
+
+        let tx_data = EvmTransactionData {
+            gas_price: Some(20000000000),
+            ..Default::default()
+        };
+
+        let pc = PriceCalculator::new(gas_price_service, None);
+
+        let params = pc
+            .get_transaction_price_params(&tx_data, &relayer)
+            .await
+            .unwrap();
+        assert_eq!(params.gas_price, Some(20000000000));
+    }
+
+    #[tokio::test]
+    async fn test_min_priority_fee_floor_raises_priority_fee() {
+        let provider = MockEvmProviderTrait::new();
+        let mut relayer = create_mock_relayer();
+        let gas_price_service =
+            EvmGasPriceService::new(provider, create_mock_evm_network("mainnet"), None);
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_priority_fee_per_gas: Some(1_000_000_000),
+            ..RelayerEvmPolicy::default()
+        });
+
+        let tx_data = EvmTransactionData {
+            gas_price: None,
+            max_fee_per_gas: Some(30_000_000_000),
+            max_priority_fee_per_gas: Some(1), // Rounded to near-zero
+            ..Default::default()
+        };
+
+        let pc = PriceCalculator::new(gas_price_service, None);
+
+        let params = pc
+            .get_transaction_price_params(&tx_data, &relayer)
+            .await
+            .unwrap();
+        assert_eq!(params.max_fee_per_gas, Some(30_000_000_000));
+        assert_eq!(params.max_priority_fee_per_gas, Some(1_000_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_min_priority_fee_floor_does_not_lower_priority_fee() {
+        let provider = MockEvmProviderTrait::new();
+        let mut relayer = create_mock_relayer();
+        let gas_price_service =
+            EvmGasPriceService::new(provider, create_mock_evm_network("mainnet"), None);
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_priority_fee_per_gas: Some(1_000_000_000),
+            ..RelayerEvmPolicy::default()
+        });
+
+        let tx_data = EvmTransactionData {
+            gas_price: None,
+            max_fee_per_gas: Some(30_000_000_000),
+            max_priority_fee_per_gas: Some(2_000_000_000),
+            ..Default::default()
+        };
+
+        let pc = PriceCalculator::new(gas_price_service, None);
+
+        let params = pc
+            .get_transaction_price_params(&tx_data, &relayer)
+            .await
+            .unwrap();
+        assert_eq!(params.max_priority_fee_per_gas, Some(2_000_000_000));
+    }
+
     #[test]
     fn test_get_base_fee_multiplier() {
         let mainnet = create_mock_evm_network("mainnet");
@@ -1322,6 +1499,55 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_eip1559_bump_applies_min_priority_fee_floor() {
+        let mut mock_service = MockEvmGasPriceServiceTrait::new();
+        let mock_prices = GasPrices {
+            legacy_prices: SpeedPrices::default(),
+            max_priority_fee_per_gas: SpeedPrices {
+                safe_low: 1_000_000_000,
+                average: 2_000_000_000,
+                fast: 3_000_000_000,
+                fastest: 4_000_000_000,
+            },
+            base_fee_per_gas: 40_000_000_000,
+        };
+        mock_service
+            .expect_get_prices_from_json_rpc()
+            .returning(move || {
+                let prices = mock_prices.clone();
+                Box::pin(async move { Ok(prices) })
+            });
+        mock_service
+            .expect_network()
+            .return_const(create_mock_evm_network("mainnet"));
+
+        let pc = PriceCalculator::new(mock_service, None);
+        let mut relayer = create_mock_relayer();
+        // Floor is above both the market tip (3 Gwei) and the minimum bump (2.2 Gwei)
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(200_000_000_000u128),
+            min_priority_fee_per_gas: Some(5_000_000_000),
+            ..Default::default()
+        });
+
+        let tx_data = EvmTransactionData {
+            max_fee_per_gas: Some(50_000_000_000),
+            max_priority_fee_per_gas: Some(2_000_000_000),
+            speed: Some(Speed::Fast),
+            ..Default::default()
+        };
+
+        let bumped = pc
+            .calculate_bumped_gas_price(&tx_data, &relayer)
+            .await
+            .unwrap();
+
+        assert_eq!(bumped.max_priority_fee_per_gas, Some(5_000_000_000));
+        assert!(bumped.max_fee_per_gas.unwrap() >= 45_000_000_000);
+        assert_eq!(bumped.is_min_bumped, Some(true));
+    }
+
     #[tokio::test]
     async fn test_is_min_bumped_flag_legacy() {
         let mut mock_service = MockEvmGasPriceServiceTrait::new();
@@ -1387,6 +1613,56 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_is_min_bumped_flag_cleared_by_hard_gas_price_ceiling() {
+        let mut mock_service = MockEvmGasPriceServiceTrait::new();
+        let mock_prices = GasPrices {
+            legacy_prices: SpeedPrices {
+                safe_low: 8_000_000_000,
+                average: 10_000_000_000,
+                fast: 12_000_000_000,
+                fastest: 15_000_000_000,
+            },
+            max_priority_fee_per_gas: SpeedPrices::default(),
+            base_fee_per_gas: 0,
+        };
+        mock_service
+            .expect_get_prices_from_json_rpc()
+            .returning(move || {
+                let prices = mock_prices.clone();
+                Box::pin(async move { Ok(prices) })
+            });
+        mock_service
+            .expect_network()
+            .return_const(create_mock_evm_network("mainnet"));
+
+        let pc = PriceCalculator::new(mock_service, None);
+        let mut relayer = create_mock_relayer();
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(100_000_000_000u128),
+            ..Default::default()
+        });
+        // The policy cap allows the 11 Gwei minimum bump, the ceiling does not
+        relayer.hard_gas_price_ceiling = Some(10_500_000_000);
+
+        let tx_data = EvmTransactionData {
+            gas_price: Some(10_000_000_000),
+            speed: Some(Speed::Fast),
+            ..Default::default()
+        };
+
+        let bumped = pc
+            .calculate_bumped_gas_price(&tx_data, &relayer)
+            .await
+            .unwrap();
+        assert_eq!(bumped.gas_price, Some(10_500_000_000));
+        assert_eq!(
+            bumped.is_min_bumped,
+            Some(false),
+            "Should not be min bumped when the ceiling cuts the bump"
+        );
+    }
+
     #[tokio::test]
     async fn test_calculate_bumped_gas_price_with_extra_fee() {
         // Set up mock gas price service
@@ -1756,9 +2032,7 @@ mod tests {
         let result = calculate_min_bump(base_price);
         assert!(
             result > base_price,
-            "Result {} should be greater than base_price {}",
-            result,
-            base_price
+            "Result {result} should be greater than base_price {base_price}"
         );
 
         let base_price = 9u128;
@@ -1803,15 +2077,11 @@ mod tests {
             let result = calculate_min_bump(base_price);
             assert!(
                 result > base_price,
-                "calculate_min_bump({}) = {} should be greater than base_price",
-                base_price,
-                result
+                "calculate_min_bump({base_price}) = {result} should be greater than base_price"
             );
             assert!(
                 result >= base_price.saturating_add(1),
-                "calculate_min_bump({}) = {} should be at least base_price + 1",
-                base_price,
-                result
+                "calculate_min_bump({base_price}) = {result} should be at least base_price + 1"
             );
         }
     }
@@ -2127,8 +2397,7 @@ mod tests {
             // For Speed::Fast, should be around 30_000_000 (0.03 Gwei) based on our mock
             assert!(
                 priority_fee <= 50_000_000, // Should be reasonable for current market, not a bump
-                "Priority fee should be based on current market, not bumped: {}",
-                priority_fee
+                "Priority fee should be based on current market, not bumped: {priority_fee}"
             );
         }
     }
@@ -28,8 +28,8 @@ use crate::{
     domain::transaction::evm::price_calculator::PriceCalculatorTrait,
     jobs::JobProducerTrait,
     models::{
-        NetworkTransactionData, RelayerRepoModel, TransactionError, TransactionRepoModel,
-        TransactionStatus, TransactionUpdateRequest,
+        NetworkTransactionData, RelayerRepoModel, StuckTxAction, TransactionError,
+        TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
     },
     repositories::{Repository, TransactionCounterTrait, TransactionRepository},
     services::{provider::EvmProviderTrait, signer::Signer},
@@ -286,23 +286,44 @@ where
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
         if self.should_resubmit(&tx).await? {
-            let resubmitted_tx = self.handle_resubmission(tx).await?;
-            return Ok(resubmitted_tx);
+            let action = self
+                .relayer()
+                .policies
+                .stuck_tx_action()
+                .unwrap_or_default();
+            match action {
+                StuckTxAction::Leave => {
+                    debug!("relayer policy leaves stuck transactions, not resubmitting");
+                }
+                action => {
+                    let resubmitted_tx = self
+                        .handle_resubmission(tx, action == StuckTxAction::Cancel)
+                        .await?;
+                    return Ok(resubmitted_tx);
+                }
+            }
         }
 
         self.update_transaction_status_if_needed(tx, TransactionStatus::Submitted)
             .await
     }
 
-    /// Processes transaction resubmission logic
+    /// Processes transaction resubmission logic.
+    ///
+    /// With `cancel`, a transaction that is not already a NOOP is replaced by one
+    /// and marked as canceled.
     async fn handle_resubmission(
         &self,
         tx: TransactionRepoModel,
+        cancel: bool,
     ) -> Result<TransactionRepoModel, TransactionError> {
         debug!("scheduling resubmit job for transaction");
 
-        let tx_to_process = if self.should_noop(&tx).await? {
-            self.process_noop_transaction(&tx).await?
+        let cancel = cancel && !is_noop(&tx.network_data.get_evm_transaction_data()?);
+        let tx_to_process = if cancel {
+            self.process_noop_transaction(&tx, true).await?
+        } else if self.should_noop(&tx).await? {
+            self.process_noop_transaction(&tx, false).await?
         } else {
             tx
         };
@@ -315,9 +336,12 @@ where
     async fn process_noop_transaction(
         &self,
         tx: &TransactionRepoModel,
+        is_cancellation: bool,
     ) -> Result<TransactionRepoModel, TransactionError> {
         debug!("preparing transaction NOOP before resubmission");
-        let update = self.prepare_noop_update_request(tx, false).await?;
+        let update = self
+            .prepare_noop_update_request(tx, is_cancellation)
+            .await?;
         let updated_tx = self
             .transaction_repository()
             .partial_update(tx.id.clone(), update)
@@ -660,7 +684,8 @@ mod tests {
         models::{
             evm::Speed, EvmTransactionData, NetworkConfigData, NetworkRepoModel,
             NetworkTransactionData, NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy,
-            RelayerRepoModel, TransactionReceipt, TransactionRepoModel, TransactionStatus, U256,
+            RelayerRepoModel, StuckTxAction, TransactionReceipt, TransactionRepoModel,
+            TransactionStatus, U256,
         },
         repositories::{
             MockNetworkRepository, MockRelayerRepository, MockTransactionCounterTrait,
@@ -1499,6 +1524,40 @@ mod tests {
             // We remain in "Submitted" after scheduling the resubmit
             assert_eq!(updated_tx.status, TransactionStatus::Submitted);
         }
+
+        #[tokio::test]
+        async fn test_leave_policy_skips_resubmission() {
+            let mut mocks = default_test_mocks();
+            let mut relayer = create_test_relayer();
+            if let RelayerNetworkPolicy::Evm(policy) = &mut relayer.policies {
+                policy.stuck_tx_action = StuckTxAction::Leave;
+            }
+
+            let mut tx = make_test_transaction(TransactionStatus::Submitted);
+            tx.sent_at = Some((Utc::now() - Duration::seconds(600)).to_rfc3339());
+
+            mocks
+                .network_repo
+                .expect_get_by_chain_id()
+                .returning(|_, _| Ok(Some(create_test_network_model())));
+            mocks
+                .job_producer
+                .expect_produce_submit_transaction_job()
+                .never();
+            mocks
+                .job_producer
+                .expect_produce_check_transaction_status_job()
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+            let updated_tx = evm_transaction
+                .handle_submitted_state(tx.clone())
+                .await
+                .unwrap();
+
+            assert_eq!(updated_tx.status, TransactionStatus::Submitted);
+            assert_eq!(updated_tx.noop_count, tx.noop_count);
+        }
     }
 
     // Tests for `handle_pending_state`
@@ -265,7 +265,7 @@ mod tests {
             TransactionError::ValidationError(msg) => {
                 assert!(msg.contains("max_fee must be greater than 0"));
             }
-            _ => panic!("Expected ValidationError, got {:?}", err),
+            _ => panic!("Expected ValidationError, got {err:?}"),
         }
     }
 
@@ -560,15 +560,16 @@ mod signed_xdr_tests {
 
         /// Helper to create a policy with User fee payment strategy
         fn create_user_fee_policy() -> RelayerStellarPolicy {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.fee_payment_strategy = Some(StellarFeePaymentStrategy::User);
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: USDC_ASSET.to_string(),
-                metadata: None,
-                max_allowed_fee: None,
-                swap_config: None,
-            }]);
-            policy
+            RelayerStellarPolicy {
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::User),
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: USDC_ASSET.to_string(),
+                    metadata: None,
+                    max_allowed_fee: None,
+                    swap_config: None,
+                }]),
+                ..Default::default()
+            }
         }
 
         /// Helper to create a signed XDR with fee payment operation
@@ -875,7 +876,7 @@ mod signed_xdr_tests {
                 TransactionError::ValidationError(_) => {
                     // Success - validation failed as expected
                 }
-                other => panic!("Expected ValidationError, got: {:?}", other),
+                other => panic!("Expected ValidationError, got: {other:?}"),
             }
         }
 
@@ -1008,7 +1009,7 @@ mod signed_xdr_tests {
                 TransactionError::ValidationError(_) => {
                     // Success - validation failed as expected
                 }
-                other => panic!("Expected ValidationError, got: {:?}", other),
+                other => panic!("Expected ValidationError, got: {other:?}"),
             }
         }
 
@@ -1038,8 +1039,10 @@ mod signed_xdr_tests {
             });
 
             // Use Relayer fee payment strategy (default)
-            let mut policy = RelayerStellarPolicy::default();
-            policy.fee_payment_strategy = Some(StellarFeePaymentStrategy::Relayer);
+            let policy = RelayerStellarPolicy {
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
+                ..Default::default()
+            };
 
             // Create simple XDR without fee payment operation
             let source_pk = PublicKey::from_string(user_account).unwrap();
@@ -1246,7 +1249,7 @@ mod signed_xdr_tests {
                 TransactionError::ValidationError(_) => {
                     // Success - validation failed as expected
                 }
-                other => panic!("Expected ValidationError, got: {:?}", other),
+                other => panic!("Expected ValidationError, got: {other:?}"),
             }
         }
     }
@@ -222,7 +222,7 @@ mod tests {
                 assert_eq!(updated_data.sequence_number, Some(42));
                 assert_eq!(updated_data.signatures.len(), 1);
             }
-            Err(e) => panic!("Test failed with error: {:?}", e),
+            Err(e) => panic!("Test failed with error: {e:?}"),
         }
     }
 
@@ -291,7 +291,7 @@ mod tests {
                 assert!(updated_data.simulation_transaction_data.is_some());
                 assert_eq!(updated_data.signatures.len(), 1);
             }
-            Err(e) => panic!("Test failed with error: {:?}", e),
+            Err(e) => panic!("Test failed with error: {e:?}"),
         }
     }
 
@@ -468,12 +468,9 @@ mod tests {
         assert!(result.is_err());
 
         match result.unwrap_err() {
-            TransactionError::UnderlyingProvider(provider_error) => match provider_error {
-                ProviderError::Other(msg) => {
-                    assert!(msg.contains("Simulation failed"));
-                }
-                _ => panic!("Expected UnexpectedError"),
-            },
+            TransactionError::UnderlyingProvider(ProviderError::Other(msg)) => {
+                assert!(msg.contains("Simulation failed"));
+            }
             _ => panic!("Expected UnexpectedError"),
         }
     }
@@ -493,8 +490,10 @@ mod tests {
         let stellar_data = create_test_stellar_data();
 
         // Create a policy with User fee payment strategy (gasless mode)
-        let mut policy = RelayerStellarPolicy::default();
-        policy.fee_payment_strategy = Some(StellarFeePaymentStrategy::User);
+        let policy = RelayerStellarPolicy {
+            fee_payment_strategy: Some(StellarFeePaymentStrategy::User),
+            ..Default::default()
+        };
 
         let result = process_operations(
             &counter,
@@ -518,7 +517,7 @@ mod tests {
                 assert!(msg.contains("not supported via operations path"));
                 assert!(msg.contains("fee bump"));
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 }
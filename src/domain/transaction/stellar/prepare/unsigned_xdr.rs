@@ -863,13 +863,15 @@ mod tests {
             signatures: vec![].try_into().unwrap(),
         });
 
-        let mut policy = RelayerStellarPolicy::default();
-        policy.allowed_tokens = Some(vec![crate::models::StellarAllowedTokensPolicy {
-            asset: format!("USDC:{}", TEST_PK_2),
-            metadata: None,
-            swap_config: None,
-            max_allowed_fee: None,
-        }]);
+        let policy = RelayerStellarPolicy {
+            allowed_tokens: Some(vec![crate::models::StellarAllowedTokensPolicy {
+                asset: format!("USDC:{TEST_PK_2}"),
+                metadata: None,
+                swap_config: None,
+                max_allowed_fee: None,
+            }]),
+            ..Default::default()
+        };
 
         let result = is_valid_swap_transaction(&envelope, relayer_address, &policy);
         assert!(result.is_ok());
@@ -1008,13 +1010,15 @@ mod tests {
             signatures: vec![].try_into().unwrap(),
         });
 
-        let mut policy = RelayerStellarPolicy::default();
-        policy.allowed_tokens = Some(vec![crate::models::StellarAllowedTokensPolicy {
-            asset: format!("USDC:{}", TEST_PK_2),
-            metadata: None,
-            swap_config: None,
-            max_allowed_fee: None,
-        }]);
+        let policy = RelayerStellarPolicy {
+            allowed_tokens: Some(vec![crate::models::StellarAllowedTokensPolicy {
+                asset: format!("USDC:{TEST_PK_2}"),
+                metadata: None,
+                swap_config: None,
+                max_allowed_fee: None,
+            }]),
+            ..Default::default()
+        };
 
         let result = is_valid_swap_transaction(&envelope, relayer_address, &policy);
         assert!(result.is_ok());
@@ -1261,11 +1265,10 @@ mod xdr_transaction_tests {
             // The StellarValidationError formats differently - check for the expected/actual pattern
             assert!(
                 msg.contains("does not match relayer account"),
-                "Error message was: {}",
-                msg
+                "Error message was: {msg}"
             );
         } else {
-            panic!("Expected ValidationError, got {:?}", result);
+            panic!("Expected ValidationError, got {result:?}");
         }
     }
 
@@ -1399,8 +1402,7 @@ mod xdr_transaction_tests {
         let result = handler.prepare_transaction_impl(tx).await;
         assert!(
             result.is_ok(),
-            "Expected successful preparation, got: {:?}",
-            result
+            "Expected successful preparation, got: {result:?}"
         );
     }
 
@@ -1424,8 +1426,10 @@ mod xdr_transaction_tests {
         stellar_data.transaction_input = TransactionInput::UnsignedXdr(xdr);
 
         // Create a policy with User fee payment strategy (gasless mode)
-        let mut policy = RelayerStellarPolicy::default();
-        policy.fee_payment_strategy = Some(StellarFeePaymentStrategy::User);
+        let policy = RelayerStellarPolicy {
+            fee_payment_strategy: Some(StellarFeePaymentStrategy::User),
+            ..Default::default()
+        };
 
         // Call process_unsigned_xdr directly with the policy
         let dex_service = MockStellarDexServiceTrait::new();
@@ -1451,7 +1455,7 @@ mod xdr_transaction_tests {
                 assert!(msg.contains("not supported via unsigned_xdr path"));
                 assert!(msg.contains("fee_bump"));
             }
-            other => panic!("Expected ValidationError, got: {:?}", other),
+            other => panic!("Expected ValidationError, got: {other:?}"),
         }
     }
 
@@ -643,7 +643,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             StellarTransactionUtilsError::InvalidAssetFormat(_) => {}
-            e => panic!("Expected InvalidAssetFormat, got: {:?}", e),
+            e => panic!("Expected InvalidAssetFormat, got: {e:?}"),
         }
 
         // Empty string
@@ -679,7 +679,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             StellarTransactionUtilsError::EmptyIssuerAddress(_) => {}
-            e => panic!("Expected EmptyIssuerAddress, got: {:?}", e),
+            e => panic!("Expected EmptyIssuerAddress, got: {e:?}"),
         }
     }
 
@@ -691,7 +691,7 @@ mod tests {
             StellarTransactionUtilsError::InvalidIssuerLength(expected, _) => {
                 assert_eq!(expected, STELLAR_ADDRESS_LENGTH);
             }
-            e => panic!("Expected InvalidIssuerLength, got: {:?}", e),
+            e => panic!("Expected InvalidIssuerLength, got: {e:?}"),
         }
     }
 
@@ -705,7 +705,7 @@ mod tests {
             StellarTransactionUtilsError::InvalidIssuerPrefix(expected, _) => {
                 assert_eq!(expected, STELLAR_ACCOUNT_PREFIX);
             }
-            e => panic!("Expected InvalidIssuerPrefix, got: {:?}", e),
+            e => panic!("Expected InvalidIssuerPrefix, got: {e:?}"),
         }
     }
 
@@ -872,7 +872,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             StellarTransactionUtilsError::InvalidAssetFormat(_) => {}
-            e => panic!("Expected InvalidAssetFormat, got: {:?}", e),
+            e => panic!("Expected InvalidAssetFormat, got: {e:?}"),
         }
     }
 
@@ -888,7 +888,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             StellarTransactionUtilsError::EmptyAssetCode(_) => {}
-            e => panic!("Expected EmptyAssetCode, got: {:?}", e),
+            e => panic!("Expected EmptyAssetCode, got: {e:?}"),
         }
     }
 
@@ -907,7 +907,7 @@ mod tests {
                 assert_eq!(max, MAX_ASSET_CODE_LENGTH);
                 assert_eq!(code, "VERYLONGASSETCODE");
             }
-            e => panic!("Expected AssetCodeTooLong, got: {:?}", e),
+            e => panic!("Expected AssetCodeTooLong, got: {e:?}"),
         }
     }
 
@@ -919,7 +919,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             StellarTransactionUtilsError::EmptyIssuerAddress(_) => {}
-            e => panic!("Expected EmptyIssuerAddress, got: {:?}", e),
+            e => panic!("Expected EmptyIssuerAddress, got: {e:?}"),
         }
     }
 
@@ -933,7 +933,7 @@ mod tests {
             StellarTransactionUtilsError::InvalidIssuerLength(expected, _) => {
                 assert_eq!(expected, STELLAR_ADDRESS_LENGTH);
             }
-            e => panic!("Expected InvalidIssuerLength, got: {:?}", e),
+            e => panic!("Expected InvalidIssuerLength, got: {e:?}"),
         }
     }
 
@@ -952,7 +952,7 @@ mod tests {
             StellarTransactionUtilsError::InvalidIssuerPrefix(expected, _) => {
                 assert_eq!(expected, STELLAR_ACCOUNT_PREFIX);
             }
-            e => panic!("Expected InvalidIssuerPrefix, got: {:?}", e),
+            e => panic!("Expected InvalidIssuerPrefix, got: {e:?}"),
         }
     }
 
@@ -1196,7 +1196,7 @@ mod tests {
                 assert_eq!(asset_id, asset);
                 assert_eq!(account_id, account);
             }
-            e => panic!("Expected NoTrustlineFound, got: {:?}", e),
+            e => panic!("Expected NoTrustlineFound, got: {e:?}"),
         }
     }
 
@@ -1224,7 +1224,7 @@ mod tests {
                 assert_eq!(asset_id, asset);
                 assert_eq!(account_id, account);
             }
-            e => panic!("Expected NoTrustlineFound, got: {:?}", e),
+            e => panic!("Expected NoTrustlineFound, got: {e:?}"),
         }
     }
 
@@ -1297,7 +1297,7 @@ mod tests {
                 assert_eq!(max, MAX_ASSET_CODE_LENGTH);
                 assert_eq!(code, "VERYLONGASSETCODE");
             }
-            e => panic!("Expected AssetCodeTooLong, got: {:?}", e),
+            e => panic!("Expected AssetCodeTooLong, got: {e:?}"),
         }
     }
 
@@ -1475,7 +1475,7 @@ mod tests {
                 assert_eq!(hi, 1);
                 assert_eq!(lo, 1000000);
             }
-            e => panic!("Expected BalanceTooLarge, got: {:?}", e),
+            e => panic!("Expected BalanceTooLarge, got: {e:?}"),
         }
     }
 
@@ -1533,7 +1533,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             StellarTransactionUtilsError::NegativeBalanceI128(_) => {}
-            e => panic!("Expected NegativeBalanceI128, got: {:?}", e),
+            e => panic!("Expected NegativeBalanceI128, got: {e:?}"),
         }
     }
 
@@ -1694,7 +1694,7 @@ mod tests {
             StellarTransactionUtilsError::NegativeBalanceI64(n) => {
                 assert_eq!(n, -1000);
             }
-            e => panic!("Expected NegativeBalanceI64, got: {:?}", e),
+            e => panic!("Expected NegativeBalanceI64, got: {e:?}"),
         }
     }
 
@@ -1749,7 +1749,7 @@ mod tests {
         assert!(result.is_err());
         match result.unwrap_err() {
             StellarTransactionUtilsError::UnexpectedBalanceType(_) => {}
-            e => panic!("Expected UnexpectedBalanceType, got: {:?}", e),
+            e => panic!("Expected UnexpectedBalanceType, got: {e:?}"),
         }
     }
 
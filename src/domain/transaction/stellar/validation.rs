@@ -1224,8 +1224,7 @@ mod tests {
         #[test]
         fn test_classic_asset_valid() {
             let result = StellarTransactionValidator::validate_fee_token_structure(&format!(
-                "USDC:{}",
-                TEST_PK
+                "USDC:{TEST_PK}"
             ));
             assert!(result.is_ok());
         }
@@ -1233,8 +1232,7 @@ mod tests {
         #[test]
         fn test_classic_asset_code_too_long() {
             let result = StellarTransactionValidator::validate_fee_token_structure(&format!(
-                "VERYLONGCODE1:{}",
-                TEST_PK
+                "VERYLONGCODE1:{TEST_PK}"
             ));
             assert!(result.is_err());
             assert!(result
@@ -1291,26 +1289,30 @@ mod tests {
 
         #[test]
         fn test_native_allowed() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: "native".to_string(),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: "native".to_string(),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            };
             assert!(StellarTransactionValidator::validate_allowed_token("native", &policy).is_ok());
             assert!(StellarTransactionValidator::validate_allowed_token("", &policy).is_ok());
         }
 
         #[test]
         fn test_native_not_allowed() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: format!("USDC:{}", TEST_PK),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: format!("USDC:{TEST_PK}"),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            };
             let result = StellarTransactionValidator::validate_allowed_token("native", &policy);
             assert!(result.is_err());
             assert!(result
@@ -1321,28 +1323,32 @@ mod tests {
 
         #[test]
         fn test_token_allowed() {
-            let token = format!("USDC:{}", TEST_PK);
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: token.clone(),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
+            let token = format!("USDC:{TEST_PK}");
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: token.clone(),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            };
             assert!(StellarTransactionValidator::validate_allowed_token(&token, &policy).is_ok());
         }
 
         #[test]
         fn test_token_not_allowed() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: format!("USDC:{}", TEST_PK),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: format!("USDC:{TEST_PK}"),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            };
             let result = StellarTransactionValidator::validate_allowed_token(
-                &format!("AQUA:{}", TEST_PK_2),
+                &format!("AQUA:{TEST_PK_2}"),
                 &policy,
             );
             assert!(result.is_err());
@@ -1364,15 +1370,19 @@ mod tests {
 
         #[test]
         fn test_fee_within_limit() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.max_fee = Some(1_000_000);
+            let policy = RelayerStellarPolicy {
+                max_fee: Some(1_000_000),
+                ..Default::default()
+            };
             assert!(StellarTransactionValidator::validate_max_fee(500_000, &policy).is_ok());
         }
 
         #[test]
         fn test_fee_exceeds_limit() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.max_fee = Some(1_000_000);
+            let policy = RelayerStellarPolicy {
+                max_fee: Some(1_000_000),
+                ..Default::default()
+            };
             let result = StellarTransactionValidator::validate_max_fee(2_000_000, &policy);
             assert!(result.is_err());
             assert!(result.unwrap_err().to_string().contains("Max fee exceeded"));
@@ -1395,13 +1405,15 @@ mod tests {
 
         #[test]
         fn test_no_max_allowed_fee_in_entry() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: "USDC:ISSUER".to_string(),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: "USDC:ISSUER".to_string(),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            };
             assert!(StellarTransactionValidator::validate_token_max_fee(
                 "USDC:ISSUER",
                 1_000_000,
@@ -1412,13 +1424,15 @@ mod tests {
 
         #[test]
         fn test_fee_within_token_limit() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: "USDC:ISSUER".to_string(),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: Some(1_000_000),
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: "USDC:ISSUER".to_string(),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: Some(1_000_000),
+                }]),
+                ..Default::default()
+            };
             assert!(StellarTransactionValidator::validate_token_max_fee(
                 "USDC:ISSUER",
                 500_000,
@@ -1429,13 +1443,15 @@ mod tests {
 
         #[test]
         fn test_fee_exceeds_token_limit() {
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: "USDC:ISSUER".to_string(),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: Some(1_000_000),
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: "USDC:ISSUER".to_string(),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: Some(1_000_000),
+                }]),
+                ..Default::default()
+            };
             let result = StellarTransactionValidator::validate_token_max_fee(
                 "USDC:ISSUER",
                 2_000_000,
@@ -2007,7 +2023,7 @@ mod tests {
             let result = StellarTransactionValidator::validate_token_payment(
                 &envelope,
                 TEST_PK_2,
-                &format!("USDC:{}", TEST_PK),
+                &format!("USDC:{TEST_PK}"),
                 1_000_000,
                 &policy,
             );
@@ -2048,13 +2064,15 @@ mod tests {
         #[test]
         fn test_token_not_in_allowed_list() {
             let envelope = create_simple_v1_envelope(TEST_PK, TEST_PK_2);
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: format!("USDC:{}", TEST_PK),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: format!("USDC:{TEST_PK}"),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            };
 
             // Native payment but only USDC is allowed
             let result = StellarTransactionValidator::validate_token_payment(
@@ -2070,13 +2088,15 @@ mod tests {
         #[test]
         fn test_payment_exceeds_token_max_fee() {
             let envelope = create_simple_v1_envelope(TEST_PK, TEST_PK_2);
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: "native".to_string(),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: Some(500_000), // Max 0.5 XLM
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: "native".to_string(),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: Some(500_000), // Max 0.5 XLM
+                }]),
+                ..Default::default()
+            };
 
             // Payment is 1M but max allowed is 500K
             let result = StellarTransactionValidator::validate_token_payment(
@@ -2088,7 +2108,7 @@ mod tests {
 
         #[test]
         fn test_classic_asset_payment() {
-            let usdc_asset = format!("USDC:{}", TEST_PK);
+            let usdc_asset = format!("USDC:{TEST_PK}");
             let payment_op = Operation {
                 source_account: None,
                 body: OperationBody::Payment(soroban_rs::xdr::PaymentOp {
@@ -2116,13 +2136,15 @@ mod tests {
                 signatures: vec![].try_into().unwrap(),
             });
 
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: usdc_asset.clone(),
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: usdc_asset.clone(),
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            };
 
             let result = StellarTransactionValidator::validate_token_payment(
                 &envelope,
@@ -2137,7 +2159,7 @@ mod tests {
         #[test]
         fn test_multiple_payments_finds_correct_token() {
             // Create envelope with two payments: one USDC to relayer, one XLM to someone else
-            let usdc_asset = format!("USDC:{}", TEST_PK);
+            let usdc_asset = format!("USDC:{TEST_PK}");
             let usdc_payment = Operation {
                 source_account: None,
                 body: OperationBody::Payment(soroban_rs::xdr::PaymentOp {
@@ -2224,15 +2246,17 @@ mod tests {
         }
 
         fn create_usdc_policy() -> RelayerStellarPolicy {
-            let usdc_asset = format!("USDC:{}", USDC_ISSUER);
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: usdc_asset,
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: None,
-            }]);
-            policy
+            let usdc_asset = format!("USDC:{USDC_ISSUER}");
+
+            RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: usdc_asset,
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: None,
+                }]),
+                ..Default::default()
+            }
         }
 
         fn create_mock_provider_with_balance(balance: i64) -> MockStellarProviderTrait {
@@ -2327,7 +2351,7 @@ mod tests {
                     Box::pin(ready(Ok(
                         crate::services::stellar_dex::StellarQuoteResponse {
                             input_asset: "native".to_string(),
-                            output_asset: format!("USDC:{}", USDC_ISSUER),
+                            output_asset: format!("USDC:{USDC_ISSUER}"),
                             in_amount: 100,
                             out_amount: 1_000_000, // 0.1 USDC
                             price_impact_pct: 0.0,
@@ -2497,14 +2521,16 @@ mod tests {
         #[tokio::test]
         async fn test_fee_exceeds_token_max() {
             let envelope = create_usdc_payment_envelope(TEST_PK, TEST_PK_2, 1_000_000);
-            let usdc_asset = format!("USDC:{}", USDC_ISSUER);
-            let mut policy = RelayerStellarPolicy::default();
-            policy.allowed_tokens = Some(vec![StellarAllowedTokensPolicy {
-                asset: usdc_asset,
-                metadata: None,
-                swap_config: None,
-                max_allowed_fee: Some(500_000), // Lower than payment amount
-            }]);
+            let usdc_asset = format!("USDC:{USDC_ISSUER}");
+            let policy = RelayerStellarPolicy {
+                allowed_tokens: Some(vec![StellarAllowedTokensPolicy {
+                    asset: usdc_asset,
+                    metadata: None,
+                    swap_config: None,
+                    max_allowed_fee: Some(500_000), // Lower than payment amount
+                }]),
+                ..Default::default()
+            };
 
             let provider = create_mock_provider_with_balance(10_000_000_000);
             let dex_service = create_mock_dex_service();
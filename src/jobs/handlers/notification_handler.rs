@@ -81,9 +81,10 @@ async fn handle_request(
 mod tests {
     use super::*;
     use crate::models::{
-        EvmTransactionResponse, NetworkType, RelayerDisabledPayload, RelayerEvmPolicy,
-        RelayerNetworkPolicyResponse, RelayerResponse, TransactionResponse, TransactionStatus,
-        WebhookNotification, WebhookPayload, U256,
+        EvmTransactionResponse, GasOracleConfig, NetworkType, RelayerDisabledPayload,
+        RelayerEvmPolicy, RelayerNetworkPolicyResponse, RelayerResponse, RevertHandling,
+        StuckTxAction, TransactionResponse, TransactionStatus, WebhookNotification, WebhookPayload,
+        U256,
     };
 
     #[tokio::test]
@@ -174,6 +175,23 @@ mod tests {
                         private_transactions: Some(false),
                         min_balance: Some(0),
                         gas_limit_estimation: None,
+                        low_balance_alerting: None,
+                        validation_rules: vec![],
+                        gas_oracle: GasOracleConfig::ProviderDefault,
+                        simulate_before_send: false,
+                        revert_handling: RevertHandling::Reject,
+                        max_calldata_bytes: None,
+                        gas_bump: None,
+                        transaction_deadline_secs: None,
+                        max_inflight_transactions: None,
+                        allowed_tx_types: None,
+                        accepted_fee_tokens: None,
+                        min_priority_fee_per_gas: None,
+                        max_daily_spend: None,
+                        stuck_tx_action: StuckTxAction::SpeedUp,
+                        gas_estimation_multiplier: None,
+                        gas_limit_cap: None,
+                        max_rpc_batch_size: None,
                     }
                     .into(),
                 )),
@@ -256,8 +256,11 @@ fn calculate_backoff_delay(retry_count: u32) -> Duration {
 mod tests {
     use super::*;
     use crate::models::{
-        DisabledReason, NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy, RelayerRepoModel,
+        AlertState, CircuitState, DisabledReason, GasOracleConfig, NetworkType, RelayerEvmPolicy,
+        RelayerHealth, RelayerMode, RelayerNetworkPolicy, RelayerRepoModel, RevertHandling,
+        StuckTxAction,
     };
+    use std::collections::{HashMap, VecDeque};
 
     #[test]
     fn test_calculate_backoff_delay() {
@@ -285,7 +288,7 @@ mod tests {
     fn create_disabled_relayer(id: &str) -> RelayerRepoModel {
         RelayerRepoModel {
             id: id.to_string(),
-            name: format!("Relayer {}", id),
+            name: format!("Relayer {id}"),
             network: "sepolia".to_string(),
             paused: false,
             network_type: NetworkType::Evm,
@@ -296,6 +299,23 @@ mod tests {
                 private_transactions: Some(false),
                 min_balance: Some(0),
                 gas_limit_estimation: Some(false),
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             }),
             signer_id: "test-signer".to_string(),
             address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
@@ -305,6 +325,45 @@ mod tests {
                 "RPC unavailable".to_string(),
             )),
             custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: "test-tenant".to_string(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            version: 0,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
         }
     }
 
@@ -535,14 +594,13 @@ mod tests {
             let delay = calculate_backoff_delay(retry);
             if delay < Duration::from_secs(60) {
                 // Before cap, should increase
-                assert!(delay > prev_delay, "Retry {}: delay should increase", retry);
+                assert!(delay > prev_delay, "Retry {retry}: delay should increase");
             } else {
                 // At or after cap, should stay at 60
                 assert_eq!(
                     delay,
                     Duration::from_secs(60),
-                    "Retry {}: should cap at 60s",
-                    retry
+                    "Retry {retry}: should cap at 60s"
                 );
             }
             prev_delay = delay;
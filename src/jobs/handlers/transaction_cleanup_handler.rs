@@ -678,7 +678,7 @@ mod tests {
         ];
 
         for (i, status) in final_statuses.iter().enumerate() {
-            let tx_id = format!("test-tx-{}", i);
+            let tx_id = format!("test-tx-{i}");
             let transaction = create_test_transaction(
                 &tx_id,
                 relayer_id,
@@ -694,8 +694,7 @@ mod tests {
                 delete_expired_transaction(&transaction, &transaction_repo, relayer_id).await;
             assert!(
                 result.is_ok(),
-                "Failed to delete transaction with status: {:?}",
-                status
+                "Failed to delete transaction with status: {status:?}"
             );
 
             // Verify it was deleted
@@ -323,7 +323,7 @@ mod tests {
             env::set_var("LOG_MAX_SIZE", "1024"); // 1KB for testing
 
             // We don't call setup_logging() again, but we can test the directory creation logic
-            if let Some(parent) = Path::new(&format!("{}/relayer.log", log_path)).parent() {
+            if let Some(parent) = Path::new(&format!("{log_path}/relayer.log")).parent() {
                 create_dir_all(parent).expect("Failed to create log directory");
             }
 
@@ -31,6 +31,12 @@ pub enum RelayerError {
     RelayerDisabled,
     #[error("Relayer is paused")]
     RelayerPaused,
+    #[error("Relayer is sunset and accepts no new transactions")]
+    RelayerSunset,
+    #[error("Relayer is in canary mode and only accepts canary transactions")]
+    RelayerInCanaryMode,
+    #[error("Relayer is not operational")]
+    RelayerNotOperational,
     #[error("Transaction sequence error: {0}")]
     TransactionSequenceError(#[from] TransactionCounterError),
     #[error("Insufficient balance error: {0}")]
@@ -62,6 +68,13 @@ impl From<RelayerError> for ApiError {
                 ApiError::ForbiddenError("Relayer disabled".to_string())
             }
             RelayerError::RelayerPaused => ApiError::ForbiddenError("Relayer paused".to_string()),
+            RelayerError::RelayerSunset => ApiError::ForbiddenError("Relayer sunset".to_string()),
+            RelayerError::RelayerInCanaryMode => {
+                ApiError::ForbiddenError("Relayer only accepts canary transactions".to_string())
+            }
+            RelayerError::RelayerNotOperational => {
+                ApiError::ForbiddenError("Relayer not operational".to_string())
+            }
             RelayerError::TransactionSequenceError(err) => ApiError::InternalError(err.to_string()),
             RelayerError::InsufficientBalanceError(msg) => ApiError::BadRequest(msg),
             RelayerError::InsufficientRelayerBalance(msg) => ApiError::BadRequest(msg),
@@ -124,6 +137,15 @@ mod tests {
         let paused = RelayerError::RelayerPaused;
         assert_eq!(paused.to_string(), "Relayer is paused");
 
+        let sunset = RelayerError::RelayerSunset;
+        assert_eq!(
+            sunset.to_string(),
+            "Relayer is sunset and accepts no new transactions"
+        );
+
+        let not_operational = RelayerError::RelayerNotOperational;
+        assert_eq!(not_operational.to_string(), "Relayer is not operational");
+
         let insufficient_balance =
             RelayerError::InsufficientBalanceError("Not enough ETH".to_string());
         assert_eq!(
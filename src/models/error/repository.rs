@@ -39,6 +39,31 @@ pub enum RepositoryError {
 
     #[error("Other error: {0}")]
     Other(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+}
+
+impl RepositoryError {
+    /// Returns the variant name, without the message, for use as a low-cardinality
+    /// log or metric label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            RepositoryError::NotFound(_) => "NotFound",
+            RepositoryError::LockError(_) => "LockError",
+            RepositoryError::ConnectionError(_) => "ConnectionError",
+            RepositoryError::ConstraintViolation(_) => "ConstraintViolation",
+            RepositoryError::InvalidData(_) => "InvalidData",
+            RepositoryError::TransactionFailure(_) => "TransactionFailure",
+            RepositoryError::TransactionValidationFailed(_) => "TransactionValidationFailed",
+            RepositoryError::PermissionDenied(_) => "PermissionDenied",
+            RepositoryError::Unknown(_) => "Unknown",
+            RepositoryError::NotSupported(_) => "NotSupported",
+            RepositoryError::UnexpectedError(_) => "UnexpectedError",
+            RepositoryError::Other(_) => "Other",
+            RepositoryError::Conflict(_) => "Conflict",
+        }
+    }
 }
 
 impl From<RepositoryError> for ApiError {
@@ -55,6 +80,18 @@ impl From<RepositoryError> for ApiError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_repository_error_kind() {
+        assert_eq!(
+            RepositoryError::NotFound("secret detail".to_string()).kind(),
+            "NotFound"
+        );
+        assert_eq!(
+            RepositoryError::PermissionDenied("read-only".to_string()).kind(),
+            "PermissionDenied"
+        );
+    }
+
     #[test]
     fn test_repository_error_to_api_error_not_found() {
         let repo_error = RepositoryError::NotFound("User not found".to_string());
@@ -88,6 +125,7 @@ mod tests {
             RepositoryError::TransactionValidationFailed("Validation failed".to_string()),
             RepositoryError::PermissionDenied("Permission denied".to_string()),
             RepositoryError::NotSupported("Not supported".to_string()),
+            RepositoryError::Conflict("Version mismatch".to_string()),
         ];
 
         for repo_error in test_cases {
@@ -1,3 +1,4 @@
+use crate::constants::DEFAULT_PER_PAGE;
 use serde::Deserialize;
 use utoipa::ToSchema;
 
@@ -5,13 +6,45 @@ use utoipa::ToSchema;
 pub struct PaginationQuery {
     #[serde(default = "default_page")]
     pub page: u32,
-    #[serde(default = "default_per_page")]
-    pub per_page: u32,
+    /// Page size; when omitted, the repository's default for the listing applies
+    #[serde(default)]
+    pub per_page: Option<u32>,
 }
 
 fn default_page() -> u32 {
     1
 }
-fn default_per_page() -> u32 {
-    10
+
+impl PaginationQuery {
+    /// Returns the requested page size, or `default` if none was requested
+    pub fn per_page_or(&self, default: u32) -> u32 {
+        self.per_page.unwrap_or(default)
+    }
+
+    /// Returns the requested page size, or the global default if none was requested
+    pub fn resolved_per_page(&self) -> u32 {
+        self.per_page_or(DEFAULT_PER_PAGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_omitted_per_page_resolves_to_default() {
+        let query: PaginationQuery = serde_json::from_str(r#"{"page": 2}"#).unwrap();
+        assert_eq!(query.page, 2);
+        assert_eq!(query.per_page, None);
+        assert_eq!(query.resolved_per_page(), DEFAULT_PER_PAGE);
+        assert_eq!(query.per_page_or(50), 50);
+    }
+
+    #[test]
+    fn test_explicit_per_page_overrides_default() {
+        let query: PaginationQuery =
+            serde_json::from_str(r#"{"page": 1, "per_page": 25}"#).unwrap();
+        assert_eq!(query.per_page_or(50), 25);
+        assert_eq!(query.resolved_per_page(), 25);
+    }
 }
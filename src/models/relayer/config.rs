@@ -10,7 +10,7 @@
 //! Used primarily during application startup to parse relayer settings from config files.
 //! Validation is handled by the domain model in mod.rs to ensure reusability.
 
-use super::{Relayer, RelayerNetworkPolicy, RelayerValidationError, RpcConfig};
+use super::{Relayer, RelayerNetworkPolicy, RelayerNetworkType, RelayerValidationError, RpcConfig};
 use crate::config::{ConfigFileError, ConfigFileNetworkType, NetworksFileConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -258,14 +258,17 @@ impl<'de> Deserialize<'de> for RelayerFileConfig {
             .and_then(Value::as_bool)
             .ok_or_else(|| de::Error::missing_field("paused"))?;
 
-        // Deserialize `network_type` using `ConfigFileNetworkType`
-        let network_type: ConfigFileNetworkType = serde_json::from_value(
-            value
-                .get("network_type")
-                .cloned()
-                .ok_or_else(|| de::Error::missing_field("network_type"))?,
-        )
-        .map_err(de::Error::custom)?;
+        // Parse `network_type` so unknown values report the offending relayer
+        let network_type: ConfigFileNetworkType = value
+            .get("network_type")
+            .ok_or_else(|| de::Error::missing_field("network_type"))?
+            .as_str()
+            .ok_or_else(|| {
+                de::Error::custom(format!("network_type for relayer '{id}' must be a string"))
+            })?
+            .parse::<RelayerNetworkType>()
+            .map_err(|e| de::Error::custom(format!("Relayer '{id}': {e}")))?
+            .into();
 
         let signer_id = value
             .get("signer_id")
@@ -380,6 +383,9 @@ impl TryFrom<RelayerFileConfig> for Relayer {
             RelayerValidationError::InvalidRpcWeight => {
                 ConfigFileError::InvalidFormat("RPC URL weight must be in range 0-100".to_string())
             }
+            RelayerValidationError::InvalidRpcHeader(msg) => {
+                ConfigFileError::InvalidFormat(format!("Invalid RPC header: {msg}"))
+            }
             RelayerValidationError::InvalidField(msg) => ConfigFileError::InvalidFormat(msg),
         })?;
 
@@ -399,6 +405,23 @@ fn convert_config_policies_to_domain(
                 whitelist_receivers: evm_policy.whitelist_receivers,
                 eip1559_pricing: evm_policy.eip1559_pricing,
                 private_transactions: evm_policy.private_transactions,
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: super::GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: super::RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: super::StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             }))
         }
         ConfigFileRelayerNetworkPolicy::Solana(solana_policy) => {
@@ -463,6 +486,10 @@ fn convert_config_policies_to_domain(
                 disallowed_accounts: solana_policy.disallowed_accounts,
                 max_allowed_fee_lamports: solana_policy.max_allowed_fee_lamports,
                 swap_config,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }))
         }
         ConfigFileRelayerNetworkPolicy::Stellar(stellar_policy) => {
@@ -521,6 +548,10 @@ fn convert_config_policies_to_domain(
                 slippage_percentage: stellar_policy.slippage_percentage,
                 fee_margin_percentage: stellar_policy.fee_margin_percentage,
                 swap_config,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }))
         }
     }
@@ -585,6 +616,9 @@ impl RelayersFileConfig {
                 RelayerValidationError::InvalidRpcWeight => ConfigFileError::InvalidFormat(
                     "RPC URL weight must be in range 0-100".to_string(),
                 ),
+                RelayerValidationError::InvalidRpcHeader(msg) => {
+                    ConfigFileError::InvalidFormat(format!("Invalid RPC header: {msg}"))
+                }
                 RelayerValidationError::InvalidField(msg) => ConfigFileError::InvalidFormat(msg),
             })?;
 
@@ -861,6 +895,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_relayer_file_config_deserialization_network_type_typo_names_relayer() {
+        let json_input = r#"{
+            "id": "typo-relayer",
+            "name": "Test Relayer",
+            "network": "mainnet",
+            "paused": false,
+            "network_type": "evmm",
+            "signer_id": "test-signer"
+        }"#;
+
+        let err = serde_json::from_str::<RelayerFileConfig>(json_input)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("typo-relayer"));
+        assert!(err.contains("Invalid network type 'evmm'"));
+        assert!(err.contains("evm, solana, stellar"));
+    }
+
     #[test]
     fn test_relayer_file_config_deserialization_wrong_policy_for_network_type() {
         // Test EVM network type with Solana policy should fail
@@ -1265,10 +1318,7 @@ mod tests {
                 // Also acceptable - network doesn't exist in our mock config
             }
             Err(other) => {
-                panic!(
-                    "Expected DuplicateId or InvalidReference error, got: {:?}",
-                    other
-                );
+                panic!("Expected DuplicateId or InvalidReference error, got: {other:?}");
             }
             Ok(_) => {
                 panic!("Expected validation to fail but it succeeded");
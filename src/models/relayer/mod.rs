@@ -28,8 +28,15 @@ pub use rpc_config::*;
 
 use crate::{
     config::ConfigFileNetworkType,
-    constants::ID_REGEX,
-    utils::{deserialize_optional_u128, serialize_optional_u128},
+    constants::{
+        DEFAULT_EVM_EIP1559_ENABLED, DEFAULT_EVM_GAS_LIMIT_ESTIMATION, DEFAULT_EVM_GAS_PRICE_CAP,
+        DEFAULT_EVM_MIN_BALANCE, DEFAULT_SOLANA_MAX_TX_DATA_SIZE, DEFAULT_SOLANA_MIN_BALANCE,
+        DEFAULT_STELLAR_CONCURRENT_TRANSACTIONS, DEFAULT_STELLAR_MIN_BALANCE,
+        GAS_LIMIT_BUFFER_MULTIPLIER, ID_REGEX, MAX_EVM_GAS_ESTIMATION_MULTIPLIER,
+        MIN_EVM_GAS_BUMP_PERCENT, MIN_EVM_GAS_ESTIMATION_MULTIPLIER, MIN_EVM_MAX_CALLDATA_BYTES,
+    },
+    models::RepositoryError,
+    utils::{deserialize_optional_u128, deserialize_u128, serialize_optional_u128, serialize_u128},
 };
 use apalis_cron::Schedule;
 use regex::Regex;
@@ -42,7 +49,7 @@ use utoipa::ToSchema;
 use validator::Validate;
 
 /// Network type enum for relayers
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum RelayerNetworkType {
     Evm,
@@ -60,6 +67,57 @@ impl Display for RelayerNetworkType {
     }
 }
 
+/// Error returned when parsing an unknown network type string
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("Invalid network type '{0}', expected one of: evm, solana, stellar")]
+pub struct ParseNetworkTypeError(pub String);
+
+impl FromStr for RelayerNetworkType {
+    type Err = ParseNetworkTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "evm" => Ok(RelayerNetworkType::Evm),
+            "solana" => Ok(RelayerNetworkType::Solana),
+            "stellar" => Ok(RelayerNetworkType::Stellar),
+            _ => Err(ParseNetworkTypeError(s.to_string())),
+        }
+    }
+}
+
+impl RelayerNetworkType {
+    /// Returns true if `address` is a well-formed account address for this network type
+    pub fn is_valid_address(&self, address: &str) -> bool {
+        match self {
+            RelayerNetworkType::Evm => address
+                .strip_prefix("0x")
+                .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit())),
+            RelayerNetworkType::Solana => solana_sdk::pubkey::Pubkey::from_str(address).is_ok(),
+            RelayerNetworkType::Stellar => {
+                stellar_strkey::ed25519::PublicKey::from_string(address).is_ok()
+            }
+        }
+    }
+
+    /// Returns the canonical form of `address` for lookups: lowercase for EVM, where
+    /// case only carries the optional checksum, and unchanged otherwise.
+    pub fn normalize_address(&self, address: &str) -> String {
+        match self {
+            RelayerNetworkType::Evm => address.to_ascii_lowercase(),
+            RelayerNetworkType::Solana | RelayerNetworkType::Stellar => address.to_string(),
+        }
+    }
+
+    /// Returns true if `a` and `b` denote the same account. EVM addresses are compared
+    /// case-insensitively, since their mixed-case checksum encoding is optional.
+    pub fn addresses_match(&self, a: &str, b: &str) -> bool {
+        match self {
+            RelayerNetworkType::Evm => a.eq_ignore_ascii_case(b),
+            RelayerNetworkType::Solana | RelayerNetworkType::Stellar => a == b,
+        }
+    }
+}
+
 impl From<ConfigFileNetworkType> for RelayerNetworkType {
     fn from(config_type: ConfigFileNetworkType) -> Self {
         match config_type {
@@ -128,6 +186,10 @@ pub enum DisabledReason {
     /// Multiple failures occurred simultaneously
     #[schema(value_type = Vec<String>)]
     Multiple(Vec<DisabledReason>),
+    /// Relayer was quarantined for a cooldown window after repeated failures
+    Quarantined(String),
+    /// Relayer was disabled by a previously scheduled maintenance disable
+    Scheduled(String),
 }
 
 // Custom serialization that sanitizes error details for external exposure
@@ -161,6 +223,14 @@ impl Serialize for DisabledReason {
                 state.serialize_field("type", "Multiple")?;
                 state.serialize_field("details", reasons)?;
             }
+            DisabledReason::Quarantined(_) => {
+                state.serialize_field("type", "Quarantined")?;
+                state.serialize_field("details", "Relayer quarantined")?;
+            }
+            DisabledReason::Scheduled(_) => {
+                state.serialize_field("type", "Scheduled")?;
+                state.serialize_field("details", "Scheduled disable")?;
+            }
         }
 
         state.end()
@@ -222,6 +292,8 @@ impl DisabledReason {
             DisabledReason::RpcValidationFailed(e) => format!("RPC validation failed: {e}"),
             DisabledReason::BalanceCheckFailed(e) => format!("Balance check failed: {e}"),
             DisabledReason::SequenceSyncFailed(e) => format!("Sequence sync failed: {e}"),
+            DisabledReason::Quarantined(e) => format!("Quarantined: {e}"),
+            DisabledReason::Scheduled(e) => format!("Scheduled disable: {e}"),
             DisabledReason::Multiple(reasons) => reasons
                 .iter()
                 .map(|r| r.description())
@@ -238,6 +310,8 @@ impl DisabledReason {
             DisabledReason::RpcValidationFailed(_) => "RPC endpoint validation failed".to_string(),
             DisabledReason::BalanceCheckFailed(_) => "Insufficient balance".to_string(),
             DisabledReason::SequenceSyncFailed(_) => "Sequence synchronization failed".to_string(),
+            DisabledReason::Quarantined(_) => "Relayer quarantined".to_string(),
+            DisabledReason::Scheduled(_) => "Scheduled disable".to_string(),
             DisabledReason::Multiple(reasons) => reasons
                 .iter()
                 .map(|r| r.safe_description())
@@ -287,6 +361,27 @@ impl std::fmt::Display for DisabledReason {
     }
 }
 
+/// Low balance alerting thresholds with hysteresis
+///
+/// An alert fires when the balance drops below `warn_below` and is only cleared
+/// once the balance rises above `clear_above`, avoiding flapping around a single value.
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AlertThresholds {
+    #[serde(
+        serialize_with = "serialize_u128",
+        deserialize_with = "deserialize_u128"
+    )]
+    #[schema(value_type = String)]
+    pub warn_below: u128,
+    #[serde(
+        serialize_with = "serialize_u128",
+        deserialize_with = "deserialize_u128"
+    )]
+    #[schema(value_type = String)]
+    pub clear_above: u128,
+}
+
 /// EVM-specific relayer policy configuration
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq, Default)]
 #[serde(deny_unknown_fields)]
@@ -313,6 +408,290 @@ pub struct RelayerEvmPolicy {
     pub eip1559_pricing: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub private_transactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub low_balance_alerting: Option<AlertThresholds>,
+    /// Declarative rules every relayed transaction must satisfy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation_rules: Vec<TransactionRule>,
+    /// Source of gas prices used by the pricing layer for this relayer
+    #[serde(default, skip_serializing_if = "GasOracleConfig::is_provider_default")]
+    pub gas_oracle: GasOracleConfig,
+    /// Simulate every transaction with `eth_call` before broadcasting it
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub simulate_before_send: bool,
+    /// What to do when a pre-send simulation reverts
+    #[serde(default, skip_serializing_if = "RevertHandling::is_reject")]
+    pub revert_handling: RevertHandling,
+    /// Maximum calldata size, in bytes, of a relayed transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_calldata_bytes: Option<u32>,
+    /// How stuck transactions are resubmitted with higher fees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_bump: Option<GasBumpPolicy>,
+    /// Seconds after which a pending transaction is dropped instead of resubmitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_deadline_secs: Option<u64>,
+    /// Maximum number of transactions the relayer may have in flight at once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inflight_transactions: Option<u32>,
+    /// Transaction types the relayer may emit; all types are allowed when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_tx_types: Option<Vec<EvmTxType>>,
+    /// ERC-20 tokens the relayer accepts as fee payment, consumed by fee estimation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_fee_tokens: Option<Vec<FeeToken>>,
+    /// Lower bound, in wei, for the EIP-1559 priority fee computed by the pricing layer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    pub min_priority_fee_per_gas: Option<u128>,
+    /// Maximum native-currency amount, in the network's smallest unit, the relayer may
+    /// spend in a rolling day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    pub max_daily_spend: Option<u128>,
+    /// What the resubmission logic does with a stuck transaction
+    #[serde(default, skip_serializing_if = "StuckTxAction::is_speed_up")]
+    pub stuck_tx_action: StuckTxAction,
+    /// Factor applied to estimated gas limits in place of the default buffer. Only
+    /// used when `gas_limit_estimation` is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_estimation_multiplier: Option<f64>,
+    /// Upper bound on gas limits derived from estimates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit_cap: Option<u64>,
+    /// Maximum number of requests in a JSON-RPC batch proxied through the relayer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_rpc_batch_size: Option<u32>,
+}
+
+impl RelayerEvmPolicy {
+    /// Gas limit to use for a transaction whose gas was estimated at `estimated`.
+    ///
+    /// Applies `gas_estimation_multiplier`, or the default buffer when unset, then
+    /// clamps the result to `gas_limit_cap`.
+    pub fn effective_gas_limit(&self, estimated: u64) -> u64 {
+        let buffered = match self.gas_estimation_multiplier {
+            Some(multiplier) => (estimated as f64 * multiplier).ceil() as u64,
+            None => estimated.saturating_mul(GAS_LIMIT_BUFFER_MULTIPLIER) / 100,
+        };
+        self.gas_limit_cap.map_or(buffered, |cap| buffered.min(cap))
+    }
+
+    /// Returns true if the ERC-20 token at `address` is accepted as fee payment.
+    /// Addresses are compared case-insensitively.
+    pub fn is_fee_token_accepted(&self, address: &str) -> bool {
+        self.accepted_fee_tokens.as_ref().is_some_and(|tokens| {
+            tokens
+                .iter()
+                .any(|token| RelayerNetworkType::Evm.addresses_match(&token.address, address))
+        })
+    }
+
+    /// Returns true if a JSON-RPC batch of `size` requests may be proxied
+    pub fn is_batch_within_limit(&self, size: usize) -> bool {
+        self.max_rpc_batch_size
+            .is_none_or(|limit| size <= limit as usize)
+    }
+
+    /// Returns true if the relayer may emit transactions of type `tx_type`
+    pub fn is_tx_type_allowed(&self, tx_type: EvmTxType) -> bool {
+        self.allowed_tx_types
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(&tx_type))
+    }
+}
+
+/// ERC-20 token accepted as fee payment by an EVM relayer
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FeeToken {
+    /// Token contract address
+    pub address: String,
+    /// Minimum accepted exchange rate, in token units per unit of native currency
+    pub min_rate: f64,
+}
+
+/// EVM transaction envelope type
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, ToSchema, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EvmTxType {
+    /// Type 0 transaction with a single gas price
+    Legacy,
+    /// Type 2 transaction with a base fee and priority fee
+    Eip1559,
+    /// Type 3 transaction carrying blobs
+    Eip4844,
+}
+
+/// Gas price source for an EVM relayer
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq, Eq, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GasOracleConfig {
+    /// Use the gas price estimation of the network's RPC provider
+    #[default]
+    ProviderDefault,
+    /// Fetch gas prices from a custom oracle endpoint
+    Custom { url: String },
+    /// Always use a fixed gas price (in wei)
+    Fixed {
+        #[serde(
+            serialize_with = "serialize_u128",
+            deserialize_with = "deserialize_u128"
+        )]
+        #[schema(value_type = String)]
+        price: u128,
+    },
+}
+
+impl GasOracleConfig {
+    pub fn is_provider_default(&self) -> bool {
+        matches!(self, Self::ProviderDefault)
+    }
+
+    /// Validates that a custom oracle endpoint is a well-formed http(s) URL
+    pub fn validate(&self) -> Result<(), RepositoryError> {
+        if let Self::Custom { url } = self {
+            let parsed = reqwest::Url::parse(url).map_err(|_| {
+                RepositoryError::InvalidData(format!("Invalid gas oracle URL: {url}"))
+            })?;
+            if !matches!(parsed.scheme(), "http" | "https") {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Gas oracle URL must use http or https: {url}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fee bumping applied when resubmitting a stuck EVM transaction
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, ToSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct GasBumpPolicy {
+    /// Fee increase applied on each resubmission, as a percentage
+    pub bump_percent: u8,
+    /// Maximum number of resubmissions of a single transaction
+    pub max_bumps: u8,
+    /// Minimum time to wait before resubmitting a transaction again
+    pub bump_interval_secs: u64,
+}
+
+impl GasBumpPolicy {
+    /// Validates that each bump meets the replacement minimum and at least one bump is allowed
+    pub fn validate(&self) -> Result<(), RepositoryError> {
+        if self.bump_percent < MIN_EVM_GAS_BUMP_PERCENT {
+            return Err(RepositoryError::InvalidData(format!(
+                "Gas bump percent must be at least {MIN_EVM_GAS_BUMP_PERCENT}, got {}",
+                self.bump_percent
+            )));
+        }
+        if self.max_bumps < 1 {
+            return Err(RepositoryError::InvalidData(
+                "Gas bump max_bumps must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Action taken when a pre-send simulation of a transaction reverts
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, ToSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RevertHandling {
+    /// Fail the transaction without broadcasting it
+    #[default]
+    Reject,
+    /// Broadcast the transaction regardless of the simulation result
+    SubmitAnyway,
+}
+
+impl RevertHandling {
+    pub fn is_reject(&self) -> bool {
+        matches!(self, Self::Reject)
+    }
+}
+
+/// Action taken on a submitted transaction that has been pending too long
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, ToSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StuckTxAction {
+    /// Resubmit the transaction with bumped fees
+    #[default]
+    SpeedUp,
+    /// Replace the transaction with a zero-value self-transaction at the same nonce
+    Cancel,
+    /// Keep waiting without resubmitting
+    Leave,
+}
+
+impl StuckTxAction {
+    pub fn is_speed_up(&self) -> bool {
+        matches!(self, Self::SpeedUp)
+    }
+}
+
+/// Declarative rule gating which transactions a relayer will relay
+///
+/// Selector rules only apply to contract calls; transactions without calldata
+/// carry no selector and are unaffected by them.
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+#[serde(tag = "type", content = "value")]
+pub enum TransactionRule {
+    /// Maximum native value (in wei) a transaction may transfer
+    #[schema(value_type = String)]
+    #[serde(
+        serialize_with = "serialize_u128",
+        deserialize_with = "deserialize_u128"
+    )]
+    MaxValue(u128),
+    /// Only these 4-byte function selectors may be called
+    #[schema(value_type = Vec<Vec<u8>>)]
+    AllowedSelectors(Vec<[u8; 4]>),
+    /// These 4-byte function selectors may never be called
+    #[schema(value_type = Vec<Vec<u8>>)]
+    DenySelectors(Vec<[u8; 4]>),
+}
+
+/// Violation of a relayer's transaction validation rules
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RuleViolation {
+    #[error("Transaction value {value} exceeds maximum allowed value {max}")]
+    ValueExceedsMax { value: u128, max: u128 },
+    #[error("Function selector 0x{} is not in the allowed list", hex::encode(.0))]
+    SelectorNotAllowed([u8; 4]),
+    #[error("Function selector 0x{} is denied", hex::encode(.0))]
+    SelectorDenied([u8; 4]),
+}
+
+impl TransactionRule {
+    /// Checks a transaction's value and optional function selector against this rule
+    pub fn evaluate(&self, value: u128, selector: Option<[u8; 4]>) -> Result<(), RuleViolation> {
+        match (self, selector) {
+            (TransactionRule::MaxValue(max), _) if value > *max => {
+                Err(RuleViolation::ValueExceedsMax { value, max: *max })
+            }
+            (TransactionRule::AllowedSelectors(allowed), Some(selector))
+                if !allowed.contains(&selector) =>
+            {
+                Err(RuleViolation::SelectorNotAllowed(selector))
+            }
+            (TransactionRule::DenySelectors(denied), Some(selector))
+                if denied.contains(&selector) =>
+            {
+                Err(RuleViolation::SelectorDenied(selector))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 /// Solana token swap configuration
@@ -463,6 +842,24 @@ pub struct RelayerSolanaPolicy {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub swap_config: Option<RelayerSolanaSwapConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub low_balance_alerting: Option<AlertThresholds>,
+    /// Seconds after which a pending transaction is dropped instead of resubmitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_deadline_secs: Option<u64>,
+    /// Maximum number of transactions the relayer may have in flight at once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inflight_transactions: Option<u32>,
+    /// Maximum native-currency amount, in the network's smallest unit, the relayer may
+    /// spend in a rolling day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    pub max_daily_spend: Option<u128>,
 }
 
 impl RelayerSolanaPolicy {
@@ -623,6 +1020,24 @@ pub struct RelayerStellarPolicy {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub swap_config: Option<RelayerStellarSwapConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub low_balance_alerting: Option<AlertThresholds>,
+    /// Seconds after which a pending transaction is dropped instead of resubmitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_deadline_secs: Option<u64>,
+    /// Maximum number of transactions the relayer may have in flight at once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inflight_transactions: Option<u32>,
+    /// Maximum native-currency amount, in the network's smallest unit, the relayer may
+    /// spend in a rolling day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    pub max_daily_spend: Option<u128>,
 }
 
 impl RelayerStellarPolicy {
@@ -655,9 +1070,10 @@ impl RelayerStellarPolicy {
     }
 }
 
-/// Network-specific policy for relayers
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
-#[serde(tag = "network_type")]
+/// Network-specific policy for relayers, serialized with a `type` tag
+/// (`{ "type": "evm", "gas_price_cap": ... }`)
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+#[serde(tag = "type")]
 pub enum RelayerNetworkPolicy {
     #[serde(rename = "evm")]
     Evm(RelayerEvmPolicy),
@@ -667,6 +1083,47 @@ pub enum RelayerNetworkPolicy {
     Stellar(RelayerStellarPolicy),
 }
 
+impl<'de> Deserialize<'de> for RelayerNetworkPolicy {
+    /// Accepts the `type`-tagged form and, for this release only, the externally tagged
+    /// form (`{ "evm": { ... } }`). The `network_type`-tagged form is accepted too because
+    /// it is how relayers already in storage were serialized before the switch to `type`.
+    ///
+    /// Both legacy forms are removed in the next release; relayers rewritten by any update
+    /// in the meantime are stored with the `type` tag.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        use serde_json::Value;
+
+        let Value::Object(mut fields) = Value::deserialize(deserializer)? else {
+            return Err(D::Error::custom("relayer policy must be an object"));
+        };
+
+        let (tag, policy) = match fields
+            .remove("type")
+            .or_else(|| fields.remove("network_type"))
+        {
+            Some(tag) => (tag, Value::Object(fields)),
+            None if fields.len() == 1 => {
+                let (tag, policy) = fields.into_iter().next().expect("map has one entry");
+                (Value::String(tag), policy)
+            }
+            None => return Err(D::Error::missing_field("type")),
+        };
+
+        let network_type: RelayerNetworkType =
+            serde_json::from_value(tag).map_err(D::Error::custom)?;
+        match network_type {
+            RelayerNetworkType::Evm => serde_json::from_value(policy).map(Self::Evm),
+            RelayerNetworkType::Solana => serde_json::from_value(policy).map(Self::Solana),
+            RelayerNetworkType::Stellar => serde_json::from_value(policy).map(Self::Stellar),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
 impl RelayerNetworkPolicy {
     /// Get EVM policy, returning default if not EVM
     pub fn get_evm_policy(&self) -> RelayerEvmPolicy {
@@ -691,98 +1148,624 @@ impl RelayerNetworkPolicy {
             _ => RelayerStellarPolicy::default(),
         }
     }
-}
-
-/// Core relayer domain model
-#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
-pub struct Relayer {
-    #[validate(
-        length(min = 1, max = 36, message = "ID must be between 1 and 36 characters"),
-        regex(
-            path = "*ID_REGEX",
-            message = "ID must contain only letters, numbers, dashes and underscores"
-        )
-    )]
-    pub id: String,
 
-    #[validate(length(min = 1, message = "Name cannot be empty"))]
-    pub name: String,
+    /// Borrow the EVM policy, if this is one
+    pub fn as_evm(&self) -> Option<&RelayerEvmPolicy> {
+        match self {
+            Self::Evm(policy) => Some(policy),
+            _ => None,
+        }
+    }
 
-    #[validate(length(min = 1, message = "Network cannot be empty"))]
-    pub network: String,
+    /// Borrow the Solana policy, if this is one
+    pub fn as_solana(&self) -> Option<&RelayerSolanaPolicy> {
+        match self {
+            Self::Solana(policy) => Some(policy),
+            _ => None,
+        }
+    }
 
-    pub paused: bool,
-    pub network_type: RelayerNetworkType,
-    pub policies: Option<RelayerNetworkPolicy>,
+    /// Borrow the Stellar policy, if this is one
+    pub fn as_stellar(&self) -> Option<&RelayerStellarPolicy> {
+        match self {
+            Self::Stellar(policy) => Some(policy),
+            _ => None,
+        }
+    }
 
-    #[validate(length(min = 1, message = "Signer ID cannot be empty"))]
-    pub signer_id: String,
+    /// Get the transaction validation rules. Only EVM policies define rules.
+    pub fn validation_rules(&self) -> &[TransactionRule] {
+        match self {
+            Self::Evm(policy) => &policy.validation_rules,
+            _ => &[],
+        }
+    }
 
-    pub notification_id: Option<String>,
-    pub custom_rpc_urls: Option<Vec<RpcConfig>>,
-}
+    /// Get the gas oracle configuration. Only EVM policies define a gas oracle.
+    pub fn gas_oracle(&self) -> Option<&GasOracleConfig> {
+        match self {
+            Self::Evm(policy) => Some(&policy.gas_oracle),
+            _ => None,
+        }
+    }
 
-impl Relayer {
-    /// Creates a new relayer
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        id: String,
-        name: String,
-        network: String,
-        paused: bool,
-        network_type: RelayerNetworkType,
-        policies: Option<RelayerNetworkPolicy>,
-        signer_id: String,
-        notification_id: Option<String>,
-        custom_rpc_urls: Option<Vec<RpcConfig>>,
-    ) -> Self {
-        Self {
-            id,
-            name,
-            network,
-            paused,
-            network_type,
-            policies,
-            signer_id,
-            notification_id,
-            custom_rpc_urls,
+    /// Whether transactions should be simulated before sending. Only EVM policies simulate.
+    pub fn simulate_before_send(&self) -> bool {
+        match self {
+            Self::Evm(policy) => policy.simulate_before_send,
+            _ => false,
         }
     }
 
-    /// Validates the relayer using both validator crate and custom validation
-    pub fn validate(&self) -> Result<(), RelayerValidationError> {
-        // Check for empty ID specifically first
-        if self.id.is_empty() {
-            return Err(RelayerValidationError::EmptyId);
+    /// Get the revert handling for failed simulations. Only EVM policies define one.
+    pub fn revert_handling(&self) -> Option<RevertHandling> {
+        match self {
+            Self::Evm(policy) => Some(policy.revert_handling),
+            _ => None,
         }
+    }
 
-        // Check for ID too long
-        if self.id.len() > 36 {
-            return Err(RelayerValidationError::IdTooLong);
+    /// Get the action taken on stuck transactions. Only EVM policies define one.
+    pub fn stuck_tx_action(&self) -> Option<StuckTxAction> {
+        match self {
+            Self::Evm(policy) => Some(policy.stuck_tx_action),
+            _ => None,
         }
+    }
 
-        // First run validator crate validation
-        Validate::validate(self).map_err(|validation_errors| {
-            // Convert validator errors to our custom error type
-            for (field, errors) in validation_errors.field_errors() {
-                if let Some(error) = errors.first() {
-                    let field_str = field.as_ref();
-                    return match (field_str, error.code.as_ref()) {
-                        ("id", "regex") => RelayerValidationError::InvalidIdFormat,
-                        ("name", "length") => RelayerValidationError::EmptyName,
-                        ("network", "length") => RelayerValidationError::EmptyNetwork,
-                        ("signer_id", "length") => RelayerValidationError::InvalidPolicy(
-                            "Signer ID cannot be empty".to_string(),
-                        ),
-                        _ => RelayerValidationError::InvalidIdFormat, // fallback
-                    };
-                }
-            }
-            // Fallback error
-            RelayerValidationError::InvalidIdFormat
-        })?;
+    /// Validates the gas oracle configuration, if any
+    pub fn validate_gas_oracle(&self) -> Result<(), RepositoryError> {
+        self.gas_oracle().map_or(Ok(()), GasOracleConfig::validate)
+    }
 
-        // Run custom validation
+    /// Runs the policy checks shared by relayer create, update and `update_policy`,
+    /// returning every failure in order
+    pub fn validation_errors(&self) -> Vec<RepositoryError> {
+        let checks = [
+            self.validate_gas_oracle(),
+            self.validate_calldata_limit(),
+            self.validate_gas_bump(),
+            self.validate_transaction_deadline(),
+            self.validate_max_inflight_transactions(),
+            self.validate_allowed_tx_types(),
+            self.validate_accepted_fee_tokens(),
+            self.validate_min_priority_fee(),
+            self.validate_max_daily_spend(),
+            self.validate_gas_estimation(),
+            self.validate_max_rpc_batch_size(),
+        ];
+        checks.into_iter().filter_map(Result::err).collect()
+    }
+
+    /// Runs the policy checks shared by relayer create, update and `update_policy`,
+    /// failing with the first violation
+    pub fn validate(&self) -> Result<(), RepositoryError> {
+        self.validation_errors()
+            .into_iter()
+            .next()
+            .map_or(Ok(()), Err)
+    }
+
+    /// Get the calldata size limit, in bytes. Only EVM policies define a limit.
+    pub fn max_calldata_bytes(&self) -> Option<u32> {
+        match self {
+            Self::Evm(policy) => policy.max_calldata_bytes,
+            _ => None,
+        }
+    }
+
+    /// Get the JSON-RPC batch size limit. Only EVM policies define one.
+    pub fn max_rpc_batch_size(&self) -> Option<u32> {
+        match self {
+            Self::Evm(policy) => policy.max_rpc_batch_size,
+            _ => None,
+        }
+    }
+
+    /// Validates that the JSON-RPC batch size limit, if set, is at least 1
+    pub fn validate_max_rpc_batch_size(&self) -> Result<(), RepositoryError> {
+        match self.max_rpc_batch_size() {
+            Some(0) => Err(RepositoryError::InvalidData(
+                "max_rpc_batch_size must be at least 1".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the gas bump policy. Only EVM policies define one.
+    pub fn gas_bump(&self) -> Option<&GasBumpPolicy> {
+        match self {
+            Self::Evm(policy) => policy.gas_bump.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Validates the gas bump policy, if any
+    pub fn validate_gas_bump(&self) -> Result<(), RepositoryError> {
+        self.gas_bump().map_or(Ok(()), GasBumpPolicy::validate)
+    }
+
+    /// Validates that the calldata limit, if set, is not below the size of a function selector
+    pub fn validate_calldata_limit(&self) -> Result<(), RepositoryError> {
+        match self.max_calldata_bytes() {
+            Some(limit) if limit < MIN_EVM_MAX_CALLDATA_BYTES => {
+                Err(RepositoryError::InvalidData(format!(
+                    "max_calldata_bytes must be at least {MIN_EVM_MAX_CALLDATA_BYTES}, got {limit}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the minimum balance the relayer should hold, in the smallest unit of its network
+    pub fn min_balance(&self) -> Option<u128> {
+        match self {
+            Self::Evm(policy) => policy.min_balance,
+            Self::Solana(policy) => policy.min_balance.map(u128::from),
+            Self::Stellar(policy) => policy.min_balance.map(u128::from),
+        }
+    }
+
+    /// Get the transaction deadline, in seconds, if configured
+    pub fn transaction_deadline_secs(&self) -> Option<u64> {
+        match self {
+            Self::Evm(policy) => policy.transaction_deadline_secs,
+            Self::Solana(policy) => policy.transaction_deadline_secs,
+            Self::Stellar(policy) => policy.transaction_deadline_secs,
+        }
+    }
+
+    /// Validates that the transaction deadline, if set, is nonzero
+    pub fn validate_transaction_deadline(&self) -> Result<(), RepositoryError> {
+        match self.transaction_deadline_secs() {
+            Some(0) => Err(RepositoryError::InvalidData(
+                "transaction_deadline_secs must be greater than 0".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the cap on in-flight transactions, if configured
+    pub fn max_inflight_transactions(&self) -> Option<u32> {
+        match self {
+            Self::Evm(policy) => policy.max_inflight_transactions,
+            Self::Solana(policy) => policy.max_inflight_transactions,
+            Self::Stellar(policy) => policy.max_inflight_transactions,
+        }
+    }
+
+    /// Validates that the in-flight transaction cap, if set, is nonzero
+    pub fn validate_max_inflight_transactions(&self) -> Result<(), RepositoryError> {
+        match self.max_inflight_transactions() {
+            Some(0) => Err(RepositoryError::InvalidData(
+                "max_inflight_transactions must be greater than 0".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the rolling daily spend cap, if configured
+    pub fn max_daily_spend(&self) -> Option<u128> {
+        match self {
+            Self::Evm(policy) => policy.max_daily_spend,
+            Self::Solana(policy) => policy.max_daily_spend,
+            Self::Stellar(policy) => policy.max_daily_spend,
+        }
+    }
+
+    /// Validates that an EVM gas estimation multiplier, if set, is within
+    /// [`MIN_EVM_GAS_ESTIMATION_MULTIPLIER`]..=[`MAX_EVM_GAS_ESTIMATION_MULTIPLIER`]
+    /// and that a gas limit cap, if set, is nonzero
+    pub fn validate_gas_estimation(&self) -> Result<(), RepositoryError> {
+        let Self::Evm(policy) = self else {
+            return Ok(());
+        };
+        if let Some(multiplier) = policy.gas_estimation_multiplier {
+            if !(MIN_EVM_GAS_ESTIMATION_MULTIPLIER..=MAX_EVM_GAS_ESTIMATION_MULTIPLIER)
+                .contains(&multiplier)
+            {
+                return Err(RepositoryError::InvalidData(format!(
+                    "gas_estimation_multiplier must be between {MIN_EVM_GAS_ESTIMATION_MULTIPLIER} and {MAX_EVM_GAS_ESTIMATION_MULTIPLIER}, got {multiplier}"
+                )));
+            }
+        }
+        if policy.gas_limit_cap == Some(0) {
+            return Err(RepositoryError::InvalidData(
+                "gas_limit_cap must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates that the daily spend cap, if set, is nonzero
+    pub fn validate_max_daily_spend(&self) -> Result<(), RepositoryError> {
+        match self.max_daily_spend() {
+            Some(0) => Err(RepositoryError::InvalidData(
+                "max_daily_spend must be greater than 0".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the EVM transaction types the relayer may emit, if restricted
+    pub fn allowed_tx_types(&self) -> Option<&[EvmTxType]> {
+        match self {
+            Self::Evm(policy) => policy.allowed_tx_types.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Validates that an EVM transaction type restriction, if set, is not empty and
+    /// does not restrict the relayer to EIP-1559 transactions with legacy pricing
+    pub fn validate_allowed_tx_types(&self) -> Result<(), RepositoryError> {
+        let Self::Evm(policy) = self else {
+            return Ok(());
+        };
+        match policy.allowed_tx_types.as_deref() {
+            Some([]) => Err(RepositoryError::InvalidData(
+                "allowed_tx_types must not be empty".to_string(),
+            )),
+            Some(allowed)
+                if allowed.iter().all(|t| *t == EvmTxType::Eip1559)
+                    && !policy
+                        .eip1559_pricing
+                        .unwrap_or(DEFAULT_EVM_EIP1559_ENABLED) =>
+            {
+                Err(RepositoryError::InvalidData(
+                    "allowed_tx_types only allows eip1559 but eip1559_pricing is disabled"
+                        .to_string(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the ERC-20 tokens accepted as fee payment, if configured
+    pub fn accepted_fee_tokens(&self) -> Option<&[FeeToken]> {
+        match self {
+            Self::Evm(policy) => policy.accepted_fee_tokens.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Get the EIP-1559 priority fee floor, if configured
+    pub fn min_priority_fee_per_gas(&self) -> Option<u128> {
+        match self {
+            Self::Evm(policy) => policy.min_priority_fee_per_gas,
+            _ => None,
+        }
+    }
+
+    /// Validates that the priority fee floor, if set, does not exceed `gas_price_cap`
+    pub fn validate_min_priority_fee(&self) -> Result<(), RepositoryError> {
+        let Self::Evm(policy) = self else {
+            return Ok(());
+        };
+        match (policy.min_priority_fee_per_gas, policy.gas_price_cap) {
+            (Some(floor), Some(cap)) if floor > cap => Err(RepositoryError::InvalidData(format!(
+                "min_priority_fee_per_gas ({floor}) must not exceed gas_price_cap ({cap})"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates that every accepted fee token has a valid EVM address and a positive,
+    /// finite minimum rate
+    pub fn validate_accepted_fee_tokens(&self) -> Result<(), RepositoryError> {
+        for token in self.accepted_fee_tokens().unwrap_or_default() {
+            if !RelayerNetworkType::Evm.is_valid_address(&token.address) {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Invalid fee token address: {}",
+                    token.address
+                )));
+            }
+            if !(token.min_rate.is_finite() && token.min_rate > 0.0) {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Fee token {} min_rate must be a positive number",
+                    token.address
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the low balance alerting thresholds, if configured
+    pub fn low_balance_alerting(&self) -> Option<&AlertThresholds> {
+        match self {
+            Self::Evm(policy) => policy.low_balance_alerting.as_ref(),
+            Self::Solana(policy) => policy.low_balance_alerting.as_ref(),
+            Self::Stellar(policy) => policy.low_balance_alerting.as_ref(),
+        }
+    }
+
+    /// Fills every setting the relayer leaves unset with the default for its network type
+    pub fn with_network_defaults(&self) -> Self {
+        match self.clone() {
+            Self::Evm(mut policy) => {
+                policy.min_balance.get_or_insert(DEFAULT_EVM_MIN_BALANCE);
+                policy
+                    .gas_limit_estimation
+                    .get_or_insert(DEFAULT_EVM_GAS_LIMIT_ESTIMATION);
+                policy
+                    .gas_price_cap
+                    .get_or_insert(DEFAULT_EVM_GAS_PRICE_CAP);
+                policy
+                    .eip1559_pricing
+                    .get_or_insert(DEFAULT_EVM_EIP1559_ENABLED);
+                Self::Evm(policy)
+            }
+            Self::Solana(mut policy) => {
+                policy.min_balance.get_or_insert(DEFAULT_SOLANA_MIN_BALANCE);
+                policy
+                    .max_tx_data_size
+                    .get_or_insert(DEFAULT_SOLANA_MAX_TX_DATA_SIZE);
+                Self::Solana(policy)
+            }
+            Self::Stellar(mut policy) => {
+                policy
+                    .min_balance
+                    .get_or_insert(DEFAULT_STELLAR_MIN_BALANCE);
+                policy
+                    .concurrent_transactions
+                    .get_or_insert(DEFAULT_STELLAR_CONCURRENT_TRANSACTIONS);
+                Self::Stellar(policy)
+            }
+        }
+    }
+
+    /// Overlays this policy's settings on a `parent` policy.
+    ///
+    /// Settings this policy leaves unset are taken from the parent, so a child relayer
+    /// only needs to store its overrides. A parent of a different network type
+    /// contributes nothing.
+    pub fn overlay(&self, parent: &Self) -> Self {
+        match (self.clone(), parent.clone()) {
+            (Self::Evm(child), Self::Evm(parent)) => Self::Evm(RelayerEvmPolicy {
+                min_balance: child.min_balance.or(parent.min_balance),
+                gas_limit_estimation: child.gas_limit_estimation.or(parent.gas_limit_estimation),
+                gas_price_cap: child.gas_price_cap.or(parent.gas_price_cap),
+                whitelist_receivers: child.whitelist_receivers.or(parent.whitelist_receivers),
+                eip1559_pricing: child.eip1559_pricing.or(parent.eip1559_pricing),
+                private_transactions: child.private_transactions.or(parent.private_transactions),
+                low_balance_alerting: child.low_balance_alerting.or(parent.low_balance_alerting),
+                validation_rules: if child.validation_rules.is_empty() {
+                    parent.validation_rules
+                } else {
+                    child.validation_rules
+                },
+                gas_oracle: if child.gas_oracle.is_provider_default() {
+                    parent.gas_oracle
+                } else {
+                    child.gas_oracle
+                },
+                simulate_before_send: child.simulate_before_send || parent.simulate_before_send,
+                revert_handling: if child.revert_handling.is_reject() {
+                    parent.revert_handling
+                } else {
+                    child.revert_handling
+                },
+                max_calldata_bytes: child.max_calldata_bytes.or(parent.max_calldata_bytes),
+                gas_bump: child.gas_bump.or(parent.gas_bump),
+                transaction_deadline_secs: child
+                    .transaction_deadline_secs
+                    .or(parent.transaction_deadline_secs),
+                max_inflight_transactions: child
+                    .max_inflight_transactions
+                    .or(parent.max_inflight_transactions),
+                allowed_tx_types: child.allowed_tx_types.or(parent.allowed_tx_types),
+                accepted_fee_tokens: child.accepted_fee_tokens.or(parent.accepted_fee_tokens),
+                min_priority_fee_per_gas: child
+                    .min_priority_fee_per_gas
+                    .or(parent.min_priority_fee_per_gas),
+                max_daily_spend: child.max_daily_spend.or(parent.max_daily_spend),
+                stuck_tx_action: if child.stuck_tx_action.is_speed_up() {
+                    parent.stuck_tx_action
+                } else {
+                    child.stuck_tx_action
+                },
+                gas_estimation_multiplier: child
+                    .gas_estimation_multiplier
+                    .or(parent.gas_estimation_multiplier),
+                gas_limit_cap: child.gas_limit_cap.or(parent.gas_limit_cap),
+                max_rpc_batch_size: child.max_rpc_batch_size.or(parent.max_rpc_batch_size),
+            }),
+            (Self::Solana(child), Self::Solana(parent)) => Self::Solana(RelayerSolanaPolicy {
+                allowed_programs: child.allowed_programs.or(parent.allowed_programs),
+                max_signatures: child.max_signatures.or(parent.max_signatures),
+                max_tx_data_size: child.max_tx_data_size.or(parent.max_tx_data_size),
+                min_balance: child.min_balance.or(parent.min_balance),
+                allowed_tokens: child.allowed_tokens.or(parent.allowed_tokens),
+                fee_payment_strategy: child.fee_payment_strategy.or(parent.fee_payment_strategy),
+                fee_margin_percentage: child.fee_margin_percentage.or(parent.fee_margin_percentage),
+                allowed_accounts: child.allowed_accounts.or(parent.allowed_accounts),
+                disallowed_accounts: child.disallowed_accounts.or(parent.disallowed_accounts),
+                max_allowed_fee_lamports: child
+                    .max_allowed_fee_lamports
+                    .or(parent.max_allowed_fee_lamports),
+                swap_config: child.swap_config.or(parent.swap_config),
+                low_balance_alerting: child.low_balance_alerting.or(parent.low_balance_alerting),
+                transaction_deadline_secs: child
+                    .transaction_deadline_secs
+                    .or(parent.transaction_deadline_secs),
+                max_inflight_transactions: child
+                    .max_inflight_transactions
+                    .or(parent.max_inflight_transactions),
+                max_daily_spend: child.max_daily_spend.or(parent.max_daily_spend),
+            }),
+            (Self::Stellar(child), Self::Stellar(parent)) => Self::Stellar(RelayerStellarPolicy {
+                min_balance: child.min_balance.or(parent.min_balance),
+                max_fee: child.max_fee.or(parent.max_fee),
+                timeout_seconds: child.timeout_seconds.or(parent.timeout_seconds),
+                concurrent_transactions: child
+                    .concurrent_transactions
+                    .or(parent.concurrent_transactions),
+                allowed_tokens: child.allowed_tokens.or(parent.allowed_tokens),
+                fee_payment_strategy: child.fee_payment_strategy.or(parent.fee_payment_strategy),
+                slippage_percentage: child.slippage_percentage.or(parent.slippage_percentage),
+                fee_margin_percentage: child.fee_margin_percentage.or(parent.fee_margin_percentage),
+                swap_config: child.swap_config.or(parent.swap_config),
+                low_balance_alerting: child.low_balance_alerting.or(parent.low_balance_alerting),
+                transaction_deadline_secs: child
+                    .transaction_deadline_secs
+                    .or(parent.transaction_deadline_secs),
+                max_inflight_transactions: child
+                    .max_inflight_transactions
+                    .or(parent.max_inflight_transactions),
+                max_daily_spend: child.max_daily_spend.or(parent.max_daily_spend),
+            }),
+            (child, _) => child,
+        }
+    }
+
+    /// Resolves the concrete policy the transaction pipeline operates under.
+    ///
+    /// Layers are applied in increasing precedence:
+    /// 1. network defaults fill settings the relayer leaves unset,
+    /// 2. the relayer's own values override those defaults,
+    /// 3. guardrails clamp the result, so a relayer can never exceed a platform limit.
+    pub fn effective(&self, guardrails: Option<&PolicyGuardrails>) -> Self {
+        let policy = self.with_network_defaults();
+        match guardrails {
+            Some(guardrails) => guardrails.clamp(policy),
+            None => policy,
+        }
+    }
+}
+
+/// Platform-wide safety limits enforced on relayer policies when they are stored.
+///
+/// Guardrails are optional; repositories without them accept any policy.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PolicyGuardrails {
+    /// Reject EVM policies without a `gas_price_cap`
+    pub require_gas_price_cap: bool,
+    /// Reject EVM policies whose `gas_price_cap` exceeds this value
+    pub max_allowed_gas_price_cap: Option<u128>,
+}
+
+impl PolicyGuardrails {
+    /// Checks a policy against the guardrails, returning a `ConstraintViolation` on failure
+    pub fn check(&self, policy: &RelayerNetworkPolicy) -> Result<(), RepositoryError> {
+        let RelayerNetworkPolicy::Evm(evm_policy) = policy else {
+            return Ok(());
+        };
+        match (evm_policy.gas_price_cap, self.max_allowed_gas_price_cap) {
+            (None, _) if self.require_gas_price_cap => Err(RepositoryError::ConstraintViolation(
+                "EVM relayer policy must define a gas_price_cap".to_string(),
+            )),
+            (Some(cap), Some(max)) if cap > max => Err(RepositoryError::ConstraintViolation(
+                format!("gas_price_cap {cap} exceeds the maximum allowed {max}"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Lowers an EVM policy's `gas_price_cap` to the maximum allowed, if it exceeds it.
+    ///
+    /// Unlike [`PolicyGuardrails::check`], which rejects a policy when it is stored,
+    /// this resolves the policy a relayer actually operates under.
+    pub fn clamp(&self, policy: RelayerNetworkPolicy) -> RelayerNetworkPolicy {
+        match (policy, self.max_allowed_gas_price_cap) {
+            (RelayerNetworkPolicy::Evm(mut evm_policy), Some(max)) => {
+                evm_policy.gas_price_cap =
+                    Some(evm_policy.gas_price_cap.map_or(max, |cap| cap.min(max)));
+                RelayerNetworkPolicy::Evm(evm_policy)
+            }
+            (policy, _) => policy,
+        }
+    }
+}
+
+/// Core relayer domain model
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct Relayer {
+    #[validate(
+        length(min = 1, max = 36, message = "ID must be between 1 and 36 characters"),
+        regex(
+            path = "*ID_REGEX",
+            message = "ID must contain only letters, numbers, dashes and underscores"
+        )
+    )]
+    pub id: String,
+
+    #[validate(length(min = 1, message = "Name cannot be empty"))]
+    pub name: String,
+
+    #[validate(length(min = 1, message = "Network cannot be empty"))]
+    pub network: String,
+
+    pub paused: bool,
+    pub network_type: RelayerNetworkType,
+    pub policies: Option<RelayerNetworkPolicy>,
+
+    #[validate(length(min = 1, message = "Signer ID cannot be empty"))]
+    pub signer_id: String,
+
+    pub notification_id: Option<String>,
+    pub custom_rpc_urls: Option<Vec<RpcConfig>>,
+}
+
+impl Relayer {
+    /// Creates a new relayer
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        name: String,
+        network: String,
+        paused: bool,
+        network_type: RelayerNetworkType,
+        policies: Option<RelayerNetworkPolicy>,
+        signer_id: String,
+        notification_id: Option<String>,
+        custom_rpc_urls: Option<Vec<RpcConfig>>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            network,
+            paused,
+            network_type,
+            policies,
+            signer_id,
+            notification_id,
+            custom_rpc_urls,
+        }
+    }
+
+    /// Validates the relayer using both validator crate and custom validation
+    pub fn validate(&self) -> Result<(), RelayerValidationError> {
+        // Check for empty ID specifically first
+        if self.id.is_empty() {
+            return Err(RelayerValidationError::EmptyId);
+        }
+
+        // Check for ID too long
+        if self.id.len() > 36 {
+            return Err(RelayerValidationError::IdTooLong);
+        }
+
+        // First run validator crate validation
+        Validate::validate(self).map_err(|validation_errors| {
+            // Convert validator errors to our custom error type
+            for (field, errors) in validation_errors.field_errors() {
+                if let Some(error) = errors.first() {
+                    let field_str = field.as_ref();
+                    return match (field_str, error.code.as_ref()) {
+                        ("id", "regex") => RelayerValidationError::InvalidIdFormat,
+                        ("name", "length") => RelayerValidationError::EmptyName,
+                        ("network", "length") => RelayerValidationError::EmptyNetwork,
+                        ("signer_id", "length") => RelayerValidationError::InvalidPolicy(
+                            "Signer ID cannot be empty".to_string(),
+                        ),
+                        _ => RelayerValidationError::InvalidIdFormat, // fallback
+                    };
+                }
+            }
+            // Fallback error
+            RelayerValidationError::InvalidIdFormat
+        })?;
+
+        // Run custom validation
         self.validate_policies()?;
         self.validate_custom_rpc_urls()?;
 
@@ -1148,6 +2131,10 @@ impl Relayer {
                 if config.weight > 100 {
                     return Err(RelayerValidationError::InvalidRpcWeight);
                 }
+
+                config
+                    .validate_headers()
+                    .map_err(|e| RelayerValidationError::InvalidRpcHeader(e.to_string()))?;
             }
         }
         Ok(())
@@ -1205,10 +2192,30 @@ pub enum RelayerValidationError {
     InvalidRpcUrl(String),
     #[error("RPC URL weight must be in range 0-100")]
     InvalidRpcWeight,
+    #[error("Invalid RPC header: {0}")]
+    InvalidRpcHeader(String),
     #[error("Invalid field: {0}")]
     InvalidField(String),
 }
 
+impl RelayerValidationError {
+    /// Stable name of the failure category, independent of the message details
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::EmptyId => "empty_id",
+            Self::InvalidIdFormat => "invalid_id_format",
+            Self::IdTooLong => "id_too_long",
+            Self::EmptyName => "empty_name",
+            Self::EmptyNetwork => "empty_network",
+            Self::InvalidPolicy(_) => "invalid_policy",
+            Self::InvalidRpcUrl(_) => "invalid_rpc_url",
+            Self::InvalidRpcWeight => "invalid_rpc_weight",
+            Self::InvalidRpcHeader(_) => "invalid_rpc_header",
+            Self::InvalidField(_) => "invalid_field",
+        }
+    }
+}
+
 /// Centralized conversion from RelayerValidationError to ApiError
 impl From<RelayerValidationError> for crate::models::ApiError {
     fn from(error: RelayerValidationError) -> Self {
@@ -1233,6 +2240,9 @@ impl From<RelayerValidationError> for crate::models::ApiError {
             RelayerValidationError::InvalidRpcWeight => {
                 "RPC URL weight must be in range 0-100".to_string()
             }
+            RelayerValidationError::InvalidRpcHeader(msg) => {
+                format!("Invalid RPC header: {msg}")
+            }
             RelayerValidationError::InvalidField(msg) => msg.clone(),
         })
     }
@@ -1572,6 +2582,28 @@ mod tests {
         assert_eq!(RelayerNetworkType::Stellar.to_string(), "stellar");
     }
 
+    #[test]
+    fn test_relayer_network_type_is_valid_address() {
+        let evm = RelayerNetworkType::Evm;
+        assert!(evm.is_valid_address("0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E"));
+        assert!(!evm.is_valid_address("742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E"));
+        assert!(!evm.is_valid_address("0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba"));
+        assert!(!evm.is_valid_address("0xZZ2d35Cc6634C0532925a3b8D8C2e48a73F6ba2E"));
+
+        let solana = RelayerNetworkType::Solana;
+        assert!(solana.is_valid_address("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"));
+        assert!(!solana.is_valid_address("0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E"));
+
+        let stellar = RelayerNetworkType::Stellar;
+        assert!(
+            stellar.is_valid_address("GCEZWKCA5VLDNRLN3RPRJMRZOX3Z6G5CHCGSNFHEYVXM3XOJMDS674JZ")
+        );
+        assert!(
+            !stellar.is_valid_address("GCEZWKCA5VLDNRLN3RPRJMRZOX3Z6G5CHCGSNFHEYVXM3XOJMDS674JA")
+        );
+        assert!(!stellar.is_valid_address("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"));
+    }
+
     #[test]
     fn test_relayer_network_type_from_config_file_type() {
         assert_eq!(
@@ -1629,6 +2661,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_relayer_network_type_from_str_round_trip() {
+        for network_type in [
+            RelayerNetworkType::Evm,
+            RelayerNetworkType::Solana,
+            RelayerNetworkType::Stellar,
+        ] {
+            let parsed = RelayerNetworkType::from_str(&network_type.to_string()).unwrap();
+            assert_eq!(parsed, network_type);
+        }
+    }
+
+    #[test]
+    fn test_relayer_network_type_from_str_invalid() {
+        let err = RelayerNetworkType::from_str("evmm").unwrap_err();
+        assert_eq!(err, ParseNetworkTypeError("evmm".to_string()));
+        assert_eq!(
+            err.to_string(),
+            "Invalid network type 'evmm', expected one of: evm, solana, stellar"
+        );
+    }
+
+    #[test]
+    fn test_transaction_rule_serialization() {
+        let rules = vec![
+            TransactionRule::MaxValue(1_000_000_000_000_000_000),
+            TransactionRule::AllowedSelectors(vec![[0xa9, 0x05, 0x9c, 0xbb]]),
+            TransactionRule::DenySelectors(vec![[0x09, 0x5e, 0xa7, 0xb3]]),
+        ];
+
+        let serialized = serde_json::to_value(&rules).unwrap();
+        assert_eq!(
+            serialized[0],
+            serde_json::json!({"type": "MaxValue", "value": "1000000000000000000"})
+        );
+
+        let deserialized: Vec<TransactionRule> = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, rules);
+    }
+
     // ===== Policy Struct Tests =====
 
     #[test]
@@ -1651,6 +2723,23 @@ mod tests {
             whitelist_receivers: Some(vec!["0x123".to_string(), "0x456".to_string()]),
             eip1559_pricing: Some(false),
             private_transactions: Some(true),
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
         };
 
         let serialized = serde_json::to_string(&policy).unwrap();
@@ -1825,189 +2914,920 @@ mod tests {
             ..RelayerSolanaPolicy::default()
         };
 
-        assert_eq!(policy.get_allowed_token_decimals("mint1"), Some(9));
-        assert_eq!(policy.get_allowed_token_decimals("mint2"), None);
-        assert_eq!(policy.get_allowed_token_decimals("mint3"), None);
+        assert_eq!(policy.get_allowed_token_decimals("mint1"), Some(9));
+        assert_eq!(policy.get_allowed_token_decimals("mint2"), None);
+        assert_eq!(policy.get_allowed_token_decimals("mint3"), None);
+    }
+
+    #[test]
+    fn test_relayer_stellar_policy_default() {
+        let policy = RelayerStellarPolicy::default();
+        assert_eq!(policy.min_balance, None);
+        assert_eq!(policy.max_fee, None);
+        assert_eq!(policy.timeout_seconds, None);
+        assert_eq!(policy.concurrent_transactions, None);
+        assert_eq!(policy.allowed_tokens, None);
+        assert_eq!(policy.fee_payment_strategy, None);
+        assert_eq!(policy.slippage_percentage, None);
+        assert_eq!(policy.fee_margin_percentage, None);
+        assert_eq!(policy.swap_config, None);
+    }
+
+    #[test]
+    fn test_stellar_allowed_tokens_policy_new() {
+        let metadata = StellarTokenMetadata {
+            kind: StellarTokenKind::Native,
+            decimals: 7,
+            canonical_asset_id: "native".to_string(),
+        };
+
+        let swap_config = StellarAllowedTokensSwapConfig {
+            slippage_percentage: Some(0.5),
+            min_amount: Some(1000),
+            max_amount: Some(10000000),
+            retain_min_amount: Some(500),
+        };
+
+        let token = StellarAllowedTokensPolicy::new(
+            "native".to_string(),
+            Some(metadata.clone()),
+            Some(100000),
+            Some(swap_config.clone()),
+        );
+
+        assert_eq!(token.asset, "native");
+        assert_eq!(token.metadata, Some(metadata));
+        assert_eq!(token.max_allowed_fee, Some(100000));
+        assert_eq!(token.swap_config, Some(swap_config));
+    }
+
+    #[test]
+    fn test_relayer_stellar_policy_get_allowed_tokens() {
+        let token1 = StellarAllowedTokensPolicy::new("native".to_string(), None, Some(1000), None);
+        let token2 = StellarAllowedTokensPolicy::new(
+            "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
+            None,
+            Some(2000),
+            None,
+        );
+
+        let policy = RelayerStellarPolicy {
+            allowed_tokens: Some(vec![token1.clone(), token2.clone()]),
+            ..RelayerStellarPolicy::default()
+        };
+
+        let tokens = policy.get_allowed_tokens();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0], token1);
+        assert_eq!(tokens[1], token2);
+
+        // Test empty case
+        let empty_policy = RelayerStellarPolicy::default();
+        let empty_tokens = empty_policy.get_allowed_tokens();
+        assert_eq!(empty_tokens.len(), 0);
+    }
+
+    #[test]
+    fn test_relayer_stellar_policy_get_allowed_token_entry() {
+        let token1 = StellarAllowedTokensPolicy::new("native".to_string(), None, Some(1000), None);
+        let token2 = StellarAllowedTokensPolicy::new(
+            "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
+            None,
+            Some(2000),
+            None,
+        );
+
+        let policy = RelayerStellarPolicy {
+            allowed_tokens: Some(vec![token1.clone(), token2.clone()]),
+            ..RelayerStellarPolicy::default()
+        };
+
+        let found_token = policy.get_allowed_token_entry("native").unwrap();
+        assert_eq!(found_token, token1);
+
+        let not_found = policy.get_allowed_token_entry(
+            "EURC:GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2",
+        );
+        assert!(not_found.is_none());
+
+        // Test empty case
+        let empty_policy = RelayerStellarPolicy::default();
+        let empty_result = empty_policy.get_allowed_token_entry("native");
+        assert!(empty_result.is_none());
+    }
+
+    #[test]
+    fn test_relayer_stellar_policy_get_allowed_token_decimals() {
+        let metadata1 = StellarTokenMetadata {
+            kind: StellarTokenKind::Native,
+            decimals: 7,
+            canonical_asset_id: "native".to_string(),
+        };
+
+        let metadata2 = StellarTokenMetadata {
+            kind: StellarTokenKind::Classic {
+                code: "USDC".to_string(),
+                issuer: "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
+            },
+            decimals: 6,
+            canonical_asset_id: "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"
+                .to_string(),
+        };
+
+        let token1 = StellarAllowedTokensPolicy::new(
+            "native".to_string(),
+            Some(metadata1),
+            Some(1000),
+            None,
+        );
+        let token2 = StellarAllowedTokensPolicy::new(
+            "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
+            Some(metadata2),
+            Some(2000),
+            None,
+        );
+        let token3 = StellarAllowedTokensPolicy::new(
+            "EURC:GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2".to_string(),
+            None,
+            Some(3000),
+            None,
+        );
+
+        let policy = RelayerStellarPolicy {
+            allowed_tokens: Some(vec![token1, token2, token3]),
+            ..RelayerStellarPolicy::default()
+        };
+
+        assert_eq!(policy.get_allowed_token_decimals("native"), Some(7));
+        assert_eq!(
+            policy.get_allowed_token_decimals(
+                "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"
+            ),
+            Some(6)
+        );
+        assert_eq!(
+            policy.get_allowed_token_decimals(
+                "EURC:GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2"
+            ),
+            None
+        );
+        assert_eq!(policy.get_allowed_token_decimals("unknown"), None);
+    }
+
+    #[test]
+    fn test_relayer_stellar_policy_get_swap_config() {
+        let swap_config = RelayerStellarSwapConfig {
+            strategies: vec![StellarSwapStrategy::OrderBook],
+            cron_schedule: Some("0 0 * * *".to_string()),
+            min_balance_threshold: Some(1000000),
+        };
+
+        let policy = RelayerStellarPolicy {
+            swap_config: Some(swap_config.clone()),
+            ..RelayerStellarPolicy::default()
+        };
+
+        let retrieved_config = policy.get_swap_config().unwrap();
+        assert_eq!(retrieved_config, swap_config);
+
+        // Test None case
+        let empty_policy = RelayerStellarPolicy::default();
+        assert!(empty_policy.get_swap_config().is_none());
+    }
+
+    // ===== GasOracleConfig Tests =====
+
+    #[test]
+    fn test_gas_oracle_config_serde_roundtrip() {
+        let cases = [
+            (
+                GasOracleConfig::ProviderDefault,
+                r#"{"type":"provider_default"}"#,
+            ),
+            (
+                GasOracleConfig::Custom {
+                    url: "https://oracle.example.com/gas".to_string(),
+                },
+                r#"{"type":"custom","url":"https://oracle.example.com/gas"}"#,
+            ),
+            (
+                GasOracleConfig::Fixed {
+                    price: 30_000_000_000,
+                },
+                r#"{"type":"fixed","price":"30000000000"}"#,
+            ),
+        ];
+        for (config, json) in cases {
+            assert_eq!(serde_json::to_string(&config).unwrap(), json);
+            let deserialized: GasOracleConfig = serde_json::from_str(json).unwrap();
+            assert_eq!(deserialized, config);
+        }
+    }
+
+    #[test]
+    fn test_evm_policy_gas_oracle_defaults_to_provider() {
+        let policy: RelayerEvmPolicy = serde_json::from_str("{}").unwrap();
+        assert_eq!(policy.gas_oracle, GasOracleConfig::ProviderDefault);
+        // The default oracle is omitted when serializing
+        assert!(!serde_json::to_string(&policy)
+            .unwrap()
+            .contains("gas_oracle"));
+
+        let network_policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_oracle: GasOracleConfig::Fixed { price: 1 },
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(
+            network_policy.gas_oracle(),
+            Some(&GasOracleConfig::Fixed { price: 1 })
+        );
+        assert!(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default())
+            .gas_oracle()
+            .is_none());
+    }
+
+    #[test]
+    fn test_gas_oracle_config_validate_custom_url() {
+        assert!(GasOracleConfig::ProviderDefault.validate().is_ok());
+        assert!(GasOracleConfig::Fixed { price: 1 }.validate().is_ok());
+        assert!(GasOracleConfig::Custom {
+            url: "https://oracle.example.com/gas".to_string()
+        }
+        .validate()
+        .is_ok());
+        assert!(matches!(
+            GasOracleConfig::Custom {
+                url: "not a url".to_string()
+            }
+            .validate(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(matches!(
+            GasOracleConfig::Custom {
+                url: "ftp://oracle.example.com/gas".to_string()
+            }
+            .validate(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    // ===== Simulation Policy Tests =====
+
+    #[test]
+    fn test_evm_policy_simulation_defaults() {
+        let policy: RelayerEvmPolicy = serde_json::from_str("{}").unwrap();
+        assert!(!policy.simulate_before_send);
+        assert_eq!(policy.revert_handling, RevertHandling::Reject);
+        // Defaults are omitted when serializing
+        let serialized = serde_json::to_string(&policy).unwrap();
+        assert!(!serialized.contains("simulate_before_send"));
+        assert!(!serialized.contains("revert_handling"));
+    }
+
+    #[test]
+    fn test_evm_policy_simulation_serde_round_trip() {
+        let policy = RelayerEvmPolicy {
+            simulate_before_send: true,
+            revert_handling: RevertHandling::SubmitAnyway,
+            ..RelayerEvmPolicy::default()
+        };
+
+        let serialized = serde_json::to_value(&policy).unwrap();
+        assert_eq!(serialized["simulate_before_send"], json!(true));
+        assert_eq!(serialized["revert_handling"], json!("submit_anyway"));
+
+        let deserialized: RelayerEvmPolicy = serde_json::from_value(serialized).unwrap();
+        assert_eq!(policy, deserialized);
+    }
+
+    #[test]
+    fn test_revert_handling_deserialization() {
+        let reject: RevertHandling = serde_json::from_str("\"reject\"").unwrap();
+        assert_eq!(reject, RevertHandling::Reject);
+        assert!(reject.is_reject());
+
+        let submit: RevertHandling = serde_json::from_str("\"submit_anyway\"").unwrap();
+        assert_eq!(submit, RevertHandling::SubmitAnyway);
+        assert!(!submit.is_reject());
+
+        assert!(serde_json::from_str::<RevertHandling>("\"ignore\"").is_err());
+    }
+
+    #[test]
+    fn test_network_policy_simulation_accessors() {
+        let network_policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            simulate_before_send: true,
+            revert_handling: RevertHandling::SubmitAnyway,
+            ..RelayerEvmPolicy::default()
+        });
+        assert!(network_policy.simulate_before_send());
+        assert_eq!(
+            network_policy.revert_handling(),
+            Some(RevertHandling::SubmitAnyway)
+        );
+
+        let default_evm = RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default());
+        assert!(!default_evm.simulate_before_send());
+        assert_eq!(default_evm.revert_handling(), Some(RevertHandling::Reject));
+
+        let solana = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default());
+        assert!(!solana.simulate_before_send());
+        assert!(solana.revert_handling().is_none());
+    }
+
+    #[test]
+    fn test_validate_calldata_limit() {
+        let with_limit = |limit| {
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_calldata_bytes: limit,
+                ..RelayerEvmPolicy::default()
+            })
+        };
+        assert!(with_limit(None).validate_calldata_limit().is_ok());
+        assert!(with_limit(Some(MIN_EVM_MAX_CALLDATA_BYTES))
+            .validate_calldata_limit()
+            .is_ok());
+        assert!(matches!(
+            with_limit(Some(MIN_EVM_MAX_CALLDATA_BYTES - 1)).validate_calldata_limit(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default())
+            .validate_calldata_limit()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_evm_policy_max_calldata_bytes_serialization() {
+        let policy: RelayerEvmPolicy =
+            serde_json::from_value(json!({ "max_calldata_bytes": 4096 })).unwrap();
+        assert_eq!(policy.max_calldata_bytes, Some(4096));
+        assert_eq!(
+            serde_json::to_value(&policy).unwrap()["max_calldata_bytes"],
+            json!(4096)
+        );
+
+        let unset = RelayerEvmPolicy::default();
+        assert!(serde_json::to_value(&unset)
+            .unwrap()
+            .get("max_calldata_bytes")
+            .is_none());
+    }
+
+    // ===== GasBumpPolicy Tests =====
+
+    #[test]
+    fn test_gas_bump_policy_serde_round_trip() {
+        let json = json!({
+            "gas_bump": {
+                "bump_percent": 12,
+                "max_bumps": 3,
+                "bump_interval_secs": 60
+            }
+        });
+        let policy: RelayerEvmPolicy = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            policy.gas_bump,
+            Some(GasBumpPolicy {
+                bump_percent: 12,
+                max_bumps: 3,
+                bump_interval_secs: 60,
+            })
+        );
+        assert_eq!(serde_json::to_value(&policy).unwrap(), json);
+
+        // Unset gas bump policies are omitted
+        assert!(serde_json::to_value(RelayerEvmPolicy::default())
+            .unwrap()
+            .get("gas_bump")
+            .is_none());
+        // Unknown fields are rejected
+        assert!(serde_json::from_value::<GasBumpPolicy>(json!({
+            "bump_percent": 12,
+            "max_bumps": 3,
+            "bump_interval_secs": 60,
+            "bump_cap": 5
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_gas_bump_policy_validate() {
+        let valid = GasBumpPolicy {
+            bump_percent: MIN_EVM_GAS_BUMP_PERCENT,
+            max_bumps: 1,
+            bump_interval_secs: 30,
+        };
+        assert!(valid.validate().is_ok());
+
+        // Sub-10% bumps would be rejected as replacements by the network
+        let sub_minimum = GasBumpPolicy {
+            bump_percent: 9,
+            ..valid
+        };
+        assert!(matches!(
+            sub_minimum.validate(),
+            Err(RepositoryError::InvalidData(msg)) if msg.contains("percent")
+        ));
+
+        let no_bumps = GasBumpPolicy {
+            max_bumps: 0,
+            ..valid
+        };
+        assert!(matches!(
+            no_bumps.validate(),
+            Err(RepositoryError::InvalidData(msg)) if msg.contains("max_bumps")
+        ));
+    }
+
+    #[test]
+    fn test_transaction_deadline_serde_across_network_types() {
+        let json = json!({ "transaction_deadline_secs": 300 });
+
+        let evm: RelayerEvmPolicy = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(evm.transaction_deadline_secs, Some(300));
+        assert_eq!(serde_json::to_value(&evm).unwrap(), json);
+
+        let solana: RelayerSolanaPolicy = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(solana.transaction_deadline_secs, Some(300));
+        assert_eq!(serde_json::to_value(&solana).unwrap(), json);
+
+        let stellar: RelayerStellarPolicy = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(stellar.transaction_deadline_secs, Some(300));
+        assert_eq!(serde_json::to_value(&stellar).unwrap(), json);
+
+        // Unset deadlines are omitted
+        assert!(serde_json::to_value(RelayerStellarPolicy::default())
+            .unwrap()
+            .get("transaction_deadline_secs")
+            .is_none());
+    }
+
+    #[test]
+    fn test_validate_transaction_deadline() {
+        let policy = |deadline| {
+            RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+                transaction_deadline_secs: deadline,
+                ..RelayerSolanaPolicy::default()
+            })
+        };
+        assert!(policy(None).validate_transaction_deadline().is_ok());
+        assert!(policy(Some(1)).validate_transaction_deadline().is_ok());
+        assert!(matches!(
+            policy(Some(0)).validate_transaction_deadline(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn test_max_inflight_transactions_accessor_and_validation() {
+        let policy = RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+            max_inflight_transactions: Some(4),
+            ..RelayerStellarPolicy::default()
+        });
+        assert_eq!(policy.max_inflight_transactions(), Some(4));
+        assert!(policy.validate_max_inflight_transactions().is_ok());
+
+        let zero = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            max_inflight_transactions: Some(0),
+            ..RelayerEvmPolicy::default()
+        });
+        assert!(zero.validate_max_inflight_transactions().is_err());
+        assert!(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default())
+            .max_inflight_transactions()
+            .is_none());
+    }
+
+    #[test]
+    fn test_is_tx_type_allowed() {
+        let unrestricted = RelayerEvmPolicy::default();
+        assert!(unrestricted.is_tx_type_allowed(EvmTxType::Legacy));
+        assert!(unrestricted.is_tx_type_allowed(EvmTxType::Eip4844));
+
+        let restricted = RelayerEvmPolicy {
+            allowed_tx_types: Some(vec![EvmTxType::Legacy, EvmTxType::Eip1559]),
+            ..RelayerEvmPolicy::default()
+        };
+        assert!(restricted.is_tx_type_allowed(EvmTxType::Legacy));
+        assert!(restricted.is_tx_type_allowed(EvmTxType::Eip1559));
+        assert!(!restricted.is_tx_type_allowed(EvmTxType::Eip4844));
+
+        let policy = RelayerNetworkPolicy::Evm(restricted);
+        assert_eq!(
+            policy.allowed_tx_types(),
+            Some(&[EvmTxType::Legacy, EvmTxType::Eip1559][..])
+        );
+        assert!(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default())
+            .allowed_tx_types()
+            .is_none());
+    }
+
+    #[test]
+    fn test_validate_allowed_tx_types() {
+        let policy = |allowed: Option<Vec<EvmTxType>>, eip1559_pricing: Option<bool>| {
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                allowed_tx_types: allowed,
+                eip1559_pricing,
+                ..RelayerEvmPolicy::default()
+            })
+        };
+        assert!(policy(None, Some(false))
+            .validate_allowed_tx_types()
+            .is_ok());
+        assert!(policy(Some(vec![EvmTxType::Legacy]), Some(false))
+            .validate_allowed_tx_types()
+            .is_ok());
+        assert!(policy(Some(vec![EvmTxType::Eip1559]), Some(true))
+            .validate_allowed_tx_types()
+            .is_ok());
+        assert!(policy(Some(vec![EvmTxType::Eip1559]), None)
+            .validate_allowed_tx_types()
+            .is_ok());
+        assert!(policy(
+            Some(vec![EvmTxType::Legacy, EvmTxType::Eip1559]),
+            Some(false)
+        )
+        .validate_allowed_tx_types()
+        .is_ok());
+
+        assert!(matches!(
+            policy(Some(vec![]), None).validate_allowed_tx_types(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(matches!(
+            policy(Some(vec![EvmTxType::Eip1559]), Some(false)).validate_allowed_tx_types(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    fn fee_token_policy(tokens: Vec<FeeToken>) -> RelayerNetworkPolicy {
+        RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            accepted_fee_tokens: Some(tokens),
+            ..RelayerEvmPolicy::default()
+        })
+    }
+
+    #[test]
+    fn test_validate_accepted_fee_tokens_address() {
+        let valid = FeeToken {
+            address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+            min_rate: 1.5,
+        };
+        assert!(fee_token_policy(vec![valid.clone()])
+            .validate_accepted_fee_tokens()
+            .is_ok());
+        assert!(RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())
+            .validate_accepted_fee_tokens()
+            .is_ok());
+
+        for address in ["", "0x1234", "A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48xx"] {
+            let invalid = FeeToken {
+                address: address.to_string(),
+                ..valid.clone()
+            };
+            assert!(matches!(
+                fee_token_policy(vec![valid.clone(), invalid]).validate_accepted_fee_tokens(),
+                Err(RepositoryError::InvalidData(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_accepted_fee_tokens_rate() {
+        for min_rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let token = FeeToken {
+                address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+                min_rate,
+            };
+            assert!(matches!(
+                fee_token_policy(vec![token]).validate_accepted_fee_tokens(),
+                Err(RepositoryError::InvalidData(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_normalize_address() {
+        assert_eq!(
+            RelayerNetworkType::Evm.normalize_address("0xAbCdEf0000000000000000000000000000000001"),
+            "0xabcdef0000000000000000000000000000000001"
+        );
+        let solana = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+        assert_eq!(RelayerNetworkType::Solana.normalize_address(solana), solana);
+    }
+
+    #[test]
+    fn test_is_fee_token_accepted() {
+        let policy = RelayerEvmPolicy {
+            accepted_fee_tokens: Some(vec![FeeToken {
+                address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+                min_rate: 1.0,
+            }]),
+            ..RelayerEvmPolicy::default()
+        };
+        assert!(policy.is_fee_token_accepted("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+        assert!(!policy.is_fee_token_accepted("0xdAC17F958D2ee523a2206206994597C13D831ec7"));
+        assert!(!RelayerEvmPolicy::default()
+            .is_fee_token_accepted("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"));
+        assert_eq!(
+            RelayerNetworkPolicy::Evm(policy)
+                .accepted_fee_tokens()
+                .map(<[FeeToken]>::len),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_allowed_tx_types_serialization() {
+        let policy = RelayerEvmPolicy {
+            allowed_tx_types: Some(vec![EvmTxType::Legacy, EvmTxType::Eip4844]),
+            ..RelayerEvmPolicy::default()
+        };
+        let json = serde_json::to_value(&policy).unwrap();
+        assert_eq!(
+            json["allowed_tx_types"],
+            serde_json::json!(["legacy", "eip4844"])
+        );
+        let back: RelayerEvmPolicy = serde_json::from_value(json).unwrap();
+        assert_eq!(back, policy);
+
+        let unset = serde_json::to_value(RelayerEvmPolicy::default()).unwrap();
+        assert!(unset.get("allowed_tx_types").is_none());
+    }
+
+    #[test]
+    fn test_min_priority_fee_per_gas_accessor() {
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_priority_fee_per_gas: Some(1_000_000_000),
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(policy.min_priority_fee_per_gas(), Some(1_000_000_000));
+        assert_eq!(
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()).min_priority_fee_per_gas(),
+            None
+        );
+        assert_eq!(
+            RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()).min_priority_fee_per_gas(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_min_priority_fee() {
+        let policy = |floor, cap| {
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                min_priority_fee_per_gas: floor,
+                gas_price_cap: cap,
+                ..RelayerEvmPolicy::default()
+            })
+        };
+        assert!(policy(Some(100), Some(100))
+            .validate_min_priority_fee()
+            .is_ok());
+        assert!(policy(Some(100), None).validate_min_priority_fee().is_ok());
+        assert!(policy(None, Some(100)).validate_min_priority_fee().is_ok());
+        assert!(matches!(
+            policy(Some(101), Some(100)).validate_min_priority_fee(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn test_min_priority_fee_per_gas_serialization() {
+        let policy = RelayerEvmPolicy {
+            min_priority_fee_per_gas: Some(1_500_000_000),
+            ..RelayerEvmPolicy::default()
+        };
+        let json = serde_json::to_value(&policy).unwrap();
+        assert!(json.get("min_priority_fee_per_gas").is_some());
+        let back: RelayerEvmPolicy = serde_json::from_value(json).unwrap();
+        assert_eq!(back, policy);
+
+        let unset = serde_json::to_value(RelayerEvmPolicy::default()).unwrap();
+        assert!(unset.get("min_priority_fee_per_gas").is_none());
+    }
+
+    #[test]
+    fn test_network_policy_gas_bump_accessor() {
+        let gas_bump = GasBumpPolicy {
+            bump_percent: 20,
+            max_bumps: 5,
+            bump_interval_secs: 120,
+        };
+        let network_policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_bump: Some(gas_bump),
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(network_policy.gas_bump(), Some(&gas_bump));
+        assert!(network_policy.validate_gas_bump().is_ok());
+
+        let invalid = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_bump: Some(GasBumpPolicy {
+                bump_percent: 5,
+                ..gas_bump
+            }),
+            ..RelayerEvmPolicy::default()
+        });
+        assert!(invalid.validate_gas_bump().is_err());
+
+        assert!(RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())
+            .gas_bump()
+            .is_none());
+        assert!(
+            RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default())
+                .gas_bump()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_overlay_inherits_simulation_settings() {
+        let parent = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            simulate_before_send: true,
+            revert_handling: RevertHandling::SubmitAnyway,
+            ..RelayerEvmPolicy::default()
+        });
+        let child = RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default());
+
+        let merged = child.overlay(&parent);
+        assert!(merged.simulate_before_send());
+        assert_eq!(merged.revert_handling(), Some(RevertHandling::SubmitAnyway));
+    }
+
+    // ===== PolicyGuardrails Tests =====
+
+    #[test]
+    fn test_policy_guardrails_only_apply_to_evm() {
+        let guardrails = PolicyGuardrails {
+            require_gas_price_cap: true,
+            max_allowed_gas_price_cap: Some(1),
+        };
+        assert!(guardrails
+            .check(&RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()))
+            .is_ok());
+        assert!(guardrails
+            .check(&RelayerNetworkPolicy::Stellar(
+                RelayerStellarPolicy::default()
+            ))
+            .is_ok());
+        assert!(matches!(
+            guardrails.check(&RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())),
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
     }
 
     #[test]
-    fn test_relayer_stellar_policy_default() {
-        let policy = RelayerStellarPolicy::default();
-        assert_eq!(policy.min_balance, None);
-        assert_eq!(policy.max_fee, None);
-        assert_eq!(policy.timeout_seconds, None);
-        assert_eq!(policy.concurrent_transactions, None);
-        assert_eq!(policy.allowed_tokens, None);
-        assert_eq!(policy.fee_payment_strategy, None);
-        assert_eq!(policy.slippage_percentage, None);
-        assert_eq!(policy.fee_margin_percentage, None);
-        assert_eq!(policy.swap_config, None);
+    fn test_policy_guardrails_default_accepts_uncapped_policy() {
+        assert!(PolicyGuardrails::default()
+            .check(&RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()))
+            .is_ok());
     }
 
     #[test]
-    fn test_stellar_allowed_tokens_policy_new() {
-        let metadata = StellarTokenMetadata {
-            kind: StellarTokenKind::Native,
-            decimals: 7,
-            canonical_asset_id: "native".to_string(),
+    fn test_policy_guardrails_clamp_lowers_gas_price_cap() {
+        let guardrails = PolicyGuardrails {
+            require_gas_price_cap: false,
+            max_allowed_gas_price_cap: Some(100),
         };
-
-        let swap_config = StellarAllowedTokensSwapConfig {
-            slippage_percentage: Some(0.5),
-            min_amount: Some(1000),
-            max_amount: Some(10000000),
-            retain_min_amount: Some(500),
+        let capped = |cap| {
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_price_cap: cap,
+                ..RelayerEvmPolicy::default()
+            })
         };
 
-        let token = StellarAllowedTokensPolicy::new(
-            "native".to_string(),
-            Some(metadata.clone()),
-            Some(100000),
-            Some(swap_config.clone()),
-        );
-
-        assert_eq!(token.asset, "native");
-        assert_eq!(token.metadata, Some(metadata));
-        assert_eq!(token.max_allowed_fee, Some(100000));
-        assert_eq!(token.swap_config, Some(swap_config));
+        assert_eq!(guardrails.clamp(capped(Some(500))), capped(Some(100)));
+        assert_eq!(guardrails.clamp(capped(Some(50))), capped(Some(50)));
+        assert_eq!(guardrails.clamp(capped(None)), capped(Some(100)));
+        let solana = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default());
+        assert_eq!(guardrails.clamp(solana.clone()), solana);
     }
 
-    #[test]
-    fn test_relayer_stellar_policy_get_allowed_tokens() {
-        let token1 = StellarAllowedTokensPolicy::new("native".to_string(), None, Some(1000), None);
-        let token2 = StellarAllowedTokensPolicy::new(
-            "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
-            None,
-            Some(2000),
-            None,
-        );
+    // ===== RelayerNetworkPolicy Tests =====
 
-        let policy = RelayerStellarPolicy {
-            allowed_tokens: Some(vec![token1.clone(), token2.clone()]),
-            ..RelayerStellarPolicy::default()
-        };
+    #[test]
+    fn test_relayer_network_policy_serializes_with_type_tag() {
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(100),
+            ..Default::default()
+        });
 
-        let tokens = policy.get_allowed_tokens();
-        assert_eq!(tokens.len(), 2);
-        assert_eq!(tokens[0], token1);
-        assert_eq!(tokens[1], token2);
+        let value = serde_json::to_value(&policy).unwrap();
+        assert_eq!(value, json!({ "type": "evm", "gas_price_cap": "100" }));
+        let deserialized: RelayerNetworkPolicy = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized, policy);
 
-        // Test empty case
-        let empty_policy = RelayerStellarPolicy::default();
-        let empty_tokens = empty_policy.get_allowed_tokens();
-        assert_eq!(empty_tokens.len(), 0);
+        let solana = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+            max_signatures: Some(3),
+            ..Default::default()
+        });
+        let json = serde_json::to_string(&solana).unwrap();
+        assert_eq!(
+            serde_json::from_str::<RelayerNetworkPolicy>(&json).unwrap(),
+            solana
+        );
     }
 
     #[test]
-    fn test_relayer_stellar_policy_get_allowed_token_entry() {
-        let token1 = StellarAllowedTokensPolicy::new("native".to_string(), None, Some(1000), None);
-        let token2 = StellarAllowedTokensPolicy::new(
-            "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
-            None,
-            Some(2000),
-            None,
-        );
+    fn test_relayer_network_policy_deserializes_legacy_forms() {
+        let expected = RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+            max_fee: Some(100),
+            ..Default::default()
+        });
 
-        let policy = RelayerStellarPolicy {
-            allowed_tokens: Some(vec![token1.clone(), token2.clone()]),
-            ..RelayerStellarPolicy::default()
-        };
+        let network_type_tagged: RelayerNetworkPolicy =
+            serde_json::from_value(json!({ "network_type": "stellar", "max_fee": 100 })).unwrap();
+        assert_eq!(network_type_tagged, expected);
 
-        let found_token = policy.get_allowed_token_entry("native").unwrap();
-        assert_eq!(found_token, token1);
+        let externally_tagged: RelayerNetworkPolicy =
+            serde_json::from_value(json!({ "stellar": { "max_fee": 100 } })).unwrap();
+        assert_eq!(externally_tagged, expected);
 
-        let not_found = policy.get_allowed_token_entry(
-            "EURC:GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2",
+        // An empty externally tagged policy is still recognized by its tag
+        let empty: RelayerNetworkPolicy = serde_json::from_value(json!({ "evm": {} })).unwrap();
+        assert_eq!(
+            empty,
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())
         );
-        assert!(not_found.is_none());
-
-        // Test empty case
-        let empty_policy = RelayerStellarPolicy::default();
-        let empty_result = empty_policy.get_allowed_token_entry("native");
-        assert!(empty_result.is_none());
     }
 
     #[test]
-    fn test_relayer_stellar_policy_get_allowed_token_decimals() {
-        let metadata1 = StellarTokenMetadata {
-            kind: StellarTokenKind::Native,
-            decimals: 7,
-            canonical_asset_id: "native".to_string(),
-        };
+    fn test_relayer_network_policy_rejects_unknown_tag() {
+        for value in [
+            json!({ "type": "bitcoin" }),
+            json!({ "max_fee": 100, "min_balance": 1 }),
+            json!("evm"),
+        ] {
+            assert!(serde_json::from_value::<RelayerNetworkPolicy>(value).is_err());
+        }
+    }
 
-        let metadata2 = StellarTokenMetadata {
-            kind: StellarTokenKind::Classic {
-                code: "USDC".to_string(),
-                issuer: "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
-            },
-            decimals: 6,
-            canonical_asset_id: "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"
-                .to_string(),
-        };
+    #[test]
+    fn test_relayer_network_policy_with_network_defaults() {
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: Some(5),
+            ..RelayerEvmPolicy::default()
+        })
+        .with_network_defaults();
 
-        let token1 = StellarAllowedTokensPolicy::new(
-            "native".to_string(),
-            Some(metadata1),
-            Some(1000),
-            None,
-        );
-        let token2 = StellarAllowedTokensPolicy::new(
-            "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
-            Some(metadata2),
-            Some(2000),
-            None,
+        let evm_policy = policy.get_evm_policy();
+        assert_eq!(evm_policy.min_balance, Some(5));
+        assert_eq!(evm_policy.gas_price_cap, Some(DEFAULT_EVM_GAS_PRICE_CAP));
+        assert_eq!(
+            evm_policy.gas_limit_estimation,
+            Some(DEFAULT_EVM_GAS_LIMIT_ESTIMATION)
         );
-        let token3 = StellarAllowedTokensPolicy::new(
-            "EURC:GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2".to_string(),
-            None,
-            Some(3000),
-            None,
+
+        let stellar_policy = RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default())
+            .with_network_defaults()
+            .get_stellar_policy();
+        assert_eq!(
+            stellar_policy.min_balance,
+            Some(DEFAULT_STELLAR_MIN_BALANCE)
         );
+    }
 
-        let policy = RelayerStellarPolicy {
-            allowed_tokens: Some(vec![token1, token2, token3]),
-            ..RelayerStellarPolicy::default()
+    #[test]
+    fn test_relayer_network_policy_effective_applies_guardrails_last() {
+        let guardrails = PolicyGuardrails {
+            require_gas_price_cap: true,
+            max_allowed_gas_price_cap: Some(10),
         };
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default());
 
-        assert_eq!(policy.get_allowed_token_decimals("native"), Some(7));
         assert_eq!(
-            policy.get_allowed_token_decimals(
-                "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"
-            ),
-            Some(6)
+            policy
+                .effective(Some(&guardrails))
+                .get_evm_policy()
+                .gas_price_cap,
+            Some(10)
         );
         assert_eq!(
-            policy.get_allowed_token_decimals(
-                "EURC:GDHU6WRG4IEQXM5NZ4BMPKOXHW76MZM4Y2IEMFDVXBSDP6SJY4ITNPP2"
-            ),
-            None
+            policy.effective(None).get_evm_policy().gas_price_cap,
+            Some(DEFAULT_EVM_GAS_PRICE_CAP)
         );
-        assert_eq!(policy.get_allowed_token_decimals("unknown"), None);
     }
 
     #[test]
-    fn test_relayer_stellar_policy_get_swap_config() {
-        let swap_config = RelayerStellarSwapConfig {
-            strategies: vec![StellarSwapStrategy::OrderBook],
-            cron_schedule: Some("0 0 * * *".to_string()),
-            min_balance_threshold: Some(1000000),
-        };
-
-        let policy = RelayerStellarPolicy {
-            swap_config: Some(swap_config.clone()),
-            ..RelayerStellarPolicy::default()
-        };
+    fn test_relayer_network_policy_overlay_prefers_child_settings() {
+        let parent = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(100),
+            min_balance: Some(5),
+            ..Default::default()
+        });
+        let child = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: Some(7),
+            ..Default::default()
+        });
 
-        let retrieved_config = policy.get_swap_config().unwrap();
-        assert_eq!(retrieved_config, swap_config);
+        let resolved = child.overlay(&parent).get_evm_policy();
+        assert_eq!(resolved.gas_price_cap, Some(100));
+        assert_eq!(resolved.min_balance, Some(7));
 
-        // Test None case
-        let empty_policy = RelayerStellarPolicy::default();
-        assert!(empty_policy.get_swap_config().is_none());
+        // A parent of another network type contributes nothing
+        let solana_parent = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default());
+        assert_eq!(child.overlay(&solana_parent), child);
     }
 
-    // ===== RelayerNetworkPolicy Tests =====
-
     #[test]
     fn test_relayer_network_policy_get_evm_policy() {
         let evm_policy = RelayerEvmPolicy {
@@ -2062,6 +3882,10 @@ mod tests {
             slippage_percentage: None,
             fee_margin_percentage: None,
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         };
 
         let network_policy = RelayerNetworkPolicy::Stellar(stellar_policy.clone());
@@ -2324,6 +4148,7 @@ mod tests {
             Some(vec![RpcConfig {
                 url: "https://example.com".to_string(),
                 weight: 150,
+                rpc_headers: None,
             }]), // Weight > 100
         );
 
@@ -2875,6 +4700,23 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(false),
                 private_transactions: None,
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             })),
             signer_id: "test-signer".to_string(),
             notification_id: Some("old-notification".to_string()),
@@ -2978,4 +4820,208 @@ mod tests {
                 || error_msg.contains("Invalid result after patch")
         );
     }
+
+    #[test]
+    fn test_policy_variant_accessors() {
+        let evm = RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default());
+        let solana = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default());
+        let stellar = RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default());
+
+        assert!(evm.as_evm().is_some());
+        assert!(evm.as_solana().is_none());
+        assert!(evm.as_stellar().is_none());
+
+        assert!(solana.as_evm().is_none());
+        assert!(solana.as_solana().is_some());
+        assert!(solana.as_stellar().is_none());
+
+        assert!(stellar.as_evm().is_none());
+        assert!(stellar.as_solana().is_none());
+        assert!(stellar.as_stellar().is_some());
+    }
+
+    #[test]
+    fn test_policy_min_balance_accessor() {
+        let evm = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: Some(1_000_000_000_000_000_000),
+            ..RelayerEvmPolicy::default()
+        });
+        let solana = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+            min_balance: Some(5_000),
+            ..RelayerSolanaPolicy::default()
+        });
+        let stellar = RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+            min_balance: Some(10_000_000),
+            ..RelayerStellarPolicy::default()
+        });
+
+        assert_eq!(evm.min_balance(), Some(1_000_000_000_000_000_000));
+        assert_eq!(solana.min_balance(), Some(5_000));
+        assert_eq!(stellar.min_balance(), Some(10_000_000));
+        assert_eq!(
+            RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()).min_balance(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_max_daily_spend_accessor_and_validation() {
+        let policy = RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+            max_daily_spend: Some(50_000_000),
+            ..RelayerStellarPolicy::default()
+        });
+        assert_eq!(policy.max_daily_spend(), Some(50_000_000));
+        assert!(policy.validate_max_daily_spend().is_ok());
+
+        let zero = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            max_daily_spend: Some(0),
+            ..RelayerEvmPolicy::default()
+        });
+        assert!(zero.validate_max_daily_spend().is_err());
+        assert_eq!(
+            RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()).max_daily_spend(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stuck_tx_action_serde_round_trip() {
+        for (action, name) in [
+            (StuckTxAction::SpeedUp, "speed_up"),
+            (StuckTxAction::Cancel, "cancel"),
+            (StuckTxAction::Leave, "leave"),
+        ] {
+            let serialized = serde_json::to_value(action).unwrap();
+            assert_eq!(serialized, json!(name));
+            let deserialized: StuckTxAction = serde_json::from_value(serialized).unwrap();
+            assert_eq!(deserialized, action);
+        }
+    }
+
+    #[test]
+    fn test_stuck_tx_action_defaults_to_speed_up() {
+        let policy: RelayerEvmPolicy = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(policy.stuck_tx_action, StuckTxAction::SpeedUp);
+        // The default is not written out
+        let serialized = serde_json::to_string(&policy).unwrap();
+        assert!(!serialized.contains("stuck_tx_action"));
+
+        let policy: RelayerEvmPolicy =
+            serde_json::from_value(json!({ "stuck_tx_action": "cancel" })).unwrap();
+        assert_eq!(policy.stuck_tx_action, StuckTxAction::Cancel);
+    }
+
+    #[test]
+    fn test_stuck_tx_action_accessor() {
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            stuck_tx_action: StuckTxAction::Leave,
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(policy.stuck_tx_action(), Some(StuckTxAction::Leave));
+        assert_eq!(
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()).stuck_tx_action(),
+            Some(StuckTxAction::SpeedUp)
+        );
+        assert_eq!(
+            RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()).stuck_tx_action(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_effective_gas_limit_applies_multiplier() {
+        let policy = RelayerEvmPolicy {
+            gas_estimation_multiplier: Some(1.5),
+            ..RelayerEvmPolicy::default()
+        };
+        assert_eq!(policy.effective_gas_limit(100_000), 150_000);
+        // Fractional gas is rounded up
+        assert_eq!(policy.effective_gas_limit(21_001), 31_502);
+
+        // Without a multiplier the default 10% buffer applies
+        assert_eq!(
+            RelayerEvmPolicy::default().effective_gas_limit(100_000),
+            110_000
+        );
+    }
+
+    #[test]
+    fn test_effective_gas_limit_clamps_to_cap() {
+        let policy = RelayerEvmPolicy {
+            gas_estimation_multiplier: Some(2.0),
+            gas_limit_cap: Some(150_000),
+            ..RelayerEvmPolicy::default()
+        };
+        assert_eq!(policy.effective_gas_limit(100_000), 150_000);
+        assert_eq!(policy.effective_gas_limit(50_000), 100_000);
+
+        let capped_default = RelayerEvmPolicy {
+            gas_limit_cap: Some(100_000),
+            ..RelayerEvmPolicy::default()
+        };
+        assert_eq!(capped_default.effective_gas_limit(100_000), 100_000);
+    }
+
+    #[test]
+    fn test_validate_gas_estimation() {
+        let evm = |multiplier: Option<f64>, cap: Option<u64>| {
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_estimation_multiplier: multiplier,
+                gas_limit_cap: cap,
+                ..RelayerEvmPolicy::default()
+            })
+        };
+        assert!(evm(None, None).validate_gas_estimation().is_ok());
+        assert!(evm(Some(1.0), Some(1)).validate_gas_estimation().is_ok());
+        assert!(evm(Some(3.0), None).validate_gas_estimation().is_ok());
+
+        for multiplier in [0.5, 0.99, 3.01, f64::NAN] {
+            let result = evm(Some(multiplier), None).validate_gas_estimation();
+            assert!(
+                matches!(result, Err(RepositoryError::InvalidData(ref msg)) if msg.contains("gas_estimation_multiplier")),
+                "multiplier {multiplier} should be rejected"
+            );
+        }
+        assert!(evm(None, Some(0)).validate_gas_estimation().is_err());
+        assert!(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default())
+            .validate_gas_estimation()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_is_batch_within_limit() {
+        let policy = RelayerEvmPolicy {
+            max_rpc_batch_size: Some(10),
+            ..RelayerEvmPolicy::default()
+        };
+        assert!(policy.is_batch_within_limit(1));
+        assert!(policy.is_batch_within_limit(10));
+        assert!(!policy.is_batch_within_limit(11));
+
+        // Unset means unlimited
+        assert!(RelayerEvmPolicy::default().is_batch_within_limit(10_000));
+    }
+
+    #[test]
+    fn test_max_rpc_batch_size_accessor_and_validation() {
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            max_rpc_batch_size: Some(1),
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(policy.max_rpc_batch_size(), Some(1));
+        assert!(policy.validate_max_rpc_batch_size().is_ok());
+
+        let zero = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            max_rpc_batch_size: Some(0),
+            ..RelayerEvmPolicy::default()
+        });
+        assert!(matches!(
+            zero.validate_max_rpc_batch_size(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert_eq!(
+            RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()).max_rpc_batch_size(),
+            None
+        );
+    }
 }
@@ -1,10 +1,24 @@
+use crate::constants::{
+    DEFAULT_EVM_EIP1559_ENABLED, DEFAULT_TENANT_ID, DERIVATION_PATH_REGEX,
+    MAX_RELAYER_SIGNER_TIMEOUT_MS, MIN_RELAYER_SIGNER_TIMEOUT_MS, RELAYER_CIRCUIT_REOPEN_SECONDS,
+    RELAYER_LINT_GAS_PRICE_CAP_FACTOR, RELAYER_SPEND_WINDOW_SECONDS,
+};
 use crate::models::{
     DisabledReason, Relayer, RelayerError, RelayerEvmPolicy, RelayerSolanaPolicy,
-    RelayerStellarPolicy,
+    RelayerStellarPolicy, RelayerValidationError, RepositoryError, RuleViolation,
+    UpdateRelayerRequest,
+};
+use crate::utils::{
+    deserialize_optional_u128, deserialize_u128, serialize_optional_u128, serialize_u128,
 };
+use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::time::Duration;
 
-use super::{RelayerNetworkPolicy, RelayerNetworkType, RpcConfig};
+use super::{HealthCheckFailure, RelayerNetworkPolicy, RelayerNetworkType, RpcConfig};
 
 // Use the domain model RelayerNetworkType directly
 pub type NetworkType = RelayerNetworkType;
@@ -21,17 +35,53 @@ impl RelayerRepoUpdater {
         Self { original: existing }
     }
 
-    /// Apply updates from a domain model while preserving runtime fields
-    ///
-    /// This method ensures that runtime fields (address, system_disabled, disabled_reason) from the
-    /// original repository model are preserved when converting from domain model,
-    /// preventing data loss during updates.
+    /// Apply updates from a domain model, keeping every field of the original that the
+    /// domain model does not carry
     pub fn apply_domain_update(self, domain: Relayer) -> RelayerRepoModel {
         let mut updated = RelayerRepoModel::from(domain);
         // Preserve runtime fields from original
         updated.address = self.original.address;
         updated.system_disabled = self.original.system_disabled;
         updated.disabled_reason = self.original.disabled_reason;
+        updated.updated_at = self.original.updated_at;
+        updated.quarantined_until = self.original.quarantined_until;
+        updated.extra_webhook_urls = self.original.extra_webhook_urls;
+        updated.alert_state = self.original.alert_state;
+        updated.tenant_id = self.original.tenant_id;
+        updated.scheduled_disable_at = self.original.scheduled_disable_at;
+        updated.scheduled_disable_reason = self.original.scheduled_disable_reason;
+        updated.hard_gas_price_ceiling = self.original.hard_gas_price_ceiling;
+        updated.status_history = self.original.status_history;
+        updated.recent_outcomes = self.original.recent_outcomes;
+        updated.pool = self.original.pool;
+        updated.derivation_path = self.original.derivation_path;
+        updated.circuit = self.original.circuit;
+        updated.last_error = self.original.last_error;
+        updated.config_versions = self.original.config_versions;
+        updated.aliases = self.original.aliases;
+        updated.standby_for = self.original.standby_for;
+        updated.health_check_interval = self.original.health_check_interval;
+        updated.last_health_check_at = self.original.last_health_check_at;
+        updated.health = self.original.health;
+        updated.sunset = self.original.sunset;
+        updated.inherits_from = self.original.inherits_from;
+        updated.fee_account = self.original.fee_account;
+        updated.chain_id = self.original.chain_id;
+        updated.tags = self.original.tags;
+        updated.metadata = self.original.metadata;
+        updated.rpc_health = self.original.rpc_health;
+        updated.signature_scheme = self.original.signature_scheme;
+        updated.nonce_group = self.original.nonce_group;
+        updated.paused_by_enforcement = self.original.paused_by_enforcement;
+        updated.log_level = self.original.log_level;
+        updated.allowed_origins = self.original.allowed_origins;
+        updated.pending_count = self.original.pending_count;
+        updated.signer_timeout_ms = self.original.signer_timeout_ms;
+        updated.spent_today = self.original.spent_today;
+        updated.spend_window_start = self.original.spend_window_start;
+        updated.mode = self.original.mode;
+        updated.ownership_history = self.original.ownership_history;
+        updated.version = self.original.version;
         updated
     }
 }
@@ -50,934 +100,4452 @@ pub struct RelayerRepoModel {
     pub system_disabled: bool,
     pub disabled_reason: Option<DisabledReason>,
     pub custom_rpc_urls: Option<Vec<RpcConfig>>,
+    /// RFC3339 timestamp of the last repository-level change to the relayer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// When set, the relayer is quarantined and becomes operational again after this time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quarantined_until: Option<DateTime<Utc>>,
+    /// Additional webhook URLs notified alongside the referenced notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_webhook_urls: Option<Vec<String>>,
+    /// Runtime low balance alert state, driven by `low_balance_alerting` thresholds
+    #[serde(default)]
+    pub alert_state: AlertState,
+    /// Tenant owning the relayer; relayers are only visible within their tenant
+    #[serde(default = "default_tenant_id")]
+    pub tenant_id: String,
+    /// Time at which a sweeper should disable the relayer, if scheduled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduled_disable_at: Option<DateTime<Utc>>,
+    /// Reason recorded alongside `scheduled_disable_at`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduled_disable_reason: Option<String>,
+    /// Absolute gas price ceiling applied after all other pricing computation,
+    /// independent of the network policy's `gas_price_cap`
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128"
+    )]
+    pub hard_gas_price_ceiling: Option<u128>,
+    /// Most recent enable/disable/quarantine transitions, oldest first
+    #[serde(default, skip_serializing_if = "VecDeque::is_empty")]
+    pub status_history: VecDeque<StatusChange>,
+    /// Most recent operation outcomes, oldest first, used to compute error rates
+    #[serde(default, skip_serializing_if = "VecDeque::is_empty")]
+    pub recent_outcomes: VecDeque<RecordedOutcome>,
+    /// Pool the relayer belongs to, used for sticky session routing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>,
+    /// BIP-32 derivation path of the relayer key for HD-wallet signers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub derivation_path: Option<String>,
+    /// Circuit breaker state, used to stop a relayer that is failing downstream
+    #[serde(default)]
+    pub circuit: CircuitState,
+    /// Most recent error seen on the relayer, kept for quick triage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<RelayerLastError>,
+    /// Most recent configuration versions, oldest first
+    #[serde(default, skip_serializing_if = "VecDeque::is_empty")]
+    pub config_versions: VecDeque<RelayerConfigVersion>,
+    /// External identifiers the relayer can also be looked up by; unique across
+    /// all relayer ids and aliases
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// ID of the primary relayer this relayer is a warm standby for. The primary
+    /// must exist and be on the same network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standby_for: Option<String>,
+    /// How often the relayer should be health checked; relayers without an interval
+    /// are never due
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check_interval: Option<Duration>,
+    /// When the most recent health check was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_health_check_at: Option<DateTime<Utc>>,
+    /// Outcome of the most recent health check
+    #[serde(default, skip_serializing_if = "RelayerHealth::is_unknown")]
+    pub health: RelayerHealth,
+    /// Set while the relayer is being decommissioned: it accepts no new transactions
+    /// but keeps processing the ones already in flight
+    #[serde(default)]
+    pub sunset: bool,
+    /// ID of the parent relayer this relayer inherits its policy from. Only the
+    /// settings the relayer overrides are stored; the rest resolve from the parent
+    /// on read, so editing the parent updates every child.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherits_from: Option<String>,
+    /// Account the relayer's fees are drawn from, for relayers using a
+    /// meta-transaction or paymaster model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_account: Option<FeeAccount>,
+    /// Chain id of the relayer's network, checked against the chains its signer is
+    /// authorized for when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    /// Free-form labels used to group relayers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Opaque integrator-owned metadata, never interpreted by the relayer
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub metadata: serde_json::Value,
+    /// Last known health of each custom RPC URL, keyed by URL
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub rpc_health: HashMap<String, RpcHealth>,
+    /// Signature scheme the relayer signs with; the network type's default when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_scheme: Option<SignatureScheme>,
+    /// Relayers in the same nonce group submit through the same address and must
+    /// coordinate nonces; all members share a network type and chain id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce_group: Option<String>,
+    /// Set when the relayer was paused by allowlist enforcement rather than by hand,
+    /// so enforcement only resumes relayers it paused itself
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub paused_by_enforcement: bool,
+    /// Logging verbosity for this relayer's traces; `None` inherits the global level
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<LogLevel>,
+    /// Origin patterns allowed to submit through this relayer, such as
+    /// `https://*.example.com`; any origin is allowed when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<String>>,
+    /// Transactions currently pending for the relayer, maintained by the repository so
+    /// the scheduler does not have to query the transaction store
+    #[serde(default)]
+    pub pending_count: u64,
+    /// How long the signing layer waits for the relayer's signer before giving up;
+    /// signing is not bounded when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_timeout_ms: Option<u64>,
+    /// Native currency spent in the current spend window, checked against the policy's
+    /// `max_daily_spend`
+    #[serde(
+        default,
+        serialize_with = "serialize_u128",
+        deserialize_with = "deserialize_u128"
+    )]
+    pub spent_today: u128,
+    /// When the current spend window opened; `spent_today` resets once it is a day old
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spend_window_start: Option<DateTime<Utc>>,
+    /// Whether the relayer takes all transactions or only canary ones
+    #[serde(default)]
+    pub mode: RelayerMode,
+    /// Most recent ownership transfers between tenants, oldest first
+    #[serde(default, skip_serializing_if = "VecDeque::is_empty")]
+    pub ownership_history: VecDeque<OwnershipTransfer>,
+    /// Incremented by the repository on every write, so a caller can make a change
+    /// conditional on the relayer not having changed since it was read
+    #[serde(default)]
+    pub version: u64,
 }
 
-impl RelayerRepoModel {
-    pub fn validate_active_state(&self) -> Result<(), RelayerError> {
-        if self.paused {
-            return Err(RelayerError::RelayerPaused);
-        }
+/// Account a relayer's fees are drawn from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FeeAccount {
+    pub address: String,
+    pub kind: FeeAccountKind,
+}
 
-        if self.system_disabled {
-            return Err(RelayerError::RelayerDisabled);
-        }
+/// Role of the account a relayer's fees are drawn from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeAccountKind {
+    /// A paymaster contract sponsoring the relayer's transactions
+    Paymaster,
+    /// A treasury account shared by several relayers
+    Treasury,
+    /// The relayer's own account
+    #[serde(rename = "self")]
+    Self_,
+}
 
-        Ok(())
+fn default_tenant_id() -> String {
+    DEFAULT_TENANT_ID.to_string()
+}
+
+/// Low balance alert state of a relayer
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertState {
+    #[default]
+    Normal,
+    Alerting,
+}
+
+/// Circuit breaker state of a relayer
+///
+/// An open circuit becomes half-open once `until` has elapsed, allowing a probe
+/// whose outcome either closes the circuit or opens it again.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum CircuitState {
+    #[default]
+    Closed,
+    Open {
+        until: DateTime<Utc>,
+    },
+    HalfOpen,
+}
+
+/// Traffic a relayer accepts
+///
+/// Relayers created through the API start in `Canary` and only take transactions
+/// explicitly marked as canaries until promoted. Relayers stored before modes existed
+/// deserialize as `Live`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RelayerMode {
+    Canary,
+    #[default]
+    Live,
+}
+
+/// Transition produced when evaluating a balance against alert thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertTransition {
+    /// Balance dropped below `warn_below`
+    Fired,
+    /// Balance rose above `clear_above`
+    Cleared,
+}
+
+/// Single status of a relayer, derived from its flags for listing and filtering.
+///
+/// When several flags are set the most severe one wins: `Sunset` outranks a system
+/// disable, which outranks a user pause. A system disable backed by a quarantine is
+/// reported as `Quarantined` until the quarantine expires, after which it no longer
+/// counts. The circuit breaker is not reflected; see
+/// [`RelayerRepoModel::is_operational`] for whether a relayer can take new work.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RelayerOperationalStatus {
+    Operational,
+    Paused,
+    Disabled,
+    Quarantined,
+    Sunset,
+}
+
+/// Criteria selecting the relayers a bulk operation applies to. Every criterion that
+/// is set must match; the default filter matches every relayer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RelayerFilter {
+    pub network_type: Option<RelayerNetworkType>,
+    pub network: Option<String>,
+    pub status: Option<RelayerOperationalStatus>,
+    pub tag: Option<String>,
+}
+
+impl RelayerFilter {
+    /// Returns whether `relayer` matches every criterion of the filter at `now`
+    pub fn matches_at(&self, relayer: &RelayerRepoModel, now: DateTime<Utc>) -> bool {
+        self.network_type
+            .is_none_or(|network_type| relayer.network_type == network_type)
+            && self
+                .network
+                .as_ref()
+                .is_none_or(|network| &relayer.network == network)
+            && self
+                .status
+                .is_none_or(|status| relayer.status_at(now) == status)
+            && self.tag.as_ref().is_none_or(|tag| relayer.has_tag(tag))
     }
 }
 
-impl Default for RelayerRepoModel {
-    fn default() -> Self {
-        Self {
-            id: "".to_string(),
-            name: "".to_string(),
-            network: "".to_string(),
-            paused: false,
-            network_type: NetworkType::Evm,
-            signer_id: "".to_string(),
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            address: "0x".to_string(),
-            notification_id: None,
-            system_disabled: false,
-            disabled_reason: None,
-            custom_rpc_urls: None,
+/// Coarse operational state of a relayer, as recorded in its status history
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RelayerState {
+    Enabled,
+    Disabled,
+    Quarantined,
+}
+
+/// A single enable/disable/quarantine transition of a relayer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatusChange {
+    pub from: RelayerState,
+    pub to: RelayerState,
+    pub at: DateTime<Utc>,
+    /// Disabled reason in effect after the transition, if any
+    pub reason: Option<DisabledReason>,
+}
+
+/// A move of a relayer from one tenant to another
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OwnershipTransfer {
+    pub from_tenant: String,
+    pub to_tenant: String,
+    /// Who performed the transfer
+    pub actor: String,
+    pub at: DateTime<Utc>,
+}
+
+/// A single success/failure outcome recorded against a relayer
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecordedOutcome {
+    pub at: DateTime<Utc>,
+    pub success: bool,
+}
+
+/// Category of an error recorded against a relayer
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RelayerErrorKind {
+    Rpc,
+    Signing,
+    Submission,
+    InsufficientBalance,
+    Validation,
+    Other,
+}
+
+/// Outcome of the most recent health check of a relayer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(tag = "status", content = "failures", rename_all = "snake_case")]
+pub enum RelayerHealth {
+    /// No health check has been recorded yet
+    #[default]
+    Unknown,
+    Healthy,
+    Unhealthy(Vec<HealthCheckFailure>),
+}
+
+impl RelayerHealth {
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown)
+    }
+}
+
+impl From<Result<(), Vec<HealthCheckFailure>>> for RelayerHealth {
+    fn from(result: Result<(), Vec<HealthCheckFailure>>) -> Self {
+        match result {
+            Ok(()) => Self::Healthy,
+            Err(failures) => Self::Unhealthy(failures),
         }
     }
 }
 
-impl From<RelayerRepoModel> for Relayer {
-    fn from(repo_model: RelayerRepoModel) -> Self {
-        Self {
-            id: repo_model.id,
-            name: repo_model.name,
-            network: repo_model.network,
-            paused: repo_model.paused,
-            network_type: repo_model.network_type,
-            policies: Some(repo_model.policies),
-            signer_id: repo_model.signer_id,
-            notification_id: repo_model.notification_id,
-            custom_rpc_urls: repo_model.custom_rpc_urls,
+/// Health of a single RPC endpoint, as last reported by its callers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcHealth {
+    /// No outcome has been recorded yet
+    #[default]
+    Unknown,
+    Healthy,
+    Unhealthy,
+}
+
+/// Signature scheme a relayer signs transactions with
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureScheme {
+    /// ECDSA over secp256k1
+    Secp256k1,
+    Ed25519,
+}
+
+impl SignatureScheme {
+    /// Scheme used by relayers of `network_type` that do not select one
+    pub fn default_for(network_type: NetworkType) -> Self {
+        match network_type {
+            NetworkType::Evm => Self::Secp256k1,
+            NetworkType::Solana | NetworkType::Stellar => Self::Ed25519,
         }
     }
+
+    /// Returns true if relayers of `network_type` can sign with this scheme
+    pub fn supports(self, network_type: NetworkType) -> bool {
+        self == Self::default_for(network_type)
+    }
 }
 
-impl From<Relayer> for RelayerRepoModel {
-    fn from(relayer: Relayer) -> Self {
-        Self {
-            id: relayer.id,
-            name: relayer.name,
-            network: relayer.network,
-            paused: relayer.paused,
-            network_type: relayer.network_type,
-            signer_id: relayer.signer_id,
-            policies: relayer.policies.unwrap_or_else(|| {
-                // Default policy based on network type
-                match relayer.network_type {
-                    RelayerNetworkType::Evm => {
-                        RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())
-                    }
-                    RelayerNetworkType::Solana => {
-                        RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default())
-                    }
-                    RelayerNetworkType::Stellar => {
-                        RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default())
-                    }
-                }
-            }),
-            address: "".to_string(), // Will be filled in later by process_relayers
-            notification_id: relayer.notification_id,
-            system_disabled: false,
-            disabled_reason: None,
-            custom_rpc_urls: relayer.custom_rpc_urls,
+/// Logging verbosity a relayer's traces are emitted at, overriding the global level
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for tracing::Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => tracing::Level::ERROR,
+            LogLevel::Warn => tracing::Level::WARN,
+            LogLevel::Info => tracing::Level::INFO,
+            LogLevel::Debug => tracing::Level::DEBUG,
+            LogLevel::Trace => tracing::Level::TRACE,
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::models::{
-        RelayerEvmPolicy, RelayerSolanaPolicy, RelayerStellarPolicy, SolanaAllowedTokensPolicy,
-        SolanaFeePaymentStrategy, StellarFeePaymentStrategy,
-    };
+/// Most recent error recorded against a relayer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelayerLastError {
+    pub message: String,
+    pub at: DateTime<Utc>,
+    pub kind: RelayerErrorKind,
+}
 
-    use super::*;
+/// Versioned configuration of a relayer.
+///
+/// Runtime state is excluded, as are the signer and address, which only change
+/// together through a rekey, and the owning tenant.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelayerConfigSnapshot {
+    pub name: String,
+    pub network: String,
+    pub paused: bool,
+    pub policies: RelayerNetworkPolicy,
+    pub notification_id: Option<String>,
+    pub custom_rpc_urls: Option<Vec<RpcConfig>>,
+    pub extra_webhook_urls: Option<Vec<String>>,
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128"
+    )]
+    pub hard_gas_price_ceiling: Option<u128>,
+    pub pool: Option<String>,
+    pub derivation_path: Option<String>,
+}
 
-    fn create_test_relayer(paused: bool, system_disabled: bool) -> RelayerRepoModel {
-        RelayerRepoModel {
-            id: "test_relayer".to_string(),
-            name: "Test Relayer".to_string(),
-            paused,
-            system_disabled,
-            disabled_reason: None,
-            network: "test_network".to_string(),
-            network_type: NetworkType::Evm,
-            signer_id: "test_signer".to_string(),
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            address: "0xtest".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
+/// A recorded configuration version of a relayer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelayerConfigVersion {
+    /// Sequential version number, starting at 1 when the relayer is created
+    pub version: u32,
+    pub at: DateTime<Utc>,
+    pub config: RelayerConfigSnapshot,
+}
+
+/// Version number and timestamp of a recorded configuration version
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelayerConfigVersionInfo {
+    pub version: u32,
+    pub at: DateTime<Utc>,
+}
+
+/// Kind of mutation recorded in the relayer change log
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// A single relayer mutation, ordered by its sequence number
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangeRecord {
+    /// Monotonically increasing sequence number, starting at 1
+    pub seq: u64,
+    pub kind: ChangeKind,
+    /// ID of the mutated relayer
+    pub id: String,
+    pub at: DateTime<Utc>,
+}
+
+/// A transaction feature a relayer may support, used to route transactions
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    Eip1559,
+    PrivateTransactions,
+    Blobs,
+}
+
+/// Transaction features a relayer supports, derived from its network type and policy
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RelayerCapabilities {
+    /// EIP-1559 fee market pricing
+    pub supports_eip1559: bool,
+    /// Submission through a private mempool
+    pub supports_private_tx: bool,
+    /// EIP-4844 blob transactions, which are priced with EIP-1559 fee fields
+    pub supports_blobs: bool,
+}
+
+impl RelayerCapabilities {
+    /// Returns true if `capability` is supported
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Eip1559 => self.supports_eip1559,
+            Capability::PrivateTransactions => self.supports_private_tx,
+            Capability::Blobs => self.supports_blobs,
         }
     }
+}
 
-    fn create_test_relayer_solana(paused: bool, system_disabled: bool) -> RelayerRepoModel {
-        RelayerRepoModel {
-            id: "test_solana_relayer".to_string(),
-            name: "Test Solana Relayer".to_string(),
-            paused,
-            system_disabled,
-            network: "mainnet".to_string(),
-            network_type: NetworkType::Solana,
-            signer_id: "test_signer".to_string(),
-            policies: RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
-                fee_payment_strategy: Some(SolanaFeePaymentStrategy::Relayer),
-                min_balance: Some(1000000),
-                max_signatures: Some(5),
-                allowed_tokens: None,
-                allowed_programs: None,
-                allowed_accounts: None,
-                disallowed_accounts: None,
-                max_tx_data_size: None,
-                max_allowed_fee_lamports: None,
-                swap_config: None,
-                fee_margin_percentage: None,
-            }),
-            address: "SolanaAddress123".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
-            ..Default::default()
+impl RelayerLastError {
+    /// Creates an error record timestamped now
+    pub fn new(kind: RelayerErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            at: Utc::now(),
+            kind,
         }
     }
+}
 
-    fn create_test_relayer_stellar(paused: bool, system_disabled: bool) -> RelayerRepoModel {
-        RelayerRepoModel {
-            id: "test_stellar_relayer".to_string(),
-            name: "Test Stellar Relayer".to_string(),
-            paused,
-            system_disabled,
-            network: "mainnet".to_string(),
-            network_type: NetworkType::Stellar,
-            signer_id: "test_signer".to_string(),
-            policies: RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
-                min_balance: Some(20000000),
-                max_fee: Some(100000),
-                timeout_seconds: Some(30),
-                concurrent_transactions: None,
-                allowed_tokens: None,
-                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
-                slippage_percentage: None,
-                fee_margin_percentage: None,
-                swap_config: None,
-            }),
-            address: "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
-            ..Default::default()
+impl RelayerRepoModel {
+    pub fn validate_active_state(&self) -> Result<(), RelayerError> {
+        if self.paused {
+            return Err(RelayerError::RelayerPaused);
         }
-    }
 
-    #[test]
-    fn test_validate_active_state_success() {
-        let relayer = create_test_relayer(false, false);
-        assert!(relayer.validate_active_state().is_ok());
-    }
+        if self.system_disabled && !self.is_quarantine_expired(Utc::now()) {
+            return Err(RelayerError::RelayerDisabled);
+        }
 
-    #[test]
-    fn test_validate_active_state_success_solana() {
-        let relayer = create_test_relayer_solana(false, false);
-        assert!(relayer.validate_active_state().is_ok());
-    }
+        if self.sunset {
+            return Err(RelayerError::RelayerSunset);
+        }
 
-    #[test]
-    fn test_validate_active_state_success_stellar() {
-        let relayer = create_test_relayer_stellar(false, false);
-        assert!(relayer.validate_active_state().is_ok());
+        Ok(())
     }
 
-    #[test]
-    fn test_validate_active_state_paused() {
-        let relayer = create_test_relayer(true, false);
-        let result = relayer.validate_active_state();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    /// Checks a transaction against the relayer's declarative validation rules.
+    ///
+    /// # Arguments
+    /// * `value` - Native value transferred by the transaction
+    /// * `selector` - 4-byte function selector, or `None` if the transaction has no calldata
+    pub fn evaluate_rules(
+        &self,
+        value: u128,
+        selector: Option<[u8; 4]>,
+    ) -> Result<(), RuleViolation> {
+        self.policies
+            .validation_rules()
+            .iter()
+            .try_for_each(|rule| rule.evaluate(value, selector))
     }
 
-    #[test]
-    fn test_validate_active_state_paused_solana() {
-        let relayer = create_test_relayer_solana(true, false);
-        let result = relayer.validate_active_state();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    /// Evaluates a balance against the policy's low balance thresholds with hysteresis.
+    ///
+    /// Moves to `Alerting` when the balance drops below `warn_below` and back to
+    /// `Normal` only once it rises above `clear_above`.
+    ///
+    /// # Returns
+    /// The transition that occurred, or `None` if the state did not change
+    pub fn evaluate_balance_alert(&mut self, balance: u128) -> Option<AlertTransition> {
+        let thresholds = self.policies.low_balance_alerting()?;
+        match self.alert_state {
+            AlertState::Normal if balance < thresholds.warn_below => {
+                self.alert_state = AlertState::Alerting;
+                Some(AlertTransition::Fired)
+            }
+            AlertState::Alerting if balance > thresholds.clear_above => {
+                self.alert_state = AlertState::Normal;
+                Some(AlertTransition::Cleared)
+            }
+            _ => None,
+        }
     }
 
-    #[test]
-    fn test_validate_active_state_paused_stellar() {
-        let relayer = create_test_relayer_stellar(true, false);
-        let result = relayer.validate_active_state();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    /// Returns the relayer-level webhook URLs notified in addition to `notification_id`
+    pub fn extra_webhook_urls(&self) -> &[String] {
+        self.extra_webhook_urls.as_deref().unwrap_or_default()
     }
 
-    #[test]
-    fn test_validate_active_state_disabled() {
-        let relayer = create_test_relayer(false, true);
-        let result = relayer.validate_active_state();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), RelayerError::RelayerDisabled));
+    /// Validates that all extra webhook URLs are well-formed http(s) URLs
+    pub fn validate_extra_webhook_urls(&self) -> Result<(), RepositoryError> {
+        for url in self.extra_webhook_urls() {
+            let parsed = reqwest::Url::parse(url).map_err(|_| {
+                RepositoryError::InvalidData(format!("Invalid extra webhook URL: {url}"))
+            })?;
+            if !matches!(parsed.scheme(), "http" | "https") {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Extra webhook URL must use http or https: {url}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the full relayer validation suite against the model without mutating it.
+    ///
+    /// Domain validation stops at its first violation, so at most one domain error is
+    /// reported alongside any repository-level violations (tenant, extra webhook URLs, fee
+    /// account, allowed origins and signer timeout).
+    pub fn validation_errors(&self) -> Vec<RelayerValidationError> {
+        let mut errors = Vec::new();
+        if let Err(e) = Relayer::from(self.clone()).validate() {
+            errors.push(e);
+        }
+        if self.tenant_id.is_empty() {
+            errors.push(RelayerValidationError::InvalidField(
+                "Relayer tenant ID cannot be empty".to_string(),
+            ));
+        }
+        if let Err(RepositoryError::InvalidData(msg)) = self.validate_extra_webhook_urls() {
+            errors.push(RelayerValidationError::InvalidField(msg));
+        }
+        if let Err(RepositoryError::InvalidData(msg)) = self.validate_fee_account() {
+            errors.push(RelayerValidationError::InvalidField(msg));
+        }
+        if let Err(RepositoryError::InvalidData(msg)) = self.validate_allowed_origins() {
+            errors.push(RelayerValidationError::InvalidField(msg));
+        }
+        if let Err(RepositoryError::InvalidData(msg)) = self.validate_signer_timeout() {
+            errors.push(RelayerValidationError::InvalidField(msg));
+        }
+        errors
+    }
+
+    /// Runs the create-time checks that do not depend on other stored relayers,
+    /// returning every failure in the order the repository checks them
+    pub fn create_validation_errors(&self) -> Vec<RepositoryError> {
+        let mut errors = Vec::new();
+        if self.tenant_id.is_empty() {
+            errors.push(RepositoryError::InvalidData(
+                "Relayer tenant ID cannot be empty".to_string(),
+            ));
+        }
+        let checks = [
+            self.validate_extra_webhook_urls(),
+            self.validate_derivation_path(),
+            self.validate_fee_account(),
+            self.validate_aliases(),
+            self.validate_signature_scheme(),
+            self.validate_allowed_origins(),
+            self.validate_signer_timeout(),
+        ];
+        errors.extend(checks.into_iter().filter_map(Result::err));
+        errors.extend(self.policies.validation_errors());
+        errors
+    }
+
+    /// Flags configuration that is valid but suspicious. Unlike `validation_errors`,
+    /// nothing reported here blocks storing the relayer.
+    ///
+    /// The gas price cap is only compared against the network when `current_gas_price`
+    /// is given.
+    pub fn lint(&self, current_gas_price: Option<u128>) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+        if self.policies.min_balance() == Some(0) {
+            warnings.push(LintWarning::new(
+                LintSeverity::Warning,
+                "policies.min_balance",
+                "min_balance is 0, so the relayer is never stopped for running out of funds",
+            ));
+        }
+        if let Some(policy) = self.policies.as_evm() {
+            if let Some([receiver]) = policy.whitelist_receivers.as_deref() {
+                if self.network_type.addresses_match(receiver, &self.address) {
+                    warnings.push(LintWarning::new(
+                        LintSeverity::Warning,
+                        "policies.whitelist_receivers",
+                        "the only whitelisted receiver is the relayer's own address",
+                    ));
+                }
+            }
+            if let (Some(cap), Some(current)) = (policy.gas_price_cap, current_gas_price) {
+                if cap < current / RELAYER_LINT_GAS_PRICE_CAP_FACTOR {
+                    warnings.push(LintWarning::new(
+                        LintSeverity::Warning,
+                        "policies.gas_price_cap",
+                        format!(
+                            "gas_price_cap {cap} is far below the current network gas price {current}"
+                        ),
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Validates a replacement signer and address before rekeying the relayer
+    pub fn validate_rekey(&self, signer_id: &str, address: &str) -> Result<(), RepositoryError> {
+        if signer_id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Signer ID cannot be empty".to_string(),
+            ));
+        }
+        if !self.network_type.is_valid_address(address) {
+            return Err(RepositoryError::InvalidData(format!(
+                "Invalid {} address: {address}",
+                self.network_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the BIP-32 derivation path of the relayer key, if set
+    pub fn derivation_path(&self) -> Option<&str> {
+        self.derivation_path.as_deref()
+    }
+
+    /// Returns the account the relayer's fees are drawn from, if set
+    pub fn fee_account(&self) -> Option<&FeeAccount> {
+        self.fee_account.as_ref()
+    }
+
+    /// Validates that the fee account address, if set, is well-formed for the
+    /// relayer's network type
+    pub fn validate_fee_account(&self) -> Result<(), RepositoryError> {
+        let Some(fee_account) = self.fee_account() else {
+            return Ok(());
+        };
+        if !self.network_type.is_valid_address(&fee_account.address) {
+            return Err(RepositoryError::InvalidData(format!(
+                "Invalid {} fee account address: {}",
+                self.network_type, fee_account.address
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validates that the relayer's chain, if set, is one its signer is authorized for.
+    /// A signer without `allowed_chains` is unrestricted.
+    pub fn validate_signer_chain(
+        &self,
+        allowed_chains: Option<&[u64]>,
+    ) -> Result<(), RepositoryError> {
+        match (self.chain_id, allowed_chains) {
+            (Some(chain_id), Some(allowed)) if !allowed.contains(&chain_id) => {
+                Err(RepositoryError::ConstraintViolation(format!(
+                    "Signer {} is not authorized for chain {chain_id}",
+                    self.signer_id
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates that the derivation path, if set, follows the BIP-32 path grammar
+    /// (e.g. `m/44'/60'/0'/0/0`) with every index below 2^31.
+    pub fn validate_derivation_path(&self) -> Result<(), RepositoryError> {
+        let Some(path) = self.derivation_path() else {
+            return Ok(());
+        };
+        // Hardened indexes are marked with a trailing `'` and must still fit in 31 bits
+        let indexes_in_range = || {
+            path.split('/').skip(1).all(|index| {
+                index
+                    .trim_end_matches('\'')
+                    .parse::<u32>()
+                    .is_ok_and(|i| i < 1 << 31)
+            })
+        };
+        if !DERIVATION_PATH_REGEX.is_match(path) || !indexes_in_range() {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Invalid derivation path: {path}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validates that aliases are non-empty, distinct and differ from the relayer's own id.
+    ///
+    /// Uniqueness across relayers is enforced by the repository.
+    pub fn validate_aliases(&self) -> Result<(), RepositoryError> {
+        let mut seen = HashSet::new();
+        for alias in &self.aliases {
+            if alias.is_empty() {
+                return Err(RepositoryError::InvalidData(
+                    "Relayer alias cannot be empty".to_string(),
+                ));
+            }
+            if alias == &self.id || !seen.insert(alias.as_str()) {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Relayer alias {alias} is duplicated"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the standby pairing against the stored `primary` relayer.
+    ///
+    /// The primary must exist, must not be the relayer itself or another standby, and must
+    /// be on the same network. That a primary has at most one standby is enforced by the
+    /// repository.
+    pub fn validate_standby_for(
+        &self,
+        primary: Option<&RelayerRepoModel>,
+    ) -> Result<(), RepositoryError> {
+        let Some(primary_id) = &self.standby_for else {
+            return Ok(());
+        };
+        if primary_id == &self.id {
+            return Err(RepositoryError::InvalidData(format!(
+                "Relayer {primary_id} cannot be a standby for itself"
+            )));
+        }
+        let primary = primary.ok_or_else(|| {
+            RepositoryError::InvalidData(format!("Primary relayer {primary_id} not found"))
+        })?;
+        if primary.standby_for.is_some() {
+            return Err(RepositoryError::InvalidData(format!(
+                "Primary relayer {primary_id} is itself a standby"
+            )));
+        }
+        if primary.network_type != self.network_type || primary.network != self.network {
+            return Err(RepositoryError::InvalidData(format!(
+                "Standby relayer {} is on network {}, but primary relayer {primary_id} is on {}",
+                self.id, self.network, primary.network
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validates the nonce group against the other stored `members` of the group.
+    ///
+    /// The group name must not be empty, and every member must share the relayer's
+    /// network type and chain id.
+    pub fn validate_nonce_group<'a>(
+        &self,
+        members: impl IntoIterator<Item = &'a RelayerRepoModel>,
+    ) -> Result<(), RepositoryError> {
+        let Some(group) = &self.nonce_group else {
+            return Ok(());
+        };
+        if group.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer nonce group cannot be empty".to_string(),
+            ));
+        }
+        for member in members {
+            if member.network_type != self.network_type || member.chain_id != self.chain_id {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Relayer {} must share network type and chain id with relayer {} of nonce group {group}",
+                    self.id, member.id
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the relayers this relayer inherits from, nearest parent first.
+    ///
+    /// `lookup` resolves a stored relayer by ID. Fails with `InvalidData` if a parent
+    /// does not exist, is of another network type, or the chain loops back on itself.
+    pub fn inheritance_chain(
+        &self,
+        lookup: impl Fn(&str) -> Option<RelayerRepoModel>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let mut visited = HashSet::from([self.id.clone()]);
+        let mut chain: Vec<RelayerRepoModel> = Vec::new();
+        let mut next = self.inherits_from.clone();
+        while let Some(parent_id) = next {
+            if !visited.insert(parent_id.clone()) {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Relayer {} inheritance forms a cycle through {parent_id}",
+                    self.id
+                )));
+            }
+            let parent = lookup(&parent_id).ok_or_else(|| {
+                RepositoryError::InvalidData(format!("Parent relayer {parent_id} not found"))
+            })?;
+            if parent.network_type != self.network_type {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Relayer {} cannot inherit from {parent_id} of another network type",
+                    self.id
+                )));
+            }
+            next = parent.inherits_from.clone();
+            chain.push(parent);
+        }
+        Ok(chain)
+    }
+
+    /// Resolves the relayer's policy by overlaying it on the policies of `ancestors`,
+    /// as returned by [`RelayerRepoModel::inheritance_chain`]
+    pub fn inherited_policies(&self, ancestors: &[RelayerRepoModel]) -> RelayerNetworkPolicy {
+        ancestors
+            .iter()
+            .fold(self.policies.clone(), |policy, parent| {
+                policy.overlay(&parent.policies)
+            })
+    }
+
+    /// Clamps a computed gas price to the relayer's hard gas price ceiling, if set
+    pub fn clamp_gas_price(&self, computed: u128) -> u128 {
+        self.hard_gas_price_ceiling
+            .map_or(computed, |ceiling| computed.min(ceiling))
+    }
+
+    /// Returns true if a scheduled disable is due at `now`
+    pub fn is_scheduled_disable_due(&self, now: DateTime<Utc>) -> bool {
+        self.scheduled_disable_at.is_some_and(|at| at <= now)
+    }
+
+    /// Returns true if the relayer was quarantined and the quarantine has elapsed at `now`
+    pub fn is_quarantine_expired(&self, now: DateTime<Utc>) -> bool {
+        self.quarantined_until.is_some_and(|until| until <= now)
+    }
+
+    /// Returns true if the relayer can currently accept new transactions.
+    ///
+    /// A relayer disabled by a quarantine is considered operational once the
+    /// quarantine has expired, even before a sweeper re-enables it. A sunset
+    /// relayer is never operational.
+    pub fn is_operational(&self) -> bool {
+        self.is_operational_at(Utc::now())
+    }
+
+    /// Same as [`Self::is_operational`], evaluated at the given point in time
+    pub fn is_operational_at(&self, now: DateTime<Utc>) -> bool {
+        !self.sunset && self.accepts_inflight_completion_at(now)
+    }
+
+    /// Returns true if the relayer is operational and takes the transaction. A canary
+    /// relayer only takes transactions marked as canaries.
+    pub fn accepts_transaction(&self, is_canary: bool) -> bool {
+        self.is_operational() && self.accepts_mode(is_canary)
+    }
+
+    /// Returns true if the relayer's mode allows the transaction, regardless of
+    /// whether it is operational
+    pub fn accepts_mode(&self, is_canary: bool) -> bool {
+        is_canary || self.mode == RelayerMode::Live
+    }
+
+    /// Returns true if the relayer is operational and no repository maintenance window
+    /// is active. `maintenance_until` is the end of the window, as returned by
+    /// `RelayerRepository::maintenance_until`.
+    pub fn is_available(&self, maintenance_until: Option<DateTime<Utc>>) -> bool {
+        self.is_available_at(maintenance_until, Utc::now())
+    }
+
+    /// Same as [`Self::is_available`], evaluated at the given point in time
+    pub fn is_available_at(
+        &self,
+        maintenance_until: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        maintenance_until.is_none_or(|until| until <= now) && self.is_operational_at(now)
+    }
+
+    /// Returns true if the relayer can keep processing transactions already in flight.
+    ///
+    /// Unlike [`Self::is_operational`], this ignores the sunset flag, so the pipeline
+    /// drains existing work on a relayer that is being decommissioned.
+    pub fn accepts_inflight_completion(&self) -> bool {
+        self.accepts_inflight_completion_at(Utc::now())
+    }
+
+    /// Same as [`Self::accepts_inflight_completion`], evaluated at the given point in time
+    pub fn accepts_inflight_completion_at(&self, now: DateTime<Utc>) -> bool {
+        !self.paused
+            && (!self.system_disabled || self.is_quarantine_expired(now))
+            && !matches!(self.circuit_at(now), CircuitState::Open { .. })
+    }
+
+    /// Returns true if an error was recorded after `since`
+    pub fn has_error_since(&self, since: DateTime<Utc>) -> bool {
+        self.last_error
+            .as_ref()
+            .is_some_and(|error| error.at > since)
+    }
+
+    /// Returns true if `len` bytes of calldata fit within the relayer's calldata limit.
+    /// Relayers without a limit accept calldata of any size.
+    pub fn is_calldata_within_limit(&self, len: usize) -> bool {
+        self.policies
+            .max_calldata_bytes()
+            .is_none_or(|limit| len <= limit as usize)
+    }
+
+    /// Custom RPC URLs from highest to lowest priority: by descending weight, then in
+    /// configuration order
+    pub fn ordered_rpc_urls(&self) -> Vec<String> {
+        let mut rpc_urls: Vec<&RpcConfig> = self.custom_rpc_urls.iter().flatten().collect();
+        rpc_urls.sort_by(|a, b| b.weight.cmp(&a.weight));
+        rpc_urls.into_iter().map(|rpc| rpc.url.clone()).collect()
+    }
+
+    /// Last known health of `url`, `Unknown` if none was recorded
+    pub fn rpc_health_of(&self, url: &str) -> RpcHealth {
+        self.rpc_health.get(url).copied().unwrap_or_default()
+    }
+
+    /// Records the health of `url`, which must be one of the relayer's custom RPC URLs
+    pub fn record_rpc_health(&mut self, url: &str, healthy: bool) -> Result<(), RepositoryError> {
+        if !self
+            .custom_rpc_urls
+            .iter()
+            .flatten()
+            .any(|rpc| rpc.url == url)
+        {
+            return Err(RepositoryError::InvalidData(format!(
+                "RPC URL {url} is not configured for relayer {}",
+                self.id
+            )));
+        }
+        let health = if healthy {
+            RpcHealth::Healthy
+        } else {
+            RpcHealth::Unhealthy
+        };
+        self.rpc_health.insert(url.to_string(), health);
+        Ok(())
+    }
+
+    /// Returns the highest-priority custom RPC URL not known to be unhealthy. When
+    /// every URL is unhealthy, falls back to the highest-priority one. Returns `None`
+    /// if the relayer has no custom RPC URLs.
+    pub fn next_healthy_rpc_url(&self) -> Option<String> {
+        let ordered = self.ordered_rpc_urls();
+        ordered
+            .iter()
+            .find(|url| self.rpc_health_of(url) != RpcHealth::Unhealthy)
+            .or_else(|| ordered.first())
+            .cloned()
+    }
+
+    /// Amount by which `balance` falls short of the policy's `min_balance`, or `None`
+    /// if the relayer has no minimum or holds at least that much
+    pub fn funding_deficit(&self, balance: u128) -> Option<i128> {
+        let min_balance = self.policies.min_balance()?;
+        (balance < min_balance).then(|| i128::try_from(min_balance - balance).unwrap_or(i128::MAX))
+    }
+
+    /// Time after which a pending transaction should be dropped rather than
+    /// resubmitted, or `None` if the relayer sets no deadline
+    pub fn transaction_deadline(&self) -> Option<Duration> {
+        self.policies
+            .transaction_deadline_secs()
+            .map(Duration::from_secs)
+    }
+
+    /// Derives the transaction features the relayer supports.
+    ///
+    /// Only EVM relayers support any of them. EIP-1559 pricing is on unless the policy
+    /// disables it, private transactions must be enabled explicitly, and blob
+    /// transactions are available wherever EIP-1559 pricing is.
+    pub fn capabilities(&self) -> RelayerCapabilities {
+        let RelayerNetworkPolicy::Evm(policy) = &self.policies else {
+            return RelayerCapabilities::default();
+        };
+        if self.network_type != NetworkType::Evm {
+            return RelayerCapabilities::default();
+        }
+        let supports_eip1559 = policy
+            .eip1559_pricing
+            .unwrap_or(DEFAULT_EVM_EIP1559_ENABLED);
+        RelayerCapabilities {
+            supports_eip1559,
+            supports_private_tx: policy.private_transactions == Some(true),
+            supports_blobs: supports_eip1559,
+        }
+    }
+
+    /// Returns true if the relayer has a health check interval that has elapsed at `now`.
+    ///
+    /// A relayer that was never checked is due immediately.
+    pub fn is_health_check_due(&self, now: DateTime<Utc>) -> bool {
+        let Some(interval) = self.health_check_interval else {
+            return false;
+        };
+        match self.last_health_check_at {
+            None => true,
+            Some(last) => chrono::Duration::from_std(interval)
+                .ok()
+                .and_then(|interval| last.checked_add_signed(interval))
+                .is_some_and(|due_at| due_at <= now),
+        }
+    }
+
+    /// Returns the effective circuit state at `now`; an open circuit whose
+    /// `until` has elapsed is half-open
+    pub fn circuit_at(&self, now: DateTime<Utc>) -> CircuitState {
+        match self.circuit {
+            CircuitState::Open { until } if until <= now => CircuitState::HalfOpen,
+            circuit => circuit,
+        }
+    }
+
+    /// Applies the outcome of a call made through the circuit at `now`.
+    ///
+    /// A half-open circuit closes on success and opens again on failure; outcomes
+    /// have no effect on closed or open circuits.
+    pub fn record_circuit_outcome(&mut self, success: bool, now: DateTime<Utc>) {
+        if self.circuit_at(now) == CircuitState::HalfOpen {
+            self.circuit = if success {
+                CircuitState::Closed
+            } else {
+                CircuitState::Open {
+                    until: now + chrono::Duration::seconds(RELAYER_CIRCUIT_REOPEN_SECONDS),
+                }
+            };
+        }
+    }
+
+    /// Returns the current coarse operational state of the relayer
+    pub fn state(&self) -> RelayerState {
+        match (self.system_disabled, self.quarantined_until) {
+            (false, _) => RelayerState::Enabled,
+            (true, Some(_)) => RelayerState::Quarantined,
+            (true, None) => RelayerState::Disabled,
+        }
+    }
+
+    /// Returns the relayer's status. See [`RelayerOperationalStatus`] for the precedence between flags.
+    pub fn status(&self) -> RelayerOperationalStatus {
+        self.status_at(Utc::now())
+    }
+
+    /// Same as [`Self::status`], evaluated at the given point in time
+    pub fn status_at(&self, now: DateTime<Utc>) -> RelayerOperationalStatus {
+        if self.sunset {
+            return RelayerOperationalStatus::Sunset;
+        }
+        if self.system_disabled {
+            match self.quarantined_until {
+                None => return RelayerOperationalStatus::Disabled,
+                Some(_) if !self.is_quarantine_expired(now) => {
+                    return RelayerOperationalStatus::Quarantined
+                }
+                // An expired quarantine no longer counts, as in `is_operational`
+                Some(_) => {}
+            }
+        }
+        if self.paused {
+            RelayerOperationalStatus::Paused
+        } else {
+            RelayerOperationalStatus::Operational
+        }
+    }
+
+    /// Tags attached to the relayer
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags().iter().any(|t| t == tag)
+    }
+
+    /// Attaches `tag` unless already present. Returns whether the relayer changed.
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        if self.has_tag(tag) {
+            return false;
+        }
+        self.tags.get_or_insert_with(Vec::new).push(tag.to_string());
+        true
+    }
+
+    /// Detaches `tag` if present. Returns whether the relayer changed.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        if !self.has_tag(tag) {
+            return false;
+        }
+        let tags = self.tags.get_or_insert_with(Vec::new);
+        tags.retain(|t| t != tag);
+        if tags.is_empty() {
+            self.tags = None;
+        }
+        true
+    }
+
+    /// Merges the top-level keys of `patch`, which must be a JSON object, into the
+    /// relayer's metadata. Keys set to `null` in the patch are removed; keys the patch
+    /// does not mention are kept. Metadata that is not an object is replaced.
+    pub fn merge_metadata(&mut self, patch: serde_json::Value) -> Result<(), RepositoryError> {
+        let serde_json::Value::Object(patch) = patch else {
+            return Err(RepositoryError::InvalidData(
+                "Relayer metadata patch must be a JSON object".to_string(),
+            ));
+        };
+        if !self.metadata.is_object() {
+            self.metadata = serde_json::Value::Object(serde_json::Map::new());
+        }
+        if let serde_json::Value::Object(metadata) = &mut self.metadata {
+            for (key, value) in patch {
+                if value.is_null() {
+                    metadata.remove(&key);
+                } else {
+                    metadata.insert(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Trims surrounding whitespace from the relayer ID
+    pub fn normalize_id(&mut self) {
+        let trimmed = self.id.trim();
+        if trimmed.len() != self.id.len() {
+            self.id = trimmed.to_string();
+        }
+    }
+
+    /// Fails with `ConstraintViolation` if the relayer ID does not match `pattern`
+    pub fn validate_id_format(&self, pattern: &Regex) -> Result<(), RepositoryError> {
+        if !pattern.is_match(&self.id) {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Relayer ID {:?} does not match the allowed format {}",
+                self.id,
+                pattern.as_str()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Logging verbosity override for this relayer, `None` meaning the global level applies
+    pub fn log_level(&self) -> Option<LogLevel> {
+        self.log_level
+    }
+
+    /// Logging verbosity for this relayer, falling back to `global` when not overridden
+    pub fn log_level_or(&self, global: LogLevel) -> LogLevel {
+        self.log_level.unwrap_or(global)
+    }
+
+    /// Returns true if `origin` may submit through this relayer. Any origin is allowed
+    /// when `allowed_origins` is unset; otherwise it must match one of the patterns,
+    /// compared case-insensitively, where `*` allows every origin and a leading `*.`
+    /// in the host matches any subdomain (but not the domain itself).
+    pub fn is_origin_allowed(&self, origin: &str) -> bool {
+        let Some(patterns) = &self.allowed_origins else {
+            return true;
+        };
+        let origin = origin.trim_end_matches('/').to_ascii_lowercase();
+        patterns
+            .iter()
+            .any(|pattern| origin_matches(&pattern.to_ascii_lowercase(), &origin))
+    }
+
+    /// Validates that every allowed origin pattern is `*` or an http(s) origin without a
+    /// path, with a wildcard only as the leading label of the host
+    pub fn validate_allowed_origins(&self) -> Result<(), RepositoryError> {
+        for pattern in self.allowed_origins.iter().flatten() {
+            if pattern == "*" {
+                continue;
+            }
+            // Substitute a concrete label for the wildcard so the rest parses as a URL
+            let concrete = pattern.replacen("://*.", "://wildcard.", 1);
+            let valid = !concrete.contains('*')
+                && reqwest::Url::parse(&concrete).is_ok_and(|url| {
+                    matches!(url.scheme(), "http" | "https")
+                        && url.host_str().is_some()
+                        && url.username().is_empty()
+                        && url.path() == "/"
+                        && url.query().is_none()
+                        && url.fragment().is_none()
+                        && !concrete.ends_with('/')
+                });
+            if !valid {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Invalid allowed origin pattern: {pattern}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds `amount` to the relayer's spend for the current window, returning the new
+    /// total. The window restarts, and the total with it, once it is a day old.
+    ///
+    /// Fails with `Conflict`, leaving the model untouched, when the spend would take
+    /// the total past the policy's `max_daily_spend`.
+    pub fn record_spend(
+        &mut self,
+        amount: u128,
+        now: DateTime<Utc>,
+    ) -> Result<u128, RepositoryError> {
+        let window_expired = self.spend_window_start.is_none_or(|start| {
+            now - start >= chrono::Duration::seconds(RELAYER_SPEND_WINDOW_SECONDS)
+        });
+        let spent = if window_expired { 0 } else { self.spent_today };
+        let total = spent.saturating_add(amount);
+        if let Some(cap) = self.policies.max_daily_spend() {
+            if total > cap {
+                return Err(RepositoryError::Conflict(format!(
+                    "Relayer {} daily spend of {total} would exceed the cap of {cap}",
+                    self.id
+                )));
+            }
+        }
+        if window_expired {
+            self.spend_window_start = Some(now);
+        }
+        self.spent_today = total;
+        Ok(total)
+    }
+
+    /// How long the signing layer waits for the relayer's signer, if bounded
+    pub fn signer_timeout(&self) -> Option<Duration> {
+        self.signer_timeout_ms.map(Duration::from_millis)
+    }
+
+    /// Validates that the signer timeout, if set, is between 100ms and 60s
+    pub fn validate_signer_timeout(&self) -> Result<(), RepositoryError> {
+        match self.signer_timeout_ms {
+            Some(ms)
+                if !(MIN_RELAYER_SIGNER_TIMEOUT_MS..=MAX_RELAYER_SIGNER_TIMEOUT_MS)
+                    .contains(&ms) =>
+            {
+                Err(RepositoryError::InvalidData(format!(
+                    "signer_timeout_ms must be between {MIN_RELAYER_SIGNER_TIMEOUT_MS} and \
+                     {MAX_RELAYER_SIGNER_TIMEOUT_MS}, got {ms}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Signature scheme the relayer signs with, defaulting to the one of its network type
+    pub fn signature_scheme(&self) -> SignatureScheme {
+        self.signature_scheme
+            .unwrap_or_else(|| SignatureScheme::default_for(self.network_type))
+    }
+
+    /// Fails with `InvalidData` if the selected signature scheme cannot be used on the
+    /// relayer's network type
+    pub fn validate_signature_scheme(&self) -> Result<(), RepositoryError> {
+        match self.signature_scheme {
+            Some(scheme) if !scheme.supports(self.network_type) => {
+                Err(RepositoryError::InvalidData(format!(
+                    "Signature scheme {scheme:?} is not supported on {} relayers",
+                    self.network_type
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Fails with `ConstraintViolation` if the serialized metadata exceeds `max_bytes`
+    pub fn validate_metadata_size(&self, max_bytes: usize) -> Result<(), RepositoryError> {
+        let size = serde_json::to_vec(&self.metadata)
+            .map_err(|e| RepositoryError::InvalidData(format!("Invalid relayer metadata: {e}")))?
+            .len();
+        if size > max_bytes {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Relayer metadata is {size} bytes, exceeding the limit of {max_bytes} bytes"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fails with `Conflict` unless the relayer is at `expected` version
+    pub fn check_version(&self, expected: u64) -> Result<(), RepositoryError> {
+        if self.version != expected {
+            return Err(RepositoryError::Conflict(format!(
+                "Relayer {} is at version {}, expected {expected}",
+                self.id, self.version
+            )));
+        }
+        Ok(())
+    }
+
+    /// Pauses the relayer if it is not `allowed`, or resumes it if it is allowed and
+    /// was paused by a previous enforcement. Relayers paused by hand are left alone.
+    ///
+    /// # Returns
+    /// `true` if the paused state changed, `false` otherwise
+    pub fn enforce_allowlist(&mut self, allowed: bool) -> bool {
+        if !allowed && !self.paused {
+            self.paused = true;
+            self.paused_by_enforcement = true;
+            true
+        } else if allowed && self.paused && self.paused_by_enforcement {
+            self.paused = false;
+            self.paused_by_enforcement = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Appends a transition from `from` to the current state to the status history,
+    /// dropping the oldest entries so at most `limit` are kept.
+    pub fn record_status_change(&mut self, from: RelayerState, limit: usize) {
+        self.status_history.push_back(StatusChange {
+            from,
+            to: self.state(),
+            at: Utc::now(),
+            reason: self.disabled_reason.clone(),
+        });
+        while self.status_history.len() > limit {
+            self.status_history.pop_front();
+        }
+    }
+
+    /// Moves the relayer to `new_tenant` and appends the transfer to the ownership
+    /// history, dropping the oldest entries so at most `limit` are kept.
+    pub fn transfer_ownership(
+        &mut self,
+        new_tenant: String,
+        actor: String,
+        limit: usize,
+    ) -> Result<(), RepositoryError> {
+        if new_tenant.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Target tenant ID cannot be empty".to_string(),
+            ));
+        }
+        if actor.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Transfer actor cannot be empty".to_string(),
+            ));
+        }
+        if new_tenant == self.tenant_id {
+            return Err(RepositoryError::InvalidData(format!(
+                "Relayer {} already belongs to tenant {new_tenant}",
+                self.id
+            )));
+        }
+        let from_tenant = std::mem::replace(&mut self.tenant_id, new_tenant.clone());
+        self.ownership_history.push_back(OwnershipTransfer {
+            from_tenant,
+            to_tenant: new_tenant,
+            actor,
+            at: Utc::now(),
+        });
+        while self.ownership_history.len() > limit {
+            self.ownership_history.pop_front();
+        }
+        Ok(())
+    }
+
+    /// Appends an operation outcome to the recent outcomes, dropping the oldest
+    /// entries so at most `limit` are kept.
+    pub fn record_recent_outcome(&mut self, success: bool, at: DateTime<Utc>, limit: usize) {
+        self.recent_outcomes
+            .push_back(RecordedOutcome { at, success });
+        while self.recent_outcomes.len() > limit {
+            self.recent_outcomes.pop_front();
+        }
+    }
+
+    /// Returns the fraction of recent outcomes at or after `since` that were failures,
+    /// or `None` if no outcome was recorded in that window.
+    pub fn error_rate_since(&self, since: DateTime<Utc>) -> Option<f64> {
+        let (total, failures) = self
+            .recent_outcomes
+            .iter()
+            .filter(|outcome| outcome.at >= since)
+            .fold((0usize, 0usize), |(total, failures), outcome| {
+                (total + 1, failures + usize::from(!outcome.success))
+            });
+        (total > 0).then(|| failures as f64 / total as f64)
+    }
+
+    /// Returns the versioned part of the relayer configuration
+    pub fn config_snapshot(&self) -> RelayerConfigSnapshot {
+        RelayerConfigSnapshot {
+            name: self.name.clone(),
+            network: self.network.clone(),
+            paused: self.paused,
+            policies: self.policies.clone(),
+            notification_id: self.notification_id.clone(),
+            custom_rpc_urls: self.custom_rpc_urls.clone(),
+            extra_webhook_urls: self.extra_webhook_urls.clone(),
+            hard_gas_price_ceiling: self.hard_gas_price_ceiling,
+            pool: self.pool.clone(),
+            derivation_path: self.derivation_path.clone(),
+        }
+    }
+
+    /// Appends the current configuration as a new version if it differs from the
+    /// latest recorded one, dropping the oldest versions so at most `limit` are kept.
+    pub fn record_config_version(&mut self, limit: usize) {
+        let config = self.config_snapshot();
+        let latest = self.config_versions.back();
+        if latest.is_some_and(|latest| latest.config == config) {
+            return;
+        }
+        let version = latest.map_or(1, |latest| latest.version + 1);
+        self.config_versions.push_back(RelayerConfigVersion {
+            version,
+            at: Utc::now(),
+            config,
+        });
+        while self.config_versions.len() > limit {
+            self.config_versions.pop_front();
+        }
+    }
+
+    /// Returns the version numbers and timestamps of the recorded configuration versions
+    pub fn config_version_infos(&self) -> Vec<RelayerConfigVersionInfo> {
+        self.config_versions
+            .iter()
+            .map(|version| RelayerConfigVersionInfo {
+                version: version.version,
+                at: version.at,
+            })
+            .collect()
+    }
+
+    /// Restores the configuration recorded as `version`, keeping runtime state.
+    ///
+    /// The restored configuration is recorded as a new version, so a rollback can
+    /// itself be rolled back.
+    pub fn restore_config_version(
+        &mut self,
+        version: u32,
+        limit: usize,
+    ) -> Result<(), RepositoryError> {
+        let config = self
+            .config_versions
+            .iter()
+            .find(|recorded| recorded.version == version)
+            .map(|recorded| recorded.config.clone())
+            .ok_or_else(|| {
+                RepositoryError::NotFound(format!(
+                    "Config version {version} of relayer {} not found",
+                    self.id
+                ))
+            })?;
+        self.name = config.name;
+        self.network = config.network;
+        self.paused = config.paused;
+        self.policies = config.policies;
+        self.notification_id = config.notification_id;
+        self.custom_rpc_urls = config.custom_rpc_urls;
+        self.extra_webhook_urls = config.extra_webhook_urls;
+        self.hard_gas_price_ceiling = config.hard_gas_price_ceiling;
+        self.pool = config.pool;
+        self.derivation_path = config.derivation_path;
+        self.record_config_version(limit);
+        Ok(())
+    }
+
+    /// Applies the repository-level fields of a partial update (paused, notification_id, name).
+    /// A `notification_id` of `Some(None)` removes the relayer's notification.
+    ///
+    /// `updated_at` is only bumped when at least one field actually changes value.
+    ///
+    /// # Returns
+    /// `true` if the model was modified, `false` otherwise
+    pub fn apply_partial_update(&mut self, update: UpdateRelayerRequest) -> bool {
+        let mut changed = false;
+        if let Some(paused) = update.paused {
+            changed |= self.paused != paused;
+            self.paused = paused;
+            // A manual pause or resume takes the relayer out of allowlist enforcement
+            self.paused_by_enforcement = false;
+        }
+        if let Some(notification_id) = update.notification_id {
+            changed |= self.notification_id != notification_id;
+            self.notification_id = notification_id;
+        }
+        if let Some(name) = update.name {
+            changed |= self.name != name;
+            self.name = name;
+        }
+        if changed {
+            self.updated_at = Some(Utc::now().to_rfc3339());
+        }
+        changed
+    }
+
+    /// Computes a stable checksum over the config-relevant fields of the relayer.
+    ///
+    /// Runtime fields (address, system_disabled, disabled_reason, updated_at,
+    /// quarantine, alert state, scheduled disables, histories, health, spend and
+    /// version counters), the canary/live mode and integrator metadata are excluded,
+    /// so the checksum only changes when the declared configuration changes.
+    pub fn config_checksum(&self) -> u64 {
+        let fingerprint = RelayerConfigFingerprint {
+            id: &self.id,
+            name: &self.name,
+            network: &self.network,
+            paused: self.paused,
+            network_type: &self.network_type,
+            signer_id: &self.signer_id,
+            policies: &self.policies,
+            notification_id: &self.notification_id,
+            custom_rpc_urls: &self.custom_rpc_urls,
+            extra_webhook_urls: &self.extra_webhook_urls,
+            tenant_id: &self.tenant_id,
+            hard_gas_price_ceiling: self.hard_gas_price_ceiling,
+            pool: &self.pool,
+            derivation_path: &self.derivation_path,
+            aliases: &self.aliases,
+            standby_for: &self.standby_for,
+            health_check_interval: self.health_check_interval,
+            inherits_from: &self.inherits_from,
+            fee_account: &self.fee_account,
+            chain_id: self.chain_id,
+            tags: &self.tags,
+            signature_scheme: self.signature_scheme,
+            nonce_group: &self.nonce_group,
+            log_level: self.log_level,
+            allowed_origins: &self.allowed_origins,
+            signer_timeout_ms: self.signer_timeout_ms,
+        };
+        // Serializing a struct with a fixed field order keeps the encoding stable across runs
+        let encoded = serde_json::to_vec(&fingerprint).unwrap_or_default();
+        let digest = Sha256::digest(&encoded);
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        u64::from_be_bytes(bytes)
+    }
+}
+
+/// Config-relevant view of a relayer used to compute [`RelayerRepoModel::config_checksum`]
+#[derive(Serialize)]
+struct RelayerConfigFingerprint<'a> {
+    id: &'a str,
+    name: &'a str,
+    network: &'a str,
+    paused: bool,
+    network_type: &'a NetworkType,
+    signer_id: &'a str,
+    policies: &'a RelayerNetworkPolicy,
+    notification_id: &'a Option<String>,
+    custom_rpc_urls: &'a Option<Vec<RpcConfig>>,
+    extra_webhook_urls: &'a Option<Vec<String>>,
+    tenant_id: &'a str,
+    #[serde(serialize_with = "serialize_optional_u128")]
+    hard_gas_price_ceiling: Option<u128>,
+    pool: &'a Option<String>,
+    derivation_path: &'a Option<String>,
+    aliases: &'a [String],
+    standby_for: &'a Option<String>,
+    health_check_interval: Option<Duration>,
+    inherits_from: &'a Option<String>,
+    fee_account: &'a Option<FeeAccount>,
+    chain_id: Option<u64>,
+    tags: &'a Option<Vec<String>>,
+    signature_scheme: Option<SignatureScheme>,
+    nonce_group: &'a Option<String>,
+    log_level: Option<LogLevel>,
+    allowed_origins: &'a Option<Vec<String>>,
+    signer_timeout_ms: Option<u64>,
+}
+
+/// Shared relayer definition used to instantiate many relayers that differ only
+/// in their identity (id, signer and address)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelayerTemplate {
+    pub name: String,
+    pub network: String,
+    pub paused: bool,
+    pub network_type: NetworkType,
+    pub policies: RelayerNetworkPolicy,
+    pub notification_id: Option<String>,
+    pub custom_rpc_urls: Option<Vec<RpcConfig>>,
+    pub extra_webhook_urls: Option<Vec<String>>,
+}
+
+impl RelayerTemplate {
+    /// Builds a repository model from the template with the given per-instance fields
+    pub fn to_repo_model(
+        &self,
+        id: String,
+        signer_id: String,
+        address: String,
+    ) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id,
+            name: self.name.clone(),
+            network: self.network.clone(),
+            paused: self.paused,
+            network_type: self.network_type,
+            signer_id,
+            policies: self.policies.clone(),
+            address,
+            notification_id: self.notification_id.clone(),
+            custom_rpc_urls: self.custom_rpc_urls.clone(),
+            extra_webhook_urls: self.extra_webhook_urls.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Lightweight import shape used to provision relayers in bulk, e.g. one row of
+/// an onboarding spreadsheet.
+///
+/// Besides the relayer's identity it carries the versioned configuration
+/// ([`RelayerConfigSnapshot`]), so exporting relayers as descriptors and provisioning
+/// from them reproduces their configuration. Runtime state is not carried.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelayerDescriptor {
+    pub id: String,
+    pub name: String,
+    pub network: String,
+    pub network_type: NetworkType,
+    pub signer_id: String,
+    pub address: String,
+    /// Network policy; the default policy for the network type is used when omitted
+    #[serde(default)]
+    pub policies: Option<RelayerNetworkPolicy>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub paused: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_rpc_urls: Option<Vec<RpcConfig>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_webhook_urls: Option<Vec<String>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128"
+    )]
+    pub hard_gas_price_ceiling: Option<u128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub derivation_path: Option<String>,
+}
+
+impl RelayerDescriptor {
+    /// Builds a repository model from the descriptor
+    pub fn to_repo_model(&self) -> RelayerRepoModel {
+        let mut relayer = RelayerRepoModel::from(Relayer {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            network: self.network.clone(),
+            paused: self.paused,
+            network_type: self.network_type,
+            policies: self.policies.clone(),
+            signer_id: self.signer_id.clone(),
+            notification_id: self.notification_id.clone(),
+            custom_rpc_urls: self.custom_rpc_urls.clone(),
+        });
+        relayer.address = self.address.clone();
+        relayer.extra_webhook_urls = self.extra_webhook_urls.clone();
+        relayer.hard_gas_price_ceiling = self.hard_gas_price_ceiling;
+        relayer.pool = self.pool.clone();
+        relayer.derivation_path = self.derivation_path.clone();
+        relayer
+    }
+
+    /// Returns every violation found in the descriptor, empty if it is valid
+    pub fn validation_errors(&self) -> Vec<RelayerValidationError> {
+        let relayer = self.to_repo_model();
+        let mut errors = relayer.validation_errors();
+        if !self.network_type.is_valid_address(&self.address) {
+            errors.push(RelayerValidationError::InvalidField(format!(
+                "Invalid {} address: {}",
+                self.network_type, self.address
+            )));
+        }
+        errors.extend(
+            relayer
+                .policies
+                .validation_errors()
+                .into_iter()
+                .map(|e| RelayerValidationError::InvalidPolicy(e.to_string())),
+        );
+        errors
+    }
+}
+
+impl From<&RelayerRepoModel> for RelayerDescriptor {
+    fn from(relayer: &RelayerRepoModel) -> Self {
+        let config = relayer.config_snapshot();
+        Self {
+            id: relayer.id.clone(),
+            name: config.name,
+            network: config.network,
+            network_type: relayer.network_type,
+            signer_id: relayer.signer_id.clone(),
+            address: relayer.address.clone(),
+            policies: Some(config.policies),
+            paused: config.paused,
+            notification_id: config.notification_id,
+            custom_rpc_urls: config.custom_rpc_urls,
+            extra_webhook_urls: config.extra_webhook_urls,
+            hard_gas_price_ceiling: config.hard_gas_price_ceiling,
+            pool: config.pool,
+            derivation_path: config.derivation_path,
+        }
+    }
+}
+
+/// How a bulk provisioning request is applied
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvisionMode {
+    /// Create every relayer, or none of them if any descriptor is invalid
+    #[default]
+    Strict,
+    /// Validate every descriptor and report the outcome without creating anything
+    DryRun,
+}
+
+/// Violations found in a single descriptor of a bulk provisioning request
+#[derive(Debug)]
+pub struct ProvisionRowError {
+    /// Position of the descriptor in the request
+    pub row: usize,
+    pub id: String,
+    pub errors: Vec<RelayerValidationError>,
+}
+
+/// Outcome of a bulk provisioning request
+#[derive(Debug, Default)]
+pub struct ProvisionReport {
+    /// Ids of the relayers created, or that would be created in dry-run mode
+    pub created: Vec<String>,
+    /// Descriptors that failed validation
+    pub errors: Vec<ProvisionRowError>,
+}
+
+impl ProvisionReport {
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Outcome of a bulk delete, in request order
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DeleteReport {
+    /// Ids of the relayers deleted
+    pub deleted: Vec<String>,
+    /// Ids that did not match a stored relayer, including repeats of an id already deleted
+    pub not_found: Vec<String>,
+}
+
+/// Fleet-wide outcome of running relayer validation, grouped by failure kind
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PolicyValidationReport {
+    /// Number of relayers validated
+    pub checked: usize,
+    /// Sorted ids of the failing relayers, keyed by `RelayerValidationError::kind`.
+    /// A relayer failing in several categories is listed under each of them.
+    pub failures: BTreeMap<&'static str, Vec<String>>,
+}
+
+impl PolicyValidationReport {
+    /// Builds the report from every relayer's validation errors
+    pub fn from_relayers<'a>(relayers: impl IntoIterator<Item = &'a RelayerRepoModel>) -> Self {
+        let mut report = Self::default();
+        for relayer in relayers {
+            report.checked += 1;
+            for error in relayer.validation_errors() {
+                let ids = report.failures.entry(error.kind()).or_default();
+                if !ids.contains(&relayer.id) {
+                    ids.push(relayer.id.clone());
+                }
+            }
+        }
+        for ids in report.failures.values_mut() {
+            ids.sort();
+        }
+        report
+    }
+
+    /// Number of relayers failing with the given kind
+    pub fn count(&self, kind: &str) -> usize {
+        self.failures.get(kind).map_or(0, Vec::len)
+    }
+
+    /// Ids of the relayers failing with the given kind
+    pub fn ids(&self, kind: &str) -> &[String] {
+        self.failures.get(kind).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Progress of a relayer decommission
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecommissionState {
+    /// The relayer is sunset and still has `remaining` transactions in flight
+    Draining { remaining: u64 },
+    /// The relayer was drained and deleted
+    Decommissioned,
+}
+
+/// Outcome of enforcing an allowlist of relayer ids, sorted by id
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EnforceReport {
+    /// Ids of the relayers paused because they are not on the allowlist
+    pub paused: Vec<String>,
+    /// Ids of allowlisted relayers resumed after a previous enforcement paused them
+    pub resumed: Vec<String>,
+}
+
+/// How much attention a lint warning deserves
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Info,
+    Warning,
+}
+
+/// Non-fatal finding about a relayer's configuration, for advisory display
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LintWarning {
+    pub severity: LintSeverity,
+    /// Path of the offending field, such as `policies.min_balance`
+    pub field: String,
+    pub message: String,
+}
+
+impl LintWarning {
+    pub fn new(severity: LintSeverity, field: &str, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Default for RelayerRepoModel {
+    fn default() -> Self {
+        Self {
+            id: "".to_string(),
+            name: "".to_string(),
+            network: "".to_string(),
+            paused: false,
+            network_type: NetworkType::Evm,
+            signer_id: "".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0x".to_string(),
+            notification_id: None,
+            system_disabled: false,
+            disabled_reason: None,
+            custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: default_tenant_id(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
+            version: 0,
+        }
+    }
+}
+
+impl From<RelayerRepoModel> for Relayer {
+    fn from(repo_model: RelayerRepoModel) -> Self {
+        Self {
+            id: repo_model.id,
+            name: repo_model.name,
+            network: repo_model.network,
+            paused: repo_model.paused,
+            network_type: repo_model.network_type,
+            policies: Some(repo_model.policies),
+            signer_id: repo_model.signer_id,
+            notification_id: repo_model.notification_id,
+            custom_rpc_urls: repo_model.custom_rpc_urls,
+        }
+    }
+}
+
+impl From<Relayer> for RelayerRepoModel {
+    fn from(relayer: Relayer) -> Self {
+        Self {
+            id: relayer.id,
+            name: relayer.name,
+            network: relayer.network,
+            paused: relayer.paused,
+            network_type: relayer.network_type,
+            signer_id: relayer.signer_id,
+            policies: relayer.policies.unwrap_or_else(|| {
+                // Default policy based on network type
+                match relayer.network_type {
+                    RelayerNetworkType::Evm => {
+                        RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())
+                    }
+                    RelayerNetworkType::Solana => {
+                        RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default())
+                    }
+                    RelayerNetworkType::Stellar => {
+                        RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default())
+                    }
+                }
+            }),
+            address: "".to_string(), // Will be filled in later by process_relayers
+            notification_id: relayer.notification_id,
+            system_disabled: false,
+            disabled_reason: None,
+            custom_rpc_urls: relayer.custom_rpc_urls,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: default_tenant_id(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
+            version: 0,
+        }
+    }
+}
+
+/// Matches a lowercased `origin` against a lowercased allowed-origin pattern
+fn origin_matches(pattern: &str, origin: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    match pattern.split_once("://*.") {
+        Some((scheme, domain)) => origin
+            .strip_prefix(scheme)
+            .and_then(|rest| rest.strip_prefix("://"))
+            .and_then(|host| host.strip_suffix(domain))
+            .and_then(|subdomain| subdomain.strip_suffix('.'))
+            .is_some_and(|subdomain| !subdomain.is_empty() && !subdomain.contains(['/', ':', '@'])),
+        None => pattern == origin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{
+        AlertThresholds, GasOracleConfig, RelayerEvmPolicy, RelayerSolanaPolicy,
+        RelayerStellarPolicy, RevertHandling, SolanaAllowedTokensPolicy, SolanaFeePaymentStrategy,
+        StellarFeePaymentStrategy, StuckTxAction, TransactionRule,
+    };
+
+    use super::*;
+    use std::collections::HashMap;
+
+    fn create_test_relayer(paused: bool, system_disabled: bool) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: "test_relayer".to_string(),
+            name: "Test Relayer".to_string(),
+            paused,
+            system_disabled,
+            disabled_reason: None,
+            network: "test_network".to_string(),
+            network_type: NetworkType::Evm,
+            signer_id: "test_signer".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0xtest".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: "test-tenant".to_string(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            version: 0,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
+        }
+    }
+
+    fn create_test_relayer_solana(paused: bool, system_disabled: bool) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: "test_solana_relayer".to_string(),
+            name: "Test Solana Relayer".to_string(),
+            paused,
+            system_disabled,
+            network: "mainnet".to_string(),
+            network_type: NetworkType::Solana,
+            signer_id: "test_signer".to_string(),
+            policies: RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+                fee_payment_strategy: Some(SolanaFeePaymentStrategy::Relayer),
+                min_balance: Some(1000000),
+                max_signatures: Some(5),
+                allowed_tokens: None,
+                allowed_programs: None,
+                allowed_accounts: None,
+                disallowed_accounts: None,
+                max_tx_data_size: None,
+                max_allowed_fee_lamports: None,
+                swap_config: None,
+                fee_margin_percentage: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
+            }),
+            address: "SolanaAddress123".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+            ..Default::default()
+        }
+    }
+
+    fn create_test_relayer_stellar(paused: bool, system_disabled: bool) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: "test_stellar_relayer".to_string(),
+            name: "Test Stellar Relayer".to_string(),
+            paused,
+            system_disabled,
+            network: "mainnet".to_string(),
+            network_type: NetworkType::Stellar,
+            signer_id: "test_signer".to_string(),
+            policies: RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+                min_balance: Some(20000000),
+                max_fee: Some(100000),
+                timeout_seconds: Some(30),
+                concurrent_transactions: None,
+                allowed_tokens: None,
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
+                slippage_percentage: None,
+                fee_margin_percentage: None,
+                swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
+            }),
+            address: "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_active_state_success() {
+        let relayer = create_test_relayer(false, false);
+        assert!(relayer.validate_active_state().is_ok());
+    }
+
+    #[test]
+    fn test_validate_active_state_success_solana() {
+        let relayer = create_test_relayer_solana(false, false);
+        assert!(relayer.validate_active_state().is_ok());
+    }
+
+    #[test]
+    fn test_validate_active_state_success_stellar() {
+        let relayer = create_test_relayer_stellar(false, false);
+        assert!(relayer.validate_active_state().is_ok());
+    }
+
+    #[test]
+    fn test_validate_active_state_paused() {
+        let relayer = create_test_relayer(true, false);
+        let result = relayer.validate_active_state();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    }
+
+    #[test]
+    fn test_validate_active_state_paused_solana() {
+        let relayer = create_test_relayer_solana(true, false);
+        let result = relayer.validate_active_state();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    }
+
+    #[test]
+    fn test_validate_active_state_paused_stellar() {
+        let relayer = create_test_relayer_stellar(true, false);
+        let result = relayer.validate_active_state();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    }
+
+    #[test]
+    fn test_validate_active_state_disabled() {
+        let relayer = create_test_relayer(false, true);
+        let result = relayer.validate_active_state();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerDisabled));
+    }
+
+    #[test]
+    fn test_validate_active_state_disabled_solana() {
+        let relayer = create_test_relayer_solana(false, true);
+        let result = relayer.validate_active_state();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerDisabled));
+    }
+
+    #[test]
+    fn test_validate_active_state_disabled_stellar() {
+        let relayer = create_test_relayer_stellar(false, true);
+        let result = relayer.validate_active_state();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerDisabled));
+    }
+
+    #[test]
+    fn test_validate_active_state_sunset() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.sunset = true;
+        let result = relayer.validate_active_state();
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerSunset));
+    }
+
+    #[test]
+    fn test_validate_active_state_both_paused_and_disabled() {
+        // When both are true, should return paused error (checked first)
+        let relayer = create_test_relayer(true, true);
+        let result = relayer.validate_active_state();
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    }
+
+    #[test]
+    fn test_conversion_from_repo_model_to_domain_evm() {
+        let repo_model = create_test_relayer(false, false);
+        let domain_relayer = Relayer::from(repo_model.clone());
+
+        assert_eq!(domain_relayer.id, repo_model.id);
+        assert_eq!(domain_relayer.name, repo_model.name);
+        assert_eq!(domain_relayer.network, repo_model.network);
+        assert_eq!(domain_relayer.paused, repo_model.paused);
+        assert_eq!(domain_relayer.network_type, repo_model.network_type);
+        assert_eq!(domain_relayer.signer_id, repo_model.signer_id);
+        assert_eq!(domain_relayer.notification_id, repo_model.notification_id);
+        assert_eq!(domain_relayer.custom_rpc_urls, repo_model.custom_rpc_urls);
+
+        // Policies should be converted correctly
+        assert!(domain_relayer.policies.is_some());
+        if let Some(RelayerNetworkPolicy::Evm(_)) = domain_relayer.policies {
+            // Success - correct policy type
+        } else {
+            panic!("Expected EVM policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_repo_model_to_domain_solana() {
+        let repo_model = create_test_relayer_solana(false, false);
+        let domain_relayer = Relayer::from(repo_model.clone());
+
+        assert_eq!(domain_relayer.id, repo_model.id);
+        assert_eq!(domain_relayer.network_type, RelayerNetworkType::Solana);
+
+        // Policies should be converted correctly
+        assert!(domain_relayer.policies.is_some());
+        if let Some(RelayerNetworkPolicy::Solana(solana_policy)) = domain_relayer.policies {
+            assert_eq!(solana_policy.min_balance, Some(1000000));
+            assert_eq!(solana_policy.max_signatures, Some(5));
+            assert_eq!(
+                solana_policy.fee_payment_strategy,
+                Some(SolanaFeePaymentStrategy::Relayer)
+            );
+        } else {
+            panic!("Expected Solana policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_repo_model_to_domain_stellar() {
+        let repo_model = create_test_relayer_stellar(false, false);
+        let domain_relayer = Relayer::from(repo_model.clone());
+
+        assert_eq!(domain_relayer.id, repo_model.id);
+        assert_eq!(domain_relayer.network_type, RelayerNetworkType::Stellar);
+
+        // Policies should be converted correctly
+        assert!(domain_relayer.policies.is_some());
+        if let Some(RelayerNetworkPolicy::Stellar(stellar_policy)) = domain_relayer.policies {
+            assert_eq!(stellar_policy.min_balance, Some(20000000));
+            assert_eq!(stellar_policy.max_fee, Some(100000));
+            assert_eq!(stellar_policy.timeout_seconds, Some(30));
+        } else {
+            panic!("Expected Stellar policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_domain_to_repo_model_evm() {
+        let domain_relayer = Relayer {
+            id: "test_evm_relayer".to_string(),
+            name: "Test EVM Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Evm,
+            policies: Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_price_cap: Some(100_000_000_000),
+                eip1559_pricing: Some(true),
+                min_balance: None,
+                gas_limit_estimation: None,
+                whitelist_receivers: None,
+                private_transactions: None,
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
+            })),
+            signer_id: "test_signer".to_string(),
+            notification_id: Some("notification_123".to_string()),
+            custom_rpc_urls: None,
+        };
+
+        let repo_model = RelayerRepoModel::from(domain_relayer.clone());
+
+        assert_eq!(repo_model.id, domain_relayer.id);
+        assert_eq!(repo_model.name, domain_relayer.name);
+        assert_eq!(repo_model.network, domain_relayer.network);
+        assert_eq!(repo_model.paused, domain_relayer.paused);
+        assert_eq!(repo_model.network_type, domain_relayer.network_type);
+        assert_eq!(repo_model.signer_id, domain_relayer.signer_id);
+        assert_eq!(repo_model.notification_id, domain_relayer.notification_id);
+        assert_eq!(repo_model.custom_rpc_urls, domain_relayer.custom_rpc_urls);
+
+        // Runtime fields should have default values
+        assert_eq!(repo_model.address, "");
+        assert!(!repo_model.system_disabled);
+
+        // Policies should be converted correctly
+        if let RelayerNetworkPolicy::Evm(evm_policy) = repo_model.policies {
+            assert_eq!(evm_policy.gas_price_cap, Some(100_000_000_000));
+            assert_eq!(evm_policy.eip1559_pricing, Some(true));
+        } else {
+            panic!("Expected EVM policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_domain_to_repo_model_solana() {
+        let domain_relayer = Relayer {
+            id: "test_solana_relayer".to_string(),
+            name: "Test Solana Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Solana,
+            policies: Some(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+                fee_payment_strategy: Some(SolanaFeePaymentStrategy::User),
+                min_balance: Some(5000000),
+                max_signatures: Some(8),
+                allowed_tokens: Some(vec![SolanaAllowedTokensPolicy::new(
+                    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+                    Some(100000),
+                    None,
+                )]),
+                allowed_programs: None,
+                allowed_accounts: None,
+                disallowed_accounts: None,
+                max_tx_data_size: None,
+                max_allowed_fee_lamports: None,
+                swap_config: None,
+                fee_margin_percentage: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
+            })),
+            signer_id: "test_signer".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        let repo_model = RelayerRepoModel::from(domain_relayer.clone());
+
+        assert_eq!(repo_model.network_type, RelayerNetworkType::Solana);
+
+        // Policies should be converted correctly
+        if let RelayerNetworkPolicy::Solana(solana_policy) = repo_model.policies {
+            assert_eq!(
+                solana_policy.fee_payment_strategy,
+                Some(SolanaFeePaymentStrategy::User)
+            );
+            assert_eq!(solana_policy.min_balance, Some(5000000));
+            assert_eq!(solana_policy.max_signatures, Some(8));
+            assert!(solana_policy.allowed_tokens.is_some());
+        } else {
+            panic!("Expected Solana policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_domain_to_repo_model_stellar() {
+        let domain_relayer = Relayer {
+            id: "test_stellar_relayer".to_string(),
+            name: "Test Stellar Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Stellar,
+            policies: Some(RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+                min_balance: Some(30000000),
+                max_fee: Some(150000),
+                timeout_seconds: Some(60),
+                concurrent_transactions: None,
+                allowed_tokens: None,
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
+                slippage_percentage: None,
+                fee_margin_percentage: None,
+                swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
+            })),
+            signer_id: "test_signer".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        let repo_model = RelayerRepoModel::from(domain_relayer.clone());
+
+        assert_eq!(repo_model.network_type, RelayerNetworkType::Stellar);
+
+        // Policies should be converted correctly
+        if let RelayerNetworkPolicy::Stellar(stellar_policy) = repo_model.policies {
+            assert_eq!(stellar_policy.min_balance, Some(30000000));
+            assert_eq!(stellar_policy.max_fee, Some(150000));
+            assert_eq!(stellar_policy.timeout_seconds, Some(60));
+        } else {
+            panic!("Expected Stellar policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_domain_with_no_policies_evm() {
+        let domain_relayer = Relayer {
+            id: "test_evm_relayer".to_string(),
+            name: "Test EVM Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Evm,
+            policies: None, // No policies provided
+            signer_id: "test_signer".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        let repo_model = RelayerRepoModel::from(domain_relayer);
+
+        // Should create default EVM policy
+        if let RelayerNetworkPolicy::Evm(evm_policy) = repo_model.policies {
+            // Default EVM policy should have all None values
+            assert_eq!(evm_policy.gas_price_cap, None);
+            assert_eq!(evm_policy.eip1559_pricing, None);
+            assert_eq!(evm_policy.min_balance, None);
+            assert_eq!(evm_policy.gas_limit_estimation, None);
+            assert_eq!(evm_policy.whitelist_receivers, None);
+            assert_eq!(evm_policy.private_transactions, None);
+        } else {
+            panic!("Expected default EVM policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_domain_with_no_policies_solana() {
+        let domain_relayer = Relayer {
+            id: "test_solana_relayer".to_string(),
+            name: "Test Solana Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Solana,
+            policies: None, // No policies provided
+            signer_id: "test_signer".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        let repo_model = RelayerRepoModel::from(domain_relayer);
+
+        // Should create default Solana policy
+        if let RelayerNetworkPolicy::Solana(solana_policy) = repo_model.policies {
+            // Default Solana policy should have all None values
+            assert_eq!(solana_policy.fee_payment_strategy, None);
+            assert_eq!(solana_policy.min_balance, None);
+            assert_eq!(solana_policy.max_signatures, None);
+            assert_eq!(solana_policy.allowed_tokens, None);
+            assert_eq!(solana_policy.allowed_programs, None);
+            assert_eq!(solana_policy.allowed_accounts, None);
+            assert_eq!(solana_policy.disallowed_accounts, None);
+            assert_eq!(solana_policy.max_tx_data_size, None);
+            assert_eq!(solana_policy.max_allowed_fee_lamports, None);
+            assert_eq!(solana_policy.swap_config, None);
+            assert_eq!(solana_policy.fee_margin_percentage, None);
+        } else {
+            panic!("Expected default Solana policy");
+        }
+    }
+
+    #[test]
+    fn test_conversion_from_domain_with_no_policies_stellar() {
+        let domain_relayer = Relayer {
+            id: "test_stellar_relayer".to_string(),
+            name: "Test Stellar Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Stellar,
+            policies: None, // No policies provided
+            signer_id: "test_signer".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        let repo_model = RelayerRepoModel::from(domain_relayer);
+
+        // Should create default Stellar policy
+        if let RelayerNetworkPolicy::Stellar(stellar_policy) = repo_model.policies {
+            // Default Stellar policy should have all None values
+            assert_eq!(stellar_policy.min_balance, None);
+            assert_eq!(stellar_policy.max_fee, None);
+            assert_eq!(stellar_policy.timeout_seconds, None);
+        } else {
+            panic!("Expected default Stellar policy");
+        }
+    }
+
+    #[test]
+    fn test_relayer_repo_updater_preserves_runtime_fields() {
+        // Create an original relayer with runtime fields set
+        let original = RelayerRepoModel {
+            id: "test_relayer".to_string(),
+            name: "Original Name".to_string(),
+            address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(), // Runtime field
+            system_disabled: true,                                             // Runtime field
+            disabled_reason: Some(DisabledReason::BalanceCheckFailed(
+                "Balance too low".to_string(),
+            )), // Runtime field
+            paused: false,
+            network: "mainnet".to_string(),
+            network_type: NetworkType::Evm,
+            signer_id: "test_signer".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            notification_id: None,
+            custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: "test-tenant".to_string(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            version: 0,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
+        };
+
+        // Create a domain model with different business fields
+        let domain_update = Relayer {
+            id: "test_relayer".to_string(),
+            name: "Updated Name".to_string(), // Changed
+            paused: true,                     // Changed
+            network: "mainnet".to_string(),
+            network_type: RelayerNetworkType::Evm,
+            signer_id: "test_signer".to_string(),
+            policies: Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())),
+            notification_id: Some("new_notification".to_string()), // Changed
+            custom_rpc_urls: None,
+        };
+
+        // Use updater to preserve runtime fields
+        let updated =
+            RelayerRepoUpdater::from_existing(original.clone()).apply_domain_update(domain_update);
+
+        // Verify business fields were updated
+        assert_eq!(updated.name, "Updated Name");
+        assert!(updated.paused);
+        assert_eq!(
+            updated.notification_id,
+            Some("new_notification".to_string())
+        );
+
+        // Verify runtime fields were preserved
+        assert_eq!(
+            updated.address,
+            "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E"
+        );
+        assert!(updated.system_disabled);
+        assert_eq!(
+            updated.disabled_reason,
+            Some(DisabledReason::BalanceCheckFailed(
+                "Balance too low".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_relayer_repo_updater_preserves_runtime_fields_solana() {
+        // Create an original Solana relayer with runtime fields set
+        let original = RelayerRepoModel {
+            id: "test_solana_relayer".to_string(),
+            name: "Original Solana Name".to_string(),
+            address: "SolanaOriginalAddress123".to_string(), // Runtime field
+            system_disabled: true,                           // Runtime field
+            disabled_reason: Some(DisabledReason::RpcValidationFailed(
+                "RPC check failed".to_string(),
+            )), // Runtime field
+            paused: false,
+            network: "mainnet".to_string(),
+            network_type: NetworkType::Solana,
+            signer_id: "test_signer".to_string(),
+            policies: RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()),
+            notification_id: None,
+            custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: "test-tenant".to_string(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            version: 0,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
+        };
+
+        // Create a domain model with different business fields
+        let domain_update = Relayer {
+            id: "test_solana_relayer".to_string(),
+            name: "Updated Solana Name".to_string(), // Changed
+            paused: true,                            // Changed
+            network: "mainnet".to_string(),
+            network_type: RelayerNetworkType::Solana,
+            signer_id: "test_signer".to_string(),
+            policies: Some(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+                min_balance: Some(2000000), // Changed
+                ..RelayerSolanaPolicy::default()
+            })),
+            notification_id: Some("solana_notification".to_string()), // Changed
+            custom_rpc_urls: None,
+        };
+
+        // Use updater to preserve runtime fields
+        let updated =
+            RelayerRepoUpdater::from_existing(original.clone()).apply_domain_update(domain_update);
+
+        // Verify business fields were updated
+        assert_eq!(updated.name, "Updated Solana Name");
+        assert!(updated.paused);
+        assert_eq!(
+            updated.notification_id,
+            Some("solana_notification".to_string())
+        );
+
+        // Verify runtime fields were preserved
+        assert_eq!(updated.address, "SolanaOriginalAddress123");
+        assert!(updated.system_disabled);
+        assert_eq!(
+            updated.disabled_reason,
+            Some(DisabledReason::RpcValidationFailed(
+                "RPC check failed".to_string()
+            ))
+        );
+
+        // Verify policies were updated
+        if let RelayerNetworkPolicy::Solana(solana_policy) = updated.policies {
+            assert_eq!(solana_policy.min_balance, Some(2000000));
+        } else {
+            panic!("Expected Solana policy");
+        }
+    }
+
+    #[test]
+    fn test_relayer_repo_updater_preserves_runtime_fields_stellar() {
+        // Create an original Stellar relayer with runtime fields set
+        let original = RelayerRepoModel {
+            id: "test_stellar_relayer".to_string(),
+            name: "Original Stellar Name".to_string(),
+            address: "GORIGINALXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(), // Runtime field
+            system_disabled: false, // Runtime field
+            disabled_reason: None,  // Runtime field
+            paused: true,
+            network: "mainnet".to_string(),
+            network_type: NetworkType::Stellar,
+            signer_id: "test_signer".to_string(),
+            policies: RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default()),
+            notification_id: Some("original_notification".to_string()),
+            custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: "test-tenant".to_string(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            version: 0,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
+        };
+
+        // Create a domain model with different business fields
+        let domain_update = Relayer {
+            id: "test_stellar_relayer".to_string(),
+            name: "Updated Stellar Name".to_string(), // Changed
+            paused: false,                            // Changed
+            network: "mainnet".to_string(),
+            network_type: RelayerNetworkType::Stellar,
+            signer_id: "test_signer".to_string(),
+            policies: Some(RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+                min_balance: Some(40000000), // Changed
+                max_fee: Some(200000),       // Changed
+                timeout_seconds: Some(120),  // Changed
+                concurrent_transactions: None,
+                allowed_tokens: None,
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
+                slippage_percentage: None,
+                fee_margin_percentage: None,
+                swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
+            })),
+            notification_id: None, // Changed
+            custom_rpc_urls: None,
+        };
+
+        // Use updater to preserve runtime fields
+        let updated =
+            RelayerRepoUpdater::from_existing(original.clone()).apply_domain_update(domain_update);
+
+        // Verify business fields were updated
+        assert_eq!(updated.name, "Updated Stellar Name");
+        assert!(!updated.paused);
+        assert_eq!(updated.notification_id, None);
+
+        // Verify runtime fields were preserved
+        assert_eq!(
+            updated.address,
+            "GORIGINALXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+        );
+        assert!(!updated.system_disabled);
+        assert_eq!(updated.disabled_reason, None);
+
+        // Verify policies were updated
+        if let RelayerNetworkPolicy::Stellar(stellar_policy) = updated.policies {
+            assert_eq!(stellar_policy.min_balance, Some(40000000));
+            assert_eq!(stellar_policy.max_fee, Some(200000));
+            assert_eq!(stellar_policy.timeout_seconds, Some(120));
+        } else {
+            panic!("Expected Stellar policy");
+        }
+    }
+
+    #[test]
+    fn test_repo_model_serialization_deserialization_evm() {
+        let original = create_test_relayer(false, false);
+
+        // Serialize to JSON
+        let serialized = serde_json::to_string(&original).unwrap();
+        assert!(!serialized.is_empty());
+
+        // Deserialize back
+        let deserialized: RelayerRepoModel = serde_json::from_str(&serialized).unwrap();
+
+        // Verify all fields match
+        assert_eq!(original.id, deserialized.id);
+        assert_eq!(original.name, deserialized.name);
+        assert_eq!(original.network, deserialized.network);
+        assert_eq!(original.paused, deserialized.paused);
+        assert_eq!(original.network_type, deserialized.network_type);
+        assert_eq!(original.signer_id, deserialized.signer_id);
+        assert_eq!(original.address, deserialized.address);
+        assert_eq!(original.notification_id, deserialized.notification_id);
+        assert_eq!(original.system_disabled, deserialized.system_disabled);
+        assert_eq!(original.custom_rpc_urls, deserialized.custom_rpc_urls);
+
+        // Verify policies match
+        match (&original.policies, &deserialized.policies) {
+            (RelayerNetworkPolicy::Evm(_), RelayerNetworkPolicy::Evm(_)) => {
+                // Success - both are EVM policies
+            }
+            _ => panic!("Policy types don't match after serialization/deserialization"),
+        }
+    }
+
+    #[test]
+    fn test_repo_model_serialization_deserialization_solana() {
+        let original = create_test_relayer_solana(true, false);
+
+        // Serialize to JSON
+        let serialized = serde_json::to_string(&original).unwrap();
+        assert!(!serialized.is_empty());
+
+        // Deserialize back
+        let deserialized: RelayerRepoModel = serde_json::from_str(&serialized).unwrap();
+
+        // Verify key fields match
+        assert_eq!(original.id, deserialized.id);
+        assert_eq!(original.network_type, RelayerNetworkType::Solana);
+        assert_eq!(deserialized.network_type, RelayerNetworkType::Solana);
+        assert_eq!(original.paused, deserialized.paused);
+
+        // Verify policies match
+        match (&original.policies, &deserialized.policies) {
+            (RelayerNetworkPolicy::Solana(orig), RelayerNetworkPolicy::Solana(deser)) => {
+                assert_eq!(orig.fee_payment_strategy, deser.fee_payment_strategy);
+                assert_eq!(orig.min_balance, deser.min_balance);
+                assert_eq!(orig.max_signatures, deser.max_signatures);
+            }
+            _ => panic!("Policy types don't match after serialization/deserialization"),
+        }
+    }
+
+    #[test]
+    fn test_repo_model_serialization_deserialization_stellar() {
+        let original = create_test_relayer_stellar(false, true);
+
+        // Serialize to JSON
+        let serialized = serde_json::to_string(&original).unwrap();
+        assert!(!serialized.is_empty());
+
+        // Deserialize back
+        let deserialized: RelayerRepoModel = serde_json::from_str(&serialized).unwrap();
+
+        // Verify key fields match
+        assert_eq!(original.id, deserialized.id);
+        assert_eq!(original.network_type, RelayerNetworkType::Stellar);
+        assert_eq!(deserialized.network_type, RelayerNetworkType::Stellar);
+        assert_eq!(original.system_disabled, deserialized.system_disabled);
+
+        // Verify policies match
+        match (&original.policies, &deserialized.policies) {
+            (RelayerNetworkPolicy::Stellar(orig), RelayerNetworkPolicy::Stellar(deser)) => {
+                assert_eq!(orig.min_balance, deser.min_balance);
+                assert_eq!(orig.max_fee, deser.max_fee);
+                assert_eq!(orig.timeout_seconds, deser.timeout_seconds);
+            }
+            _ => panic!("Policy types don't match after serialization/deserialization"),
+        }
+    }
+
+    #[test]
+    fn test_repo_model_default() {
+        let default_model = RelayerRepoModel::default();
+
+        assert_eq!(default_model.id, "");
+        assert_eq!(default_model.name, "");
+        assert_eq!(default_model.network, "");
+        assert!(!default_model.paused);
+        assert_eq!(default_model.network_type, NetworkType::Evm);
+        assert_eq!(default_model.signer_id, "");
+        assert_eq!(default_model.address, "0x");
+        assert_eq!(default_model.notification_id, None);
+        assert!(!default_model.system_disabled);
+        assert_eq!(default_model.custom_rpc_urls, None);
+
+        // Default should have EVM policy
+        if let RelayerNetworkPolicy::Evm(_) = default_model.policies {
+            // Success
+        } else {
+            panic!("Default should have EVM policy");
+        }
+    }
+
+    #[test]
+    fn test_round_trip_conversion_all_network_types() {
+        // Test round-trip conversion: Domain -> Repo -> Domain for all network types
+
+        // EVM
+        let original_evm = Relayer {
+            id: "evm_relayer".to_string(),
+            name: "EVM Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Evm,
+            policies: Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_price_cap: Some(50_000_000_000),
+                eip1559_pricing: Some(true),
+                min_balance: None,
+                gas_limit_estimation: None,
+                whitelist_receivers: None,
+                private_transactions: None,
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
+            })),
+            signer_id: "evm_signer".to_string(),
+            notification_id: Some("evm_notification".to_string()),
+            custom_rpc_urls: None,
+        };
+
+        let repo_evm = RelayerRepoModel::from(original_evm.clone());
+        let recovered_evm = Relayer::from(repo_evm);
+
+        assert_eq!(original_evm.id, recovered_evm.id);
+        assert_eq!(original_evm.network_type, recovered_evm.network_type);
+        assert_eq!(original_evm.notification_id, recovered_evm.notification_id);
+
+        // Solana
+        let original_solana = Relayer {
+            id: "solana_relayer".to_string(),
+            name: "Solana Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: true,
+            network_type: RelayerNetworkType::Solana,
+            policies: Some(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+                fee_payment_strategy: Some(SolanaFeePaymentStrategy::User),
+                min_balance: Some(3000000),
+                max_signatures: None,
+                allowed_tokens: None,
+                allowed_programs: None,
+                allowed_accounts: None,
+                disallowed_accounts: None,
+                max_tx_data_size: None,
+                max_allowed_fee_lamports: None,
+                swap_config: None,
+                fee_margin_percentage: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
+            })),
+            signer_id: "solana_signer".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        let repo_solana = RelayerRepoModel::from(original_solana.clone());
+        let recovered_solana = Relayer::from(repo_solana);
+
+        assert_eq!(original_solana.id, recovered_solana.id);
+        assert_eq!(original_solana.network_type, recovered_solana.network_type);
+        assert_eq!(original_solana.paused, recovered_solana.paused);
+
+        // Stellar
+        let original_stellar = Relayer {
+            id: "stellar_relayer".to_string(),
+            name: "Stellar Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Stellar,
+            policies: Some(RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+                min_balance: Some(50000000),
+                max_fee: Some(250000),
+                timeout_seconds: Some(180),
+                concurrent_transactions: None,
+                allowed_tokens: None,
+                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
+                slippage_percentage: None,
+                fee_margin_percentage: None,
+                swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
+            })),
+            signer_id: "stellar_signer".to_string(),
+            notification_id: Some("stellar_notification".to_string()),
+            custom_rpc_urls: None,
+        };
+
+        let repo_stellar = RelayerRepoModel::from(original_stellar.clone());
+        let recovered_stellar = Relayer::from(repo_stellar);
+
+        assert_eq!(original_stellar.id, recovered_stellar.id);
+        assert_eq!(
+            original_stellar.network_type,
+            recovered_stellar.network_type
+        );
+        assert_eq!(
+            original_stellar.notification_id,
+            recovered_stellar.notification_id
+        );
+    }
+
+    #[test]
+    fn test_apply_partial_update_tracks_changes() {
+        let mut relayer = create_test_relayer(false, false);
+
+        let changed = relayer.apply_partial_update(UpdateRelayerRequest {
+            paused: Some(false),
+            name: Some("Test Relayer".to_string()),
+            ..Default::default()
+        });
+        assert!(!changed);
+        assert!(relayer.updated_at.is_none());
+
+        let changed = relayer.apply_partial_update(UpdateRelayerRequest {
+            notification_id: Some(Some("notification-1".to_string())),
+            ..Default::default()
+        });
+        assert!(changed);
+        assert_eq!(relayer.notification_id, Some("notification-1".to_string()));
+        assert!(relayer.updated_at.is_some());
+    }
+
+    #[test]
+    fn test_is_operational_with_quarantine() {
+        let now = Utc::now();
+        let mut relayer = create_test_relayer(false, true);
+        relayer.disabled_reason = Some(DisabledReason::Quarantined("flaky".to_string()));
+        relayer.quarantined_until = Some(now + chrono::Duration::minutes(5));
+
+        assert!(!relayer.is_operational_at(now));
+        assert!(relayer.validate_active_state().is_err());
+
+        // Once the quarantine elapses the relayer is operational again
+        let after_expiry = now + chrono::Duration::minutes(10);
+        assert!(relayer.is_quarantine_expired(after_expiry));
+        assert!(relayer.is_operational_at(after_expiry));
+
+        relayer.quarantined_until = Some(now - chrono::Duration::seconds(1));
+        assert!(relayer.is_operational());
+        assert!(relayer.validate_active_state().is_ok());
+    }
+
+    #[test]
+    fn test_sunset_relayer_only_drains_inflight_work() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.sunset = true;
+        assert!(!relayer.is_operational());
+        assert!(relayer.accepts_inflight_completion());
+
+        // Sunset does not override being paused or disabled
+        relayer.paused = true;
+        assert!(!relayer.accepts_inflight_completion());
+    }
+
+    #[test]
+    fn test_is_operational_without_quarantine() {
+        assert!(create_test_relayer(false, false).is_operational());
+        assert!(!create_test_relayer(true, false).is_operational());
+        // A regular system disable never expires on its own
+        assert!(!create_test_relayer(false, true).is_operational());
+    }
+
+    #[test]
+    fn test_validate_nonce_group() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.nonce_group = Some("shared-eoa".to_string());
+        relayer.chain_id = Some(1);
+        let mut member = create_test_relayer(false, false);
+        member.id = "member".to_string();
+        member.nonce_group = relayer.nonce_group.clone();
+        member.chain_id = Some(1);
+        assert!(relayer.validate_nonce_group([&member]).is_ok());
+
+        member.chain_id = Some(10);
+        assert!(matches!(
+            relayer.validate_nonce_group([&member]),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        let solana = create_test_relayer_solana(false, false);
+        assert!(relayer.validate_nonce_group([&solana]).is_err());
+
+        relayer.nonce_group = Some(String::new());
+        assert!(relayer.validate_nonce_group([]).is_err());
+    }
+
+    #[test]
+    fn test_signature_scheme_defaults_from_network_type() {
+        assert_eq!(
+            create_test_relayer(false, false).signature_scheme(),
+            SignatureScheme::Secp256k1
+        );
+        assert_eq!(
+            create_test_relayer_solana(false, false).signature_scheme(),
+            SignatureScheme::Ed25519
+        );
+        assert_eq!(
+            create_test_relayer_stellar(false, false).signature_scheme(),
+            SignatureScheme::Ed25519
+        );
+
+        let mut relayer = create_test_relayer(false, false);
+        relayer.signature_scheme = Some(SignatureScheme::Secp256k1);
+        assert_eq!(relayer.signature_scheme(), SignatureScheme::Secp256k1);
+        assert!(relayer.validate_signature_scheme().is_ok());
+    }
+
+    #[test]
+    fn test_incompatible_signature_scheme_is_rejected() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.signature_scheme = Some(SignatureScheme::Ed25519);
+        assert!(matches!(
+            relayer.validate_signature_scheme(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(relayer
+            .create_validation_errors()
+            .iter()
+            .any(|e| e.to_string().contains("Signature scheme")));
+
+        let mut relayer = create_test_relayer_solana(false, false);
+        relayer.signature_scheme = Some(SignatureScheme::Secp256k1);
+        assert!(relayer.validate_signature_scheme().is_err());
+    }
+
+    #[test]
+    fn test_funding_deficit() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: Some(1_000),
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(relayer.funding_deficit(400), Some(600));
+        assert_eq!(relayer.funding_deficit(1_000), None);
+        assert_eq!(relayer.funding_deficit(2_000), None);
+
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: None,
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(relayer.funding_deficit(0), None);
+    }
+
+    #[test]
+    fn test_is_available_during_maintenance() {
+        let now = Utc::now();
+        let until = now + chrono::Duration::minutes(5);
+        let relayer = create_test_relayer(false, false);
+        assert!(relayer.is_available_at(None, now));
+        assert!(!relayer.is_available_at(Some(until), now));
+
+        // The window ends by itself, restoring the relayer's own state
+        let after_window = until + chrono::Duration::seconds(1);
+        assert!(relayer.is_available_at(Some(until), after_window));
+        assert!(!create_test_relayer(true, false).is_available_at(Some(until), after_window));
+    }
+
+    #[test]
+    fn test_status_for_each_flag() {
+        let now = Utc::now();
+        assert_eq!(
+            create_test_relayer(false, false).status_at(now),
+            RelayerOperationalStatus::Operational
+        );
+        assert_eq!(
+            create_test_relayer(true, false).status_at(now),
+            RelayerOperationalStatus::Paused
+        );
+        assert_eq!(
+            create_test_relayer(false, true).status_at(now),
+            RelayerOperationalStatus::Disabled
+        );
+
+        let mut quarantined = create_test_relayer(false, true);
+        quarantined.quarantined_until = Some(now + chrono::Duration::minutes(5));
+        assert_eq!(
+            quarantined.status_at(now),
+            RelayerOperationalStatus::Quarantined
+        );
+
+        let mut sunset = create_test_relayer(false, false);
+        sunset.sunset = true;
+        assert_eq!(sunset.status_at(now), RelayerOperationalStatus::Sunset);
+    }
+
+    #[test]
+    fn test_add_and_remove_tag() {
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.add_tag("team-a"));
+        assert!(!relayer.add_tag("team-a"));
+        assert_eq!(relayer.tags(), ["team-a".to_string()]);
+
+        assert!(relayer.remove_tag("team-a"));
+        assert!(!relayer.remove_tag("team-a"));
+        assert!(relayer.tags.is_none());
+    }
+
+    #[test]
+    fn test_relayer_filter_matches_every_criterion() {
+        let now = Utc::now();
+        let mut relayer = create_test_relayer(false, false);
+        relayer.add_tag("team-a");
+        assert!(RelayerFilter::default().matches_at(&relayer, now));
+
+        let filter = RelayerFilter {
+            network_type: Some(RelayerNetworkType::Evm),
+            status: Some(RelayerOperationalStatus::Operational),
+            tag: Some("team-a".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches_at(&relayer, now));
+
+        relayer.paused = true;
+        assert!(!filter.matches_at(&relayer, now));
+        relayer.paused = false;
+        relayer.remove_tag("team-a");
+        assert!(!filter.matches_at(&relayer, now));
+    }
+
+    #[test]
+    fn test_status_precedence() {
+        let now = Utc::now();
+        // Disabled outranks paused
+        assert_eq!(
+            create_test_relayer(true, true).status_at(now),
+            RelayerOperationalStatus::Disabled
+        );
+
+        // An expired quarantine falls through to the remaining flags
+        let mut relayer = create_test_relayer(true, true);
+        relayer.quarantined_until = Some(now - chrono::Duration::seconds(1));
+        assert_eq!(relayer.status_at(now), RelayerOperationalStatus::Paused);
+
+        // Sunset outranks everything
+        relayer.quarantined_until = Some(now + chrono::Duration::minutes(5));
+        relayer.sunset = true;
+        assert_eq!(relayer.status_at(now), RelayerOperationalStatus::Sunset);
+    }
+
+    #[test]
+    fn test_extra_webhook_urls_accessor() {
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.extra_webhook_urls().is_empty());
+
+        relayer.extra_webhook_urls = Some(vec![
+            "https://hooks.slack.com/services/team".to_string(),
+            "http://localhost:8080/webhook".to_string(),
+        ]);
+        assert_eq!(
+            relayer.extra_webhook_urls(),
+            &[
+                "https://hooks.slack.com/services/team".to_string(),
+                "http://localhost:8080/webhook".to_string(),
+            ]
+        );
+        assert!(relayer.validate_extra_webhook_urls().is_ok());
     }
 
     #[test]
-    fn test_validate_active_state_disabled_solana() {
-        let relayer = create_test_relayer_solana(false, true);
-        let result = relayer.validate_active_state();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), RelayerError::RelayerDisabled));
+    fn test_validate_extra_webhook_urls_rejects_bad_scheme() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.extra_webhook_urls = Some(vec!["ftp://example.com/webhook".to_string()]);
+        assert!(matches!(
+            relayer.validate_extra_webhook_urls(),
+            Err(RepositoryError::InvalidData(msg)) if msg.contains("http or https")
+        ));
+
+        relayer.extra_webhook_urls = Some(vec!["not a url".to_string()]);
+        assert!(matches!(
+            relayer.validate_extra_webhook_urls(),
+            Err(RepositoryError::InvalidData(_))
+        ));
     }
 
     #[test]
-    fn test_validate_active_state_disabled_stellar() {
-        let relayer = create_test_relayer_stellar(false, true);
-        let result = relayer.validate_active_state();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), RelayerError::RelayerDisabled));
+    fn test_evaluate_balance_alert_hysteresis() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            low_balance_alerting: Some(AlertThresholds {
+                warn_below: 100,
+                clear_above: 200,
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(relayer.evaluate_balance_alert(150), None);
+        assert_eq!(
+            relayer.evaluate_balance_alert(99),
+            Some(AlertTransition::Fired)
+        );
+        assert_eq!(relayer.alert_state, AlertState::Alerting);
+
+        // Hovering between the watermarks does not clear or re-fire the alert
+        assert_eq!(relayer.evaluate_balance_alert(150), None);
+        assert_eq!(relayer.evaluate_balance_alert(50), None);
+        assert_eq!(relayer.evaluate_balance_alert(200), None);
+        assert_eq!(relayer.alert_state, AlertState::Alerting);
+
+        assert_eq!(
+            relayer.evaluate_balance_alert(201),
+            Some(AlertTransition::Cleared)
+        );
+        assert_eq!(relayer.alert_state, AlertState::Normal);
     }
 
     #[test]
-    fn test_validate_active_state_both_paused_and_disabled() {
-        // When both are true, should return paused error (checked first)
-        let relayer = create_test_relayer(true, true);
-        let result = relayer.validate_active_state();
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), RelayerError::RelayerPaused));
+    fn test_evaluate_balance_alert_without_thresholds() {
+        let mut relayer = create_test_relayer(false, false);
+        assert_eq!(relayer.evaluate_balance_alert(0), None);
+        assert_eq!(relayer.alert_state, AlertState::Normal);
+    }
+
+    fn create_test_relayer_with_rules() -> RelayerRepoModel {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            validation_rules: vec![
+                TransactionRule::MaxValue(1_000),
+                TransactionRule::DenySelectors(vec![[0x09, 0x5e, 0xa7, 0xb3]]),
+            ],
+            ..Default::default()
+        });
+        relayer
     }
 
     #[test]
-    fn test_conversion_from_repo_model_to_domain_evm() {
-        let repo_model = create_test_relayer(false, false);
-        let domain_relayer = Relayer::from(repo_model.clone());
+    fn test_evaluate_rules_value_over_cap() {
+        let relayer = create_test_relayer_with_rules();
+        assert_eq!(
+            relayer.evaluate_rules(1_001, None),
+            Err(RuleViolation::ValueExceedsMax {
+                value: 1_001,
+                max: 1_000
+            })
+        );
+    }
 
-        assert_eq!(domain_relayer.id, repo_model.id);
-        assert_eq!(domain_relayer.name, repo_model.name);
-        assert_eq!(domain_relayer.network, repo_model.network);
-        assert_eq!(domain_relayer.paused, repo_model.paused);
-        assert_eq!(domain_relayer.network_type, repo_model.network_type);
-        assert_eq!(domain_relayer.signer_id, repo_model.signer_id);
-        assert_eq!(domain_relayer.notification_id, repo_model.notification_id);
-        assert_eq!(domain_relayer.custom_rpc_urls, repo_model.custom_rpc_urls);
+    #[test]
+    fn test_evaluate_rules_denied_selector() {
+        let relayer = create_test_relayer_with_rules();
+        let result = relayer.evaluate_rules(0, Some([0x09, 0x5e, 0xa7, 0xb3]));
+        assert_eq!(
+            result,
+            Err(RuleViolation::SelectorDenied([0x09, 0x5e, 0xa7, 0xb3]))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Function selector 0x095ea7b3 is denied"
+        );
+    }
 
-        // Policies should be converted correctly
-        assert!(domain_relayer.policies.is_some());
-        if let Some(RelayerNetworkPolicy::Evm(_)) = domain_relayer.policies {
-            // Success - correct policy type
-        } else {
-            panic!("Expected EVM policy");
-        }
+    #[test]
+    fn test_evaluate_rules_allowed_transaction() {
+        let relayer = create_test_relayer_with_rules();
+        assert!(relayer
+            .evaluate_rules(1_000, Some([0xa9, 0x05, 0x9c, 0xbb]))
+            .is_ok());
+        assert!(relayer.evaluate_rules(500, None).is_ok());
+
+        // Relayers without rules accept everything
+        assert!(create_test_relayer(false, false)
+            .evaluate_rules(u128::MAX, Some([0x09, 0x5e, 0xa7, 0xb3]))
+            .is_ok());
     }
 
     #[test]
-    fn test_conversion_from_repo_model_to_domain_solana() {
-        let repo_model = create_test_relayer_solana(false, false);
-        let domain_relayer = Relayer::from(repo_model.clone());
+    fn test_evaluate_rules_allowed_selectors() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            validation_rules: vec![TransactionRule::AllowedSelectors(vec![[
+                0xa9, 0x05, 0x9c, 0xbb,
+            ]])],
+            ..Default::default()
+        });
 
-        assert_eq!(domain_relayer.id, repo_model.id);
-        assert_eq!(domain_relayer.network_type, RelayerNetworkType::Solana);
+        assert!(relayer
+            .evaluate_rules(0, Some([0xa9, 0x05, 0x9c, 0xbb]))
+            .is_ok());
+        assert_eq!(
+            relayer.evaluate_rules(0, Some([0x09, 0x5e, 0xa7, 0xb3])),
+            Err(RuleViolation::SelectorNotAllowed([0x09, 0x5e, 0xa7, 0xb3]))
+        );
+    }
 
-        // Policies should be converted correctly
-        assert!(domain_relayer.policies.is_some());
-        if let Some(RelayerNetworkPolicy::Solana(solana_policy)) = domain_relayer.policies {
-            assert_eq!(solana_policy.min_balance, Some(1000000));
-            assert_eq!(solana_policy.max_signatures, Some(5));
-            assert_eq!(
-                solana_policy.fee_payment_strategy,
-                Some(SolanaFeePaymentStrategy::Relayer)
+    #[test]
+    fn test_clamp_gas_price() {
+        let mut relayer = create_test_relayer(false, false);
+        assert_eq!(relayer.clamp_gas_price(u128::MAX), u128::MAX);
+
+        relayer.hard_gas_price_ceiling = Some(500_000_000_000);
+        assert_eq!(relayer.clamp_gas_price(900_000_000_000), 500_000_000_000);
+        assert_eq!(relayer.clamp_gas_price(20_000_000_000), 20_000_000_000);
+    }
+
+    #[test]
+    fn test_validation_errors_collects_repository_violations() {
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.validation_errors().is_empty());
+
+        relayer.tenant_id = String::new();
+        relayer.extra_webhook_urls = Some(vec!["ftp://example.com/hook".to_string()]);
+        let errors = relayer.validation_errors();
+        assert_eq!(errors.len(), 2);
+        assert!(errors
+            .iter()
+            .all(|e| matches!(e, RelayerValidationError::InvalidField(_))));
+    }
+
+    #[test]
+    fn test_validate_derivation_path() {
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.derivation_path().is_none());
+        assert!(relayer.validate_derivation_path().is_ok());
+
+        for valid in ["m", "m/44'/60'/0'/0/0", "m/0/1/2", "m/2147483647'"] {
+            relayer.derivation_path = Some(valid.to_string());
+            assert_eq!(relayer.derivation_path(), Some(valid));
+            assert!(relayer.validate_derivation_path().is_ok(), "{valid}");
+        }
+
+        for invalid in [
+            "",
+            "44'/60'/0'/0/0",
+            "m/",
+            "m/44''/60",
+            "m/44'/sixty'/0",
+            "m/2147483648",
+            "M/44'/60'/0'/0/0",
+        ] {
+            relayer.derivation_path = Some(invalid.to_string());
+            assert!(
+                matches!(
+                    relayer.validate_derivation_path(),
+                    Err(RepositoryError::ConstraintViolation(_))
+                ),
+                "{invalid}"
             );
-        } else {
-            panic!("Expected Solana policy");
         }
     }
 
     #[test]
-    fn test_conversion_from_repo_model_to_domain_stellar() {
-        let repo_model = create_test_relayer_stellar(false, false);
-        let domain_relayer = Relayer::from(repo_model.clone());
+    fn test_validate_fee_account() {
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.fee_account().is_none());
+        assert!(relayer.validate_fee_account().is_ok());
 
-        assert_eq!(domain_relayer.id, repo_model.id);
-        assert_eq!(domain_relayer.network_type, RelayerNetworkType::Stellar);
+        relayer.fee_account = Some(FeeAccount {
+            address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+            kind: FeeAccountKind::Paymaster,
+        });
+        assert!(relayer.validate_fee_account().is_ok());
+        assert_eq!(
+            relayer.fee_account().map(|fee_account| fee_account.kind),
+            Some(FeeAccountKind::Paymaster)
+        );
 
-        // Policies should be converted correctly
-        assert!(domain_relayer.policies.is_some());
-        if let Some(RelayerNetworkPolicy::Stellar(stellar_policy)) = domain_relayer.policies {
-            assert_eq!(stellar_policy.min_balance, Some(20000000));
-            assert_eq!(stellar_policy.max_fee, Some(100000));
-            assert_eq!(stellar_policy.timeout_seconds, Some(30));
-        } else {
-            panic!("Expected Stellar policy");
-        }
+        // A Solana address is not a valid EVM fee account
+        relayer.fee_account = Some(FeeAccount {
+            address: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+            kind: FeeAccountKind::Treasury,
+        });
+        assert!(matches!(
+            relayer.validate_fee_account(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(relayer.validation_errors().iter().any(|e| matches!(
+            e,
+            RelayerValidationError::InvalidField(msg) if msg.contains("fee account")
+        )));
+
+        let mut solana = create_test_relayer_solana(false, false);
+        solana.fee_account = Some(FeeAccount {
+            address: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+            kind: FeeAccountKind::Treasury,
+        });
+        assert!(solana.validate_fee_account().is_ok());
     }
 
     #[test]
-    fn test_conversion_from_domain_to_repo_model_evm() {
-        let domain_relayer = Relayer {
-            id: "test_evm_relayer".to_string(),
-            name: "Test EVM Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Evm,
-            policies: Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
-                gas_price_cap: Some(100_000_000_000),
-                eip1559_pricing: Some(true),
-                min_balance: None,
-                gas_limit_estimation: None,
-                whitelist_receivers: None,
-                private_transactions: None,
-            })),
-            signer_id: "test_signer".to_string(),
-            notification_id: Some("notification_123".to_string()),
-            custom_rpc_urls: None,
+    fn test_fee_account_serialization() {
+        let fee_account = FeeAccount {
+            address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+            kind: FeeAccountKind::Self_,
         };
+        let value = serde_json::to_value(&fee_account).unwrap();
+        assert_eq!(value["kind"], "self");
+        assert_eq!(
+            serde_json::from_value::<FeeAccount>(value).unwrap(),
+            fee_account
+        );
+        assert_eq!(
+            serde_json::from_str::<FeeAccountKind>("\"paymaster\"").unwrap(),
+            FeeAccountKind::Paymaster
+        );
 
-        let repo_model = RelayerRepoModel::from(domain_relayer.clone());
+        // Relayers without a fee account omit the field
+        let relayer = create_test_relayer(false, false);
+        let value = serde_json::to_value(&relayer).unwrap();
+        assert!(value.get("fee_account").is_none());
+    }
 
-        assert_eq!(repo_model.id, domain_relayer.id);
-        assert_eq!(repo_model.name, domain_relayer.name);
-        assert_eq!(repo_model.network, domain_relayer.network);
-        assert_eq!(repo_model.paused, domain_relayer.paused);
-        assert_eq!(repo_model.network_type, domain_relayer.network_type);
-        assert_eq!(repo_model.signer_id, domain_relayer.signer_id);
-        assert_eq!(repo_model.notification_id, domain_relayer.notification_id);
-        assert_eq!(repo_model.custom_rpc_urls, domain_relayer.custom_rpc_urls);
+    #[test]
+    fn test_create_validation_errors_aggregates_failures() {
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.create_validation_errors().is_empty());
 
-        // Runtime fields should have default values
-        assert_eq!(repo_model.address, "");
-        assert!(!repo_model.system_disabled);
+        relayer.tenant_id = String::new();
+        relayer.derivation_path = Some("not/a/path".to_string());
+        relayer.aliases = vec![relayer.id.clone()];
 
-        // Policies should be converted correctly
-        if let RelayerNetworkPolicy::Evm(evm_policy) = repo_model.policies {
-            assert_eq!(evm_policy.gas_price_cap, Some(100_000_000_000));
-            assert_eq!(evm_policy.eip1559_pricing, Some(true));
-        } else {
-            panic!("Expected EVM policy");
-        }
+        let errors = relayer.create_validation_errors();
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        // Reported in the order the repository checks them
+        assert!(errors[0].to_string().contains("tenant ID"));
+        assert!(matches!(errors[1], RepositoryError::ConstraintViolation(_)));
+        assert!(errors[2].to_string().contains("alias"));
     }
 
     #[test]
-    fn test_conversion_from_domain_to_repo_model_solana() {
-        let domain_relayer = Relayer {
-            id: "test_solana_relayer".to_string(),
-            name: "Test Solana Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Solana,
-            policies: Some(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
-                fee_payment_strategy: Some(SolanaFeePaymentStrategy::User),
-                min_balance: Some(5000000),
-                max_signatures: Some(8),
-                allowed_tokens: Some(vec![SolanaAllowedTokensPolicy::new(
-                    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
-                    Some(100000),
-                    None,
-                )]),
-                allowed_programs: None,
-                allowed_accounts: None,
-                disallowed_accounts: None,
-                max_tx_data_size: None,
-                max_allowed_fee_lamports: None,
-                swap_config: None,
-                fee_margin_percentage: None,
-            })),
-            signer_id: "test_signer".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
-        };
+    fn test_validate_signer_chain() {
+        let mut relayer = create_test_relayer(false, false);
+        // Without a chain id there is nothing to check
+        assert!(relayer.validate_signer_chain(Some(&[1])).is_ok());
 
-        let repo_model = RelayerRepoModel::from(domain_relayer.clone());
+        relayer.chain_id = Some(10);
+        assert!(relayer.validate_signer_chain(Some(&[1, 10])).is_ok());
+        assert!(relayer.validate_signer_chain(None).is_ok());
+        assert!(matches!(
+            relayer.validate_signer_chain(Some(&[1])),
+            Err(RepositoryError::ConstraintViolation(msg)) if msg.contains("test_signer")
+        ));
+        assert!(relayer.validate_signer_chain(Some(&[])).is_err());
+    }
 
-        assert_eq!(repo_model.network_type, RelayerNetworkType::Solana);
+    fn create_test_relayer_with_rpc_urls() -> RelayerRepoModel {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.custom_rpc_urls = Some(vec![
+            RpcConfig::with_weight("https://backup.example.com".to_string(), 50).unwrap(),
+            RpcConfig::with_weight("https://primary.example.com".to_string(), 100).unwrap(),
+            RpcConfig::with_weight("https://fallback.example.com".to_string(), 50).unwrap(),
+        ]);
+        relayer
+    }
 
-        // Policies should be converted correctly
-        if let RelayerNetworkPolicy::Solana(solana_policy) = repo_model.policies {
-            assert_eq!(
-                solana_policy.fee_payment_strategy,
-                Some(SolanaFeePaymentStrategy::User)
-            );
-            assert_eq!(solana_policy.min_balance, Some(5000000));
-            assert_eq!(solana_policy.max_signatures, Some(8));
-            assert!(solana_policy.allowed_tokens.is_some());
-        } else {
-            panic!("Expected Solana policy");
+    #[test]
+    fn test_ordered_rpc_urls_by_weight_then_config_order() {
+        let relayer = create_test_relayer_with_rpc_urls();
+        assert_eq!(
+            relayer.ordered_rpc_urls(),
+            vec![
+                "https://primary.example.com",
+                "https://backup.example.com",
+                "https://fallback.example.com",
+            ]
+        );
+        assert!(create_test_relayer(false, false)
+            .next_healthy_rpc_url()
+            .is_none());
+    }
+
+    #[test]
+    fn test_next_healthy_rpc_url_skips_unhealthy_primary() {
+        let mut relayer = create_test_relayer_with_rpc_urls();
+        // URLs without a recorded outcome are usable
+        assert_eq!(
+            relayer.next_healthy_rpc_url().as_deref(),
+            Some("https://primary.example.com")
+        );
+
+        relayer
+            .record_rpc_health("https://primary.example.com", false)
+            .unwrap();
+        assert_eq!(
+            relayer.next_healthy_rpc_url().as_deref(),
+            Some("https://backup.example.com")
+        );
+
+        relayer
+            .record_rpc_health("https://primary.example.com", true)
+            .unwrap();
+        assert_eq!(
+            relayer.next_healthy_rpc_url().as_deref(),
+            Some("https://primary.example.com")
+        );
+    }
+
+    #[test]
+    fn test_next_healthy_rpc_url_falls_back_to_primary_when_all_unhealthy() {
+        let mut relayer = create_test_relayer_with_rpc_urls();
+        for url in relayer.ordered_rpc_urls() {
+            relayer.record_rpc_health(&url, false).unwrap();
         }
+        assert_eq!(
+            relayer.next_healthy_rpc_url().as_deref(),
+            Some("https://primary.example.com")
+        );
     }
 
     #[test]
-    fn test_conversion_from_domain_to_repo_model_stellar() {
-        let domain_relayer = Relayer {
-            id: "test_stellar_relayer".to_string(),
-            name: "Test Stellar Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Stellar,
-            policies: Some(RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
-                min_balance: Some(30000000),
-                max_fee: Some(150000),
-                timeout_seconds: Some(60),
-                concurrent_transactions: None,
-                allowed_tokens: None,
-                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
-                slippage_percentage: None,
-                fee_margin_percentage: None,
-                swap_config: None,
-            })),
-            signer_id: "test_signer".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
-        };
+    fn test_record_rpc_health_rejects_unconfigured_url() {
+        let mut relayer = create_test_relayer_with_rpc_urls();
+        let result = relayer.record_rpc_health("https://unknown.example.com", false);
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert!(relayer.rpc_health.is_empty());
+    }
 
-        let repo_model = RelayerRepoModel::from(domain_relayer.clone());
+    #[test]
+    fn test_merge_metadata_is_shallow() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer
+            .merge_metadata(serde_json::json!({ "cost_center": "ops", "ticket": "OPS-1" }))
+            .unwrap();
+        relayer
+            .merge_metadata(serde_json::json!({ "ticket": null, "team": { "name": "infra" } }))
+            .unwrap();
+        assert_eq!(
+            relayer.metadata,
+            serde_json::json!({ "cost_center": "ops", "team": { "name": "infra" } })
+        );
+
+        let result = relayer.merge_metadata(serde_json::json!(["not", "an", "object"]));
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_validate_metadata_size() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.metadata = serde_json::json!({ "key": "value" });
+        let size = relayer.metadata.to_string().len();
+        assert!(relayer.validate_metadata_size(size).is_ok());
+        assert!(matches!(
+            relayer.validate_metadata_size(size - 1),
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_transaction_deadline() {
+        // No deadline
+        assert!(create_test_relayer(false, false)
+            .transaction_deadline()
+            .is_none());
+
+        let mut evm = create_test_relayer(false, false);
+        evm.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            transaction_deadline_secs: Some(120),
+            ..RelayerEvmPolicy::default()
+        });
+        assert_eq!(evm.transaction_deadline(), Some(Duration::from_secs(120)));
+
+        let mut solana = create_test_relayer_solana(false, false);
+        solana.policies = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+            transaction_deadline_secs: Some(60),
+            ..RelayerSolanaPolicy::default()
+        });
+        assert_eq!(solana.transaction_deadline(), Some(Duration::from_secs(60)));
+
+        let mut stellar = create_test_relayer_stellar(false, false);
+        stellar.policies = RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
+            transaction_deadline_secs: Some(30),
+            ..RelayerStellarPolicy::default()
+        });
+        assert_eq!(
+            stellar.transaction_deadline(),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_is_calldata_within_limit() {
+        // Unset: any size is accepted
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.is_calldata_within_limit(0));
+        assert!(relayer.is_calldata_within_limit(1_000_000));
+
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            max_calldata_bytes: Some(1024),
+            ..RelayerEvmPolicy::default()
+        });
+        // Under and at the limit
+        assert!(relayer.is_calldata_within_limit(68));
+        assert!(relayer.is_calldata_within_limit(1024));
+        // Over the limit
+        assert!(!relayer.is_calldata_within_limit(1025));
+
+        // Non-EVM relayers have no calldata limit
+        let solana = create_test_relayer_solana(false, false);
+        assert!(solana.is_calldata_within_limit(1_000_000));
+    }
 
-        assert_eq!(repo_model.network_type, RelayerNetworkType::Stellar);
+    #[test]
+    fn test_config_checksum_ignores_runtime_fields() {
+        let relayer = create_test_relayer(false, false);
+        let checksum = relayer.config_checksum();
+        assert_eq!(checksum, relayer.clone().config_checksum());
 
-        // Policies should be converted correctly
-        if let RelayerNetworkPolicy::Stellar(stellar_policy) = repo_model.policies {
-            assert_eq!(stellar_policy.min_balance, Some(30000000));
-            assert_eq!(stellar_policy.max_fee, Some(150000));
-            assert_eq!(stellar_policy.timeout_seconds, Some(60));
-        } else {
-            panic!("Expected Stellar policy");
-        }
+        let mut runtime_changed = relayer.clone();
+        runtime_changed.address = "0xother".to_string();
+        runtime_changed.system_disabled = true;
+        runtime_changed.disabled_reason = Some(DisabledReason::NonceSyncFailed(
+            "nonce sync failed".to_string(),
+        ));
+        runtime_changed.updated_at = Some(Utc::now().to_rfc3339());
+        runtime_changed.quarantined_until = Some(Utc::now());
+        runtime_changed.alert_state = AlertState::Alerting;
+        runtime_changed.scheduled_disable_at = Some(Utc::now());
+        runtime_changed.scheduled_disable_reason = Some("maintenance".to_string());
+        runtime_changed.last_error = Some(RelayerLastError::new(RelayerErrorKind::Rpc, "timeout"));
+        assert_eq!(runtime_changed.config_checksum(), checksum);
     }
 
     #[test]
-    fn test_conversion_from_domain_with_no_policies_evm() {
-        let domain_relayer = Relayer {
-            id: "test_evm_relayer".to_string(),
-            name: "Test EVM Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Evm,
-            policies: None, // No policies provided
-            signer_id: "test_signer".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
-        };
+    fn test_config_checksum_covers_every_config_field() {
+        let relayer = create_test_relayer(false, false);
 
-        let repo_model = RelayerRepoModel::from(domain_relayer);
+        // Every field is listed so that adding one fails to compile until it is
+        // classified: config fields go into the fingerprint and into `changes` below,
+        // runtime fields into the exclusions documented on `config_checksum`
+        let RelayerRepoModel {
+            id: _,
+            name: _,
+            network: _,
+            paused: _,
+            network_type: _,
+            signer_id: _,
+            policies: _,
+            address: _,
+            notification_id: _,
+            system_disabled: _,
+            disabled_reason: _,
+            custom_rpc_urls: _,
+            updated_at: _,
+            quarantined_until: _,
+            extra_webhook_urls: _,
+            alert_state: _,
+            tenant_id: _,
+            scheduled_disable_at: _,
+            scheduled_disable_reason: _,
+            hard_gas_price_ceiling: _,
+            status_history: _,
+            recent_outcomes: _,
+            pool: _,
+            derivation_path: _,
+            circuit: _,
+            last_error: _,
+            config_versions: _,
+            aliases: _,
+            standby_for: _,
+            health_check_interval: _,
+            last_health_check_at: _,
+            health: _,
+            sunset: _,
+            inherits_from: _,
+            fee_account: _,
+            chain_id: _,
+            tags: _,
+            metadata: _,
+            rpc_health: _,
+            signature_scheme: _,
+            nonce_group: _,
+            paused_by_enforcement: _,
+            log_level: _,
+            allowed_origins: _,
+            pending_count: _,
+            signer_timeout_ms: _,
+            spent_today: _,
+            spend_window_start: _,
+            mode: _,
+            ownership_history: _,
+            version: _,
+        } = relayer.clone();
 
-        // Should create default EVM policy
-        if let RelayerNetworkPolicy::Evm(evm_policy) = repo_model.policies {
-            // Default EVM policy should have all None values
-            assert_eq!(evm_policy.gas_price_cap, None);
-            assert_eq!(evm_policy.eip1559_pricing, None);
-            assert_eq!(evm_policy.min_balance, None);
-            assert_eq!(evm_policy.gas_limit_estimation, None);
-            assert_eq!(evm_policy.whitelist_receivers, None);
-            assert_eq!(evm_policy.private_transactions, None);
-        } else {
-            panic!("Expected default EVM policy");
+        type Change = fn(&mut RelayerRepoModel);
+        let changes: Vec<(&str, Change)> = vec![
+            ("aliases", |r| r.aliases = vec!["alias".to_string()]),
+            ("standby_for", |r| {
+                r.standby_for = Some("primary".to_string())
+            }),
+            ("health_check_interval", |r| {
+                r.health_check_interval = Some(Duration::from_secs(60))
+            }),
+            ("inherits_from", |r| {
+                r.inherits_from = Some("parent".to_string())
+            }),
+            ("fee_account", |r| {
+                r.fee_account = Some(FeeAccount {
+                    address: "0xfee".to_string(),
+                    kind: FeeAccountKind::Treasury,
+                })
+            }),
+            ("chain_id", |r| r.chain_id = Some(1)),
+            ("tags", |r| r.tags = Some(vec!["batch-a".to_string()])),
+            ("signature_scheme", |r| {
+                r.signature_scheme = Some(SignatureScheme::Ed25519)
+            }),
+            ("nonce_group", |r| r.nonce_group = Some("group".to_string())),
+            ("log_level", |r| r.log_level = Some(LogLevel::Debug)),
+            ("allowed_origins", |r| {
+                r.allowed_origins = Some(vec!["https://example.com".to_string()])
+            }),
+            ("signer_timeout_ms", |r| r.signer_timeout_ms = Some(500)),
+        ];
+        for (field, change) in changes {
+            let mut changed = relayer.clone();
+            change(&mut changed);
+            assert_ne!(
+                changed.config_checksum(),
+                relayer.config_checksum(),
+                "{field} is not covered by the checksum"
+            );
         }
     }
 
     #[test]
-    fn test_conversion_from_domain_with_no_policies_solana() {
-        let domain_relayer = Relayer {
-            id: "test_solana_relayer".to_string(),
-            name: "Test Solana Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Solana,
-            policies: None, // No policies provided
-            signer_id: "test_signer".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
-        };
+    fn test_record_config_version_skips_unchanged_config() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.record_config_version(10);
+        relayer.record_config_version(10);
+        assert_eq!(relayer.config_versions.len(), 1);
 
-        let repo_model = RelayerRepoModel::from(domain_relayer);
+        // Runtime fields are not part of the versioned config
+        relayer.system_disabled = true;
+        relayer.address = "0xother".to_string();
+        relayer.record_config_version(10);
+        assert_eq!(relayer.config_versions.len(), 1);
 
-        // Should create default Solana policy
-        if let RelayerNetworkPolicy::Solana(solana_policy) = repo_model.policies {
-            // Default Solana policy should have all None values
-            assert_eq!(solana_policy.fee_payment_strategy, None);
-            assert_eq!(solana_policy.min_balance, None);
-            assert_eq!(solana_policy.max_signatures, None);
-            assert_eq!(solana_policy.allowed_tokens, None);
-            assert_eq!(solana_policy.allowed_programs, None);
-            assert_eq!(solana_policy.allowed_accounts, None);
-            assert_eq!(solana_policy.disallowed_accounts, None);
-            assert_eq!(solana_policy.max_tx_data_size, None);
-            assert_eq!(solana_policy.max_allowed_fee_lamports, None);
-            assert_eq!(solana_policy.swap_config, None);
-            assert_eq!(solana_policy.fee_margin_percentage, None);
-        } else {
-            panic!("Expected default Solana policy");
-        }
+        relayer.name = "Renamed".to_string();
+        relayer.record_config_version(10);
+        let versions: Vec<u32> = relayer
+            .config_version_infos()
+            .iter()
+            .map(|info| info.version)
+            .collect();
+        assert_eq!(versions, vec![1, 2]);
     }
 
     #[test]
-    fn test_conversion_from_domain_with_no_policies_stellar() {
-        let domain_relayer = Relayer {
-            id: "test_stellar_relayer".to_string(),
-            name: "Test Stellar Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Stellar,
-            policies: None, // No policies provided
-            signer_id: "test_signer".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
-        };
+    fn test_is_health_check_due() {
+        let now = Utc::now();
+        let mut relayer = create_test_relayer(false, false);
+        assert!(!relayer.is_health_check_due(now));
 
-        let repo_model = RelayerRepoModel::from(domain_relayer);
+        relayer.health_check_interval = Some(Duration::from_secs(60));
+        assert!(relayer.is_health_check_due(now));
 
-        // Should create default Stellar policy
-        if let RelayerNetworkPolicy::Stellar(stellar_policy) = repo_model.policies {
-            // Default Stellar policy should have all None values
-            assert_eq!(stellar_policy.min_balance, None);
-            assert_eq!(stellar_policy.max_fee, None);
-            assert_eq!(stellar_policy.timeout_seconds, None);
-        } else {
-            panic!("Expected default Stellar policy");
-        }
+        relayer.last_health_check_at = Some(now - chrono::Duration::seconds(30));
+        assert!(!relayer.is_health_check_due(now));
+        relayer.last_health_check_at = Some(now - chrono::Duration::seconds(60));
+        assert!(relayer.is_health_check_due(now));
     }
 
     #[test]
-    fn test_relayer_repo_updater_preserves_runtime_fields() {
-        // Create an original relayer with runtime fields set
-        let original = RelayerRepoModel {
-            id: "test_relayer".to_string(),
-            name: "Original Name".to_string(),
-            address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(), // Runtime field
-            system_disabled: true,                                             // Runtime field
-            disabled_reason: Some(DisabledReason::BalanceCheckFailed(
-                "Balance too low".to_string(),
-            )), // Runtime field
-            paused: false,
-            network: "mainnet".to_string(),
-            network_type: NetworkType::Evm,
-            signer_id: "test_signer".to_string(),
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            notification_id: None,
-            custom_rpc_urls: None,
-        };
-
-        // Create a domain model with different business fields
-        let domain_update = Relayer {
-            id: "test_relayer".to_string(),
-            name: "Updated Name".to_string(), // Changed
-            paused: true,                     // Changed
-            network: "mainnet".to_string(),
-            network_type: RelayerNetworkType::Evm,
-            signer_id: "test_signer".to_string(),
-            policies: Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default())),
-            notification_id: Some("new_notification".to_string()), // Changed
-            custom_rpc_urls: None,
-        };
+    fn test_validate_standby_for() {
+        let mut primary = create_test_relayer(false, false);
+        primary.id = "primary".to_string();
+        let mut standby = create_test_relayer(false, false);
+        standby.id = "standby".to_string();
+        assert!(standby.validate_standby_for(None).is_ok());
 
-        // Use updater to preserve runtime fields
-        let updated =
-            RelayerRepoUpdater::from_existing(original.clone()).apply_domain_update(domain_update);
+        standby.standby_for = Some("primary".to_string());
+        assert!(standby.validate_standby_for(Some(&primary)).is_ok());
+        assert!(matches!(
+            standby.validate_standby_for(None),
+            Err(RepositoryError::InvalidData(_))
+        ));
 
-        // Verify business fields were updated
-        assert_eq!(updated.name, "Updated Name");
-        assert!(updated.paused);
-        assert_eq!(
-            updated.notification_id,
-            Some("new_notification".to_string())
-        );
+        let mut other_network = primary.clone();
+        other_network.network = "other".to_string();
+        assert!(matches!(
+            standby.validate_standby_for(Some(&other_network)),
+            Err(RepositoryError::InvalidData(_))
+        ));
 
-        // Verify runtime fields were preserved
-        assert_eq!(
-            updated.address,
-            "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E"
-        );
-        assert!(updated.system_disabled);
-        assert_eq!(
-            updated.disabled_reason,
-            Some(DisabledReason::BalanceCheckFailed(
-                "Balance too low".to_string()
-            ))
-        );
+        standby.standby_for = Some("standby".to_string());
+        assert!(matches!(
+            standby.validate_standby_for(Some(&primary)),
+            Err(RepositoryError::InvalidData(_))
+        ));
     }
 
     #[test]
-    fn test_relayer_repo_updater_preserves_runtime_fields_solana() {
-        // Create an original Solana relayer with runtime fields set
-        let original = RelayerRepoModel {
-            id: "test_solana_relayer".to_string(),
-            name: "Original Solana Name".to_string(),
-            address: "SolanaOriginalAddress123".to_string(), // Runtime field
-            system_disabled: true,                           // Runtime field
-            disabled_reason: Some(DisabledReason::RpcValidationFailed(
-                "RPC check failed".to_string(),
-            )), // Runtime field
-            paused: false,
-            network: "mainnet".to_string(),
-            network_type: NetworkType::Solana,
-            signer_id: "test_signer".to_string(),
-            policies: RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()),
-            notification_id: None,
-            custom_rpc_urls: None,
+    fn test_inheritance_chain() {
+        let relayer_with = |id: &str, parent: Option<&str>| RelayerRepoModel {
+            id: id.to_string(),
+            inherits_from: parent.map(str::to_string),
+            ..create_test_relayer(false, false)
         };
+        let stored: HashMap<String, RelayerRepoModel> = [
+            relayer_with("root", None),
+            relayer_with("parent", Some("root")),
+            relayer_with("loop", Some("child")),
+        ]
+        .into_iter()
+        .map(|relayer| (relayer.id.clone(), relayer))
+        .collect();
+        let lookup = |id: &str| stored.get(id).cloned();
 
-        // Create a domain model with different business fields
-        let domain_update = Relayer {
-            id: "test_solana_relayer".to_string(),
-            name: "Updated Solana Name".to_string(), // Changed
-            paused: true,                            // Changed
-            network: "mainnet".to_string(),
-            network_type: RelayerNetworkType::Solana,
-            signer_id: "test_signer".to_string(),
-            policies: Some(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
-                min_balance: Some(2000000), // Changed
-                ..RelayerSolanaPolicy::default()
-            })),
-            notification_id: Some("solana_notification".to_string()), // Changed
-            custom_rpc_urls: None,
-        };
+        let chain = relayer_with("child", Some("parent"))
+            .inheritance_chain(lookup)
+            .unwrap();
+        let ids: Vec<&str> = chain.iter().map(|relayer| relayer.id.as_str()).collect();
+        assert_eq!(ids, vec!["parent", "root"]);
 
-        // Use updater to preserve runtime fields
-        let updated =
-            RelayerRepoUpdater::from_existing(original.clone()).apply_domain_update(domain_update);
+        assert!(matches!(
+            relayer_with("child", Some("loop")).inheritance_chain(lookup),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(matches!(
+            relayer_with("child", Some("missing")).inheritance_chain(lookup),
+            Err(RepositoryError::InvalidData(_))
+        ));
 
-        // Verify business fields were updated
-        assert_eq!(updated.name, "Updated Solana Name");
-        assert!(updated.paused);
-        assert_eq!(
-            updated.notification_id,
-            Some("solana_notification".to_string())
-        );
+        let mut solana_parent = create_test_relayer_solana(false, false);
+        solana_parent.id = "solana".to_string();
+        let mismatched = relayer_with("child", Some("solana"))
+            .inheritance_chain(|_| Some(solana_parent.clone()));
+        assert!(matches!(mismatched, Err(RepositoryError::InvalidData(_))));
+    }
 
-        // Verify runtime fields were preserved
-        assert_eq!(updated.address, "SolanaOriginalAddress123");
-        assert!(updated.system_disabled);
+    #[test]
+    fn test_capabilities_derived_from_policy() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default());
+        let capabilities = relayer.capabilities();
+        // EIP-1559 pricing defaults to on, private transactions to off
+        assert!(capabilities.supports(Capability::Eip1559));
+        assert!(capabilities.supports(Capability::Blobs));
+        assert!(!capabilities.supports(Capability::PrivateTransactions));
+
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            eip1559_pricing: Some(false),
+            private_transactions: Some(true),
+            ..Default::default()
+        });
         assert_eq!(
-            updated.disabled_reason,
-            Some(DisabledReason::RpcValidationFailed(
-                "RPC check failed".to_string()
-            ))
+            relayer.capabilities(),
+            RelayerCapabilities {
+                supports_eip1559: false,
+                supports_private_tx: true,
+                supports_blobs: false,
+            }
         );
 
-        // Verify policies were updated
-        if let RelayerNetworkPolicy::Solana(solana_policy) = updated.policies {
-            assert_eq!(solana_policy.min_balance, Some(2000000));
-        } else {
-            panic!("Expected Solana policy");
+        let solana = create_test_relayer_solana(false, false);
+        assert_eq!(solana.capabilities(), RelayerCapabilities::default());
+    }
+
+    #[test]
+    fn test_validate_aliases() {
+        let mut relayer = create_test_relayer(false, false);
+        relayer.aliases = vec!["billing-7".to_string(), "crm:relayer".to_string()];
+        assert!(relayer.validate_aliases().is_ok());
+
+        for aliases in [
+            vec![String::new()],
+            vec!["a".to_string(), "a".to_string()],
+            vec![relayer.id.clone()],
+        ] {
+            relayer.aliases = aliases;
+            assert!(matches!(
+                relayer.validate_aliases(),
+                Err(RepositoryError::InvalidData(_))
+            ));
         }
     }
 
     #[test]
-    fn test_relayer_repo_updater_preserves_runtime_fields_stellar() {
-        // Create an original Stellar relayer with runtime fields set
-        let original = RelayerRepoModel {
-            id: "test_stellar_relayer".to_string(),
-            name: "Original Stellar Name".to_string(),
-            address: "GORIGINALXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(), // Runtime field
-            system_disabled: false, // Runtime field
-            disabled_reason: None,  // Runtime field
-            paused: true,
-            network: "mainnet".to_string(),
-            network_type: NetworkType::Stellar,
-            signer_id: "test_signer".to_string(),
-            policies: RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default()),
-            notification_id: Some("original_notification".to_string()),
-            custom_rpc_urls: None,
-        };
+    fn test_has_error_since() {
+        let now = Utc::now();
+        let mut relayer = create_test_relayer(false, false);
+        assert!(!relayer.has_error_since(now - chrono::Duration::hours(1)));
 
-        // Create a domain model with different business fields
-        let domain_update = Relayer {
-            id: "test_stellar_relayer".to_string(),
-            name: "Updated Stellar Name".to_string(), // Changed
-            paused: false,                            // Changed
-            network: "mainnet".to_string(),
-            network_type: RelayerNetworkType::Stellar,
-            signer_id: "test_signer".to_string(),
-            policies: Some(RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
-                min_balance: Some(40000000), // Changed
-                max_fee: Some(200000),       // Changed
-                timeout_seconds: Some(120),  // Changed
-                concurrent_transactions: None,
-                allowed_tokens: None,
-                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
-                slippage_percentage: None,
-                fee_margin_percentage: None,
-                swap_config: None,
-            })),
-            notification_id: None, // Changed
-            custom_rpc_urls: None,
-        };
+        relayer.last_error = Some(RelayerLastError {
+            message: "nonce too low".to_string(),
+            at: now,
+            kind: RelayerErrorKind::Submission,
+        });
+        assert!(relayer.has_error_since(now - chrono::Duration::hours(1)));
+        assert!(!relayer.has_error_since(now));
+    }
+
+    #[test]
+    fn test_config_checksum_changes_with_policy() {
+        let relayer = create_test_relayer(false, false);
+        let mut policy_changed = relayer.clone();
+        policy_changed.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(100_000_000_000),
+            ..RelayerEvmPolicy::default()
+        });
+        assert_ne!(policy_changed.config_checksum(), relayer.config_checksum());
+    }
+
+    #[test]
+    fn test_record_recent_outcome_keeps_most_recent() {
+        let mut relayer = create_test_relayer(false, false);
+        let now = Utc::now();
+        for i in 0..5 {
+            relayer.record_recent_outcome(i % 2 == 0, now, 3);
+        }
+        assert_eq!(relayer.recent_outcomes.len(), 3);
+        let successes: Vec<bool> = relayer.recent_outcomes.iter().map(|o| o.success).collect();
+        assert_eq!(successes, vec![true, false, true]);
+    }
 
-        // Use updater to preserve runtime fields
-        let updated =
-            RelayerRepoUpdater::from_existing(original.clone()).apply_domain_update(domain_update);
+    #[test]
+    fn test_error_rate_since_ignores_outcomes_before_window() {
+        let mut relayer = create_test_relayer(false, false);
+        let now = Utc::now();
+        assert_eq!(relayer.error_rate_since(now), None);
 
-        // Verify business fields were updated
-        assert_eq!(updated.name, "Updated Stellar Name");
-        assert!(!updated.paused);
-        assert_eq!(updated.notification_id, None);
+        relayer.record_recent_outcome(false, now - chrono::Duration::minutes(10), 10);
+        relayer.record_recent_outcome(false, now, 10);
+        relayer.record_recent_outcome(true, now, 10);
+        relayer.record_recent_outcome(true, now, 10);
+        relayer.record_recent_outcome(true, now, 10);
 
-        // Verify runtime fields were preserved
         assert_eq!(
-            updated.address,
-            "GORIGINALXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+            relayer.error_rate_since(now - chrono::Duration::minutes(1)),
+            Some(0.25)
+        );
+        assert_eq!(
+            relayer.error_rate_since(now - chrono::Duration::hours(1)),
+            Some(0.4)
         );
-        assert!(!updated.system_disabled);
-        assert_eq!(updated.disabled_reason, None);
-
-        // Verify policies were updated
-        if let RelayerNetworkPolicy::Stellar(stellar_policy) = updated.policies {
-            assert_eq!(stellar_policy.min_balance, Some(40000000));
-            assert_eq!(stellar_policy.max_fee, Some(200000));
-            assert_eq!(stellar_policy.timeout_seconds, Some(120));
-        } else {
-            panic!("Expected Stellar policy");
-        }
     }
 
     #[test]
-    fn test_repo_model_serialization_deserialization_evm() {
-        let original = create_test_relayer(false, false);
+    fn test_log_level_none_inherits_global() {
+        let mut relayer = create_test_relayer(false, false);
+        assert_eq!(relayer.log_level(), None);
+        assert_eq!(relayer.log_level_or(LogLevel::Warn), LogLevel::Warn);
 
-        // Serialize to JSON
-        let serialized = serde_json::to_string(&original).unwrap();
-        assert!(!serialized.is_empty());
+        relayer.log_level = Some(LogLevel::Debug);
+        assert_eq!(relayer.log_level_or(LogLevel::Warn), LogLevel::Debug);
+        assert_eq!(tracing::Level::from(LogLevel::Debug), tracing::Level::DEBUG);
+    }
 
-        // Deserialize back
-        let deserialized: RelayerRepoModel = serde_json::from_str(&serialized).unwrap();
+    #[test]
+    fn test_log_level_serialization() {
+        let mut relayer = create_test_relayer(false, false);
+        let json = serde_json::to_value(&relayer).unwrap();
+        assert!(json.get("log_level").is_none());
 
-        // Verify all fields match
-        assert_eq!(original.id, deserialized.id);
-        assert_eq!(original.name, deserialized.name);
-        assert_eq!(original.network, deserialized.network);
-        assert_eq!(original.paused, deserialized.paused);
-        assert_eq!(original.network_type, deserialized.network_type);
-        assert_eq!(original.signer_id, deserialized.signer_id);
-        assert_eq!(original.address, deserialized.address);
-        assert_eq!(original.notification_id, deserialized.notification_id);
-        assert_eq!(original.system_disabled, deserialized.system_disabled);
-        assert_eq!(original.custom_rpc_urls, deserialized.custom_rpc_urls);
+        relayer.log_level = Some(LogLevel::Trace);
+        let json = serde_json::to_value(&relayer).unwrap();
+        assert_eq!(json["log_level"], "trace");
+        let back: RelayerRepoModel = serde_json::from_value(json).unwrap();
+        assert_eq!(back.log_level, Some(LogLevel::Trace));
+    }
 
-        // Verify policies match
-        match (&original.policies, &deserialized.policies) {
-            (RelayerNetworkPolicy::Evm(_), RelayerNetworkPolicy::Evm(_)) => {
-                // Success - both are EVM policies
-            }
-            _ => panic!("Policy types don't match after serialization/deserialization"),
+    fn relayer_with_origins(origins: &[&str]) -> RelayerRepoModel {
+        RelayerRepoModel {
+            allowed_origins: Some(origins.iter().map(|o| o.to_string()).collect()),
+            ..create_test_relayer(false, false)
         }
     }
 
     #[test]
-    fn test_repo_model_serialization_deserialization_solana() {
-        let original = create_test_relayer_solana(true, false);
+    fn test_is_origin_allowed_exact_match() {
+        let relayer = relayer_with_origins(&["https://app.example.com"]);
+        assert!(relayer.is_origin_allowed("https://app.example.com"));
+        assert!(relayer.is_origin_allowed("HTTPS://App.Example.com"));
+        assert!(!relayer.is_origin_allowed("http://app.example.com"));
+    }
 
-        // Serialize to JSON
-        let serialized = serde_json::to_string(&original).unwrap();
-        assert!(!serialized.is_empty());
+    #[test]
+    fn test_is_origin_allowed_wildcard_subdomain() {
+        let relayer = relayer_with_origins(&["https://*.example.com"]);
+        assert!(relayer.is_origin_allowed("https://app.example.com"));
+        assert!(relayer.is_origin_allowed("https://a.b.example.com"));
+        // The wildcard requires a subdomain
+        assert!(!relayer.is_origin_allowed("https://example.com"));
+        assert!(!relayer.is_origin_allowed("https://evil-example.com"));
+    }
 
-        // Deserialize back
-        let deserialized: RelayerRepoModel = serde_json::from_str(&serialized).unwrap();
+    #[test]
+    fn test_is_origin_allowed_no_match() {
+        let relayer = relayer_with_origins(&["https://app.example.com", "https://*.example.org"]);
+        assert!(!relayer.is_origin_allowed("https://evil.com"));
+        assert!(!relayer.is_origin_allowed("https://app.example.com.evil.com"));
+        assert!(!relayer.is_origin_allowed("https://app.example.com:8443"));
+        assert!(!relayer_with_origins(&[]).is_origin_allowed("https://app.example.com"));
+    }
 
-        // Verify key fields match
-        assert_eq!(original.id, deserialized.id);
-        assert_eq!(original.network_type, RelayerNetworkType::Solana);
-        assert_eq!(deserialized.network_type, RelayerNetworkType::Solana);
-        assert_eq!(original.paused, deserialized.paused);
+    #[test]
+    fn test_is_origin_allowed_unrestricted() {
+        let relayer = create_test_relayer(false, false);
+        assert_eq!(relayer.allowed_origins, None);
+        assert!(relayer.is_origin_allowed("https://anything.example"));
+        assert!(relayer_with_origins(&["*"]).is_origin_allowed("https://anything.example"));
+    }
 
-        // Verify policies match
-        match (&original.policies, &deserialized.policies) {
-            (RelayerNetworkPolicy::Solana(orig), RelayerNetworkPolicy::Solana(deser)) => {
-                assert_eq!(orig.fee_payment_strategy, deser.fee_payment_strategy);
-                assert_eq!(orig.min_balance, deser.min_balance);
-                assert_eq!(orig.max_signatures, deser.max_signatures);
-            }
-            _ => panic!("Policy types don't match after serialization/deserialization"),
+    #[test]
+    fn test_validate_allowed_origins() {
+        assert!(relayer_with_origins(&[
+            "*",
+            "https://app.example.com",
+            "http://localhost:3000",
+            "https://*.example.com",
+        ])
+        .validate_allowed_origins()
+        .is_ok());
+
+        for invalid in [
+            "app.example.com",
+            "ftp://example.com",
+            "https://example.com/path",
+            "https://example.com/",
+            "https://app.*.example.com",
+            "https://*example.com",
+        ] {
+            assert!(
+                matches!(
+                    relayer_with_origins(&[invalid]).validate_allowed_origins(),
+                    Err(RepositoryError::InvalidData(_))
+                ),
+                "{invalid} should be rejected"
+            );
         }
     }
 
     #[test]
-    fn test_repo_model_serialization_deserialization_stellar() {
-        let original = create_test_relayer_stellar(false, true);
-
-        // Serialize to JSON
-        let serialized = serde_json::to_string(&original).unwrap();
-        assert!(!serialized.is_empty());
+    fn test_lint_clean_relayer_has_no_warnings() {
+        let relayer = create_test_relayer(false, false);
+        assert!(relayer.lint(None).is_empty());
+        assert!(relayer.lint(Some(50_000_000_000)).is_empty());
+    }
 
-        // Deserialize back
-        let deserialized: RelayerRepoModel = serde_json::from_str(&serialized).unwrap();
+    #[test]
+    fn test_lint_warns_on_zero_min_balance() {
+        let relayer = RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                min_balance: Some(0),
+                ..RelayerEvmPolicy::default()
+            }),
+            ..create_test_relayer(false, false)
+        };
+        let warnings = relayer.lint(None);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].severity, LintSeverity::Warning);
+        assert_eq!(warnings[0].field, "policies.min_balance");
+    }
 
-        // Verify key fields match
-        assert_eq!(original.id, deserialized.id);
-        assert_eq!(original.network_type, RelayerNetworkType::Stellar);
-        assert_eq!(deserialized.network_type, RelayerNetworkType::Stellar);
-        assert_eq!(original.system_disabled, deserialized.system_disabled);
+    #[test]
+    fn test_lint_warns_on_self_only_whitelist_and_low_gas_cap() {
+        let relayer = RelayerRepoModel {
+            address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                whitelist_receivers: Some(vec![
+                    "0x742d35cc6634c0532925a3b8d8c2e48a73f6ba2e".to_string()
+                ]),
+                gas_price_cap: Some(1_000_000_000),
+                ..RelayerEvmPolicy::default()
+            }),
+            ..create_test_relayer(false, false)
+        };
+        let fields = |warnings: Vec<LintWarning>| -> Vec<String> {
+            warnings.into_iter().map(|w| w.field).collect()
+        };
+        assert_eq!(
+            fields(relayer.lint(None)),
+            vec!["policies.whitelist_receivers"]
+        );
+        assert_eq!(
+            fields(relayer.lint(Some(30_000_000_000))),
+            vec!["policies.whitelist_receivers", "policies.gas_price_cap"]
+        );
+        // A cap within range of the network price is fine
+        assert_eq!(relayer.lint(Some(1_500_000_000)).len(), 1);
+    }
 
-        // Verify policies match
-        match (&original.policies, &deserialized.policies) {
-            (RelayerNetworkPolicy::Stellar(orig), RelayerNetworkPolicy::Stellar(deser)) => {
-                assert_eq!(orig.min_balance, deser.min_balance);
-                assert_eq!(orig.max_fee, deser.max_fee);
-                assert_eq!(orig.timeout_seconds, deser.timeout_seconds);
-            }
-            _ => panic!("Policy types don't match after serialization/deserialization"),
-        }
+    #[test]
+    fn test_signer_timeout_defaults_to_none() {
+        let relayer = create_test_relayer(false, false);
+        assert_eq!(relayer.signer_timeout_ms, None);
+        assert_eq!(relayer.signer_timeout(), None);
+        assert!(relayer.validate_signer_timeout().is_ok());
     }
 
     #[test]
-    fn test_repo_model_default() {
-        let default_model = RelayerRepoModel::default();
+    fn test_signer_timeout_converts_millis_to_duration() {
+        let relayer = RelayerRepoModel {
+            signer_timeout_ms: Some(2_500),
+            ..create_test_relayer(false, false)
+        };
+        assert_eq!(relayer.signer_timeout(), Some(Duration::from_millis(2_500)));
+    }
 
-        assert_eq!(default_model.id, "");
-        assert_eq!(default_model.name, "");
-        assert_eq!(default_model.network, "");
-        assert!(!default_model.paused);
-        assert_eq!(default_model.network_type, NetworkType::Evm);
-        assert_eq!(default_model.signer_id, "");
-        assert_eq!(default_model.address, "0x");
-        assert_eq!(default_model.notification_id, None);
-        assert!(!default_model.system_disabled);
-        assert_eq!(default_model.custom_rpc_urls, None);
+    #[test]
+    fn test_validate_signer_timeout_range() {
+        let with_timeout = |ms: u64| RelayerRepoModel {
+            signer_timeout_ms: Some(ms),
+            ..create_test_relayer(false, false)
+        };
+        assert!(with_timeout(100).validate_signer_timeout().is_ok());
+        assert!(with_timeout(60_000).validate_signer_timeout().is_ok());
+        assert!(matches!(
+            with_timeout(99).validate_signer_timeout(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(matches!(
+            with_timeout(60_001).validate_signer_timeout(),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(!with_timeout(0).validation_errors().is_empty());
+    }
 
-        // Default should have EVM policy
-        if let RelayerNetworkPolicy::Evm(_) = default_model.policies {
-            // Success
-        } else {
-            panic!("Default should have EVM policy");
+    fn create_spend_capped_relayer(cap: u128) -> RelayerRepoModel {
+        RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_daily_spend: Some(cap),
+                ..RelayerEvmPolicy::default()
+            }),
+            ..create_test_relayer(false, false)
         }
     }
 
     #[test]
-    fn test_round_trip_conversion_all_network_types() {
-        // Test round-trip conversion: Domain -> Repo -> Domain for all network types
+    fn test_record_spend_allows_spending_up_to_cap() {
+        let mut relayer = create_spend_capped_relayer(100);
+        let now = Utc::now();
+        assert_eq!(relayer.record_spend(60, now).unwrap(), 60);
+        assert_eq!(relayer.record_spend(40, now).unwrap(), 100);
+        assert_eq!(relayer.spent_today, 100);
+        assert_eq!(relayer.spend_window_start, Some(now));
+    }
 
-        // EVM
-        let original_evm = Relayer {
-            id: "evm_relayer".to_string(),
-            name: "EVM Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Evm,
-            policies: Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
-                gas_price_cap: Some(50_000_000_000),
-                eip1559_pricing: Some(true),
-                min_balance: None,
-                gas_limit_estimation: None,
-                whitelist_receivers: None,
-                private_transactions: None,
-            })),
-            signer_id: "evm_signer".to_string(),
-            notification_id: Some("evm_notification".to_string()),
-            custom_rpc_urls: None,
-        };
+    #[test]
+    fn test_record_spend_rejects_spend_over_cap() {
+        let mut relayer = create_spend_capped_relayer(100);
+        let now = Utc::now();
+        relayer.record_spend(90, now).unwrap();
+        assert!(matches!(
+            relayer.record_spend(11, now),
+            Err(RepositoryError::Conflict(_))
+        ));
+        // The rejected spend is not counted
+        assert_eq!(relayer.spent_today, 90);
+    }
 
-        let repo_evm = RelayerRepoModel::from(original_evm.clone());
-        let recovered_evm = Relayer::from(repo_evm);
+    #[test]
+    fn test_record_spend_resets_after_window_rolls_over() {
+        let mut relayer = create_spend_capped_relayer(100);
+        let start = Utc::now();
+        relayer.record_spend(100, start).unwrap();
 
-        assert_eq!(original_evm.id, recovered_evm.id);
-        assert_eq!(original_evm.network_type, recovered_evm.network_type);
-        assert_eq!(original_evm.notification_id, recovered_evm.notification_id);
+        let almost = start + chrono::Duration::seconds(RELAYER_SPEND_WINDOW_SECONDS - 1);
+        assert!(relayer.record_spend(1, almost).is_err());
 
-        // Solana
-        let original_solana = Relayer {
-            id: "solana_relayer".to_string(),
-            name: "Solana Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: true,
-            network_type: RelayerNetworkType::Solana,
-            policies: Some(RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
-                fee_payment_strategy: Some(SolanaFeePaymentStrategy::User),
-                min_balance: Some(3000000),
-                max_signatures: None,
-                allowed_tokens: None,
-                allowed_programs: None,
-                allowed_accounts: None,
-                disallowed_accounts: None,
-                max_tx_data_size: None,
-                max_allowed_fee_lamports: None,
-                swap_config: None,
-                fee_margin_percentage: None,
-            })),
-            signer_id: "solana_signer".to_string(),
-            notification_id: None,
-            custom_rpc_urls: None,
+        let next_day = start + chrono::Duration::seconds(RELAYER_SPEND_WINDOW_SECONDS);
+        assert_eq!(relayer.record_spend(30, next_day).unwrap(), 30);
+        assert_eq!(relayer.spend_window_start, Some(next_day));
+    }
+
+    #[test]
+    fn test_record_spend_is_unbounded_without_cap() {
+        let mut relayer = create_test_relayer(false, false);
+        assert_eq!(
+            relayer.record_spend(u128::MAX, Utc::now()).unwrap(),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn test_canary_relayer_accepts_only_canary_transactions() {
+        let relayer = RelayerRepoModel {
+            mode: RelayerMode::Canary,
+            ..create_test_relayer(false, false)
         };
+        assert!(relayer.accepts_transaction(true));
+        assert!(!relayer.accepts_transaction(false));
+    }
 
-        let repo_solana = RelayerRepoModel::from(original_solana.clone());
-        let recovered_solana = Relayer::from(repo_solana);
+    #[test]
+    fn test_live_relayer_accepts_all_transactions() {
+        let relayer = create_test_relayer(false, false);
+        assert_eq!(relayer.mode, RelayerMode::Live);
+        assert!(relayer.accepts_transaction(true));
+        assert!(relayer.accepts_transaction(false));
 
-        assert_eq!(original_solana.id, recovered_solana.id);
-        assert_eq!(original_solana.network_type, recovered_solana.network_type);
-        assert_eq!(original_solana.paused, recovered_solana.paused);
+        // A paused relayer takes nothing, canary or not
+        let paused = create_test_relayer(true, false);
+        assert!(!paused.accepts_transaction(true));
+        assert!(!paused.accepts_transaction(false));
+    }
 
-        // Stellar
-        let original_stellar = Relayer {
-            id: "stellar_relayer".to_string(),
-            name: "Stellar Relayer".to_string(),
-            network: "mainnet".to_string(),
-            paused: false,
-            network_type: RelayerNetworkType::Stellar,
-            policies: Some(RelayerNetworkPolicy::Stellar(RelayerStellarPolicy {
-                min_balance: Some(50000000),
-                max_fee: Some(250000),
-                timeout_seconds: Some(180),
-                concurrent_transactions: None,
-                allowed_tokens: None,
-                fee_payment_strategy: Some(StellarFeePaymentStrategy::Relayer),
-                slippage_percentage: None,
-                fee_margin_percentage: None,
-                swap_config: None,
-            })),
-            signer_id: "stellar_signer".to_string(),
-            notification_id: Some("stellar_notification".to_string()),
-            custom_rpc_urls: None,
-        };
+    #[test]
+    fn test_relayer_mode_defaults_to_live_when_missing() {
+        let mut value = serde_json::to_value(create_test_relayer(false, false)).unwrap();
+        value.as_object_mut().unwrap().remove("mode");
+        let relayer: RelayerRepoModel = serde_json::from_value(value).unwrap();
+        assert_eq!(relayer.mode, RelayerMode::Live);
+    }
 
-        let repo_stellar = RelayerRepoModel::from(original_stellar.clone());
-        let recovered_stellar = Relayer::from(repo_stellar);
+    #[test]
+    fn test_transfer_ownership_keeps_bounded_history() {
+        let mut relayer = create_test_relayer(false, false);
 
-        assert_eq!(original_stellar.id, recovered_stellar.id);
-        assert_eq!(
-            original_stellar.network_type,
-            recovered_stellar.network_type
-        );
+        for tenant in ["tenant-1", "tenant-2", "tenant-3"] {
+            relayer
+                .transfer_ownership(tenant.to_string(), "ops".to_string(), 2)
+                .unwrap();
+        }
+        assert_eq!(relayer.tenant_id, "tenant-3");
+        let moves: Vec<_> = relayer
+            .ownership_history
+            .iter()
+            .map(|t| (t.from_tenant.as_str(), t.to_tenant.as_str()))
+            .collect();
         assert_eq!(
-            original_stellar.notification_id,
-            recovered_stellar.notification_id
+            moves,
+            vec![("tenant-1", "tenant-2"), ("tenant-2", "tenant-3")]
         );
+
+        for (tenant, actor) in [("", "ops"), ("tenant-4", ""), ("tenant-3", "ops")] {
+            let result = relayer.transfer_ownership(tenant.to_string(), actor.to_string(), 2);
+            assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        }
+        assert_eq!(relayer.tenant_id, "tenant-3");
     }
 }
@@ -15,7 +15,10 @@ use super::{
     Relayer, RelayerEvmPolicy, RelayerNetworkPolicy, RelayerNetworkType, RelayerSolanaPolicy,
     RelayerStellarPolicy, RpcConfig,
 };
-use crate::{models::error::ApiError, utils::generate_uuid};
+use crate::{
+    models::{error::ApiError, RepositoryError},
+    utils::{deserialize_double_option, generate_uuid},
+};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -169,12 +172,49 @@ pub struct UpdateRelayerRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub policies: Option<CreateRelayerPolicyRequest>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(nullable = false)]
-    pub notification_id: Option<String>,
+    /// Missing leaves the notification unchanged, `null` removes it and a string sets it
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_double_option"
+    )]
+    #[schema(value_type = Option<String>, nullable = true)]
+    pub notification_id: Option<Option<String>>,
     pub custom_rpc_urls: Option<Vec<RpcConfig>>,
 }
 
+impl UpdateRelayerRequest {
+    /// Returns true if none of the fields handled by repository partial updates
+    /// (paused, notification_id, name) are set
+    pub fn is_empty_patch(&self) -> bool {
+        self.paused.is_none() && self.notification_id.is_none() && self.name.is_none()
+    }
+
+    /// Validates the fields handled by repository partial updates.
+    ///
+    /// Whether a notification ID refers to an existing notification is checked by the
+    /// API layer, which has access to the notification repository.
+    pub fn validate_patch(&self) -> Result<(), RepositoryError> {
+        if self
+            .name
+            .as_ref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
+            return Err(RepositoryError::InvalidData(
+                "Relayer name cannot be empty".to_string(),
+            ));
+        }
+        if let Some(Some(notification_id)) = &self.notification_id {
+            if notification_id.is_empty() {
+                return Err(RepositoryError::InvalidData(
+                    "Notification ID cannot be empty".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Request model for updating an existing relayer
 /// All fields are optional to allow partial updates
 /// Note: network and signer_id are not updateable after creation
@@ -261,7 +301,8 @@ mod tests {
     use super::*;
     use crate::models::{
         relayer::{
-            RelayerEvmPolicy, RelayerSolanaPolicy, RelayerStellarPolicy, SolanaFeePaymentStrategy,
+            GasOracleConfig, RelayerEvmPolicy, RelayerSolanaPolicy, RelayerStellarPolicy,
+            RevertHandling, SolanaFeePaymentStrategy, StuckTxAction,
         },
         StellarFeePaymentStrategy,
     };
@@ -281,6 +322,23 @@ mod tests {
                 private_transactions: None,
                 min_balance: None,
                 gas_limit_estimation: None,
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             })),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -310,6 +368,10 @@ mod tests {
                 slippage_percentage: None,
                 fee_margin_percentage: None,
                 swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             })),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -352,6 +414,10 @@ mod tests {
                 max_allowed_fee_lamports: None,
                 swap_config: None,
                 fee_margin_percentage: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             })),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -419,6 +485,10 @@ mod tests {
                 max_allowed_fee_lamports: None,
                 swap_config: None,
                 fee_margin_percentage: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             })),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -463,6 +533,10 @@ mod tests {
                 slippage_percentage: None,
                 fee_margin_percentage: None,
                 swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             })),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -882,6 +956,21 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_update_request_notification_id_distinguishes_null_from_missing() {
+        let missing: UpdateRelayerRequest = serde_json::from_str(r#"{"name": "Test"}"#).unwrap();
+        assert_eq!(missing.notification_id, None);
+
+        let cleared: UpdateRelayerRequest =
+            serde_json::from_str(r#"{"notification_id": null}"#).unwrap();
+        assert_eq!(cleared.notification_id, Some(None));
+        assert!(!cleared.is_empty_patch());
+
+        let set: UpdateRelayerRequest =
+            serde_json::from_str(r#"{"notification_id": "notif-123"}"#).unwrap();
+        assert_eq!(set.notification_id, Some(Some("notif-123".to_string())));
+    }
+
     #[test]
     fn test_comprehensive_update_request() {
         // Test a comprehensive update request with multiple fields
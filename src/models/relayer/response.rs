@@ -185,7 +185,9 @@ impl From<Relayer> for RelayerResponse {
                 .map(|policy| convert_policy_to_response(policy, relayer.network_type)),
             signer_id: relayer.signer_id,
             notification_id: relayer.notification_id,
-            custom_rpc_urls: relayer.custom_rpc_urls,
+            custom_rpc_urls: relayer
+                .custom_rpc_urls
+                .map(|configs| configs.into_iter().map(RpcConfig::redacted).collect()),
             address: None,
             system_disabled: None,
             disabled_reason: None,
@@ -214,7 +216,9 @@ impl From<RelayerRepoModel> for RelayerResponse {
             policies,
             signer_id: model.signer_id,
             notification_id: model.notification_id,
-            custom_rpc_urls: model.custom_rpc_urls,
+            custom_rpc_urls: model
+                .custom_rpc_urls
+                .map(|configs| configs.into_iter().map(RpcConfig::redacted).collect()),
             address: Some(model.address),
             system_disabled: Some(model.system_disabled),
             disabled_reason: model.disabled_reason,
@@ -549,9 +553,10 @@ mod tests {
     use super::*;
     use crate::models::{
         relayer::{
-            RelayerEvmPolicy, RelayerSolanaPolicy, RelayerSolanaSwapConfig, RelayerStellarPolicy,
-            SolanaAllowedTokensPolicy, SolanaFeePaymentStrategy, SolanaSwapStrategy,
-            StellarAllowedTokensPolicy, StellarFeePaymentStrategy, StellarSwapStrategy,
+            GasOracleConfig, RelayerEvmPolicy, RelayerSolanaPolicy, RelayerSolanaSwapConfig,
+            RelayerStellarPolicy, RevertHandling, SolanaAllowedTokensPolicy,
+            SolanaFeePaymentStrategy, SolanaSwapStrategy, StellarAllowedTokensPolicy,
+            StellarFeePaymentStrategy, StellarSwapStrategy, StuckTxAction,
         },
         StellarTokenKind, StellarTokenMetadata,
     };
@@ -571,6 +576,23 @@ mod tests {
                 private_transactions: None,
                 min_balance: None,
                 gas_limit_estimation: None,
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             })),
             "test-signer".to_string(),
             None,
@@ -594,6 +616,23 @@ mod tests {
                     private_transactions: None,
                     min_balance: Some(DEFAULT_EVM_MIN_BALANCE),
                     gas_limit_estimation: Some(DEFAULT_EVM_GAS_LIMIT_ESTIMATION),
+                    low_balance_alerting: None,
+                    validation_rules: vec![],
+                    gas_oracle: GasOracleConfig::ProviderDefault,
+                    simulate_before_send: false,
+                    revert_handling: RevertHandling::Reject,
+                    max_calldata_bytes: None,
+                    gas_bump: None,
+                    transaction_deadline_secs: None,
+                    max_inflight_transactions: None,
+                    allowed_tx_types: None,
+                    accepted_fee_tokens: None,
+                    min_priority_fee_per_gas: None,
+                    max_daily_spend: None,
+                    stuck_tx_action: StuckTxAction::SpeedUp,
+                    gas_estimation_multiplier: None,
+                    gas_limit_cap: None,
+                    max_rpc_batch_size: None,
                 }
                 .into()
             ))
@@ -605,6 +644,27 @@ mod tests {
         assert_eq!(response.system_disabled, None);
     }
 
+    #[test]
+    fn test_from_repo_model_redacts_rpc_header_values() {
+        let model = RelayerRepoModel {
+            custom_rpc_urls: Some(vec![RpcConfig {
+                rpc_headers: Some(
+                    [("x-api-key".to_string(), "super-secret-key".to_string())]
+                        .into_iter()
+                        .collect(),
+                ),
+                ..RpcConfig::new("https://example.com".to_string())
+            }]),
+            ..Default::default()
+        };
+
+        let response = RelayerResponse::from(model);
+        let configs = response.custom_rpc_urls.unwrap();
+        let headers = configs[0].rpc_headers.as_ref().unwrap();
+        assert_eq!(headers["x-api-key"], "[REDACTED]");
+        assert_eq!(configs[0].url, "https://example.com");
+    }
+
     #[test]
     fn test_from_domain_relayer_solana() {
         let relayer = Relayer::new(
@@ -629,6 +689,10 @@ mod tests {
                 disallowed_accounts: None,
                 max_allowed_fee_lamports: None,
                 swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             })),
             "test-signer".to_string(),
             None,
@@ -667,6 +731,10 @@ mod tests {
                 slippage_percentage: None,
                 fee_margin_percentage: None,
                 swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             })),
             "test-signer".to_string(),
             None,
@@ -1056,6 +1124,23 @@ mod tests {
                 gas_limit_estimation: None,
                 whitelist_receivers: None,
                 private_transactions: None,
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             }),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -1104,6 +1189,10 @@ mod tests {
                 disallowed_accounts: None,
                 max_allowed_fee_lamports: None,
                 swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -1173,6 +1262,10 @@ mod tests {
                     cron_schedule: Some("0 0 * * *".to_string()),
                     min_balance_threshold: Some(10000000),
                 }),
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             signer_id: "test-signer".to_string(),
             notification_id: None,
@@ -1237,6 +1330,10 @@ mod tests {
             slippage_percentage: None,
             fee_margin_percentage: None,
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         };
 
         let response_with_user = StellarPolicyResponse::from(policy_with_user);
@@ -1257,6 +1354,10 @@ mod tests {
             slippage_percentage: None,
             fee_margin_percentage: None,
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         };
 
         let response_with_relayer = StellarPolicyResponse::from(policy_with_relayer);
@@ -1277,6 +1378,10 @@ mod tests {
             slippage_percentage: None,
             fee_margin_percentage: None,
             swap_config: None,
+            low_balance_alerting: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            max_daily_spend: None,
         };
 
         let response_omitted = StellarPolicyResponse::from(policy_omitted);
@@ -6,6 +6,8 @@
 use crate::constants::DEFAULT_RPC_WEIGHT;
 use eyre::{eyre, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use thiserror::Error;
 use utoipa::ToSchema;
 
@@ -13,6 +15,10 @@ use utoipa::ToSchema;
 pub enum RpcConfigError {
     #[error("Invalid weight: {value}. Must be between 0 and 100.")]
     InvalidWeight { value: u8 },
+    #[error("Invalid RPC header name: {name:?}. Must be a valid HTTP token.")]
+    InvalidHeaderName { name: String },
+    #[error("Invalid value for RPC header {name}: must not contain control characters.")]
+    InvalidHeaderValue { name: String },
 }
 
 /// Returns the default RPC weight.
@@ -21,7 +27,7 @@ fn default_rpc_weight() -> u8 {
 }
 
 /// Configuration for an RPC endpoint.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+#[derive(Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub struct RpcConfig {
     /// The RPC endpoint URL.
     pub url: String,
@@ -29,6 +35,29 @@ pub struct RpcConfig {
     /// Defaults to DEFAULT_RPC_WEIGHT (100). Should be between 0 and 100.
     #[serde(default = "default_rpc_weight")]
     pub weight: u8,
+    /// Extra headers sent with every request to this endpoint, such as provider API keys.
+    /// Values are treated as secrets and redacted from the `Debug` output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_headers: Option<HashMap<String, String>>,
+}
+
+impl fmt::Debug for RpcConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Header names are kept so the configuration stays debuggable, values may be secrets
+        let rpc_headers = self.rpc_headers.as_ref().map(|headers| {
+            let mut names: Vec<&String> = headers.keys().collect();
+            names.sort();
+            names
+                .into_iter()
+                .map(|name| (name, "[REDACTED]"))
+                .collect::<Vec<_>>()
+        });
+        f.debug_struct("RpcConfig")
+            .field("url", &self.url)
+            .field("weight", &self.weight)
+            .field("rpc_headers", &rpc_headers)
+            .finish()
+    }
 }
 
 impl RpcConfig {
@@ -41,6 +70,7 @@ impl RpcConfig {
         Self {
             url,
             weight: DEFAULT_RPC_WEIGHT,
+            rpc_headers: None,
         }
     }
 
@@ -59,7 +89,11 @@ impl RpcConfig {
         if weight > 100 {
             return Err(RpcConfigError::InvalidWeight { value: weight });
         }
-        Ok(Self { url, weight })
+        Ok(Self {
+            url,
+            weight,
+            rpc_headers: None,
+        })
     }
 
     /// Gets the weight of this RPC endpoint.
@@ -71,6 +105,40 @@ impl RpcConfig {
         self.weight
     }
 
+    /// Returns this configuration with every header value replaced by `[REDACTED]`,
+    /// for responses and logs that must not expose provider credentials.
+    pub fn redacted(mut self) -> Self {
+        if let Some(headers) = &mut self.rpc_headers {
+            headers
+                .values_mut()
+                .for_each(|value| *value = "[REDACTED]".to_string());
+        }
+        self
+    }
+
+    /// Validates the custom headers of this endpoint.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if every header name is a valid HTTP token and no value contains
+    ///   control characters.
+    /// * `Err(RpcConfigError::InvalidHeaderName)` or `Err(RpcConfigError::InvalidHeaderValue)`
+    ///   for the first offending header. Errors never include the header value.
+    pub fn validate_headers(&self) -> Result<(), RpcConfigError> {
+        let Some(headers) = &self.rpc_headers else {
+            return Ok(());
+        };
+        for (name, value) in headers {
+            if !is_http_token(name) {
+                return Err(RpcConfigError::InvalidHeaderName { name: name.clone() });
+            }
+            if value.chars().any(char::is_control) {
+                return Err(RpcConfigError::InvalidHeaderValue { name: name.clone() });
+            }
+        }
+        Ok(())
+    }
+
     /// Validates that a URL has an HTTP or HTTPS scheme.
     /// Helper function, hence private.
     fn validate_url_scheme(url: &str) -> Result<()> {
@@ -89,7 +157,8 @@ impl RpcConfig {
     /// * `configs` - A slice of RpcConfig objects
     ///
     /// # Returns
-    /// * `Result<()>` - Ok if all URLs have valid schemes, error on first invalid URL
+    /// * `Result<()>` - Ok if all URLs have valid schemes and all headers are valid,
+    ///   error on the first invalid entry
     ///
     /// # Examples
     /// ```rust, ignore
@@ -105,11 +174,20 @@ impl RpcConfig {
         for config in configs {
             // Call the helper function using Self to refer to the type for associated functions
             Self::validate_url_scheme(&config.url)?;
+            config.validate_headers()?;
         }
         Ok(())
     }
 }
 
+/// Returns true if `name` is a non-empty HTTP token (RFC 9110, section 5.6.2).
+fn is_http_token(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,7 +218,11 @@ mod tests {
     fn test_get_weight_returns_weight_value() {
         let url = "https://example.com".to_string();
         let weight: u8 = 10;
-        let config = RpcConfig { url, weight };
+        let config = RpcConfig {
+            url,
+            weight,
+            rpc_headers: None,
+        };
 
         assert_eq!(config.get_weight(), weight);
     }
@@ -242,4 +324,88 @@ mod tests {
         let result = RpcConfig::validate_list(&configs);
         assert!(result.is_err(), "Should fail with all invalid URLs");
     }
+
+    fn config_with_headers(headers: &[(&str, &str)]) -> RpcConfig {
+        RpcConfig {
+            rpc_headers: Some(
+                headers
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            ),
+            ..RpcConfig::new("https://example.com".to_string())
+        }
+    }
+
+    #[test]
+    fn test_validate_headers_accepts_valid_headers() {
+        let config = config_with_headers(&[
+            ("x-api-key", "secret-key"),
+            ("Authorization", "Bearer token with spaces"),
+        ]);
+        assert!(config.validate_headers().is_ok());
+        assert!(RpcConfig::validate_list(&[config]).is_ok());
+        assert!(RpcConfig::new("https://example.com".to_string())
+            .validate_headers()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_headers_rejects_invalid_name() {
+        for name in ["", "x api key", "x-api-key:", "x-ключ"] {
+            let config = config_with_headers(&[(name, "value")]);
+            assert_eq!(
+                config.validate_headers(),
+                Err(RpcConfigError::InvalidHeaderName {
+                    name: name.to_string()
+                })
+            );
+        }
+        let config = config_with_headers(&[("bad name", "value")]);
+        assert!(RpcConfig::validate_list(&[config]).is_err());
+    }
+
+    #[test]
+    fn test_validate_headers_rejects_control_characters_in_value() {
+        let config = config_with_headers(&[("x-api-key", "secret\r\nInjected: yes")]);
+        let err = config.validate_headers().unwrap_err();
+        assert_eq!(
+            err,
+            RpcConfigError::InvalidHeaderValue {
+                name: "x-api-key".to_string()
+            }
+        );
+        assert!(!err.to_string().contains("secret"));
+    }
+
+    #[test]
+    fn test_debug_redacts_header_values() {
+        let config = config_with_headers(&[("x-api-key", "super-secret-key")]);
+        let debug = format!("{config:?}");
+        assert!(debug.contains("x-api-key"));
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("super-secret-key"));
+        assert!(debug.contains("https://example.com"));
+    }
+
+    #[test]
+    fn test_headers_round_trip_through_serde() {
+        let config = config_with_headers(&[("x-api-key", "secret")]);
+        let json = serde_json::to_string(&config).unwrap();
+        let back: RpcConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, config);
+
+        let plain =
+            serde_json::to_value(RpcConfig::new("https://example.com".to_string())).unwrap();
+        assert!(plain.get("rpc_headers").is_none());
+    }
+
+    #[test]
+    fn test_redacted_replaces_header_values() {
+        let redacted = config_with_headers(&[("x-api-key", "super-secret-key")]).redacted();
+        assert_eq!(
+            redacted.rpc_headers.unwrap()["x-api-key"],
+            "[REDACTED]".to_string()
+        );
+    }
 }
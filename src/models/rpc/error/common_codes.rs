@@ -40,8 +40,7 @@ mod tests {
         for code in codes {
             assert!(
                 !(code >= -32099 && code <= -32000),
-                "Code {} is part of the reserved range for implementation-defined server errors",
-                code
+                "Code {code} is part of the reserved range for implementation-defined server errors"
             );
         }
     }
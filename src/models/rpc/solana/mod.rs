@@ -156,6 +156,9 @@ pub struct SignTransactionResult {
 #[derive(Clone)]
 pub struct SignAndSendTransactionRequestParams {
     pub transaction: EncodedSerializedTransaction,
+    /// Marks a test transaction accepted while the relayer is in canary mode
+    #[serde(default)]
+    pub canary: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
@@ -32,6 +32,9 @@ use serde::{Deserialize, Serialize};
 pub struct SignerRepoModel {
     pub id: String,
     pub config: SignerConfigStorage,
+    /// Chain ids the signer may back relayers on; unrestricted when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_chains: Option<Vec<u64>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -190,6 +193,7 @@ impl From<Signer> for SignerRepoModel {
         Self {
             id: signer.id,
             config: signer.config.into(),
+            allowed_chains: None,
         }
     }
 }
@@ -530,6 +534,7 @@ mod tests {
         let repo_model = SignerRepoModel {
             id: "test-id".to_string(),
             config: SignerConfigStorage::AwsKms(domain_config),
+            allowed_chains: None,
         };
 
         let core = Signer::from(repo_model);
@@ -553,6 +558,7 @@ mod tests {
         let repo_model = SignerRepoModel {
             id: "test-id".to_string(),
             config: SignerConfigStorage::Local(storage_config),
+            allowed_chains: None,
         };
 
         assert!(repo_model.validate().is_ok());
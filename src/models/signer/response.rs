@@ -184,6 +184,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[1; 32])),
             }),
+            allowed_chains: None,
         };
 
         let response = SignerResponse::from(repo_model);
@@ -247,13 +248,13 @@ mod tests {
             let repo_model = SignerRepoModel {
                 id: "test".to_string(),
                 config,
+                allowed_chains: None,
             };
 
             let response = SignerResponse::from(repo_model);
             assert_eq!(
                 response.r#type, expected_type,
-                "Type mapping failed for {:?}",
-                expected_type
+                "Type mapping failed for {expected_type:?}"
             );
             assert_eq!(response.config, expected_config);
         }
@@ -805,6 +805,11 @@ impl
             &NetworkRepoModel,
         ),
     ) -> Result<Self, Self::Error> {
+        // Every new transaction goes through here, so the canary gate covers all submission paths
+        if !relayer_model.accepts_mode(request.is_canary()) {
+            return Err(RelayerError::RelayerInCanaryMode);
+        }
+
         let now = Utc::now().to_rfc3339();
 
         match request {
@@ -1607,6 +1612,7 @@ mod tests {
             max_priority_fee_per_gas: Some(2000000000), // Should be ignored
             speed: Some(Speed::Fast),
             valid_until: None,
+            canary: false,
         };
 
         let result = EvmTransactionData::for_replacement(&old_data, &new_request);
@@ -1655,6 +1661,7 @@ mod tests {
             max_priority_fee_per_gas: None,
             speed: Some(Speed::Fast),
             valid_until: Some("2024-12-31T23:59:59Z".to_string()),
+            canary: false,
         });
 
         let relayer_model = RelayerRepoModel {
@@ -1723,6 +1730,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_try_from_network_transaction_request_canary_relayer() {
+        use crate::models::{NetworkRepoModel, NetworkType, RelayerMode, RelayerRepoModel};
+
+        let request = |canary: bool| {
+            NetworkTransactionRequest::Evm(EvmTransactionRequest {
+                to: Some("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string()),
+                value: U256::from(1u64),
+                data: None,
+                gas_limit: Some(21000),
+                gas_price: Some(20000000000),
+                max_fee_per_gas: None,
+                max_priority_fee_per_gas: None,
+                speed: None,
+                valid_until: None,
+                canary,
+            })
+        };
+
+        let relayer_model = RelayerRepoModel {
+            id: "relayer-id".to_string(),
+            network_type: NetworkType::Evm,
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
+            mode: RelayerMode::Canary,
+            ..Default::default()
+        };
+
+        let network_model = NetworkRepoModel {
+            id: "evm:ethereum".to_string(),
+            name: "ethereum".to_string(),
+            network_type: NetworkType::Evm,
+            config: NetworkConfigData::Evm(EvmNetworkConfig {
+                common: NetworkConfigCommon {
+                    network: "ethereum".to_string(),
+                    from: None,
+                    rpc_urls: Some(vec!["https://mainnet.infura.io".to_string()]),
+                    explorer_urls: None,
+                    average_blocktime_ms: Some(12000),
+                    is_testnet: Some(false),
+                    tags: None,
+                },
+                chain_id: Some(1),
+                required_confirmations: Some(12),
+                features: None,
+                symbol: Some("ETH".to_string()),
+                gas_price_cache: None,
+            }),
+        };
+
+        let result =
+            TransactionRepoModel::try_from((&request(false), &relayer_model, &network_model));
+        assert!(matches!(result, Err(RelayerError::RelayerInCanaryMode)));
+
+        let result =
+            TransactionRepoModel::try_from((&request(true), &relayer_model, &network_model));
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_try_from_network_transaction_request_solana() {
         use crate::models::{
@@ -1736,6 +1802,7 @@ mod tests {
                 )),
                 instructions: None,
                 valid_until: None,
+                canary: false,
             },
         );
 
@@ -1813,6 +1880,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         });
 
         let relayer_model = RelayerRepoModel {
@@ -2005,6 +2073,7 @@ mod tests {
             max_priority_fee_per_gas: None,
             speed: None,
             valid_until: None,
+            canary: false,
         };
 
         let result = EvmTransactionData::for_replacement(&old_data, &new_request);
@@ -2132,6 +2201,10 @@ mod tests {
                 slippage_percentage: None,
                 fee_margin_percentage: None,
                 swap_config: None,
+                low_balance_alerting: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                max_daily_spend: None,
             }),
             address: "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF".to_string(),
             notification_id: None,
@@ -2248,6 +2321,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2276,6 +2350,7 @@ mod tests {
             transaction_xdr: Some(unsigned_xdr.to_string()),
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2360,6 +2435,7 @@ mod tests {
             transaction_xdr: Some(signed_xdr.to_string()),
             fee_bump: Some(true),
             max_fee: Some(20000000),
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2389,6 +2465,7 @@ mod tests {
             transaction_xdr: Some(signed_xdr.clone()),
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2415,6 +2492,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: Some(true),
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2448,6 +2526,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2480,6 +2559,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2491,8 +2571,7 @@ mod tests {
                 let err_str = err.to_string();
                 assert!(
                     err_str.contains("Soroban operations must be exclusive"),
-                    "Expected error about Soroban operation exclusivity, got: {}",
-                    err_str
+                    "Expected error about Soroban operation exclusivity, got: {err_str}"
                 );
             }
         }
@@ -2524,6 +2603,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2535,8 +2615,7 @@ mod tests {
                 let err_str = err.to_string();
                 assert!(
                     err_str.contains("Transaction can contain at most one Soroban operation"),
-                    "Expected error about multiple Soroban operations, got: {}",
-                    err_str
+                    "Expected error about multiple Soroban operations, got: {err_str}"
                 );
             }
         }
@@ -2566,6 +2645,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2592,6 +2672,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2603,8 +2684,7 @@ mod tests {
                 let err_str = err.to_string();
                 assert!(
                     err_str.contains("Soroban operations cannot have a memo"),
-                    "Expected error about memo restriction, got: {}",
-                    err_str
+                    "Expected error about memo restriction, got: {err_str}"
                 );
             }
         }
@@ -2627,6 +2707,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2654,6 +2735,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2681,6 +2763,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2779,8 +2862,7 @@ mod tests {
             // Should set delete_at for final status
             assert!(
                 transaction.delete_at.is_some(),
-                "delete_at should be set for status: {:?}",
-                status
+                "delete_at should be set for status: {status:?}"
             );
 
             // Verify the timestamp is reasonable
@@ -2800,8 +2882,7 @@ mod tests {
             assert!(
                 duration_from_before >= expected_duration - tolerance &&
                 duration_from_before <= expected_duration + tolerance,
-                "delete_at should be approximately 3 hours from now for status: {:?}. Duration from start: {:?}, Expected: {:?}, Config hours at runtime: {}",
-                status, duration_from_before, expected_duration, actual_hours_at_runtime
+                "delete_at should be approximately 3 hours from now for status: {status:?}. Duration from start: {duration_from_before:?}, Expected: {expected_duration:?}, Config hours at runtime: {actual_hours_at_runtime}"
             );
         }
 
@@ -2845,8 +2926,7 @@ mod tests {
         assert!(
             duration_from_before >= expected_duration - tolerance &&
             duration_from_before <= expected_duration + tolerance,
-            "delete_at should be approximately 4 hours from now (default). Duration from start: {:?}, Expected: {:?}",
-            duration_from_before, expected_duration
+            "delete_at should be approximately 4 hours from now (default). Duration from start: {duration_from_before:?}, Expected: {expected_duration:?}"
         );
     }
 
@@ -2875,8 +2955,7 @@ mod tests {
 
             assert!(
                 transaction.delete_at.is_some(),
-                "delete_at should be set for {} hours",
-                expiration_hours
+                "delete_at should be set for {expiration_hours} hours"
             );
 
             let delete_at_str = transaction.delete_at.unwrap();
@@ -2891,8 +2970,7 @@ mod tests {
             assert!(
                 duration_from_before >= expected_duration - tolerance &&
                 duration_from_before <= expected_duration + tolerance,
-                "delete_at should be approximately {} hours from now. Duration from start: {:?}, Expected: {:?}",
-                expiration_hours, duration_from_before, expected_duration
+                "delete_at should be approximately {expiration_hours} hours from now. Duration from start: {duration_from_before:?}, Expected: {expected_duration:?}"
             );
         }
 
@@ -2913,8 +2991,7 @@ mod tests {
 
             assert!(
                 result.is_some(),
-                "calculate_delete_at should return Some for {} hours",
-                hours
+                "calculate_delete_at should return Some for {hours} hours"
             );
 
             let delete_at_str = result.unwrap();
@@ -2929,8 +3006,7 @@ mod tests {
 
             assert!(
                 delete_at >= expected_min && delete_at <= expected_max,
-                "Calculated delete_at should be approximately {} hours from now. Got: {}, Expected between: {} and {}",
-                hours, delete_at, expected_min, expected_max
+                "Calculated delete_at should be approximately {hours} hours from now. Got: {delete_at}, Expected between: {expected_min} and {expected_max}"
             );
         }
     }
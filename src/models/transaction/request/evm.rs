@@ -1,6 +1,6 @@
 use crate::{
     constants::ZERO_ADDRESS,
-    models::{ApiError, RelayerNetworkPolicy, RelayerRepoModel, U256},
+    models::{ApiError, RelayerRepoModel, U256},
     utils::calculate_intrinsic_gas,
 };
 use serde::{Deserialize, Serialize};
@@ -25,6 +25,9 @@ pub struct EvmTransactionRequest {
     pub max_priority_fee_per_gas: Option<u128>,
     #[schema(nullable = false)]
     pub valid_until: Option<String>,
+    /// Marks a test transaction accepted while the relayer is in canary mode
+    #[serde(default)]
+    pub canary: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
@@ -57,7 +60,7 @@ pub fn validate_evm_transaction_request(
     }
 
     // Validate gas_limit based on gas_limit_estimation policy
-    if let RelayerNetworkPolicy::Evm(evm_policy) = &relayer.policies {
+    if let Some(evm_policy) = relayer.policies.as_evm() {
         // If gas_limit_estimation is disabled (Some(false)), gas_limit must be provided
         if evm_policy.gas_limit_estimation == Some(false) && request.gas_limit.is_none() {
             return Err(ApiError::BadRequest(
@@ -101,7 +104,7 @@ pub fn validate_target_address(
     request: &EvmTransactionRequest,
     relayer: &RelayerRepoModel,
 ) -> Result<(), ApiError> {
-    if let RelayerNetworkPolicy::Evm(evm_policy) = &relayer.policies {
+    if let Some(evm_policy) = relayer.policies.as_evm() {
         if let Some(whitelist) = &evm_policy.whitelist_receivers {
             let target_address = request.to.clone().unwrap_or_default().to_lowercase();
             let mut allowed_addresses: Vec<String> =
@@ -172,7 +175,7 @@ pub fn validate_price_params(
     }
 
     if is_legacy {
-        if let RelayerNetworkPolicy::Evm(evm_policy) = &relayer.policies {
+        if let Some(evm_policy) = relayer.policies.as_evm() {
             if let Some(gas_price_cap) = evm_policy.gas_price_cap {
                 if request.gas_price.unwrap_or(0) > gas_price_cap {
                     return Err(ApiError::BadRequest("Gas price is too high".to_string()));
@@ -202,6 +205,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         }
     }
 
@@ -528,8 +532,7 @@ mod tests {
         if let Err(ApiError::BadRequest(msg)) = result {
             assert!(
                 msg.contains("gas_limit is required when gas_limit_estimation policy is disabled"),
-                "Expected specific error message, got: {}",
-                msg
+                "Expected specific error message, got: {msg}"
             );
         } else {
             panic!("Expected BadRequest error");
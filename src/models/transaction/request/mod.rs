@@ -47,6 +47,15 @@ impl NetworkTransactionRequest {
             NetworkTransactionRequest::Solana(request) => request.validate(relayer),
         }
     }
+
+    /// Whether the request is a test transaction for a relayer in canary mode.
+    pub fn is_canary(&self) -> bool {
+        match self {
+            NetworkTransactionRequest::Evm(request) => request.canary,
+            NetworkTransactionRequest::Solana(request) => request.canary,
+            NetworkTransactionRequest::Stellar(request) => request.canary,
+        }
+    }
 }
 
 /// Network-agnostic fee estimate request parameters for gasless transactions.
@@ -24,6 +24,10 @@ pub struct SolanaTransactionRequest {
     /// Optional RFC3339 timestamp when transaction should expire
     #[schema(nullable = true)]
     pub valid_until: Option<String>,
+
+    /// Marks a test transaction accepted while the relayer is in canary mode
+    #[serde(default)]
+    pub canary: bool,
 }
 
 impl SolanaTransactionRequest {
@@ -239,10 +243,11 @@ impl SolanaTransactionRequest {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::RelayerRepoModel;
+    use crate::models::{AlertState, CircuitState, RelayerHealth, RelayerMode, RelayerRepoModel};
     use base64::Engine;
     use solana_sdk::{message::Message, pubkey::Pubkey};
     use solana_system_interface::instruction as system_instruction;
+    use std::collections::{HashMap, VecDeque};
 
     fn create_test_relayer() -> RelayerRepoModel {
         RelayerRepoModel {
@@ -260,6 +265,45 @@ mod tests {
             system_disabled: false,
             disabled_reason: None,
             custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: "test-tenant".to_string(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            version: 0,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
         }
     }
 
@@ -318,6 +362,7 @@ mod tests {
             transaction: Some(transaction),
             instructions: None,
             valid_until: None,
+            canary: false,
         };
 
         assert!(request.validate(&relayer).is_ok());
@@ -332,6 +377,7 @@ mod tests {
             transaction: None,
             instructions: Some(vec![instruction]),
             valid_until: None,
+            canary: false,
         };
 
         assert!(request.validate(&relayer).is_ok());
@@ -348,6 +394,7 @@ mod tests {
             transaction: Some(transaction),
             instructions: Some(vec![instruction]),
             valid_until: None,
+            canary: false,
         };
 
         let result = request.validate(&relayer);
@@ -366,6 +413,7 @@ mod tests {
             transaction: None,
             instructions: None,
             valid_until: None,
+            canary: false,
         };
 
         let result = request.validate(&relayer);
@@ -385,6 +433,7 @@ mod tests {
             transaction: None,
             instructions: Some(vec![create_valid_instruction_spec()]),
             valid_until: Some(future_time.to_rfc3339()),
+            canary: false,
         };
 
         assert!(request.validate(&relayer).is_ok());
@@ -399,6 +448,7 @@ mod tests {
             transaction: None,
             instructions: Some(vec![create_valid_instruction_spec()]),
             valid_until: Some(past_time.to_rfc3339()),
+            canary: false,
         };
 
         let result = request.validate(&relayer);
@@ -417,6 +467,7 @@ mod tests {
             transaction: None,
             instructions: Some(vec![create_valid_instruction_spec()]),
             valid_until: Some("invalid-timestamp".to_string()),
+            canary: false,
         };
 
         let result = request.validate(&relayer);
@@ -611,8 +662,8 @@ mod tests {
         for i in 0..(REQUEST_MAX_TOTAL_ACCOUNTS + 1) {
             let mut instruction = create_valid_instruction_spec();
             // Change program_id to create unique accounts
-            instruction.program_id = format!("{:0>44}", i); // Create unique but invalid pubkeys
-                                                            // Add a unique account
+            instruction.program_id = format!("{i:0>44}"); // Create unique but invalid pubkeys
+                                                          // Add a unique account
             instruction.accounts.push(crate::models::SolanaAccountMeta {
                 pubkey: format!("{:0>44}", i + 1000), // Another unique account
                 is_signer: false,
@@ -25,6 +25,9 @@ pub struct StellarTransactionRequest {
     /// Maximum fee in stroops (defaults to 0.1 XLM = 1,000,000 stroops)
     #[schema(nullable = true)]
     pub max_fee: Option<i64>,
+    /// Marks a test transaction accepted while the relayer is in canary mode
+    #[serde(default)]
+    pub canary: bool,
 }
 
 impl StellarTransactionRequest {
@@ -107,6 +110,7 @@ mod tests {
             transaction_xdr: Some("AAAAA...".to_string()),
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let result = req.validate();
@@ -130,6 +134,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let result = req.validate();
@@ -157,6 +162,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: Some(true),
             max_fee: None,
+            canary: false,
         };
 
         let result = req.validate();
@@ -180,6 +186,7 @@ mod tests {
             transaction_xdr: Some("AAAAA...".to_string()),
             fee_bump: Some(true),
             max_fee: Some(10000000),
+            canary: false,
         };
 
         let result = req.validate();
@@ -203,6 +210,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let result = req.validate();
@@ -222,6 +230,7 @@ mod tests {
             transaction_xdr: Some("AAAAA...".to_string()),
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         let result = req.validate();
@@ -241,6 +250,7 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            canary: false,
         };
 
         assert_eq!(
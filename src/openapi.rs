@@ -62,6 +62,7 @@ impl Modify for SecurityAddon {
         relayer_docs::doc_create_relayer,
         relayer_docs::doc_update_relayer,
         relayer_docs::doc_delete_relayer,
+        relayer_docs::doc_promote_relayer,
         relayer_docs::doc_get_relayer_balance,
         relayer_docs::doc_get_transaction_by_nonce,
         relayer_docs::doc_get_transaction_by_id,
@@ -68,7 +68,7 @@ impl ApiKeyRepositoryTrait for InMemoryApiKeyRepository {
         query: PaginationQuery,
     ) -> Result<PaginatedResult<ApiKeyRepoModel>, RepositoryError> {
         let total = self.count().await?;
-        let start = ((query.page - 1) * query.per_page) as usize;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
 
         let items = self
             .store
@@ -76,7 +76,7 @@ impl ApiKeyRepositoryTrait for InMemoryApiKeyRepository {
             .await
             .values()
             .skip(start)
-            .take(query.per_page as usize)
+            .take(query.resolved_per_page() as usize)
             .cloned()
             .collect();
 
@@ -84,7 +84,7 @@ impl ApiKeyRepositoryTrait for InMemoryApiKeyRepository {
             items,
             total: total as u64,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -204,7 +204,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
 
         let result = api_key_repository.list_paginated(query).await;
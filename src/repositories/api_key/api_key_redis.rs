@@ -163,7 +163,7 @@ impl ApiKeyRepositoryTrait for RedisApiKeyRepository {
             ));
         }
 
-        if query.per_page == 0 {
+        if query.resolved_per_page() == 0 {
             return Err(RepositoryError::InvalidData(
                 "Per page count must be greater than 0".to_string(),
             ));
@@ -182,7 +182,7 @@ impl ApiKeyRepositoryTrait for RedisApiKeyRepository {
                 items: vec![],
                 total: 0,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
@@ -192,8 +192,8 @@ impl ApiKeyRepositoryTrait for RedisApiKeyRepository {
             .await
             .map_err(|e| self.map_redis_error(e, "list_paginated_members"))?;
 
-        let start = ((query.page - 1) * query.per_page) as usize;
-        let end = (start + query.per_page as usize).min(all_ids.len());
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let end = (start + query.resolved_per_page() as usize).min(all_ids.len());
 
         let ids_to_query = &all_ids[start..end];
         let items = self.get_by_ids(ids_to_query).await?;
@@ -202,7 +202,7 @@ impl ApiKeyRepositoryTrait for RedisApiKeyRepository {
             items: items.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -522,13 +522,13 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
 
         let result = repo.list_paginated(query).await;
         assert!(result.is_ok());
         let result = result.unwrap();
-        println!("result: {:?}", result);
+        println!("result: {result:?}");
         assert!(result.items.len() == 2);
     }
 
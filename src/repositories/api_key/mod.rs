@@ -357,7 +357,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = storage.list_paginated(query).await.unwrap();
 
@@ -405,7 +405,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
         let result = storage.list_paginated(query).await.unwrap();
 
@@ -453,7 +453,7 @@ mod tests {
         // List all plugins
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = storage.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 2);
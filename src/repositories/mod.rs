@@ -43,6 +43,31 @@ pub struct PaginatedResult<T> {
     pub per_page: u32,
 }
 
+impl<T> PaginatedResult<T> {
+    /// Number of pages for the current `total` and `per_page`; zero when there are no results
+    pub fn total_pages(&self) -> u64 {
+        if self.total == 0 || self.per_page == 0 {
+            return 0;
+        }
+        self.total.div_ceil(u64::from(self.per_page))
+    }
+
+    /// Whether a page after the current one contains results
+    pub fn has_next(&self) -> bool {
+        u64::from(self.page) < self.total_pages()
+    }
+
+    /// Whether a page before the current one contains results
+    pub fn has_prev(&self) -> bool {
+        self.page > 1 && self.total_pages() > 0
+    }
+
+    /// Whether the current page contains no items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
 pub struct BatchRetrievalResult<T> {
     pub results: Vec<T>,
     pub failed_ids: Vec<String>,
@@ -56,6 +81,24 @@ use utoipa::ToSchema;
 #[allow(dead_code)]
 #[cfg_attr(test, automock)]
 pub trait Repository<T, ID> {
+    /// Prepare the backend before serving traffic (e.g. establish pools, check migrations).
+    ///
+    /// Must be idempotent: calling it more than once has the same effect as calling it once.
+    /// The default implementation is a no-op, suitable for in-memory storage.
+    async fn init(&self) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    /// Check that the backend is reachable, e.g. for a liveness endpoint.
+    ///
+    /// Must be cheap enough to call frequently: persistent backends should issue a single
+    /// round-trip (`PING`, `SELECT 1`) and fail with `ConnectionError` when the backend
+    /// cannot be reached. The default implementation always succeeds, suitable for
+    /// in-memory storage.
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
     async fn create(&self, entity: T) -> Result<T, RepositoryError>;
     async fn get_by_id(&self, id: ID) -> Result<T, RepositoryError>;
     async fn list_all(&self) -> Result<Vec<T>, RepositoryError>;
@@ -68,8 +111,16 @@ pub trait Repository<T, ID> {
     async fn count(&self) -> Result<usize, RepositoryError>;
 
     /// Check if the repository contains any entries.
+    ///
+    /// Implementations should answer this with a cheap existence check (e.g. `EXISTS`
+    /// or `LIMIT 1` in SQL backends) rather than loading or counting all entries.
     async fn has_entries(&self) -> Result<bool, RepositoryError>;
 
+    /// Check if the repository is empty. Logical negation of [`Repository::has_entries`].
+    async fn is_empty(&self) -> Result<bool, RepositoryError> {
+        Ok(!self.has_entries().await?)
+    }
+
     /// Drop all entries from storage.
     /// This completely clears all data, indexes, and metadata.
     /// Use with caution as this permanently deletes all data.
@@ -83,3 +134,59 @@ pub enum ConversionError {
     #[error("Invalid config: {0}")]
     InvalidConfig(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paginated(items: Vec<u32>, total: u64, page: u32, per_page: u32) -> PaginatedResult<u32> {
+        PaginatedResult {
+            items,
+            total,
+            page,
+            per_page,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_default_is_noop() {
+        let repo = InMemoryRelayerRepository::new();
+        assert!(repo.init().await.is_ok());
+        // Idempotent
+        assert!(repo.init().await.is_ok());
+        assert!(repo.is_empty().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_ping_default_succeeds_for_in_memory() {
+        let repo = InMemoryRelayerRepository::new();
+        assert!(repo.ping().await.is_ok());
+    }
+
+    #[test]
+    fn test_paginated_result_empty() {
+        let result = paginated(vec![], 0, 1, 10);
+        assert!(result.is_empty());
+        assert_eq!(result.total_pages(), 0);
+        assert!(!result.has_next());
+        assert!(!result.has_prev());
+    }
+
+    #[test]
+    fn test_paginated_result_page_beyond_last() {
+        let result = paginated(vec![], 3, 5, 2);
+        assert!(result.is_empty());
+        assert_eq!(result.total_pages(), 2);
+        assert!(!result.has_next());
+        assert!(result.has_prev());
+    }
+
+    #[test]
+    fn test_paginated_result_middle_page() {
+        let result = paginated(vec![3, 4], 5, 2, 2);
+        assert!(!result.is_empty());
+        assert_eq!(result.total_pages(), 3);
+        assert!(result.has_next());
+        assert!(result.has_prev());
+    }
+}
@@ -70,6 +70,13 @@ impl NetworkRepositoryStorage {
 
 #[async_trait]
 impl Repository<NetworkRepoModel, String> for NetworkRepositoryStorage {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        match self {
+            NetworkRepositoryStorage::InMemory(repo) => repo.ping().await,
+            NetworkRepositoryStorage::Redis(repo) => repo.ping().await,
+        }
+    }
+
     async fn create(&self, entity: NetworkRepoModel) -> Result<NetworkRepoModel, RepositoryError> {
         match self {
             NetworkRepositoryStorage::InMemory(repo) => repo.create(entity).await,
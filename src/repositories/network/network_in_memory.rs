@@ -305,7 +305,7 @@ mod tests {
         let pagination_result = repo
             .list_paginated(PaginationQuery {
                 page: 1,
-                per_page: 10,
+                per_page: Some(10),
             })
             .await;
         assert!(matches!(
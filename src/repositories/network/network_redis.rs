@@ -233,6 +233,17 @@ impl fmt::Debug for RedisNetworkRepository {
 
 #[async_trait]
 impl Repository<NetworkRepoModel, String> for RedisNetworkRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let _: String = redis::cmd("PING")
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.map_ping_error(e))?;
+
+        Ok(())
+    }
+
     async fn create(&self, entity: NetworkRepoModel) -> Result<NetworkRepoModel, RepositoryError> {
         if entity.id.is_empty() {
             return Err(RepositoryError::InvalidData(
@@ -339,7 +350,7 @@ impl Repository<NetworkRepoModel, String> for RedisNetworkRepository {
         &self,
         query: PaginationQuery,
     ) -> Result<PaginatedResult<NetworkRepoModel>, RepositoryError> {
-        if query.per_page == 0 {
+        if query.resolved_per_page() == 0 {
             return Err(RepositoryError::InvalidData(
                 "per_page must be greater than 0".to_string(),
             ));
@@ -348,7 +359,7 @@ impl Repository<NetworkRepoModel, String> for RedisNetworkRepository {
         let network_list_key = self.network_list_key();
         let mut conn = self.client.as_ref().clone();
 
-        debug!(page = %query.page, per_page = %query.per_page, "listing paginated networks");
+        debug!(page = %query.page, per_page = %query.resolved_per_page(), "listing paginated networks");
 
         let all_ids: Vec<String> = conn
             .smembers(&network_list_key)
@@ -356,7 +367,7 @@ impl Repository<NetworkRepoModel, String> for RedisNetworkRepository {
             .map_err(|e| self.map_redis_error(e, "list_paginated_networks"))?;
 
         let total = all_ids.len() as u64;
-        let per_page = query.per_page as usize;
+        let per_page = query.resolved_per_page() as usize;
         let page = query.page as usize;
         let total_pages = all_ids.len().div_ceil(per_page);
 
@@ -366,7 +377,7 @@ impl Repository<NetworkRepoModel, String> for RedisNetworkRepository {
                 items: Vec::new(),
                 total,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
@@ -381,7 +392,7 @@ impl Repository<NetworkRepoModel, String> for RedisNetworkRepository {
             items: networks.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -695,7 +706,7 @@ mod tests {
     async fn setup_test_repo() -> RedisNetworkRepository {
         let redis_url = "redis://localhost:6379";
         let random_id = Uuid::new_v4().to_string();
-        let key_prefix = format!("test_prefix_{}", random_id);
+        let key_prefix = format!("test_prefix_{random_id}");
 
         let client = redis::Client::open(redis_url).expect("Failed to create Redis client");
         let connection_manager = ConnectionManager::new(client)
@@ -770,7 +781,7 @@ mod tests {
         let random_id = Uuid::new_v4().to_string();
         let random_name = Uuid::new_v4().to_string();
         let mut network = create_test_network(&random_name, NetworkType::Evm);
-        network.id = format!("evm:{}", random_id);
+        network.id = format!("evm:{random_id}");
 
         // Create the network first
         repo.create(network.clone()).await.unwrap();
@@ -791,7 +802,7 @@ mod tests {
         let random_id = Uuid::new_v4().to_string();
         let random_name = Uuid::new_v4().to_string();
         let mut network = create_test_network(&random_name, NetworkType::Evm);
-        network.id = format!("evm:{}", random_id);
+        network.id = format!("evm:{random_id}");
 
         // Create the network first
         repo.create(network.clone()).await.unwrap();
@@ -860,7 +871,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
 
         let result = repo.list_paginated(query).await.unwrap();
@@ -995,7 +1006,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 0,
+            per_page: Some(0),
         };
         let result = repo.list_paginated(query).await;
         assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
@@ -54,6 +54,13 @@ impl NotificationRepositoryStorage {
 
 #[async_trait]
 impl Repository<NotificationRepoModel, String> for NotificationRepositoryStorage {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        match self {
+            NotificationRepositoryStorage::InMemory(repo) => repo.ping().await,
+            NotificationRepositoryStorage::Redis(repo) => repo.ping().await,
+        }
+    }
+
     async fn create(
         &self,
         entity: NotificationRepoModel,
@@ -223,14 +230,14 @@ mod tests {
 
         // Add test notifications
         for i in 1..=5 {
-            let notification = create_test_notification(&format!("notification-{}", i));
+            let notification = create_test_notification(&format!("notification-{i}"));
             storage.create(notification).await?;
         }
 
         // Test pagination
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
         let page = storage.list_paginated(query).await?;
 
@@ -242,7 +249,7 @@ mod tests {
         // Test second page
         let query2 = PaginationQuery {
             page: 2,
-            per_page: 2,
+            per_page: Some(2),
         };
         let page2 = storage.list_paginated(query2).await?;
 
@@ -254,7 +261,7 @@ mod tests {
         // Test final page
         let query3 = PaginationQuery {
             page: 3,
-            per_page: 2,
+            per_page: Some(2),
         };
         let page3 = storage.list_paginated(query3).await?;
 
@@ -402,7 +409,7 @@ mod tests {
 
         // Add multiple notifications
         for i in 1..=5 {
-            let notification = create_test_notification(&format!("notification-{}", i));
+            let notification = create_test_notification(&format!("notification-{i}"));
             storage.create(notification).await?;
         }
 
@@ -498,7 +505,7 @@ mod tests {
         // Test pagination with empty storage
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let page = storage.list_paginated(query).await?;
         assert_eq!(page.items.len(), 0);
@@ -513,7 +520,7 @@ mod tests {
         // Test pagination with single item
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let page = storage.list_paginated(query).await?;
         assert_eq!(page.items.len(), 1);
@@ -524,7 +531,7 @@ mod tests {
         // Test pagination with page beyond total
         let query = PaginationQuery {
             page: 3,
-            per_page: 10,
+            per_page: Some(10),
         };
         let page = storage.list_paginated(query).await?;
         assert_eq!(page.items.len(), 0);
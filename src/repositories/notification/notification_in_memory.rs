@@ -125,14 +125,14 @@ impl Repository<NotificationRepoModel, String> for InMemoryNotificationRepositor
         query: PaginationQuery,
     ) -> Result<PaginatedResult<NotificationRepoModel>, RepositoryError> {
         let total = self.count().await?;
-        let start = ((query.page - 1) * query.per_page) as usize;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
         let items: Vec<NotificationRepoModel> = self
             .store
             .lock()
             .await
             .values()
             .skip(start)
-            .take(query.per_page as usize)
+            .take(query.resolved_per_page() as usize)
             .cloned()
             .collect();
 
@@ -140,7 +140,7 @@ impl Repository<NotificationRepoModel, String> for InMemoryNotificationRepositor
             items,
             total: total as u64,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -125,6 +125,17 @@ impl fmt::Debug for RedisNotificationRepository {
 
 #[async_trait]
 impl Repository<NotificationRepoModel, String> for RedisNotificationRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let _: String = redis::cmd("PING")
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.map_ping_error(e))?;
+
+        Ok(())
+    }
+
     async fn create(
         &self,
         entity: NotificationRepoModel,
@@ -230,7 +241,7 @@ impl Repository<NotificationRepoModel, String> for RedisNotificationRepository {
         &self,
         query: PaginationQuery,
     ) -> Result<PaginatedResult<NotificationRepoModel>, RepositoryError> {
-        if query.per_page == 0 {
+        if query.resolved_per_page() == 0 {
             return Err(RepositoryError::InvalidData(
                 "per_page must be greater than 0".to_string(),
             ));
@@ -239,7 +250,7 @@ impl Repository<NotificationRepoModel, String> for RedisNotificationRepository {
         let mut conn = self.client.as_ref().clone();
         let notification_list_key = self.notification_list_key();
 
-        debug!(page = %query.page, per_page = %query.per_page, "fetching paginated notifications");
+        debug!(page = %query.page, per_page = %query.resolved_per_page(), "fetching paginated notifications");
 
         let all_notification_ids: Vec<String> = conn
             .smembers(&notification_list_key)
@@ -247,8 +258,8 @@ impl Repository<NotificationRepoModel, String> for RedisNotificationRepository {
             .map_err(|e| self.map_redis_error(e, "list_paginated_notification_ids"))?;
 
         let total = all_notification_ids.len() as u64;
-        let start = ((query.page - 1) * query.per_page) as usize;
-        let end = (start + query.per_page as usize).min(all_notification_ids.len());
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let end = (start + query.resolved_per_page() as usize).min(all_notification_ids.len());
 
         if start >= all_notification_ids.len() {
             debug!(page = %query.page, total = %total, "page is beyond available data");
@@ -256,7 +267,7 @@ impl Repository<NotificationRepoModel, String> for RedisNotificationRepository {
                 items: vec![],
                 total,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
@@ -269,7 +280,7 @@ impl Repository<NotificationRepoModel, String> for RedisNotificationRepository {
             items: items.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -660,14 +671,14 @@ mod tests {
         for i in 1..=10 {
             let random_id = Uuid::new_v4().to_string();
             let notification =
-                create_test_notification_with_url(&random_id, &format!("http://test{}.com", i));
+                create_test_notification_with_url(&random_id, &format!("http://test{i}.com"));
             repo.create(notification).await.unwrap();
         }
 
         // Test first page with 3 items per page
         let query = PaginationQuery {
             page: 1,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 3);
@@ -678,7 +689,7 @@ mod tests {
         // Test empty page (beyond total items)
         let query = PaginationQuery {
             page: 1000,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 0);
@@ -688,7 +699,7 @@ mod tests {
     #[ignore = "Requires active Redis instance"]
     async fn test_debug_implementation() {
         let repo = setup_test_repo().await;
-        let debug_str = format!("{:?}", repo);
+        let debug_str = format!("{repo:?}");
         assert!(debug_str.contains("RedisNotificationRepository"));
         assert!(debug_str.contains("test_prefix"));
     }
@@ -709,7 +720,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 0,
+            per_page: Some(0),
         };
         let result = repo.list_paginated(query).await;
         assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
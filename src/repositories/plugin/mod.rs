@@ -324,7 +324,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = storage.list_paginated(query).await.unwrap();
 
@@ -354,7 +354,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
         let result = storage.list_paginated(query).await.unwrap();
 
@@ -390,7 +390,7 @@ mod tests {
         // List all plugins
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = storage.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 2);
@@ -73,7 +73,7 @@ impl PluginRepositoryTrait for InMemoryPluginRepository {
         query: PaginationQuery,
     ) -> Result<PaginatedResult<PluginModel>, RepositoryError> {
         let total = self.count().await?;
-        let start = ((query.page - 1) * query.per_page) as usize;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
 
         let items = self
             .store
@@ -81,7 +81,7 @@ impl PluginRepositoryTrait for InMemoryPluginRepository {
             .await
             .values()
             .skip(start)
-            .take(query.per_page as usize)
+            .take(query.resolved_per_page() as usize)
             .cloned()
             .collect();
 
@@ -89,7 +89,7 @@ impl PluginRepositoryTrait for InMemoryPluginRepository {
             items,
             total: total as u64,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -210,7 +210,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
 
         let result = plugin_repository.list_paginated(query).await;
@@ -214,7 +214,7 @@ impl PluginRepositoryTrait for RedisPluginRepository {
             ));
         }
 
-        if query.per_page == 0 {
+        if query.resolved_per_page() == 0 {
             return Err(RepositoryError::InvalidData(
                 "Per page count must be greater than 0".to_string(),
             ));
@@ -234,7 +234,7 @@ impl PluginRepositoryTrait for RedisPluginRepository {
                 items: vec![],
                 total: 0,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
@@ -244,8 +244,8 @@ impl PluginRepositoryTrait for RedisPluginRepository {
             .await
             .map_err(|e| self.map_redis_error(e, "list_paginated_members"))?;
 
-        let start = ((query.page - 1) * query.per_page) as usize;
-        let end = (start + query.per_page as usize).min(all_ids.len());
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let end = (start + query.resolved_per_page() as usize).min(all_ids.len());
 
         let ids_to_query = &all_ids[start..end];
         let items = self.get_by_ids(ids_to_query).await?;
@@ -254,7 +254,7 @@ impl PluginRepositoryTrait for RedisPluginRepository {
             items: items.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -473,7 +473,7 @@ mod tests {
     #[ignore = "Requires active Redis instance"]
     async fn test_debug_implementation() {
         let repo = setup_test_repo().await;
-        let debug_str = format!("{:?}", repo);
+        let debug_str = format!("{repo:?}");
         assert!(debug_str.contains("RedisPluginRepository"));
         assert!(debug_str.contains("test_plugin"));
     }
@@ -559,7 +559,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
 
         let result = repo.list_paginated(query).await;
@@ -80,6 +80,16 @@ pub trait RedisRepository {
             _ => RepositoryError::Other(format!("Redis operation '{context}' failed: {error}")),
         }
     }
+
+    /// Maps the error of a `PING` liveness check. Not reaching Redis at all is a
+    /// `ConnectionError`; other failures are mapped by [`Self::map_redis_error`].
+    fn map_ping_error(&self, error: RedisError) -> RepositoryError {
+        if error.is_io_error() || error.is_connection_dropped() {
+            warn!(error = %error, "redis ping failed");
+            return RepositoryError::ConnectionError(format!("Redis is unreachable: {error}"));
+        }
+        self.map_redis_error(error, "ping")
+    }
 }
 
 #[cfg(test)]
@@ -261,6 +271,34 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_map_ping_error_unreachable_server() {
+        let repo = TestRedisRepository::new();
+        // Nothing listens on port 1, so connecting fails the way a ping to a down server does
+        let client = redis::Client::open("redis://127.0.0.1:1").unwrap();
+        let redis_error = client
+            .get_multiplexed_async_connection()
+            .await
+            .expect_err("connecting to a closed port should fail");
+
+        let result = repo.map_ping_error(redis_error);
+
+        assert!(
+            matches!(result, RepositoryError::ConnectionError(ref msg) if msg.contains("unreachable")),
+            "expected ConnectionError, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_map_ping_error_other_failures() {
+        let repo = TestRedisRepository::new();
+        let redis_error = RedisError::from((redis::ErrorKind::AuthenticationFailed, "Auth failed"));
+
+        let result = repo.map_ping_error(redis_error);
+
+        assert!(matches!(result, RepositoryError::InvalidData(_)));
+    }
+
     #[test]
     fn test_map_redis_error_no_script_error() {
         let repo = TestRedisRepository::new();
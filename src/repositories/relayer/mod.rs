@@ -7,34 +7,168 @@
 //! ## Features
 //!
 //! - **CRUD Operations**: Create, read, update, and delete relayer configurations
-//! - **Status Management**: Enable/disable relayers and track their state
+//! - **Status Management**: Enable/disable relayers and track their state, with a bounded
+//!   history of status transitions
 //! - **Policy Management**: Update relayer network policies
 //! - **Partial Updates**: Support for partial relayer configuration updates
 //! - **Active Filtering**: Query for active (non-paused) relayers
 //! - **Pagination Support**: Efficient paginated listing of relayers
+//! - **Snapshots**: Consistent point-in-time views for fleet-wide aggregates
+//! - **Sticky Pools**: Deterministic session-key routing to relayers within a pool
+//! - **Worker Sharding**: Stable assignment of relayers to worker processes
+//! - **Bulk Provisioning**: All-or-nothing creation of relayers from import descriptors
+//! - **Change Log**: Bounded, sequenced record of mutations for catch-up after a reconnect
+//! - **Maintenance Windows**: Repository-wide switch making every relayer unavailable until
+//!   a set time, without touching per-relayer paused state
+//! - **Config Read Guards**: Pin a relayer's configuration while a transaction is prepared,
+//!   holding off `update` and `update_policy` until released
+//! - **Decommissioning**: Sunset, drain check and delete bundled into one pollable call
+//! - **Error Rates**: Bounded ring of recent outcomes per relayer, used to list relayers
+//!   whose error rate over a window exceeds a threshold
+//! - **Submission Deduplication**: Per-relayer keys with a TTL, collapsing identical
+//!   in-flight transactions
+//! - **Ownership Transfer**: Moves relayers between tenants, keeping a bounded history
+//!   of who moved them
+//! - **Encryption at Rest**: Optional encryption of sensitive fields, or whole records,
+//!   written by the Redis backend through an injected provider
+//! - **Tracing**: Debug-level spans per operation carrying the relayer id, operation
+//!   name and outcome
 //!
 //! ## Repository Implementations
 //!
 //! - [`InMemoryRelayerRepository`]: Fast in-memory storage for testing/development
 //! - [`RedisRelayerRepository`]: Redis-backed storage for production environments
+//! - [`ReadOnlyRelayerRepository`]: Decorator rejecting writes while read-only mode is engaged
+//! - [`CompositeRelayerRepository`]: Merged, id-deduplicated view over several backends
+//!   during a migration, with writes routed to a primary
+//! - [`CachedRelayerRepository`]: Decorator caching `get_by_id`, kept fresh across
+//!   instances by an [`InvalidationPublisher`]/[`InvalidationSubscriber`] channel
 //!
 
+mod relayer_cached;
+mod relayer_change_log;
+mod relayer_composite;
+mod relayer_config_locks;
+mod relayer_dedup;
+mod relayer_encryption;
+mod relayer_error_rate;
 mod relayer_in_memory;
+mod relayer_invalidation;
+mod relayer_maintenance;
+mod relayer_persistence;
+mod relayer_provision;
+mod relayer_read_only;
 mod relayer_redis;
+mod relayer_slots;
+mod relayer_snapshot;
+mod relayer_sticky;
+#[cfg(test)]
+pub(crate) mod relayer_stress;
 
+pub use relayer_cached::*;
+pub use relayer_change_log::*;
+pub use relayer_composite::*;
+pub use relayer_config_locks::*;
+pub use relayer_dedup::*;
+pub use relayer_encryption::*;
+pub use relayer_error_rate::*;
 pub use relayer_in_memory::*;
+pub use relayer_invalidation::*;
+pub use relayer_maintenance::*;
+pub use relayer_persistence::*;
+pub use relayer_provision::*;
+pub use relayer_read_only::*;
 pub use relayer_redis::*;
+pub use relayer_slots::*;
+pub use relayer_snapshot::*;
+pub use relayer_sticky::*;
 
 use crate::{
     models::UpdateRelayerRequest,
     models::{
-        DisabledReason, PaginationQuery, RelayerNetworkPolicy, RelayerRepoModel, RepositoryError,
+        AlertTransition, Capability, ChangeRecord, DecommissionState, DeleteReport, DisabledReason,
+        EnforceReport, HealthCheckFailure, LintWarning, LogLevel, NetworkType, PaginationQuery,
+        PolicyValidationReport, ProvisionMode, ProvisionReport, RelayerConfigVersionInfo,
+        RelayerDescriptor, RelayerFilter, RelayerLastError, RelayerNetworkPolicy,
+        RelayerOperationalStatus, RelayerRepoModel, RelayerTemplate, RelayerValidationError,
+        RepositoryError, StatusChange,
     },
     repositories::{PaginatedResult, Repository},
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use redis::aio::ConnectionManager;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::{field, instrument, warn, Span};
+
+/// Predicate over a relayer's network policy, used by `list_where_policy`
+pub type PolicyPredicate = Arc<dyn Fn(&RelayerNetworkPolicy) -> bool + Send + Sync>;
+
+/// Derives the address a relayer's signer controls from the signer configuration.
+///
+/// Injected into address verification so the repository stays agnostic of signer types.
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait AddressResolver: Send + Sync {
+    /// Returns the address derived from the relayer's `signer_id` and `derivation_path`
+    async fn resolve_address(&self, relayer: &RelayerRepoModel) -> Result<String, RepositoryError>;
+}
+
+/// Fetches a relayer's current on-chain balance, in the smallest unit of its network.
+///
+/// Injected into funding queries so the repository stays agnostic of network providers.
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait BalanceProvider: Send + Sync {
+    /// Returns the current balance of the relayer's address
+    async fn balance(&self, relayer: &RelayerRepoModel) -> Result<u128, RepositoryError>;
+}
+
+/// Counts the transactions a relayer still has in flight.
+///
+/// Injected into `decommission` so the repository can wait for a relayer to drain
+/// without depending on the transaction repository.
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait InflightChecker: Send + Sync {
+    /// Returns the number of transactions of the relayer that are not yet final
+    async fn inflight_count(&self, relayer: &RelayerRepoModel) -> Result<u64, RepositoryError>;
+}
+
+/// Source of the chains each signer is authorized for.
+///
+/// Injected into relayer repositories so that `create` and `rekey` can reject a relayer
+/// whose chain its signer may not sign for.
+#[async_trait]
+pub trait SignerChainAllowlist: Send + Sync + std::fmt::Debug {
+    /// Returns the chain ids `signer_id` is authorized for, or `None` if it is unrestricted
+    async fn signer_allowed_chains(
+        &self,
+        signer_id: &str,
+    ) -> Result<Option<Vec<u64>>, RepositoryError>;
+}
+
+/// Source of the tenants relayers may belong to.
+///
+/// Injected into relayer repositories so that `transfer_ownership` can reject a
+/// transfer to a tenant that does not exist.
+#[async_trait]
+pub trait TenantRegistry: Send + Sync + std::fmt::Debug {
+    /// Returns true if `tenant_id` names a known tenant
+    async fn tenant_exists(&self, tenant_id: &str) -> Result<bool, RepositoryError>;
+}
+
+/// Source of the notifications relayers may reference.
+///
+/// Injected into relayer repositories so that `partial_update` can reject a
+/// `notification_id` that does not name an existing notification.
+#[async_trait]
+pub trait NotificationRegistry: Send + Sync + std::fmt::Debug {
+    /// Returns true if `notification_id` names a known notification
+    async fn notification_exists(&self, notification_id: &str) -> Result<bool, RepositoryError>;
+}
 
 #[async_trait]
 pub trait RelayerRepository: Repository<RelayerRepoModel, String> + Send + Sync {
@@ -49,204 +183,2430 @@ pub trait RelayerRepository: Repository<RelayerRepoModel, String> + Send + Sync
     ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
     async fn partial_update(
         &self,
-        id: String,
-        update: UpdateRelayerRequest,
-    ) -> Result<RelayerRepoModel, RepositoryError>;
-    async fn enable_relayer(&self, relayer_id: String)
-        -> Result<RelayerRepoModel, RepositoryError>;
-    async fn disable_relayer(
+        id: String,
+        update: UpdateRelayerRequest,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    async fn enable_relayer(&self, relayer_id: String)
+        -> Result<RelayerRepoModel, RepositoryError>;
+    async fn disable_relayer(
+        &self,
+        relayer_id: String,
+        reason: DisabledReason,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    async fn update_policy(
+        &self,
+        id: String,
+        policy: RelayerNetworkPolicy,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Creates a relayer at most once per idempotency key.
+    ///
+    /// Repeating a call with an already processed key returns the originally
+    /// created relayer instead of creating a duplicate. Keys are retained for
+    /// `RELAYER_IDEMPOTENCY_KEY_TTL_SECONDS`.
+    async fn create_idempotent(
+        &self,
+        relayer: RelayerRepoModel,
+        idempotency_key: String,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Quarantines a relayer until the given time.
+    ///
+    /// The relayer is system-disabled with a `Quarantined` reason and becomes
+    /// operational again once `until` has elapsed.
+    async fn quarantine(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Returns quarantined relayers whose quarantine has elapsed at `now`, so a
+    /// sweeper can re-enable them.
+    async fn list_expired_quarantines(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+    /// Builds a relayer from a template and stores it.
+    ///
+    /// The instance is validated and inserted exactly like a regular `create`.
+    async fn instantiate(
+        &self,
+        template: &RelayerTemplate,
+        id: String,
+        signer_id: String,
+        address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Evaluates a relayer balance against its low balance alert thresholds and
+    /// persists the resulting alert state.
+    ///
+    /// Returns the transition (fired or cleared) if the alert state changed.
+    async fn evaluate_balance_alert(
+        &self,
+        id: String,
+        balance: u128,
+    ) -> Result<Option<AlertTransition>, RepositoryError>;
+    /// Gets a relayer by ID within a tenant.
+    ///
+    /// Returns `NotFound` if the relayer does not exist or belongs to another tenant.
+    async fn get_by_id_scoped(
+        &self,
+        id: String,
+        tenant_id: &str,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Lists the relayers belonging to a tenant, paginated.
+    async fn list_by_tenant(
+        &self,
+        tenant_id: &str,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+    /// Returns a consistent point-in-time view of all relayers, read in a single
+    /// repository operation, for computing cross-aggregate consistent statistics.
+    async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError>;
+    /// Schedules a relayer to be disabled at a future time.
+    ///
+    /// The relayer stays operational until a sweeper actions the schedule via
+    /// `disable_relayer`, which clears it.
+    async fn schedule_disable(
+        &self,
+        id: String,
+        at: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Returns relayers whose scheduled disable time is at or before `now`.
+    async fn list_due_scheduled_disables(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+    /// Clears a pending scheduled disable.
+    async fn cancel_scheduled_disable(
+        &self,
+        id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Returns the ids of desired relayers whose stored config checksum differs
+    /// from the desired one. Desired relayers that are not stored count as drifted.
+    async fn find_drifted(
+        &self,
+        desired: &[RelayerRepoModel],
+    ) -> Result<Vec<String>, RepositoryError>;
+    /// Returns the recorded enable/disable/quarantine transitions of a relayer, oldest first.
+    async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError>;
+    /// Validates every stored relayer against the current validation rules without
+    /// mutating anything, returning the offending relayer ids with their violations.
+    async fn validate_all(
+        &self,
+    ) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError>;
+    /// Deterministically maps a session key to an operational relayer in a pool.
+    ///
+    /// Repeated calls with the same key return the same relayer until the pool
+    /// membership changes; if the preferred relayer is not operational, the next
+    /// relayer in hash order is returned.
+    async fn pick_sticky(
+        &self,
+        pool: String,
+        session_key: &str,
+        strategy: StickyStrategy,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Atomically replaces a relayer's signer and address.
+    ///
+    /// Both fields are written together so they can never be out of sync. The new
+    /// address must be valid for the relayer's network type.
+    async fn rekey(
+        &self,
+        id: String,
+        new_signer_id: String,
+        new_address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Returns the subset of `ids` that exist in the store, checked in a single
+    /// repository operation rather than one lookup per id.
+    async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError>;
+    /// Releases unused capacity held by the backing store, e.g. after many deletions.
+    ///
+    /// Intended to be triggered during low traffic; a no-op for backends where it
+    /// does not apply.
+    async fn compact(&self) -> Result<(), RepositoryError>;
+    /// Opens the relayer's circuit breaker until the given time, after which it becomes half-open.
+    async fn trip_circuit(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Closes the relayer's circuit breaker regardless of its current state.
+    async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Records the outcome of a call through the relayer's circuit breaker.
+    ///
+    /// A half-open circuit closes on success and opens again on failure.
+    async fn record_outcome(
+        &self,
+        id: String,
+        success: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Lists the relayers whose policy matches `predicate`, paginated.
+    async fn list_where_policy(
+        &self,
+        predicate: PolicyPredicate,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+    /// Validates bulk import descriptors and creates the corresponding relayers.
+    ///
+    /// In strict mode the batch is all-or-nothing: if any descriptor is invalid,
+    /// nothing is created and a `ConstraintViolation` listing the offending rows is
+    /// returned. In dry-run mode nothing is created and the report lists the ids
+    /// that would be created along with the per-row violations.
+    async fn provision_from_descriptors(
+        &self,
+        descriptors: Vec<RelayerDescriptor>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionReport, RepositoryError>;
+    /// Records the most recent error seen on a relayer, replacing any previous one.
+    async fn record_error(
+        &self,
+        id: String,
+        error: RelayerLastError,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Clears the recorded last error of a relayer.
+    async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Returns relayers whose last error was recorded after `since`.
+    async fn list_with_recent_errors(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+    /// Returns the recorded configuration versions of a relayer, oldest first.
+    async fn list_config_versions(
+        &self,
+        id: String,
+    ) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError>;
+    /// Restores the configuration recorded as `version`, preserving the relayer's
+    /// current runtime state. The restored configuration is recorded as a new version.
+    async fn rollback_to(
+        &self,
+        id: String,
+        version: u32,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Gets a relayer by one of its aliases.
+    async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Returns the changes recorded after sequence number `seq`, oldest first.
+    /// Fails with `NotFound` if some of those changes were evicted from the bounded
+    /// change log, in which case the caller must perform a full resync.
+    async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError>;
+    /// Resolves the policy the relayer operates under: network defaults, overridden by
+    /// the relayer's policy as inherited from its parents, clamped by the repository's
+    /// guardrails.
+    /// See [`RelayerNetworkPolicy::effective`] for the precedence rules.
+    async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError>;
+    /// Gets the warm standby paired with the primary relayer `primary_id`, if any.
+    async fn get_standby(
+        &self,
+        primary_id: String,
+    ) -> Result<Option<RelayerRepoModel>, RepositoryError>;
+    /// Lists the relayers whose health check interval has elapsed at `now`.
+    /// Relayers without an interval are never due; relayers never checked are due at once.
+    async fn list_due_health_checks(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+    /// Records the result of a health check performed at `at`, updating the relayer's
+    /// `health` and `last_health_check_at`.
+    async fn record_health_check(
+        &self,
+        id: String,
+        result: Result<(), Vec<HealthCheckFailure>>,
+        at: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Marks the relayer as sunset: it accepts no new transactions but keeps
+    /// processing in-flight ones until it is decommissioned.
+    async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Clears the sunset flag, so the relayer accepts new transactions again.
+    async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Returns the relayer with its policy resolved through `inherits_from`: settings
+    /// the relayer leaves unset are taken from its parent, recursively.
+    async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Deletes the relayers with the given ids in one atomic step.
+    ///
+    /// Missing ids do not fail the call; they are reported in
+    /// [`DeleteReport::not_found`]. Fails with `ConstraintViolation`, deleting
+    /// nothing, if a relayer left behind inherits from one being deleted.
+    async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError>;
+    /// Lists the relayers of `network_type`, paginated. A query without `per_page`
+    /// uses the repository's page size for that network type.
+    async fn list_by_network_type(
+        &self,
+        network_type: NetworkType,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+    /// Pauses the relayer only if its `version` still equals `expected_version`.
+    ///
+    /// Fails with `Conflict`, leaving the relayer untouched, if it was written since the
+    /// caller read it. On success the version is incremented.
+    async fn pause_if_version(
+        &self,
+        id: String,
+        expected_version: u64,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Groups the operational relayers by network type, read in a single repository
+    /// operation. Network types without operational relayers are absent, and each
+    /// group is sorted by relayer id.
+    async fn group_by_network_type(
+        &self,
+    ) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError>;
+    /// Runs every create-time check against `relayer` without persisting it, returning
+    /// all failures rather than stopping at the first, e.g. to preview a provisioning run.
+    async fn validate_create(&self, relayer: RelayerRepoModel) -> Result<(), Vec<RepositoryError>>;
+    /// Lists the relayers with the given status, paginated. `total` counts only the
+    /// matching relayers.
+    async fn list_by_status(
+        &self,
+        status: RelayerOperationalStatus,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+    /// Attaches `tag` to every relayer matching `filter`, in a single write. Relayers
+    /// that already carry the tag are left untouched. Returns the number of relayers
+    /// changed.
+    async fn add_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError>;
+
+    /// Detaches `tag` from every relayer matching `filter`, in a single write. Relayers
+    /// without the tag are left untouched. Returns the number of relayers changed.
+    async fn remove_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError>;
+    /// Replaces the relayer's metadata. Fails with `ConstraintViolation` if the
+    /// metadata exceeds the repository's size limit.
+    async fn set_metadata(
+        &self,
+        id: String,
+        metadata: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+
+    /// Shallow-merges `patch` into the relayer's metadata, see
+    /// [`RelayerRepoModel::merge_metadata`]. Fails with `ConstraintViolation` if the
+    /// merged metadata exceeds the repository's size limit.
+    async fn merge_metadata(
+        &self,
+        id: String,
+        patch: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Takes one of the relayer's in-flight transaction slots, sized from its policy's
+    /// `max_inflight_transactions`. The slot is released when the returned guard is
+    /// dropped. Fails with `Conflict` when every slot is taken.
+    ///
+    /// The in-memory backend tracks slots per process. The Redis backend counts the
+    /// slots of capped relayers in Redis, so the cap holds across every instance
+    /// sharing it.
+    async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError>;
+    /// Records whether the relayer's custom RPC URL `url` is healthy
+    async fn record_rpc_health(
+        &self,
+        id: String,
+        url: String,
+        healthy: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+
+    /// Returns the RPC URL the relayer should use next, skipping unhealthy ones.
+    /// See [`RelayerRepoModel::next_healthy_rpc_url`].
+    async fn next_healthy_rpc_url(&self, id: String) -> Result<Option<String>, RepositoryError> {
+        Ok(self.get_by_id(id).await?.next_healthy_rpc_url())
+    }
+    /// Puts every relayer into maintenance until `until`, without touching their
+    /// own paused state. Maintenance ends by itself once `until` has passed.
+    async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError>;
+
+    /// Ends the current maintenance window, if any
+    async fn exit_maintenance(&self) -> Result<(), RepositoryError>;
+
+    /// Returns the end of the active maintenance window, or None outside maintenance
+    async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError>;
+
+    /// Returns true if the relayer is operational and no maintenance window is active.
+    /// See [`RelayerRepoModel::is_available`].
+    async fn is_available(&self, id: String) -> Result<bool, RepositoryError> {
+        let relayer = self.get_by_id(id).await?;
+        Ok(relayer.is_available(self.maintenance_until().await?))
+    }
+    /// Lists the operational relayers of the given network type, sorted by id
+    async fn list_active_by_network_type(
+        &self,
+        network_type: NetworkType,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+    /// Lists the members of the nonce group `group`, sorted by id
+    async fn list_by_nonce_group(
+        &self,
+        group: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+    /// Lists relayers whose error rate over the outcomes recorded within `window`
+    /// exceeds `threshold` (a fraction between 0 and 1), sorted by id. Relayers
+    /// without outcomes in the window are never listed.
+    async fn list_high_error_rate(
+        &self,
+        window: Duration,
+        threshold: f64,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+    /// Pauses every relayer whose id is not in `allowed` and resumes every allowlisted
+    /// relayer that a previous enforcement paused. Relayers paused by hand stay paused.
+    async fn enforce_allowlist(
+        &self,
+        allowed: HashSet<String>,
+    ) -> Result<EnforceReport, RepositoryError>;
+    /// Sets or, with `None`, clears the relayer's logging verbosity override. Takes
+    /// effect on the relayer's next traces without a restart.
+    async fn set_log_level(
+        &self,
+        id: String,
+        level: Option<LogLevel>,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Pins a snapshot of every relayer for a pagination session. Pass the token to
+    /// `list_paginated_at` so all pages see the same relayers while the store changes.
+    async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError>;
+    /// Lists one page of relayers, from the snapshot pinned by `token` if given and
+    /// from the live store otherwise. Pages read through a token are ordered by id.
+    async fn list_paginated_at(
+        &self,
+        query: PaginationQuery,
+        token: Option<&ReadToken>,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        match token {
+            Some(token) => token.paginate(&query),
+            None => self.list_paginated(query).await,
+        }
+    }
+    /// Looks up the relayers of `network_type` owning any of `addresses`, in one pass.
+    ///
+    /// The result is keyed by normalized address (see
+    /// [`NetworkType::normalize_address`]) and only holds addresses that matched. If
+    /// several relayers share an address, the one with the smallest id is returned.
+    async fn get_many_by_address(
+        &self,
+        addresses: Vec<String>,
+        network_type: NetworkType,
+    ) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError>;
+    /// Pins the configuration of relayer `id` while a transaction is prepared.
+    ///
+    /// While the returned guard is held, `update` and `update_policy` on the relayer
+    /// wait for it to be dropped and fail with `Conflict` once the config lock timeout
+    /// elapses. Other state changes (status, outcomes, health) are not blocked.
+    async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError>;
+    /// Increments the relayer's pending transaction counter, returning the new count.
+    async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError>;
+    /// Decrements the relayer's pending transaction counter, returning the new count.
+    ///
+    /// The counter saturates at zero, so an unmatched decrement never underflows.
+    async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError>;
+    /// Returns the number of transactions currently pending for the relayer.
+    async fn pending_count(&self, id: String) -> Result<u64, RepositoryError>;
+    /// Returns non-fatal warnings about the relayer's configuration, for advisory display.
+    ///
+    /// Unlike `validate_all`, these never block storing a relayer. Checks that need the
+    /// current network gas price are skipped; see [`RelayerRepoModel::lint`].
+    async fn lint(&self, id: String) -> Result<Vec<LintWarning>, RepositoryError> {
+        Ok(self.get_by_id(id).await?.lint(None))
+    }
+
+    /// Validates every stored relayer and reports how many fail, and which, per
+    /// failure kind. Meant for sizing remediation before tightening validation.
+    async fn policy_validation_report(&self) -> Result<PolicyValidationReport, RepositoryError> {
+        Ok(PolicyValidationReport::from_relayers(
+            self.list_all().await?.iter(),
+        ))
+    }
+
+    /// Records `amount` of native currency spent by the relayer, returning its total
+    /// for the current daily window.
+    ///
+    /// Fails with `Conflict` when the spend would exceed the policy's `max_daily_spend`;
+    /// see [`RelayerRepoModel::record_spend`].
+    async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError>;
+
+    /// Takes a canary relayer live, so it accepts all transactions. Promoting a live
+    /// relayer leaves it unchanged.
+    async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+
+    /// Records a submission key, such as a hash of sender, nonce and calldata, for the
+    /// relayer so identical in-flight transactions can be collapsed. Returns `true`
+    /// the first time a key is registered and `false` while it is still known. Keys
+    /// expire after `ttl`.
+    async fn register_submission(
+        &self,
+        id: String,
+        tx_hash_key: String,
+        ttl: Duration,
+    ) -> Result<bool, RepositoryError>;
+
+    /// Moves a relayer to `new_tenant`, recording `actor` in its ownership history.
+    /// When a tenant registry is injected, fails with `ConstraintViolation` if the
+    /// target tenant is unknown.
+    async fn transfer_ownership(
+        &self,
+        id: String,
+        new_tenant: String,
+        actor: String,
+    ) -> Result<RelayerRepoModel, RepositoryError>;
+
+    /// Lists the EVM relayers with private transactions enabled, paginated.
+    async fn list_with_private_transactions(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.list_where_policy(
+            Arc::new(|policy| match policy {
+                RelayerNetworkPolicy::Evm(evm) => evm.private_transactions == Some(true),
+                _ => false,
+            }),
+            query,
+        )
+        .await
+    }
+    /// Lists the EVM relayers whose `gas_price_cap` is set and below `max`, paginated.
+    async fn list_with_gas_price_cap_below(
+        &self,
+        max: u128,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.list_where_policy(
+            Arc::new(move |policy| match policy {
+                RelayerNetworkPolicy::Evm(evm) => evm.gas_price_cap.is_some_and(|cap| cap < max),
+                _ => false,
+            }),
+            query,
+        )
+        .await
+    }
+    /// Lists the relayers that support `capability`.
+    ///
+    /// See [`RelayerRepoModel::capabilities`] for how support is derived.
+    async fn list_with_capability(
+        &self,
+        capability: Capability,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        Ok(self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| relayer.capabilities().supports(capability))
+            .collect())
+    }
+
+    /// Exports every relayer as a [`RelayerDescriptor`], sorted by ID. Provisioning
+    /// from the result with `provision_from_descriptors` recreates the relayers with
+    /// the same configuration.
+    async fn export_as_descriptors(&self) -> Result<Vec<RelayerDescriptor>, RepositoryError> {
+        let mut descriptors: Vec<RelayerDescriptor> = self
+            .list_all()
+            .await?
+            .iter()
+            .map(RelayerDescriptor::from)
+            .collect();
+        descriptors.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(descriptors)
+    }
+
+    /// Lists the relayers whose fees are drawn from the account at `address`.
+    async fn list_by_fee_account(
+        &self,
+        address: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        Ok(self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| {
+                relayer.fee_account().is_some_and(|fee_account| {
+                    relayer
+                        .network_type
+                        .addresses_match(&fee_account.address, address)
+                })
+            })
+            .collect())
+    }
+
+    /// Returns whether the relayer's stored address matches the address `resolver`
+    /// derives from its signer configuration.
+    async fn verify_address_matches_signer(
+        &self,
+        id: String,
+        resolver: &dyn AddressResolver,
+    ) -> Result<bool, RepositoryError> {
+        let relayer = self.get_by_id(id).await?;
+        let expected = resolver.resolve_address(&relayer).await?;
+        Ok(relayer
+            .network_type
+            .addresses_match(&relayer.address, &expected))
+    }
+    /// Replaces the relayer's stored address with the address `resolver` derives from
+    /// its signer configuration. A relayer whose address already matches is returned
+    /// unchanged.
+    async fn repair_address(
+        &self,
+        id: String,
+        resolver: &dyn AddressResolver,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let relayer = self.get_by_id(id.clone()).await?;
+        let expected = resolver.resolve_address(&relayer).await?;
+        if relayer
+            .network_type
+            .addresses_match(&relayer.address, &expected)
+        {
+            return Ok(relayer);
+        }
+        self.rekey(id, relayer.signer_id, expected).await
+    }
+    /// Lists the relayers whose balance is below their policy's `min_balance`, paired
+    /// with the deficit (`min_balance - balance`), most underfunded first.
+    ///
+    /// Relayers whose balance `balances` fails to fetch are skipped.
+    async fn list_by_funding_urgency(
+        &self,
+        balances: &dyn BalanceProvider,
+    ) -> Result<Vec<(RelayerRepoModel, i128)>, RepositoryError> {
+        let mut underfunded = Vec::new();
+        for relayer in self.list_all().await? {
+            let balance = match balances.balance(&relayer).await {
+                Ok(balance) => balance,
+                Err(e) => {
+                    warn!(relayer_id = %relayer.id, error = %e, "failed to fetch relayer balance, skipping");
+                    continue;
+                }
+            };
+            if let Some(deficit) = relayer.funding_deficit(balance) {
+                underfunded.push((relayer, deficit));
+            }
+        }
+        // Ties are broken by id so the order is deterministic
+        underfunded.sort_by(|(a, a_deficit), (b, b_deficit)| {
+            b_deficit.cmp(a_deficit).then_with(|| a.id.cmp(&b.id))
+        });
+        Ok(underfunded)
+    }
+
+    /// Computes fleet metrics for export from a single snapshot.
+    ///
+    /// Balances are fetched only for relayers with a minimum balance; relayers whose
+    /// balance fails to fetch are not counted as below it.
+    async fn metrics_snapshot(
+        &self,
+        balances: &dyn BalanceProvider,
+    ) -> Result<RelayerMetricsSnapshot, RepositoryError> {
+        let snapshot = self.snapshot().await?;
+        let mut known = HashMap::new();
+        for relayer in snapshot
+            .relayers()
+            .filter(|r| r.policies.min_balance().is_some())
+        {
+            match balances.balance(relayer).await {
+                Ok(balance) => {
+                    known.insert(relayer.id.clone(), balance);
+                }
+                Err(e) => {
+                    warn!(relayer_id = %relayer.id, error = %e, "failed to fetch relayer balance for metrics");
+                }
+            }
+        }
+        Ok(snapshot.metrics(&known))
+    }
+
+    /// Reports whitelisted receivers shared between relayers, which can point at a
+    /// mis-templated config. Read from a single snapshot; see
+    /// [`RelayerSnapshot::whitelist_overlaps`].
+    async fn whitelist_overlap_report(&self) -> Result<Vec<WhitelistOverlap>, RepositoryError> {
+        Ok(self.snapshot().await?.whitelist_overlaps())
+    }
+
+    /// Decommissions a relayer: marks it sunset so it takes no new transactions,
+    /// then deletes it once `inflight_checker` reports no transactions in flight.
+    ///
+    /// While transactions remain the relayer is kept and `Draining` is returned, so
+    /// a poller can call again until it gets `Decommissioned`. Repeated calls on a
+    /// draining relayer do not modify it further.
+    async fn decommission(
+        &self,
+        id: String,
+        inflight_checker: &dyn InflightChecker,
+    ) -> Result<DecommissionState, RepositoryError> {
+        let mut relayer = self.get_by_id(id.clone()).await?;
+        if !relayer.sunset {
+            relayer = self.sunset(id.clone()).await?;
+        }
+        let remaining = inflight_checker.inflight_count(&relayer).await?;
+        if remaining > 0 {
+            return Ok(DecommissionState::Draining { remaining });
+        }
+        self.delete_by_id(id).await?;
+        Ok(DecommissionState::Decommissioned)
+    }
+    /// Assigns every stored relayer to one of `worker_count` workers using
+    /// rendezvous hashing, keyed by worker index.
+    ///
+    /// The assignment is deterministic, and when `worker_count` changes only the
+    /// relayers whose highest scoring worker was added or removed move. Every worker
+    /// index is present in the result, with relayer ids sorted.
+    async fn worker_assignment(
+        &self,
+        worker_count: usize,
+    ) -> Result<HashMap<usize, Vec<String>>, RepositoryError> {
+        if worker_count == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Worker count must be greater than 0".to_string(),
+            ));
+        }
+
+        let mut assignment: HashMap<usize, Vec<String>> = (0..worker_count)
+            .map(|worker| (worker, Vec::new()))
+            .collect();
+        for relayer in self.list_all().await? {
+            let worker = assign_worker(&relayer.id, worker_count);
+            assignment.entry(worker).or_default().push(relayer.id);
+        }
+        for ids in assignment.values_mut() {
+            ids.sort();
+        }
+        Ok(assignment)
+    }
+    /// Returns true if this repository uses persistent storage (e.g., Redis).
+    /// Returns false for in-memory storage.
+    fn is_persistent_storage(&self) -> bool;
+}
+
+/// Enum wrapper for different relayer repository implementations
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum RelayerRepositoryStorage {
+    InMemory(InMemoryRelayerRepository),
+    Redis(RedisRelayerRepository),
+}
+
+impl RelayerRepositoryStorage {
+    pub fn new_in_memory() -> Self {
+        Self::InMemory(InMemoryRelayerRepository::new())
+    }
+
+    pub fn new_redis(
+        connection_manager: Arc<ConnectionManager>,
+        key_prefix: String,
+    ) -> Result<Self, RepositoryError> {
+        Ok(Self::Redis(RedisRelayerRepository::new(
+            connection_manager,
+            key_prefix,
+        )?))
+    }
+}
+
+impl Default for RelayerRepositoryStorage {
+    fn default() -> Self {
+        Self::new_in_memory()
+    }
+}
+
+/// Maps each address of `wanted` owned by one of `relayers` to its relayer, keyed by
+/// normalized address. On shared addresses the relayer with the smallest id wins.
+pub fn match_addresses<'a>(
+    relayers: impl IntoIterator<Item = &'a RelayerRepoModel>,
+    wanted: &HashSet<String>,
+) -> HashMap<String, RelayerRepoModel> {
+    let mut matched: HashMap<String, RelayerRepoModel> = HashMap::new();
+    for relayer in relayers {
+        let address = relayer.network_type.normalize_address(&relayer.address);
+        if !wanted.contains(&address) {
+            continue;
+        }
+        match matched.get(&address) {
+            Some(existing) if existing.id <= relayer.id => {}
+            _ => {
+                matched.insert(address, relayer.clone());
+            }
+        }
+    }
+    matched
+}
+
+/// Fails with `ConstraintViolation` if a relayer outside `deleted` inherits from one
+/// in it, since deleting the parent would leave the child's policy unresolvable
+pub fn ensure_not_inherited<'a>(
+    relayers: impl IntoIterator<Item = &'a RelayerRepoModel>,
+    deleted: &HashSet<&str>,
+) -> Result<(), RepositoryError> {
+    let mut children: Vec<&str> = relayers
+        .into_iter()
+        .filter(|relayer| {
+            !deleted.contains(relayer.id.as_str())
+                && relayer
+                    .inherits_from
+                    .as_deref()
+                    .is_some_and(|parent| deleted.contains(parent))
+        })
+        .map(|relayer| relayer.id.as_str())
+        .collect();
+    if children.is_empty() {
+        return Ok(());
+    }
+    children.sort_unstable();
+    Err(RepositoryError::ConstraintViolation(format!(
+        "Relayers {} inherit from a relayer being deleted",
+        children.join(", ")
+    )))
+}
+
+/// Records the outcome of a repository operation on the current span.
+///
+/// Only the error kind is recorded, never the error message or relayer data.
+fn record_span_outcome<T>(result: Result<T, RepositoryError>) -> Result<T, RepositoryError> {
+    let outcome = match &result {
+        Ok(_) => "Ok",
+        Err(err) => err.kind(),
+    };
+    Span::current().record("outcome", outcome);
+    result
+}
+
+#[async_trait]
+impl Repository<RelayerRepoModel, String> for RelayerRepositoryStorage {
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::init",
+        skip_all,
+        fields(
+            op = "init",
+            outcome = field::Empty,
+        )
+    )]
+    async fn init(&self) -> Result<(), RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.init().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.init().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::ping",
+        skip_all,
+        fields(
+            op = "ping",
+            outcome = field::Empty,
+        )
+    )]
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.ping().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.ping().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::create",
+        skip_all,
+        fields(
+            op = "create",
+            id = %entity.id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn create(&self, entity: RelayerRepoModel) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.create(entity).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.create(entity).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::get_by_id",
+        skip_all,
+        fields(
+            op = "get_by_id",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn get_by_id(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.get_by_id(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.get_by_id(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_all",
+        skip_all,
+        fields(
+            op = "list_all",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_all(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_all().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_all().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_paginated",
+        skip_all,
+        fields(
+            op = "list_paginated",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_paginated(query).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_paginated(query).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::update",
+        skip_all,
+        fields(
+            op = "update",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn update(
+        &self,
+        id: String,
+        entity: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.update(id, entity).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.update(id, entity).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::delete_by_id",
+        skip_all,
+        fields(
+            op = "delete_by_id",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.delete_by_id(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.delete_by_id(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::count",
+        skip_all,
+        fields(
+            op = "count",
+            outcome = field::Empty,
+        )
+    )]
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.count().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.count().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::has_entries",
+        skip_all,
+        fields(
+            op = "has_entries",
+            outcome = field::Empty,
+        )
+    )]
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.has_entries().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.has_entries().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::drop_all_entries",
+        skip_all,
+        fields(
+            op = "drop_all_entries",
+            outcome = field::Empty,
+        )
+    )]
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.drop_all_entries().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.drop_all_entries().await,
+        })
+    }
+}
+
+#[async_trait]
+impl RelayerRepository for RelayerRepositoryStorage {
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_active",
+        skip_all,
+        fields(
+            op = "list_active",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_active(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_active().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_active().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_by_signer_id",
+        skip_all,
+        fields(
+            op = "list_by_signer_id",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_by_signer_id(
+        &self,
+        signer_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_by_signer_id(signer_id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_by_signer_id(signer_id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_by_notification_id",
+        skip_all,
+        fields(
+            op = "list_by_notification_id",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_by_notification_id(
+        &self,
+        notification_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.list_by_notification_id(notification_id).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.list_by_notification_id(notification_id).await
+            }
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::partial_update",
+        skip_all,
+        fields(
+            op = "partial_update",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn partial_update(
+        &self,
+        id: String,
+        update: UpdateRelayerRequest,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.partial_update(id, update).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.partial_update(id, update).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::enable_relayer",
+        skip_all,
+        fields(
+            op = "enable_relayer",
+            id = %relayer_id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn enable_relayer(
+        &self,
+        relayer_id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.enable_relayer(relayer_id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.enable_relayer(relayer_id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::disable_relayer",
+        skip_all,
+        fields(
+            op = "disable_relayer",
+            id = %relayer_id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn disable_relayer(
+        &self,
+        relayer_id: String,
+        reason: DisabledReason,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.disable_relayer(relayer_id, reason).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => repo.disable_relayer(relayer_id, reason).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::update_policy",
+        skip_all,
+        fields(
+            op = "update_policy",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn update_policy(
+        &self,
+        id: String,
+        policy: RelayerNetworkPolicy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.update_policy(id, policy).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.update_policy(id, policy).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::create_idempotent",
+        skip_all,
+        fields(
+            op = "create_idempotent",
+            id = %relayer.id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn create_idempotent(
+        &self,
+        relayer: RelayerRepoModel,
+        idempotency_key: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.create_idempotent(relayer, idempotency_key).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.create_idempotent(relayer, idempotency_key).await
+            }
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::quarantine",
+        skip_all,
+        fields(
+            op = "quarantine",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn quarantine(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.quarantine(id, until, reason).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.quarantine(id, until, reason).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_expired_quarantines",
+        skip_all,
+        fields(
+            op = "list_expired_quarantines",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_expired_quarantines(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_expired_quarantines(now).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_expired_quarantines(now).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::instantiate",
+        skip_all,
+        fields(
+            op = "instantiate",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn instantiate(
+        &self,
+        template: &RelayerTemplate,
+        id: String,
+        signer_id: String,
+        address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.instantiate(template, id, signer_id, address).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.instantiate(template, id, signer_id, address).await
+            }
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::evaluate_balance_alert",
+        skip_all,
+        fields(
+            op = "evaluate_balance_alert",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn evaluate_balance_alert(
+        &self,
+        id: String,
+        balance: u128,
+    ) -> Result<Option<AlertTransition>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.evaluate_balance_alert(id, balance).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => repo.evaluate_balance_alert(id, balance).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::get_by_id_scoped",
+        skip_all,
+        fields(
+            op = "get_by_id_scoped",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn get_by_id_scoped(
+        &self,
+        id: String,
+        tenant_id: &str,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.get_by_id_scoped(id, tenant_id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.get_by_id_scoped(id, tenant_id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_by_tenant",
+        skip_all,
+        fields(
+            op = "list_by_tenant",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_by_tenant(
+        &self,
+        tenant_id: &str,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_by_tenant(tenant_id, query).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_by_tenant(tenant_id, query).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::snapshot",
+        skip_all,
+        fields(
+            op = "snapshot",
+            outcome = field::Empty,
+        )
+    )]
+    async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.snapshot().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.snapshot().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::schedule_disable",
+        skip_all,
+        fields(
+            op = "schedule_disable",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn schedule_disable(
+        &self,
+        id: String,
+        at: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.schedule_disable(id, at, reason).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.schedule_disable(id, at, reason).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_due_scheduled_disables",
+        skip_all,
+        fields(
+            op = "list_due_scheduled_disables",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_due_scheduled_disables(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_due_scheduled_disables(now).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_due_scheduled_disables(now).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::cancel_scheduled_disable",
+        skip_all,
+        fields(
+            op = "cancel_scheduled_disable",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn cancel_scheduled_disable(
+        &self,
+        id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.cancel_scheduled_disable(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.cancel_scheduled_disable(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::find_drifted",
+        skip_all,
+        fields(
+            op = "find_drifted",
+            outcome = field::Empty,
+        )
+    )]
+    async fn find_drifted(
+        &self,
+        desired: &[RelayerRepoModel],
+    ) -> Result<Vec<String>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.find_drifted(desired).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.find_drifted(desired).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::get_status_history",
+        skip_all,
+        fields(
+            op = "get_status_history",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.get_status_history(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.get_status_history(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::validate_all",
+        skip_all,
+        fields(
+            op = "validate_all",
+            outcome = field::Empty,
+        )
+    )]
+    async fn validate_all(
+        &self,
+    ) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.validate_all().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.validate_all().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::pick_sticky",
+        skip_all,
+        fields(
+            op = "pick_sticky",
+            outcome = field::Empty,
+        )
+    )]
+    async fn pick_sticky(
+        &self,
+        pool: String,
+        session_key: &str,
+        strategy: StickyStrategy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.pick_sticky(pool, session_key, strategy).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.pick_sticky(pool, session_key, strategy).await
+            }
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::rekey",
+        skip_all,
+        fields(
+            op = "rekey",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn rekey(
+        &self,
+        id: String,
+        new_signer_id: String,
+        new_address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.rekey(id, new_signer_id, new_address).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.rekey(id, new_signer_id, new_address).await
+            }
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::existing_ids",
+        skip_all,
+        fields(
+            op = "existing_ids",
+            outcome = field::Empty,
+        )
+    )]
+    async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.existing_ids(ids).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.existing_ids(ids).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::compact",
+        skip_all,
+        fields(
+            op = "compact",
+            outcome = field::Empty,
+        )
+    )]
+    async fn compact(&self) -> Result<(), RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.compact().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.compact().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::trip_circuit",
+        skip_all,
+        fields(
+            op = "trip_circuit",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn trip_circuit(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.trip_circuit(id, until).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.trip_circuit(id, until).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::reset_circuit",
+        skip_all,
+        fields(
+            op = "reset_circuit",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.reset_circuit(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.reset_circuit(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::record_outcome",
+        skip_all,
+        fields(
+            op = "record_outcome",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn record_outcome(
+        &self,
+        id: String,
+        success: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.record_outcome(id, success).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.record_outcome(id, success).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_where_policy",
+        skip_all,
+        fields(
+            op = "list_where_policy",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_where_policy(
+        &self,
+        predicate: PolicyPredicate,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.list_where_policy(predicate, query).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => repo.list_where_policy(predicate, query).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::provision_from_descriptors",
+        skip_all,
+        fields(
+            op = "provision_from_descriptors",
+            outcome = field::Empty,
+        )
+    )]
+    async fn provision_from_descriptors(
+        &self,
+        descriptors: Vec<RelayerDescriptor>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionReport, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.provision_from_descriptors(descriptors, mode).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.provision_from_descriptors(descriptors, mode).await
+            }
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::record_error",
+        skip_all,
+        fields(
+            op = "record_error",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn record_error(
+        &self,
+        id: String,
+        error: RelayerLastError,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.record_error(id, error).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.record_error(id, error).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::clear_error",
+        skip_all,
+        fields(
+            op = "clear_error",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.clear_error(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.clear_error(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_with_recent_errors",
+        skip_all,
+        fields(
+            op = "list_with_recent_errors",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_with_recent_errors(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_with_recent_errors(since).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_with_recent_errors(since).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_config_versions",
+        skip_all,
+        fields(
+            op = "list_config_versions",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_config_versions(
+        &self,
+        id: String,
+    ) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_config_versions(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_config_versions(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::rollback_to",
+        skip_all,
+        fields(
+            op = "rollback_to",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn rollback_to(
+        &self,
+        id: String,
+        version: u32,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.rollback_to(id, version).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.rollback_to(id, version).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::get_by_alias",
+        skip_all,
+        fields(
+            op = "get_by_alias",
+            alias = %alias,
+            outcome = field::Empty,
+        )
+    )]
+    async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.get_by_alias(alias).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.get_by_alias(alias).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::changes_since",
+        skip_all,
+        fields(
+            op = "changes_since",
+            seq = %seq,
+            outcome = field::Empty,
+        )
+    )]
+    async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.changes_since(seq).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.changes_since(seq).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::effective_policy",
+        skip_all,
+        fields(
+            op = "effective_policy",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.effective_policy(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.effective_policy(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::get_standby",
+        skip_all,
+        fields(
+            op = "get_standby",
+            id = %primary_id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn get_standby(
+        &self,
+        primary_id: String,
+    ) -> Result<Option<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.get_standby(primary_id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.get_standby(primary_id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_due_health_checks",
+        skip_all,
+        fields(
+            op = "list_due_health_checks",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_due_health_checks(
         &self,
-        relayer_id: String,
-        reason: DisabledReason,
-    ) -> Result<RelayerRepoModel, RepositoryError>;
-    async fn update_policy(
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_due_health_checks(now).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_due_health_checks(now).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::record_health_check",
+        skip_all,
+        fields(
+            op = "record_health_check",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn record_health_check(
         &self,
         id: String,
-        policy: RelayerNetworkPolicy,
-    ) -> Result<RelayerRepoModel, RepositoryError>;
-    /// Returns true if this repository uses persistent storage (e.g., Redis).
-    /// Returns false for in-memory storage.
-    fn is_persistent_storage(&self) -> bool;
-}
+        result: Result<(), Vec<HealthCheckFailure>>,
+        at: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.record_health_check(id, result, at).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => repo.record_health_check(id, result, at).await,
+        })
+    }
 
-/// Enum wrapper for different relayer repository implementations
-#[derive(Debug, Clone)]
-pub enum RelayerRepositoryStorage {
-    InMemory(InMemoryRelayerRepository),
-    Redis(RedisRelayerRepository),
-}
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::sunset",
+        skip_all,
+        fields(
+            op = "sunset",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.sunset(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.sunset(id).await,
+        })
+    }
 
-impl RelayerRepositoryStorage {
-    pub fn new_in_memory() -> Self {
-        Self::InMemory(InMemoryRelayerRepository::new())
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::unsunset",
+        skip_all,
+        fields(
+            op = "unsunset",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.unsunset(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.unsunset(id).await,
+        })
     }
 
-    pub fn new_redis(
-        connection_manager: Arc<ConnectionManager>,
-        key_prefix: String,
-    ) -> Result<Self, RepositoryError> {
-        Ok(Self::Redis(RedisRelayerRepository::new(
-            connection_manager,
-            key_prefix,
-        )?))
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::get_by_id_effective",
+        skip_all,
+        fields(
+            op = "get_by_id_effective",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.get_by_id_effective(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.get_by_id_effective(id).await,
+        })
     }
-}
 
-impl Default for RelayerRepositoryStorage {
-    fn default() -> Self {
-        Self::new_in_memory()
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::delete_many",
+        skip_all,
+        fields(
+            op = "delete_many",
+            outcome = field::Empty,
+        )
+    )]
+    async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.delete_many(ids).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.delete_many(ids).await,
+        })
     }
-}
 
-#[async_trait]
-impl Repository<RelayerRepoModel, String> for RelayerRepositoryStorage {
-    async fn create(&self, entity: RelayerRepoModel) -> Result<RelayerRepoModel, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.create(entity).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.create(entity).await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_by_network_type",
+        skip_all,
+        fields(
+            op = "list_by_network_type",
+            network_type = %network_type,
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_by_network_type(
+        &self,
+        network_type: NetworkType,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.list_by_network_type(network_type, query).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.list_by_network_type(network_type, query).await
+            }
+        })
     }
 
-    async fn get_by_id(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.get_by_id(id).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.get_by_id(id).await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::pause_if_version",
+        skip_all,
+        fields(
+            op = "pause_if_version",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn pause_if_version(
+        &self,
+        id: String,
+        expected_version: u64,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.pause_if_version(id, expected_version).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.pause_if_version(id, expected_version).await
+            }
+        })
     }
 
-    async fn list_all(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.list_all().await,
-            RelayerRepositoryStorage::Redis(repo) => repo.list_all().await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::group_by_network_type",
+        skip_all,
+        fields(
+            op = "group_by_network_type",
+            outcome = field::Empty,
+        )
+    )]
+    async fn group_by_network_type(
+        &self,
+    ) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.group_by_network_type().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.group_by_network_type().await,
+        })
     }
 
-    async fn list_paginated(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::validate_create",
+        skip_all,
+        fields(
+            op = "validate_create",
+            id = %relayer.id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn validate_create(&self, relayer: RelayerRepoModel) -> Result<(), Vec<RepositoryError>> {
+        let result = match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.validate_create(relayer).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.validate_create(relayer).await,
+        };
+        // The first failure stands in for the outcome, matching what create would return
+        let outcome = match &result {
+            Ok(()) => "Ok",
+            Err(errors) => errors.first().map_or("Ok", RepositoryError::kind),
+        };
+        Span::current().record("outcome", outcome);
+        result
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_by_status",
+        skip_all,
+        fields(
+            op = "list_by_status",
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_by_status(
         &self,
+        status: RelayerOperationalStatus,
         query: PaginationQuery,
     ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.list_paginated(query).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.list_paginated(query).await,
-        }
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_by_status(status, query).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_by_status(status, query).await,
+        })
     }
 
-    async fn update(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::add_tag_where",
+        skip_all,
+        fields(
+            op = "add_tag_where",
+            tag = %tag,
+            outcome = field::Empty,
+        )
+    )]
+    async fn add_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.add_tag_where(filter, tag).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.add_tag_where(filter, tag).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::remove_tag_where",
+        skip_all,
+        fields(
+            op = "remove_tag_where",
+            tag = %tag,
+            outcome = field::Empty,
+        )
+    )]
+    async fn remove_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.remove_tag_where(filter, tag).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.remove_tag_where(filter, tag).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::set_metadata",
+        skip_all,
+        fields(
+            op = "set_metadata",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn set_metadata(
         &self,
         id: String,
-        entity: RelayerRepoModel,
+        metadata: serde_json::Value,
     ) -> Result<RelayerRepoModel, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.update(id, entity).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.update(id, entity).await,
-        }
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.set_metadata(id, metadata).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.set_metadata(id, metadata).await,
+        })
     }
 
-    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.delete_by_id(id).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.delete_by_id(id).await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::merge_metadata",
+        skip_all,
+        fields(
+            op = "merge_metadata",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn merge_metadata(
+        &self,
+        id: String,
+        patch: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.merge_metadata(id, patch).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.merge_metadata(id, patch).await,
+        })
     }
 
-    async fn count(&self) -> Result<usize, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.count().await,
-            RelayerRepositoryStorage::Redis(repo) => repo.count().await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::try_acquire_slot",
+        skip_all,
+        fields(
+            op = "try_acquire_slot",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.try_acquire_slot(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.try_acquire_slot(id).await,
+        })
     }
 
-    async fn has_entries(&self) -> Result<bool, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.has_entries().await,
-            RelayerRepositoryStorage::Redis(repo) => repo.has_entries().await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::record_rpc_health",
+        skip_all,
+        fields(
+            op = "record_rpc_health",
+            id = %id,
+            healthy = healthy,
+            outcome = field::Empty,
+        )
+    )]
+    async fn record_rpc_health(
+        &self,
+        id: String,
+        url: String,
+        healthy: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.record_rpc_health(id, url, healthy).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => repo.record_rpc_health(id, url, healthy).await,
+        })
     }
 
-    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.drop_all_entries().await,
-            RelayerRepositoryStorage::Redis(repo) => repo.drop_all_entries().await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::enter_maintenance",
+        skip_all,
+        fields(
+            op = "enter_maintenance",
+            until = %until,
+            outcome = field::Empty,
+        )
+    )]
+    async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.enter_maintenance(until).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.enter_maintenance(until).await,
+        })
     }
-}
 
-#[async_trait]
-impl RelayerRepository for RelayerRepositoryStorage {
-    async fn list_active(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.list_active().await,
-            RelayerRepositoryStorage::Redis(repo) => repo.list_active().await,
-        }
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::exit_maintenance",
+        skip_all,
+        fields(
+            op = "exit_maintenance",
+            outcome = field::Empty,
+        )
+    )]
+    async fn exit_maintenance(&self) -> Result<(), RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.exit_maintenance().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.exit_maintenance().await,
+        })
     }
 
-    async fn list_by_signer_id(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::maintenance_until",
+        skip_all,
+        fields(
+            op = "maintenance_until",
+            outcome = field::Empty,
+        )
+    )]
+    async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.maintenance_until().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.maintenance_until().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_active_by_network_type",
+        skip_all,
+        fields(
+            op = "list_active_by_network_type",
+            network_type = %network_type,
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_active_by_network_type(
         &self,
-        signer_id: &str,
+        network_type: NetworkType,
     ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.list_by_signer_id(signer_id).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.list_by_signer_id(signer_id).await,
-        }
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.list_active_by_network_type(network_type).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.list_active_by_network_type(network_type).await
+            }
+        })
     }
 
-    async fn list_by_notification_id(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_by_nonce_group",
+        skip_all,
+        fields(
+            op = "list_by_nonce_group",
+            group = %group,
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_by_nonce_group(
         &self,
-        notification_id: &str,
+        group: &str,
     ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
-        match self {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.list_by_nonce_group(group).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.list_by_nonce_group(group).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::list_high_error_rate",
+        skip_all,
+        fields(
+            op = "list_high_error_rate",
+            threshold = threshold,
+            outcome = field::Empty,
+        )
+    )]
+    async fn list_high_error_rate(
+        &self,
+        window: Duration,
+        threshold: f64,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
             RelayerRepositoryStorage::InMemory(repo) => {
-                repo.list_by_notification_id(notification_id).await
+                repo.list_high_error_rate(window, threshold).await
             }
             RelayerRepositoryStorage::Redis(repo) => {
-                repo.list_by_notification_id(notification_id).await
+                repo.list_high_error_rate(window, threshold).await
             }
-        }
+        })
     }
 
-    async fn partial_update(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::enforce_allowlist",
+        skip_all,
+        fields(
+            op = "enforce_allowlist",
+            allowed = allowed.len(),
+            outcome = field::Empty,
+        )
+    )]
+    async fn enforce_allowlist(
+        &self,
+        allowed: HashSet<String>,
+    ) -> Result<EnforceReport, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.enforce_allowlist(allowed).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.enforce_allowlist(allowed).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::set_log_level",
+        skip_all,
+        fields(
+            op = "set_log_level",
+            id = %id,
+            outcome = field::Empty,
+        )
+    )]
+    async fn set_log_level(
         &self,
         id: String,
-        update: UpdateRelayerRequest,
+        level: Option<LogLevel>,
     ) -> Result<RelayerRepoModel, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.partial_update(id, update).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.partial_update(id, update).await,
-        }
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.set_log_level(id, level).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.set_log_level(id, level).await,
+        })
     }
 
-    async fn enable_relayer(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::begin_consistent_read",
+        skip_all,
+        fields(
+            op = "begin_consistent_read",
+            outcome = field::Empty,
+        )
+    )]
+    async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.begin_consistent_read().await,
+            RelayerRepositoryStorage::Redis(repo) => repo.begin_consistent_read().await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::get_many_by_address",
+        skip_all,
+        fields(
+            op = "get_many_by_address",
+            network_type = %network_type,
+            addresses = addresses.len(),
+            outcome = field::Empty,
+        )
+    )]
+    async fn get_many_by_address(
         &self,
-        relayer_id: String,
-    ) -> Result<RelayerRepoModel, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.enable_relayer(relayer_id).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.enable_relayer(relayer_id).await,
-        }
+        addresses: Vec<String>,
+        network_type: NetworkType,
+    ) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.get_many_by_address(addresses, network_type).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.get_many_by_address(addresses, network_type).await
+            }
+        })
     }
 
-    async fn disable_relayer(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::acquire_config_read",
+        skip_all,
+        fields(op = "acquire_config_read", relayer_id = %id, outcome = field::Empty)
+    )]
+    async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.acquire_config_read(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.acquire_config_read(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::increment_pending",
+        skip_all,
+        fields(op = "increment_pending", id = %id, outcome = field::Empty)
+    )]
+    async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.increment_pending(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.increment_pending(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::decrement_pending",
+        skip_all,
+        fields(op = "decrement_pending", id = %id, outcome = field::Empty)
+    )]
+    async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.decrement_pending(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.decrement_pending(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::pending_count",
+        skip_all,
+        fields(op = "pending_count", id = %id, outcome = field::Empty)
+    )]
+    async fn pending_count(&self, id: String) -> Result<u64, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.pending_count(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.pending_count(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::record_spend",
+        skip_all,
+        fields(op = "record_spend", id = %id, outcome = field::Empty)
+    )]
+    async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.record_spend(id, amount).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.record_spend(id, amount).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::promote_to_live",
+        skip_all,
+        fields(op = "promote_to_live", id = %id, outcome = field::Empty)
+    )]
+    async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => repo.promote_to_live(id).await,
+            RelayerRepositoryStorage::Redis(repo) => repo.promote_to_live(id).await,
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::register_submission",
+        skip_all,
+        fields(op = "register_submission", id = %id, outcome = field::Empty)
+    )]
+    async fn register_submission(
         &self,
-        relayer_id: String,
-        reason: DisabledReason,
-    ) -> Result<RelayerRepoModel, RepositoryError> {
-        match self {
+        id: String,
+        tx_hash_key: String,
+        ttl: Duration,
+    ) -> Result<bool, RepositoryError> {
+        record_span_outcome(match self {
             RelayerRepositoryStorage::InMemory(repo) => {
-                repo.disable_relayer(relayer_id, reason).await
+                repo.register_submission(id, tx_hash_key, ttl).await
             }
-            RelayerRepositoryStorage::Redis(repo) => repo.disable_relayer(relayer_id, reason).await,
-        }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.register_submission(id, tx_hash_key, ttl).await
+            }
+        })
     }
 
-    async fn update_policy(
+    #[instrument(
+        level = "debug",
+        name = "RelayerRepository::transfer_ownership",
+        skip_all,
+        fields(op = "transfer_ownership", id = %id, outcome = field::Empty)
+    )]
+    async fn transfer_ownership(
         &self,
         id: String,
-        policy: RelayerNetworkPolicy,
+        new_tenant: String,
+        actor: String,
     ) -> Result<RelayerRepoModel, RepositoryError> {
-        match self {
-            RelayerRepositoryStorage::InMemory(repo) => repo.update_policy(id, policy).await,
-            RelayerRepositoryStorage::Redis(repo) => repo.update_policy(id, policy).await,
-        }
+        record_span_outcome(match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.transfer_ownership(id, new_tenant, actor).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.transfer_ownership(id, new_tenant, actor).await
+            }
+        })
     }
 
     fn is_persistent_storage(&self) -> bool {
@@ -260,7 +2620,10 @@ impl RelayerRepository for RelayerRepositoryStorage {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy};
+    use crate::models::{
+        GasOracleConfig, NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy, RevertHandling,
+        StuckTxAction,
+    };
 
     fn create_test_relayer(id: String) -> RelayerRepoModel {
         RelayerRepoModel {
@@ -276,6 +2639,23 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(false),
                 private_transactions: Some(false),
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             }),
             signer_id: "test".to_string(),
             address: "0x".to_string(),
@@ -376,6 +2756,23 @@ mod tests {
             whitelist_receivers: None,
             eip1559_pricing: Some(true),
             private_transactions: Some(false),
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
         });
         let policy_updated = impl_repo
             .update_policy(relayer.id.clone(), new_policy)
@@ -408,7 +2805,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
 
         let result = impl_repo.list_paginated(query).await.unwrap();
@@ -469,6 +2866,137 @@ mod tests {
         repo.drop_all_entries().await.unwrap();
         assert!(!repo.has_entries().await.unwrap());
     }
+
+    type CapturedSpan = (String, std::collections::HashMap<String, String>);
+
+    /// Captures the fields of every span, keyed by span name
+    #[derive(Clone, Default)]
+    struct SpanCapture {
+        spans: Arc<std::sync::Mutex<Vec<CapturedSpan>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut std::collections::HashMap<String, String>);
+
+    impl tracing::field::Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    impl<S> tracing_subscriber::Layer<S> for SpanCapture
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = std::collections::HashMap::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((attrs.metadata().name().to_string(), fields));
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(spans.len() - 1);
+            }
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            if let Some(index) = ctx
+                .span(id)
+                .and_then(|span| span.extensions().get::<usize>().copied())
+            {
+                let mut spans = self.spans.lock().unwrap();
+                values.record(&mut FieldVisitor(&mut spans[index].1));
+            }
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_get_by_id_miss_emits_not_found_span() {
+        use tracing_subscriber::prelude::*;
+
+        let capture = SpanCapture::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let repo = RelayerRepositoryStorage::new_in_memory();
+        let result = repo.get_by_id("missing-relayer".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+
+        let spans = capture.spans.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| name == "RelayerRepository::get_by_id")
+            .expect("get_by_id span should be emitted");
+        assert_eq!(fields.get("op").map(String::as_str), Some("get_by_id"));
+        assert_eq!(
+            fields.get("id").map(String::as_str),
+            Some("missing-relayer")
+        );
+        assert_eq!(fields.get("outcome").map(String::as_str), Some("NotFound"));
+    }
+
+    #[actix_web::test]
+    async fn test_init_is_forwarded_to_backend() {
+        use tracing_subscriber::prelude::*;
+
+        let capture = SpanCapture::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let repo = RelayerRepositoryStorage::new_in_memory();
+        repo.init().await.unwrap();
+
+        let spans = capture.spans.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| name == "RelayerRepository::init")
+            .expect("init span should be emitted");
+        assert_eq!(fields.get("op").map(String::as_str), Some("init"));
+        assert_eq!(fields.get("outcome").map(String::as_str), Some("Ok"));
+    }
+
+    #[actix_web::test]
+    async fn test_update_policy_span_does_not_record_policy() {
+        use tracing_subscriber::prelude::*;
+
+        let capture = SpanCapture::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let repo = RelayerRepositoryStorage::new_in_memory();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        repo.update_policy(
+            "test".to_string(),
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+        )
+        .await
+        .unwrap();
+
+        let spans = capture.spans.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| name == "RelayerRepository::update_policy")
+            .expect("update_policy span should be emitted");
+        assert_eq!(fields.get("outcome").map(String::as_str), Some("Ok"));
+        let mut keys: Vec<&str> = fields.keys().map(String::as_str).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["id", "op", "outcome"]);
+    }
 }
 
 #[cfg(test)]
@@ -497,6 +3025,75 @@ mockall::mock! {
         async fn enable_relayer(&self, relayer_id: String) -> Result<RelayerRepoModel, RepositoryError>;
         async fn disable_relayer(&self, relayer_id: String, reason: DisabledReason) -> Result<RelayerRepoModel, RepositoryError>;
         async fn update_policy(&self, id: String, policy: RelayerNetworkPolicy) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn create_idempotent(&self, relayer: RelayerRepoModel, idempotency_key: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn quarantine(&self, id: String, until: DateTime<Utc>, reason: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn list_expired_quarantines(&self, now: DateTime<Utc>) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+        async fn instantiate(&self, template: &RelayerTemplate, id: String, signer_id: String, address: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn evaluate_balance_alert(&self, id: String, balance: u128) -> Result<Option<AlertTransition>, RepositoryError>;
+        async fn get_by_id_scoped(&self, id: String, tenant_id: &str) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn list_by_tenant(&self, tenant_id: &str, query: PaginationQuery) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+        async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError>;
+        async fn schedule_disable(&self, id: String, at: DateTime<Utc>, reason: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn list_due_scheduled_disables(&self, now: DateTime<Utc>) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+        async fn cancel_scheduled_disable(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn find_drifted(&self, desired: &[RelayerRepoModel]) -> Result<Vec<String>, RepositoryError>;
+        async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError>;
+        async fn validate_all(&self) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError>;
+        async fn pick_sticky(&self, pool: String, session_key: &str, strategy: StickyStrategy) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn rekey(&self, id: String, new_signer_id: String, new_address: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError>;
+        async fn compact(&self) -> Result<(), RepositoryError>;
+        async fn trip_circuit(&self, id: String, until: DateTime<Utc>) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn record_outcome(&self, id: String, success: bool) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn list_where_policy(&self, predicate: PolicyPredicate, query: PaginationQuery) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+        async fn provision_from_descriptors(&self, descriptors: Vec<RelayerDescriptor>, mode: ProvisionMode) -> Result<ProvisionReport, RepositoryError>;
+        async fn record_error(&self, id: String, error: RelayerLastError) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn list_with_recent_errors(&self, since: DateTime<Utc>) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+        async fn list_config_versions(&self, id: String) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError>;
+        async fn rollback_to(&self, id: String, version: u32) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError>;
+        async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError>;
+        async fn get_standby(&self, primary_id: String) -> Result<Option<RelayerRepoModel>, RepositoryError>;
+        async fn list_due_health_checks(&self, now: DateTime<Utc>) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+        async fn record_health_check(&self, id: String, result: Result<(), Vec<HealthCheckFailure>>, at: DateTime<Utc>) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError>;
+        async fn list_by_network_type(&self, network_type: NetworkType, query: PaginationQuery) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+        async fn pause_if_version(&self, id: String, expected_version: u64) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn group_by_network_type(&self) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError>;
+        async fn validate_create(&self, relayer: RelayerRepoModel) -> Result<(), Vec<RepositoryError>>;
+        async fn list_by_status(&self, status: RelayerOperationalStatus, query: PaginationQuery) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError>;
+        async fn add_tag_where(&self, filter: RelayerFilter, tag: String) -> Result<u64, RepositoryError>;
+        async fn remove_tag_where(&self, filter: RelayerFilter, tag: String) -> Result<u64, RepositoryError>;
+        async fn set_metadata(&self, id: String, metadata: serde_json::Value) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn merge_metadata(&self, id: String, patch: serde_json::Value) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError>;
+        async fn record_rpc_health(&self, id: String, url: String, healthy: bool) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn next_healthy_rpc_url(&self, id: String) -> Result<Option<String>, RepositoryError>;
+        async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError>;
+        async fn exit_maintenance(&self) -> Result<(), RepositoryError>;
+        async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError>;
+        async fn is_available(&self, id: String) -> Result<bool, RepositoryError>;
+        async fn list_active_by_network_type(&self, network_type: NetworkType) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+        async fn list_by_nonce_group(&self, group: &str) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+        async fn list_high_error_rate(&self, window: Duration, threshold: f64) -> Result<Vec<RelayerRepoModel>, RepositoryError>;
+        async fn enforce_allowlist(&self, allowed: HashSet<String>) -> Result<EnforceReport, RepositoryError>;
+        async fn set_log_level(&self, id: String, level: Option<LogLevel>) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError>;
+        async fn get_many_by_address(&self, addresses: Vec<String>, network_type: NetworkType) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError>;
+        async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError>;
+        async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError>;
+        async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError>;
+        async fn pending_count(&self, id: String) -> Result<u64, RepositoryError>;
+        async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError>;
+        async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn register_submission(&self, id: String, tx_hash_key: String, ttl: Duration) -> Result<bool, RepositoryError>;
+        async fn transfer_ownership(&self, id: String, new_tenant: String, actor: String) -> Result<RelayerRepoModel, RepositoryError>;
         fn is_persistent_storage(&self) -> bool;
     }
 }
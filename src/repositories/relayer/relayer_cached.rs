@@ -0,0 +1,845 @@
+//! Caching decorator for relayer repositories.
+//!
+//! [`CachedRelayerRepository`] keeps relayers returned by `get_by_id` in a
+//! local cache. Every write it passes to the inner repository evicts the
+//! affected relayers and publishes an [`Invalidation`], so that other instances
+//! sharing the same storage evict their copies once
+//! [`CachedRelayerRepository::spawn_invalidation_listener`] is running. All
+//! other reads pass straight through.
+
+use crate::models::{
+    AlertTransition, ChangeRecord, DeleteReport, DisabledReason, EnforceReport, HealthCheckFailure,
+    LogLevel, NetworkType, PaginationQuery, ProvisionMode, ProvisionReport,
+    RelayerConfigVersionInfo, RelayerDescriptor, RelayerFilter, RelayerLastError,
+    RelayerNetworkPolicy, RelayerOperationalStatus, RelayerRepoModel, RelayerTemplate,
+    RelayerValidationError, RepositoryError, StatusChange, UpdateRelayerRequest,
+};
+use crate::repositories::{
+    ConfigReadGuard, Invalidation, InvalidationPublisher, InvalidationSubscriber, PaginatedResult,
+    PolicyPredicate, ReadToken, RelayerRepository, RelayerSnapshot, Repository, SlotGuard,
+    StickyStrategy,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+#[derive(Debug)]
+pub struct CachedRelayerRepository<R> {
+    inner: R,
+    cache: DashMap<String, RelayerRepoModel>,
+    /// Bumped by every eviction, so a read that raced one is not cached
+    generation: AtomicU64,
+    publisher: Arc<dyn InvalidationPublisher>,
+}
+
+impl<R> CachedRelayerRepository<R> {
+    /// Wraps `inner`, publishing the invalidations its writes cause to `publisher`
+    pub fn new(inner: R, publisher: Arc<dyn InvalidationPublisher>) -> Self {
+        Self {
+            inner,
+            cache: DashMap::new(),
+            generation: AtomicU64::new(0),
+            publisher,
+        }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn is_cached(&self, id: &str) -> bool {
+        self.cache.contains_key(id)
+    }
+
+    /// Drops the cached relayers `invalidation` names
+    pub fn evict(&self, invalidation: &Invalidation) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        match invalidation {
+            Invalidation::Relayer(id) => {
+                self.cache.remove(id);
+            }
+            Invalidation::All => self.cache.clear(),
+        }
+    }
+
+    /// Evicts locally, then tells the other instances to do the same
+    async fn invalidate(&self, invalidation: Invalidation) {
+        self.evict(&invalidation);
+        if let Err(e) = self.publisher.publish(invalidation).await {
+            warn!(error = %e, "failed to publish relayer invalidation");
+        }
+    }
+}
+
+impl<R: Send + Sync + 'static> CachedRelayerRepository<R> {
+    /// Subscribes to `subscriber` and evicts whatever the invalidations received
+    /// name, until the channel closes or the returned task is aborted
+    pub async fn spawn_invalidation_listener(
+        self: Arc<Self>,
+        subscriber: &dyn InvalidationSubscriber,
+    ) -> Result<JoinHandle<()>, RepositoryError> {
+        let mut receiver = subscriber.subscribe().await?;
+        Ok(tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(invalidation) => self.evict(&invalidation),
+                    Err(RecvError::Lagged(missed)) => {
+                        // The missed invalidations are unknown, so nothing cached can be trusted
+                        warn!(
+                            missed,
+                            "relayer invalidation listener lagged, clearing cache"
+                        );
+                        self.evict(&Invalidation::All);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }))
+    }
+}
+
+#[async_trait]
+impl<R: RelayerRepository> Repository<RelayerRepoModel, String> for CachedRelayerRepository<R> {
+    async fn init(&self) -> Result<(), RepositoryError> {
+        self.inner.init().await
+    }
+
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        self.inner.ping().await
+    }
+
+    async fn create(&self, entity: RelayerRepoModel) -> Result<RelayerRepoModel, RepositoryError> {
+        // A new ID has nothing cached under it yet
+        self.inner.create(entity).await
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        if let Some(cached) = self.cache.get(&id) {
+            return Ok(cached.clone());
+        }
+        // An invalidation landing while the read is in flight may already cover
+        // what it returns, so the result is only cached if none did
+        let generation = self.generation.load(Ordering::SeqCst);
+        let relayer = self.inner.get_by_id(id.clone()).await?;
+        if self.generation.load(Ordering::SeqCst) == generation {
+            self.cache.insert(id, relayer.clone());
+        }
+        Ok(relayer)
+    }
+
+    async fn list_all(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_all().await
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_paginated(query).await
+    }
+
+    async fn update(
+        &self,
+        id: String,
+        entity: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.update(id.clone(), entity).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        let result = self.inner.delete_by_id(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        self.inner.count().await
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        self.inner.has_entries().await
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        let result = self.inner.drop_all_entries().await;
+        self.invalidate(Invalidation::All).await;
+        result
+    }
+}
+
+#[async_trait]
+impl<R: RelayerRepository> RelayerRepository for CachedRelayerRepository<R> {
+    async fn list_active(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_active().await
+    }
+
+    async fn list_by_signer_id(
+        &self,
+        signer_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_signer_id(signer_id).await
+    }
+
+    async fn list_by_notification_id(
+        &self,
+        notification_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_notification_id(notification_id).await
+    }
+
+    async fn partial_update(
+        &self,
+        id: String,
+        update: UpdateRelayerRequest,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.partial_update(id.clone(), update).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn enable_relayer(
+        &self,
+        relayer_id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.enable_relayer(relayer_id.clone()).await;
+        self.invalidate(Invalidation::Relayer(relayer_id)).await;
+        result
+    }
+
+    async fn disable_relayer(
+        &self,
+        relayer_id: String,
+        reason: DisabledReason,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.disable_relayer(relayer_id.clone(), reason).await;
+        self.invalidate(Invalidation::Relayer(relayer_id)).await;
+        result
+    }
+
+    async fn update_policy(
+        &self,
+        id: String,
+        policy: RelayerNetworkPolicy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.update_policy(id.clone(), policy).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn create_idempotent(
+        &self,
+        relayer: RelayerRepoModel,
+        idempotency_key: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.create_idempotent(relayer, idempotency_key).await
+    }
+
+    async fn quarantine(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.quarantine(id.clone(), until, reason).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn list_expired_quarantines(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_expired_quarantines(now).await
+    }
+
+    async fn instantiate(
+        &self,
+        template: &RelayerTemplate,
+        id: String,
+        signer_id: String,
+        address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner
+            .instantiate(template, id, signer_id, address)
+            .await
+    }
+
+    async fn evaluate_balance_alert(
+        &self,
+        id: String,
+        balance: u128,
+    ) -> Result<Option<AlertTransition>, RepositoryError> {
+        let result = self.inner.evaluate_balance_alert(id.clone(), balance).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn get_by_id_scoped(
+        &self,
+        id: String,
+        tenant_id: &str,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.get_by_id_scoped(id, tenant_id).await
+    }
+
+    async fn list_by_tenant(
+        &self,
+        tenant_id: &str,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_tenant(tenant_id, query).await
+    }
+
+    async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError> {
+        self.inner.snapshot().await
+    }
+
+    async fn schedule_disable(
+        &self,
+        id: String,
+        at: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.schedule_disable(id.clone(), at, reason).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn list_due_scheduled_disables(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_due_scheduled_disables(now).await
+    }
+
+    async fn cancel_scheduled_disable(
+        &self,
+        id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.cancel_scheduled_disable(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn find_drifted(
+        &self,
+        desired: &[RelayerRepoModel],
+    ) -> Result<Vec<String>, RepositoryError> {
+        self.inner.find_drifted(desired).await
+    }
+
+    async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError> {
+        self.inner.get_status_history(id).await
+    }
+
+    async fn validate_all(
+        &self,
+    ) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError> {
+        self.inner.validate_all().await
+    }
+
+    async fn pick_sticky(
+        &self,
+        pool: String,
+        session_key: &str,
+        strategy: StickyStrategy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.pick_sticky(pool, session_key, strategy).await
+    }
+
+    async fn rekey(
+        &self,
+        id: String,
+        new_signer_id: String,
+        new_address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self
+            .inner
+            .rekey(id.clone(), new_signer_id, new_address)
+            .await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError> {
+        self.inner.existing_ids(ids).await
+    }
+
+    async fn compact(&self) -> Result<(), RepositoryError> {
+        // Compaction releases memory without changing any relayer data
+        self.inner.compact().await
+    }
+
+    async fn trip_circuit(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.trip_circuit(id.clone(), until).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.reset_circuit(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn record_outcome(
+        &self,
+        id: String,
+        success: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.record_outcome(id.clone(), success).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn list_where_policy(
+        &self,
+        predicate: PolicyPredicate,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_where_policy(predicate, query).await
+    }
+
+    async fn provision_from_descriptors(
+        &self,
+        descriptors: Vec<RelayerDescriptor>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionReport, RepositoryError> {
+        // Provisioning only creates relayers, so nothing cached goes stale
+        self.inner
+            .provision_from_descriptors(descriptors, mode)
+            .await
+    }
+
+    async fn record_error(
+        &self,
+        id: String,
+        error: RelayerLastError,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.record_error(id.clone(), error).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.clear_error(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn list_with_recent_errors(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_with_recent_errors(since).await
+    }
+
+    async fn list_config_versions(
+        &self,
+        id: String,
+    ) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError> {
+        self.inner.list_config_versions(id).await
+    }
+
+    async fn rollback_to(
+        &self,
+        id: String,
+        version: u32,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.rollback_to(id.clone(), version).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.get_by_alias(alias).await
+    }
+
+    async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError> {
+        self.inner.changes_since(seq).await
+    }
+
+    async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError> {
+        self.inner.effective_policy(id).await
+    }
+
+    async fn get_standby(
+        &self,
+        primary_id: String,
+    ) -> Result<Option<RelayerRepoModel>, RepositoryError> {
+        self.inner.get_standby(primary_id).await
+    }
+
+    async fn list_due_health_checks(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_due_health_checks(now).await
+    }
+
+    async fn record_health_check(
+        &self,
+        id: String,
+        result: Result<(), Vec<HealthCheckFailure>>,
+        at: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.record_health_check(id.clone(), result, at).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.sunset(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.unsunset(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.get_by_id_effective(id).await
+    }
+
+    async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError> {
+        let result = self.inner.delete_many(ids.clone()).await;
+        for id in ids {
+            self.invalidate(Invalidation::Relayer(id)).await;
+        }
+        result
+    }
+
+    async fn list_by_network_type(
+        &self,
+        network_type: NetworkType,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_network_type(network_type, query).await
+    }
+
+    async fn pause_if_version(
+        &self,
+        id: String,
+        expected_version: u64,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self
+            .inner
+            .pause_if_version(id.clone(), expected_version)
+            .await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn group_by_network_type(
+        &self,
+    ) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError> {
+        self.inner.group_by_network_type().await
+    }
+
+    async fn validate_create(&self, relayer: RelayerRepoModel) -> Result<(), Vec<RepositoryError>> {
+        self.inner.validate_create(relayer).await
+    }
+
+    async fn list_by_status(
+        &self,
+        status: RelayerOperationalStatus,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_status(status, query).await
+    }
+
+    async fn add_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        let result = self.inner.add_tag_where(filter, tag).await;
+        self.invalidate(Invalidation::All).await;
+        result
+    }
+
+    async fn remove_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        let result = self.inner.remove_tag_where(filter, tag).await;
+        self.invalidate(Invalidation::All).await;
+        result
+    }
+
+    async fn set_metadata(
+        &self,
+        id: String,
+        metadata: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.set_metadata(id.clone(), metadata).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn merge_metadata(
+        &self,
+        id: String,
+        patch: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.merge_metadata(id.clone(), patch).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError> {
+        self.inner.try_acquire_slot(id).await
+    }
+
+    async fn record_rpc_health(
+        &self,
+        id: String,
+        url: String,
+        healthy: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.record_rpc_health(id.clone(), url, healthy).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError> {
+        self.inner.enter_maintenance(until).await
+    }
+
+    async fn exit_maintenance(&self) -> Result<(), RepositoryError> {
+        self.inner.exit_maintenance().await
+    }
+
+    async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        self.inner.maintenance_until().await
+    }
+
+    async fn list_active_by_network_type(
+        &self,
+        network_type: NetworkType,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_active_by_network_type(network_type).await
+    }
+
+    async fn list_by_nonce_group(
+        &self,
+        group: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_nonce_group(group).await
+    }
+
+    async fn list_high_error_rate(
+        &self,
+        window: Duration,
+        threshold: f64,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_high_error_rate(window, threshold).await
+    }
+
+    async fn enforce_allowlist(
+        &self,
+        allowed: HashSet<String>,
+    ) -> Result<EnforceReport, RepositoryError> {
+        let result = self.inner.enforce_allowlist(allowed).await;
+        self.invalidate(Invalidation::All).await;
+        result
+    }
+
+    async fn set_log_level(
+        &self,
+        id: String,
+        level: Option<LogLevel>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.set_log_level(id.clone(), level).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError> {
+        self.inner.begin_consistent_read().await
+    }
+
+    async fn get_many_by_address(
+        &self,
+        addresses: Vec<String>,
+        network_type: NetworkType,
+    ) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError> {
+        self.inner
+            .get_many_by_address(addresses, network_type)
+            .await
+    }
+
+    async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError> {
+        self.inner.acquire_config_read(id).await
+    }
+
+    async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        let result = self.inner.increment_pending(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        let result = self.inner.decrement_pending(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn pending_count(&self, id: String) -> Result<u64, RepositoryError> {
+        self.inner.pending_count(id).await
+    }
+
+    async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError> {
+        let result = self.inner.record_spend(id.clone(), amount).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self.inner.promote_to_live(id.clone()).await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    async fn register_submission(
+        &self,
+        id: String,
+        tx_hash_key: String,
+        ttl: Duration,
+    ) -> Result<bool, RepositoryError> {
+        self.inner.register_submission(id, tx_hash_key, ttl).await
+    }
+
+    async fn transfer_ownership(
+        &self,
+        id: String,
+        new_tenant: String,
+        actor: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let result = self
+            .inner
+            .transfer_ownership(id.clone(), new_tenant, actor)
+            .await;
+        self.invalidate(Invalidation::Relayer(id)).await;
+        result
+    }
+
+    fn is_persistent_storage(&self) -> bool {
+        self.inner.is_persistent_storage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryRelayerRepository, InProcessInvalidationChannel};
+
+    fn create_test_relayer(id: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: id.to_string(),
+            name: format!("Relayer {id}"),
+            network: "TestNet".to_string(),
+            signer_id: "test".to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn create_cached_repo(
+        channel: &InProcessInvalidationChannel,
+    ) -> CachedRelayerRepository<InMemoryRelayerRepository> {
+        let inner = InMemoryRelayerRepository::new();
+        inner.create(create_test_relayer("existing")).await.unwrap();
+        CachedRelayerRepository::new(inner, Arc::new(channel.clone()))
+    }
+
+    async fn wait_until_evicted<R>(repo: &CachedRelayerRepository<R>, id: &str) {
+        for _ in 0..100 {
+            if !repo.is_cached(id) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("{id} was never evicted");
+    }
+
+    #[tokio::test]
+    async fn test_get_by_id_is_served_from_cache() {
+        let channel = InProcessInvalidationChannel::new();
+        let repo = create_cached_repo(&channel).await;
+        let id = "existing".to_string();
+
+        repo.get_by_id(id.clone()).await.unwrap();
+        assert!(repo.is_cached(&id));
+
+        // A write that bypasses the decorator is not seen until an invalidation arrives
+        let mut changed = create_test_relayer("existing");
+        changed.name = "Changed".to_string();
+        repo.inner().update(id.clone(), changed).await.unwrap();
+        assert_eq!(repo.get_by_id(id).await.unwrap().name, "Relayer existing");
+    }
+
+    #[tokio::test]
+    async fn test_write_evicts_and_publishes() {
+        let channel = InProcessInvalidationChannel::new();
+        let mut receiver = channel.subscribe().await.unwrap();
+        let repo = create_cached_repo(&channel).await;
+        let id = "existing".to_string();
+
+        repo.get_by_id(id.clone()).await.unwrap();
+        repo.disable_relayer(
+            id.clone(),
+            DisabledReason::BalanceCheckFailed("low balance".to_string()),
+        )
+        .await
+        .unwrap();
+
+        assert!(!repo.is_cached(&id));
+        assert!(repo.get_by_id(id.clone()).await.unwrap().system_disabled);
+        assert_eq!(receiver.recv().await.unwrap(), Invalidation::Relayer(id));
+    }
+
+    #[tokio::test]
+    async fn test_remote_invalidation_evicts_cached_entry() {
+        let channel = InProcessInvalidationChannel::new();
+        let repo = Arc::new(create_cached_repo(&channel).await);
+        let listener = repo
+            .clone()
+            .spawn_invalidation_listener(&channel)
+            .await
+            .unwrap();
+        let id = "existing".to_string();
+
+        repo.get_by_id(id.clone()).await.unwrap();
+        assert!(repo.is_cached(&id));
+
+        // Another instance changes the relayer in the shared storage and announces it
+        let mut changed = create_test_relayer("existing");
+        changed.name = "Changed remotely".to_string();
+        repo.inner().update(id.clone(), changed).await.unwrap();
+        channel
+            .publish(Invalidation::Relayer(id.clone()))
+            .await
+            .unwrap();
+
+        wait_until_evicted(&repo, &id).await;
+        assert_eq!(repo.get_by_id(id).await.unwrap().name, "Changed remotely");
+        listener.abort();
+    }
+}
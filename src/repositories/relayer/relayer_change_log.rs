@@ -0,0 +1,134 @@
+//! Bounded log of relayer mutations.
+//!
+//! Every create, update and delete is appended as a [`ChangeRecord`] with a
+//! monotonically increasing sequence number. A consumer that loses its
+//! connection remembers the last sequence it processed and catches up with
+//! [`changes_after`]; if the records it missed have already been evicted it
+//! gets an error and must fall back to a full resync.
+
+use crate::models::{ChangeKind, ChangeRecord, RepositoryError};
+use chrono::Utc;
+use std::collections::VecDeque;
+
+/// In-process change log keeping the most recent `capacity` records
+#[derive(Debug, Clone)]
+pub struct ChangeLog {
+    records: VecDeque<ChangeRecord>,
+    latest_seq: u64,
+    capacity: usize,
+}
+
+impl ChangeLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: VecDeque::new(),
+            latest_seq: 0,
+            capacity,
+        }
+    }
+
+    /// Appends a record for `id`, evicting the oldest record once the log is full
+    pub fn append(&mut self, kind: ChangeKind, id: &str) -> ChangeRecord {
+        self.latest_seq += 1;
+        let record = ChangeRecord {
+            seq: self.latest_seq,
+            kind,
+            id: id.to_string(),
+            at: Utc::now(),
+        };
+        self.records.push_back(record.clone());
+        while self.records.len() > self.capacity {
+            self.records.pop_front();
+        }
+        record
+    }
+
+    /// Sequence number of the most recent record, or zero if nothing was appended
+    pub fn latest_seq(&self) -> u64 {
+        self.latest_seq
+    }
+
+    /// Returns the records with a sequence number greater than `seq`
+    pub fn since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError> {
+        changes_after(self.records.iter().cloned(), self.latest_seq, seq)
+    }
+}
+
+/// Selects the records after `seq` from a log whose newest sequence is `latest_seq`.
+///
+/// Fails with `NotFound` if records after `seq` have been evicted, and with
+/// `InvalidData` if `seq` is ahead of the log.
+pub fn changes_after(
+    records: impl IntoIterator<Item = ChangeRecord>,
+    latest_seq: u64,
+    seq: u64,
+) -> Result<Vec<ChangeRecord>, RepositoryError> {
+    if seq > latest_seq {
+        return Err(RepositoryError::InvalidData(format!(
+            "Change sequence {seq} is ahead of the latest change {latest_seq}"
+        )));
+    }
+    if seq == latest_seq {
+        return Ok(Vec::new());
+    }
+
+    let missed: Vec<ChangeRecord> = records
+        .into_iter()
+        .filter(|record| record.seq > seq)
+        .collect();
+    match missed.first() {
+        Some(first) if first.seq == seq + 1 => Ok(missed),
+        _ => Err(RepositoryError::NotFound(format!(
+            "Relayer changes after sequence {seq} have been evicted, a full resync is required"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_assigns_sequence_numbers() {
+        let mut log = ChangeLog::new(10);
+        let first = log.append(ChangeKind::Created, "a");
+        let second = log.append(ChangeKind::Updated, "a");
+
+        assert_eq!(first.seq, 1);
+        assert_eq!(second.seq, 2);
+        assert_eq!(second.kind, ChangeKind::Updated);
+    }
+
+    #[test]
+    fn test_since_returns_records_after_cursor() {
+        let mut log = ChangeLog::new(10);
+        log.append(ChangeKind::Created, "a");
+        log.append(ChangeKind::Created, "b");
+        log.append(ChangeKind::Deleted, "a");
+
+        let seqs: Vec<u64> = log.since(1).unwrap().iter().map(|r| r.seq).collect();
+        assert_eq!(seqs, vec![2, 3]);
+        assert!(log.since(3).unwrap().is_empty());
+        assert_eq!(log.since(0).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_since_reports_eviction_gap() {
+        let mut log = ChangeLog::new(2);
+        for id in ["a", "b", "c", "d"] {
+            log.append(ChangeKind::Created, id);
+        }
+
+        assert!(matches!(log.since(1), Err(RepositoryError::NotFound(_))));
+        let seqs: Vec<u64> = log.since(2).unwrap().iter().map(|r| r.seq).collect();
+        assert_eq!(seqs, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_since_rejects_cursor_ahead_of_log() {
+        let mut log = ChangeLog::new(2);
+        log.append(ChangeKind::Created, "a");
+
+        assert!(matches!(log.since(5), Err(RepositoryError::InvalidData(_))));
+    }
+}
@@ -0,0 +1,1090 @@
+//! Composite view over several relayer repositories.
+//!
+//! [`CompositeRelayerRepository`] wraps an ordered list of [`RelayerRepository`]
+//! backends so that two stores can be served as one while relayers are migrated
+//! between them. Reads see the union of all backends, deduplicated by id: when a
+//! relayer exists in several backends, the copy in the earliest backend wins.
+//! Lookups by id are answered by that backend. The first backend is the primary and
+//! takes every write, so reads of a relayer it holds always see what was written; a
+//! write to a relayer that only exists elsewhere fails with `NotFound`, and creating a
+//! relayer whose id any other backend holds fails with `ConstraintViolation`. The change
+//! log, maintenance window and idempotency keys are those of the primary.
+
+use crate::models::{
+    AlertTransition, ChangeRecord, DeleteReport, DisabledReason, EnforceReport, HealthCheckFailure,
+    LogLevel, NetworkType, PaginationQuery, ProvisionMode, ProvisionReport, ProvisionRowError,
+    RelayerConfigVersionInfo, RelayerDescriptor, RelayerFilter, RelayerLastError,
+    RelayerNetworkPolicy, RelayerOperationalStatus, RelayerRepoModel, RelayerTemplate,
+    RelayerValidationError, RepositoryError, StatusChange, UpdateRelayerRequest,
+};
+use crate::repositories::{
+    match_addresses, provision_rejected, select_sticky, ConfigReadGuard, PaginatedResult,
+    PolicyPredicate, ReadToken, RelayerRepository, RelayerSnapshot, Repository, SlotGuard,
+    StickyStrategy,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct CompositeRelayerRepository<R> {
+    backends: Vec<R>,
+}
+
+impl<R> CompositeRelayerRepository<R> {
+    /// Wraps `backends`, earliest first, routing writes to the first
+    pub fn new(backends: Vec<R>) -> Result<Self, RepositoryError> {
+        if backends.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Composite repository needs at least one backend".to_string(),
+            ));
+        }
+        Ok(Self { backends })
+    }
+
+    pub fn backends(&self) -> &[R] {
+        &self.backends
+    }
+
+    /// The backend every write is routed to, which also wins every read of its relayers
+    pub fn primary(&self) -> &R {
+        &self.backends[0]
+    }
+}
+
+/// Items that merged reads deduplicate by relayer id
+trait RelayerKeyed {
+    fn relayer_id(&self) -> &str;
+}
+
+impl RelayerKeyed for RelayerRepoModel {
+    fn relayer_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl RelayerKeyed for (String, Vec<RelayerValidationError>) {
+    fn relayer_id(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<R: RelayerRepository> CompositeRelayerRepository<R> {
+    /// Returns the earliest backend holding `id`, whose copy of the relayer reads see
+    async fn owner(&self, id: &str) -> Result<&R, RepositoryError> {
+        for backend in &self.backends {
+            if !backend.existing_ids(vec![id.to_string()]).await?.is_empty() {
+                return Ok(backend);
+            }
+        }
+        Err(RepositoryError::NotFound(format!(
+            "Relayer with ID {id} not found"
+        )))
+    }
+
+    /// Returns those of `ids` held by a backend other than the primary, which checks
+    /// its own ids on create
+    async fn held_outside_primary(
+        &self,
+        ids: Vec<String>,
+    ) -> Result<HashSet<String>, RepositoryError> {
+        let mut held = HashSet::new();
+        for backend in &self.backends[1..] {
+            held.extend(backend.existing_ids(ids.clone()).await?);
+        }
+        Ok(held)
+    }
+
+    /// Fails with `ConstraintViolation` if a backend other than the primary holds `id`,
+    /// since the new relayer would be shadowed by, or duplicate, that copy in reads
+    async fn ensure_id_available(&self, id: &str) -> Result<(), RepositoryError> {
+        if self
+            .held_outside_primary(vec![id.to_string()])
+            .await?
+            .is_empty()
+        {
+            Ok(())
+        } else {
+            Err(RepositoryError::ConstraintViolation(format!(
+                "Relayer with ID {id} already exists in another backend"
+            )))
+        }
+    }
+
+    /// Runs `read` against every backend and merges the results, sorted by id.
+    ///
+    /// An item is dropped if an earlier backend holds a relayer with the same id, even
+    /// when that relayer was not part of the earlier backend's result, so a filtered
+    /// read never surfaces a copy the merged view hides.
+    async fn merge_reads<'a, T, F, Fut>(&'a self, read: F) -> Result<Vec<T>, RepositoryError>
+    where
+        T: RelayerKeyed,
+        F: Fn(&'a R) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, RepositoryError>>,
+    {
+        let mut merged: Vec<T> = Vec::new();
+        for (index, backend) in self.backends.iter().enumerate() {
+            let mut items = read(backend).await?;
+            if index > 0 && !items.is_empty() {
+                let ids: Vec<String> = items
+                    .iter()
+                    .map(|item| item.relayer_id().to_string())
+                    .collect();
+                let mut shadowed = HashSet::new();
+                for earlier in &self.backends[..index] {
+                    shadowed.extend(earlier.existing_ids(ids.clone()).await?);
+                }
+                items.retain(|item| !shadowed.contains(item.relayer_id()));
+            }
+            merged.extend(items);
+        }
+        merged.sort_by(|a, b| a.relayer_id().cmp(b.relayer_id()));
+        Ok(merged)
+    }
+}
+
+/// Returns the requested page of `relayers`, which must already be in a stable order
+fn paginate(
+    relayers: Vec<RelayerRepoModel>,
+    query: &PaginationQuery,
+) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+    let per_page = query.resolved_per_page();
+    if query.page == 0 {
+        return Err(RepositoryError::InvalidData(
+            "Page number must be greater than 0".to_string(),
+        ));
+    }
+    if per_page == 0 {
+        return Err(RepositoryError::InvalidData(
+            "Per page count must be greater than 0".to_string(),
+        ));
+    }
+
+    let total = relayers.len() as u64;
+    let start = ((query.page - 1) * per_page) as usize;
+    let items = relayers
+        .into_iter()
+        .skip(start)
+        .take(per_page as usize)
+        .collect();
+    Ok(PaginatedResult {
+        items,
+        total,
+        page: query.page,
+        per_page,
+    })
+}
+
+#[async_trait]
+impl<R: RelayerRepository> Repository<RelayerRepoModel, String> for CompositeRelayerRepository<R> {
+    async fn init(&self) -> Result<(), RepositoryError> {
+        for backend in &self.backends {
+            backend.init().await?;
+        }
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        for backend in &self.backends {
+            backend.ping().await?;
+        }
+        Ok(())
+    }
+
+    async fn create(
+        &self,
+        mut entity: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        entity.normalize_id();
+        self.ensure_id_available(&entity.id).await?;
+        self.primary().create(entity).await
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.owner(&id).await?.get_by_id(id).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_all()).await
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        paginate(self.list_all().await?, &query)
+    }
+
+    async fn update(
+        &self,
+        id: String,
+        entity: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().update(id, entity).await
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        self.primary().delete_by_id(id).await
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        Ok(self.list_all().await?.len())
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        for backend in &self.backends {
+            if backend.has_entries().await? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        self.primary().drop_all_entries().await
+    }
+}
+
+#[async_trait]
+impl<R: RelayerRepository> RelayerRepository for CompositeRelayerRepository<R> {
+    async fn list_active(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_active()).await
+    }
+
+    async fn list_by_signer_id(
+        &self,
+        signer_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_by_signer_id(signer_id))
+            .await
+    }
+
+    async fn list_by_notification_id(
+        &self,
+        notification_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_by_notification_id(notification_id))
+            .await
+    }
+
+    async fn partial_update(
+        &self,
+        id: String,
+        update: UpdateRelayerRequest,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().partial_update(id, update).await
+    }
+
+    async fn enable_relayer(
+        &self,
+        relayer_id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().enable_relayer(relayer_id).await
+    }
+
+    async fn disable_relayer(
+        &self,
+        relayer_id: String,
+        reason: DisabledReason,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().disable_relayer(relayer_id, reason).await
+    }
+
+    async fn update_policy(
+        &self,
+        id: String,
+        policy: RelayerNetworkPolicy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().update_policy(id, policy).await
+    }
+
+    async fn create_idempotent(
+        &self,
+        mut relayer: RelayerRepoModel,
+        idempotency_key: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        relayer.normalize_id();
+        self.ensure_id_available(&relayer.id).await?;
+        self.primary()
+            .create_idempotent(relayer, idempotency_key)
+            .await
+    }
+
+    async fn quarantine(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().quarantine(id, until, reason).await
+    }
+
+    async fn list_expired_quarantines(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_expired_quarantines(now))
+            .await
+    }
+
+    async fn instantiate(
+        &self,
+        template: &RelayerTemplate,
+        id: String,
+        signer_id: String,
+        address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_id_available(&id).await?;
+        self.primary()
+            .instantiate(template, id, signer_id, address)
+            .await
+    }
+
+    async fn evaluate_balance_alert(
+        &self,
+        id: String,
+        balance: u128,
+    ) -> Result<Option<AlertTransition>, RepositoryError> {
+        self.primary().evaluate_balance_alert(id, balance).await
+    }
+
+    async fn get_by_id_scoped(
+        &self,
+        id: String,
+        tenant_id: &str,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.owner(&id).await?.get_by_id_scoped(id, tenant_id).await
+    }
+
+    async fn list_by_tenant(
+        &self,
+        tenant_id: &str,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        let relayers = self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| relayer.tenant_id == tenant_id)
+            .collect();
+        paginate(relayers, &query)
+    }
+
+    async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError> {
+        Ok(RelayerSnapshot::from(self.list_all().await?))
+    }
+
+    async fn schedule_disable(
+        &self,
+        id: String,
+        at: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().schedule_disable(id, at, reason).await
+    }
+
+    async fn list_due_scheduled_disables(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_due_scheduled_disables(now))
+            .await
+    }
+
+    async fn cancel_scheduled_disable(
+        &self,
+        id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().cancel_scheduled_disable(id).await
+    }
+
+    async fn find_drifted(
+        &self,
+        desired: &[RelayerRepoModel],
+    ) -> Result<Vec<String>, RepositoryError> {
+        let snapshot = self.snapshot().await?;
+        Ok(desired
+            .iter()
+            .filter(|wanted| {
+                snapshot
+                    .get(&wanted.id)
+                    .map(|stored| stored.config_checksum())
+                    != Some(wanted.config_checksum())
+            })
+            .map(|wanted| wanted.id.clone())
+            .collect())
+    }
+
+    async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError> {
+        self.owner(&id).await?.get_status_history(id).await
+    }
+
+    async fn validate_all(
+        &self,
+    ) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError> {
+        self.merge_reads(|backend| backend.validate_all()).await
+    }
+
+    async fn pick_sticky(
+        &self,
+        pool: String,
+        session_key: &str,
+        strategy: StickyStrategy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let members = self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| relayer.pool.as_deref() == Some(pool.as_str()))
+            .collect();
+        select_sticky(members, session_key, strategy).ok_or_else(|| {
+            RepositoryError::NotFound(format!("No operational relayer found in pool {pool}"))
+        })
+    }
+
+    async fn rekey(
+        &self,
+        id: String,
+        new_signer_id: String,
+        new_address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().rekey(id, new_signer_id, new_address).await
+    }
+
+    async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError> {
+        let mut existing = HashSet::new();
+        for backend in &self.backends {
+            existing.extend(backend.existing_ids(ids.clone()).await?);
+        }
+        Ok(existing)
+    }
+
+    async fn compact(&self) -> Result<(), RepositoryError> {
+        for backend in &self.backends {
+            backend.compact().await?;
+        }
+        Ok(())
+    }
+
+    async fn trip_circuit(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().trip_circuit(id, until).await
+    }
+
+    async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().reset_circuit(id).await
+    }
+
+    async fn record_outcome(
+        &self,
+        id: String,
+        success: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().record_outcome(id, success).await
+    }
+
+    async fn list_where_policy(
+        &self,
+        predicate: PolicyPredicate,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        let relayers = self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| predicate(&relayer.policies))
+            .collect();
+        paginate(relayers, &query)
+    }
+
+    async fn provision_from_descriptors(
+        &self,
+        descriptors: Vec<RelayerDescriptor>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionReport, RepositoryError> {
+        let ids = descriptors
+            .iter()
+            .map(|descriptor| descriptor.id.clone())
+            .collect();
+        let held = self.held_outside_primary(ids).await?;
+        if held.is_empty() {
+            return self
+                .primary()
+                .provision_from_descriptors(descriptors, mode)
+                .await;
+        }
+
+        // Validate against the primary without creating anything, then add the rows
+        // whose id another backend holds
+        let mut report = self
+            .primary()
+            .provision_from_descriptors(descriptors.clone(), ProvisionMode::DryRun)
+            .await?;
+        report.created.retain(|id| !held.contains(id));
+        for (row, descriptor) in descriptors.iter().enumerate() {
+            if !held.contains(&descriptor.id) {
+                continue;
+            }
+            let error = RelayerValidationError::InvalidField(format!(
+                "Relayer with ID {} already exists in another backend",
+                descriptor.id
+            ));
+            match report
+                .errors
+                .iter_mut()
+                .find(|existing| existing.row == row)
+            {
+                Some(existing) => existing.errors.push(error),
+                None => report.errors.push(ProvisionRowError {
+                    row,
+                    id: descriptor.id.clone(),
+                    errors: vec![error],
+                }),
+            }
+        }
+        report.errors.sort_by_key(|row_error| row_error.row);
+
+        if mode == ProvisionMode::Strict {
+            return Err(provision_rejected(&report.errors, descriptors.len()));
+        }
+        Ok(report)
+    }
+
+    async fn record_error(
+        &self,
+        id: String,
+        error: RelayerLastError,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().record_error(id, error).await
+    }
+
+    async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().clear_error(id).await
+    }
+
+    async fn list_with_recent_errors(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_with_recent_errors(since))
+            .await
+    }
+
+    async fn list_config_versions(
+        &self,
+        id: String,
+    ) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError> {
+        self.owner(&id).await?.list_config_versions(id).await
+    }
+
+    async fn rollback_to(
+        &self,
+        id: String,
+        version: u32,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().rollback_to(id, version).await
+    }
+
+    async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError> {
+        for backend in &self.backends {
+            match backend.get_by_alias(alias.clone()).await {
+                // Resolve through the merged view so a shadowing backend's copy wins
+                Ok(relayer) => return self.get_by_id(relayer.id).await,
+                Err(RepositoryError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(RepositoryError::NotFound(format!(
+            "Relayer with alias {alias} not found"
+        )))
+    }
+
+    async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError> {
+        self.primary().changes_since(seq).await
+    }
+
+    async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError> {
+        self.owner(&id).await?.effective_policy(id).await
+    }
+
+    async fn get_standby(
+        &self,
+        primary_id: String,
+    ) -> Result<Option<RelayerRepoModel>, RepositoryError> {
+        self.owner(&primary_id).await?.get_standby(primary_id).await
+    }
+
+    async fn list_due_health_checks(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_due_health_checks(now))
+            .await
+    }
+
+    async fn record_health_check(
+        &self,
+        id: String,
+        result: Result<(), Vec<HealthCheckFailure>>,
+        at: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().record_health_check(id, result, at).await
+    }
+
+    async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().sunset(id).await
+    }
+
+    async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().unsunset(id).await
+    }
+
+    async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.owner(&id).await?.get_by_id_effective(id).await
+    }
+
+    async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError> {
+        self.primary().delete_many(ids).await
+    }
+
+    async fn list_by_network_type(
+        &self,
+        network_type: NetworkType,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        let relayers = self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| relayer.network_type == network_type)
+            .collect();
+        paginate(relayers, &query)
+    }
+
+    async fn pause_if_version(
+        &self,
+        id: String,
+        expected_version: u64,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().pause_if_version(id, expected_version).await
+    }
+
+    async fn group_by_network_type(
+        &self,
+    ) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError> {
+        Ok(self.snapshot().await?.group_operational_by_network_type())
+    }
+
+    async fn validate_create(
+        &self,
+        mut relayer: RelayerRepoModel,
+    ) -> Result<(), Vec<RepositoryError>> {
+        relayer.normalize_id();
+        let held = self.ensure_id_available(&relayer.id).await;
+        let validated = self.primary().validate_create(relayer).await;
+        match (held, validated) {
+            (Ok(()), validated) => validated,
+            (Err(error), Ok(())) => Err(vec![error]),
+            (Err(error), Err(mut errors)) => {
+                errors.push(error);
+                Err(errors)
+            }
+        }
+    }
+
+    async fn list_by_status(
+        &self,
+        status: RelayerOperationalStatus,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        let now = Utc::now();
+        let relayers = self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| relayer.status_at(now) == status)
+            .collect();
+        paginate(relayers, &query)
+    }
+
+    async fn add_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.primary().add_tag_where(filter, tag).await
+    }
+
+    async fn remove_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.primary().remove_tag_where(filter, tag).await
+    }
+
+    async fn set_metadata(
+        &self,
+        id: String,
+        metadata: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().set_metadata(id, metadata).await
+    }
+
+    async fn merge_metadata(
+        &self,
+        id: String,
+        patch: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().merge_metadata(id, patch).await
+    }
+
+    async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError> {
+        self.owner(&id).await?.try_acquire_slot(id).await
+    }
+
+    async fn record_rpc_health(
+        &self,
+        id: String,
+        url: String,
+        healthy: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().record_rpc_health(id, url, healthy).await
+    }
+
+    async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError> {
+        self.primary().enter_maintenance(until).await
+    }
+
+    async fn exit_maintenance(&self) -> Result<(), RepositoryError> {
+        self.primary().exit_maintenance().await
+    }
+
+    async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        self.primary().maintenance_until().await
+    }
+
+    async fn list_active_by_network_type(
+        &self,
+        network_type: NetworkType,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_active_by_network_type(network_type))
+            .await
+    }
+
+    async fn list_by_nonce_group(
+        &self,
+        group: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_by_nonce_group(group))
+            .await
+    }
+
+    async fn list_high_error_rate(
+        &self,
+        window: Duration,
+        threshold: f64,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.merge_reads(|backend| backend.list_high_error_rate(window, threshold))
+            .await
+    }
+
+    async fn enforce_allowlist(
+        &self,
+        allowed: HashSet<String>,
+    ) -> Result<EnforceReport, RepositoryError> {
+        self.primary().enforce_allowlist(allowed).await
+    }
+
+    async fn set_log_level(
+        &self,
+        id: String,
+        level: Option<LogLevel>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().set_log_level(id, level).await
+    }
+
+    async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError> {
+        let seq = self.primary().begin_consistent_read().await?.seq();
+        Ok(ReadToken::new(seq, self.snapshot().await?))
+    }
+
+    async fn get_many_by_address(
+        &self,
+        addresses: Vec<String>,
+        network_type: NetworkType,
+    ) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError> {
+        let wanted: HashSet<String> = addresses
+            .iter()
+            .map(|address| network_type.normalize_address(address))
+            .collect();
+        let relayers = self.list_all().await?;
+        Ok(match_addresses(
+            relayers.iter().filter(|r| r.network_type == network_type),
+            &wanted,
+        ))
+    }
+
+    async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError> {
+        self.owner(&id).await?.acquire_config_read(id).await
+    }
+
+    async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        self.primary().increment_pending(id).await
+    }
+
+    async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        self.primary().decrement_pending(id).await
+    }
+
+    async fn pending_count(&self, id: String) -> Result<u64, RepositoryError> {
+        self.owner(&id).await?.pending_count(id).await
+    }
+
+    async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError> {
+        self.primary().record_spend(id, amount).await
+    }
+
+    async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary().promote_to_live(id).await
+    }
+
+    async fn register_submission(
+        &self,
+        id: String,
+        tx_hash_key: String,
+        ttl: Duration,
+    ) -> Result<bool, RepositoryError> {
+        self.owner(&id)
+            .await?
+            .register_submission(id, tx_hash_key, ttl)
+            .await
+    }
+
+    async fn transfer_ownership(
+        &self,
+        id: String,
+        new_tenant: String,
+        actor: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.primary()
+            .transfer_ownership(id, new_tenant, actor)
+            .await
+    }
+
+    fn is_persistent_storage(&self) -> bool {
+        self.primary().is_persistent_storage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::InMemoryRelayerRepository;
+
+    fn create_test_relayer(id: &str, name: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: id.to_string(),
+            name: name.to_string(),
+            network: "TestNet".to_string(),
+            signer_id: "test".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// New and old backends sharing the `shared` relayer, the new one first as primary
+    async fn create_composite_repo() -> CompositeRelayerRepository<InMemoryRelayerRepository> {
+        let old = InMemoryRelayerRepository::new();
+        old.create(create_test_relayer("shared", "Old Shared"))
+            .await
+            .unwrap();
+        old.create(create_test_relayer("old-only", "Old Only"))
+            .await
+            .unwrap();
+
+        let new = InMemoryRelayerRepository::new();
+        new.create(create_test_relayer("shared", "New Shared"))
+            .await
+            .unwrap();
+        new.create(create_test_relayer("new-only", "New Only"))
+            .await
+            .unwrap();
+
+        CompositeRelayerRepository::new(vec![new, old]).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn test_reads_prefer_earlier_backend() {
+        let repo = create_composite_repo().await;
+
+        assert_eq!(
+            repo.get_by_id("shared".to_string()).await.unwrap().name,
+            "New Shared"
+        );
+        assert_eq!(
+            repo.get_by_id("old-only".to_string()).await.unwrap().name,
+            "Old Only"
+        );
+        assert!(matches!(
+            repo.get_by_id("missing".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+
+        let ids: Vec<String> = repo
+            .list_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|relayer| relayer.id)
+            .collect();
+        assert_eq!(ids, vec!["new-only", "old-only", "shared"]);
+        assert_eq!(repo.count().await.unwrap(), 3);
+        assert_eq!(repo.snapshot().await.unwrap().len(), 3);
+    }
+
+    #[actix_web::test]
+    async fn test_list_paginated_merges_backends() {
+        let repo = create_composite_repo().await;
+        let page = |page| PaginationQuery {
+            page,
+            per_page: Some(2),
+        };
+
+        let first = repo.list_paginated(page(1)).await.unwrap();
+        assert_eq!(first.total, 3);
+        assert_eq!(first.per_page, 2);
+        let ids: Vec<&str> = first.items.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["new-only", "old-only"]);
+
+        let second = repo.list_paginated(page(2)).await.unwrap();
+        assert_eq!(second.total, 3);
+        assert_eq!(second.items.len(), 1);
+        assert_eq!(second.items[0].id, "shared");
+        assert_eq!(second.items[0].name, "New Shared");
+
+        let past_end = repo.list_paginated(page(3)).await.unwrap();
+        assert_eq!(past_end.total, 3);
+        assert!(past_end.items.is_empty());
+
+        assert!(matches!(
+            repo.list_paginated(page(0)).await,
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_filtered_reads_respect_shadowing() {
+        let repo = create_composite_repo().await;
+        // The primary's copy is paused, so the active copy in the old backend is hidden
+        let mut shared = repo.backends()[0]
+            .get_by_id("shared".to_string())
+            .await
+            .unwrap();
+        shared.paused = true;
+        repo.backends()[0]
+            .update("shared".to_string(), shared)
+            .await
+            .unwrap();
+
+        let active: Vec<String> = repo
+            .list_active()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|relayer| relayer.id)
+            .collect();
+        assert_eq!(active, vec!["new-only", "old-only"]);
+    }
+
+    #[actix_web::test]
+    async fn test_writes_route_to_primary() {
+        let repo = create_composite_repo().await;
+
+        repo.create(create_test_relayer("created", "Created"))
+            .await
+            .unwrap();
+        assert!(repo.backends()[0]
+            .get_by_id("created".to_string())
+            .await
+            .is_ok());
+        assert!(repo.backends()[1]
+            .get_by_id("created".to_string())
+            .await
+            .is_err());
+        assert_eq!(repo.count().await.unwrap(), 4);
+
+        // Relayers only held by a non-primary backend cannot be written
+        assert!(matches!(
+            repo.update(
+                "old-only".to_string(),
+                create_test_relayer("old-only", "Renamed")
+            )
+            .await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_create_rejects_id_held_by_secondary() {
+        let repo = create_composite_repo().await;
+
+        assert!(matches!(
+            repo.create(create_test_relayer("old-only", "Duplicate"))
+                .await,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert!(matches!(
+            repo.create_idempotent(
+                create_test_relayer("old-only", "Duplicate"),
+                "key-1".to_string()
+            )
+            .await,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert!(repo
+            .validate_create(create_test_relayer("old-only", "Duplicate"))
+            .await
+            .is_err());
+
+        // Nothing reached the primary, and reads still see the original
+        assert!(repo.backends()[0]
+            .get_by_id("old-only".to_string())
+            .await
+            .is_err());
+        assert_eq!(
+            repo.get_by_id("old-only".to_string()).await.unwrap().name,
+            "Old Only"
+        );
+        assert_eq!(repo.count().await.unwrap(), 3);
+    }
+
+    #[actix_web::test]
+    async fn test_writes_are_visible_to_reads() {
+        let repo = create_composite_repo().await;
+
+        // The shared relayer is written where it is read from
+        let mut shared = repo.get_by_id("shared".to_string()).await.unwrap();
+        shared.name = "Renamed".to_string();
+        repo.update("shared".to_string(), shared).await.unwrap();
+        assert_eq!(
+            repo.get_by_id("shared".to_string()).await.unwrap().name,
+            "Renamed"
+        );
+
+        repo.sunset("shared".to_string()).await.unwrap();
+        assert!(repo.get_by_id("shared".to_string()).await.unwrap().sunset);
+    }
+
+    #[test]
+    fn test_new_rejects_no_backends() {
+        assert!(matches!(
+            CompositeRelayerRepository::<InMemoryRelayerRepository>::new(vec![]),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+}
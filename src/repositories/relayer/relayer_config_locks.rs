@@ -0,0 +1,134 @@
+//! Per-relayer configuration read locks.
+//!
+//! A [`ConfigReadGuard`] pins a relayer's configuration while a transaction is
+//! prepared. `update` and `update_policy` on that relayer wait for outstanding
+//! guards to be released, and give up with `Conflict` once the write timeout
+//! elapses. Locks are held by the current process only: a guard pins the
+//! relayer against writers in the same process, not against other instances
+//! sharing a Redis backend. Those writes cannot interleave with this process's
+//! writes either way, since the Redis backend stores every relayer update with a
+//! compare-and-set against the record it replaces.
+
+use crate::models::{RelayerRepoModel, RepositoryError};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock};
+
+/// Consistent view of a relayer's configuration, held while a transaction is
+/// prepared. Configuration writes to the relayer wait until it is dropped.
+#[derive(Debug)]
+pub struct ConfigReadGuard {
+    relayer: RelayerRepoModel,
+    _lock: OwnedRwLockReadGuard<()>,
+}
+
+impl ConfigReadGuard {
+    /// The relayer as it was when the guard was acquired
+    pub fn relayer(&self) -> &RelayerRepoModel {
+        &self.relayer
+    }
+}
+
+/// Exclusive hold on a relayer's configuration for the duration of a write
+#[derive(Debug)]
+pub struct ConfigWriteGuard {
+    _lock: OwnedRwLockWriteGuard<()>,
+}
+
+/// Read/write locks over the configuration of each relayer
+#[derive(Debug)]
+pub struct RelayerConfigLocks {
+    locks: Mutex<HashMap<String, Arc<RwLock<()>>>>,
+    write_timeout: Duration,
+}
+
+impl RelayerConfigLocks {
+    pub fn new(write_timeout: Duration) -> Self {
+        Self {
+            locks: Mutex::new(HashMap::new()),
+            write_timeout,
+        }
+    }
+
+    /// How long `write` waits for read guards to be released
+    pub fn write_timeout(&self) -> Duration {
+        self.write_timeout
+    }
+
+    async fn lock_for(&self, id: &str) -> Arc<RwLock<()>> {
+        self.locks
+            .lock()
+            .await
+            .entry(id.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Takes a read lock on the configuration of `id`, then loads the relayer with
+    /// `load` so the returned view cannot change while the guard is held
+    pub async fn read<F, Fut>(&self, id: &str, load: F) -> Result<ConfigReadGuard, RepositoryError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<RelayerRepoModel, RepositoryError>>,
+    {
+        let lock = self.lock_for(id).await.read_owned().await;
+        let relayer = load().await?;
+        Ok(ConfigReadGuard {
+            relayer,
+            _lock: lock,
+        })
+    }
+
+    /// Takes the write lock on the configuration of `id`, failing with `Conflict` if
+    /// read guards are still held when the write timeout elapses
+    pub async fn write(&self, id: &str) -> Result<ConfigWriteGuard, RepositoryError> {
+        let lock = self.lock_for(id).await;
+        match tokio::time::timeout(self.write_timeout, lock.write_owned()).await {
+            Ok(lock) => Ok(ConfigWriteGuard { _lock: lock }),
+            Err(_) => Err(RepositoryError::Conflict(format!(
+                "Configuration of relayer {id} is still pinned by a transaction after {:?}",
+                self.write_timeout
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(
+        id: &str,
+    ) -> impl std::future::Future<Output = Result<RelayerRepoModel, RepositoryError>> {
+        let relayer = RelayerRepoModel {
+            id: id.to_string(),
+            ..Default::default()
+        };
+        async move { Ok(relayer) }
+    }
+
+    #[tokio::test]
+    async fn test_write_times_out_while_read_guard_held() {
+        let locks = RelayerConfigLocks::new(Duration::from_millis(20));
+        let guard = locks.read("relayer-1", || load("relayer-1")).await.unwrap();
+        assert_eq!(guard.relayer().id, "relayer-1");
+
+        assert!(matches!(
+            locks.write("relayer-1").await,
+            Err(RepositoryError::Conflict(_))
+        ));
+        // Other relayers are unaffected
+        assert!(locks.write("relayer-2").await.is_ok());
+
+        drop(guard);
+        assert!(locks.write("relayer-1").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_read_guards_share_the_lock() {
+        let locks = RelayerConfigLocks::new(Duration::from_millis(20));
+        let _first = locks.read("relayer-1", || load("relayer-1")).await.unwrap();
+        let _second = locks.read("relayer-1", || load("relayer-1")).await.unwrap();
+    }
+}
@@ -0,0 +1,46 @@
+//! Short-lived deduplication of transaction submissions.
+//!
+//! Submission keys, typically derived from sender, nonce and calldata, are
+//! registered per relayer and expire after a TTL. A key registered while a
+//! previous registration is still live marks the submission as a duplicate, so
+//! the pipeline can collapse it instead of submitting the transaction twice.
+
+use crate::models::RepositoryError;
+use std::time::Duration;
+
+/// Checks that a submission key is not empty and is kept for a nonzero time
+pub fn validate_submission(tx_hash_key: &str, ttl: Duration) -> Result<(), RepositoryError> {
+    if tx_hash_key.is_empty() {
+        return Err(RepositoryError::InvalidData(
+            "Submission key cannot be empty".to_string(),
+        ));
+    }
+    if ttl.is_zero() {
+        return Err(RepositoryError::InvalidData(
+            "Submission TTL must be greater than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_submission_accepts_key_with_ttl() {
+        assert!(validate_submission("0xabc:7", Duration::from_secs(30)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_submission_rejects_empty_key_and_zero_ttl() {
+        assert!(matches!(
+            validate_submission("", Duration::from_secs(30)),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(matches!(
+            validate_submission("0xabc:7", Duration::ZERO),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+}
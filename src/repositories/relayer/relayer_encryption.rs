@@ -0,0 +1,252 @@
+//! Encryption at rest for relayer records written by persistent backends.
+//!
+//! A [`RelayerRecordCodec`] turns a relayer into the JSON stored by the backend and
+//! back, encrypting either the sensitive fields or the whole record with an injected
+//! [`EncryptionProvider`]. Encrypted values are stored as `{"encrypted": "<base64>"}`
+//! envelopes, so records written before encryption was enabled, or with a different
+//! scope, still decode.
+
+use crate::models::{RelayerRepoModel, RepositoryError};
+use crate::utils::{base64_decode, base64_encode};
+use serde_json::{json, Value};
+use std::fmt;
+use std::sync::Arc;
+
+/// Envelope key holding base64 ciphertext
+const ENCRYPTED_KEY: &str = "encrypted";
+
+/// Relayer fields that may carry credentials, such as API keys embedded in RPC URLs
+/// or webhook tokens
+pub const SENSITIVE_RELAYER_FIELDS: &[&str] = &["custom_rpc_urls", "extra_webhook_urls"];
+
+/// Symmetric encryption applied to stored relayer data
+pub trait EncryptionProvider: Send + Sync + fmt::Debug {
+    fn encrypt(&self, plaintext: &[u8]) -> Vec<u8>;
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, RepositoryError>;
+}
+
+/// Provider storing data as is
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopEncryption;
+
+impl EncryptionProvider for NoopEncryption {
+    fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        plaintext.to_vec()
+    }
+
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, RepositoryError> {
+        Ok(ciphertext.to_vec())
+    }
+}
+
+/// Which parts of a relayer record are encrypted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EncryptionScope {
+    /// Only [`SENSITIVE_RELAYER_FIELDS`], leaving the rest readable for debugging
+    #[default]
+    SensitiveFields,
+    /// The whole serialized record
+    WholeRecord,
+}
+
+/// Encodes relayers for storage. The default codec does not encrypt anything.
+#[derive(Debug, Clone, Default)]
+pub struct RelayerRecordCodec {
+    provider: Option<Arc<dyn EncryptionProvider>>,
+    scope: EncryptionScope,
+}
+
+impl RelayerRecordCodec {
+    pub fn new(provider: Arc<dyn EncryptionProvider>, scope: EncryptionScope) -> Self {
+        Self {
+            provider: Some(provider),
+            scope,
+        }
+    }
+
+    pub fn scope(&self) -> EncryptionScope {
+        self.scope
+    }
+
+    /// Serializes the relayer, encrypting according to the codec's scope
+    pub fn encode(&self, relayer: &RelayerRepoModel) -> Result<String, RepositoryError> {
+        let serialize_error = |e: serde_json::Error| {
+            RepositoryError::InvalidData(format!("Failed to serialize relayer {}: {e}", relayer.id))
+        };
+        let Some(provider) = &self.provider else {
+            return serde_json::to_string(relayer).map_err(serialize_error);
+        };
+        let sealed = match self.scope {
+            EncryptionScope::WholeRecord => seal(
+                provider.as_ref(),
+                &serde_json::to_vec(relayer).map_err(serialize_error)?,
+            ),
+            EncryptionScope::SensitiveFields => {
+                let mut value = serde_json::to_value(relayer).map_err(serialize_error)?;
+                if let Some(record) = value.as_object_mut() {
+                    for field in SENSITIVE_RELAYER_FIELDS {
+                        if let Some(plain) = record.get(*field).filter(|v| !v.is_null()) {
+                            let bytes = serde_json::to_vec(plain).map_err(serialize_error)?;
+                            record.insert((*field).to_string(), seal(provider.as_ref(), &bytes));
+                        }
+                    }
+                }
+                value
+            }
+        };
+        serde_json::to_string(&sealed).map_err(serialize_error)
+    }
+
+    /// Deserializes a stored relayer, decrypting any encrypted envelope it contains
+    pub fn decode(&self, stored: &str, id: &str) -> Result<RelayerRepoModel, RepositoryError> {
+        let deserialize_error = |e: serde_json::Error| {
+            RepositoryError::InvalidData(format!("Failed to deserialize relayer {id}: {e}"))
+        };
+        let mut value: Value = serde_json::from_str(stored).map_err(deserialize_error)?;
+        if is_sealed(&value) {
+            let bytes = self.unseal(&value, id)?;
+            value = serde_json::from_slice(&bytes).map_err(deserialize_error)?;
+        } else if let Some(record) = value.as_object_mut() {
+            for field in SENSITIVE_RELAYER_FIELDS {
+                if let Some(sealed) = record.get(*field).filter(|v| is_sealed(v)) {
+                    let bytes = self.unseal(sealed, id)?;
+                    let plain = serde_json::from_slice(&bytes).map_err(deserialize_error)?;
+                    record.insert((*field).to_string(), plain);
+                }
+            }
+        }
+        serde_json::from_value(value).map_err(deserialize_error)
+    }
+
+    fn unseal(&self, sealed: &Value, id: &str) -> Result<Vec<u8>, RepositoryError> {
+        let provider = self.provider.as_ref().ok_or_else(|| {
+            RepositoryError::InvalidData(format!(
+                "Relayer {id} is stored encrypted but no encryption provider is configured"
+            ))
+        })?;
+        let ciphertext = sealed[ENCRYPTED_KEY]
+            .as_str()
+            .map(base64_decode)
+            .transpose()
+            .map_err(|e| {
+                RepositoryError::InvalidData(format!("Invalid ciphertext for relayer {id}: {e}"))
+            })?
+            .unwrap_or_default();
+        provider.decrypt(&ciphertext)
+    }
+}
+
+fn seal(provider: &dyn EncryptionProvider, plaintext: &[u8]) -> Value {
+    json!({ ENCRYPTED_KEY: base64_encode(&provider.encrypt(plaintext)) })
+}
+
+fn is_sealed(value: &Value) -> bool {
+    value.as_object().is_some_and(|object| {
+        object.len() == 1 && object.get(ENCRYPTED_KEY).is_some_and(Value::is_string)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RpcConfig;
+
+    /// Reversible provider XOR-ing every byte with a fixed key
+    #[derive(Debug)]
+    struct XorEncryption(u8);
+
+    impl EncryptionProvider for XorEncryption {
+        fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+            plaintext.iter().map(|b| b ^ self.0).collect()
+        }
+
+        fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, RepositoryError> {
+            Ok(self.encrypt(ciphertext))
+        }
+    }
+
+    const RPC_URL: &str = "https://rpc.example.com/v1/secret-api-key";
+
+    fn create_test_relayer() -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: "encrypted".to_string(),
+            name: "Encrypted".to_string(),
+            network: "sepolia".to_string(),
+            signer_id: "signer".to_string(),
+            address: "0x742d35cc6634c0532925a3b8d8c2e48a73f6ba2e".to_string(),
+            custom_rpc_urls: Some(vec![RpcConfig::new(RPC_URL.to_string())]),
+            ..Default::default()
+        }
+    }
+
+    fn assert_same_relayer(actual: &RelayerRepoModel, expected: &RelayerRepoModel) {
+        assert_eq!(
+            serde_json::to_value(actual).unwrap(),
+            serde_json::to_value(expected).unwrap()
+        );
+    }
+
+    fn codec(scope: EncryptionScope) -> RelayerRecordCodec {
+        RelayerRecordCodec::new(Arc::new(XorEncryption(0x5a)), scope)
+    }
+
+    #[test]
+    fn test_default_codec_stores_plain_json() {
+        let relayer = create_test_relayer();
+        let stored = RelayerRecordCodec::default().encode(&relayer).unwrap();
+        assert_eq!(stored, serde_json::to_string(&relayer).unwrap());
+        let decoded = RelayerRecordCodec::default()
+            .decode(&stored, &relayer.id)
+            .unwrap();
+        assert_same_relayer(&decoded, &relayer);
+    }
+
+    #[test]
+    fn test_whole_record_encryption_round_trip() {
+        let relayer = create_test_relayer();
+        let codec = codec(EncryptionScope::WholeRecord);
+
+        let stored = codec.encode(&relayer).unwrap();
+        assert_ne!(stored, serde_json::to_string(&relayer).unwrap());
+        assert!(!stored.contains(RPC_URL));
+        assert!(!stored.contains("sepolia"));
+
+        assert_same_relayer(&codec.decode(&stored, &relayer.id).unwrap(), &relayer);
+    }
+
+    #[test]
+    fn test_sensitive_field_encryption_round_trip() {
+        let relayer = create_test_relayer();
+        let codec = codec(EncryptionScope::SensitiveFields);
+
+        let stored = codec.encode(&relayer).unwrap();
+        assert!(!stored.contains(RPC_URL));
+        // Non-sensitive fields stay readable
+        assert!(stored.contains("sepolia"));
+
+        assert_same_relayer(&codec.decode(&stored, &relayer.id).unwrap(), &relayer);
+    }
+
+    #[test]
+    fn test_decode_reads_plain_records_and_other_scopes() {
+        let relayer = create_test_relayer();
+        let plain = serde_json::to_string(&relayer).unwrap();
+        let whole = codec(EncryptionScope::WholeRecord)
+            .encode(&relayer)
+            .unwrap();
+
+        let sensitive = codec(EncryptionScope::SensitiveFields);
+        assert_same_relayer(&sensitive.decode(&plain, &relayer.id).unwrap(), &relayer);
+        assert_same_relayer(&sensitive.decode(&whole, &relayer.id).unwrap(), &relayer);
+    }
+
+    #[test]
+    fn test_decode_encrypted_record_without_provider_fails() {
+        let relayer = create_test_relayer();
+        let stored = codec(EncryptionScope::WholeRecord)
+            .encode(&relayer)
+            .unwrap();
+        let result = RelayerRecordCodec::default().decode(&stored, &relayer.id);
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+}
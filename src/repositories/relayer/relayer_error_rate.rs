@@ -0,0 +1,66 @@
+//! Error rate tracking over recently recorded relayer outcomes.
+//!
+//! Each relayer keeps a bounded ring of its most recent outcomes; the error rate
+//! over a window is the share of failures among the outcomes recorded within it.
+
+use crate::models::RepositoryError;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Returns the start of an error rate window of length `window` ending at `now`
+pub fn error_rate_window_start(
+    window: Duration,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, RepositoryError> {
+    chrono::Duration::from_std(window)
+        .ok()
+        .and_then(|window| now.checked_sub_signed(window))
+        .ok_or_else(|| {
+            RepositoryError::InvalidData(format!("Error rate window {window:?} is too large"))
+        })
+}
+
+/// Checks that `threshold` is an error rate between 0 and 1
+pub fn validate_error_rate_threshold(threshold: f64) -> Result<(), RepositoryError> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(RepositoryError::InvalidData(format!(
+            "Error rate threshold {threshold} must be between 0 and 1"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_rate_window_start_subtracts_window() {
+        let now = Utc::now();
+        assert_eq!(
+            error_rate_window_start(Duration::from_secs(60), now).unwrap(),
+            now - chrono::Duration::seconds(60)
+        );
+    }
+
+    #[test]
+    fn test_error_rate_window_start_rejects_overflowing_window() {
+        assert!(matches!(
+            error_rate_window_start(Duration::MAX, Utc::now()),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_error_rate_threshold() {
+        assert!(validate_error_rate_threshold(0.0).is_ok());
+        assert!(validate_error_rate_threshold(0.5).is_ok());
+        assert!(validate_error_rate_threshold(1.0).is_ok());
+        for threshold in [-0.1, 1.1, f64::NAN] {
+            assert!(matches!(
+                validate_error_rate_threshold(threshold),
+                Err(RepositoryError::InvalidData(_))
+            ));
+        }
+    }
+}
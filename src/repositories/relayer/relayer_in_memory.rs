@@ -7,29 +7,571 @@
 //! The `RelayerRepository` trait is designed to be implemented by any storage backend,
 //! allowing for flexibility in how relayers are stored and managed. The in-memory
 //! implementation is useful for testing and development purposes.
+use crate::constants::{
+    DEFAULT_PER_PAGE, DEFAULT_RELAYER_CHANGE_LOG_CAPACITY,
+    DEFAULT_RELAYER_CONFIG_LOCK_TIMEOUT_SECONDS, DEFAULT_RELAYER_CONFIG_VERSION_LIMIT,
+    DEFAULT_RELAYER_METADATA_MAX_BYTES, DEFAULT_RELAYER_OUTCOME_HISTORY_LIMIT,
+    DEFAULT_RELAYER_STATUS_HISTORY_LIMIT, RELAYER_IDEMPOTENCY_KEY_TTL_SECONDS, RELAYER_ID_REGEX,
+};
 use crate::models::PaginationQuery;
 use crate::{
     models::UpdateRelayerRequest,
-    models::{DisabledReason, RelayerNetworkPolicy, RelayerRepoModel, RepositoryError},
+    models::{
+        AlertTransition, ChangeKind, ChangeRecord, CircuitState, DeleteReport, DisabledReason,
+        EnforceReport, HealthCheckFailure, LogLevel, NetworkType, PolicyGuardrails, ProvisionMode,
+        ProvisionReport, RelayerConfigVersionInfo, RelayerDescriptor, RelayerFilter, RelayerHealth,
+        RelayerLastError, RelayerMode, RelayerNetworkPolicy, RelayerOperationalStatus,
+        RelayerRepoModel, RelayerTemplate, RelayerValidationError, RepositoryError, StatusChange,
+    },
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use eyre::Result;
-use std::collections::HashMap;
+use regex::Regex;
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, MutexGuard};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::warn;
+
+use crate::repositories::{
+    ensure_not_inherited, error_rate_window_start, match_addresses, prepare_provision,
+    provision_rejected, read_relayer_store, select_sticky, validate_error_rate_threshold,
+    validate_maintenance_until, validate_submission, write_relayer_store, ChangeLog,
+    ConfigReadGuard, NotificationRegistry, PaginatedResult, PolicyPredicate, ReadToken,
+    RelayerConfigLocks, RelayerRepository, RelayerSlots, RelayerSnapshot, Repository,
+    SignerChainAllowlist, SlotGuard, StickyStrategy, TenantRegistry,
+};
 
-use crate::repositories::{PaginatedResult, RelayerRepository, Repository};
+/// Relayer IDs per network type
+type NetworkIndex = HashMap<NetworkType, HashSet<String>>;
 
 #[derive(Debug)]
 pub struct InMemoryRelayerRepository {
     store: Mutex<HashMap<String, RelayerRepoModel>>,
+    /// Processed idempotency keys mapped to the created relayer ID and the time they were recorded.
+    /// Never locked while `store` is held; deletes lock it first and drop the keys of
+    /// the relayers they remove.
+    idempotency_keys: Mutex<HashMap<String, (String, Instant)>>,
+    /// Registered submission keys per relayer, mapped to the time they expire
+    submissions: Mutex<HashMap<(String, String), Instant>>,
+    /// Secondary index from relayer alias to relayer ID, kept in sync with `store`.
+    /// Always locked after `store`.
+    alias_index: Mutex<HashMap<String, String>>,
+    /// Secondary index from network type to relayer IDs, kept in sync with `store`.
+    /// Always locked after `store` and `alias_index`.
+    network_index: Mutex<NetworkIndex>,
+    /// Bounded log of mutations, appended while `store` is held
+    change_log: Mutex<ChangeLog>,
+    /// Maximum number of status transitions kept per relayer
+    status_history_limit: usize,
+    /// Maximum number of operation outcomes kept per relayer
+    outcome_history_limit: usize,
+    /// Maximum number of configuration versions kept per relayer
+    config_version_limit: usize,
+    /// Maximum size, in bytes of serialized JSON, of a relayer's metadata
+    metadata_size_limit: usize,
+    /// Optional safety limits enforced on policies in `create`, `update` and `update_policy`
+    policy_guardrails: Option<PolicyGuardrails>,
+    /// Page size per network type for listings whose query omits `per_page`
+    per_page_defaults: HashMap<NetworkType, u32>,
+    /// Optional source of signer chain allowlists checked in `create` and `rekey`
+    signer_chain_allowlist: Option<Arc<dyn SignerChainAllowlist>>,
+    /// Optional source of known tenants checked in `transfer_ownership`
+    tenant_registry: Option<Arc<dyn TenantRegistry>>,
+    /// Optional source of known notifications checked in `partial_update`
+    notification_registry: Option<Arc<dyn NotificationRegistry>>,
+    /// In-flight transaction slots handed out by `try_acquire_slot`
+    slots: RelayerSlots,
+    /// Configuration read locks handed out by `acquire_config_read`
+    config_locks: RelayerConfigLocks,
+    /// End of the maintenance window set by `enter_maintenance`, if any
+    maintenance_until: Mutex<Option<DateTime<Utc>>>,
+    /// Rule relayer IDs must match in `create`
+    id_pattern: Regex,
+    /// Serializes `save_to_path`; held from the snapshot until the file is in place,
+    /// so an older snapshot can never overwrite a newer one
+    save_lock: Mutex<()>,
 }
 
 impl InMemoryRelayerRepository {
     pub fn new() -> Self {
         Self {
             store: Mutex::new(HashMap::new()),
+            idempotency_keys: Mutex::new(HashMap::new()),
+            submissions: Mutex::new(HashMap::new()),
+            alias_index: Mutex::new(HashMap::new()),
+            network_index: Mutex::new(HashMap::new()),
+            change_log: Mutex::new(ChangeLog::new(DEFAULT_RELAYER_CHANGE_LOG_CAPACITY)),
+            status_history_limit: DEFAULT_RELAYER_STATUS_HISTORY_LIMIT,
+            outcome_history_limit: DEFAULT_RELAYER_OUTCOME_HISTORY_LIMIT,
+            config_version_limit: DEFAULT_RELAYER_CONFIG_VERSION_LIMIT,
+            metadata_size_limit: DEFAULT_RELAYER_METADATA_MAX_BYTES,
+            policy_guardrails: None,
+            per_page_defaults: HashMap::new(),
+            signer_chain_allowlist: None,
+            tenant_registry: None,
+            notification_registry: None,
+            slots: RelayerSlots::new(),
+            config_locks: RelayerConfigLocks::new(Duration::from_secs(
+                DEFAULT_RELAYER_CONFIG_LOCK_TIMEOUT_SECONDS,
+            )),
+            maintenance_until: Mutex::new(None),
+            id_pattern: RELAYER_ID_REGEX.clone(),
+            save_lock: Mutex::new(()),
+        }
+    }
+
+    /// Sets the maximum number of status transitions kept per relayer
+    pub fn with_status_history_limit(mut self, limit: usize) -> Self {
+        self.status_history_limit = limit;
+        self
+    }
+
+    /// Sets the maximum number of operation outcomes kept per relayer
+    pub fn with_outcome_history_limit(mut self, limit: usize) -> Self {
+        self.outcome_history_limit = limit;
+        self
+    }
+
+    /// Sets how long `update` and `update_policy` wait for config read guards to be
+    /// released before failing with `Conflict`
+    pub fn with_config_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.config_locks = RelayerConfigLocks::new(timeout);
+        self
+    }
+
+    /// Sets the maximum number of configuration versions kept per relayer
+    pub fn with_config_version_limit(mut self, limit: usize) -> Self {
+        self.config_version_limit = limit;
+        self
+    }
+
+    /// Sets the maximum size, in bytes of serialized JSON, of a relayer's metadata
+    pub fn with_metadata_size_limit(mut self, max_bytes: usize) -> Self {
+        self.metadata_size_limit = max_bytes;
+        self
+    }
+
+    /// Replaces the rule relayer IDs must match in `create`, by default
+    /// [`RELAYER_ID_REGEX`]. The pattern should be anchored.
+    pub fn with_id_pattern(mut self, pattern: Regex) -> Self {
+        self.id_pattern = pattern;
+        self
+    }
+
+    /// Sets the maximum number of records kept in the change log
+    pub fn with_change_log_capacity(mut self, capacity: usize) -> Self {
+        self.change_log = Mutex::new(ChangeLog::new(capacity));
+        self
+    }
+
+    /// Enforces the given guardrails on policies stored by this repository
+    pub fn with_policy_guardrails(mut self, guardrails: PolicyGuardrails) -> Self {
+        self.policy_guardrails = Some(guardrails);
+        self
+    }
+
+    /// Checks relayer chains against the chains their signers are authorized for
+    /// in `create` and `rekey`
+    pub fn with_signer_chain_allowlist(mut self, allowlist: Arc<dyn SignerChainAllowlist>) -> Self {
+        self.signer_chain_allowlist = Some(allowlist);
+        self
+    }
+
+    /// Checks target tenants of `transfer_ownership` against the given registry
+    pub fn with_tenant_registry(mut self, registry: Arc<dyn TenantRegistry>) -> Self {
+        self.tenant_registry = Some(registry);
+        self
+    }
+
+    /// Checks notifications referenced by `partial_update` against the given registry
+    pub fn with_notification_registry(mut self, registry: Arc<dyn NotificationRegistry>) -> Self {
+        self.notification_registry = Some(registry);
+        self
+    }
+
+    /// Sets the page size per network type used when a query omits `per_page`.
+    /// Network types without an entry fall back to [`DEFAULT_PER_PAGE`].
+    pub fn with_per_page_defaults(mut self, defaults: HashMap<NetworkType, u32>) -> Self {
+        self.per_page_defaults = defaults;
+        self
+    }
+
+    /// Fails if `tenant_id` is not known to the injected tenant registry. Any tenant
+    /// is accepted when no registry is injected.
+    async fn check_tenant(&self, tenant_id: &str) -> Result<(), RepositoryError> {
+        let Some(registry) = &self.tenant_registry else {
+            return Ok(());
+        };
+        if !registry.tenant_exists(tenant_id).await? {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Tenant {tenant_id} does not exist"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fails if a patch points the relayer at a notification unknown to the injected
+    /// notification registry. Any notification is accepted when no registry is injected.
+    async fn check_notification(
+        &self,
+        update: &UpdateRelayerRequest,
+    ) -> Result<(), RepositoryError> {
+        let (Some(registry), Some(Some(notification_id))) =
+            (&self.notification_registry, &update.notification_id)
+        else {
+            return Ok(());
+        };
+        if !registry.notification_exists(notification_id).await? {
+            return Err(RepositoryError::NotFound(format!(
+                "Notification with ID {notification_id} not found"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Page size for a listing of `network_type` relayers whose query omits `per_page`
+    fn default_per_page(&self, network_type: NetworkType) -> u32 {
+        self.per_page_defaults
+            .get(&network_type)
+            .copied()
+            .unwrap_or(DEFAULT_PER_PAGE)
+    }
+
+    fn check_policy_guardrails(
+        &self,
+        policy: &RelayerNetworkPolicy,
+    ) -> Result<(), RepositoryError> {
+        match &self.policy_guardrails {
+            Some(guardrails) => guardrails.check(policy),
+            None => Ok(()),
+        }
+    }
+
+    /// Fails if the relayer's chain is not one its signer is authorized for. Only
+    /// relayers with a chain id are checked, and only when an allowlist is injected.
+    async fn check_signer_chain(&self, relayer: &RelayerRepoModel) -> Result<(), RepositoryError> {
+        let (Some(allowlist), Some(_)) = (&self.signer_chain_allowlist, relayer.chain_id) else {
+            return Ok(());
+        };
+        let allowed_chains = allowlist.signer_allowed_chains(&relayer.signer_id).await?;
+        relayer.validate_signer_chain(allowed_chains.as_deref())
+    }
+
+    /// Fails if any alias of `relayer` is already used as another relayer's ID or alias
+    fn check_alias_collisions(
+        store: &HashMap<String, RelayerRepoModel>,
+        alias_index: &HashMap<String, String>,
+        relayer: &RelayerRepoModel,
+    ) -> Result<(), RepositoryError> {
+        for alias in &relayer.aliases {
+            let owned_by_other = alias_index
+                .get(alias)
+                .is_some_and(|owner| owner != &relayer.id);
+            if store.contains_key(alias) || owned_by_other {
+                return Err(RepositoryError::ConstraintViolation(format!(
+                    "Relayer alias {alias} is already in use"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails if `relayer`'s standby pairing is invalid or its primary already has a standby
+    fn check_standby_pairing(
+        store: &HashMap<String, RelayerRepoModel>,
+        relayer: &RelayerRepoModel,
+    ) -> Result<(), RepositoryError> {
+        let Some(primary_id) = &relayer.standby_for else {
+            return Ok(());
+        };
+        relayer.validate_standby_for(store.get(primary_id))?;
+        if store
+            .values()
+            .any(|other| other.id != relayer.id && other.standby_for.as_ref() == Some(primary_id))
+        {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Relayer {primary_id} already has a standby"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fails if `relayer` does not share network type and chain id with the other
+    /// members of its nonce group
+    fn check_nonce_group(
+        store: &HashMap<String, RelayerRepoModel>,
+        relayer: &RelayerRepoModel,
+    ) -> Result<(), RepositoryError> {
+        relayer.validate_nonce_group(
+            store
+                .values()
+                .filter(|other| other.id != relayer.id && other.nonce_group == relayer.nonce_group),
+        )
+    }
+
+    /// Returns every way `relayer` conflicts with the stored relayers: a taken id or
+    /// alias, an invalid standby pairing or nonce group, or a broken inheritance chain
+    fn create_conflicts(
+        store: &HashMap<String, RelayerRepoModel>,
+        alias_index: &HashMap<String, String>,
+        relayer: &RelayerRepoModel,
+    ) -> Vec<RepositoryError> {
+        let mut errors = Vec::new();
+        if store.contains_key(&relayer.id) {
+            errors.push(RepositoryError::ConstraintViolation(format!(
+                "Relayer with ID {} already exists",
+                relayer.id
+            )));
+        }
+        if alias_index.contains_key(&relayer.id) {
+            errors.push(RepositoryError::ConstraintViolation(format!(
+                "Relayer ID {} is already in use as an alias",
+                relayer.id
+            )));
+        }
+        errors.extend(Self::check_alias_collisions(store, alias_index, relayer).err());
+        errors.extend(Self::check_standby_pairing(store, relayer).err());
+        errors.extend(Self::check_nonce_group(store, relayer).err());
+        errors.extend(relayer.inheritance_chain(|id| store.get(id).cloned()).err());
+        errors
+    }
+
+    /// Appends a mutation of the relayer `id` to the change log
+    async fn record_change(&self, kind: ChangeKind, id: &str) {
+        self.change_log.lock().await.append(kind, id);
+    }
+
+    fn index_network(network_index: &mut NetworkIndex, relayer: &RelayerRepoModel) {
+        network_index
+            .entry(relayer.network_type)
+            .or_default()
+            .insert(relayer.id.clone());
+    }
+
+    fn unindex_network(network_index: &mut NetworkIndex, relayer: &RelayerRepoModel) {
+        if let Some(ids) = network_index.get_mut(&relayer.network_type) {
+            ids.remove(&relayer.id);
+            if ids.is_empty() {
+                network_index.remove(&relayer.network_type);
+            }
+        }
+    }
+
+    /// Writes every stored relayer to `path` as versioned JSON, atomically replacing
+    /// the file. Idempotency keys and the change log are not saved.
+    pub async fn save_to_path(&self, path: &Path) -> Result<(), RepositoryError> {
+        let _save_guard = Self::acquire_lock(&self.save_lock).await?;
+        let relayers: Vec<RelayerRepoModel> = Self::acquire_lock(&self.store)
+            .await?
+            .values()
+            .cloned()
+            .collect();
+        let path = path.to_path_buf();
+        tokio::task::spawn_blocking(move || write_relayer_store(&path, relayers))
+            .await
+            .map_err(|e| RepositoryError::Other(format!("Relayer store save task failed: {e}")))?
+    }
+
+    /// Creates a repository holding the relayers saved at `path` by [`Self::save_to_path`].
+    /// Settings start at their defaults and can be changed with the `with_*` builders.
+    pub fn load_from_path(path: &Path) -> Result<Self, RepositoryError> {
+        let mut store = HashMap::new();
+        let mut alias_index = HashMap::new();
+        let mut network_index = HashMap::new();
+        for relayer in read_relayer_store(path)? {
+            for alias in &relayer.aliases {
+                alias_index.insert(alias.clone(), relayer.id.clone());
+            }
+            Self::index_network(&mut network_index, &relayer);
+            let id = relayer.id.clone();
+            if store.insert(id.clone(), relayer).is_some() {
+                return Err(RepositoryError::InvalidData(format!(
+                    "Relayer store {} contains relayer {id} more than once",
+                    path.display()
+                )));
+            }
+        }
+
+        Ok(Self {
+            store: Mutex::new(store),
+            alias_index: Mutex::new(alias_index),
+            network_index: Mutex::new(network_index),
+            ..Self::new()
+        })
+    }
+
+    /// Creates a repository holding a single relayer defined by environment variables
+    /// named `{prefix}_*`, for deployments without a configuration file.
+    ///
+    /// Required: `{prefix}_RELAYER_ID`, `{prefix}_NETWORK`, `{prefix}_SIGNER_ID` and
+    /// `{prefix}_ADDRESS`. Optional: `{prefix}_NAME` (defaults to the relayer ID),
+    /// `{prefix}_NETWORK_TYPE` (`evm`, `solana` or `stellar`, defaults to `evm`),
+    /// `{prefix}_PAUSED`, `{prefix}_NOTIFICATION_ID`, `{prefix}_POLICIES` (the network
+    /// policy as `type`-tagged JSON) and `{prefix}_MIN_BALANCE`, which overrides the
+    /// policy's minimum balance.
+    ///
+    /// The relayer goes through the same validation as a provisioned one. Settings start
+    /// at their defaults and can be changed with the `with_*` builders.
+    pub fn from_env(prefix: &str) -> Result<Self, RepositoryError> {
+        let name_of = |suffix: &str| format!("{prefix}_{suffix}");
+        let optional = |suffix: &str| -> Result<Option<String>, RepositoryError> {
+            match env::var(name_of(suffix)) {
+                Ok(value) if value.trim().is_empty() => Ok(None),
+                Ok(value) => Ok(Some(value.trim().to_string())),
+                Err(env::VarError::NotPresent) => Ok(None),
+                Err(env::VarError::NotUnicode(_)) => Err(RepositoryError::InvalidData(format!(
+                    "Environment variable {} is not valid unicode",
+                    name_of(suffix)
+                ))),
+            }
+        };
+        let required = |suffix: &str| -> Result<String, RepositoryError> {
+            optional(suffix)?.ok_or_else(|| {
+                RepositoryError::InvalidData(format!(
+                    "Missing required environment variable {}",
+                    name_of(suffix)
+                ))
+            })
+        };
+        let invalid = |suffix: &str, error: &dyn std::fmt::Display| {
+            RepositoryError::InvalidData(format!(
+                "Invalid environment variable {}: {error}",
+                name_of(suffix)
+            ))
+        };
+
+        let id = required("RELAYER_ID")?;
+        let network_type = match optional("NETWORK_TYPE")? {
+            Some(value) => value
+                .to_lowercase()
+                .parse::<NetworkType>()
+                .map_err(|e| invalid("NETWORK_TYPE", &e))?,
+            None => NetworkType::Evm,
+        };
+        let paused = match optional("PAUSED")? {
+            Some(value) => value.parse().map_err(|e| invalid("PAUSED", &e))?,
+            None => false,
+        };
+        let policies = optional("POLICIES")?
+            .map(|value| serde_json::from_str(&value).map_err(|e| invalid("POLICIES", &e)))
+            .transpose()?;
+        let mut descriptor = RelayerDescriptor {
+            name: optional("NAME")?.unwrap_or_else(|| id.clone()),
+            id,
+            network: required("NETWORK")?,
+            network_type,
+            signer_id: required("SIGNER_ID")?,
+            address: required("ADDRESS")?,
+            policies,
+            paused,
+            notification_id: optional("NOTIFICATION_ID")?,
+            custom_rpc_urls: None,
+            extra_webhook_urls: None,
+            hard_gas_price_ceiling: None,
+            pool: None,
+            derivation_path: None,
+        };
+
+        if let Some(value) = optional("MIN_BALANCE")? {
+            let min_balance: u128 = value.parse().map_err(|e| invalid("MIN_BALANCE", &e))?;
+            let mut policies = descriptor.to_repo_model().policies;
+            match &mut policies {
+                RelayerNetworkPolicy::Evm(policy) => policy.min_balance = Some(min_balance),
+                RelayerNetworkPolicy::Solana(policy) => {
+                    policy.min_balance =
+                        Some(u64::try_from(min_balance).map_err(|e| invalid("MIN_BALANCE", &e))?)
+                }
+                RelayerNetworkPolicy::Stellar(policy) => {
+                    policy.min_balance =
+                        Some(u64::try_from(min_balance).map_err(|e| invalid("MIN_BALANCE", &e))?)
+                }
+            }
+            descriptor.policies = Some(policies);
+        }
+
+        if let Some(error) = descriptor.validation_errors().first() {
+            return Err(RepositoryError::InvalidData(format!(
+                "Invalid relayer defined by {prefix}_* environment variables: {error}"
+            )));
+        }
+        let repo = Self::new();
+        let mut relayer = descriptor.to_repo_model();
+        relayer.validate_id_format(&repo.id_pattern)?;
+        if let Some(error) = relayer.create_validation_errors().into_iter().next() {
+            return Err(error);
+        }
+        relayer.record_config_version(repo.config_version_limit);
+
+        let mut network_index = HashMap::new();
+        Self::index_network(&mut network_index, &relayer);
+        Ok(Self {
+            store: Mutex::new(HashMap::from([(relayer.id.clone(), relayer)])),
+            network_index: Mutex::new(network_index),
+            ..repo
+        })
+    }
+
+    /// Saves the repository to `path` every `interval` until the returned task is
+    /// aborted. A failed save is logged and retried on the next tick.
+    pub fn spawn_auto_save(self: Arc<Self>, path: PathBuf, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick completes immediately; there is nothing new to save yet
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.save_to_path(&path).await {
+                    warn!(path = %path.display(), error = %e, "failed to auto-save relayer store");
+                }
+            }
+        })
+    }
+
+    /// Applies `retag` to every relayer matching `filter` under one store lock,
+    /// returning how many relayers it changed
+    async fn retag_where(
+        &self,
+        filter: &RelayerFilter,
+        mut retag: impl FnMut(&mut RelayerRepoModel) -> bool,
+    ) -> Result<u64, RepositoryError> {
+        let now = Utc::now();
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let mut changed = 0;
+        for (id, relayer) in store.iter_mut() {
+            if filter.matches_at(relayer, now) && retag(relayer) {
+                relayer.version += 1;
+                relayer.updated_at = Some(now.to_rfc3339());
+                self.record_change(ChangeKind::Updated, id).await;
+                changed += 1;
+            }
         }
+        Ok(changed)
     }
+
+    /// Rejects a page number or page size of zero before either is used to compute
+    /// an offset
+    fn validate_page_query(query: &PaginationQuery) -> Result<(), RepositoryError> {
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+
+        if query.resolved_per_page() == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     async fn acquire_lock<T>(lock: &Mutex<T>) -> Result<MutexGuard<T>, RepositoryError> {
         Ok(lock.lock().await)
     }
@@ -49,9 +591,59 @@ impl Clone for InMemoryRelayerRepository {
             .try_lock()
             .map(|guard| guard.clone())
             .unwrap_or_else(|_| HashMap::new());
+        let idempotency_keys = self
+            .idempotency_keys
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
+        let submissions = self
+            .submissions
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
+        let alias_index = self
+            .alias_index
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
+        let network_index = self
+            .network_index
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
+        let change_log = self
+            .change_log
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| ChangeLog::new(DEFAULT_RELAYER_CHANGE_LOG_CAPACITY));
+        let maintenance_until = self
+            .maintenance_until
+            .try_lock()
+            .map(|guard| *guard)
+            .unwrap_or(None);
 
         Self {
             store: Mutex::new(data),
+            idempotency_keys: Mutex::new(idempotency_keys),
+            submissions: Mutex::new(submissions),
+            alias_index: Mutex::new(alias_index),
+            network_index: Mutex::new(network_index),
+            change_log: Mutex::new(change_log),
+            status_history_limit: self.status_history_limit,
+            outcome_history_limit: self.outcome_history_limit,
+            config_version_limit: self.config_version_limit,
+            metadata_size_limit: self.metadata_size_limit,
+            policy_guardrails: self.policy_guardrails.clone(),
+            per_page_defaults: self.per_page_defaults.clone(),
+            signer_chain_allowlist: self.signer_chain_allowlist.clone(),
+            tenant_registry: self.tenant_registry.clone(),
+            notification_registry: self.notification_registry.clone(),
+            // Slots belong to the transactions of the original repository
+            slots: RelayerSlots::new(),
+            config_locks: RelayerConfigLocks::new(self.config_locks.write_timeout()),
+            maintenance_until: Mutex::new(maintenance_until),
+            id_pattern: self.id_pattern.clone(),
+            save_lock: Mutex::new(()),
         }
     }
 }
@@ -104,10 +696,29 @@ impl RelayerRepository for InMemoryRelayerRepository {
         id: String,
         update: UpdateRelayerRequest,
     ) -> Result<RelayerRepoModel, RepositoryError> {
+        if update.is_empty_patch() {
+            return self.get_by_id(id).await;
+        }
+        update.validate_patch()?;
+        self.check_notification(&update).await?;
+
         let mut store = Self::acquire_lock(&self.store).await?;
+        if let Some(name) = &update.name {
+            if store
+                .values()
+                .any(|other| other.id != id && &other.name == name)
+            {
+                return Err(RepositoryError::ConstraintViolation(format!(
+                    "Relayer name {name} is already in use"
+                )));
+            }
+        }
         if let Some(relayer) = store.get_mut(&id) {
-            if let Some(paused) = update.paused {
-                relayer.paused = paused;
+            // Leave the version and change log untouched when nothing changes
+            if relayer.apply_partial_update(update) {
+                relayer.record_config_version(self.config_version_limit);
+                relayer.version += 1;
+                self.record_change(ChangeKind::Updated, &id).await;
             }
             Ok(relayer.clone())
         } else {
@@ -122,11 +733,17 @@ impl RelayerRepository for InMemoryRelayerRepository {
         id: String,
         policy: RelayerNetworkPolicy,
     ) -> Result<RelayerRepoModel, RepositoryError> {
+        policy.validate()?;
+        self.check_policy_guardrails(&policy)?;
+        let _config = self.config_locks.write(&id).await?;
         let mut store = Self::acquire_lock(&self.store).await?;
         let relayer = store
             .get_mut(&id)
             .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
         relayer.policies = policy;
+        relayer.record_config_version(self.config_version_limit);
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
         Ok(relayer.clone())
     }
 
@@ -137,8 +754,15 @@ impl RelayerRepository for InMemoryRelayerRepository {
     ) -> Result<RelayerRepoModel, RepositoryError> {
         let mut store = self.store.lock().await;
         if let Some(relayer) = store.get_mut(&relayer_id) {
+            let from = relayer.state();
             relayer.system_disabled = true;
             relayer.disabled_reason = Some(reason);
+            relayer.quarantined_until = None;
+            relayer.scheduled_disable_at = None;
+            relayer.scheduled_disable_reason = None;
+            relayer.record_status_change(from, self.status_history_limit);
+            relayer.version += 1;
+            self.record_change(ChangeKind::Updated, &relayer_id).await;
             Ok(relayer.clone())
         } else {
             Err(RepositoryError::NotFound(format!(
@@ -153,8 +777,13 @@ impl RelayerRepository for InMemoryRelayerRepository {
     ) -> Result<RelayerRepoModel, RepositoryError> {
         let mut store = self.store.lock().await;
         if let Some(relayer) = store.get_mut(&relayer_id) {
+            let from = relayer.state();
             relayer.system_disabled = false;
             relayer.disabled_reason = None;
+            relayer.quarantined_until = None;
+            relayer.record_status_change(from, self.status_history_limit);
+            relayer.version += 1;
+            self.record_change(ChangeKind::Updated, &relayer_id).await;
             Ok(relayer.clone())
         } else {
             Err(RepositoryError::NotFound(format!(
@@ -163,662 +792,6639 @@ impl RelayerRepository for InMemoryRelayerRepository {
         }
     }
 
-    fn is_persistent_storage(&self) -> bool {
-        false
+    async fn create_idempotent(
+        &self,
+        relayer: RelayerRepoModel,
+        idempotency_key: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        if idempotency_key.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Idempotency key cannot be empty".to_string(),
+            ));
+        }
+
+        // Hold the key lock for the whole operation so concurrent retries are serialized
+        let mut keys = Self::acquire_lock(&self.idempotency_keys).await?;
+        let ttl = Duration::from_secs(RELAYER_IDEMPOTENCY_KEY_TTL_SECONDS);
+        let now = Instant::now();
+        keys.retain(|_, (_, recorded_at)| now.duration_since(*recorded_at) < ttl);
+
+        if let Some((relayer_id, _)) = keys.get(&idempotency_key) {
+            return self.get_by_id(relayer_id.clone()).await;
+        }
+
+        let created = self.create(relayer).await?;
+        keys.insert(idempotency_key, (created.id.clone(), now));
+        Ok(created)
     }
-}
 
-#[async_trait]
-impl Repository<RelayerRepoModel, String> for InMemoryRelayerRepository {
-    async fn create(&self, relayer: RelayerRepoModel) -> Result<RelayerRepoModel, RepositoryError> {
+    async fn quarantine(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
         let mut store = Self::acquire_lock(&self.store).await?;
-        if store.contains_key(&relayer.id) {
-            return Err(RepositoryError::ConstraintViolation(format!(
-                "Relayer with ID {} already exists",
-                relayer.id
-            )));
-        }
-        store.insert(relayer.id.clone(), relayer.clone());
-        Ok(relayer)
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        let from = relayer.state();
+        relayer.system_disabled = true;
+        relayer.disabled_reason = Some(DisabledReason::Quarantined(reason));
+        relayer.quarantined_until = Some(until);
+        relayer.record_status_change(from, self.status_history_limit);
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
     }
 
-    async fn get_by_id(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+    async fn list_expired_quarantines(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
         let store = Self::acquire_lock(&self.store).await?;
-        match store.get(&id) {
-            Some(relayer) => Ok(relayer.clone()),
-            None => Err(RepositoryError::NotFound(format!(
-                "Relayer with ID {id} not found"
-            ))),
-        }
+        let expired: Vec<RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| relayer.system_disabled && relayer.is_quarantine_expired(now))
+            .cloned()
+            .collect();
+        Ok(expired)
     }
-    #[allow(clippy::map_entry)]
-    async fn update(
+
+    async fn instantiate(
         &self,
+        template: &RelayerTemplate,
         id: String,
-        relayer: RelayerRepoModel,
+        signer_id: String,
+        address: String,
     ) -> Result<RelayerRepoModel, RepositoryError> {
-        let mut store = Self::acquire_lock(&self.store).await?;
-        if store.contains_key(&id) {
-            // Ensure we update the existing entry
-            let mut updated_relayer = relayer;
-            updated_relayer.id = id.clone(); // Preserve original ID
-            store.insert(id, updated_relayer.clone());
-            Ok(updated_relayer)
-        } else {
-            Err(RepositoryError::NotFound(format!(
-                "Relayer with ID {id} not found"
-            )))
-        }
+        self.create(template.to_repo_model(id, signer_id, address))
+            .await
     }
 
-    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+    async fn evaluate_balance_alert(
+        &self,
+        id: String,
+        balance: u128,
+    ) -> Result<Option<AlertTransition>, RepositoryError> {
         let mut store = Self::acquire_lock(&self.store).await?;
-        if store.remove(&id).is_some() {
-            Ok(())
-        } else {
-            Err(RepositoryError::NotFound(format!(
-                "Relayer with ID {id} not found"
-            )))
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        let transition = relayer.evaluate_balance_alert(balance);
+        if transition.is_some() {
+            relayer.version += 1;
+            self.record_change(ChangeKind::Updated, &id).await;
         }
+        Ok(transition)
     }
 
-    async fn list_all(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+    async fn get_by_id_scoped(
+        &self,
+        id: String,
+        tenant_id: &str,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
         let store = Self::acquire_lock(&self.store).await?;
-        Ok(store.values().cloned().collect())
+        match store.get(&id) {
+            Some(relayer) if relayer.tenant_id == tenant_id => Ok(relayer.clone()),
+            _ => Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            ))),
+        }
     }
 
-    async fn list_paginated(
+    async fn list_by_tenant(
         &self,
+        tenant_id: &str,
         query: PaginationQuery,
     ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
-        let total = self.count().await?;
-        let start = ((query.page - 1) * query.per_page) as usize;
-        let items = self
-            .store
-            .lock()
-            .await
+        Self::validate_page_query(&query)?;
+
+        let store = Self::acquire_lock(&self.store).await?;
+        let tenant_relayers: Vec<&RelayerRepoModel> = store
             .values()
+            .filter(|relayer| relayer.tenant_id == tenant_id)
+            .collect();
+        let total = tenant_relayers.len() as u64;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let items = tenant_relayers
+            .into_iter()
             .skip(start)
-            .take(query.per_page as usize)
+            .take(query.resolved_per_page() as usize)
             .cloned()
             .collect();
         Ok(PaginatedResult {
             items,
-            total: total as u64,
+            total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
-    async fn count(&self) -> Result<usize, RepositoryError> {
-        Ok(self.store.lock().await.len())
-    }
-
-    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+    async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError> {
         let store = Self::acquire_lock(&self.store).await?;
-        Ok(!store.is_empty())
+        Ok(RelayerSnapshot::new(store.clone()))
     }
 
-    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+    async fn schedule_disable(
+        &self,
+        id: String,
+        at: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
         let mut store = Self::acquire_lock(&self.store).await?;
-        store.clear();
-        Ok(())
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.scheduled_disable_at = Some(at);
+        relayer.scheduled_disable_reason = Some(reason);
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::models::{NetworkType, RelayerEvmPolicy};
+
+    async fn list_due_scheduled_disables(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let due: Vec<RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| relayer.is_scheduled_disable_due(now))
+            .cloned()
+            .collect();
+        Ok(due)
+    }
+
+    async fn cancel_scheduled_disable(
+        &self,
+        id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.scheduled_disable_at = None;
+        relayer.scheduled_disable_reason = None;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn find_drifted(
+        &self,
+        desired: &[RelayerRepoModel],
+    ) -> Result<Vec<String>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let drifted: Vec<String> = desired
+            .iter()
+            .filter(|wanted| {
+                store.get(&wanted.id).map(|stored| stored.config_checksum())
+                    != Some(wanted.config_checksum())
+            })
+            .map(|wanted| wanted.id.clone())
+            .collect();
+        Ok(drifted)
+    }
+
+    async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        Ok(relayer.status_history.iter().cloned().collect())
+    }
+
+    async fn validate_all(
+        &self,
+    ) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let offenders: Vec<(String, Vec<RelayerValidationError>)> = store
+            .values()
+            .filter_map(|relayer| {
+                let errors = relayer.validation_errors();
+                (!errors.is_empty()).then(|| (relayer.id.clone(), errors))
+            })
+            .collect();
+        Ok(offenders)
+    }
+
+    async fn pick_sticky(
+        &self,
+        pool: String,
+        session_key: &str,
+        strategy: StickyStrategy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let members: Vec<RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| relayer.pool.as_deref() == Some(pool.as_str()))
+            .cloned()
+            .collect();
+        select_sticky(members, session_key, strategy).ok_or_else(|| {
+            RepositoryError::NotFound(format!("No operational relayer found in pool {pool}"))
+        })
+    }
+
+    async fn rekey(
+        &self,
+        id: String,
+        new_signer_id: String,
+        new_address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        // The allowlist is consulted before taking the lock, as it may call out to the
+        // signer repository
+        let rekeyed = RelayerRepoModel {
+            signer_id: new_signer_id.clone(),
+            ..self.get_by_id(id.clone()).await?
+        };
+        self.check_signer_chain(&rekeyed).await?;
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.validate_rekey(&new_signer_id, &new_address)?;
+        relayer.signer_id = new_signer_id;
+        relayer.address = new_address;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(ids
+            .into_iter()
+            .filter(|id| store.contains_key(id))
+            .collect())
+    }
+
+    async fn compact(&self) -> Result<(), RepositoryError> {
+        {
+            let mut store = Self::acquire_lock(&self.store).await?;
+            store.shrink_to_fit();
+            Self::acquire_lock(&self.alias_index).await?.shrink_to_fit();
+        }
+        // `create_idempotent` takes `store` while holding `idempotency_keys`, so the
+        // side maps are only locked once `store` has been released
+        Self::acquire_lock(&self.idempotency_keys)
+            .await?
+            .shrink_to_fit();
+        let mut submissions = Self::acquire_lock(&self.submissions).await?;
+        let now = Instant::now();
+        submissions.retain(|_, expires_at| *expires_at > now);
+        submissions.shrink_to_fit();
+        Ok(())
+    }
+
+    async fn trip_circuit(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.circuit = CircuitState::Open { until };
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.circuit = CircuitState::Closed;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn record_outcome(
+        &self,
+        id: String,
+        success: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        let now = Utc::now();
+        relayer.record_circuit_outcome(success, now);
+        relayer.record_recent_outcome(success, now, self.outcome_history_limit);
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn list_where_policy(
+        &self,
+        predicate: PolicyPredicate,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        Self::validate_page_query(&query)?;
+
+        let store = Self::acquire_lock(&self.store).await?;
+        let matching: Vec<&RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| predicate(&relayer.policies))
+            .collect();
+        let total = matching.len() as u64;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let items = matching
+            .into_iter()
+            .skip(start)
+            .take(query.resolved_per_page() as usize)
+            .cloned()
+            .collect();
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page: query.resolved_per_page(),
+        })
+    }
+
+    async fn provision_from_descriptors(
+        &self,
+        descriptors: Vec<RelayerDescriptor>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionReport, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let alias_index = Self::acquire_lock(&self.alias_index).await?;
+        // Ids already taken by another relayer's alias count as existing
+        let existing: HashSet<String> = descriptors
+            .iter()
+            .filter(|descriptor| {
+                store.contains_key(&descriptor.id) || alias_index.contains_key(&descriptor.id)
+            })
+            .map(|descriptor| descriptor.id.clone())
+            .collect();
+        let (relayers, errors) = prepare_provision(&descriptors, &existing, |policy| {
+            self.check_policy_guardrails(policy)
+        });
+
+        if mode == ProvisionMode::Strict && !errors.is_empty() {
+            return Err(provision_rejected(&errors, descriptors.len()));
+        }
+
+        let created = relayers.iter().map(|relayer| relayer.id.clone()).collect();
+        if mode == ProvisionMode::Strict {
+            let mut network_index = Self::acquire_lock(&self.network_index).await?;
+            for mut relayer in relayers {
+                relayer.record_config_version(self.config_version_limit);
+                Self::index_network(&mut network_index, &relayer);
+                self.record_change(ChangeKind::Created, &relayer.id).await;
+                store.insert(relayer.id.clone(), relayer);
+            }
+        }
+        Ok(ProvisionReport { created, errors })
+    }
+
+    async fn record_error(
+        &self,
+        id: String,
+        error: RelayerLastError,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.last_error = Some(error);
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.last_error = None;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn list_with_recent_errors(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let recent: Vec<RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| relayer.has_error_since(since))
+            .cloned()
+            .collect();
+        Ok(recent)
+    }
+
+    async fn list_config_versions(
+        &self,
+        id: String,
+    ) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError> {
+        let relayer = self.get_by_id(id).await?;
+        Ok(relayer.config_version_infos())
+    }
+
+    async fn rollback_to(
+        &self,
+        id: String,
+        version: u32,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+
+        // Validate the restored config against the current rules before committing it
+        let mut restored = relayer.clone();
+        restored.restore_config_version(version, self.config_version_limit)?;
+        restored.validate_extra_webhook_urls()?;
+        restored.validate_derivation_path()?;
+        restored.validate_fee_account()?;
+        self.check_policy_guardrails(&restored.policies)?;
+
+        *relayer = restored;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let alias_index = Self::acquire_lock(&self.alias_index).await?;
+        alias_index
+            .get(&alias)
+            .and_then(|id| store.get(id))
+            .cloned()
+            .ok_or_else(|| {
+                RepositoryError::NotFound(format!("Relayer with alias {alias} not found"))
+            })
+    }
+
+    async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError> {
+        Self::acquire_lock(&self.change_log).await?.since(seq)
+    }
+
+    async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError> {
+        let relayer = self.get_by_id_effective(id).await?;
+        Ok(relayer.policies.effective(self.policy_guardrails.as_ref()))
+    }
+
+    async fn get_standby(
+        &self,
+        primary_id: String,
+    ) -> Result<Option<RelayerRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        if !store.contains_key(&primary_id) {
+            return Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {primary_id} not found"
+            )));
+        }
+        Ok(store
+            .values()
+            .find(|relayer| relayer.standby_for.as_ref() == Some(&primary_id))
+            .cloned())
+    }
+
+    async fn list_due_health_checks(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let due: Vec<RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| relayer.is_health_check_due(now))
+            .cloned()
+            .collect();
+        Ok(due)
+    }
+
+    async fn record_health_check(
+        &self,
+        id: String,
+        result: Result<(), Vec<HealthCheckFailure>>,
+        at: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.health = RelayerHealth::from(result);
+        relayer.last_health_check_at = Some(at);
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.sunset = true;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.sunset = false;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let mut relayer = store
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        let ancestors = relayer.inheritance_chain(|id| store.get(id).cloned())?;
+        relayer.policies = relayer.inherited_policies(&ancestors);
+        Ok(relayer)
+    }
+
+    async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError> {
+        // Taken before `store`, in the order `create_idempotent` takes them
+        let mut keys = Self::acquire_lock(&self.idempotency_keys).await?;
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let mut alias_index = Self::acquire_lock(&self.alias_index).await?;
+        let mut network_index = Self::acquire_lock(&self.network_index).await?;
+        let deleted: HashSet<&str> = ids
+            .iter()
+            .map(String::as_str)
+            .filter(|id| store.contains_key(*id))
+            .collect();
+        ensure_not_inherited(store.values(), &deleted)?;
+
+        let mut report = DeleteReport::default();
+        for id in ids {
+            match store.remove(&id) {
+                Some(removed) => {
+                    for alias in &removed.aliases {
+                        alias_index.remove(alias);
+                    }
+                    Self::unindex_network(&mut network_index, &removed);
+                    keys.retain(|_, (relayer_id, _)| *relayer_id != id);
+                    self.record_change(ChangeKind::Deleted, &id).await;
+                    report.deleted.push(id);
+                }
+                None => report.not_found.push(id),
+            }
+        }
+        Ok(report)
+    }
+
+    async fn list_by_network_type(
+        &self,
+        network_type: NetworkType,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        let per_page = query.per_page_or(self.default_per_page(network_type));
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+
+        if per_page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+
+        let store = Self::acquire_lock(&self.store).await?;
+        let network_index = Self::acquire_lock(&self.network_index).await?;
+        let mut matching: Vec<&RelayerRepoModel> = network_index
+            .get(&network_type)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| store.get(id))
+            .collect();
+        matching.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let total = matching.len() as u64;
+        let start = ((query.page - 1) * per_page) as usize;
+        let items: Vec<RelayerRepoModel> = matching
+            .into_iter()
+            .skip(start)
+            .take(per_page as usize)
+            .cloned()
+            .collect();
+
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page,
+        })
+    }
+
+    async fn pause_if_version(
+        &self,
+        id: String,
+        expected_version: u64,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.check_version(expected_version)?;
+        let from = relayer.state();
+        relayer.paused = true;
+        relayer.record_status_change(from, self.status_history_limit);
+        relayer.record_config_version(self.config_version_limit);
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn group_by_network_type(
+        &self,
+    ) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError> {
+        Ok(self.snapshot().await?.group_operational_by_network_type())
+    }
+
+    async fn validate_create(
+        &self,
+        mut relayer: RelayerRepoModel,
+    ) -> Result<(), Vec<RepositoryError>> {
+        relayer.normalize_id();
+        let mut errors: Vec<RepositoryError> = relayer
+            .validate_id_format(&self.id_pattern)
+            .err()
+            .into_iter()
+            .collect();
+        errors.extend(relayer.create_validation_errors());
+        errors.extend(self.check_policy_guardrails(&relayer.policies).err());
+        errors.extend(
+            relayer
+                .validate_metadata_size(self.metadata_size_limit)
+                .err(),
+        );
+        errors.extend(self.check_signer_chain(&relayer).await.err());
+        let store = Self::acquire_lock(&self.store).await.map_err(|e| vec![e])?;
+        let alias_index = Self::acquire_lock(&self.alias_index)
+            .await
+            .map_err(|e| vec![e])?;
+        errors.extend(Self::create_conflicts(&store, &alias_index, &relayer));
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    async fn list_by_status(
+        &self,
+        status: RelayerOperationalStatus,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        Self::validate_page_query(&query)?;
+
+        let now = Utc::now();
+        let store = Self::acquire_lock(&self.store).await?;
+        let matching: Vec<&RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| relayer.status_at(now) == status)
+            .collect();
+        let total = matching.len() as u64;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let items = matching
+            .into_iter()
+            .skip(start)
+            .take(query.resolved_per_page() as usize)
+            .cloned()
+            .collect();
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page: query.resolved_per_page(),
+        })
+    }
+
+    async fn add_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.retag_where(&filter, |relayer| relayer.add_tag(&tag))
+            .await
+    }
+
+    async fn remove_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.retag_where(&filter, |relayer| relayer.remove_tag(&tag))
+            .await
+    }
+
+    async fn set_metadata(
+        &self,
+        id: String,
+        metadata: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        let mut updated = relayer.clone();
+        updated.metadata = metadata;
+        updated.validate_metadata_size(self.metadata_size_limit)?;
+        updated.version += 1;
+        *relayer = updated;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn merge_metadata(
+        &self,
+        id: String,
+        patch: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        let mut updated = relayer.clone();
+        updated.merge_metadata(patch)?;
+        updated.validate_metadata_size(self.metadata_size_limit)?;
+        updated.version += 1;
+        *relayer = updated;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError> {
+        let relayer = self.get_by_id(id).await?;
+        self.slots.try_acquire(&relayer).await
+    }
+
+    async fn record_rpc_health(
+        &self,
+        id: String,
+        url: String,
+        healthy: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.record_rpc_health(&url, healthy)?;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError> {
+        validate_maintenance_until(until, Utc::now())?;
+        *Self::acquire_lock(&self.maintenance_until).await? = Some(until);
+        Ok(())
+    }
+
+    async fn exit_maintenance(&self) -> Result<(), RepositoryError> {
+        *Self::acquire_lock(&self.maintenance_until).await? = None;
+        Ok(())
+    }
+
+    async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        let maintenance_until = Self::acquire_lock(&self.maintenance_until).await?;
+        let now = Utc::now();
+        Ok(maintenance_until.filter(|until| *until > now))
+    }
+
+    async fn list_active_by_network_type(
+        &self,
+        network_type: NetworkType,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let network_index = Self::acquire_lock(&self.network_index).await?;
+        let now = Utc::now();
+        // Operational state depends on the time, so it is checked on the indexed candidates
+        let mut active: Vec<RelayerRepoModel> = network_index
+            .get(&network_type)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| store.get(id))
+            .filter(|relayer| relayer.is_operational_at(now))
+            .cloned()
+            .collect();
+        active.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(active)
+    }
+
+    async fn list_by_nonce_group(
+        &self,
+        group: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        let mut members: Vec<RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| relayer.nonce_group.as_deref() == Some(group))
+            .cloned()
+            .collect();
+        members.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(members)
+    }
+
+    async fn list_high_error_rate(
+        &self,
+        window: Duration,
+        threshold: f64,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        validate_error_rate_threshold(threshold)?;
+        let since = error_rate_window_start(window, Utc::now())?;
+        let store = Self::acquire_lock(&self.store).await?;
+        let mut relayers: Vec<RelayerRepoModel> = store
+            .values()
+            .filter(|relayer| {
+                relayer
+                    .error_rate_since(since)
+                    .is_some_and(|rate| rate > threshold)
+            })
+            .cloned()
+            .collect();
+        relayers.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(relayers)
+    }
+
+    async fn enforce_allowlist(
+        &self,
+        allowed: HashSet<String>,
+    ) -> Result<EnforceReport, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let mut report = EnforceReport::default();
+        for (id, relayer) in store.iter_mut() {
+            let from = relayer.state();
+            if !relayer.enforce_allowlist(allowed.contains(id)) {
+                continue;
+            }
+            relayer.record_status_change(from, self.status_history_limit);
+            relayer.record_config_version(self.config_version_limit);
+            relayer.version += 1;
+            self.record_change(ChangeKind::Updated, id).await;
+            if relayer.paused {
+                report.paused.push(id.clone());
+            } else {
+                report.resumed.push(id.clone());
+            }
+        }
+        report.paused.sort();
+        report.resumed.sort();
+        Ok(report)
+    }
+
+    async fn set_log_level(
+        &self,
+        id: String,
+        level: Option<LogLevel>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.log_level = level;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError> {
+        // The change log is appended while `store` is held, so the sequence matches the snapshot
+        let store = Self::acquire_lock(&self.store).await?;
+        let change_log = Self::acquire_lock(&self.change_log).await?;
+        Ok(ReadToken::new(
+            change_log.latest_seq(),
+            RelayerSnapshot::new(store.clone()),
+        ))
+    }
+
+    async fn get_many_by_address(
+        &self,
+        addresses: Vec<String>,
+        network_type: NetworkType,
+    ) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError> {
+        let wanted: HashSet<String> = addresses
+            .iter()
+            .map(|address| network_type.normalize_address(address))
+            .collect();
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(match_addresses(
+            store.values().filter(|r| r.network_type == network_type),
+            &wanted,
+        ))
+    }
+
+    async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError> {
+        self.config_locks
+            .read(&id, || self.get_by_id(id.clone()))
+            .await
+    }
+
+    async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        // The counter is runtime state, like the Redis pending key: no version, no change record
+        relayer.pending_count = relayer.pending_count.saturating_add(1);
+        Ok(relayer.pending_count)
+    }
+
+    async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        // The counter is runtime state, like the Redis pending key: no version, no change record
+        relayer.pending_count = relayer.pending_count.saturating_sub(1);
+        Ok(relayer.pending_count)
+    }
+
+    async fn pending_count(&self, id: String) -> Result<u64, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        store
+            .get(&id)
+            .map(|relayer| relayer.pending_count)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))
+    }
+
+    async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        // Spend is runtime state, like the Redis spend key: no version, no change record
+        relayer.record_spend(amount, Utc::now())
+    }
+
+    async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        if relayer.mode != RelayerMode::Live {
+            relayer.mode = RelayerMode::Live;
+            relayer.version += 1;
+            self.record_change(ChangeKind::Updated, &id).await;
+        }
+        Ok(relayer.clone())
+    }
+
+    async fn register_submission(
+        &self,
+        id: String,
+        tx_hash_key: String,
+        ttl: Duration,
+    ) -> Result<bool, RepositoryError> {
+        validate_submission(&tx_hash_key, ttl)?;
+        if !Self::acquire_lock(&self.store).await?.contains_key(&id) {
+            return Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            )));
+        }
+
+        let mut submissions = Self::acquire_lock(&self.submissions).await?;
+        let now = Instant::now();
+        submissions.retain(|_, expires_at| *expires_at > now);
+        match submissions.entry((id, tx_hash_key)) {
+            Entry::Occupied(_) => Ok(false),
+            Entry::Vacant(entry) => {
+                entry.insert(now + ttl);
+                Ok(true)
+            }
+        }
+    }
+
+    async fn transfer_ownership(
+        &self,
+        id: String,
+        new_tenant: String,
+        actor: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        // The registry is consulted before taking the lock, as it may call out to
+        // another service
+        self.check_tenant(&new_tenant).await?;
+        let _config = self.config_locks.write(&id).await?;
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let relayer = store
+            .get_mut(&id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        relayer.transfer_ownership(new_tenant, actor, self.status_history_limit)?;
+        relayer.version += 1;
+        self.record_change(ChangeKind::Updated, &id).await;
+        Ok(relayer.clone())
+    }
+
+    fn is_persistent_storage(&self) -> bool {
+        false
+    }
+}
+
+#[async_trait]
+impl Repository<RelayerRepoModel, String> for InMemoryRelayerRepository {
+    async fn create(
+        &self,
+        mut relayer: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        relayer.normalize_id();
+        relayer.validate_id_format(&self.id_pattern)?;
+        if let Some(error) = relayer.create_validation_errors().into_iter().next() {
+            return Err(error);
+        }
+        self.check_policy_guardrails(&relayer.policies)?;
+        relayer.validate_metadata_size(self.metadata_size_limit)?;
+        self.check_signer_chain(&relayer).await?;
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let mut alias_index = Self::acquire_lock(&self.alias_index).await?;
+        if let Some(error) = Self::create_conflicts(&store, &alias_index, &relayer)
+            .into_iter()
+            .next()
+        {
+            return Err(error);
+        }
+        relayer.record_config_version(self.config_version_limit);
+        for alias in &relayer.aliases {
+            alias_index.insert(alias.clone(), relayer.id.clone());
+        }
+        Self::index_network(
+            &mut *Self::acquire_lock(&self.network_index).await?,
+            &relayer,
+        );
+        store.insert(relayer.id.clone(), relayer.clone());
+        self.record_change(ChangeKind::Created, &relayer.id).await;
+        Ok(relayer)
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        match store.get(&id) {
+            Some(relayer) => Ok(relayer.clone()),
+            None => Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            ))),
+        }
+    }
+    #[allow(clippy::map_entry)]
+    async fn update(
+        &self,
+        id: String,
+        relayer: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        relayer.validate_extra_webhook_urls()?;
+        relayer.validate_derivation_path()?;
+        relayer.validate_fee_account()?;
+        relayer.validate_signature_scheme()?;
+        relayer.validate_allowed_origins()?;
+        relayer.validate_signer_timeout()?;
+        relayer.policies.validate()?;
+        self.check_policy_guardrails(&relayer.policies)?;
+        relayer.validate_metadata_size(self.metadata_size_limit)?;
+        let _config = self.config_locks.write(&id).await?;
+        let mut store = Self::acquire_lock(&self.store).await?;
+        if store.contains_key(&id) {
+            // Ensure we update the existing entry
+            let mut updated_relayer = relayer;
+            updated_relayer.id = id.clone(); // Preserve original ID
+            updated_relayer.validate_aliases()?;
+            let mut alias_index = Self::acquire_lock(&self.alias_index).await?;
+            Self::check_alias_collisions(&store, &alias_index, &updated_relayer)?;
+            // Only a changed pairing is validated, so a standby whose primary was deleted
+            // can still be updated
+            if store.get(&id).map(|existing| &existing.standby_for)
+                != Some(&updated_relayer.standby_for)
+            {
+                Self::check_standby_pairing(&store, &updated_relayer)?;
+            }
+            Self::check_nonce_group(&store, &updated_relayer)?;
+            if store.get(&id).map(|existing| &existing.inherits_from)
+                != Some(&updated_relayer.inherits_from)
+            {
+                updated_relayer.inheritance_chain(|id| store.get(id).cloned())?;
+            }
+            updated_relayer.record_config_version(self.config_version_limit);
+            let mut network_index = Self::acquire_lock(&self.network_index).await?;
+            if let Some(existing) = store.get(&id) {
+                updated_relayer.version = existing.version + 1;
+                // Counters are not part of the caller's model, so a stale copy keeps them
+                updated_relayer.pending_count = existing.pending_count;
+                updated_relayer.spent_today = existing.spent_today;
+                updated_relayer.spend_window_start = existing.spend_window_start;
+                for alias in &existing.aliases {
+                    alias_index.remove(alias);
+                }
+                Self::unindex_network(&mut network_index, existing);
+            }
+            Self::index_network(&mut network_index, &updated_relayer);
+            for alias in &updated_relayer.aliases {
+                alias_index.insert(alias.clone(), id.clone());
+            }
+            store.insert(id.clone(), updated_relayer.clone());
+            self.record_change(ChangeKind::Updated, &id).await;
+            Ok(updated_relayer)
+        } else {
+            Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            )))
+        }
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        // Taken before `store`, in the order `create_idempotent` takes them
+        let mut keys = Self::acquire_lock(&self.idempotency_keys).await?;
+        let mut store = Self::acquire_lock(&self.store).await?;
+        if store.contains_key(&id) {
+            ensure_not_inherited(store.values(), &HashSet::from([id.as_str()]))?;
+        }
+        if let Some(removed) = store.remove(&id) {
+            let mut alias_index = Self::acquire_lock(&self.alias_index).await?;
+            for alias in &removed.aliases {
+                alias_index.remove(alias);
+            }
+            Self::unindex_network(
+                &mut *Self::acquire_lock(&self.network_index).await?,
+                &removed,
+            );
+            // A retried `create_idempotent` creates the relayer again
+            keys.retain(|_, (relayer_id, _)| *relayer_id != id);
+            self.record_change(ChangeKind::Deleted, &id).await;
+            Ok(())
+        } else {
+            Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            )))
+        }
+    }
+
+    async fn list_all(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(store.values().cloned().collect())
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+
+        let per_page = query.resolved_per_page();
+        if per_page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+
+        // The total and the page come from the same view of the store
+        let store = Self::acquire_lock(&self.store).await?;
+        let start = ((query.page - 1) * per_page) as usize;
+        let items = store
+            .values()
+            .skip(start)
+            .take(per_page as usize)
+            .cloned()
+            .collect();
+        Ok(PaginatedResult {
+            items,
+            total: store.len() as u64,
+            page: query.page,
+            per_page,
+        })
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        Ok(self.store.lock().await.len())
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(!store.is_empty())
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        let mut keys = Self::acquire_lock(&self.idempotency_keys).await?;
+        let mut store = Self::acquire_lock(&self.store).await?;
+        for id in store.keys() {
+            self.record_change(ChangeKind::Deleted, id).await;
+        }
+        store.clear();
+        keys.clear();
+        Self::acquire_lock(&self.alias_index).await?.clear();
+        Self::acquire_lock(&self.network_index).await?.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::{DEFAULT_EVM_EIP1559_ENABLED, DEFAULT_EVM_MIN_BALANCE};
+    use crate::models::{
+        AlertState, AlertThresholds, DecommissionState, EvmTxType, FeeAccount, FeeAccountKind,
+        GasBumpPolicy, GasOracleConfig, NetworkType, RelayerErrorKind, RelayerEvmPolicy,
+        RelayerState, RevertHandling, RpcConfig, RpcHealth, SignatureScheme, StuckTxAction,
+    };
 
     use super::*;
+    use crate::models::Capability;
+    use crate::models::{LocalSignerConfigStorage, SignerConfigStorage, SignerRepoModel};
+    use crate::repositories::{
+        MockAddressResolver, MockBalanceProvider, MockInflightChecker, SignerRepositoryStorage,
+        WhitelistOverlap,
+    };
+    use futures::future::ready;
+    use secrets::SecretVec;
+    use std::sync::Arc;
+
+    fn create_test_relayer(id: String) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: id.clone(),
+            name: format!("Relayer {}", id.clone()),
+            network: "TestNet".to_string(),
+            paused: false,
+            network_type: NetworkType::Evm,
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_price_cap: None,
+                whitelist_receivers: None,
+                eip1559_pricing: Some(false),
+                private_transactions: Some(false),
+                min_balance: Some(0),
+                gas_limit_estimation: Some(true),
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
+            }),
+            signer_id: "test".to_string(),
+            address: "0x".to_string(),
+            notification_id: None,
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_new_repository_is_empty() {
+        let repo = InMemoryRelayerRepository::new();
+        assert_eq!(repo.count().await.unwrap(), 0);
+    }
+
+    #[actix_web::test]
+    async fn test_add_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = create_test_relayer("test".to_string());
+
+        repo.create(relayer.clone()).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 1);
+
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(stored.id, relayer.id);
+        assert_eq!(stored.name, relayer.name);
+    }
+
+    #[actix_web::test]
+    async fn test_update_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+
+        repo.create(relayer.clone()).await.unwrap();
+
+        relayer.name = "Updated Name".to_string();
+        repo.update("test".to_string(), relayer.clone())
+            .await
+            .unwrap();
+
+        let updated = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(updated.name, "Updated Name");
+    }
+
+    #[actix_web::test]
+    async fn test_list_relayers() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer1 = create_test_relayer("test".to_string());
+        let relayer2 = create_test_relayer("test2".to_string());
+
+        repo.create(relayer1.clone()).await.unwrap();
+        repo.create(relayer2).await.unwrap();
+
+        let relayers = repo.list_all().await.unwrap();
+        assert_eq!(relayers.len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_list_active_relayers() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer1 = create_test_relayer("test".to_string());
+        let mut relayer2 = create_test_relayer("test2".to_string());
+
+        relayer2.paused = true;
+
+        repo.create(relayer1.clone()).await.unwrap();
+        repo.create(relayer2).await.unwrap();
+
+        let active_relayers = repo.list_active().await.unwrap();
+        assert_eq!(active_relayers.len(), 1);
+        assert_eq!(active_relayers[0].id, "test".to_string());
+    }
+
+    #[actix_web::test]
+    async fn test_update_nonexistent_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = create_test_relayer("test".to_string());
+
+        let result = repo.update("test".to_string(), relayer).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_get_nonexistent_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+
+        let result = repo.get_by_id("test".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+
+        // Add a relayer to the repository
+        let relayer_id = "test_relayer".to_string();
+        let initial_relayer = create_test_relayer(relayer_id.clone());
+
+        repo.create(initial_relayer.clone()).await.unwrap();
+
+        // Perform a partial update on the relayer
+        let update_req = UpdateRelayerRequest {
+            name: None,
+            paused: Some(true),
+            policies: None,
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        let updated_relayer = repo
+            .partial_update(relayer_id.clone(), update_req)
+            .await
+            .unwrap();
+
+        assert_eq!(updated_relayer.id, initial_relayer.id);
+        assert!(updated_relayer.paused);
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_multiple_fields() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer_id = "test_relayer".to_string();
+        repo.create(create_test_relayer(relayer_id.clone()))
+            .await
+            .unwrap();
+
+        let update_req = UpdateRelayerRequest {
+            name: Some("Renamed Relayer".to_string()),
+            paused: Some(true),
+            notification_id: Some(Some("notification-1".to_string())),
+            ..Default::default()
+        };
+
+        let updated_relayer = repo
+            .partial_update(relayer_id.clone(), update_req)
+            .await
+            .unwrap();
+
+        assert_eq!(updated_relayer.name, "Renamed Relayer");
+        assert!(updated_relayer.paused);
+        assert_eq!(
+            updated_relayer.notification_id,
+            Some("notification-1".to_string())
+        );
+        assert!(updated_relayer.updated_at.is_some());
+
+        let stored = repo.get_by_id(relayer_id).await.unwrap();
+        assert_eq!(stored.name, "Renamed Relayer");
+        assert_eq!(stored.updated_at, updated_relayer.updated_at);
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_name_only() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.notification_id = Some("notification-1".to_string());
+        repo.create(relayer.clone()).await.unwrap();
+
+        let updated = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    name: Some("Renamed Relayer".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated.name, "Renamed Relayer");
+        assert_eq!(updated.notification_id, relayer.notification_id);
+        assert_eq!(updated.policies, relayer.policies);
+
+        let result = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    name: Some(" ".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_sets_and_clears_notification_id() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let updated = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    notification_id: Some(Some("notification-1".to_string())),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.notification_id, Some("notification-1".to_string()));
+
+        let cleared = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    notification_id: Some(None),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(cleared.notification_id, None);
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(stored.notification_id, None);
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_rejects_duplicate_name() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("a".to_string()))
+            .await
+            .unwrap();
+        repo.create(create_test_relayer("b".to_string()))
+            .await
+            .unwrap();
+
+        let result = repo
+            .partial_update(
+                "b".to_string(),
+                UpdateRelayerRequest {
+                    name: Some("Relayer a".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert_eq!(
+            repo.get_by_id("b".to_string()).await.unwrap().name,
+            "Relayer b"
+        );
+
+        // Keeping its own name is not a conflict
+        let unchanged = repo
+            .partial_update(
+                "a".to_string(),
+                UpdateRelayerRequest {
+                    name: Some("Relayer a".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(unchanged.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_rejects_unknown_notification() {
+        let repo = InMemoryRelayerRepository::new().with_notification_registry(Arc::new(
+            KnownNotifications(HashSet::from(["notification-1".to_string()])),
+        ));
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let result = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    notification_id: Some(Some("missing".to_string())),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+
+        let updated = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    notification_id: Some(Some("notification-1".to_string())),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.notification_id, Some("notification-1".to_string()));
+
+        // Clearing the notification needs no lookup
+        let cleared = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    notification_id: Some(None),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(cleared.notification_id, None);
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_empty_patch_is_noop() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer_id = "test_relayer".to_string();
+        let initial_relayer = create_test_relayer(relayer_id.clone());
+        repo.create(initial_relayer.clone()).await.unwrap();
+
+        let result = repo
+            .partial_update(relayer_id.clone(), UpdateRelayerRequest::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.name, initial_relayer.name);
+        assert_eq!(result.paused, initial_relayer.paused);
+        assert!(result.updated_at.is_none());
+
+        // Setting a field to its current value is not a change either
+        let unchanged = repo
+            .partial_update(
+                relayer_id,
+                UpdateRelayerRequest {
+                    paused: Some(false),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert!(unchanged.updated_at.is_none());
+        assert_eq!(unchanged.version, initial_relayer.version);
+        assert_eq!(repo.changes_since(0).await.unwrap().len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_partial_update_empty_patch_nonexistent_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+
+        let result = repo
+            .partial_update("missing".to_string(), UpdateRelayerRequest::default())
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_disable_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+
+        // Add a relayer to the repository
+        let relayer_id = "test_relayer".to_string();
+        let initial_relayer = create_test_relayer(relayer_id.clone());
+
+        repo.create(initial_relayer.clone()).await.unwrap();
+
+        // Disable the relayer
+        let disabled_relayer = repo
+            .disable_relayer(
+                relayer_id.clone(),
+                DisabledReason::BalanceCheckFailed("test reason".to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(disabled_relayer.id, initial_relayer.id);
+        assert!(disabled_relayer.system_disabled);
+        assert_eq!(
+            disabled_relayer.disabled_reason,
+            Some(DisabledReason::BalanceCheckFailed(
+                "test reason".to_string()
+            ))
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_enable_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+
+        // Add a relayer to the repository
+        let relayer_id = "test_relayer".to_string();
+        let mut initial_relayer = create_test_relayer(relayer_id.clone());
+
+        initial_relayer.system_disabled = true;
+
+        repo.create(initial_relayer.clone()).await.unwrap();
+
+        // Enable the relayer
+        let enabled_relayer = repo.enable_relayer(relayer_id.clone()).await.unwrap();
+
+        assert_eq!(enabled_relayer.id, initial_relayer.id);
+        assert!(!enabled_relayer.system_disabled);
+    }
+
+    #[actix_web::test]
+    async fn test_update_policy() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = create_test_relayer("test".to_string());
+
+        repo.create(relayer.clone()).await.unwrap();
+
+        // Create a new policy to update
+        let new_policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(50000000000),
+            whitelist_receivers: Some(vec!["0x1234".to_string()]),
+            eip1559_pricing: Some(true),
+            private_transactions: Some(true),
+            min_balance: Some(1000000),
+            gas_limit_estimation: Some(true),
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
+        });
+
+        // Update the policy
+        let updated_relayer = repo
+            .update_policy("test".to_string(), new_policy.clone())
+            .await
+            .unwrap();
+
+        // Verify the policy was updated
+        match updated_relayer.policies {
+            RelayerNetworkPolicy::Evm(policy) => {
+                assert_eq!(policy.gas_price_cap, Some(50000000000));
+                assert_eq!(policy.whitelist_receivers, Some(vec!["0x1234".to_string()]));
+                assert_eq!(policy.eip1559_pricing, Some(true));
+                assert!(policy.private_transactions.unwrap_or(false));
+                assert_eq!(policy.min_balance, Some(1000000));
+            }
+            _ => panic!("Unexpected policy type"),
+        }
+    }
+
+    // test has_entries
+    #[actix_web::test]
+    async fn test_has_entries() {
+        let repo = InMemoryRelayerRepository::new();
+        assert!(!repo.has_entries().await.unwrap());
+
+        let relayer = create_test_relayer("test".to_string());
+
+        repo.create(relayer.clone()).await.unwrap();
+        assert!(repo.has_entries().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_drop_all_entries() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = create_test_relayer("test".to_string());
+
+        repo.create(relayer.clone()).await.unwrap();
+
+        assert!(repo.has_entries().await.unwrap());
+
+        repo.drop_all_entries().await.unwrap();
+        assert!(!repo.has_entries().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_signer_id() {
+        let repo = InMemoryRelayerRepository::new();
+
+        // Create test relayers with different signers
+        let relayer1 = RelayerRepoModel {
+            id: "relayer-1".to_string(),
+            name: "Relayer 1".to_string(),
+            network: "ethereum".to_string(),
+            paused: false,
+            network_type: NetworkType::Evm,
+            signer_id: "signer-alpha".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0x1111".to_string(),
+            notification_id: None,
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        let relayer2 = RelayerRepoModel {
+            id: "relayer-2".to_string(),
+            name: "Relayer 2".to_string(),
+            network: "polygon".to_string(),
+            paused: true,
+            network_type: NetworkType::Evm,
+            signer_id: "signer-alpha".to_string(), // Same signer as relayer1
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0x2222".to_string(),
+            notification_id: None,
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        let relayer3 = RelayerRepoModel {
+            id: "relayer-3".to_string(),
+            name: "Relayer 3".to_string(),
+            network: "solana".to_string(),
+            paused: false,
+            network_type: NetworkType::Solana,
+            signer_id: "signer-beta".to_string(), // Different signer
+            policies: RelayerNetworkPolicy::Solana(crate::models::RelayerSolanaPolicy::default()),
+            address: "solana-addr".to_string(),
+            notification_id: None,
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        let relayer4 = RelayerRepoModel {
+            id: "relayer-4".to_string(),
+            name: "Relayer 4".to_string(),
+            network: "stellar".to_string(),
+            paused: false,
+            network_type: NetworkType::Stellar,
+            signer_id: "signer-alpha".to_string(), // Same signer as relayer1 and relayer2
+            policies: RelayerNetworkPolicy::Stellar(crate::models::RelayerStellarPolicy::default()),
+            address: "stellar-addr".to_string(),
+            notification_id: Some("notification-1".to_string()),
+            system_disabled: true,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        // Add all relayers to the repository
+        repo.create(relayer1).await.unwrap();
+        repo.create(relayer2).await.unwrap();
+        repo.create(relayer3).await.unwrap();
+        repo.create(relayer4).await.unwrap();
+
+        // Test: Find relayers with signer-alpha (should return 3: relayer-1, relayer-2, relayer-4)
+        let relayers_with_alpha = repo.list_by_signer_id("signer-alpha").await.unwrap();
+        assert_eq!(relayers_with_alpha.len(), 3);
+
+        let alpha_ids: Vec<String> = relayers_with_alpha.iter().map(|r| r.id.clone()).collect();
+        assert!(alpha_ids.contains(&"relayer-1".to_string()));
+        assert!(alpha_ids.contains(&"relayer-2".to_string()));
+        assert!(alpha_ids.contains(&"relayer-4".to_string()));
+        assert!(!alpha_ids.contains(&"relayer-3".to_string()));
+
+        // Verify the relayers have different states (paused, system_disabled)
+        let relayer2_found = relayers_with_alpha
+            .iter()
+            .find(|r| r.id == "relayer-2")
+            .unwrap();
+        let relayer4_found = relayers_with_alpha
+            .iter()
+            .find(|r| r.id == "relayer-4")
+            .unwrap();
+        assert!(relayer2_found.paused); // Should be paused
+        assert!(relayer4_found.system_disabled); // Should be disabled
+
+        // Test: Find relayers with signer-beta (should return 1: relayer-3)
+        let relayers_with_beta = repo.list_by_signer_id("signer-beta").await.unwrap();
+        assert_eq!(relayers_with_beta.len(), 1);
+        assert_eq!(relayers_with_beta[0].id, "relayer-3");
+        assert_eq!(relayers_with_beta[0].network_type, NetworkType::Solana);
+
+        // Test: Find relayers with non-existent signer (should return empty)
+        let relayers_with_gamma = repo.list_by_signer_id("signer-gamma").await.unwrap();
+        assert_eq!(relayers_with_gamma.len(), 0);
+
+        // Test: Find relayers with empty signer ID (should return empty)
+        let relayers_with_empty = repo.list_by_signer_id("").await.unwrap();
+        assert_eq!(relayers_with_empty.len(), 0);
+
+        // Test: Verify total count hasn't changed
+        assert_eq!(repo.count().await.unwrap(), 4);
+
+        // Test: Remove one relayer and verify list_by_signer_id updates correctly
+        repo.delete_by_id("relayer-2".to_string()).await.unwrap();
+
+        let relayers_with_alpha_after_delete =
+            repo.list_by_signer_id("signer-alpha").await.unwrap();
+        assert_eq!(relayers_with_alpha_after_delete.len(), 2); // Should now be 2 instead of 3
+
+        let alpha_ids_after: Vec<String> = relayers_with_alpha_after_delete
+            .iter()
+            .map(|r| r.id.clone())
+            .collect();
+        assert!(alpha_ids_after.contains(&"relayer-1".to_string()));
+        assert!(!alpha_ids_after.contains(&"relayer-2".to_string())); // Deleted
+        assert!(alpha_ids_after.contains(&"relayer-4".to_string()));
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_notification_id() {
+        let repo = InMemoryRelayerRepository::new();
+
+        // Create test relayers with different notifications
+        let relayer1 = RelayerRepoModel {
+            id: "relayer-1".to_string(),
+            name: "Relayer 1".to_string(),
+            network: "ethereum".to_string(),
+            paused: false,
+            network_type: NetworkType::Evm,
+            signer_id: "test-signer".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0x1111".to_string(),
+            notification_id: Some("notification-alpha".to_string()),
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        let relayer2 = RelayerRepoModel {
+            id: "relayer-2".to_string(),
+            name: "Relayer 2".to_string(),
+            network: "polygon".to_string(),
+            paused: true,
+            network_type: NetworkType::Evm,
+            signer_id: "test-signer".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0x2222".to_string(),
+            notification_id: Some("notification-alpha".to_string()), // Same notification as relayer1
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        let relayer3 = RelayerRepoModel {
+            id: "relayer-3".to_string(),
+            name: "Relayer 3".to_string(),
+            network: "solana".to_string(),
+            paused: false,
+            network_type: NetworkType::Solana,
+            signer_id: "test-signer".to_string(),
+            policies: RelayerNetworkPolicy::Solana(crate::models::RelayerSolanaPolicy::default()),
+            address: "solana-addr".to_string(),
+            notification_id: Some("notification-beta".to_string()), // Different notification
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        let relayer4 = RelayerRepoModel {
+            id: "relayer-4".to_string(),
+            name: "Relayer 4".to_string(),
+            network: "stellar".to_string(),
+            paused: false,
+            network_type: NetworkType::Stellar,
+            signer_id: "test-signer".to_string(),
+            policies: RelayerNetworkPolicy::Stellar(crate::models::RelayerStellarPolicy::default()),
+            address: "stellar-addr".to_string(),
+            notification_id: None, // No notification
+            system_disabled: true,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        let relayer5 = RelayerRepoModel {
+            id: "relayer-5".to_string(),
+            name: "Relayer 5".to_string(),
+            network: "bsc".to_string(),
+            paused: false,
+            network_type: NetworkType::Evm,
+            signer_id: "test-signer".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            address: "0x5555".to_string(),
+            notification_id: Some("notification-alpha".to_string()), // Same notification as relayer1 and relayer2
+            system_disabled: false,
+            custom_rpc_urls: None,
+            ..Default::default()
+        };
+
+        // Add all relayers to the repository
+        repo.create(relayer1).await.unwrap();
+        repo.create(relayer2).await.unwrap();
+        repo.create(relayer3).await.unwrap();
+        repo.create(relayer4).await.unwrap();
+        repo.create(relayer5).await.unwrap();
+
+        // Test: Find relayers with notification-alpha (should return 3: relayer-1, relayer-2, relayer-5)
+        let relayers_with_alpha = repo
+            .list_by_notification_id("notification-alpha")
+            .await
+            .unwrap();
+        assert_eq!(relayers_with_alpha.len(), 3);
+
+        let alpha_ids: Vec<String> = relayers_with_alpha.iter().map(|r| r.id.clone()).collect();
+        assert!(alpha_ids.contains(&"relayer-1".to_string()));
+        assert!(alpha_ids.contains(&"relayer-2".to_string()));
+        assert!(alpha_ids.contains(&"relayer-5".to_string()));
+        assert!(!alpha_ids.contains(&"relayer-3".to_string()));
+        assert!(!alpha_ids.contains(&"relayer-4".to_string()));
+
+        // Verify the relayers have different states (paused, different networks)
+        let relayer2_found = relayers_with_alpha
+            .iter()
+            .find(|r| r.id == "relayer-2")
+            .unwrap();
+        let relayer5_found = relayers_with_alpha
+            .iter()
+            .find(|r| r.id == "relayer-5")
+            .unwrap();
+        assert!(relayer2_found.paused); // Should be paused
+        assert_eq!(relayer5_found.network, "bsc"); // Should be on BSC network
+
+        // Test: Find relayers with notification-beta (should return 1: relayer-3)
+        let relayers_with_beta = repo
+            .list_by_notification_id("notification-beta")
+            .await
+            .unwrap();
+        assert_eq!(relayers_with_beta.len(), 1);
+        assert_eq!(relayers_with_beta[0].id, "relayer-3");
+        assert_eq!(relayers_with_beta[0].network_type, NetworkType::Solana);
+
+        // Test: Find relayers with non-existent notification (should return empty)
+        let relayers_with_gamma = repo
+            .list_by_notification_id("notification-gamma")
+            .await
+            .unwrap();
+        assert_eq!(relayers_with_gamma.len(), 0);
+
+        // Test: Find relayers with empty string notification (should return empty)
+        let relayers_with_empty = repo.list_by_notification_id("").await.unwrap();
+        assert_eq!(relayers_with_empty.len(), 0);
+
+        // Test: Verify total count hasn't changed
+        assert_eq!(repo.count().await.unwrap(), 5);
+
+        // Test: Remove one relayer and verify list_by_notification_id updates correctly
+        repo.delete_by_id("relayer-2".to_string()).await.unwrap();
+
+        let relayers_with_alpha_after_delete = repo
+            .list_by_notification_id("notification-alpha")
+            .await
+            .unwrap();
+        assert_eq!(relayers_with_alpha_after_delete.len(), 2); // Should now be 2 instead of 3
+
+        let alpha_ids_after: Vec<String> = relayers_with_alpha_after_delete
+            .iter()
+            .map(|r| r.id.clone())
+            .collect();
+        assert!(alpha_ids_after.contains(&"relayer-1".to_string()));
+        assert!(!alpha_ids_after.contains(&"relayer-2".to_string())); // Deleted
+        assert!(alpha_ids_after.contains(&"relayer-5".to_string()));
+
+        // Test: Update a relayer's notification and verify the lists update correctly
+        let mut updated_relayer = repo.get_by_id("relayer-5".to_string()).await.unwrap();
+        updated_relayer.notification_id = Some("notification-beta".to_string());
+        repo.update("relayer-5".to_string(), updated_relayer)
+            .await
+            .unwrap();
+
+        // Check notification-alpha list again (should now have only relayer-1)
+        let relayers_with_alpha_final = repo
+            .list_by_notification_id("notification-alpha")
+            .await
+            .unwrap();
+        assert_eq!(relayers_with_alpha_final.len(), 1);
+        assert_eq!(relayers_with_alpha_final[0].id, "relayer-1");
+
+        // Check notification-beta list (should now have relayer-3 and relayer-5)
+        let relayers_with_beta_final = repo
+            .list_by_notification_id("notification-beta")
+            .await
+            .unwrap();
+        assert_eq!(relayers_with_beta_final.len(), 2);
+        let beta_ids_final: Vec<String> = relayers_with_beta_final
+            .iter()
+            .map(|r| r.id.clone())
+            .collect();
+        assert!(beta_ids_final.contains(&"relayer-3".to_string()));
+        assert!(beta_ids_final.contains(&"relayer-5".to_string()));
+    }
+
+    #[actix_web::test]
+    async fn test_is_empty_tracks_create_and_delete() {
+        let repo = InMemoryRelayerRepository::new();
+        assert!(repo.is_empty().await.unwrap());
+        assert!(!repo.has_entries().await.unwrap());
+
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        assert!(!repo.is_empty().await.unwrap());
+        assert!(repo.has_entries().await.unwrap());
+
+        repo.delete_by_id("test".to_string()).await.unwrap();
+        assert!(repo.is_empty().await.unwrap());
+        assert!(!repo.has_entries().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_create_relayer_with_extra_webhook_urls() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.extra_webhook_urls = Some(vec!["https://example.com/hook".to_string()]);
+
+        let created = repo.create(relayer).await.unwrap();
+        assert_eq!(
+            created.extra_webhook_urls(),
+            &["https://example.com/hook".to_string()]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_create_relayer_rejects_invalid_extra_webhook_url() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.extra_webhook_urls = Some(vec!["ws://example.com/hook".to_string()]);
+
+        let result = repo.create(relayer.clone()).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(repo.count().await.unwrap(), 0);
+
+        // Updates are validated as well
+        relayer.extra_webhook_urls = None;
+        repo.create(relayer.clone()).await.unwrap();
+        relayer.extra_webhook_urls = Some(vec!["ws://example.com/hook".to_string()]);
+        let result = repo.update("test".to_string(), relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    fn create_test_template() -> RelayerTemplate {
+        RelayerTemplate {
+            name: "Mainnet EVM".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: NetworkType::Evm,
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_price_cap: Some(100_000_000_000),
+                whitelist_receivers: Some(vec!["0xabc".to_string()]),
+                ..Default::default()
+            }),
+            notification_id: Some("notification-1".to_string()),
+            custom_rpc_urls: None,
+            extra_webhook_urls: None,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_instantiate_from_template() {
+        let repo = InMemoryRelayerRepository::new();
+        let template = create_test_template();
+
+        let first = repo
+            .instantiate(
+                &template,
+                "relayer-1".to_string(),
+                "signer-1".to_string(),
+                "0x1".to_string(),
+            )
+            .await
+            .unwrap();
+        let second = repo
+            .instantiate(
+                &template,
+                "relayer-2".to_string(),
+                "signer-2".to_string(),
+                "0x2".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(first.policies, second.policies);
+        assert_eq!(first.policies, template.policies);
+        assert_eq!(first.notification_id, second.notification_id);
+        assert_eq!(first.network, second.network);
+
+        assert_ne!(first.id, second.id);
+        assert_ne!(first.signer_id, second.signer_id);
+        assert_ne!(first.address, second.address);
+        assert_eq!(repo.count().await.unwrap(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_instantiate_from_template_is_validated_like_create() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut template = create_test_template();
+
+        repo.instantiate(
+            &template,
+            "relayer-1".to_string(),
+            "signer-1".to_string(),
+            "0x1".to_string(),
+        )
+        .await
+        .unwrap();
+
+        // Duplicate ids are rejected
+        let result = repo
+            .instantiate(
+                &template,
+                "relayer-1".to_string(),
+                "signer-2".to_string(),
+                "0x2".to_string(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+
+        template.extra_webhook_urls = Some(vec!["ftp://example.com".to_string()]);
+        let result = repo
+            .instantiate(
+                &template,
+                "relayer-3".to_string(),
+                "signer-3".to_string(),
+                "0x3".to_string(),
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_evaluate_balance_alert_hysteresis() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            low_balance_alerting: Some(AlertThresholds {
+                warn_below: 1_000,
+                clear_above: 2_000,
+            }),
+            ..Default::default()
+        });
+        repo.create(relayer).await.unwrap();
+
+        let steps = [
+            (1_500, None, AlertState::Normal),
+            (999, Some(AlertTransition::Fired), AlertState::Alerting),
+            (500, None, AlertState::Alerting),
+            (1_500, None, AlertState::Alerting),
+            (2_001, Some(AlertTransition::Cleared), AlertState::Normal),
+            (1_500, None, AlertState::Normal),
+        ];
+        for (balance, expected_transition, expected_state) in steps {
+            let transition = repo
+                .evaluate_balance_alert("test".to_string(), balance)
+                .await
+                .unwrap();
+            assert_eq!(transition, expected_transition, "balance {balance}");
+            let stored = repo.get_by_id("test".to_string()).await.unwrap();
+            assert_eq!(stored.alert_state, expected_state, "balance {balance}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_evaluate_balance_alert_nonexistent_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+
+        let result = repo.evaluate_balance_alert("missing".to_string(), 0).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    fn create_test_relayer_for_tenant(id: &str, tenant_id: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            tenant_id: tenant_id.to_string(),
+            ..create_test_relayer(id.to_string())
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_create_relayer_requires_tenant_id() {
+        let repo = InMemoryRelayerRepository::new();
+
+        let result = repo
+            .create(create_test_relayer_for_tenant("test", ""))
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(repo.count().await.unwrap(), 0);
+    }
+
+    #[actix_web::test]
+    async fn test_get_by_id_scoped_cross_tenant_not_found() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer_for_tenant("relayer-a", "tenant-a"))
+            .await
+            .unwrap();
+
+        let own = repo
+            .get_by_id_scoped("relayer-a".to_string(), "tenant-a")
+            .await
+            .unwrap();
+        assert_eq!(own.id, "relayer-a");
+
+        let other = repo
+            .get_by_id_scoped("relayer-a".to_string(), "tenant-b")
+            .await;
+        assert!(matches!(other, Err(RepositoryError::NotFound(_))));
+
+        let missing = repo
+            .get_by_id_scoped("missing".to_string(), "tenant-a")
+            .await;
+        assert!(matches!(missing, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_tenant_is_isolated() {
+        let repo = InMemoryRelayerRepository::new();
+        for (id, tenant) in [
+            ("relayer-a1", "tenant-a"),
+            ("relayer-a2", "tenant-a"),
+            ("relayer-b1", "tenant-b"),
+        ] {
+            repo.create(create_test_relayer_for_tenant(id, tenant))
+                .await
+                .unwrap();
+        }
+        let query = PaginationQuery {
+            page: 1,
+            per_page: Some(10),
+        };
+
+        let tenant_a = repo
+            .list_by_tenant("tenant-a", query.clone())
+            .await
+            .unwrap();
+        assert_eq!(tenant_a.total, 2);
+        assert!(tenant_a.items.iter().all(|r| r.tenant_id == "tenant-a"));
+
+        let tenant_b = repo
+            .list_by_tenant("tenant-b", query.clone())
+            .await
+            .unwrap();
+        assert_eq!(tenant_b.total, 1);
+        assert_eq!(tenant_b.items[0].id, "relayer-b1");
+
+        let tenant_c = repo.list_by_tenant("tenant-c", query).await.unwrap();
+        assert_eq!(tenant_c.total, 0);
+        assert!(tenant_c.is_empty());
+        assert_eq!(tenant_c.total_pages(), 0);
+        assert!(!tenant_c.has_next());
+        assert!(!tenant_c.has_prev());
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_tenant_rejects_page_zero() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer_for_tenant("relayer-a1", "tenant-a"))
+            .await
+            .unwrap();
+
+        let result = repo
+            .list_by_tenant(
+                "tenant-a",
+                PaginationQuery {
+                    page: 0,
+                    per_page: Some(10),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_list_paginated_page_beyond_last() {
+        let repo = InMemoryRelayerRepository::new();
+        for id in ["relayer-1", "relayer-2", "relayer-3"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let result = repo
+            .list_paginated(PaginationQuery {
+                page: 3,
+                per_page: Some(2),
+            })
+            .await
+            .unwrap();
+
+        assert!(result.is_empty());
+        assert_eq!(result.total, 3);
+        assert_eq!(result.total_pages(), 2);
+        assert!(!result.has_next());
+        assert!(result.has_prev());
+    }
+
+    #[actix_web::test]
+    async fn test_list_paginated_rejects_zero_page_and_per_page() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("relayer-1".to_string()))
+            .await
+            .unwrap();
+
+        for (page, per_page) in [(0, Some(10)), (1, Some(0))] {
+            let result = repo
+                .list_paginated(PaginationQuery { page, per_page })
+                .await;
+            assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_snapshot_aggregates_agree() {
+        let repo = InMemoryRelayerRepository::new();
+        for (id, signer_id) in [
+            ("relayer-1", "signer-1"),
+            ("relayer-2", "signer-1"),
+            ("relayer-3", "signer-2"),
+        ] {
+            let mut relayer = create_test_relayer(id.to_string());
+            relayer.signer_id = signer_id.to_string();
+            repo.create(relayer).await.unwrap();
+        }
+
+        let snapshot = repo.snapshot().await.unwrap();
+
+        // Changes after the snapshot was taken are not reflected in it
+        repo.create(create_test_relayer("relayer-4".to_string()))
+            .await
+            .unwrap();
+
+        let summary = snapshot.summary();
+        let groups = snapshot.group_by_signer();
+        let grouped_total: usize = groups.values().map(Vec::len).sum();
+
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.total, grouped_total);
+        assert_eq!(groups["signer-1"], vec!["relayer-1", "relayer-2"]);
+    }
+
+    #[actix_web::test]
+    async fn test_schedule_disable_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let at = Utc::now() + chrono::Duration::hours(1);
+        let scheduled = repo
+            .schedule_disable("test".to_string(), at, "maintenance".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(scheduled.scheduled_disable_at, Some(at));
+        assert_eq!(
+            scheduled.scheduled_disable_reason,
+            Some("maintenance".to_string())
+        );
+        // Still operational until the sweeper runs
+        assert!(!scheduled.system_disabled);
+        assert!(scheduled.is_operational());
+    }
+
+    #[actix_web::test]
+    async fn test_list_due_scheduled_disables() {
+        let repo = InMemoryRelayerRepository::new();
+        let at = Utc::now() + chrono::Duration::hours(1);
+        for id in ["scheduled", "unscheduled"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+        repo.schedule_disable("scheduled".to_string(), at, "maintenance".to_string())
+            .await
+            .unwrap();
+
+        let before = repo
+            .list_due_scheduled_disables(at - chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+        assert!(before.is_empty());
+
+        let at_time = repo.list_due_scheduled_disables(at).await.unwrap();
+        assert_eq!(at_time.len(), 1);
+        assert_eq!(at_time[0].id, "scheduled");
+
+        let after = repo
+            .list_due_scheduled_disables(at + chrono::Duration::minutes(5))
+            .await
+            .unwrap();
+        assert_eq!(after.len(), 1);
+
+        // Actioning the schedule clears it
+        repo.disable_relayer(
+            "scheduled".to_string(),
+            DisabledReason::Scheduled("maintenance".to_string()),
+        )
+        .await
+        .unwrap();
+        let after_disable = repo.list_due_scheduled_disables(at).await.unwrap();
+        assert!(after_disable.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_cancel_scheduled_disable() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        let at = Utc::now() + chrono::Duration::hours(1);
+        repo.schedule_disable("test".to_string(), at, "maintenance".to_string())
+            .await
+            .unwrap();
+
+        let cancelled = repo
+            .cancel_scheduled_disable("test".to_string())
+            .await
+            .unwrap();
+        assert!(cancelled.scheduled_disable_at.is_none());
+        assert!(cancelled.scheduled_disable_reason.is_none());
+
+        let due = repo.list_due_scheduled_disables(at).await.unwrap();
+        assert!(due.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_find_drifted() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("in-sync".to_string()))
+            .await
+            .unwrap();
+        repo.create(create_test_relayer("drifted".to_string()))
+            .await
+            .unwrap();
+        // Runtime-only changes on the stored relayer do not count as drift
+        repo.disable_relayer(
+            "in-sync".to_string(),
+            DisabledReason::BalanceCheckFailed("low balance".to_string()),
+        )
+        .await
+        .unwrap();
+
+        let mut drifted = create_test_relayer("drifted".to_string());
+        drifted.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(100_000_000_000),
+            ..RelayerEvmPolicy::default()
+        });
+        let desired = vec![
+            create_test_relayer("in-sync".to_string()),
+            drifted,
+            create_test_relayer("missing".to_string()),
+        ];
+
+        let mut result = repo.find_drifted(&desired).await.unwrap();
+        result.sort();
+        assert_eq!(result, vec!["drifted".to_string(), "missing".to_string()]);
+    }
+
+    #[actix_web::test]
+    async fn test_status_history_records_transitions_in_order() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        repo.disable_relayer(
+            "test".to_string(),
+            DisabledReason::RpcValidationFailed("rpc down".to_string()),
+        )
+        .await
+        .unwrap();
+        repo.enable_relayer("test".to_string()).await.unwrap();
+        repo.quarantine(
+            "test".to_string(),
+            Utc::now() + chrono::Duration::minutes(10),
+            "flapping".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let history = repo.get_status_history("test".to_string()).await.unwrap();
+        let transitions: Vec<(RelayerState, RelayerState)> = history
+            .iter()
+            .map(|change| (change.from, change.to))
+            .collect();
+        assert_eq!(
+            transitions,
+            vec![
+                (RelayerState::Enabled, RelayerState::Disabled),
+                (RelayerState::Disabled, RelayerState::Enabled),
+                (RelayerState::Enabled, RelayerState::Quarantined),
+            ]
+        );
+        assert_eq!(
+            history[0].reason,
+            Some(DisabledReason::RpcValidationFailed("rpc down".to_string()))
+        );
+        assert!(history[1].reason.is_none());
+        assert!(history.windows(2).all(|pair| pair[0].at <= pair[1].at));
+    }
+
+    #[actix_web::test]
+    async fn test_status_history_is_capped() {
+        let repo = InMemoryRelayerRepository::new().with_status_history_limit(3);
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        for _ in 0..3 {
+            repo.disable_relayer(
+                "test".to_string(),
+                DisabledReason::BalanceCheckFailed("low balance".to_string()),
+            )
+            .await
+            .unwrap();
+            repo.enable_relayer("test".to_string()).await.unwrap();
+        }
+
+        let history = repo.get_status_history("test".to_string()).await.unwrap();
+        assert_eq!(history.len(), 3);
+        // The oldest transitions are dropped first
+        assert_eq!(history[0].to, RelayerState::Enabled);
+        assert_eq!(history[1].to, RelayerState::Disabled);
+        assert_eq!(history[2].to, RelayerState::Enabled);
+    }
+
+    #[actix_web::test]
+    async fn test_get_status_history_not_found() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo.get_status_history("missing".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_validate_all_reports_only_invalid_relayers() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("valid".to_string()))
+            .await
+            .unwrap();
+        let mut invalid = create_test_relayer("invalid".to_string());
+        invalid.network_type = NetworkType::Stellar;
+        repo.create(invalid).await.unwrap();
+
+        let offenders = repo.validate_all().await.unwrap();
+        assert_eq!(offenders.len(), 1);
+        let (id, errors) = &offenders[0];
+        assert_eq!(id, "invalid");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            RelayerValidationError::InvalidPolicy(_)
+        ));
+
+        // Validation does not mutate the stored relayers
+        assert_eq!(repo.count().await.unwrap(), 2);
+    }
+
+    fn create_pool_relayer(id: &str, pool: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            pool: Some(pool.to_string()),
+            ..create_test_relayer(id.to_string())
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_pick_sticky_is_stable_for_session_key() {
+        let repo = InMemoryRelayerRepository::new();
+        for i in 0..4 {
+            repo.create(create_pool_relayer(&format!("pool-{i}"), "dapp"))
+                .await
+                .unwrap();
+        }
+        repo.create(create_pool_relayer("other", "other-pool"))
+            .await
+            .unwrap();
+
+        let first = repo
+            .pick_sticky("dapp".to_string(), "user-1", StickyStrategy::ConsistentHash)
+            .await
+            .unwrap();
+        assert_eq!(first.pool.as_deref(), Some("dapp"));
+        for _ in 0..5 {
+            let again = repo
+                .pick_sticky("dapp".to_string(), "user-1", StickyStrategy::ConsistentHash)
+                .await
+                .unwrap();
+            assert_eq!(again.id, first.id);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_pick_sticky_falls_back_when_primary_paused() {
+        let repo = InMemoryRelayerRepository::new();
+        for i in 0..4 {
+            repo.create(create_pool_relayer(&format!("pool-{i}"), "dapp"))
+                .await
+                .unwrap();
+        }
+
+        let primary = repo
+            .pick_sticky("dapp".to_string(), "user-1", StickyStrategy::Rendezvous)
+            .await
+            .unwrap();
+        repo.partial_update(
+            primary.id.clone(),
+            UpdateRelayerRequest {
+                paused: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let fallback = repo
+            .pick_sticky("dapp".to_string(), "user-1", StickyStrategy::Rendezvous)
+            .await
+            .unwrap();
+        assert_ne!(fallback.id, primary.id);
+        assert!(!fallback.paused);
+        // The fallback is stable as well
+        let again = repo
+            .pick_sticky("dapp".to_string(), "user-1", StickyStrategy::Rendezvous)
+            .await
+            .unwrap();
+        assert_eq!(again.id, fallback.id);
+    }
+
+    #[actix_web::test]
+    async fn test_pick_sticky_unknown_pool() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        let result = repo
+            .pick_sticky("missing".to_string(), "user-1", StickyStrategy::default())
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_rekey_updates_signer_and_address_together() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let new_address = "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string();
+        let rekeyed = repo
+            .rekey(
+                "test".to_string(),
+                "new-signer".to_string(),
+                new_address.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(rekeyed.signer_id, "new-signer");
+        assert_eq!(rekeyed.address, new_address);
+
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(stored.signer_id, "new-signer");
+        assert_eq!(stored.address, new_address);
+    }
+
+    fn resolver_returning(address: &str) -> MockAddressResolver {
+        let address = address.to_string();
+        let mut resolver = MockAddressResolver::new();
+        resolver
+            .expect_resolve_address()
+            .returning(move |_| Box::pin(ready(Ok(address.clone()))));
+        resolver
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_funding_urgency_orders_by_deficit() {
+        let repo = InMemoryRelayerRepository::new();
+        for (id, min_balance) in [
+            ("small-deficit", Some(1_000)),
+            ("large-deficit", Some(5_000)),
+            ("funded", Some(1_000)),
+            ("no-minimum", None),
+            ("unreachable", Some(1_000)),
+        ] {
+            let mut relayer = create_test_relayer(id.to_string());
+            if let RelayerNetworkPolicy::Evm(policy) = &mut relayer.policies {
+                policy.min_balance = min_balance;
+            }
+            repo.create(relayer).await.unwrap();
+        }
+
+        let mut balances = MockBalanceProvider::new();
+        balances.expect_balance().returning(|relayer| {
+            Box::pin(ready(match relayer.id.as_str() {
+                "small-deficit" => Ok(900),
+                "large-deficit" => Ok(1_000),
+                "funded" => Ok(1_000),
+                "no-minimum" => Ok(0),
+                _ => Err(RepositoryError::ConnectionError("rpc down".to_string())),
+            }))
+        });
+
+        let urgent = repo.list_by_funding_urgency(&balances).await.unwrap();
+        let urgent: Vec<(&str, i128)> = urgent
+            .iter()
+            .map(|(relayer, deficit)| (relayer.id.as_str(), *deficit))
+            .collect();
+        assert_eq!(
+            urgent,
+            vec![("large-deficit", 4_000), ("small-deficit", 100)]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_verify_address_matches_signer() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(RelayerRepoModel {
+            address: "0x742d35cc6634c0532925a3b8d8c2e48a73f6ba2e".to_string(),
+            ..create_test_relayer("test".to_string())
+        })
+        .await
+        .unwrap();
+
+        // EVM addresses match regardless of checksum casing
+        let matching = resolver_returning("0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E");
+        assert!(repo
+            .verify_address_matches_signer("test".to_string(), &matching)
+            .await
+            .unwrap());
+
+        let mismatched = resolver_returning("0x0000000000000000000000000000000000000001");
+        assert!(!repo
+            .verify_address_matches_signer("test".to_string(), &mismatched)
+            .await
+            .unwrap());
+
+        let result = repo
+            .verify_address_matches_signer("missing".to_string(), &matching)
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_repair_address_stores_derived_address() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        let derived = "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E";
+        let resolver = resolver_returning(derived);
+
+        let repaired = repo
+            .repair_address("test".to_string(), &resolver)
+            .await
+            .unwrap();
+        assert_eq!(repaired.address, derived);
+        assert_eq!(
+            repo.get_by_id("test".to_string()).await.unwrap().address,
+            derived
+        );
+        assert!(repo
+            .verify_address_matches_signer("test".to_string(), &resolver)
+            .await
+            .unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_list_with_capability() {
+        let repo = InMemoryRelayerRepository::new();
+        // The test relayer has EIP-1559 pricing and private transactions disabled
+        repo.create(create_test_relayer("legacy".to_string()))
+            .await
+            .unwrap();
+        repo.create(RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                eip1559_pricing: Some(true),
+                private_transactions: Some(true),
+                ..Default::default()
+            }),
+            ..create_test_relayer("private".to_string())
+        })
+        .await
+        .unwrap();
+        repo.create(RelayerRepoModel {
+            network_type: NetworkType::Solana,
+            policies: RelayerNetworkPolicy::Solana(Default::default()),
+            ..create_test_relayer("solana".to_string())
+        })
+        .await
+        .unwrap();
+
+        for capability in [
+            Capability::Eip1559,
+            Capability::PrivateTransactions,
+            Capability::Blobs,
+        ] {
+            let ids: Vec<String> = repo
+                .list_with_capability(capability)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|relayer| relayer.id)
+                .collect();
+            assert_eq!(ids, vec!["private".to_string()], "{capability:?}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_rekey_rejects_malformed_address() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let result = repo
+            .rekey(
+                "test".to_string(),
+                "new-signer".to_string(),
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+
+        // Neither field was changed
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(stored.signer_id, "test");
+        assert_eq!(stored.address, "0x");
+    }
+
+    #[actix_web::test]
+    async fn test_rekey_not_found() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo
+            .rekey(
+                "missing".to_string(),
+                "new-signer".to_string(),
+                "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    fn create_capped_relayer(id: &str, gas_price_cap: Option<u128>) -> RelayerRepoModel {
+        RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_price_cap,
+                ..RelayerEvmPolicy::default()
+            }),
+            ..create_test_relayer(id.to_string())
+        }
+    }
+
+    fn create_guarded_repo() -> InMemoryRelayerRepository {
+        InMemoryRelayerRepository::new().with_policy_guardrails(PolicyGuardrails {
+            require_gas_price_cap: true,
+            max_allowed_gas_price_cap: Some(500_000_000_000),
+        })
+    }
+
+    #[actix_web::test]
+    async fn test_policy_guardrails_reject_missing_cap() {
+        let repo = create_guarded_repo();
+        let result = repo.create(create_capped_relayer("test", None)).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert_eq!(repo.count().await.unwrap(), 0);
+    }
+
+    #[actix_web::test]
+    async fn test_policy_guardrails_reject_cap_over_max() {
+        let repo = create_guarded_repo();
+        let result = repo
+            .create(create_capped_relayer("test", Some(600_000_000_000)))
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_policy_guardrails_accept_compliant_policy() {
+        let repo = create_guarded_repo();
+        repo.create(create_capped_relayer("test", Some(100_000_000_000)))
+            .await
+            .unwrap();
+
+        // update_policy is guarded as well and leaves the stored policy untouched on rejection
+        let result = repo
+            .update_policy(
+                "test".to_string(),
+                RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(
+            stored.policies.get_evm_policy().gas_price_cap,
+            Some(100_000_000_000)
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_policy_guardrails_reject_update_without_cap() {
+        let repo = create_guarded_repo();
+        repo.create(create_capped_relayer("test", Some(100_000_000_000)))
+            .await
+            .unwrap();
+
+        let result = repo
+            .update("test".to_string(), create_capped_relayer("test", None))
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(
+            stored.policies.get_evm_policy().gas_price_cap,
+            Some(100_000_000_000)
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_no_policy_guardrails_accepts_uncapped_policy() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("test", None))
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_effective_policy_guardrail_clamps_relayer_cap() {
+        // The relayer was stored before the guardrails were tightened
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("test", Some(600_000_000_000)))
+            .await
+            .unwrap();
+        let repo = repo.with_policy_guardrails(PolicyGuardrails {
+            require_gas_price_cap: true,
+            max_allowed_gas_price_cap: Some(500_000_000_000),
+        });
+
+        let policy = repo.effective_policy("test".to_string()).await.unwrap();
+        assert_eq!(policy.get_evm_policy().gas_price_cap, Some(500_000_000_000));
+    }
+
+    #[actix_web::test]
+    async fn test_effective_policy_relayer_value_overrides_network_default() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("test", Some(7)))
+            .await
+            .unwrap();
+
+        let policy = repo
+            .effective_policy("test".to_string())
+            .await
+            .unwrap()
+            .get_evm_policy();
+        assert_eq!(policy.gas_price_cap, Some(7));
+        assert_eq!(policy.min_balance, Some(DEFAULT_EVM_MIN_BALANCE));
+        assert_eq!(policy.eip1559_pricing, Some(DEFAULT_EVM_EIP1559_ENABLED));
+
+        let result = repo.effective_policy("missing".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    fn create_inheriting_relayer(id: &str, parent: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            inherits_from: Some(parent.to_string()),
+            ..create_capped_relayer(id, None)
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_get_by_id_effective_inherits_parent_gas_price_cap() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("parent", Some(100)))
+            .await
+            .unwrap();
+        repo.create(create_inheriting_relayer("child", "parent"))
+            .await
+            .unwrap();
+
+        let child = repo.get_by_id_effective("child".to_string()).await.unwrap();
+        assert_eq!(child.policies.get_evm_policy().gas_price_cap, Some(100));
+        // Only the override is stored
+        let stored = repo.get_by_id("child".to_string()).await.unwrap();
+        assert_eq!(stored.policies.get_evm_policy().gas_price_cap, None);
+
+        let result = repo.get_by_id_effective("missing".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_editing_parent_changes_child_effective_policy() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("parent", Some(100)))
+            .await
+            .unwrap();
+        repo.create(create_inheriting_relayer("child", "parent"))
+            .await
+            .unwrap();
+
+        repo.update(
+            "parent".to_string(),
+            create_capped_relayer("parent", Some(50)),
+        )
+        .await
+        .unwrap();
+
+        let policy = repo
+            .effective_policy("child".to_string())
+            .await
+            .unwrap()
+            .get_evm_policy();
+        assert_eq!(policy.gas_price_cap, Some(50));
+    }
+
+    #[actix_web::test]
+    async fn test_child_override_takes_precedence_over_parent() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("root", Some(100)))
+            .await
+            .unwrap();
+        repo.create(create_inheriting_relayer("parent", "root"))
+            .await
+            .unwrap();
+        repo.create(RelayerRepoModel {
+            inherits_from: Some("parent".to_string()),
+            ..create_capped_relayer("child", Some(7))
+        })
+        .await
+        .unwrap();
+
+        let parent = repo
+            .get_by_id_effective("parent".to_string())
+            .await
+            .unwrap();
+        assert_eq!(parent.policies.get_evm_policy().gas_price_cap, Some(100));
+        let child = repo.get_by_id_effective("child".to_string()).await.unwrap();
+        assert_eq!(child.policies.get_evm_policy().gas_price_cap, Some(7));
+    }
+
+    #[actix_web::test]
+    async fn test_delete_refuses_parent_with_children() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("parent", Some(100)))
+            .await
+            .unwrap();
+        repo.create(create_inheriting_relayer("child", "parent"))
+            .await
+            .unwrap();
+
+        let result = repo.delete_by_id("parent".to_string()).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let result = repo.delete_many(vec!["parent".to_string()]).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert!(repo.get_by_id_effective("child".to_string()).await.is_ok());
+
+        // Deleting the children along with the parent leaves nobody orphaned
+        let report = repo
+            .delete_many(vec!["parent".to_string(), "child".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(report.deleted, vec!["parent", "child"]);
+    }
+
+    #[actix_web::test]
+    async fn test_inheritance_cycles_and_missing_parents_are_rejected() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_capped_relayer("a", Some(100)))
+            .await
+            .unwrap();
+        repo.create(create_inheriting_relayer("b", "a"))
+            .await
+            .unwrap();
+
+        let result = repo
+            .update("a".to_string(), create_inheriting_relayer("a", "b"))
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        let result = repo
+            .update("a".to_string(), create_inheriting_relayer("a", "a"))
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(
+            repo.get_by_id("a".to_string()).await.unwrap().inherits_from,
+            None
+        );
+
+        let result = repo.create(create_inheriting_relayer("c", "missing")).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_delete_many_partitions_deleted_and_missing_ids() {
+        let repo = InMemoryRelayerRepository::new();
+        for id in ["a", "b", "c"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let report = repo
+            .delete_many(vec![
+                "a".to_string(),
+                "missing".to_string(),
+                "c".to_string(),
+                "a".to_string(),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(report.deleted, vec!["a".to_string(), "c".to_string()]);
+        assert_eq!(
+            report.not_found,
+            vec!["missing".to_string(), "a".to_string()]
+        );
+        let remaining: Vec<String> = repo
+            .list_all()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|relayer| relayer.id)
+            .collect();
+        assert_eq!(remaining, vec!["b".to_string()]);
+        assert_eq!(repo.changes_since(3).await.unwrap().len(), 2);
+    }
+
+    async fn create_per_network_paged_repo() -> InMemoryRelayerRepository {
+        let repo = InMemoryRelayerRepository::new().with_per_page_defaults(HashMap::from([
+            (NetworkType::Solana, 3),
+            (NetworkType::Evm, 2),
+        ]));
+        for i in 0..5 {
+            repo.create(RelayerRepoModel {
+                network_type: NetworkType::Solana,
+                policies: RelayerNetworkPolicy::Solana(Default::default()),
+                ..create_test_relayer(format!("solana-{i}"))
+            })
+            .await
+            .unwrap();
+            repo.create(create_test_relayer(format!("evm-{i}")))
+                .await
+                .unwrap();
+            repo.create(RelayerRepoModel {
+                network_type: NetworkType::Stellar,
+                policies: RelayerNetworkPolicy::Stellar(Default::default()),
+                ..create_test_relayer(format!("stellar-{i}"))
+            })
+            .await
+            .unwrap();
+        }
+        repo
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_network_type_applies_per_network_default() {
+        let repo = create_per_network_paged_repo().await;
+        let query = PaginationQuery {
+            page: 1,
+            per_page: None,
+        };
+
+        let solana = repo
+            .list_by_network_type(NetworkType::Solana, query.clone())
+            .await
+            .unwrap();
+        assert_eq!(solana.per_page, 3);
+        assert_eq!(solana.items.len(), 3);
+        assert_eq!(solana.total, 5);
+        assert!(solana
+            .items
+            .iter()
+            .all(|relayer| relayer.network_type == NetworkType::Solana));
+
+        let evm = repo
+            .list_by_network_type(NetworkType::Evm, query.clone())
+            .await
+            .unwrap();
+        assert_eq!(evm.per_page, 2);
+        assert_eq!(evm.items.len(), 2);
+
+        // No default configured for Stellar, so the global default applies
+        let stellar = repo
+            .list_by_network_type(NetworkType::Stellar, query)
+            .await
+            .unwrap();
+        assert_eq!(stellar.per_page, DEFAULT_PER_PAGE);
+        assert_eq!(stellar.items.len(), 5);
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_network_type_explicit_per_page_overrides_default() {
+        let repo = create_per_network_paged_repo().await;
+
+        let solana = repo
+            .list_by_network_type(
+                NetworkType::Solana,
+                PaginationQuery {
+                    page: 2,
+                    per_page: Some(4),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(solana.per_page, 4);
+        assert_eq!(solana.items.len(), 1);
+
+        let result = repo
+            .list_by_network_type(
+                NetworkType::Solana,
+                PaginationQuery {
+                    page: 1,
+                    per_page: Some(0),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_existing_ids_returns_present_subset() {
+        let repo = InMemoryRelayerRepository::new();
+        for id in ["a", "b", "c"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let existing = repo
+            .existing_ids(vec![
+                "a".to_string(),
+                "missing-1".to_string(),
+                "c".to_string(),
+                "missing-2".to_string(),
+                "a".to_string(),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(existing, HashSet::from(["a".to_string(), "c".to_string()]));
+
+        assert!(repo.existing_ids(vec![]).await.unwrap().is_empty());
+    }
+
+    /// Stores a valid relayer, then checks that `update` rejects it with `policy` and
+    /// leaves the stored relayer untouched
+    async fn assert_update_rejects_policy(policy: RelayerEvmPolicy) {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = create_test_relayer("test".to_string());
+        repo.create(relayer.clone()).await.unwrap();
+
+        let result = repo
+            .update(
+                "test".to_string(),
+                RelayerRepoModel {
+                    policies: RelayerNetworkPolicy::Evm(policy),
+                    ..relayer.clone()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(stored.policies, relayer.policies);
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_invalid_gas_oracle_url() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            gas_oracle: GasOracleConfig::Custom {
+                url: "not a url".to_string(),
+            },
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_zero_calldata_limit() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_calldata_bytes: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_gas_bump_below_replacement_minimum() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            gas_bump: Some(crate::models::GasBumpPolicy {
+                bump_percent: 5,
+                max_bumps: 3,
+                bump_interval_secs: 60,
+            }),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_zero_transaction_deadline() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            transaction_deadline_secs: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_zero_max_inflight_transactions() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_inflight_transactions: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_empty_allowed_tx_types() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            allowed_tx_types: Some(vec![]),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_invalid_fee_token_address() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            accepted_fee_tokens: Some(vec![crate::models::FeeToken {
+                address: "not-an-address".to_string(),
+                min_rate: 1.0,
+            }]),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_priority_fee_floor_above_cap() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            min_priority_fee_per_gas: Some(101),
+            gas_price_cap: Some(100),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_zero_max_daily_spend() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_daily_spend: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_zero_gas_limit_cap() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            gas_limit_cap: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_update_rejects_zero_max_rpc_batch_size() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_rpc_batch_size: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[actix_web::test]
+    async fn test_create_rejects_invalid_gas_oracle_url() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_oracle: GasOracleConfig::Custom {
+                    url: "not a url".to_string(),
+                },
+                ..RelayerEvmPolicy::default()
+            }),
+            ..create_test_relayer("test".to_string())
+        };
+        let result = repo.create(relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(repo.count().await.unwrap(), 0);
+    }
+
+    #[actix_web::test]
+    async fn test_create_with_derivation_path() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = RelayerRepoModel {
+            derivation_path: Some("m/44'/60'/0'/0/0".to_string()),
+            ..create_test_relayer("valid".to_string())
+        };
+        let created = repo.create(relayer).await.unwrap();
+        assert_eq!(created.derivation_path(), Some("m/44'/60'/0'/0/0"));
+
+        let malformed = RelayerRepoModel {
+            derivation_path: Some("m/44'/60'/x".to_string()),
+            ..create_test_relayer("malformed".to_string())
+        };
+        let result = repo.create(malformed).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+
+        // Relayers without a derivation path are unaffected
+        let created = repo
+            .create(create_test_relayer("none".to_string()))
+            .await
+            .unwrap();
+        assert!(created.derivation_path().is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_compact_keeps_remaining_entries() {
+        let repo = InMemoryRelayerRepository::new();
+        for i in 0..200 {
+            repo.create(create_test_relayer(format!("relayer-{i}")))
+                .await
+                .unwrap();
+        }
+        for i in 10..200 {
+            repo.delete_by_id(format!("relayer-{i}")).await.unwrap();
+        }
+        let capacity_before = repo.store.lock().await.capacity();
+
+        repo.compact().await.unwrap();
+
+        assert!(repo.store.lock().await.capacity() < capacity_before);
+        assert_eq!(repo.count().await.unwrap(), 10);
+        for i in 0..10 {
+            let relayer = repo.get_by_id(format!("relayer-{i}")).await.unwrap();
+            assert_eq!(relayer.name, format!("Relayer relayer-{i}"));
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_circuit_trip_and_reset() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let until = Utc::now() + chrono::Duration::minutes(5);
+        let tripped = repo.trip_circuit("test".to_string(), until).await.unwrap();
+        assert_eq!(tripped.circuit, CircuitState::Open { until });
+        assert!(!tripped.is_operational());
+
+        // Outcomes do not affect an open circuit
+        let still_open = repo.record_outcome("test".to_string(), true).await.unwrap();
+        assert_eq!(still_open.circuit, CircuitState::Open { until });
+
+        let reset = repo.reset_circuit("test".to_string()).await.unwrap();
+        assert_eq!(reset.circuit, CircuitState::Closed);
+        assert!(reset.is_operational());
+    }
+
+    #[actix_web::test]
+    async fn test_circuit_half_open_probe_success_closes() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let tripped = repo
+            .trip_circuit(
+                "test".to_string(),
+                Utc::now() - chrono::Duration::seconds(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(tripped.circuit_at(Utc::now()), CircuitState::HalfOpen);
+        // A half-open circuit lets probes through
+        assert!(tripped.is_operational());
+
+        let closed = repo.record_outcome("test".to_string(), true).await.unwrap();
+        assert_eq!(closed.circuit, CircuitState::Closed);
+    }
+
+    #[actix_web::test]
+    async fn test_circuit_half_open_probe_failure_reopens() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        repo.trip_circuit(
+            "test".to_string(),
+            Utc::now() - chrono::Duration::seconds(1),
+        )
+        .await
+        .unwrap();
+
+        let reopened = repo
+            .record_outcome("test".to_string(), false)
+            .await
+            .unwrap();
+        assert!(matches!(
+            reopened.circuit,
+            CircuitState::Open { until } if until > Utc::now()
+        ));
+        assert!(!reopened.is_operational());
+    }
+
+    fn create_relayer_with_evm_policy(id: &str, policy: RelayerEvmPolicy) -> RelayerRepoModel {
+        RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(policy),
+            ..create_test_relayer(id.to_string())
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_list_with_private_transactions() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_relayer_with_evm_policy(
+            "private",
+            RelayerEvmPolicy {
+                private_transactions: Some(true),
+                ..RelayerEvmPolicy::default()
+            },
+        ))
+        .await
+        .unwrap();
+        repo.create(create_test_relayer("public".to_string()))
+            .await
+            .unwrap();
+
+        let result = repo
+            .list_with_private_transactions(PaginationQuery {
+                page: 1,
+                per_page: Some(10),
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].id, "private");
+    }
+
+    #[actix_web::test]
+    async fn test_list_with_gas_price_cap_below() {
+        let repo = InMemoryRelayerRepository::new();
+        for (id, cap) in [("low", Some(10)), ("high", Some(100)), ("uncapped", None)] {
+            repo.create(create_relayer_with_evm_policy(
+                id,
+                RelayerEvmPolicy {
+                    gas_price_cap: cap,
+                    ..RelayerEvmPolicy::default()
+                },
+            ))
+            .await
+            .unwrap();
+        }
+
+        let result = repo
+            .list_with_gas_price_cap_below(
+                50,
+                PaginationQuery {
+                    page: 1,
+                    per_page: Some(10),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].id, "low");
+    }
+
+    #[actix_web::test]
+    async fn test_list_where_policy_paginates() {
+        let repo = InMemoryRelayerRepository::new();
+        for i in 0..5 {
+            repo.create(create_test_relayer(format!("relayer-{i}")))
+                .await
+                .unwrap();
+        }
+
+        let predicate: PolicyPredicate =
+            Arc::new(|policy| policy.get_evm_policy().min_balance == Some(0));
+        let page = repo
+            .list_where_policy(
+                predicate,
+                PaginationQuery {
+                    page: 2,
+                    per_page: Some(2),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total, 5);
+        assert_eq!(page.items.len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_list_where_policy_rejects_page_zero() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("relayer-0".to_string()))
+            .await
+            .unwrap();
+
+        let predicate: PolicyPredicate = Arc::new(|_| true);
+        let result = repo
+            .list_where_policy(
+                predicate,
+                PaginationQuery {
+                    page: 0,
+                    per_page: Some(2),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_status_counts_only_matching() {
+        let repo = InMemoryRelayerRepository::new();
+        for i in 0..5 {
+            let mut relayer = create_test_relayer(format!("relayer-{i}"));
+            relayer.paused = i < 3;
+            repo.create(relayer).await.unwrap();
+        }
+
+        let page = repo
+            .list_by_status(
+                RelayerOperationalStatus::Paused,
+                PaginationQuery {
+                    page: 2,
+                    per_page: Some(2),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items.len(), 1);
+        assert!(page.items[0].paused);
+
+        let operational = repo
+            .list_by_status(
+                RelayerOperationalStatus::Operational,
+                PaginationQuery {
+                    page: 1,
+                    per_page: Some(10),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(operational.total, 2);
+    }
+
+    #[actix_web::test]
+    async fn test_list_by_status_rejects_page_zero() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo
+            .list_by_status(
+                RelayerOperationalStatus::Operational,
+                PaginationQuery {
+                    page: 0,
+                    per_page: Some(10),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_add_and_remove_tag_where() {
+        let repo = create_per_network_paged_repo().await;
+        let evm = RelayerFilter {
+            network_type: Some(NetworkType::Evm),
+            ..Default::default()
+        };
+
+        let added = repo
+            .add_tag_where(evm.clone(), "batch-a".to_string())
+            .await
+            .unwrap();
+        assert_eq!(added, 5);
+        // Relayers already carrying the tag are skipped
+        let added_again = repo
+            .add_tag_where(evm.clone(), "batch-a".to_string())
+            .await
+            .unwrap();
+        assert_eq!(added_again, 0);
+
+        let relayer = repo.get_by_id("evm-0".to_string()).await.unwrap();
+        assert_eq!(relayer.tags(), ["batch-a".to_string()]);
+        assert!(relayer.updated_at.is_some());
+        let solana = repo.get_by_id("solana-0".to_string()).await.unwrap();
+        assert!(solana.tags().is_empty());
+
+        let removed = repo
+            .remove_tag_where(RelayerFilter::default(), "batch-a".to_string())
+            .await
+            .unwrap();
+        assert_eq!(removed, 5);
+        let relayer = repo.get_by_id("evm-0".to_string()).await.unwrap();
+        assert!(relayer.tags.is_none());
+        assert_eq!(relayer.version, 2);
+    }
+
+    #[actix_web::test]
+    async fn test_record_rpc_health_moves_selection() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.custom_rpc_urls = Some(vec![
+            RpcConfig::new("https://primary.example.com".to_string()),
+            RpcConfig::new("https://backup.example.com".to_string()),
+        ]);
+        repo.create(relayer).await.unwrap();
+
+        let updated = repo
+            .record_rpc_health(
+                "test".to_string(),
+                "https://primary.example.com".to_string(),
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            updated.rpc_health_of("https://primary.example.com"),
+            RpcHealth::Unhealthy
+        );
+        assert_eq!(
+            repo.next_healthy_rpc_url("test".to_string()).await.unwrap(),
+            Some("https://backup.example.com".to_string())
+        );
+
+        repo.record_rpc_health(
+            "test".to_string(),
+            "https://backup.example.com".to_string(),
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            repo.next_healthy_rpc_url("test".to_string()).await.unwrap(),
+            Some("https://primary.example.com".to_string())
+        );
+    }
+
+    async fn assert_active_index_matches_scan(repo: &InMemoryRelayerRepository) {
+        let all = repo.list_all().await.unwrap();
+        for network_type in [NetworkType::Evm, NetworkType::Solana, NetworkType::Stellar] {
+            let mut scanned: Vec<String> = all
+                .iter()
+                .filter(|relayer| relayer.network_type == network_type && relayer.is_operational())
+                .map(|relayer| relayer.id.clone())
+                .collect();
+            scanned.sort();
+            let indexed: Vec<String> = repo
+                .list_active_by_network_type(network_type)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|relayer| relayer.id)
+                .collect();
+            assert_eq!(indexed, scanned, "{network_type:?}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_list_active_by_network_type_matches_scan_across_mutations() {
+        let repo = InMemoryRelayerRepository::new();
+        let solana = |id: &str| RelayerRepoModel {
+            network_type: NetworkType::Solana,
+            policies: RelayerNetworkPolicy::Solana(Default::default()),
+            ..create_test_relayer(id.to_string())
+        };
+        for i in 0..3 {
+            repo.create(create_test_relayer(format!("evm-{i}")))
+                .await
+                .unwrap();
+            repo.create(solana(&format!("solana-{i}"))).await.unwrap();
+        }
+        assert_active_index_matches_scan(&repo).await;
+        assert_eq!(
+            repo.list_active_by_network_type(NetworkType::Evm)
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+
+        // Status changes
+        repo.partial_update(
+            "evm-0".to_string(),
+            UpdateRelayerRequest {
+                paused: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        repo.disable_relayer(
+            "solana-1".to_string(),
+            DisabledReason::BalanceCheckFailed("low balance".to_string()),
+        )
+        .await
+        .unwrap();
+        assert_active_index_matches_scan(&repo).await;
+        repo.enable_relayer("solana-1".to_string()).await.unwrap();
+        assert_active_index_matches_scan(&repo).await;
+
+        // Network changes
+        repo.update("evm-1".to_string(), solana("evm-1"))
+            .await
+            .unwrap();
+        assert_active_index_matches_scan(&repo).await;
+        assert!(repo
+            .list_active_by_network_type(NetworkType::Solana)
+            .await
+            .unwrap()
+            .iter()
+            .any(|relayer| relayer.id == "evm-1"));
+        let evm_page = repo
+            .list_by_network_type(
+                NetworkType::Evm,
+                PaginationQuery {
+                    page: 1,
+                    per_page: Some(10),
+                },
+            )
+            .await
+            .unwrap();
+        let evm_ids: Vec<&str> = evm_page.items.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(evm_ids, ["evm-0", "evm-2"]);
+
+        // Removals
+        repo.delete_by_id("solana-0".to_string()).await.unwrap();
+        repo.delete_many(vec!["evm-2".to_string()]).await.unwrap();
+        assert_active_index_matches_scan(&repo).await;
+
+        repo.drop_all_entries().await.unwrap();
+        assert_active_index_matches_scan(&repo).await;
+        assert!(repo
+            .list_active_by_network_type(NetworkType::Solana)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    fn set_env_relayer(prefix: &str) {
+        std::env::set_var(format!("{prefix}_RELAYER_ID"), "env-relayer");
+        std::env::set_var(format!("{prefix}_NETWORK"), "sepolia");
+        std::env::set_var(format!("{prefix}_SIGNER_ID"), "env-signer");
+        std::env::set_var(
+            format!("{prefix}_ADDRESS"),
+            "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E",
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_from_env_builds_relayer() {
+        let prefix = "RELAYER_FROM_ENV_TEST_OK";
+        set_env_relayer(prefix);
+        std::env::set_var(format!("{prefix}_PAUSED"), "true");
+        std::env::set_var(
+            format!("{prefix}_POLICIES"),
+            r#"{"type": "evm", "gas_price_cap": 100}"#,
+        );
+        std::env::set_var(format!("{prefix}_MIN_BALANCE"), "5000");
+
+        let repo = InMemoryRelayerRepository::from_env(prefix).unwrap();
+        let relayer = repo.get_by_id("env-relayer".to_string()).await.unwrap();
+        assert_eq!(relayer.name, "env-relayer");
+        assert_eq!(relayer.network, "sepolia");
+        assert_eq!(relayer.network_type, NetworkType::Evm);
+        assert_eq!(relayer.signer_id, "env-signer");
+        assert_eq!(
+            relayer.address,
+            "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E"
+        );
+        assert!(relayer.paused);
+        let policy = relayer.policies.get_evm_policy();
+        assert_eq!(policy.gas_price_cap, Some(100));
+        assert_eq!(policy.min_balance, Some(5000));
+        assert_eq!(repo.count().await.unwrap(), 1);
+    }
+
+    #[test]
+    fn test_from_env_names_missing_variable() {
+        let prefix = "RELAYER_FROM_ENV_TEST_MISSING";
+        set_env_relayer(prefix);
+        std::env::remove_var(format!("{prefix}_SIGNER_ID"));
+
+        let error = InMemoryRelayerRepository::from_env(prefix).unwrap_err();
+        assert!(matches!(error, RepositoryError::InvalidData(_)));
+        assert!(
+            error
+                .to_string()
+                .contains("RELAYER_FROM_ENV_TEST_MISSING_SIGNER_ID"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_from_env_rejects_invalid_values() {
+        let prefix = "RELAYER_FROM_ENV_TEST_INVALID";
+        set_env_relayer(prefix);
+        std::env::set_var(format!("{prefix}_NETWORK_TYPE"), "bitcoin");
+        let error = InMemoryRelayerRepository::from_env(prefix).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("RELAYER_FROM_ENV_TEST_INVALID_NETWORK_TYPE"),
+            "{error}"
+        );
+
+        std::env::remove_var(format!("{prefix}_NETWORK_TYPE"));
+        std::env::set_var(format!("{prefix}_ADDRESS"), "not-an-address");
+        assert!(matches!(
+            InMemoryRelayerRepository::from_env(prefix),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_maintenance_makes_every_relayer_unavailable() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("active".to_string()))
+            .await
+            .unwrap();
+        let mut paused = create_test_relayer("paused".to_string());
+        paused.paused = true;
+        repo.create(paused).await.unwrap();
+
+        let until = Utc::now() + chrono::Duration::hours(1);
+        repo.enter_maintenance(until).await.unwrap();
+        assert_eq!(repo.maintenance_until().await.unwrap(), Some(until));
+        assert!(!repo.is_available("active".to_string()).await.unwrap());
+        assert!(!repo.is_available("paused".to_string()).await.unwrap());
+
+        // Per-relayer state is untouched and applies again after maintenance
+        repo.exit_maintenance().await.unwrap();
+        assert_eq!(repo.maintenance_until().await.unwrap(), None);
+        assert!(repo.is_available("active".to_string()).await.unwrap());
+        assert!(!repo.is_available("paused".to_string()).await.unwrap());
+        assert!(repo.get_by_id("paused".to_string()).await.unwrap().paused);
+    }
+
+    #[actix_web::test]
+    async fn test_maintenance_ends_on_its_own() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        repo.enter_maintenance(Utc::now() + chrono::Duration::milliseconds(50))
+            .await
+            .unwrap();
+        assert!(!repo.is_available("test".to_string()).await.unwrap());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(repo.maintenance_until().await.unwrap(), None);
+        assert!(repo.is_available("test".to_string()).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_enter_maintenance_rejects_past_end() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo
+            .enter_maintenance(Utc::now() - chrono::Duration::seconds(1))
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(repo.maintenance_until().await.unwrap(), None);
+    }
+
+    #[actix_web::test]
+    async fn test_try_acquire_slot_enforces_cap() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        if let RelayerNetworkPolicy::Evm(policy) = &mut relayer.policies {
+            policy.max_inflight_transactions = Some(2);
+        }
+        repo.create(relayer).await.unwrap();
+
+        let first = repo.try_acquire_slot("test".to_string()).await.unwrap();
+        let _second = repo.try_acquire_slot("test".to_string()).await.unwrap();
+        let result = repo.try_acquire_slot("test".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::Conflict(_))));
+
+        // Dropping a guard frees its slot
+        drop(first);
+        assert!(repo.try_acquire_slot("test".to_string()).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_update_policy_rejects_inconsistent_tx_types() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            allowed_tx_types: Some(vec![EvmTxType::Eip1559]),
+            eip1559_pricing: Some(false),
+            ..RelayerEvmPolicy::default()
+        });
+        let result = repo.update_policy("test".to_string(), policy).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            allowed_tx_types: Some(vec![EvmTxType::Legacy]),
+            eip1559_pricing: Some(false),
+            ..RelayerEvmPolicy::default()
+        });
+        let updated = repo
+            .update_policy("test".to_string(), policy)
+            .await
+            .unwrap();
+        assert_eq!(
+            updated.policies.allowed_tx_types(),
+            Some(&[EvmTxType::Legacy][..])
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_try_acquire_slot_unknown_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo.try_acquire_slot("missing".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_set_metadata() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let metadata = serde_json::json!({ "cost_center": "ops" });
+        let updated = repo
+            .set_metadata("test".to_string(), metadata.clone())
+            .await
+            .unwrap();
+        assert_eq!(updated.metadata, metadata);
+        assert_eq!(updated.version, 1);
+
+        let result = repo.set_metadata("missing".to_string(), metadata).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_merge_metadata_preserves_untouched_keys() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        repo.set_metadata(
+            "test".to_string(),
+            serde_json::json!({ "cost_center": "ops", "ticket": "OPS-1" }),
+        )
+        .await
+        .unwrap();
+
+        let updated = repo
+            .merge_metadata("test".to_string(), serde_json::json!({ "ticket": "OPS-2" }))
+            .await
+            .unwrap();
+        assert_eq!(
+            updated.metadata,
+            serde_json::json!({ "cost_center": "ops", "ticket": "OPS-2" })
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_metadata_over_size_limit_is_rejected_on_create_and_update() {
+        let repo = InMemoryRelayerRepository::new().with_metadata_size_limit(32);
+        let oversized = RelayerRepoModel {
+            metadata: serde_json::json!({ "note": "x".repeat(64) }),
+            ..create_test_relayer("test".to_string())
+        };
+        let result = repo.create(oversized.clone()).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert!(repo.validate_create(oversized.clone()).await.is_err());
+
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        let result = repo.update("test".to_string(), oversized).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let relayer = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(
+            relayer.metadata,
+            create_test_relayer("test".to_string()).metadata
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_metadata_over_size_limit_is_rejected() {
+        let repo = InMemoryRelayerRepository::new().with_metadata_size_limit(32);
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        repo.set_metadata("test".to_string(), serde_json::json!({ "a": 1 }))
+            .await
+            .unwrap();
+
+        let oversized = serde_json::json!({ "note": "x".repeat(64) });
+        let result = repo
+            .set_metadata("test".to_string(), oversized.clone())
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let result = repo.merge_metadata("test".to_string(), oversized).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+
+        // The stored metadata is left unchanged
+        let relayer = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(relayer.metadata, serde_json::json!({ "a": 1 }));
+        assert_eq!(relayer.version, 1);
+    }
+
+    #[actix_web::test]
+    async fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("relayer-1".to_string()))
+            .await
+            .unwrap();
+        repo.create(create_test_relayer_with_aliases("relayer-2", &["primary"]))
+            .await
+            .unwrap();
+
+        repo.save_to_path(&path).await.unwrap();
+        let loaded = InMemoryRelayerRepository::load_from_path(&path).unwrap();
+
+        assert_eq!(loaded.count().await.unwrap(), 2);
+        let original = repo.get_by_id("relayer-1".to_string()).await.unwrap();
+        let restored = loaded.get_by_id("relayer-1".to_string()).await.unwrap();
+        assert_eq!(restored.name, original.name);
+        assert_eq!(restored.address, original.address);
+        assert_eq!(restored.version, original.version);
+        // The alias index is rebuilt from the loaded relayers
+        let aliased = loaded.get_by_alias("primary".to_string()).await.unwrap();
+        assert_eq!(aliased.id, "relayer-2");
+    }
+
+    #[actix_web::test]
+    async fn test_load_from_older_schema_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+        // Stores from before schema versioning carry version 0, and their relayers
+        // lack `sunset` and `version`
+        let mut relayer =
+            serde_json::to_value(create_test_relayer("relayer-1".to_string())).unwrap();
+        let fields = relayer.as_object_mut().unwrap();
+        fields.remove("sunset");
+        fields.remove("version");
+        let document = serde_json::json!({
+            "schema_version": 0,
+            "relayers": [relayer],
+        });
+        std::fs::write(&path, document.to_string()).unwrap();
+
+        let loaded = InMemoryRelayerRepository::load_from_path(&path).unwrap();
+        let relayer = loaded.get_by_id("relayer-1".to_string()).await.unwrap();
+        assert!(!relayer.sunset);
+        assert_eq!(relayer.version, 0);
+    }
+
+    #[actix_web::test]
+    async fn test_concurrent_saves_leave_a_complete_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+        let repo = Arc::new(InMemoryRelayerRepository::new());
+        repo.create(create_test_relayer("relayer-1".to_string()))
+            .await
+            .unwrap();
+
+        let saves: Vec<_> = (0..8)
+            .map(|_| {
+                let repo = repo.clone();
+                let path = path.clone();
+                tokio::spawn(async move { repo.save_to_path(&path).await })
+            })
+            .collect();
+        for save in saves {
+            save.await.unwrap().unwrap();
+        }
+
+        let loaded = InMemoryRelayerRepository::load_from_path(&path).unwrap();
+        assert_eq!(loaded.count().await.unwrap(), 1);
+        // Every save renamed its own temporary file into place
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_auto_save_writes_periodically() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+        let repo = Arc::new(InMemoryRelayerRepository::new());
+        repo.create(create_test_relayer("relayer-1".to_string()))
+            .await
+            .unwrap();
+
+        let task = repo
+            .clone()
+            .spawn_auto_save(path.clone(), Duration::from_millis(10));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        task.abort();
+
+        let loaded = InMemoryRelayerRepository::load_from_path(&path).unwrap();
+        assert_eq!(loaded.count().await.unwrap(), 1);
+    }
+
+    fn create_test_descriptor(id: &str) -> RelayerDescriptor {
+        RelayerDescriptor {
+            id: id.to_string(),
+            name: format!("Relayer {id}"),
+            network: "sepolia".to_string(),
+            network_type: NetworkType::Evm,
+            signer_id: "test".to_string(),
+            address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+            policies: None,
+            paused: false,
+            notification_id: None,
+            custom_rpc_urls: None,
+            extra_webhook_urls: None,
+            hard_gas_price_ceiling: None,
+            pool: None,
+            derivation_path: None,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_export_as_descriptors_round_trip() {
+        let repo = InMemoryRelayerRepository::new();
+        let address = "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string();
+        let mut configured = create_test_relayer("relayer-1".to_string());
+        configured.address = address.clone();
+        configured.paused = true;
+        configured.notification_id = Some("notification".to_string());
+        configured.custom_rpc_urls =
+            Some(vec![RpcConfig::new("https://rpc.example.com".to_string())]);
+        configured.extra_webhook_urls = Some(vec!["https://hooks.example.com".to_string()]);
+        configured.hard_gas_price_ceiling = Some(500_000_000_000);
+        configured.pool = Some("pool-a".to_string());
+        repo.create(configured).await.unwrap();
+        let mut plain = create_test_relayer("relayer-2".to_string());
+        plain.address = address;
+        repo.create(plain).await.unwrap();
+        // Runtime state is not exported
+        repo.disable_relayer(
+            "relayer-2".to_string(),
+            DisabledReason::RpcValidationFailed("rpc down".to_string()),
+        )
+        .await
+        .unwrap();
+
+        let mut before = repo.list_all().await.unwrap();
+        before.sort_by(|a, b| a.id.cmp(&b.id));
+        let descriptors = repo.export_as_descriptors().await.unwrap();
+        assert_eq!(
+            descriptors
+                .iter()
+                .map(|d| d.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["relayer-1", "relayer-2"]
+        );
+
+        repo.drop_all_entries().await.unwrap();
+        let report = repo
+            .provision_from_descriptors(descriptors, ProvisionMode::Strict)
+            .await
+            .unwrap();
+        assert_eq!(report.created.len(), 2);
+
+        for original in before {
+            let restored = repo.get_by_id(original.id.clone()).await.unwrap();
+            assert_eq!(restored.config_snapshot(), original.config_snapshot());
+            assert_eq!(restored.signer_id, original.signer_id);
+            assert_eq!(restored.address, original.address);
+        }
+        let restored = repo.get_by_id("relayer-2".to_string()).await.unwrap();
+        assert!(!restored.system_disabled);
+    }
+
+    #[actix_web::test]
+    async fn test_provision_from_descriptors_clean_batch() {
+        let repo = InMemoryRelayerRepository::new();
+        let descriptors = vec![
+            create_test_descriptor("relayer-1"),
+            create_test_descriptor("relayer-2"),
+            create_test_descriptor("relayer-3"),
+        ];
+
+        let report = repo
+            .provision_from_descriptors(descriptors, ProvisionMode::Strict)
+            .await
+            .unwrap();
+
+        assert!(report.is_clean());
+        assert_eq!(report.created, vec!["relayer-1", "relayer-2", "relayer-3"]);
+        assert_eq!(repo.count().await.unwrap(), 3);
+        let stored = repo.get_by_id("relayer-2".to_string()).await.unwrap();
+        assert_eq!(stored.address, "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E");
+        assert!(matches!(stored.policies, RelayerNetworkPolicy::Evm(_)));
+    }
+
+    #[actix_web::test]
+    async fn test_provision_from_descriptors_strict_aborts_on_invalid_row() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut invalid = create_test_descriptor("relayer-2");
+        invalid.address = "not-an-address".to_string();
+        let descriptors = vec![
+            create_test_descriptor("relayer-1"),
+            invalid,
+            create_test_descriptor("relayer-3"),
+        ];
+
+        let result = repo
+            .provision_from_descriptors(descriptors, ProvisionMode::Strict)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(ref msg)) if msg.contains("row 1 (relayer-2)")
+        ));
+        assert!(!repo.has_entries().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_provision_from_descriptors_dry_run_reports_without_creating() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("existing".to_string()))
+            .await
+            .unwrap();
+        let descriptors = vec![
+            create_test_descriptor("relayer-1"),
+            create_test_descriptor("existing"),
+        ];
+
+        let report = repo
+            .provision_from_descriptors(descriptors, ProvisionMode::DryRun)
+            .await
+            .unwrap();
+
+        assert_eq!(report.created, vec!["relayer-1"]);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].row, 1);
+        assert_eq!(report.errors[0].id, "existing");
+        assert_eq!(repo.count().await.unwrap(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_record_error() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        repo.record_error(
+            "test".to_string(),
+            RelayerLastError::new(RelayerErrorKind::Rpc, "connection refused"),
+        )
+        .await
+        .unwrap();
+        let updated = repo
+            .record_error(
+                "test".to_string(),
+                RelayerLastError::new(RelayerErrorKind::Submission, "nonce too low"),
+            )
+            .await
+            .unwrap();
+
+        let last_error = updated.last_error.unwrap();
+        assert_eq!(last_error.kind, RelayerErrorKind::Submission);
+        assert_eq!(last_error.message, "nonce too low");
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(
+            stored.last_error.unwrap().kind,
+            RelayerErrorKind::Submission
+        );
+
+        let result = repo
+            .record_error(
+                "missing".to_string(),
+                RelayerLastError::new(RelayerErrorKind::Other, "boom"),
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_clear_error() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        repo.record_error(
+            "test".to_string(),
+            RelayerLastError::new(RelayerErrorKind::Signing, "signer unavailable"),
+        )
+        .await
+        .unwrap();
+
+        let cleared = repo.clear_error("test".to_string()).await.unwrap();
+        assert!(cleared.last_error.is_none());
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert!(stored.last_error.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_list_with_recent_errors() {
+        let repo = InMemoryRelayerRepository::new();
+        let now = Utc::now();
+        for id in ["stale", "recent", "healthy"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+        repo.record_error(
+            "stale".to_string(),
+            RelayerLastError {
+                message: "old failure".to_string(),
+                at: now - chrono::Duration::hours(2),
+                kind: RelayerErrorKind::Rpc,
+            },
+        )
+        .await
+        .unwrap();
+        repo.record_error(
+            "recent".to_string(),
+            RelayerLastError {
+                message: "new failure".to_string(),
+                at: now,
+                kind: RelayerErrorKind::InsufficientBalance,
+            },
+        )
+        .await
+        .unwrap();
+
+        let recent = repo
+            .list_with_recent_errors(now - chrono::Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].id, "recent");
+
+        let all = repo
+            .list_with_recent_errors(now - chrono::Duration::hours(3))
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_config_versions_and_rollback() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        // Two config changes
+        repo.update_policy(
+            "test".to_string(),
+            RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                gas_price_cap: Some(50_000_000_000),
+                ..RelayerEvmPolicy::default()
+            }),
+        )
+        .await
+        .unwrap();
+        repo.partial_update(
+            "test".to_string(),
+            UpdateRelayerRequest {
+                name: Some("Renamed".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let versions = repo.list_config_versions("test".to_string()).await.unwrap();
+        let numbers: Vec<u32> = versions.iter().map(|v| v.version).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+
+        // Runtime changes do not create versions
+        repo.disable_relayer(
+            "test".to_string(),
+            DisabledReason::BalanceCheckFailed("low balance".to_string()),
+        )
+        .await
+        .unwrap();
+        repo.record_error(
+            "test".to_string(),
+            RelayerLastError::new(RelayerErrorKind::Rpc, "timeout"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            repo.list_config_versions("test".to_string())
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+
+        let rolled_back = repo.rollback_to("test".to_string(), 1).await.unwrap();
+        assert_eq!(rolled_back.name, "Relayer test");
+        assert_eq!(
+            rolled_back.policies,
+            create_test_relayer("test".to_string()).policies
+        );
+        assert!(rolled_back.system_disabled);
+        assert!(rolled_back.disabled_reason.is_some());
+        assert!(rolled_back.last_error.is_some());
+
+        let versions = repo.list_config_versions("test".to_string()).await.unwrap();
+        assert_eq!(versions.last().unwrap().version, 4);
+
+        let result = repo.rollback_to("test".to_string(), 42).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_config_versions_are_bounded() {
+        let repo = InMemoryRelayerRepository::new().with_config_version_limit(2);
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        for name in ["first", "second", "third"] {
+            repo.partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    name: Some(name.to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        let versions = repo.list_config_versions("test".to_string()).await.unwrap();
+        let numbers: Vec<u32> = versions.iter().map(|v| v.version).collect();
+        assert_eq!(numbers, vec![3, 4]);
+        let result = repo.rollback_to("test".to_string(), 1).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_worker_assignment() {
+        let repo = InMemoryRelayerRepository::new();
+        for i in 0..20 {
+            repo.create(create_test_relayer(format!("relayer-{i}")))
+                .await
+                .unwrap();
+        }
+
+        let assignment = repo.worker_assignment(3).await.unwrap();
+        assert_eq!(assignment.len(), 3);
+        let mut assigned: Vec<String> = assignment.values().flatten().cloned().collect();
+        assigned.sort();
+        let mut expected: Vec<String> = (0..20).map(|i| format!("relayer-{i}")).collect();
+        expected.sort();
+        assert_eq!(assigned, expected);
+
+        // Deterministic across calls
+        assert_eq!(repo.worker_assignment(3).await.unwrap(), assignment);
+
+        let result = repo.worker_assignment(0).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    fn create_test_relayer_with_aliases(id: &str, aliases: &[&str]) -> RelayerRepoModel {
+        RelayerRepoModel {
+            aliases: aliases.iter().map(|alias| alias.to_string()).collect(),
+            ..create_test_relayer(id.to_string())
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_get_by_alias() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer_with_aliases(
+            "relayer-1",
+            &["billing-7", "crm:relayer"],
+        ))
+        .await
+        .unwrap();
+
+        for alias in ["billing-7", "crm:relayer"] {
+            let found = repo.get_by_alias(alias.to_string()).await.unwrap();
+            assert_eq!(found.id, "relayer-1");
+        }
+        let result = repo.get_by_alias("unknown".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+
+        // Replacing the aliases releases the old ones
+        let mut relayer = repo.get_by_id("relayer-1".to_string()).await.unwrap();
+        relayer.aliases = vec!["erp-3".to_string()];
+        repo.update("relayer-1".to_string(), relayer).await.unwrap();
+        assert!(repo.get_by_alias("billing-7".to_string()).await.is_err());
+        assert_eq!(
+            repo.get_by_alias("erp-3".to_string()).await.unwrap().id,
+            "relayer-1"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_colliding_alias_rejected() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer_with_aliases("relayer-1", &["shared"]))
+            .await
+            .unwrap();
+
+        // Alias of another relayer
+        let result = repo
+            .create(create_test_relayer_with_aliases("relayer-2", &["shared"]))
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+
+        // Id of another relayer
+        let result = repo
+            .create(create_test_relayer_with_aliases(
+                "relayer-2",
+                &["relayer-1"],
+            ))
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+
+        // Id already used as an alias
+        let result = repo.create(create_test_relayer("shared".to_string())).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+
+        // Updates are checked as well
+        repo.create(create_test_relayer("relayer-2".to_string()))
+            .await
+            .unwrap();
+        let result = repo
+            .update(
+                "relayer-2".to_string(),
+                create_test_relayer_with_aliases("relayer-2", &["shared"]),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert_eq!(
+            repo.get_by_alias("shared".to_string()).await.unwrap().id,
+            "relayer-1"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_removes_aliases_from_index() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer_with_aliases(
+            "relayer-1",
+            &["billing-7"],
+        ))
+        .await
+        .unwrap();
+
+        repo.delete_by_id("relayer-1".to_string()).await.unwrap();
+        let result = repo.get_by_alias("billing-7".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+
+        // The alias can be reused once released
+        repo.create(create_test_relayer_with_aliases(
+            "relayer-2",
+            &["billing-7"],
+        ))
+        .await
+        .unwrap();
+        assert_eq!(
+            repo.get_by_alias("billing-7".to_string()).await.unwrap().id,
+            "relayer-2"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_changes_since_catches_up_after_mutations() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("a".to_string()))
+            .await
+            .unwrap();
+        let cursor = repo.changes_since(0).await.unwrap().last().unwrap().seq;
+
+        repo.create(create_test_relayer("b".to_string()))
+            .await
+            .unwrap();
+        repo.disable_relayer(
+            "a".to_string(),
+            DisabledReason::BalanceCheckFailed("low".to_string()),
+        )
+        .await
+        .unwrap();
+        repo.delete_by_id("b".to_string()).await.unwrap();
+
+        let changes = repo.changes_since(cursor).await.unwrap();
+        let summary: Vec<(u64, ChangeKind, &str)> = changes
+            .iter()
+            .map(|change| (change.seq, change.kind, change.id.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (2, ChangeKind::Created, "b"),
+                (3, ChangeKind::Updated, "a"),
+                (4, ChangeKind::Deleted, "b"),
+            ]
+        );
+        assert!(repo.changes_since(4).await.unwrap().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_changes_since_reports_evicted_changes() {
+        let repo = InMemoryRelayerRepository::new().with_change_log_capacity(2);
+        for id in ["a", "b", "c"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let result = repo.changes_since(0).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+
+        let seqs: Vec<u64> = repo
+            .changes_since(1)
+            .await
+            .unwrap()
+            .iter()
+            .map(|change| change.seq)
+            .collect();
+        assert_eq!(seqs, vec![2, 3]);
+    }
+
+    fn create_standby_relayer(id: &str, primary_id: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            standby_for: Some(primary_id.to_string()),
+            ..create_test_relayer(id.to_string())
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_standby_pairing_resolves_standby() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("primary".to_string()))
+            .await
+            .unwrap();
+        assert!(repo
+            .get_standby("primary".to_string())
+            .await
+            .unwrap()
+            .is_none());
+
+        repo.create(create_standby_relayer("standby", "primary"))
+            .await
+            .unwrap();
+
+        let standby = repo.get_standby("primary".to_string()).await.unwrap();
+        assert_eq!(
+            standby.map(|relayer| relayer.id),
+            Some("standby".to_string())
+        );
+
+        // A primary has at most one standby
+        let result = repo
+            .create(create_standby_relayer("second", "primary"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+
+        let result = repo.get_standby("missing".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_standby_pairing_rejects_cross_network_standby() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("primary".to_string()))
+            .await
+            .unwrap();
+
+        let mut standby = create_standby_relayer("standby", "primary");
+        standby.network = "OtherNet".to_string();
+        let result = repo.create(standby.clone()).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+
+        // Pairing an existing relayer through update is validated as well
+        standby.standby_for = None;
+        repo.create(standby.clone()).await.unwrap();
+        standby.standby_for = Some("primary".to_string());
+        let result = repo.update("standby".to_string(), standby).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert!(repo
+            .get_standby("primary".to_string())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_list_due_health_checks_honors_per_relayer_intervals() {
+        let repo = InMemoryRelayerRepository::new();
+        let now = Utc::now();
+        let checked_at = now - chrono::Duration::minutes(5);
+        for (id, interval_minutes) in [("fast", Some(1)), ("slow", Some(10)), ("manual", None)] {
+            let mut relayer = create_test_relayer(id.to_string());
+            relayer.health_check_interval =
+                interval_minutes.map(|minutes| Duration::from_secs(minutes * 60));
+            relayer.last_health_check_at = Some(checked_at);
+            repo.create(relayer).await.unwrap();
+        }
+        let mut never_checked = create_test_relayer("new".to_string());
+        never_checked.health_check_interval = Some(Duration::from_secs(600));
+        repo.create(never_checked).await.unwrap();
+
+        let mut due: Vec<String> = repo
+            .list_due_health_checks(now)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|relayer| relayer.id)
+            .collect();
+        due.sort();
+        assert_eq!(due, vec!["fast".to_string(), "new".to_string()]);
+    }
+
+    #[actix_web::test]
+    async fn test_record_health_check_updates_state() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.health_check_interval = Some(Duration::from_secs(60));
+        repo.create(relayer).await.unwrap();
+        let at = Utc::now();
+
+        let failure = HealthCheckFailure::RpcValidationFailed("timeout".to_string());
+        let updated = repo
+            .record_health_check("test".to_string(), Err(vec![failure.clone()]), at)
+            .await
+            .unwrap();
+        assert_eq!(updated.health, RelayerHealth::Unhealthy(vec![failure]));
+        assert_eq!(updated.last_health_check_at, Some(at));
+        assert!(repo.list_due_health_checks(at).await.unwrap().is_empty());
+
+        let updated = repo
+            .record_health_check("test".to_string(), Ok(()), at)
+            .await
+            .unwrap();
+        assert_eq!(updated.health, RelayerHealth::Healthy);
+
+        let result = repo
+            .record_health_check("missing".to_string(), Ok(()), at)
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_sunset_and_unsunset_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let sunset = repo.sunset("test".to_string()).await.unwrap();
+        assert!(sunset.sunset);
+        assert!(!sunset.is_operational());
+        assert!(sunset.accepts_inflight_completion());
+        // Sunset is distinct from pausing
+        assert!(!sunset.paused);
+
+        let restored = repo.unsunset("test".to_string()).await.unwrap();
+        assert!(!restored.sunset);
+        assert!(restored.is_operational());
+        assert!(!repo.get_by_id("test".to_string()).await.unwrap().sunset);
+
+        let result = repo.sunset("missing".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_pause_if_version_with_matching_version() {
+        let repo = InMemoryRelayerRepository::new();
+        let created = repo
+            .create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let paused = repo
+            .pause_if_version("test".to_string(), created.version)
+            .await
+            .unwrap();
+        assert!(paused.paused);
+        assert_eq!(paused.version, created.version + 1);
+        assert_eq!(
+            repo.get_by_id("test".to_string()).await.unwrap().version,
+            paused.version
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_pause_if_version_rejects_stale_version() {
+        let repo = InMemoryRelayerRepository::new();
+        let created = repo
+            .create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        // Another writer changes the relayer after it was read
+        repo.sunset("test".to_string()).await.unwrap();
+        let before = repo.get_by_id("test".to_string()).await.unwrap();
+
+        let result = repo
+            .pause_if_version("test".to_string(), created.version)
+            .await;
+        assert!(matches!(result, Err(RepositoryError::Conflict(_))));
+
+        let after = repo.get_by_id("test".to_string()).await.unwrap();
+        assert!(!after.paused);
+        assert_eq!(after.version, before.version);
+        assert_eq!(before.version, created.version + 1);
+
+        let result = repo.pause_if_version("missing".to_string(), 0).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_group_by_network_type_excludes_non_operational() {
+        let repo = InMemoryRelayerRepository::new();
+        let solana = |id: &str| RelayerRepoModel {
+            network_type: NetworkType::Solana,
+            policies: RelayerNetworkPolicy::Solana(Default::default()),
+            ..create_test_relayer(id.to_string())
+        };
+        for relayer in [
+            create_test_relayer("evm-b".to_string()),
+            create_test_relayer("evm-a".to_string()),
+            RelayerRepoModel {
+                paused: true,
+                ..create_test_relayer("evm-paused".to_string())
+            },
+            solana("solana-a"),
+            RelayerRepoModel {
+                paused: true,
+                ..solana("solana-paused")
+            },
+        ] {
+            repo.create(relayer).await.unwrap();
+        }
+        repo.sunset("evm-b".to_string()).await.unwrap();
+
+        let groups = repo.group_by_network_type().await.unwrap();
+        let ids = |network_type| -> Vec<String> {
+            groups[&network_type]
+                .iter()
+                .map(|relayer| relayer.id.clone())
+                .collect()
+        };
+        assert_eq!(groups.len(), 2);
+        assert_eq!(ids(NetworkType::Evm), vec!["evm-a".to_string()]);
+        assert_eq!(ids(NetworkType::Solana), vec!["solana-a".to_string()]);
+        assert!(!groups.contains_key(&NetworkType::Stellar));
+    }
+
+    #[actix_web::test]
+    async fn test_create_rejects_calldata_limit_below_minimum() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_calldata_bytes: Some(0),
+                ..RelayerEvmPolicy::default()
+            }),
+            ..create_test_relayer("test".to_string())
+        };
+
+        let result = repo.create(relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(repo.count().await.unwrap(), 0);
+    }
+
+    async fn create_signer_chain_repo() -> InMemoryRelayerRepository {
+        let signers = SignerRepositoryStorage::new_in_memory();
+        for (id, allowed_chains) in [
+            ("mainnet-signer", Some(vec![1, 10])),
+            ("testnet-signer", Some(vec![11155111])),
+            ("unrestricted-signer", None),
+        ] {
+            signers
+                .create(SignerRepoModel {
+                    id: id.to_string(),
+                    config: SignerConfigStorage::Local(LocalSignerConfigStorage {
+                        raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[1; 32])),
+                    }),
+                    allowed_chains,
+                })
+                .await
+                .unwrap();
+        }
+        InMemoryRelayerRepository::new().with_signer_chain_allowlist(Arc::new(signers))
+    }
+
+    fn create_chain_relayer(id: &str, signer_id: &str, chain_id: Option<u64>) -> RelayerRepoModel {
+        RelayerRepoModel {
+            signer_id: signer_id.to_string(),
+            chain_id,
+            ..create_test_relayer(id.to_string())
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_create_accepts_chain_allowed_for_signer() {
+        let repo = create_signer_chain_repo().await;
+        let relayer = create_chain_relayer("optimism", "mainnet-signer", Some(10));
+        assert!(repo.create(relayer).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_create_rejects_chain_not_allowed_for_signer() {
+        let repo = create_signer_chain_repo().await;
+        let relayer = create_chain_relayer("sepolia", "mainnet-signer", Some(11155111));
+
+        let result = repo.create(relayer.clone()).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(msg)) if msg.contains("11155111")
+        ));
+        assert!(repo.validate_create(relayer).await.is_err());
+        assert!(repo.is_empty().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_create_with_unrestricted_signer_or_no_chain() {
+        let repo = create_signer_chain_repo().await;
+        // A signer without an allowlist may back any chain
+        repo.create(create_chain_relayer(
+            "any",
+            "unrestricted-signer",
+            Some(137),
+        ))
+        .await
+        .unwrap();
+        // Relayers without a chain id are not checked
+        repo.create(create_chain_relayer("no-chain", "mainnet-signer", None))
+            .await
+            .unwrap();
+        // Repositories without an injected allowlist do not check either
+        InMemoryRelayerRepository::new()
+            .create(create_chain_relayer(
+                "sepolia",
+                "mainnet-signer",
+                Some(11155111),
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_rekey_rejects_signer_not_allowed_for_chain() {
+        let repo = create_signer_chain_repo().await;
+        repo.create(create_chain_relayer(
+            "sepolia",
+            "testnet-signer",
+            Some(11155111),
+        ))
+        .await
+        .unwrap();
+        let new_address = "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string();
+
+        let result = repo
+            .rekey(
+                "sepolia".to_string(),
+                "mainnet-signer".to_string(),
+                new_address.clone(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let stored = repo.get_by_id("sepolia".to_string()).await.unwrap();
+        assert_eq!(stored.signer_id, "testnet-signer");
+
+        let rekeyed = repo
+            .rekey(
+                "sepolia".to_string(),
+                "unrestricted-signer".to_string(),
+                new_address,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rekeyed.signer_id, "unrestricted-signer");
+    }
+
+    #[actix_web::test]
+    async fn test_validate_create_reports_every_failure() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("existing".to_string()))
+            .await
+            .unwrap();
+
+        let relayer = RelayerRepoModel {
+            tenant_id: String::new(),
+            extra_webhook_urls: Some(vec!["ftp://hooks.example.com".to_string()]),
+            fee_account: Some(FeeAccount {
+                address: "not-an-address".to_string(),
+                kind: FeeAccountKind::Treasury,
+            }),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_calldata_bytes: Some(1),
+                ..RelayerEvmPolicy::default()
+            }),
+            standby_for: Some("missing-primary".to_string()),
+            ..create_test_relayer("existing".to_string())
+        };
+
+        let errors = repo.validate_create(relayer.clone()).await.unwrap_err();
+        assert_eq!(errors.len(), 6, "{errors:?}");
+        let invalid_data = errors
+            .iter()
+            .filter(|e| matches!(e, RepositoryError::InvalidData(_)))
+            .count();
+        assert_eq!(invalid_data, 5, "{errors:?}");
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            RepositoryError::ConstraintViolation(msg) if msg.contains("already exists")
+        )));
+        assert!(errors
+            .iter()
+            .any(|e| e.to_string().contains("missing-primary")));
+
+        // create fails with the first of the reported errors, and nothing is stored
+        let create_error = repo.create(relayer).await.unwrap_err();
+        assert_eq!(create_error.to_string(), errors[0].to_string());
+        assert_eq!(repo.count().await.unwrap(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_create_accepts_valid_id() {
+        let repo = InMemoryRelayerRepository::new();
+        let id = format!("relayer_{}", "a".repeat(56));
+        assert_eq!(id.len(), 64);
+        let created = repo.create(create_test_relayer(id.clone())).await.unwrap();
+        assert_eq!(created.id, id);
+    }
+
+    #[actix_web::test]
+    async fn test_create_rejects_id_with_slash() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo
+            .create(create_test_relayer("relayer/1".to_string()))
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert!(repo.is_empty().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_create_rejects_over_length_id() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo.create(create_test_relayer("a".repeat(65))).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+    }
 
-    fn create_test_relayer(id: String) -> RelayerRepoModel {
+    #[actix_web::test]
+    async fn test_create_trims_id() {
+        let repo = InMemoryRelayerRepository::new();
+        let created = repo
+            .create(create_test_relayer("  relayer-1 ".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(created.id, "relayer-1");
+        assert!(repo.get_by_id("relayer-1".to_string()).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_create_with_custom_id_pattern() {
+        let repo = InMemoryRelayerRepository::new()
+            .with_id_pattern(Regex::new(r"^[a-z0-9-]{1,16}$").unwrap());
+        assert!(repo
+            .create(create_test_relayer("relayer-1".to_string()))
+            .await
+            .is_ok());
+        assert!(matches!(
+            repo.create(create_test_relayer("Relayer_2".to_string()))
+                .await,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let errors = repo
+            .validate_create(create_test_relayer("Relayer_2".to_string()))
+            .await
+            .unwrap_err();
+        assert!(matches!(errors[0], RepositoryError::ConstraintViolation(_)));
+    }
+
+    fn create_grouped_relayer(id: &str, group: &str, chain_id: Option<u64>) -> RelayerRepoModel {
         RelayerRepoModel {
-            id: id.clone(),
-            name: format!("Relayer {}", id.clone()),
-            network: "TestNet".to_string(),
-            paused: false,
-            network_type: NetworkType::Evm,
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
-                gas_price_cap: None,
-                whitelist_receivers: None,
-                eip1559_pricing: Some(false),
-                private_transactions: Some(false),
-                min_balance: Some(0),
-                gas_limit_estimation: Some(true),
-            }),
-            signer_id: "test".to_string(),
-            address: "0x".to_string(),
-            notification_id: None,
-            system_disabled: false,
-            custom_rpc_urls: None,
-            ..Default::default()
+            nonce_group: Some(group.to_string()),
+            chain_id,
+            ..create_test_relayer(id.to_string())
         }
     }
 
     #[actix_web::test]
-    async fn test_new_repository_is_empty() {
+    async fn test_list_by_nonce_group() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_grouped_relayer("b", "shared-eoa", Some(1)))
+            .await
+            .unwrap();
+        repo.create(create_grouped_relayer("a", "shared-eoa", Some(1)))
+            .await
+            .unwrap();
+        repo.create(create_grouped_relayer("c", "multisig", Some(1)))
+            .await
+            .unwrap();
+        repo.create(create_test_relayer("d".to_string()))
+            .await
+            .unwrap();
+
+        let members: Vec<String> = repo
+            .list_by_nonce_group("shared-eoa")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|relayer| relayer.id)
+            .collect();
+        assert_eq!(members, vec!["a", "b"]);
+        assert!(repo
+            .list_by_nonce_group("missing")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_nonce_group_rejects_member_on_another_chain() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_grouped_relayer("a", "shared-eoa", Some(1)))
+            .await
+            .unwrap();
+
+        let result = repo
+            .create(create_grouped_relayer("b", "shared-eoa", Some(10)))
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+
+        // Moving an existing member to another chain is rejected as well
+        repo.create(create_grouped_relayer("b", "shared-eoa", Some(1)))
+            .await
+            .unwrap();
+        let result = repo
+            .update(
+                "b".to_string(),
+                create_grouped_relayer("b", "shared-eoa", Some(10)),
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(
+            repo.list_by_nonce_group("shared-eoa").await.unwrap().len(),
+            2
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_signature_scheme_checked_on_store() {
         let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.signature_scheme = Some(SignatureScheme::Ed25519);
+        let result = repo.create(relayer.clone()).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+
+        relayer.signature_scheme = None;
+        let created = repo.create(relayer.clone()).await.unwrap();
+        assert_eq!(created.signature_scheme(), SignatureScheme::Secp256k1);
+
+        relayer.signature_scheme = Some(SignatureScheme::Ed25519);
+        let result = repo.update("test".to_string(), relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(stored.signature_scheme, None);
+    }
+
+    #[actix_web::test]
+    async fn test_validate_create_does_not_persist() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = create_test_relayer("new".to_string());
+
+        assert!(repo.validate_create(relayer.clone()).await.is_ok());
+        assert!(repo.is_empty().await.unwrap());
+        assert!(repo.changes_since(0).await.unwrap().is_empty());
+
+        repo.create(relayer).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_gas_bump_below_replacement_minimum_is_rejected() {
+        let repo = InMemoryRelayerRepository::new();
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_bump: Some(GasBumpPolicy {
+                bump_percent: 5,
+                max_bumps: 3,
+                bump_interval_secs: 60,
+            }),
+            ..RelayerEvmPolicy::default()
+        });
+
+        let relayer = RelayerRepoModel {
+            policies: policy.clone(),
+            ..create_test_relayer("test".to_string())
+        };
+        let result = repo.create(relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        let result = repo.update_policy("test".to_string(), policy).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_create_rejects_invalid_fee_account() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = RelayerRepoModel {
+            fee_account: Some(FeeAccount {
+                address: "not-an-address".to_string(),
+                kind: FeeAccountKind::Paymaster,
+            }),
+            ..create_test_relayer("test".to_string())
+        };
+
+        let result = repo.create(relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
         assert_eq!(repo.count().await.unwrap(), 0);
     }
 
     #[actix_web::test]
-    async fn test_add_relayer() {
+    async fn test_list_by_fee_account() {
         let repo = InMemoryRelayerRepository::new();
-        let relayer = create_test_relayer("test".to_string());
+        let paymaster = "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E";
+        let with_fee_account = |id: &str, address: &str| RelayerRepoModel {
+            fee_account: Some(FeeAccount {
+                address: address.to_string(),
+                kind: FeeAccountKind::Paymaster,
+            }),
+            ..create_test_relayer(id.to_string())
+        };
+        repo.create(with_fee_account("a", paymaster)).await.unwrap();
+        repo.create(with_fee_account("b", &paymaster.to_lowercase()))
+            .await
+            .unwrap();
+        repo.create(with_fee_account(
+            "c",
+            "0x0000000000000000000000000000000000000001",
+        ))
+        .await
+        .unwrap();
+        repo.create(create_test_relayer("d".to_string()))
+            .await
+            .unwrap();
 
-        repo.create(relayer.clone()).await.unwrap();
+        // EVM addresses match regardless of checksum casing
+        let mut ids: Vec<_> = repo
+            .list_by_fee_account(&paymaster.to_uppercase().replacen("0X", "0x", 1))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|relayer| relayer.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["a", "b"]);
+
+        assert!(repo
+            .list_by_fee_account("0x0000000000000000000000000000000000000002")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_quarantine_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer_id = "test_relayer".to_string();
+        repo.create(create_test_relayer(relayer_id.clone()))
+            .await
+            .unwrap();
+
+        let until = Utc::now() + chrono::Duration::minutes(10);
+        let quarantined = repo
+            .quarantine(relayer_id.clone(), until, "repeated failures".to_string())
+            .await
+            .unwrap();
+
+        assert!(quarantined.system_disabled);
+        assert_eq!(
+            quarantined.disabled_reason,
+            Some(DisabledReason::Quarantined("repeated failures".to_string()))
+        );
+        assert_eq!(quarantined.quarantined_until, Some(until));
+        assert!(!quarantined.is_operational());
+
+        // Enabling clears the quarantine
+        let enabled = repo.enable_relayer(relayer_id).await.unwrap();
+        assert!(enabled.quarantined_until.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_quarantine_nonexistent_relayer() {
+        let repo = InMemoryRelayerRepository::new();
+
+        let result = repo
+            .quarantine("missing".to_string(), Utc::now(), "reason".to_string())
+            .await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_list_expired_quarantines() {
+        let repo = InMemoryRelayerRepository::new();
+        let now = Utc::now();
+        for id in ["expired", "active", "disabled"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+
+        repo.quarantine(
+            "expired".to_string(),
+            now - chrono::Duration::minutes(1),
+            "cooldown".to_string(),
+        )
+        .await
+        .unwrap();
+        repo.quarantine(
+            "active".to_string(),
+            now + chrono::Duration::minutes(10),
+            "cooldown".to_string(),
+        )
+        .await
+        .unwrap();
+        repo.disable_relayer(
+            "disabled".to_string(),
+            DisabledReason::BalanceCheckFailed("low balance".to_string()),
+        )
+        .await
+        .unwrap();
+
+        let expired = repo.list_expired_quarantines(now).await.unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, "expired");
+
+        // The still-active quarantine shows up once its window has passed
+        let later = repo
+            .list_expired_quarantines(now + chrono::Duration::minutes(11))
+            .await
+            .unwrap();
+        assert_eq!(later.len(), 2);
+        assert!(later
+            .iter()
+            .all(|r| r.is_operational_at(now + chrono::Duration::minutes(11))));
+    }
+
+    #[actix_web::test]
+    async fn test_create_idempotent_repeated_key_returns_original() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = create_test_relayer("relayer-1".to_string());
+
+        let first = repo
+            .create_idempotent(relayer, "key-1".to_string())
+            .await
+            .unwrap();
+
+        // A retry with the same key but a freshly generated ID must not create a duplicate
+        let retry = create_test_relayer("relayer-2".to_string());
+        let second = repo
+            .create_idempotent(retry, "key-1".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(first.id, "relayer-1");
+        assert_eq!(second.id, "relayer-1");
         assert_eq!(repo.count().await.unwrap(), 1);
+    }
 
-        let stored = repo.get_by_id("test".to_string()).await.unwrap();
-        assert_eq!(stored.id, relayer.id);
-        assert_eq!(stored.name, relayer.name);
+    #[actix_web::test]
+    async fn test_create_idempotent_retry_after_delete_creates_again() {
+        let repo = InMemoryRelayerRepository::new();
+        let retry = || create_test_relayer("relayer-1".to_string());
+
+        repo.create_idempotent(retry(), "key-1".to_string())
+            .await
+            .unwrap();
+        repo.delete_by_id("relayer-1".to_string()).await.unwrap();
+        let recreated = repo
+            .create_idempotent(retry(), "key-1".to_string())
+            .await
+            .unwrap();
+        assert_eq!(recreated.id, "relayer-1");
+
+        repo.delete_many(vec!["relayer-1".to_string()])
+            .await
+            .unwrap();
+        assert!(repo
+            .create_idempotent(retry(), "key-1".to_string())
+            .await
+            .is_ok());
+
+        repo.drop_all_entries().await.unwrap();
+        assert!(repo
+            .create_idempotent(retry(), "key-1".to_string())
+            .await
+            .is_ok());
+        assert_eq!(repo.count().await.unwrap(), 1);
     }
 
     #[actix_web::test]
-    async fn test_update_relayer() {
+    async fn test_create_idempotent_different_keys_create_distinct_relayers() {
         let repo = InMemoryRelayerRepository::new();
-        let mut relayer = create_test_relayer("test".to_string());
 
-        repo.create(relayer.clone()).await.unwrap();
+        let first = repo
+            .create_idempotent(
+                create_test_relayer("relayer-1".to_string()),
+                "key-1".to_string(),
+            )
+            .await
+            .unwrap();
+        let second = repo
+            .create_idempotent(
+                create_test_relayer("relayer-2".to_string()),
+                "key-2".to_string(),
+            )
+            .await
+            .unwrap();
 
-        relayer.name = "Updated Name".to_string();
-        repo.update("test".to_string(), relayer.clone())
+        assert_ne!(first.id, second.id);
+        assert_eq!(repo.count().await.unwrap(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_create_idempotent_concurrent_with_compact_does_not_deadlock() {
+        let repo = Arc::new(InMemoryRelayerRepository::new());
+        let mut tasks = Vec::new();
+        for i in 0..50 {
+            let creator = repo.clone();
+            tasks.push(tokio::spawn(async move {
+                creator
+                    .create_idempotent(
+                        create_test_relayer(format!("relayer-{i}")),
+                        format!("key-{i}"),
+                    )
+                    .await
+                    .map(|_| ())
+            }));
+            let compactor = repo.clone();
+            tasks.push(tokio::spawn(async move { compactor.compact().await }));
+        }
+
+        let finished = tokio::time::timeout(Duration::from_secs(10), async {
+            for task in tasks {
+                task.await.unwrap().unwrap();
+            }
+        })
+        .await;
+        assert!(finished.is_ok(), "create_idempotent and compact deadlocked");
+        assert_eq!(repo.count().await.unwrap(), 50);
+    }
+
+    #[actix_web::test]
+    async fn test_create_idempotent_empty_key() {
+        let repo = InMemoryRelayerRepository::new();
+
+        let result = repo
+            .create_idempotent(create_test_relayer("relayer-1".to_string()), "".to_string())
+            .await;
+
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(repo.count().await.unwrap(), 0);
+    }
+
+    #[actix_web::test]
+    async fn test_list_high_error_rate() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("failing".to_string()))
+            .await
+            .unwrap();
+        repo.create(create_test_relayer("healthy".to_string()))
+            .await
+            .unwrap();
+        repo.create(create_test_relayer("idle".to_string()))
             .await
             .unwrap();
 
-        let updated = repo.get_by_id("test".to_string()).await.unwrap();
-        assert_eq!(updated.name, "Updated Name");
+        for success in [false, false, false, true] {
+            repo.record_outcome("failing".to_string(), success)
+                .await
+                .unwrap();
+        }
+        for success in [true, true, true, false] {
+            repo.record_outcome("healthy".to_string(), success)
+                .await
+                .unwrap();
+        }
+
+        let window = Duration::from_secs(60);
+        let high = repo.list_high_error_rate(window, 0.5).await.unwrap();
+        let ids: Vec<&str> = high.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["failing"]);
+
+        let high = repo.list_high_error_rate(window, 0.2).await.unwrap();
+        let ids: Vec<&str> = high.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["failing", "healthy"]);
+
+        assert!(repo
+            .list_high_error_rate(window, 0.75)
+            .await
+            .unwrap()
+            .is_empty());
     }
 
     #[actix_web::test]
-    async fn test_list_relayers() {
+    async fn test_list_high_error_rate_drops_outcomes_past_limit() {
+        let repo = InMemoryRelayerRepository::new().with_outcome_history_limit(2);
+        repo.create(create_test_relayer("relayer".to_string()))
+            .await
+            .unwrap();
+
+        for success in [false, false, true, true] {
+            repo.record_outcome("relayer".to_string(), success)
+                .await
+                .unwrap();
+        }
+
+        let relayer = repo.get_by_id("relayer".to_string()).await.unwrap();
+        assert_eq!(relayer.recent_outcomes.len(), 2);
+        assert!(repo
+            .list_high_error_rate(Duration::from_secs(60), 0.0)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_list_high_error_rate_rejects_invalid_threshold() {
         let repo = InMemoryRelayerRepository::new();
-        let relayer1 = create_test_relayer("test".to_string());
-        let relayer2 = create_test_relayer("test2".to_string());
+        let result = repo
+            .list_high_error_rate(Duration::from_secs(60), 1.5)
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
 
-        repo.create(relayer1.clone()).await.unwrap();
-        repo.create(relayer2).await.unwrap();
+    #[actix_web::test]
+    async fn test_enforce_allowlist() {
+        let repo = InMemoryRelayerRepository::new();
+        for id in ["allowed", "dropped", "manual"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+        repo.partial_update(
+            "manual".to_string(),
+            UpdateRelayerRequest {
+                paused: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
 
-        let relayers = repo.list_all().await.unwrap();
-        assert_eq!(relayers.len(), 2);
+        let allowed: HashSet<String> = ["allowed".to_string()].into_iter().collect();
+        let report = repo.enforce_allowlist(allowed).await.unwrap();
+        assert_eq!(report.paused, vec!["dropped".to_string()]);
+        assert!(report.resumed.is_empty());
+        let dropped = repo.get_by_id("dropped".to_string()).await.unwrap();
+        assert!(dropped.paused);
+        assert!(dropped.paused_by_enforcement);
+        assert!(!repo.get_by_id("allowed".to_string()).await.unwrap().paused);
+
+        // Re-adding the dropped relayer resumes it, while the manual pause stays
+        let allowed: HashSet<String> = ["allowed", "dropped", "manual"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let report = repo.enforce_allowlist(allowed.clone()).await.unwrap();
+        assert!(report.paused.is_empty());
+        assert_eq!(report.resumed, vec!["dropped".to_string()]);
+        let dropped = repo.get_by_id("dropped".to_string()).await.unwrap();
+        assert!(!dropped.paused);
+        assert!(!dropped.paused_by_enforcement);
+        assert!(repo.get_by_id("manual".to_string()).await.unwrap().paused);
+
+        // Enforcing the same allowlist again changes nothing
+        assert_eq!(
+            repo.enforce_allowlist(allowed).await.unwrap(),
+            EnforceReport::default()
+        );
     }
 
     #[actix_web::test]
-    async fn test_list_active_relayers() {
+    async fn test_manual_resume_clears_enforcement_marker() {
         let repo = InMemoryRelayerRepository::new();
-        let relayer1 = create_test_relayer("test".to_string());
-        let mut relayer2 = create_test_relayer("test2".to_string());
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        repo.enforce_allowlist(HashSet::new()).await.unwrap();
+        assert!(
+            repo.get_by_id("test".to_string())
+                .await
+                .unwrap()
+                .paused_by_enforcement
+        );
 
-        relayer2.paused = true;
+        let resumed = repo
+            .partial_update(
+                "test".to_string(),
+                UpdateRelayerRequest {
+                    paused: Some(false),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert!(!resumed.paused);
+        assert!(!resumed.paused_by_enforcement);
+    }
 
-        repo.create(relayer1.clone()).await.unwrap();
-        repo.create(relayer2).await.unwrap();
+    #[actix_web::test]
+    async fn test_set_log_level() {
+        let repo = InMemoryRelayerRepository::new();
+        let relayer = repo
+            .create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(relayer.log_level(), None);
 
-        let active_relayers = repo.list_active().await.unwrap();
-        assert_eq!(active_relayers.len(), 1);
-        assert_eq!(active_relayers[0].id, "test".to_string());
+        let updated = repo
+            .set_log_level("test".to_string(), Some(LogLevel::Trace))
+            .await
+            .unwrap();
+        assert_eq!(updated.log_level(), Some(LogLevel::Trace));
+        assert_eq!(updated.version, relayer.version + 1);
+        let stored = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(stored.log_level_or(LogLevel::Info), LogLevel::Trace);
+
+        let cleared = repo.set_log_level("test".to_string(), None).await.unwrap();
+        assert_eq!(cleared.log_level(), None);
+        assert_eq!(cleared.log_level_or(LogLevel::Info), LogLevel::Info);
     }
 
     #[actix_web::test]
-    async fn test_update_nonexistent_relayer() {
+    async fn test_set_log_level_unknown_relayer() {
         let repo = InMemoryRelayerRepository::new();
-        let relayer = create_test_relayer("test".to_string());
-
-        let result = repo.update("test".to_string(), relayer).await;
+        let result = repo
+            .set_log_level("missing".to_string(), Some(LogLevel::Debug))
+            .await;
         assert!(matches!(result, Err(RepositoryError::NotFound(_))));
     }
 
     #[actix_web::test]
-    async fn test_get_nonexistent_relayer() {
+    async fn test_list_paginated_at_reads_pinned_snapshot() {
         let repo = InMemoryRelayerRepository::new();
+        for i in 1..=4 {
+            repo.create(create_test_relayer(format!("relayer-{i}")))
+                .await
+                .unwrap();
+        }
+        let token = repo.begin_consistent_read().await.unwrap();
+        let query = |page| PaginationQuery {
+            page,
+            per_page: Some(2),
+        };
 
-        let result = repo.get_by_id("test".to_string()).await;
-        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+        let first = repo
+            .list_paginated_at(query(1), Some(&token))
+            .await
+            .unwrap();
+        let ids: Vec<&str> = first.items.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["relayer-1", "relayer-2"]);
+
+        // Mutate the live store between pages
+        repo.delete_by_id("relayer-3".to_string()).await.unwrap();
+        repo.create(create_test_relayer("relayer-0".to_string()))
+            .await
+            .unwrap();
+        repo.partial_update(
+            "relayer-4".to_string(),
+            UpdateRelayerRequest {
+                paused: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let second = repo
+            .list_paginated_at(query(2), Some(&token))
+            .await
+            .unwrap();
+        let ids: Vec<&str> = second.items.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["relayer-3", "relayer-4"]);
+        assert!(!second.items[1].paused);
+        assert_eq!(second.total, 4);
+
+        // Without a token the live store is read
+        let live = repo.list_paginated_at(query(1), None).await.unwrap();
+        assert_eq!(live.total, 4);
+        assert!(live.items.iter().all(|r| r.id != "relayer-3"));
+        assert!(repo.begin_consistent_read().await.unwrap().seq() > token.seq());
     }
 
     #[actix_web::test]
-    async fn test_partial_update_relayer() {
+    async fn test_list_paginated_at_rejects_zero_page() {
         let repo = InMemoryRelayerRepository::new();
+        let token = repo.begin_consistent_read().await.unwrap();
+        let result = repo
+            .list_paginated_at(
+                PaginationQuery {
+                    page: 0,
+                    per_page: Some(10),
+                },
+                Some(&token),
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
 
-        // Add a relayer to the repository
-        let relayer_id = "test_relayer".to_string();
-        let initial_relayer = create_test_relayer(relayer_id.clone());
-
-        repo.create(initial_relayer.clone()).await.unwrap();
+    #[actix_web::test]
+    async fn test_decommission_drains_then_deletes() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
 
-        // Perform a partial update on the relayer
-        let update_req = UpdateRelayerRequest {
-            name: None,
-            paused: Some(true),
-            policies: None,
-            notification_id: None,
-            custom_rpc_urls: None,
-        };
+        let mut draining = MockInflightChecker::new();
+        draining
+            .expect_inflight_count()
+            .returning(|_| Box::pin(ready(Ok(3))));
+        let state = repo
+            .decommission("test".to_string(), &draining)
+            .await
+            .unwrap();
+        assert_eq!(state, DecommissionState::Draining { remaining: 3 });
+        let relayer = repo.get_by_id("test".to_string()).await.unwrap();
+        assert!(relayer.sunset);
 
-        let updated_relayer = repo
-            .partial_update(relayer_id.clone(), update_req)
+        // Polling again while draining leaves the relayer untouched
+        let state = repo
+            .decommission("test".to_string(), &draining)
             .await
             .unwrap();
+        assert_eq!(state, DecommissionState::Draining { remaining: 3 });
+        let polled = repo.get_by_id("test".to_string()).await.unwrap();
+        assert_eq!(polled.version, relayer.version);
 
-        assert_eq!(updated_relayer.id, initial_relayer.id);
-        assert!(updated_relayer.paused);
+        let mut drained = MockInflightChecker::new();
+        drained
+            .expect_inflight_count()
+            .returning(|_| Box::pin(ready(Ok(0))));
+        let state = repo
+            .decommission("test".to_string(), &drained)
+            .await
+            .unwrap();
+        assert_eq!(state, DecommissionState::Decommissioned);
+        assert!(matches!(
+            repo.get_by_id("test".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
     }
 
     #[actix_web::test]
-    async fn test_disable_relayer() {
+    async fn test_decommission_keeps_relayer_when_check_fails() {
         let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
 
-        // Add a relayer to the repository
-        let relayer_id = "test_relayer".to_string();
-        let initial_relayer = create_test_relayer(relayer_id.clone());
+        let mut checker = MockInflightChecker::new();
+        checker.expect_inflight_count().returning(|_| {
+            Box::pin(ready(Err(RepositoryError::ConnectionError(
+                "redis down".to_string(),
+            ))))
+        });
+        let result = repo.decommission("test".to_string(), &checker).await;
+        assert!(matches!(result, Err(RepositoryError::ConnectionError(_))));
+        assert!(repo.get_by_id("test".to_string()).await.unwrap().sunset);
+    }
 
-        repo.create(initial_relayer.clone()).await.unwrap();
+    #[actix_web::test]
+    async fn test_get_many_by_address() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut evm = create_test_relayer("evm".to_string());
+        evm.address = "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string();
+        repo.create(evm).await.unwrap();
+        let mut other = create_test_relayer("other".to_string());
+        other.address = "0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string();
+        repo.create(other).await.unwrap();
+        let mut solana = create_test_relayer("solana".to_string());
+        solana.network_type = NetworkType::Solana;
+        solana.policies =
+            RelayerNetworkPolicy::Solana(crate::models::RelayerSolanaPolicy::default());
+        solana.address = "0x742d35cc6634c0532925a3b8d8c2e48a73f6ba2e".to_string();
+        repo.create(solana).await.unwrap();
 
-        // Disable the relayer
-        let disabled_relayer = repo
-            .disable_relayer(
-                relayer_id.clone(),
-                DisabledReason::BalanceCheckFailed("test reason".to_string()),
+        let found = repo
+            .get_many_by_address(
+                vec![
+                    "0x742D35CC6634C0532925A3B8D8C2E48A73F6BA2E".to_string(),
+                    "0x0000000000000000000000000000000000000001".to_string(),
+                    "0xdac17f958d2ee523a2206206994597c13d831ec7".to_string(),
+                ],
+                NetworkType::Evm,
             )
             .await
             .unwrap();
 
-        assert_eq!(disabled_relayer.id, initial_relayer.id);
-        assert!(disabled_relayer.system_disabled);
+        assert_eq!(found.len(), 2);
         assert_eq!(
-            disabled_relayer.disabled_reason,
-            Some(DisabledReason::BalanceCheckFailed(
-                "test reason".to_string()
-            ))
+            found["0x742d35cc6634c0532925a3b8d8c2e48a73f6ba2e"].id,
+            "evm"
+        );
+        assert_eq!(
+            found["0xdac17f958d2ee523a2206206994597c13d831ec7"].id,
+            "other"
         );
     }
 
     #[actix_web::test]
-    async fn test_enable_relayer() {
+    async fn test_get_many_by_address_no_matches() {
         let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
 
-        // Add a relayer to the repository
-        let relayer_id = "test_relayer".to_string();
-        let mut initial_relayer = create_test_relayer(relayer_id.clone());
+        let found = repo
+            .get_many_by_address(
+                vec!["0x0000000000000000000000000000000000000001".to_string()],
+                NetworkType::Evm,
+            )
+            .await
+            .unwrap();
+        assert!(found.is_empty());
+        assert!(repo
+            .get_many_by_address(vec![], NetworkType::Evm)
+            .await
+            .unwrap()
+            .is_empty());
+    }
 
-        initial_relayer.system_disabled = true;
+    #[actix_web::test]
+    async fn test_update_policy_conflicts_while_config_read_guard_held() {
+        let repo =
+            InMemoryRelayerRepository::new().with_config_lock_timeout(Duration::from_millis(50));
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
 
-        repo.create(initial_relayer.clone()).await.unwrap();
+        let guard = repo.acquire_config_read("test".to_string()).await.unwrap();
+        assert_eq!(guard.relayer().id, "test");
 
-        // Enable the relayer
-        let enabled_relayer = repo.enable_relayer(relayer_id.clone()).await.unwrap();
+        let policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(1_000),
+            ..Default::default()
+        });
+        assert!(matches!(
+            repo.update_policy("test".to_string(), policy.clone()).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+        // The pinned view is unchanged and state changes are not blocked
+        assert_eq!(
+            guard.relayer().policies,
+            repo.get_by_id("test".to_string()).await.unwrap().policies
+        );
+        assert!(repo.record_outcome("test".to_string(), true).await.is_ok());
 
-        assert_eq!(enabled_relayer.id, initial_relayer.id);
-        assert!(!enabled_relayer.system_disabled);
+        drop(guard);
+        let updated = repo
+            .update_policy("test".to_string(), policy.clone())
+            .await
+            .unwrap();
+        assert_eq!(updated.policies, policy);
     }
 
     #[actix_web::test]
-    async fn test_update_policy() {
+    async fn test_update_waits_for_config_read_guard_release() {
+        let repo = Arc::new(InMemoryRelayerRepository::new());
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+
+        let guard = repo.acquire_config_read("test".to_string()).await.unwrap();
+        let writer = {
+            let repo = repo.clone();
+            let mut relayer = guard.relayer().clone();
+            relayer.name = "Renamed".to_string();
+            tokio::spawn(async move { repo.update("test".to_string(), relayer).await })
+        };
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!writer.is_finished());
+        assert_eq!(
+            repo.get_by_id("test".to_string()).await.unwrap().name,
+            guard.relayer().name
+        );
+
+        drop(guard);
+        let updated = writer.await.unwrap().unwrap();
+        assert_eq!(updated.name, "Renamed");
+    }
+
+    #[actix_web::test]
+    async fn test_acquire_config_read_not_found() {
         let repo = InMemoryRelayerRepository::new();
-        let relayer = create_test_relayer("test".to_string());
+        assert!(matches!(
+            repo.acquire_config_read("missing".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_allowed_origins_rejected_at_store_time() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("test".to_string());
+        relayer.allowed_origins = Some(vec!["https://example.com/path".to_string()]);
+        assert!(matches!(
+            repo.create(relayer.clone()).await,
+            Err(RepositoryError::InvalidData(_))
+        ));
 
+        relayer.allowed_origins = Some(vec!["https://*.example.com".to_string()]);
         repo.create(relayer.clone()).await.unwrap();
 
-        // Create a new policy to update
-        let new_policy = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
-            gas_price_cap: Some(50000000000),
-            whitelist_receivers: Some(vec!["0x1234".to_string()]),
-            eip1559_pricing: Some(true),
-            private_transactions: Some(true),
-            min_balance: Some(1000000),
-            gas_limit_estimation: Some(true),
-        });
+        relayer.allowed_origins = Some(vec!["*.example.com".to_string()]);
+        assert!(matches!(
+            repo.update("test".to_string(), relayer).await,
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
 
-        // Update the policy
-        let updated_relayer = repo
-            .update_policy("test".to_string(), new_policy.clone())
+    #[actix_web::test]
+    async fn test_increment_and_decrement_pending() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("test".to_string()))
             .await
             .unwrap();
+        assert_eq!(repo.pending_count("test".to_string()).await.unwrap(), 0);
 
-        // Verify the policy was updated
-        match updated_relayer.policies {
-            RelayerNetworkPolicy::Evm(policy) => {
-                assert_eq!(policy.gas_price_cap, Some(50000000000));
-                assert_eq!(policy.whitelist_receivers, Some(vec!["0x1234".to_string()]));
-                assert_eq!(policy.eip1559_pricing, Some(true));
-                assert!(policy.private_transactions.unwrap_or(false));
-                assert_eq!(policy.min_balance, Some(1000000));
-            }
-            _ => panic!("Unexpected policy type"),
-        }
+        assert_eq!(repo.increment_pending("test".to_string()).await.unwrap(), 1);
+        assert_eq!(repo.increment_pending("test".to_string()).await.unwrap(), 2);
+        assert_eq!(repo.decrement_pending("test".to_string()).await.unwrap(), 1);
+        assert_eq!(repo.pending_count("test".to_string()).await.unwrap(), 1);
+
+        assert!(matches!(
+            repo.increment_pending("missing".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+        assert!(matches!(
+            repo.pending_count("missing".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
     }
 
-    // test has_entries
     #[actix_web::test]
-    async fn test_has_entries() {
+    async fn test_pending_counter_is_not_versioned_config() {
         let repo = InMemoryRelayerRepository::new();
-        assert!(!repo.has_entries().await.unwrap());
+        let created = repo
+            .create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
+        let seq = repo.changes_since(0).await.unwrap().len();
 
-        let relayer = create_test_relayer("test".to_string());
+        repo.increment_pending("test".to_string()).await.unwrap();
+        repo.increment_pending("test".to_string()).await.unwrap();
+        repo.decrement_pending("test".to_string()).await.unwrap();
 
-        repo.create(relayer.clone()).await.unwrap();
-        assert!(repo.has_entries().await.unwrap());
+        // Counter ticks neither bump the version nor fill the change log
+        assert_eq!(repo.changes_since(0).await.unwrap().len(), seq);
+        let paused = repo
+            .pause_if_version("test".to_string(), created.version)
+            .await
+            .unwrap();
+        assert_eq!(paused.pending_count, 1);
+
+        // Updating from a copy read before the ticks keeps the stored counter
+        let updated = repo.update("test".to_string(), created).await.unwrap();
+        assert_eq!(updated.pending_count, 1);
+        assert_eq!(repo.pending_count("test".to_string()).await.unwrap(), 1);
     }
 
     #[actix_web::test]
-    async fn test_drop_all_entries() {
+    async fn test_decrement_pending_saturates_at_zero() {
         let repo = InMemoryRelayerRepository::new();
-        let relayer = create_test_relayer("test".to_string());
+        repo.create(create_test_relayer("test".to_string()))
+            .await
+            .unwrap();
 
-        repo.create(relayer.clone()).await.unwrap();
+        assert_eq!(repo.decrement_pending("test".to_string()).await.unwrap(), 0);
+        assert_eq!(repo.increment_pending("test".to_string()).await.unwrap(), 1);
+        assert_eq!(repo.decrement_pending("test".to_string()).await.unwrap(), 0);
+        assert_eq!(repo.decrement_pending("test".to_string()).await.unwrap(), 0);
+        assert_eq!(repo.pending_count("test".to_string()).await.unwrap(), 0);
+    }
 
-        assert!(repo.has_entries().await.unwrap());
+    #[actix_web::test]
+    async fn test_pending_count_reflected_in_summary() {
+        let repo = InMemoryRelayerRepository::new();
+        for id in ["relayer-1", "relayer-2"] {
+            repo.create(create_test_relayer(id.to_string()))
+                .await
+                .unwrap();
+        }
+        repo.increment_pending("relayer-1".to_string())
+            .await
+            .unwrap();
+        repo.increment_pending("relayer-1".to_string())
+            .await
+            .unwrap();
+        repo.increment_pending("relayer-2".to_string())
+            .await
+            .unwrap();
 
-        repo.drop_all_entries().await.unwrap();
-        assert!(!repo.has_entries().await.unwrap());
+        let summary = repo.snapshot().await.unwrap().summary();
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.pending, 3);
+
+        repo.decrement_pending("relayer-2".to_string())
+            .await
+            .unwrap();
+        assert_eq!(repo.snapshot().await.unwrap().summary().pending, 2);
     }
 
     #[actix_web::test]
-    async fn test_list_by_signer_id() {
+    async fn test_lint() {
         let repo = InMemoryRelayerRepository::new();
-
-        // Create test relayers with different signers
-        let relayer1 = RelayerRepoModel {
-            id: "relayer-1".to_string(),
-            name: "Relayer 1".to_string(),
-            network: "ethereum".to_string(),
-            paused: false,
-            network_type: NetworkType::Evm,
-            signer_id: "signer-alpha".to_string(),
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            address: "0x1111".to_string(),
-            notification_id: None,
-            system_disabled: false,
-            custom_rpc_urls: None,
+        let mut clean = create_test_relayer("clean".to_string());
+        clean.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: Some(1),
             ..Default::default()
-        };
-
-        let relayer2 = RelayerRepoModel {
-            id: "relayer-2".to_string(),
-            name: "Relayer 2".to_string(),
-            network: "polygon".to_string(),
-            paused: true,
-            network_type: NetworkType::Evm,
-            signer_id: "signer-alpha".to_string(), // Same signer as relayer1
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            address: "0x2222".to_string(),
-            notification_id: None,
-            system_disabled: false,
-            custom_rpc_urls: None,
+        });
+        repo.create(clean).await.unwrap();
+        let mut relayer = create_test_relayer("unfunded".to_string());
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: Some(0),
             ..Default::default()
-        };
+        });
+        repo.create(relayer).await.unwrap();
 
-        let relayer3 = RelayerRepoModel {
-            id: "relayer-3".to_string(),
-            name: "Relayer 3".to_string(),
-            network: "solana".to_string(),
-            paused: false,
-            network_type: NetworkType::Solana,
-            signer_id: "signer-beta".to_string(), // Different signer
-            policies: RelayerNetworkPolicy::Solana(crate::models::RelayerSolanaPolicy::default()),
-            address: "solana-addr".to_string(),
-            notification_id: None,
-            system_disabled: false,
-            custom_rpc_urls: None,
-            ..Default::default()
-        };
+        assert!(repo.lint("clean".to_string()).await.unwrap().is_empty());
+        let warnings = repo.lint("unfunded".to_string()).await.unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "policies.min_balance");
+        assert!(matches!(
+            repo.lint("missing".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
 
-        let relayer4 = RelayerRepoModel {
-            id: "relayer-4".to_string(),
-            name: "Relayer 4".to_string(),
-            network: "stellar".to_string(),
-            paused: false,
-            network_type: NetworkType::Stellar,
-            signer_id: "signer-alpha".to_string(), // Same signer as relayer1 and relayer2
-            policies: RelayerNetworkPolicy::Stellar(crate::models::RelayerStellarPolicy::default()),
-            address: "stellar-addr".to_string(),
-            notification_id: Some("notification-1".to_string()),
-            system_disabled: true,
-            custom_rpc_urls: None,
-            ..Default::default()
-        };
+    #[actix_web::test]
+    async fn test_policy_validation_report_groups_failures_by_kind() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("valid".to_string()))
+            .await
+            .unwrap();
+        for id in ["mismatch-b", "mismatch-a"] {
+            let mut relayer = create_test_relayer(id.to_string());
+            relayer.network_type = NetworkType::Stellar;
+            repo.create(relayer).await.unwrap();
+        }
+        let mut unnamed = create_test_relayer("unnamed".to_string());
+        unnamed.name = String::new();
+        repo.create(unnamed).await.unwrap();
 
-        // Add all relayers to the repository
-        repo.create(relayer1).await.unwrap();
-        repo.create(relayer2).await.unwrap();
-        repo.create(relayer3).await.unwrap();
-        repo.create(relayer4).await.unwrap();
+        let report = repo.policy_validation_report().await.unwrap();
+        assert_eq!(report.checked, 4);
+        assert!(!report.is_clean());
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.count("invalid_policy"), 2);
+        assert_eq!(report.ids("invalid_policy"), ["mismatch-a", "mismatch-b"]);
+        assert_eq!(report.count("empty_name"), 1);
+        assert_eq!(report.ids("empty_name"), ["unnamed"]);
+        assert_eq!(report.count("invalid_field"), 0);
+        assert!(report.ids("invalid_field").is_empty());
+    }
 
-        // Test: Find relayers with signer-alpha (should return 3: relayer-1, relayer-2, relayer-4)
-        let relayers_with_alpha = repo.list_by_signer_id("signer-alpha").await.unwrap();
-        assert_eq!(relayers_with_alpha.len(), 3);
+    #[actix_web::test]
+    async fn test_record_spend_enforces_daily_cap() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("spender".to_string());
+        if let RelayerNetworkPolicy::Evm(policy) = &mut relayer.policies {
+            policy.max_daily_spend = Some(1_000);
+        }
+        repo.create(relayer).await.unwrap();
+
+        assert_eq!(
+            repo.record_spend("spender".to_string(), 600).await.unwrap(),
+            600
+        );
+        assert_eq!(
+            repo.record_spend("spender".to_string(), 400).await.unwrap(),
+            1_000
+        );
+        let result = repo.record_spend("spender".to_string(), 1).await;
+        assert!(matches!(result, Err(RepositoryError::Conflict(_))));
 
-        let alpha_ids: Vec<String> = relayers_with_alpha.iter().map(|r| r.id.clone()).collect();
-        assert!(alpha_ids.contains(&"relayer-1".to_string()));
-        assert!(alpha_ids.contains(&"relayer-2".to_string()));
-        assert!(alpha_ids.contains(&"relayer-4".to_string()));
-        assert!(!alpha_ids.contains(&"relayer-3".to_string()));
+        let stored = repo.get_by_id("spender".to_string()).await.unwrap();
+        assert_eq!(stored.spent_today, 1_000);
+        assert!(stored.spend_window_start.is_some());
+    }
 
-        // Verify the relayers have different states (paused, system_disabled)
-        let relayer2_found = relayers_with_alpha
-            .iter()
-            .find(|r| r.id == "relayer-2")
-            .unwrap();
-        let relayer4_found = relayers_with_alpha
-            .iter()
-            .find(|r| r.id == "relayer-4")
+    #[actix_web::test]
+    async fn test_spend_is_not_versioned_config() {
+        let repo = InMemoryRelayerRepository::new();
+        let created = repo
+            .create(create_test_relayer("spender".to_string()))
+            .await
             .unwrap();
-        assert!(relayer2_found.paused); // Should be paused
-        assert!(relayer4_found.system_disabled); // Should be disabled
+        let seq = repo.changes_since(0).await.unwrap().len();
 
-        // Test: Find relayers with signer-beta (should return 1: relayer-3)
-        let relayers_with_beta = repo.list_by_signer_id("signer-beta").await.unwrap();
-        assert_eq!(relayers_with_beta.len(), 1);
-        assert_eq!(relayers_with_beta[0].id, "relayer-3");
-        assert_eq!(relayers_with_beta[0].network_type, NetworkType::Solana);
+        repo.record_spend("spender".to_string(), 250).await.unwrap();
 
-        // Test: Find relayers with non-existent signer (should return empty)
-        let relayers_with_gamma = repo.list_by_signer_id("signer-gamma").await.unwrap();
-        assert_eq!(relayers_with_gamma.len(), 0);
+        // Recording spend neither bumps the version nor fills the change log
+        assert_eq!(repo.changes_since(0).await.unwrap().len(), seq);
+        let stored = repo.get_by_id("spender".to_string()).await.unwrap();
+        assert_eq!(stored.version, created.version);
 
-        // Test: Find relayers with empty signer ID (should return empty)
-        let relayers_with_empty = repo.list_by_signer_id("").await.unwrap();
-        assert_eq!(relayers_with_empty.len(), 0);
+        // Updating from a copy read before the spend keeps the stored total
+        let updated = repo.update("spender".to_string(), created).await.unwrap();
+        assert_eq!(updated.spent_today, 250);
+        assert_eq!(updated.spend_window_start, stored.spend_window_start);
+    }
 
-        // Test: Verify total count hasn't changed
-        assert_eq!(repo.count().await.unwrap(), 4);
+    #[actix_web::test]
+    async fn test_record_spend_not_found() {
+        let repo = InMemoryRelayerRepository::new();
+        let result = repo.record_spend("missing".to_string(), 1).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
 
-        // Test: Remove one relayer and verify list_by_signer_id updates correctly
-        repo.delete_by_id("relayer-2".to_string()).await.unwrap();
+    #[actix_web::test]
+    async fn test_promote_to_live_lifts_canary_restriction() {
+        let repo = InMemoryRelayerRepository::new();
+        let mut relayer = create_test_relayer("canary".to_string());
+        relayer.mode = RelayerMode::Canary;
+        repo.create(relayer).await.unwrap();
 
-        let relayers_with_alpha_after_delete =
-            repo.list_by_signer_id("signer-alpha").await.unwrap();
-        assert_eq!(relayers_with_alpha_after_delete.len(), 2); // Should now be 2 instead of 3
+        let stored = repo.get_by_id("canary".to_string()).await.unwrap();
+        assert!(stored.accepts_transaction(true));
+        assert!(!stored.accepts_transaction(false));
 
-        let alpha_ids_after: Vec<String> = relayers_with_alpha_after_delete
-            .iter()
-            .map(|r| r.id.clone())
-            .collect();
-        assert!(alpha_ids_after.contains(&"relayer-1".to_string()));
-        assert!(!alpha_ids_after.contains(&"relayer-2".to_string())); // Deleted
-        assert!(alpha_ids_after.contains(&"relayer-4".to_string()));
+        let promoted = repo.promote_to_live("canary".to_string()).await.unwrap();
+        assert_eq!(promoted.mode, RelayerMode::Live);
+        assert!(promoted.accepts_transaction(false));
+        assert_eq!(promoted.version, stored.version + 1);
+
+        // Promoting again is a no-op
+        let again = repo.promote_to_live("canary".to_string()).await.unwrap();
+        assert_eq!(again.version, promoted.version);
     }
 
     #[actix_web::test]
-    async fn test_list_by_notification_id() {
+    async fn test_promote_to_live_not_found() {
         let repo = InMemoryRelayerRepository::new();
+        let result = repo.promote_to_live("missing".to_string()).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
 
-        // Create test relayers with different notifications
-        let relayer1 = RelayerRepoModel {
-            id: "relayer-1".to_string(),
-            name: "Relayer 1".to_string(),
-            network: "ethereum".to_string(),
-            paused: false,
-            network_type: NetworkType::Evm,
-            signer_id: "test-signer".to_string(),
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            address: "0x1111".to_string(),
-            notification_id: Some("notification-alpha".to_string()),
-            system_disabled: false,
-            custom_rpc_urls: None,
-            ..Default::default()
-        };
-
-        let relayer2 = RelayerRepoModel {
-            id: "relayer-2".to_string(),
-            name: "Relayer 2".to_string(),
-            network: "polygon".to_string(),
-            paused: true,
-            network_type: NetworkType::Evm,
-            signer_id: "test-signer".to_string(),
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            address: "0x2222".to_string(),
-            notification_id: Some("notification-alpha".to_string()), // Same notification as relayer1
-            system_disabled: false,
-            custom_rpc_urls: None,
-            ..Default::default()
-        };
+    #[actix_web::test]
+    async fn test_metrics_snapshot_counts_underfunded_relayers() {
+        let repo = InMemoryRelayerRepository::new();
+        for (id, min_balance) in [
+            ("underfunded", Some(1_000)),
+            ("funded", Some(1_000)),
+            ("unbounded", None),
+        ] {
+            let mut relayer = create_test_relayer(id.to_string());
+            if let RelayerNetworkPolicy::Evm(policy) = &mut relayer.policies {
+                policy.min_balance = min_balance;
+            }
+            repo.create(relayer).await.unwrap();
+        }
+        repo.increment_pending("funded".to_string()).await.unwrap();
 
-        let relayer3 = RelayerRepoModel {
-            id: "relayer-3".to_string(),
-            name: "Relayer 3".to_string(),
-            network: "solana".to_string(),
-            paused: false,
-            network_type: NetworkType::Solana,
-            signer_id: "test-signer".to_string(),
-            policies: RelayerNetworkPolicy::Solana(crate::models::RelayerSolanaPolicy::default()),
-            address: "solana-addr".to_string(),
-            notification_id: Some("notification-beta".to_string()), // Different notification
-            system_disabled: false,
-            custom_rpc_urls: None,
-            ..Default::default()
-        };
+        let mut balances = MockBalanceProvider::new();
+        balances.expect_balance().returning(|relayer| {
+            Box::pin(ready(match relayer.id.as_str() {
+                "underfunded" => Ok(10),
+                "funded" => Ok(5_000),
+                _ => panic!("balance fetched for a relayer without a minimum"),
+            }))
+        });
 
-        let relayer4 = RelayerRepoModel {
-            id: "relayer-4".to_string(),
-            name: "Relayer 4".to_string(),
-            network: "stellar".to_string(),
-            paused: false,
-            network_type: NetworkType::Stellar,
-            signer_id: "test-signer".to_string(),
-            policies: RelayerNetworkPolicy::Stellar(crate::models::RelayerStellarPolicy::default()),
-            address: "stellar-addr".to_string(),
-            notification_id: None, // No notification
-            system_disabled: true,
-            custom_rpc_urls: None,
-            ..Default::default()
-        };
+        let metrics = repo.metrics_snapshot(&balances).await.unwrap();
+        assert_eq!(metrics.total, 3);
+        assert_eq!(metrics.by_network_type.get(&NetworkType::Evm), Some(&3));
+        assert_eq!(metrics.pending, 1);
+        assert_eq!(metrics.below_min_balance, 1);
+        assert_eq!(metrics.by_status.values().sum::<usize>(), 3);
+    }
 
-        let relayer5 = RelayerRepoModel {
-            id: "relayer-5".to_string(),
-            name: "Relayer 5".to_string(),
-            network: "bsc".to_string(),
-            paused: false,
-            network_type: NetworkType::Evm,
-            signer_id: "test-signer".to_string(),
-            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
-            address: "0x5555".to_string(),
-            notification_id: Some("notification-alpha".to_string()), // Same notification as relayer1 and relayer2
-            system_disabled: false,
-            custom_rpc_urls: None,
-            ..Default::default()
-        };
+    #[actix_web::test]
+    async fn test_whitelist_overlap_report_lists_shared_receivers() {
+        let repo = InMemoryRelayerRepository::new();
+        for (id, receivers) in [
+            (
+                "first",
+                vec![
+                    "0xAbC0000000000000000000000000000000000001",
+                    "0x0000000000000000000000000000000000000002",
+                ],
+            ),
+            ("second", vec!["0xabc0000000000000000000000000000000000001"]),
+            (
+                "disjoint",
+                vec!["0x0000000000000000000000000000000000000003"],
+            ),
+        ] {
+            let mut relayer = create_test_relayer(id.to_string());
+            if let RelayerNetworkPolicy::Evm(policy) = &mut relayer.policies {
+                policy.whitelist_receivers =
+                    Some(receivers.into_iter().map(String::from).collect());
+            }
+            repo.create(relayer).await.unwrap();
+        }
 
-        // Add all relayers to the repository
-        repo.create(relayer1).await.unwrap();
-        repo.create(relayer2).await.unwrap();
-        repo.create(relayer3).await.unwrap();
-        repo.create(relayer4).await.unwrap();
-        repo.create(relayer5).await.unwrap();
+        let overlaps = repo.whitelist_overlap_report().await.unwrap();
+        assert_eq!(
+            overlaps,
+            vec![WhitelistOverlap {
+                address: "0xabc0000000000000000000000000000000000001".to_string(),
+                relayer_ids: vec!["first".to_string(), "second".to_string()],
+            }]
+        );
+    }
 
-        // Test: Find relayers with notification-alpha (should return 3: relayer-1, relayer-2, relayer-5)
-        let relayers_with_alpha = repo
-            .list_by_notification_id("notification-alpha")
+    #[actix_web::test]
+    async fn test_register_submission_catches_duplicates() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("dedup".to_string()))
             .await
             .unwrap();
-        assert_eq!(relayers_with_alpha.len(), 3);
+        repo.create(create_test_relayer("other".to_string()))
+            .await
+            .unwrap();
+        let ttl = Duration::from_secs(60);
 
-        let alpha_ids: Vec<String> = relayers_with_alpha.iter().map(|r| r.id.clone()).collect();
-        assert!(alpha_ids.contains(&"relayer-1".to_string()));
-        assert!(alpha_ids.contains(&"relayer-2".to_string()));
-        assert!(alpha_ids.contains(&"relayer-5".to_string()));
-        assert!(!alpha_ids.contains(&"relayer-3".to_string()));
-        assert!(!alpha_ids.contains(&"relayer-4".to_string()));
+        assert!(repo
+            .register_submission("dedup".to_string(), "0xabc:7".to_string(), ttl)
+            .await
+            .unwrap());
+        assert!(!repo
+            .register_submission("dedup".to_string(), "0xabc:7".to_string(), ttl)
+            .await
+            .unwrap());
+        // Keys are scoped to the relayer
+        assert!(repo
+            .register_submission("other".to_string(), "0xabc:7".to_string(), ttl)
+            .await
+            .unwrap());
 
-        // Verify the relayers have different states (paused, different networks)
-        let relayer2_found = relayers_with_alpha
-            .iter()
-            .find(|r| r.id == "relayer-2")
-            .unwrap();
-        let relayer5_found = relayers_with_alpha
-            .iter()
-            .find(|r| r.id == "relayer-5")
+        let missing = repo
+            .register_submission("missing".to_string(), "0xabc:7".to_string(), ttl)
+            .await;
+        assert!(matches!(missing, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_register_submission_allows_resubmission_after_expiry() {
+        let repo = InMemoryRelayerRepository::new();
+        repo.create(create_test_relayer("dedup".to_string()))
+            .await
             .unwrap();
-        assert!(relayer2_found.paused); // Should be paused
-        assert_eq!(relayer5_found.network, "bsc"); // Should be on BSC network
+        let ttl = Duration::from_millis(20);
 
-        // Test: Find relayers with notification-beta (should return 1: relayer-3)
-        let relayers_with_beta = repo
-            .list_by_notification_id("notification-beta")
+        assert!(repo
+            .register_submission("dedup".to_string(), "0xabc:7".to_string(), ttl)
+            .await
+            .unwrap());
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert!(repo
+            .register_submission("dedup".to_string(), "0xabc:7".to_string(), ttl)
+            .await
+            .unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_max_rpc_batch_size_zero_rejected_on_store() {
+        let repo = InMemoryRelayerRepository::new();
+        let zero_limit = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            max_rpc_batch_size: Some(0),
+            ..RelayerEvmPolicy::default()
+        });
+        let relayer = RelayerRepoModel {
+            policies: zero_limit.clone(),
+            ..create_test_relayer("batch".to_string())
+        };
+        let result = repo.create(relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        assert_eq!(repo.count().await.unwrap(), 0);
+
+        repo.create(create_test_relayer("batch".to_string()))
             .await
             .unwrap();
-        assert_eq!(relayers_with_beta.len(), 1);
-        assert_eq!(relayers_with_beta[0].id, "relayer-3");
-        assert_eq!(relayers_with_beta[0].network_type, NetworkType::Solana);
+        let result = repo.update_policy("batch".to_string(), zero_limit).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
 
-        // Test: Find relayers with non-existent notification (should return empty)
-        let relayers_with_gamma = repo
-            .list_by_notification_id("notification-gamma")
+        let limited = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            max_rpc_batch_size: Some(1),
+            ..RelayerEvmPolicy::default()
+        });
+        let updated = repo
+            .update_policy("batch".to_string(), limited)
             .await
             .unwrap();
-        assert_eq!(relayers_with_gamma.len(), 0);
+        assert_eq!(updated.policies.max_rpc_batch_size(), Some(1));
+    }
 
-        // Test: Find relayers with empty string notification (should return empty)
-        let relayers_with_empty = repo.list_by_notification_id("").await.unwrap();
-        assert_eq!(relayers_with_empty.len(), 0);
+    /// Notification registry backed by a fixed set of notification IDs
+    #[derive(Debug)]
+    struct KnownNotifications(HashSet<String>);
 
-        // Test: Verify total count hasn't changed
-        assert_eq!(repo.count().await.unwrap(), 5);
+    #[async_trait]
+    impl NotificationRegistry for KnownNotifications {
+        async fn notification_exists(
+            &self,
+            notification_id: &str,
+        ) -> Result<bool, RepositoryError> {
+            Ok(self.0.contains(notification_id))
+        }
+    }
 
-        // Test: Remove one relayer and verify list_by_notification_id updates correctly
-        repo.delete_by_id("relayer-2".to_string()).await.unwrap();
+    /// Tenant registry backed by a fixed set of tenant IDs
+    #[derive(Debug)]
+    struct KnownTenants(HashSet<String>);
 
-        let relayers_with_alpha_after_delete = repo
-            .list_by_notification_id("notification-alpha")
+    #[async_trait]
+    impl TenantRegistry for KnownTenants {
+        async fn tenant_exists(&self, tenant_id: &str) -> Result<bool, RepositoryError> {
+            Ok(self.0.contains(tenant_id))
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_transfer_ownership_records_audit_entry() {
+        let repo = InMemoryRelayerRepository::new();
+        let created = repo
+            .create(create_test_relayer_for_tenant("moving", "tenant-a"))
             .await
             .unwrap();
-        assert_eq!(relayers_with_alpha_after_delete.len(), 2); // Should now be 2 instead of 3
-
-        let alpha_ids_after: Vec<String> = relayers_with_alpha_after_delete
-            .iter()
-            .map(|r| r.id.clone())
-            .collect();
-        assert!(alpha_ids_after.contains(&"relayer-1".to_string()));
-        assert!(!alpha_ids_after.contains(&"relayer-2".to_string())); // Deleted
-        assert!(alpha_ids_after.contains(&"relayer-5".to_string()));
 
-        // Test: Update a relayer's notification and verify the lists update correctly
-        let mut updated_relayer = repo.get_by_id("relayer-5".to_string()).await.unwrap();
-        updated_relayer.notification_id = Some("notification-beta".to_string());
-        repo.update("relayer-5".to_string(), updated_relayer)
+        let transferred = repo
+            .transfer_ownership(
+                "moving".to_string(),
+                "tenant-b".to_string(),
+                "alice@example.com".to_string(),
+            )
             .await
             .unwrap();
+        assert_eq!(transferred.tenant_id, "tenant-b");
+        assert_eq!(transferred.version, created.version + 1);
+        assert_eq!(transferred.ownership_history.len(), 1);
+        let entry = &transferred.ownership_history[0];
+        assert_eq!(entry.from_tenant, "tenant-a");
+        assert_eq!(entry.to_tenant, "tenant-b");
+        assert_eq!(entry.actor, "alice@example.com");
 
-        // Check notification-alpha list again (should now have only relayer-1)
-        let relayers_with_alpha_final = repo
-            .list_by_notification_id("notification-alpha")
+        // The relayer is now only visible to its new tenant
+        assert!(repo
+            .get_by_id_scoped("moving".to_string(), "tenant-a")
+            .await
+            .is_err());
+        assert!(repo
+            .get_by_id_scoped("moving".to_string(), "tenant-b")
+            .await
+            .is_ok());
+
+        let same_tenant = repo
+            .transfer_ownership(
+                "moving".to_string(),
+                "tenant-b".to_string(),
+                "alice@example.com".to_string(),
+            )
+            .await;
+        assert!(matches!(same_tenant, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_transfer_ownership_rejects_unknown_tenant() {
+        let repo = InMemoryRelayerRepository::new().with_tenant_registry(Arc::new(KnownTenants(
+            HashSet::from(["tenant-a".to_string(), "tenant-b".to_string()]),
+        )));
+        repo.create(create_test_relayer_for_tenant("moving", "tenant-a"))
             .await
             .unwrap();
-        assert_eq!(relayers_with_alpha_final.len(), 1);
-        assert_eq!(relayers_with_alpha_final[0].id, "relayer-1");
 
-        // Check notification-beta list (should now have relayer-3 and relayer-5)
-        let relayers_with_beta_final = repo
-            .list_by_notification_id("notification-beta")
+        let result = repo
+            .transfer_ownership(
+                "moving".to_string(),
+                "tenant-unknown".to_string(),
+                "alice@example.com".to_string(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let stored = repo.get_by_id("moving".to_string()).await.unwrap();
+        assert_eq!(stored.tenant_id, "tenant-a");
+        assert!(stored.ownership_history.is_empty());
+
+        let transferred = repo
+            .transfer_ownership(
+                "moving".to_string(),
+                "tenant-b".to_string(),
+                "alice@example.com".to_string(),
+            )
             .await
             .unwrap();
-        assert_eq!(relayers_with_beta_final.len(), 2);
-        let beta_ids_final: Vec<String> = relayers_with_beta_final
-            .iter()
-            .map(|r| r.id.clone())
-            .collect();
-        assert!(beta_ids_final.contains(&"relayer-3".to_string()));
-        assert!(beta_ids_final.contains(&"relayer-5".to_string()));
+        assert_eq!(transferred.tenant_id, "tenant-b");
     }
 }
@@ -0,0 +1,206 @@
+//! Invalidation channel for relayer caches shared by several instances.
+//!
+//! A caching decorator such as [`CachedRelayerRepository`](super::CachedRelayerRepository)
+//! publishes an [`Invalidation`] after every write it lets through, and evicts
+//! whatever the invalidations it receives name. [`InProcessInvalidationChannel`]
+//! connects decorators within one process; with the `redis-invalidation` feature,
+//! [`RedisInvalidationChannel`] connects instances over Redis pub/sub.
+
+use crate::constants::RELAYER_INVALIDATION_CHANNEL_CAPACITY;
+use crate::models::RepositoryError;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+/// Cached relayer state that no longer matches storage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Invalidation {
+    /// The relayer with this ID changed or was deleted
+    Relayer(String),
+    /// Any relayer may have changed, e.g. after a bulk write
+    All,
+}
+
+/// Sends invalidations to every subscriber of the channel
+#[async_trait]
+pub trait InvalidationPublisher: Send + Sync + std::fmt::Debug {
+    async fn publish(&self, invalidation: Invalidation) -> Result<(), RepositoryError>;
+}
+
+/// Receives invalidations published on the channel, including this instance's own
+#[async_trait]
+pub trait InvalidationSubscriber: Send + Sync + std::fmt::Debug {
+    /// Starts a subscription. A receiver that falls behind skips the missed
+    /// invalidations and reports how many with `RecvError::Lagged`.
+    async fn subscribe(&self) -> Result<broadcast::Receiver<Invalidation>, RepositoryError>;
+}
+
+/// Invalidation channel connecting the caches of one process
+#[derive(Debug, Clone)]
+pub struct InProcessInvalidationChannel {
+    sender: broadcast::Sender<Invalidation>,
+}
+
+impl InProcessInvalidationChannel {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(RELAYER_INVALIDATION_CHANNEL_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl Default for InProcessInvalidationChannel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl InvalidationPublisher for InProcessInvalidationChannel {
+    async fn publish(&self, invalidation: Invalidation) -> Result<(), RepositoryError> {
+        // Nobody listening is not an error: there is no cache to invalidate
+        let _ = self.sender.send(invalidation);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl InvalidationSubscriber for InProcessInvalidationChannel {
+    async fn subscribe(&self) -> Result<broadcast::Receiver<Invalidation>, RepositoryError> {
+        Ok(self.sender.subscribe())
+    }
+}
+
+#[cfg(feature = "redis-invalidation")]
+pub use redis_channel::RedisInvalidationChannel;
+
+#[cfg(feature = "redis-invalidation")]
+mod redis_channel {
+    use super::*;
+    use futures::StreamExt;
+    use redis::aio::ConnectionManager;
+    use redis::AsyncCommands;
+    use std::fmt;
+    use std::sync::Arc;
+    use tracing::warn;
+
+    const RELAYER_INVALIDATION_CHANNEL: &str = "relayer_invalidations";
+
+    /// Invalidation channel over Redis pub/sub, connecting every instance that uses
+    /// the same key prefix
+    #[derive(Clone)]
+    pub struct RedisInvalidationChannel {
+        /// Opens the dedicated connection each subscription needs
+        client: redis::Client,
+        publisher: Arc<ConnectionManager>,
+        channel: String,
+    }
+
+    impl RedisInvalidationChannel {
+        pub fn new(
+            client: redis::Client,
+            publisher: Arc<ConnectionManager>,
+            key_prefix: &str,
+        ) -> Self {
+            Self {
+                client,
+                publisher,
+                channel: format!("{key_prefix}:{RELAYER_INVALIDATION_CHANNEL}"),
+            }
+        }
+    }
+
+    impl fmt::Debug for RedisInvalidationChannel {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("RedisInvalidationChannel")
+                .field("publisher", &"<ConnectionManager>")
+                .field("channel", &self.channel)
+                .finish()
+        }
+    }
+
+    #[async_trait]
+    impl InvalidationPublisher for RedisInvalidationChannel {
+        async fn publish(&self, invalidation: Invalidation) -> Result<(), RepositoryError> {
+            let message = serde_json::to_string(&invalidation).map_err(|e| {
+                RepositoryError::InvalidData(format!("Failed to serialize invalidation: {e}"))
+            })?;
+            let mut conn = self.publisher.as_ref().clone();
+            let _: () = conn
+                .publish(&self.channel, message)
+                .await
+                .map_err(|e| RepositoryError::ConnectionError(e.to_string()))?;
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl InvalidationSubscriber for RedisInvalidationChannel {
+        async fn subscribe(&self) -> Result<broadcast::Receiver<Invalidation>, RepositoryError> {
+            let mut pubsub = self
+                .client
+                .get_async_pubsub()
+                .await
+                .map_err(|e| RepositoryError::ConnectionError(e.to_string()))?;
+            pubsub
+                .subscribe(&self.channel)
+                .await
+                .map_err(|e| RepositoryError::ConnectionError(e.to_string()))?;
+
+            let (sender, receiver) = broadcast::channel(RELAYER_INVALIDATION_CHANNEL_CAPACITY);
+            tokio::spawn(async move {
+                let mut messages = pubsub.into_on_message();
+                // Ends when the connection drops or every receiver is gone
+                while let Some(message) = messages.next().await {
+                    let invalidation = message
+                        .get_payload::<String>()
+                        .map_err(|e| e.to_string())
+                        .and_then(|payload| {
+                            serde_json::from_str::<Invalidation>(&payload)
+                                .map_err(|e| e.to_string())
+                        });
+                    match invalidation {
+                        Ok(invalidation) => {
+                            if sender.send(invalidation).is_err() {
+                                break;
+                            }
+                        }
+                        Err(e) => warn!(error = %e, "ignoring malformed relayer invalidation"),
+                    }
+                }
+            });
+            Ok(receiver)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_process_channel_delivers_to_every_subscriber() {
+        let channel = InProcessInvalidationChannel::new();
+        let mut first = channel.subscribe().await.unwrap();
+        let mut second = channel.subscribe().await.unwrap();
+
+        channel
+            .publish(Invalidation::Relayer("relayer-1".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            first.recv().await.unwrap(),
+            Invalidation::Relayer("relayer-1".to_string())
+        );
+        assert_eq!(
+            second.recv().await.unwrap(),
+            Invalidation::Relayer("relayer-1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_publish_without_subscribers_succeeds() {
+        let channel = InProcessInvalidationChannel::new();
+        assert!(channel.publish(Invalidation::All).await.is_ok());
+    }
+}
@@ -0,0 +1,47 @@
+//! Repository-wide maintenance windows.
+//!
+//! While a window is active every relayer reports unavailable, regardless of its
+//! own state. The window is stored next to the relayers rather than on them, so
+//! each relayer's paused state is left as it was and applies again once the
+//! window ends.
+
+use crate::models::RepositoryError;
+use chrono::{DateTime, Utc};
+
+/// Checks that a maintenance window ending at `until` has not already ended at `now`
+pub fn validate_maintenance_until(
+    until: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<(), RepositoryError> {
+    if until <= now {
+        return Err(RepositoryError::InvalidData(format!(
+            "Maintenance window end {until} is not in the future"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_validate_maintenance_until_accepts_future_end() {
+        let now = Utc::now();
+        assert!(validate_maintenance_until(now + Duration::minutes(5), now).is_ok());
+    }
+
+    #[test]
+    fn test_validate_maintenance_until_rejects_past_end() {
+        let now = Utc::now();
+        assert!(matches!(
+            validate_maintenance_until(now, now),
+            Err(RepositoryError::InvalidData(_))
+        ));
+        assert!(matches!(
+            validate_maintenance_until(now - Duration::seconds(1), now),
+            Err(RepositoryError::InvalidData(_))
+        ));
+    }
+}
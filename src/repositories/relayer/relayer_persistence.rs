@@ -0,0 +1,186 @@
+//! On-disk persistence for the in-memory relayer repository.
+//!
+//! The store is written as a versioned JSON document. Each write goes to a
+//! uniquely named temporary file next to the target, which is then renamed over
+//! it and the directory synced, so a crash mid-write leaves the previous file
+//! intact and a completed write survives a crash.
+
+use crate::{
+    constants::RELAYER_STORE_SCHEMA_VERSION,
+    models::{RelayerRepoModel, RepositoryError},
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Distinguishes temporary files of concurrent writes within one process
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Layout of a persisted relayer store
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedRelayerStore {
+    schema_version: u32,
+    relayers: Vec<RelayerRepoModel>,
+}
+
+/// Writes `relayers` to `path`, atomically replacing any existing file
+pub fn write_relayer_store(
+    path: &Path,
+    relayers: Vec<RelayerRepoModel>,
+) -> Result<(), RepositoryError> {
+    let document = PersistedRelayerStore {
+        schema_version: RELAYER_STORE_SCHEMA_VERSION,
+        relayers,
+    };
+    let serialized = serde_json::to_vec(&document).map_err(|e| {
+        RepositoryError::InvalidData(format!("Failed to serialize relayer store: {e}"))
+    })?;
+
+    let temp_path = temp_path(path);
+    let write = || -> std::io::Result<()> {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(&serialized)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)
+    };
+    write().map_err(|e| {
+        // Best effort: a leftover temporary file is harmless but never reused
+        let _ = fs::remove_file(&temp_path);
+        io_error(path, "write", e)
+    })?;
+    sync_parent_dir(path).map_err(|e| io_error(path, "sync directory of", e))
+}
+
+/// Reads the relayers stored at `path`.
+///
+/// Fails with `NotSupported` for a file written by a newer schema version.
+pub fn read_relayer_store(path: &Path) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+    let contents = fs::read(path).map_err(|e| io_error(path, "read", e))?;
+    let document: PersistedRelayerStore = serde_json::from_slice(&contents).map_err(|e| {
+        RepositoryError::InvalidData(format!("Invalid relayer store {}: {e}", path.display()))
+    })?;
+
+    if document.schema_version > RELAYER_STORE_SCHEMA_VERSION {
+        return Err(RepositoryError::NotSupported(format!(
+            "Relayer store {} has schema version {}, newer than the supported version {}",
+            path.display(),
+            document.schema_version,
+            RELAYER_STORE_SCHEMA_VERSION
+        )));
+    }
+    Ok(document.relayers)
+}
+
+/// Path of a fresh temporary file a store is written to before being renamed to `path`
+fn temp_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    path.with_file_name(file_name)
+}
+
+/// Flushes the directory entry created by the rename to disk
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> std::io::Result<()> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs::File::open(parent)?.sync_all()
+}
+
+/// Directories cannot be opened for syncing on this platform; the rename is left to the OS
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+fn io_error(path: &Path, action: &str, error: std::io::Error) -> RepositoryError {
+    RepositoryError::Other(format!(
+        "Failed to {action} relayer store {}: {error}",
+        path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_relayer(id: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: id.to_string(),
+            name: format!("Relayer {id}"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_write_and_read_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+
+        write_relayer_store(&path, vec![create_test_relayer("relayer-1")]).unwrap();
+        let relayers = read_relayer_store(&path).unwrap();
+        assert_eq!(relayers.len(), 1);
+        assert_eq!(relayers[0].id, "relayer-1");
+        let entries: Vec<_> = fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1, "no temporary file should be left behind");
+    }
+
+    #[test]
+    fn test_temp_paths_are_unique() {
+        let path = Path::new("/tmp/relayers.json");
+        let first = temp_path(path);
+        let second = temp_path(path);
+        assert_ne!(first, second);
+        assert_eq!(first.parent(), path.parent());
+    }
+
+    #[test]
+    fn test_write_replaces_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+
+        write_relayer_store(&path, vec![create_test_relayer("relayer-1")]).unwrap();
+        write_relayer_store(&path, vec![create_test_relayer("relayer-2")]).unwrap();
+        let relayers = read_relayer_store(&path).unwrap();
+        assert_eq!(relayers.len(), 1);
+        assert_eq!(relayers[0].id, "relayer-2");
+    }
+
+    #[test]
+    fn test_read_rejects_newer_schema_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+        let document = serde_json::json!({
+            "schema_version": RELAYER_STORE_SCHEMA_VERSION + 1,
+            "relayers": [],
+        });
+        fs::write(&path, document.to_string()).unwrap();
+
+        let result = read_relayer_store(&path);
+        assert!(matches!(result, Err(RepositoryError::NotSupported(_))));
+    }
+
+    #[test]
+    fn test_read_rejects_malformed_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("relayers.json");
+        fs::write(&path, "not json").unwrap();
+
+        let result = read_relayer_store(&path);
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = read_relayer_store(&dir.path().join("missing.json"));
+        assert!(matches!(result, Err(RepositoryError::Other(_))));
+    }
+}
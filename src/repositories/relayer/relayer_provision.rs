@@ -0,0 +1,163 @@
+//! Bulk relayer provisioning from descriptors.
+//!
+//! Validates a batch of [`RelayerDescriptor`]s against each other and against
+//! the relayers already stored, so repository implementations can create the
+//! whole batch at once or reject it without writing anything.
+
+use crate::models::{
+    ProvisionRowError, RelayerDescriptor, RelayerNetworkPolicy, RelayerRepoModel,
+    RelayerValidationError, RepositoryError,
+};
+use std::collections::HashSet;
+
+/// Converts descriptors to repository models, collecting per-row violations.
+///
+/// Besides the descriptor's own validation, a row is rejected if its id is
+/// already stored (`existing`), repeats an earlier row, or its policy fails
+/// `check_policy`. Models are only returned for rows without violations.
+pub fn prepare_provision<F>(
+    descriptors: &[RelayerDescriptor],
+    existing: &HashSet<String>,
+    check_policy: F,
+) -> (Vec<RelayerRepoModel>, Vec<ProvisionRowError>)
+where
+    F: Fn(&RelayerNetworkPolicy) -> Result<(), RepositoryError>,
+{
+    let mut seen = HashSet::new();
+    let mut relayers = Vec::new();
+    let mut row_errors = Vec::new();
+
+    for (row, descriptor) in descriptors.iter().enumerate() {
+        let relayer = descriptor.to_repo_model();
+        let mut errors = descriptor.validation_errors();
+        if existing.contains(&descriptor.id) {
+            errors.push(RelayerValidationError::InvalidField(format!(
+                "Relayer with ID {} already exists",
+                descriptor.id
+            )));
+        }
+        if !seen.insert(descriptor.id.as_str()) {
+            errors.push(RelayerValidationError::InvalidField(format!(
+                "Relayer ID {} appears more than once in the batch",
+                descriptor.id
+            )));
+        }
+        if let Err(e) = check_policy(&relayer.policies) {
+            errors.push(RelayerValidationError::InvalidPolicy(e.to_string()));
+        }
+
+        if errors.is_empty() {
+            relayers.push(relayer);
+        } else {
+            row_errors.push(ProvisionRowError {
+                row,
+                id: descriptor.id.clone(),
+                errors,
+            });
+        }
+    }
+
+    (relayers, row_errors)
+}
+
+/// Builds the error returned when a strict provisioning batch is rejected
+pub fn provision_rejected(row_errors: &[ProvisionRowError], total: usize) -> RepositoryError {
+    let details = row_errors
+        .iter()
+        .map(|row_error| {
+            let messages: Vec<String> = row_error.errors.iter().map(|e| e.to_string()).collect();
+            format!(
+                "row {} ({}): {}",
+                row_error.row,
+                row_error.id,
+                messages.join("; ")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    RepositoryError::ConstraintViolation(format!(
+        "{} of {total} relayer descriptors are invalid, nothing was provisioned: {details}",
+        row_errors.len()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NetworkType;
+
+    fn create_descriptor(id: &str) -> RelayerDescriptor {
+        RelayerDescriptor {
+            id: id.to_string(),
+            name: format!("Relayer {id}"),
+            network: "sepolia".to_string(),
+            network_type: NetworkType::Evm,
+            signer_id: "signer".to_string(),
+            address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+            policies: None,
+            paused: false,
+            notification_id: None,
+            custom_rpc_urls: None,
+            extra_webhook_urls: None,
+            hard_gas_price_ceiling: None,
+            pool: None,
+            derivation_path: None,
+        }
+    }
+
+    #[test]
+    fn test_prepare_provision_valid_batch() {
+        let descriptors = vec![create_descriptor("a"), create_descriptor("b")];
+        let (relayers, errors) = prepare_provision(&descriptors, &HashSet::new(), |_| Ok(()));
+
+        assert!(errors.is_empty());
+        let ids: Vec<&str> = relayers.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert_eq!(relayers[0].address, descriptors[0].address);
+    }
+
+    #[test]
+    fn test_prepare_provision_reports_each_invalid_row() {
+        let mut bad_address = create_descriptor("bad-address");
+        bad_address.address = "not-an-address".to_string();
+        let descriptors = vec![
+            create_descriptor("existing"),
+            create_descriptor("a"),
+            create_descriptor("a"),
+            bad_address,
+        ];
+        let existing = HashSet::from(["existing".to_string()]);
+
+        let (relayers, errors) = prepare_provision(&descriptors, &existing, |_| Ok(()));
+
+        assert_eq!(relayers.len(), 1);
+        let rows: Vec<usize> = errors.iter().map(|e| e.row).collect();
+        assert_eq!(rows, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_prepare_provision_applies_policy_check() {
+        let descriptors = vec![create_descriptor("a")];
+        let (relayers, errors) = prepare_provision(&descriptors, &HashSet::new(), |_| {
+            Err(RepositoryError::ConstraintViolation("no cap".to_string()))
+        });
+
+        assert!(relayers.is_empty());
+        assert!(matches!(
+            errors[0].errors[0],
+            RelayerValidationError::InvalidPolicy(_)
+        ));
+    }
+
+    #[test]
+    fn test_provision_rejected_lists_rows() {
+        let descriptors = vec![create_descriptor(""), create_descriptor("a")];
+        let (_, errors) = prepare_provision(&descriptors, &HashSet::new(), |_| Ok(()));
+
+        let error = provision_rejected(&errors, descriptors.len());
+        assert!(
+            matches!(error, RepositoryError::ConstraintViolation(ref msg)
+            if msg.starts_with("1 of 2 relayer descriptors are invalid") && msg.contains("row 0"))
+        );
+    }
+}
@@ -0,0 +1,809 @@
+//! Read-only decorator for relayer repositories.
+//!
+//! [`ReadOnlyRelayerRepository`] wraps any [`RelayerRepository`] and, while
+//! read-only mode is engaged, rejects every mutating operation with
+//! [`RepositoryError::PermissionDenied`]. Runtime state is no exception: pending
+//! counters, spend, in-flight slots and submission keys are rejected like config
+//! writes. Reads always pass through to the inner repository. The mode can be toggled at runtime, so writes can be
+//! frozen across the fleet during maintenance without a restart.
+
+use crate::models::{
+    AlertTransition, ChangeRecord, DeleteReport, DisabledReason, EnforceReport, HealthCheckFailure,
+    LogLevel, NetworkType, PaginationQuery, ProvisionMode, ProvisionReport,
+    RelayerConfigVersionInfo, RelayerDescriptor, RelayerFilter, RelayerLastError,
+    RelayerNetworkPolicy, RelayerOperationalStatus, RelayerRepoModel, RelayerTemplate,
+    RelayerValidationError, RepositoryError, StatusChange, UpdateRelayerRequest,
+};
+use crate::repositories::{
+    ConfigReadGuard, PaginatedResult, PolicyPredicate, ReadToken, RelayerRepository,
+    RelayerSnapshot, Repository, SlotGuard, StickyStrategy,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tracing::warn;
+
+#[derive(Debug)]
+pub struct ReadOnlyRelayerRepository<R> {
+    inner: R,
+    read_only: AtomicBool,
+}
+
+impl<R> ReadOnlyRelayerRepository<R> {
+    /// Wraps `inner`, starting with read-only mode engaged or not
+    pub fn new(inner: R, read_only: bool) -> Self {
+        Self {
+            inner,
+            read_only: AtomicBool::new(read_only),
+        }
+    }
+
+    /// Rejects all writes until [`Self::disengage`] is called
+    pub fn engage(&self) {
+        self.read_only.store(true, Ordering::SeqCst);
+    }
+
+    /// Lets writes through to the inner repository again
+    pub fn disengage(&self) {
+        self.read_only.store(false, Ordering::SeqCst);
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    fn ensure_writable(&self, operation: &str) -> Result<(), RepositoryError> {
+        if self.is_read_only() {
+            warn!(operation = %operation, "rejected relayer write while read-only mode is engaged");
+            return Err(RepositoryError::PermissionDenied(format!(
+                "Relayer repository is read-only, {operation} is not allowed"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: RelayerRepository> Repository<RelayerRepoModel, String> for ReadOnlyRelayerRepository<R> {
+    async fn init(&self) -> Result<(), RepositoryError> {
+        self.inner.init().await
+    }
+
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        self.inner.ping().await
+    }
+
+    async fn create(&self, entity: RelayerRepoModel) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("create")?;
+        self.inner.create(entity).await
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_all().await
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_paginated(query).await
+    }
+
+    async fn update(
+        &self,
+        id: String,
+        entity: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("update")?;
+        self.inner.update(id, entity).await
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        self.ensure_writable("delete_by_id")?;
+        self.inner.delete_by_id(id).await
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        self.inner.count().await
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        self.inner.has_entries().await
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        self.ensure_writable("drop_all_entries")?;
+        self.inner.drop_all_entries().await
+    }
+}
+
+#[async_trait]
+impl<R: RelayerRepository> RelayerRepository for ReadOnlyRelayerRepository<R> {
+    async fn list_active(&self) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_active().await
+    }
+
+    async fn list_by_signer_id(
+        &self,
+        signer_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_signer_id(signer_id).await
+    }
+
+    async fn list_by_notification_id(
+        &self,
+        notification_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_notification_id(notification_id).await
+    }
+
+    async fn partial_update(
+        &self,
+        id: String,
+        update: UpdateRelayerRequest,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("partial_update")?;
+        self.inner.partial_update(id, update).await
+    }
+
+    async fn enable_relayer(
+        &self,
+        relayer_id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("enable_relayer")?;
+        self.inner.enable_relayer(relayer_id).await
+    }
+
+    async fn disable_relayer(
+        &self,
+        relayer_id: String,
+        reason: DisabledReason,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("disable_relayer")?;
+        self.inner.disable_relayer(relayer_id, reason).await
+    }
+
+    async fn update_policy(
+        &self,
+        id: String,
+        policy: RelayerNetworkPolicy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("update_policy")?;
+        self.inner.update_policy(id, policy).await
+    }
+
+    async fn create_idempotent(
+        &self,
+        relayer: RelayerRepoModel,
+        idempotency_key: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("create_idempotent")?;
+        self.inner.create_idempotent(relayer, idempotency_key).await
+    }
+
+    async fn quarantine(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("quarantine")?;
+        self.inner.quarantine(id, until, reason).await
+    }
+
+    async fn list_expired_quarantines(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_expired_quarantines(now).await
+    }
+
+    async fn instantiate(
+        &self,
+        template: &RelayerTemplate,
+        id: String,
+        signer_id: String,
+        address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("instantiate")?;
+        self.inner
+            .instantiate(template, id, signer_id, address)
+            .await
+    }
+
+    async fn evaluate_balance_alert(
+        &self,
+        id: String,
+        balance: u128,
+    ) -> Result<Option<AlertTransition>, RepositoryError> {
+        self.ensure_writable("evaluate_balance_alert")?;
+        self.inner.evaluate_balance_alert(id, balance).await
+    }
+
+    async fn get_by_id_scoped(
+        &self,
+        id: String,
+        tenant_id: &str,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.get_by_id_scoped(id, tenant_id).await
+    }
+
+    async fn list_by_tenant(
+        &self,
+        tenant_id: &str,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_tenant(tenant_id, query).await
+    }
+
+    async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError> {
+        self.inner.snapshot().await
+    }
+
+    async fn schedule_disable(
+        &self,
+        id: String,
+        at: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("schedule_disable")?;
+        self.inner.schedule_disable(id, at, reason).await
+    }
+
+    async fn list_due_scheduled_disables(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_due_scheduled_disables(now).await
+    }
+
+    async fn cancel_scheduled_disable(
+        &self,
+        id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("cancel_scheduled_disable")?;
+        self.inner.cancel_scheduled_disable(id).await
+    }
+
+    async fn find_drifted(
+        &self,
+        desired: &[RelayerRepoModel],
+    ) -> Result<Vec<String>, RepositoryError> {
+        self.inner.find_drifted(desired).await
+    }
+
+    async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError> {
+        self.inner.get_status_history(id).await
+    }
+
+    async fn validate_all(
+        &self,
+    ) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError> {
+        self.inner.validate_all().await
+    }
+
+    async fn pick_sticky(
+        &self,
+        pool: String,
+        session_key: &str,
+        strategy: StickyStrategy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.pick_sticky(pool, session_key, strategy).await
+    }
+
+    async fn rekey(
+        &self,
+        id: String,
+        new_signer_id: String,
+        new_address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("rekey")?;
+        self.inner.rekey(id, new_signer_id, new_address).await
+    }
+
+    async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError> {
+        self.inner.existing_ids(ids).await
+    }
+
+    async fn compact(&self) -> Result<(), RepositoryError> {
+        // Compaction releases memory without changing any relayer data
+        self.inner.compact().await
+    }
+
+    async fn trip_circuit(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("trip_circuit")?;
+        self.inner.trip_circuit(id, until).await
+    }
+
+    async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("reset_circuit")?;
+        self.inner.reset_circuit(id).await
+    }
+
+    async fn record_outcome(
+        &self,
+        id: String,
+        success: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("record_outcome")?;
+        self.inner.record_outcome(id, success).await
+    }
+
+    async fn list_where_policy(
+        &self,
+        predicate: PolicyPredicate,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_where_policy(predicate, query).await
+    }
+
+    async fn provision_from_descriptors(
+        &self,
+        descriptors: Vec<RelayerDescriptor>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionReport, RepositoryError> {
+        // A dry run never writes, so it stays available while read-only
+        if mode == ProvisionMode::Strict {
+            self.ensure_writable("provision_from_descriptors")?;
+        }
+        self.inner
+            .provision_from_descriptors(descriptors, mode)
+            .await
+    }
+
+    async fn record_error(
+        &self,
+        id: String,
+        error: RelayerLastError,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("record_error")?;
+        self.inner.record_error(id, error).await
+    }
+
+    async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("clear_error")?;
+        self.inner.clear_error(id).await
+    }
+
+    async fn list_with_recent_errors(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_with_recent_errors(since).await
+    }
+
+    async fn list_config_versions(
+        &self,
+        id: String,
+    ) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError> {
+        self.inner.list_config_versions(id).await
+    }
+
+    async fn rollback_to(
+        &self,
+        id: String,
+        version: u32,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("rollback_to")?;
+        self.inner.rollback_to(id, version).await
+    }
+
+    async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.get_by_alias(alias).await
+    }
+
+    async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError> {
+        self.inner.changes_since(seq).await
+    }
+
+    async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError> {
+        self.inner.effective_policy(id).await
+    }
+
+    async fn get_standby(
+        &self,
+        primary_id: String,
+    ) -> Result<Option<RelayerRepoModel>, RepositoryError> {
+        self.inner.get_standby(primary_id).await
+    }
+
+    async fn list_due_health_checks(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_due_health_checks(now).await
+    }
+
+    async fn record_health_check(
+        &self,
+        id: String,
+        result: Result<(), Vec<HealthCheckFailure>>,
+        at: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("record_health_check")?;
+        self.inner.record_health_check(id, result, at).await
+    }
+
+    async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("sunset")?;
+        self.inner.sunset(id).await
+    }
+
+    async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("unsunset")?;
+        self.inner.unsunset(id).await
+    }
+
+    async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.inner.get_by_id_effective(id).await
+    }
+
+    async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError> {
+        self.ensure_writable("delete_many")?;
+        self.inner.delete_many(ids).await
+    }
+
+    async fn list_by_network_type(
+        &self,
+        network_type: NetworkType,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_network_type(network_type, query).await
+    }
+
+    async fn pause_if_version(
+        &self,
+        id: String,
+        expected_version: u64,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("pause_if_version")?;
+        self.inner.pause_if_version(id, expected_version).await
+    }
+
+    async fn group_by_network_type(
+        &self,
+    ) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError> {
+        self.inner.group_by_network_type().await
+    }
+
+    async fn validate_create(&self, relayer: RelayerRepoModel) -> Result<(), Vec<RepositoryError>> {
+        self.inner.validate_create(relayer).await
+    }
+
+    async fn list_by_status(
+        &self,
+        status: RelayerOperationalStatus,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_status(status, query).await
+    }
+
+    async fn add_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.ensure_writable("add_tag_where")?;
+        self.inner.add_tag_where(filter, tag).await
+    }
+
+    async fn remove_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.ensure_writable("remove_tag_where")?;
+        self.inner.remove_tag_where(filter, tag).await
+    }
+
+    async fn set_metadata(
+        &self,
+        id: String,
+        metadata: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("set_metadata")?;
+        self.inner.set_metadata(id, metadata).await
+    }
+
+    async fn merge_metadata(
+        &self,
+        id: String,
+        patch: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("merge_metadata")?;
+        self.inner.merge_metadata(id, patch).await
+    }
+
+    async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError> {
+        self.ensure_writable("try_acquire_slot")?;
+        self.inner.try_acquire_slot(id).await
+    }
+
+    async fn record_rpc_health(
+        &self,
+        id: String,
+        url: String,
+        healthy: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("record_rpc_health")?;
+        self.inner.record_rpc_health(id, url, healthy).await
+    }
+
+    async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError> {
+        self.ensure_writable("enter_maintenance")?;
+        self.inner.enter_maintenance(until).await
+    }
+
+    async fn exit_maintenance(&self) -> Result<(), RepositoryError> {
+        self.ensure_writable("exit_maintenance")?;
+        self.inner.exit_maintenance().await
+    }
+
+    async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        self.inner.maintenance_until().await
+    }
+
+    async fn list_active_by_network_type(
+        &self,
+        network_type: NetworkType,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_active_by_network_type(network_type).await
+    }
+
+    async fn list_by_nonce_group(
+        &self,
+        group: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_by_nonce_group(group).await
+    }
+
+    async fn list_high_error_rate(
+        &self,
+        window: Duration,
+        threshold: f64,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        self.inner.list_high_error_rate(window, threshold).await
+    }
+
+    async fn enforce_allowlist(
+        &self,
+        allowed: HashSet<String>,
+    ) -> Result<EnforceReport, RepositoryError> {
+        self.ensure_writable("enforce_allowlist")?;
+        self.inner.enforce_allowlist(allowed).await
+    }
+
+    async fn set_log_level(
+        &self,
+        id: String,
+        level: Option<LogLevel>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("set_log_level")?;
+        self.inner.set_log_level(id, level).await
+    }
+
+    async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError> {
+        self.inner.begin_consistent_read().await
+    }
+
+    async fn get_many_by_address(
+        &self,
+        addresses: Vec<String>,
+        network_type: NetworkType,
+    ) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError> {
+        self.inner
+            .get_many_by_address(addresses, network_type)
+            .await
+    }
+
+    async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError> {
+        self.inner.acquire_config_read(id).await
+    }
+
+    async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        self.ensure_writable("increment_pending")?;
+        self.inner.increment_pending(id).await
+    }
+
+    async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        self.ensure_writable("decrement_pending")?;
+        self.inner.decrement_pending(id).await
+    }
+
+    async fn pending_count(&self, id: String) -> Result<u64, RepositoryError> {
+        self.inner.pending_count(id).await
+    }
+
+    async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError> {
+        self.ensure_writable("record_spend")?;
+        self.inner.record_spend(id, amount).await
+    }
+
+    async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("promote_to_live")?;
+        self.inner.promote_to_live(id).await
+    }
+
+    async fn register_submission(
+        &self,
+        id: String,
+        tx_hash_key: String,
+        ttl: Duration,
+    ) -> Result<bool, RepositoryError> {
+        self.ensure_writable("register_submission")?;
+        self.inner.register_submission(id, tx_hash_key, ttl).await
+    }
+
+    async fn transfer_ownership(
+        &self,
+        id: String,
+        new_tenant: String,
+        actor: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.ensure_writable("transfer_ownership")?;
+        self.inner.transfer_ownership(id, new_tenant, actor).await
+    }
+
+    fn is_persistent_storage(&self) -> bool {
+        self.inner.is_persistent_storage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RelayerErrorKind, RelayerEvmPolicy};
+    use crate::repositories::InMemoryRelayerRepository;
+
+    fn create_test_relayer(id: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: id.to_string(),
+            name: format!("Relayer {id}"),
+            network: "TestNet".to_string(),
+            signer_id: "test".to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn create_read_only_repo() -> ReadOnlyRelayerRepository<InMemoryRelayerRepository> {
+        let inner = InMemoryRelayerRepository::new();
+        inner.create(create_test_relayer("existing")).await.unwrap();
+        ReadOnlyRelayerRepository::new(inner, true)
+    }
+
+    fn assert_denied<T: std::fmt::Debug>(result: Result<T, RepositoryError>) {
+        assert!(
+            matches!(result, Err(RepositoryError::PermissionDenied(_))),
+            "expected PermissionDenied, got {result:?}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_reads_pass_through_while_read_only() {
+        let repo = create_read_only_repo().await;
+        assert!(repo.is_read_only());
+
+        assert_eq!(
+            repo.get_by_id("existing".to_string()).await.unwrap().id,
+            "existing"
+        );
+        assert_eq!(repo.list_all().await.unwrap().len(), 1);
+        assert_eq!(repo.count().await.unwrap(), 1);
+        assert!(repo.has_entries().await.unwrap());
+        assert_eq!(repo.list_active().await.unwrap().len(), 1);
+        assert_eq!(repo.snapshot().await.unwrap().len(), 1);
+        assert!(repo
+            .existing_ids(vec!["existing".to_string()])
+            .await
+            .unwrap()
+            .contains("existing"));
+        assert!(repo.compact().await.is_ok());
+        assert_eq!(repo.maintenance_until().await.unwrap(), None);
+    }
+
+    #[actix_web::test]
+    async fn test_every_mutation_rejected_while_read_only() {
+        let repo = create_read_only_repo().await;
+        let id = || "existing".to_string();
+        let later = Utc::now() + chrono::Duration::hours(1);
+
+        assert_denied(repo.create(create_test_relayer("new")).await);
+        assert_denied(repo.update(id(), create_test_relayer("existing")).await);
+        assert_denied(repo.delete_by_id(id()).await);
+        assert_denied(repo.drop_all_entries().await);
+        assert_denied(
+            repo.partial_update(id(), UpdateRelayerRequest::default())
+                .await,
+        );
+        assert_denied(repo.enable_relayer(id()).await);
+        assert_denied(
+            repo.disable_relayer(
+                id(),
+                DisabledReason::BalanceCheckFailed("low balance".to_string()),
+            )
+            .await,
+        );
+        assert_denied(
+            repo.update_policy(id(), RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()))
+                .await,
+        );
+        assert_denied(
+            repo.create_idempotent(create_test_relayer("new"), "key".to_string())
+                .await,
+        );
+        assert_denied(repo.quarantine(id(), later, "flapping".to_string()).await);
+        assert_denied(repo.evaluate_balance_alert(id(), 0).await);
+        assert_denied(
+            repo.schedule_disable(id(), later, "maintenance".to_string())
+                .await,
+        );
+        assert_denied(repo.cancel_scheduled_disable(id()).await);
+        assert_denied(
+            repo.rekey(
+                id(),
+                "new-signer".to_string(),
+                "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+            )
+            .await,
+        );
+        assert_denied(repo.trip_circuit(id(), later).await);
+        assert_denied(repo.reset_circuit(id()).await);
+        assert_denied(repo.record_outcome(id(), true).await);
+        assert_denied(
+            repo.record_error(
+                id(),
+                RelayerLastError::new(RelayerErrorKind::Rpc, "timeout"),
+            )
+            .await,
+        );
+        assert_denied(repo.clear_error(id()).await);
+        assert_denied(repo.rollback_to(id(), 1).await);
+        assert_denied(repo.enter_maintenance(later).await);
+        assert_denied(repo.exit_maintenance().await);
+        assert_denied(repo.increment_pending(id()).await);
+        assert_denied(repo.record_spend(id(), 1).await);
+        assert!(matches!(
+            repo.try_acquire_slot(id()).await,
+            Err(RepositoryError::PermissionDenied(_))
+        ));
+        assert_denied(
+            repo.register_submission(id(), "0xabc".to_string(), Duration::from_secs(60))
+                .await,
+        );
+
+        // Nothing reached the inner repository
+        assert_eq!(repo.inner().count().await.unwrap(), 1);
+        let stored = repo.inner().get_by_id(id()).await.unwrap();
+        assert_eq!(stored.signer_id, "test");
+        assert!(!stored.system_disabled);
+    }
+
+    #[actix_web::test]
+    async fn test_mutations_succeed_after_disengaging() {
+        let repo = create_read_only_repo().await;
+        assert_denied(repo.create(create_test_relayer("new")).await);
+
+        repo.disengage();
+        assert!(!repo.is_read_only());
+        repo.create(create_test_relayer("new")).await.unwrap();
+        repo.delete_by_id("existing".to_string()).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 1);
+
+        repo.engage();
+        assert_denied(repo.delete_by_id("new".to_string()).await);
+    }
+}
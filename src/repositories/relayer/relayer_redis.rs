@@ -1,25 +1,149 @@
 //! Redis-backed implementation of the RelayerRepository.
 
+use crate::constants::{
+    DEFAULT_PER_PAGE, DEFAULT_RELAYER_CHANGE_LOG_CAPACITY,
+    DEFAULT_RELAYER_CONFIG_LOCK_TIMEOUT_SECONDS, DEFAULT_RELAYER_CONFIG_VERSION_LIMIT,
+    DEFAULT_RELAYER_METADATA_MAX_BYTES, DEFAULT_RELAYER_OUTCOME_HISTORY_LIMIT,
+    DEFAULT_RELAYER_STATUS_HISTORY_LIMIT, RELAYER_IDEMPOTENCY_KEY_TTL_SECONDS, RELAYER_ID_REGEX,
+    RELAYER_SLOT_TTL_SECONDS, RELAYER_WRITE_MAX_ATTEMPTS,
+};
 use crate::models::UpdateRelayerRequest;
 use crate::models::{
-    DisabledReason, PaginationQuery, RelayerNetworkPolicy, RelayerRepoModel, RepositoryError,
+    AlertTransition, ChangeKind, ChangeRecord, CircuitState, DeleteReport, DisabledReason,
+    EnforceReport, HealthCheckFailure, LogLevel, NetworkType, PaginationQuery, PolicyGuardrails,
+    ProvisionMode, ProvisionReport, RelayerConfigVersionInfo, RelayerDescriptor, RelayerFilter,
+    RelayerHealth, RelayerLastError, RelayerMode, RelayerNetworkPolicy, RelayerOperationalStatus,
+    RelayerRepoModel, RelayerTemplate, RelayerValidationError, RepositoryError, StatusChange,
 };
 use crate::repositories::redis_base::RedisRepository;
-use crate::repositories::{BatchRetrievalResult, PaginatedResult, RelayerRepository, Repository};
+use crate::repositories::{
+    changes_after, ensure_not_inherited, error_rate_window_start, match_addresses,
+    prepare_provision, provision_rejected, select_sticky, validate_error_rate_threshold,
+    validate_maintenance_until, validate_submission, BatchRetrievalResult, ConfigReadGuard,
+    EncryptionProvider, EncryptionScope, NotificationRegistry, PaginatedResult, PolicyPredicate,
+    ReadToken, RelayerConfigLocks, RelayerRecordCodec, RelayerRepository, RelayerSlots,
+    RelayerSnapshot, Repository, SignerChainAllowlist, SlotGuard, StickyStrategy, TenantRegistry,
+};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
 use redis::aio::ConnectionManager;
-use redis::AsyncCommands;
+use redis::{AsyncCommands, ExistenceCheck, Script, SetExpiry, SetOptions};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, error, warn};
 
 const RELAYER_PREFIX: &str = "relayer";
 const RELAYER_LIST_KEY: &str = "relayer_list";
+const RELAYER_IDEMPOTENCY_PREFIX: &str = "relayer_idempotency";
+const RELAYER_IDEMPOTENCY_INDEX_PREFIX: &str = "relayer_idempotency_index";
+const RELAYER_SUBMISSION_PREFIX: &str = "relayer_submission";
+const RELAYER_ALIAS_INDEX_KEY: &str = "relayer_alias";
+const RELAYER_CHANGE_LOG_KEY: &str = "relayer_changes";
+const RELAYER_CHANGE_SEQ_KEY: &str = "relayer_change_seq";
+const RELAYER_MAINTENANCE_KEY: &str = "relayer_maintenance";
+const RELAYER_PENDING_PREFIX: &str = "relayer_pending";
+const RELAYER_SPEND_PREFIX: &str = "relayer_spend";
+const RELAYER_SLOTS_PREFIX: &str = "relayer_slots";
+
+lazy_static! {
+    /// Replaces the relayer record at KEYS[1] with ARGV[2] only if it is still ARGV[1],
+    /// then adds ID ARGV[3] to the ID set at KEYS[2] and updates the alias index at
+    /// KEYS[3]: ARGV[4] aliases to release follow, the remaining arguments are aliases
+    /// to claim. Returns 1 if written, 0 if the record had changed.
+    static ref UPDATE_IF_UNCHANGED_SCRIPT: Script = Script::new(
+        r"
+        if (redis.call('GET', KEYS[1]) or '') ~= ARGV[1] then
+            return 0
+        end
+        redis.call('SET', KEYS[1], ARGV[2])
+        redis.call('SADD', KEYS[2], ARGV[3])
+        local released = tonumber(ARGV[4])
+        for i = 5, 4 + released do
+            redis.call('HDEL', KEYS[3], ARGV[i])
+        end
+        for i = 5 + released, #ARGV do
+            redis.call('HSET', KEYS[3], ARGV[i], ARGV[3])
+        end
+        return 1
+        "
+    );
+
+    /// Sets KEYS[1] to ARGV[2] only if it still holds ARGV[1], an empty ARGV[1] standing
+    /// for a missing key. Returns 1 if written, 0 if the value had changed.
+    static ref SET_IF_UNCHANGED_SCRIPT: Script = Script::new(
+        r"
+        if (redis.call('GET', KEYS[1]) or '') ~= ARGV[1] then
+            return 0
+        end
+        redis.call('SET', KEYS[1], ARGV[2])
+        return 1
+        "
+    );
+
+    /// Takes a slot from the counter at KEYS[1] if fewer than ARGV[1] are taken,
+    /// refreshing its expiry to ARGV[2] milliseconds. Returns 1 if a slot was taken.
+    static ref ACQUIRE_SLOT_SCRIPT: Script = Script::new(
+        r"
+        local taken = redis.call('INCR', KEYS[1])
+        redis.call('PEXPIRE', KEYS[1], ARGV[2])
+        if taken > tonumber(ARGV[1]) then
+            redis.call('DECR', KEYS[1])
+            return 0
+        end
+        return 1
+        "
+    );
+
+    /// Decrements the counter at KEYS[1] unless it is already zero or missing,
+    /// returning the new value
+    static ref DECREMENT_TO_ZERO_SCRIPT: Script = Script::new(
+        r"
+        local count = tonumber(redis.call('GET', KEYS[1]) or '0')
+        if count <= 0 then
+            return 0
+        end
+        return redis.call('DECR', KEYS[1])
+        "
+    );
+}
 
 #[derive(Clone)]
 pub struct RedisRelayerRepository {
     pub client: Arc<ConnectionManager>,
     pub key_prefix: String,
+    /// Maximum number of status transitions kept per relayer
+    pub status_history_limit: usize,
+    /// Maximum number of operation outcomes kept per relayer
+    pub outcome_history_limit: usize,
+    /// Maximum number of configuration versions kept per relayer
+    pub config_version_limit: usize,
+    /// Maximum size, in bytes of serialized JSON, of a relayer's metadata
+    pub metadata_size_limit: usize,
+    /// Maximum number of records kept in the change log
+    pub change_log_capacity: usize,
+    /// Optional safety limits enforced on policies in `create`, `update` and `update_policy`
+    pub policy_guardrails: Option<PolicyGuardrails>,
+    /// Page size per network type for listings whose query omits `per_page`
+    pub per_page_defaults: HashMap<NetworkType, u32>,
+    /// Optional source of signer chain allowlists checked in `create` and `rekey`
+    pub signer_chain_allowlist: Option<Arc<dyn SignerChainAllowlist>>,
+    /// Optional source of known tenants checked in `transfer_ownership`
+    pub tenant_registry: Option<Arc<dyn TenantRegistry>>,
+    /// Optional source of known notifications checked in `partial_update`
+    pub notification_registry: Option<Arc<dyn NotificationRegistry>>,
+    /// In-flight transaction slots of uncapped relayers handed out by `try_acquire_slot`,
+    /// shared by clones. Slots of capped relayers are counted in Redis.
+    pub slots: Arc<RelayerSlots>,
+    /// Configuration read locks handed out by `acquire_config_read`, shared by clones
+    pub config_locks: Arc<RelayerConfigLocks>,
+    /// Rule relayer IDs must match in `create`
+    pub id_pattern: Regex,
+    /// Encoding of stored relayers, encrypting them when a provider is configured
+    pub codec: RelayerRecordCodec,
 }
 
 impl RedisRepository for RedisRelayerRepository {}
@@ -38,9 +162,173 @@ impl RedisRelayerRepository {
         Ok(Self {
             client: connection_manager,
             key_prefix,
+            status_history_limit: DEFAULT_RELAYER_STATUS_HISTORY_LIMIT,
+            outcome_history_limit: DEFAULT_RELAYER_OUTCOME_HISTORY_LIMIT,
+            config_version_limit: DEFAULT_RELAYER_CONFIG_VERSION_LIMIT,
+            metadata_size_limit: DEFAULT_RELAYER_METADATA_MAX_BYTES,
+            change_log_capacity: DEFAULT_RELAYER_CHANGE_LOG_CAPACITY,
+            policy_guardrails: None,
+            per_page_defaults: HashMap::new(),
+            signer_chain_allowlist: None,
+            tenant_registry: None,
+            notification_registry: None,
+            slots: Arc::new(RelayerSlots::new()),
+            config_locks: Arc::new(RelayerConfigLocks::new(Duration::from_secs(
+                DEFAULT_RELAYER_CONFIG_LOCK_TIMEOUT_SECONDS,
+            ))),
+            id_pattern: RELAYER_ID_REGEX.clone(),
+            codec: RelayerRecordCodec::default(),
         })
     }
 
+    /// Sets the maximum number of status transitions kept per relayer
+    pub fn with_status_history_limit(mut self, limit: usize) -> Self {
+        self.status_history_limit = limit;
+        self
+    }
+
+    /// Sets the maximum number of operation outcomes kept per relayer
+    pub fn with_outcome_history_limit(mut self, limit: usize) -> Self {
+        self.outcome_history_limit = limit;
+        self
+    }
+
+    /// Sets how long `update` and `update_policy` wait for config read guards to be
+    /// released before failing with `Conflict`
+    pub fn with_config_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.config_locks = Arc::new(RelayerConfigLocks::new(timeout));
+        self
+    }
+
+    /// Sets the maximum number of configuration versions kept per relayer
+    pub fn with_config_version_limit(mut self, limit: usize) -> Self {
+        self.config_version_limit = limit;
+        self
+    }
+
+    /// Sets the maximum size, in bytes of serialized JSON, of a relayer's metadata
+    pub fn with_metadata_size_limit(mut self, max_bytes: usize) -> Self {
+        self.metadata_size_limit = max_bytes;
+        self
+    }
+
+    /// Replaces the rule relayer IDs must match in `create`, by default
+    /// [`RELAYER_ID_REGEX`]. The pattern should be anchored.
+    pub fn with_id_pattern(mut self, pattern: Regex) -> Self {
+        self.id_pattern = pattern;
+        self
+    }
+
+    /// Sets the maximum number of records kept in the change log
+    pub fn with_change_log_capacity(mut self, capacity: usize) -> Self {
+        self.change_log_capacity = capacity;
+        self
+    }
+
+    /// Enforces the given guardrails on policies stored by this repository
+    pub fn with_policy_guardrails(mut self, guardrails: PolicyGuardrails) -> Self {
+        self.policy_guardrails = Some(guardrails);
+        self
+    }
+
+    /// Checks relayer chains against the chains their signers are authorized for
+    /// in `create` and `rekey`
+    pub fn with_signer_chain_allowlist(mut self, allowlist: Arc<dyn SignerChainAllowlist>) -> Self {
+        self.signer_chain_allowlist = Some(allowlist);
+        self
+    }
+
+    /// Encrypts stored relayers with the given provider. Records written before
+    /// encryption was enabled are still read.
+    pub fn with_encryption(
+        mut self,
+        provider: Arc<dyn EncryptionProvider>,
+        scope: EncryptionScope,
+    ) -> Self {
+        self.codec = RelayerRecordCodec::new(provider, scope);
+        self
+    }
+
+    /// Checks target tenants of `transfer_ownership` against the given registry
+    pub fn with_tenant_registry(mut self, registry: Arc<dyn TenantRegistry>) -> Self {
+        self.tenant_registry = Some(registry);
+        self
+    }
+
+    /// Checks notifications referenced by `partial_update` against the given registry
+    pub fn with_notification_registry(mut self, registry: Arc<dyn NotificationRegistry>) -> Self {
+        self.notification_registry = Some(registry);
+        self
+    }
+
+    /// Sets the page size per network type used when a query omits `per_page`.
+    /// Network types without an entry fall back to [`DEFAULT_PER_PAGE`].
+    pub fn with_per_page_defaults(mut self, defaults: HashMap<NetworkType, u32>) -> Self {
+        self.per_page_defaults = defaults;
+        self
+    }
+
+    /// Fails if the relayer's chain is not one its signer is authorized for. Only
+    /// relayers with a chain id are checked, and only when an allowlist is injected.
+    async fn check_signer_chain(&self, relayer: &RelayerRepoModel) -> Result<(), RepositoryError> {
+        let (Some(allowlist), Some(_)) = (&self.signer_chain_allowlist, relayer.chain_id) else {
+            return Ok(());
+        };
+        let allowed_chains = allowlist.signer_allowed_chains(&relayer.signer_id).await?;
+        relayer.validate_signer_chain(allowed_chains.as_deref())
+    }
+
+    /// Fails if `tenant_id` is not known to the injected tenant registry. Any tenant
+    /// is accepted when no registry is injected.
+    async fn check_tenant(&self, tenant_id: &str) -> Result<(), RepositoryError> {
+        let Some(registry) = &self.tenant_registry else {
+            return Ok(());
+        };
+        if !registry.tenant_exists(tenant_id).await? {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Tenant {tenant_id} does not exist"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fails if a patch points the relayer at a notification unknown to the injected
+    /// notification registry. Any notification is accepted when no registry is injected.
+    async fn check_notification(
+        &self,
+        update: &UpdateRelayerRequest,
+    ) -> Result<(), RepositoryError> {
+        let (Some(registry), Some(Some(notification_id))) =
+            (&self.notification_registry, &update.notification_id)
+        else {
+            return Ok(());
+        };
+        if !registry.notification_exists(notification_id).await? {
+            return Err(RepositoryError::NotFound(format!(
+                "Notification with ID {notification_id} not found"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Page size for a listing of `network_type` relayers whose query omits `per_page`
+    fn default_per_page(&self, network_type: NetworkType) -> u32 {
+        self.per_page_defaults
+            .get(&network_type)
+            .copied()
+            .unwrap_or(DEFAULT_PER_PAGE)
+    }
+
+    fn check_policy_guardrails(
+        &self,
+        policy: &RelayerNetworkPolicy,
+    ) -> Result<(), RepositoryError> {
+        match &self.policy_guardrails {
+            Some(guardrails) => guardrails.check(policy),
+            None => Ok(()),
+        }
+    }
+
     /// Generate key for relayer data: relayer:{relayer_id}
     fn relayer_key(&self, relayer_id: &str) -> String {
         format!("{}:{}:{}", self.key_prefix, RELAYER_PREFIX, relayer_id)
@@ -51,6 +339,484 @@ impl RedisRelayerRepository {
         format!("{}:{}", self.key_prefix, RELAYER_LIST_KEY)
     }
 
+    /// Generate key for a processed creation idempotency key: relayer_idempotency:{key}
+    fn idempotency_key(&self, key: &str) -> String {
+        format!("{}:{}:{}", self.key_prefix, RELAYER_IDEMPOTENCY_PREFIX, key)
+    }
+
+    /// Generate key for the set of idempotency keys claimed for a relayer:
+    /// relayer_idempotency_index:{relayer_id}
+    fn idempotency_index_key(&self, relayer_id: &str) -> String {
+        format!(
+            "{}:{}:{}",
+            self.key_prefix, RELAYER_IDEMPOTENCY_INDEX_PREFIX, relayer_id
+        )
+    }
+
+    /// Returns the idempotency keys claimed for `relayer_ids`, along with their index
+    /// keys, so they are deleted with the relayers and a retry creates it again
+    async fn idempotency_keys_of(
+        &self,
+        conn: &mut ConnectionManager,
+        relayer_ids: &[String],
+    ) -> Result<Vec<String>, RepositoryError> {
+        let index_keys: Vec<String> = relayer_ids
+            .iter()
+            .map(|id| self.idempotency_index_key(id))
+            .collect();
+        let mut pipe = redis::pipe();
+        for index_key in &index_keys {
+            pipe.smembers(index_key);
+        }
+        let claimed: Vec<Vec<String>> = pipe
+            .query_async(conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_idempotency_keys"))?;
+        Ok(claimed.into_iter().flatten().chain(index_keys).collect())
+    }
+
+    /// Generate key for a registered submission: relayer_submission:{relayer_id}:{key}
+    fn submission_key(&self, relayer_id: &str, key: &str) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            self.key_prefix, RELAYER_SUBMISSION_PREFIX, relayer_id, key
+        )
+    }
+
+    /// Generate key for the alias index: relayer_alias (hash of alias to relayer ID)
+    fn alias_index_key(&self) -> String {
+        format!("{}:{}", self.key_prefix, RELAYER_ALIAS_INDEX_KEY)
+    }
+
+    /// Generate key for the change log: relayer_changes (list of serialized change records)
+    fn change_log_key(&self) -> String {
+        format!("{}:{}", self.key_prefix, RELAYER_CHANGE_LOG_KEY)
+    }
+
+    /// Generate key for the change log sequence counter: relayer_change_seq
+    fn change_seq_key(&self) -> String {
+        format!("{}:{}", self.key_prefix, RELAYER_CHANGE_SEQ_KEY)
+    }
+
+    /// Generate key for the maintenance window: relayer_maintenance (end of the window,
+    /// expiring with it)
+    fn maintenance_key(&self) -> String {
+        format!("{}:{}", self.key_prefix, RELAYER_MAINTENANCE_KEY)
+    }
+
+    /// Generate key for a relayer's pending transaction count: relayer_pending:{relayer_id}
+    fn pending_key(&self, relayer_id: &str) -> String {
+        format!(
+            "{}:{}:{}",
+            self.key_prefix, RELAYER_PENDING_PREFIX, relayer_id
+        )
+    }
+
+    /// Generate key for a relayer's spend in the current window:
+    /// relayer_spend:{relayer_id} (`{window start in ms}:{amount}`)
+    fn spend_key(&self, relayer_id: &str) -> String {
+        format!(
+            "{}:{}:{}",
+            self.key_prefix, RELAYER_SPEND_PREFIX, relayer_id
+        )
+    }
+
+    /// Generate key for a relayer's taken in-flight slots: relayer_slots:{relayer_id}
+    /// (expiring [`RELAYER_SLOT_TTL_SECONDS`] after the last slot was taken)
+    fn slots_key(&self, relayer_id: &str) -> String {
+        format!(
+            "{}:{}:{}",
+            self.key_prefix, RELAYER_SLOTS_PREFIX, relayer_id
+        )
+    }
+
+    /// Keys of the counters a relayer keeps outside its record, so they can be changed
+    /// atomically on the server. They are read along with the record and deleted with it.
+    fn counter_keys(&self, relayer_id: &str) -> Vec<String> {
+        vec![self.pending_key(relayer_id), self.spend_key(relayer_id)]
+    }
+
+    /// Overlays counter values read from [`Self::counter_keys`], in the same order, on
+    /// `relayer`. A missing counter is zero.
+    fn apply_counters(
+        &self,
+        relayer: &mut RelayerRepoModel,
+        counters: &[Option<String>],
+    ) -> Result<(), RepositoryError> {
+        relayer.pending_count = match &counters[0] {
+            Some(count) => count.parse().map_err(|e| {
+                RepositoryError::InvalidData(format!(
+                    "Invalid pending count of relayer {}: {e}",
+                    relayer.id
+                ))
+            })?,
+            None => 0,
+        };
+        self.apply_spend(relayer, counters[1].as_deref())
+    }
+
+    /// Overlays the spend stored under [`Self::spend_key`] on `relayer`. A missing
+    /// value means no spend window is open.
+    fn apply_spend(
+        &self,
+        relayer: &mut RelayerRepoModel,
+        stored: Option<&str>,
+    ) -> Result<(), RepositoryError> {
+        let Some(stored) = stored else {
+            relayer.spend_window_start = None;
+            relayer.spent_today = 0;
+            return Ok(());
+        };
+        let invalid = || {
+            RepositoryError::InvalidData(format!(
+                "Invalid spend {stored} of relayer {}",
+                relayer.id
+            ))
+        };
+        let (window_start, spent) = stored.split_once(':').ok_or_else(invalid)?;
+        let window_start = window_start
+            .parse()
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or_else(invalid)?;
+        let spent = spent.parse().map_err(|_| invalid())?;
+        relayer.spend_window_start = Some(window_start);
+        relayer.spent_today = spent;
+        Ok(())
+    }
+
+    /// Appends mutations of the given relayers to the change log, trimming it to capacity
+    async fn record_changes(
+        &self,
+        conn: &mut ConnectionManager,
+        kind: ChangeKind,
+        ids: &[String],
+    ) -> Result<(), RepositoryError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        // Reserve a contiguous block of sequence numbers for the batch
+        let last_seq: u64 = conn
+            .incr(self.change_seq_key(), ids.len() as u64)
+            .await
+            .map_err(|e| self.map_redis_error(e, "record_changes_reserve_seq"))?;
+        let first_seq = last_seq - ids.len() as u64 + 1;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for (offset, id) in ids.iter().enumerate() {
+            let record = ChangeRecord {
+                seq: first_seq + offset as u64,
+                kind,
+                id: id.clone(),
+                at: Utc::now(),
+            };
+            let serialized = self.serialize_entity(&record, |r| &r.id, "relayer change record")?;
+            pipe.rpush(self.change_log_key(), serialized);
+        }
+        pipe.ltrim(
+            self.change_log_key(),
+            -(self.change_log_capacity as isize),
+            -1,
+        );
+
+        pipe.exec_async(conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "record_changes_pipeline"))?;
+        Ok(())
+    }
+
+    /// Applies `retag` to every relayer matching `filter` and writes the changed ones in
+    /// a single atomic pipeline, returning how many relayers it changed
+    async fn retag_where(
+        &self,
+        filter: &RelayerFilter,
+        mut retag: impl FnMut(&mut RelayerRepoModel) -> bool,
+    ) -> Result<u64, RepositoryError> {
+        let now = Utc::now();
+        let mut changed: Vec<RelayerRepoModel> = Vec::new();
+        for mut relayer in self.list_all().await? {
+            if filter.matches_at(&relayer, now) && retag(&mut relayer) {
+                relayer.version += 1;
+                relayer.updated_at = Some(now.to_rfc3339());
+                changed.push(relayer);
+            }
+        }
+        if changed.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = self.client.as_ref().clone();
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for relayer in &changed {
+            let serialized = self.codec.encode(relayer)?;
+            pipe.set(self.relayer_key(&relayer.id), serialized);
+        }
+        pipe.exec_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "retag_relayers_pipeline"))?;
+
+        let ids: Vec<String> = changed.into_iter().map(|relayer| relayer.id).collect();
+        self.record_changes(&mut conn, ChangeKind::Updated, &ids)
+            .await?;
+        debug!(count = %ids.len(), "retagged relayers");
+        Ok(ids.len() as u64)
+    }
+
+    /// Fails if any alias of `relayer` is already used as another relayer's ID or alias
+    async fn check_alias_collisions(
+        &self,
+        conn: &mut ConnectionManager,
+        relayer: &RelayerRepoModel,
+    ) -> Result<(), RepositoryError> {
+        for alias in &relayer.aliases {
+            let owner: Option<String> = conn
+                .hget(self.alias_index_key(), alias)
+                .await
+                .map_err(|e| self.map_redis_error(e, "check_alias_owner"))?;
+            let is_relayer_id: bool = conn
+                .sismember(self.relayer_list_key(), alias)
+                .await
+                .map_err(|e| self.map_redis_error(e, "check_alias_is_relayer_id"))?;
+            if is_relayer_id || owner.is_some_and(|owner| owner != relayer.id) {
+                return Err(RepositoryError::ConstraintViolation(format!(
+                    "Relayer alias {alias} is already in use"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails if `relayer`'s standby pairing is invalid or its primary already has a standby
+    async fn check_standby_pairing(
+        &self,
+        relayer: &RelayerRepoModel,
+    ) -> Result<(), RepositoryError> {
+        let Some(primary_id) = &relayer.standby_for else {
+            return Ok(());
+        };
+        let primary = match self.get_by_id(primary_id.clone()).await {
+            Ok(primary) => Some(primary),
+            Err(RepositoryError::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+        relayer.validate_standby_for(primary.as_ref())?;
+
+        let all_relayers = self.list_all().await?;
+        if all_relayers
+            .iter()
+            .any(|other| other.id != relayer.id && other.standby_for.as_ref() == Some(primary_id))
+        {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Relayer {primary_id} already has a standby"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fails if `relayer` does not share network type and chain id with the other
+    /// members of its nonce group
+    async fn check_nonce_group(&self, relayer: &RelayerRepoModel) -> Result<(), RepositoryError> {
+        if relayer.nonce_group.is_none() {
+            return Ok(());
+        }
+        let all_relayers = self.list_all().await?;
+        relayer.validate_nonce_group(
+            all_relayers
+                .iter()
+                .filter(|other| other.id != relayer.id && other.nonce_group == relayer.nonce_group),
+        )
+    }
+
+    /// Returns the relayers `relayer` inherits from, nearest parent first
+    async fn load_inheritance_chain(
+        &self,
+        relayer: &RelayerRepoModel,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        if relayer.inherits_from.is_none() {
+            return Ok(Vec::new());
+        }
+        let stored: HashMap<String, RelayerRepoModel> = self
+            .list_all()
+            .await?
+            .into_iter()
+            .map(|relayer| (relayer.id.clone(), relayer))
+            .collect();
+        relayer.inheritance_chain(|id| stored.get(id).cloned())
+    }
+
+    /// Replaces the stored relayer with `entity` without taking the config write
+    /// lock; `update` takes the lock first. Only for full replacements: changes to
+    /// part of a relayer go through [`Self::update_with`], so they are applied to
+    /// whatever a concurrent writer stored.
+    ///
+    /// The write is a compare-and-set against the record it replaces, so concurrent
+    /// writers, in this process or another, never store the same version twice.
+    async fn update_unlocked(
+        &self,
+        id: String,
+        entity: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |_| Ok(entity.clone())).await
+    }
+
+    /// Reads the stored record of `id` along with the relayer it decodes to, with its
+    /// counters applied
+    async fn get_record(
+        &self,
+        conn: &mut ConnectionManager,
+        id: &str,
+    ) -> Result<(String, RelayerRepoModel), RepositoryError> {
+        let mut keys = vec![self.relayer_key(id)];
+        keys.extend(self.counter_keys(id));
+        let mut values: Vec<Option<String>> = conn
+            .mget(&keys)
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_relayer_record"))?;
+        let record = values[0]
+            .take()
+            .ok_or_else(|| RepositoryError::NotFound(format!("Relayer with ID {id} not found")))?;
+        let mut relayer = self.codec.decode(&record, id)?;
+        self.apply_counters(&mut relayer, &values[1..])?;
+        Ok((record, relayer))
+    }
+
+    /// Applies `change` to the stored relayer and writes the result with a
+    /// compare-and-set against the record it was read from. When another writer gets
+    /// in first, the relayer is read again and `change` applied to the new state, up
+    /// to [`RELAYER_WRITE_MAX_ATTEMPTS`] times. Does not take the config write lock.
+    async fn update_with<F>(
+        &self,
+        id: &str,
+        mut change: F,
+    ) -> Result<RelayerRepoModel, RepositoryError>
+    where
+        F: FnMut(RelayerRepoModel) -> Result<RelayerRepoModel, RepositoryError> + Send,
+    {
+        if id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer ID cannot be empty".to_string(),
+            ));
+        }
+
+        let mut conn = self.client.as_ref().clone();
+        for _ in 0..RELAYER_WRITE_MAX_ATTEMPTS {
+            let (record, existing) = self.get_record(&mut conn, id).await?;
+
+            let mut updated = change(existing.clone())?;
+            // Ensure we preserve the original ID
+            updated.id = id.to_string();
+            if updated.name.is_empty() {
+                return Err(RepositoryError::InvalidData(
+                    "Relayer name cannot be empty".to_string(),
+                ));
+            }
+            updated.validate_extra_webhook_urls()?;
+            updated.validate_derivation_path()?;
+            updated.validate_fee_account()?;
+            updated.validate_signature_scheme()?;
+            updated.validate_allowed_origins()?;
+            updated.validate_signer_timeout()?;
+            updated.validate_aliases()?;
+            self.check_alias_collisions(&mut conn, &updated).await?;
+            // Only a changed pairing is validated, so a standby whose primary was deleted
+            // can still be updated
+            if existing.standby_for != updated.standby_for {
+                self.check_standby_pairing(&updated).await?;
+            }
+            self.check_nonce_group(&updated).await?;
+            if existing.inherits_from != updated.inherits_from {
+                self.load_inheritance_chain(&updated).await?;
+            }
+            updated.record_config_version(self.config_version_limit);
+            updated.version = existing.version + 1;
+
+            if self
+                .write_if_unchanged(&mut conn, &record, &existing, &updated)
+                .await?
+            {
+                self.record_changes(&mut conn, ChangeKind::Updated, &[id.to_string()])
+                    .await?;
+                debug!(relayer_id = %id, "updated relayer");
+                return Ok(updated);
+            }
+            debug!(relayer_id = %id, "relayer changed while being updated, retrying");
+        }
+
+        Err(RepositoryError::Conflict(format!(
+            "Relayer {id} kept changing while being updated"
+        )))
+    }
+
+    /// Writes `updated` in place of `existing` if the stored record is still `record`,
+    /// keeping the ID set and alias index in step. Returns false, writing nothing, when
+    /// another writer changed the relayer first.
+    async fn write_if_unchanged(
+        &self,
+        conn: &mut ConnectionManager,
+        record: &str,
+        existing: &RelayerRepoModel,
+        updated: &RelayerRepoModel,
+    ) -> Result<bool, RepositoryError> {
+        let serialized = self.codec.encode(updated)?;
+        let released: Vec<&String> = existing
+            .aliases
+            .iter()
+            .filter(|alias| !updated.aliases.contains(alias))
+            .collect();
+
+        let mut invocation = UPDATE_IF_UNCHANGED_SCRIPT.prepare_invoke();
+        invocation
+            .key(self.relayer_key(&updated.id))
+            .key(self.relayer_list_key())
+            .key(self.alias_index_key())
+            .arg(record)
+            .arg(serialized)
+            .arg(&updated.id)
+            .arg(released.len());
+        for alias in released {
+            invocation.arg(alias);
+        }
+        for alias in &updated.aliases {
+            invocation.arg(alias);
+        }
+
+        invocation
+            .invoke_async(conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "update_relayer_if_unchanged"))
+    }
+
+    /// Lua scripts run by this repository, loaded by `init`
+    fn scripts() -> [&'static Script; 4] {
+        [
+            &UPDATE_IF_UNCHANGED_SCRIPT,
+            &SET_IF_UNCHANGED_SCRIPT,
+            &ACQUIRE_SLOT_SCRIPT,
+            &DECREMENT_TO_ZERO_SCRIPT,
+        ]
+    }
+
+    /// Fails with `NotFound` if no relayer is stored under `id`
+    async fn check_exists(
+        &self,
+        conn: &mut ConnectionManager,
+        id: &str,
+    ) -> Result<(), RepositoryError> {
+        let exists: bool = conn
+            .exists(self.relayer_key(id))
+            .await
+            .map_err(|e| self.map_redis_error(e, "check_relayer_exists"))?;
+        if !exists {
+            return Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            )));
+        }
+        Ok(())
+    }
+
     /// Batch fetch relayers by IDs
     async fn get_relayers_by_ids(
         &self,
@@ -65,9 +831,14 @@ impl RedisRelayerRepository {
         }
 
         let mut conn = self.client.as_ref().clone();
-        let keys: Vec<String> = ids.iter().map(|id| self.relayer_key(id)).collect();
+        // Each relayer's record is followed by its counters
+        let keys: Vec<String> = ids
+            .iter()
+            .flat_map(|id| std::iter::once(self.relayer_key(id)).chain(self.counter_keys(id)))
+            .collect();
+        let keys_per_relayer = keys.len() / ids.len();
 
-        debug!(count = %keys.len(), "batch fetching relayer data");
+        debug!(count = %ids.len(), "batch fetching relayer data");
 
         let values: Vec<Option<String>> = conn
             .mget(&keys)
@@ -77,10 +848,14 @@ impl RedisRelayerRepository {
         let mut relayers = Vec::new();
         let mut failed_count = 0;
         let mut failed_ids = Vec::new();
-        for (i, value) in values.into_iter().enumerate() {
-            match value {
+        for (i, values) in values.chunks(keys_per_relayer).enumerate() {
+            match &values[0] {
                 Some(json) => {
-                    match self.deserialize_entity(&json, &ids[i], "relayer") {
+                    let decoded = self.codec.decode(json, &ids[i]).and_then(|mut relayer| {
+                        self.apply_counters(&mut relayer, &values[1..])?;
+                        Ok(relayer)
+                    });
+                    match decoded {
                         Ok(relayer) => relayers.push(relayer),
                         Err(e) => {
                             failed_count += 1;
@@ -113,52 +888,94 @@ impl fmt::Debug for RedisRelayerRepository {
         f.debug_struct("RedisRelayerRepository")
             .field("client", &"<ConnectionManager>")
             .field("key_prefix", &self.key_prefix)
+            .field("status_history_limit", &self.status_history_limit)
+            .field("outcome_history_limit", &self.outcome_history_limit)
+            .field("config_version_limit", &self.config_version_limit)
+            .field("metadata_size_limit", &self.metadata_size_limit)
+            .field("change_log_capacity", &self.change_log_capacity)
+            .field("policy_guardrails", &self.policy_guardrails)
+            .field("per_page_defaults", &self.per_page_defaults)
+            .field("signer_chain_allowlist", &self.signer_chain_allowlist)
+            .field("tenant_registry", &self.tenant_registry)
+            .field("notification_registry", &self.notification_registry)
+            .field("slots", &self.slots)
+            .field("config_locks", &self.config_locks)
+            .field("id_pattern", &self.id_pattern.as_str())
+            .field("codec", &self.codec)
             .finish()
     }
 }
 
 #[async_trait]
 impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
-    async fn create(&self, entity: RelayerRepoModel) -> Result<RelayerRepoModel, RepositoryError> {
+    /// Checks the server is reachable, then loads the scripts relayer writes run so
+    /// the first writes do not pay for it
+    async fn init(&self) -> Result<(), RepositoryError> {
+        self.ping().await?;
+
+        let mut conn = self.client.as_ref().clone();
+        for script in Self::scripts() {
+            script
+                .load_async(&mut conn)
+                .await
+                .map_err(|e| self.map_redis_error(e, "init_load_script"))?;
+        }
+
+        debug!("initialized relayer storage");
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let _: String = redis::cmd("PING")
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.map_ping_error(e))?;
+
+        Ok(())
+    }
+
+    async fn create(
+        &self,
+        mut entity: RelayerRepoModel,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        entity.normalize_id();
         if entity.id.is_empty() {
             return Err(RepositoryError::InvalidData(
                 "Relayer ID cannot be empty".to_string(),
             ));
         }
 
-        if entity.name.is_empty() {
-            return Err(RepositoryError::InvalidData(
-                "Relayer name cannot be empty".to_string(),
-            ));
-        }
+        // Surface the first failure, in the order the checks run
+        self.validate_create(entity.clone())
+            .await
+            .map_err(|mut errors| errors.remove(0))?;
 
         let mut conn = self.client.as_ref().clone();
         let relayer_key = self.relayer_key(&entity.id);
 
-        // Check if relayer already exists
-        let exists: bool = conn
-            .exists(&relayer_key)
-            .await
-            .map_err(|e| self.map_redis_error(e, "create_relayer_exists_check"))?;
-
-        if exists {
-            return Err(RepositoryError::ConstraintViolation(format!(
-                "Relayer with ID {} already exists",
-                entity.id
-            )));
-        }
-
-        let serialized = self.serialize_entity(&entity, |r| &r.id, "relayer")?;
+        entity.record_config_version(self.config_version_limit);
+        let serialized = self.codec.encode(&entity)?;
 
         // Use pipeline for atomic operations
         let mut pipe = redis::pipe();
         pipe.atomic();
         pipe.set(&relayer_key, &serialized);
         pipe.sadd(self.relayer_list_key(), &entity.id);
+        for alias in &entity.aliases {
+            pipe.hset(self.alias_index_key(), alias, &entity.id);
+        }
 
         pipe.exec_async(&mut conn)
             .await
             .map_err(|e| self.map_redis_error(e, "create_relayer_pipeline"))?;
+        self.record_changes(
+            &mut conn,
+            ChangeKind::Created,
+            std::slice::from_ref(&entity.id),
+        )
+        .await?;
 
         debug!(relayer_id = %entity.id, "created relayer");
         Ok(entity)
@@ -172,25 +989,19 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
         }
 
         let mut conn = self.client.as_ref().clone();
-        let relayer_key = self.relayer_key(&id);
 
         debug!(relayer_id = %id, "fetching relayer");
 
-        let json: Option<String> = conn
-            .get(&relayer_key)
-            .await
-            .map_err(|e| self.map_redis_error(e, "get_relayer_by_id"))?;
-
-        match json {
-            Some(json) => {
+        match self.get_record(&mut conn, &id).await {
+            Ok((_, relayer)) => {
                 debug!(relayer_id = %id, "found relayer");
-                self.deserialize_entity(&json, &id, "relayer")
+                Ok(relayer)
             }
-            None => {
-                debug!(relayer_id = %id, "relayer not found");
-                Err(RepositoryError::NotFound(format!(
-                    "Relayer with ID {id} not found"
-                )))
+            Err(e) => {
+                if matches!(e, RepositoryError::NotFound(_)) {
+                    debug!(relayer_id = %id, "relayer not found");
+                }
+                Err(e)
             }
         }
     }
@@ -222,7 +1033,7 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
             ));
         }
 
-        if query.per_page == 0 {
+        if query.resolved_per_page() == 0 {
             return Err(RepositoryError::InvalidData(
                 "Per page count must be greater than 0".to_string(),
             ));
@@ -242,7 +1053,7 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
                 items: vec![],
                 total: 0,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
@@ -252,8 +1063,8 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
             .await
             .map_err(|e| self.map_redis_error(e, "list_paginated_members"))?;
 
-        let start = ((query.page - 1) * query.per_page) as usize;
-        let end = (start + query.per_page as usize).min(all_ids.len());
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let end = (start + query.resolved_per_page() as usize).min(all_ids.len());
 
         let page_ids = &all_ids[start..end];
         let items = self.get_relayers_by_ids(page_ids).await?;
@@ -262,7 +1073,7 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
             items: items.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -271,84 +1082,47 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
         id: String,
         entity: RelayerRepoModel,
     ) -> Result<RelayerRepoModel, RepositoryError> {
+        entity.policies.validate()?;
+        self.check_policy_guardrails(&entity.policies)?;
+        entity.validate_metadata_size(self.metadata_size_limit)?;
+        let _config = self.config_locks.write(&id).await?;
+        self.update_unlocked(id, entity).await
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
         if id.is_empty() {
             return Err(RepositoryError::InvalidData(
                 "Relayer ID cannot be empty".to_string(),
             ));
         }
 
-        if entity.name.is_empty() {
-            return Err(RepositoryError::InvalidData(
-                "Relayer name cannot be empty".to_string(),
-            ));
-        }
-
         let mut conn = self.client.as_ref().clone();
         let relayer_key = self.relayer_key(&id);
 
-        // Check if relayer exists
-        let exists: bool = conn
-            .exists(&relayer_key)
-            .await
-            .map_err(|e| self.map_redis_error(e, "update_relayer_exists_check"))?;
-
-        if !exists {
-            return Err(RepositoryError::NotFound(format!(
-                "Relayer with ID {id} not found"
-            )));
-        }
-
-        // Ensure we preserve the original ID
-        let mut updated_entity = entity;
-        updated_entity.id = id.clone();
-
-        let serialized = self.serialize_entity(&updated_entity, |r| &r.id, "relayer")?;
-
-        // Use pipeline for atomic operations
-        let mut pipe = redis::pipe();
-        pipe.atomic();
-        pipe.set(&relayer_key, &serialized);
-        pipe.sadd(self.relayer_list_key(), &id);
-
-        pipe.exec_async(&mut conn)
-            .await
-            .map_err(|e| self.map_redis_error(e, "update_relayer_pipeline"))?;
-
-        debug!(relayer_id = %id, "updated relayer");
-        Ok(updated_entity)
-    }
-
-    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
-        if id.is_empty() {
-            return Err(RepositoryError::InvalidData(
-                "Relayer ID cannot be empty".to_string(),
-            ));
-        }
-
-        let mut conn = self.client.as_ref().clone();
-        let relayer_key = self.relayer_key(&id);
-
-        // Check if relayer exists
-        let exists: bool = conn
-            .exists(&relayer_key)
-            .await
-            .map_err(|e| self.map_redis_error(e, "delete_relayer_exists_check"))?;
-
-        if !exists {
-            return Err(RepositoryError::NotFound(format!(
-                "Relayer with ID {id} not found"
-            )));
-        }
+        // Fetch the stored relayer, whose aliases must be released
+        let existing = self.get_by_id(id.clone()).await?;
+        ensure_not_inherited(&self.list_all().await?, &HashSet::from([id.as_str()]))?;
+        let idempotency_keys = self
+            .idempotency_keys_of(&mut conn, std::slice::from_ref(&id))
+            .await?;
 
         // Use pipeline for atomic operations
         let mut pipe = redis::pipe();
         pipe.atomic();
         pipe.del(&relayer_key);
+        for key in self.counter_keys(&id).into_iter().chain(idempotency_keys) {
+            pipe.del(key);
+        }
         pipe.srem(self.relayer_list_key(), &id);
+        for alias in &existing.aliases {
+            pipe.hdel(self.alias_index_key(), alias);
+        }
 
         pipe.exec_async(&mut conn)
             .await
             .map_err(|e| self.map_redis_error(e, "delete_relayer_pipeline"))?;
+        self.record_changes(&mut conn, ChangeKind::Deleted, std::slice::from_ref(&id))
+            .await?;
 
         debug!(relayer_id = %id, "deleted relayer");
         Ok(())
@@ -398,6 +1172,8 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
             return Ok(());
         }
 
+        let idempotency_keys = self.idempotency_keys_of(&mut conn, &relayer_ids).await?;
+
         // Use pipeline for atomic operations
         let mut pipe = redis::pipe();
         pipe.atomic();
@@ -406,14 +1182,23 @@ impl Repository<RelayerRepoModel, String> for RedisRelayerRepository {
         for relayer_id in &relayer_ids {
             let relayer_key = self.relayer_key(relayer_id);
             pipe.del(&relayer_key);
+            for key in self.counter_keys(relayer_id) {
+                pipe.del(key);
+            }
+        }
+        for key in idempotency_keys {
+            pipe.del(key);
         }
 
-        // Delete the relayer list key
+        // Delete the relayer list key and the alias index
         pipe.del(&relayer_list_key);
+        pipe.del(self.alias_index_key());
 
         pipe.exec_async(&mut conn)
             .await
             .map_err(|e| self.map_redis_error(e, "drop_all_entries_pipeline"))?;
+        self.record_changes(&mut conn, ChangeKind::Deleted, &relayer_ids)
+            .await?;
 
         debug!(count = %relayer_ids.len(), "dropped relayer entries");
         Ok(())
@@ -426,107 +1211,1438 @@ impl RelayerRepository for RedisRelayerRepository {
         let all_relayers = self.list_all().await?;
         let active_relayers: Vec<RelayerRepoModel> = all_relayers
             .into_iter()
-            .filter(|relayer| !relayer.paused)
+            .filter(|relayer| !relayer.paused)
+            .collect();
+
+        debug!(count = %active_relayers.len(), "found active relayers");
+        Ok(active_relayers)
+    }
+
+    async fn list_by_signer_id(
+        &self,
+        signer_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let all_relayers = self.list_all().await?;
+        let relayers_with_signer: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.signer_id == signer_id)
+            .collect();
+
+        debug!(count = %relayers_with_signer.len(), signer_id = %signer_id, "found relayers using signer");
+        Ok(relayers_with_signer)
+    }
+
+    async fn list_by_notification_id(
+        &self,
+        notification_id: &str,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let all_relayers = self.list_all().await?;
+        let relayers_with_notification: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| {
+                relayer
+                    .notification_id
+                    .as_ref()
+                    .is_some_and(|id| id == notification_id)
+            })
+            .collect();
+
+        debug!(count = %relayers_with_notification.len(), notification_id = %notification_id, "found relayers using notification");
+        Ok(relayers_with_notification)
+    }
+
+    async fn partial_update(
+        &self,
+        id: String,
+        update: UpdateRelayerRequest,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        update.validate_patch()?;
+        self.check_notification(&update).await?;
+
+        // First get the current relayer
+        let relayer = self.get_by_id(id.clone()).await?;
+
+        if let Some(name) = &update.name {
+            if self
+                .list_all()
+                .await?
+                .iter()
+                .any(|other| other.id != id && &other.name == name)
+            {
+                return Err(RepositoryError::ConstraintViolation(format!(
+                    "Relayer name {name} is already in use"
+                )));
+            }
+        }
+
+        // Skip the write entirely when nothing is set or nothing changes
+        if update.is_empty_patch() || !relayer.clone().apply_partial_update(update.clone()) {
+            return Ok(relayer);
+        }
+
+        // Update the relayer
+        self.update_with(&id, |mut relayer| {
+            relayer.apply_partial_update(update.clone());
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn enable_relayer(
+        &self,
+        relayer_id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&relayer_id, |mut relayer| {
+            // Update the system_disabled flag and clear reason
+            let from = relayer.state();
+            relayer.system_disabled = false;
+            relayer.disabled_reason = None;
+            relayer.quarantined_until = None;
+            relayer.record_status_change(from, self.status_history_limit);
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn disable_relayer(
+        &self,
+        relayer_id: String,
+        reason: DisabledReason,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&relayer_id, |mut relayer| {
+            // Update the system_disabled flag and set reason
+            let from = relayer.state();
+            relayer.system_disabled = true;
+            relayer.disabled_reason = Some(reason.clone());
+            relayer.quarantined_until = None;
+            relayer.scheduled_disable_at = None;
+            relayer.scheduled_disable_reason = None;
+            relayer.record_status_change(from, self.status_history_limit);
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn update_policy(
+        &self,
+        id: String,
+        policy: RelayerNetworkPolicy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        policy.validate()?;
+        self.check_policy_guardrails(&policy)?;
+
+        let _config = self.config_locks.write(&id).await?;
+
+        self.update_with(&id, |mut relayer| {
+            relayer.policies = policy.clone();
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn create_idempotent(
+        &self,
+        mut relayer: RelayerRepoModel,
+        idempotency_key: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        if idempotency_key.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Idempotency key cannot be empty".to_string(),
+            ));
+        }
+        // The claimed key must point at the ID `create` stores
+        relayer.normalize_id();
+
+        let mut conn = self.client.as_ref().clone();
+        let key = self.idempotency_key(&idempotency_key);
+
+        // Claim the key before creating so concurrent retries cannot both create a relayer
+        let options = SetOptions::default()
+            .conditional_set(ExistenceCheck::NX)
+            .with_expiration(SetExpiry::EX(RELAYER_IDEMPOTENCY_KEY_TTL_SECONDS));
+        let claimed: Option<String> = conn
+            .set_options(&key, &relayer.id, options)
+            .await
+            .map_err(|e| self.map_redis_error(e, "create_idempotent_claim_key"))?;
+
+        if claimed.is_none() {
+            let relayer_id: Option<String> = conn
+                .get(&key)
+                .await
+                .map_err(|e| self.map_redis_error(e, "create_idempotent_get_key"))?;
+            debug!(idempotency_key = %idempotency_key, "idempotency key already processed");
+            return match relayer_id {
+                Some(relayer_id) => self.get_by_id(relayer_id).await,
+                None => Err(RepositoryError::ConstraintViolation(format!(
+                    "Idempotency key {idempotency_key} expired while being processed"
+                ))),
+            };
+        }
+
+        // Index the key under the relayer, so deleting the relayer releases it
+        let index_key = self.idempotency_index_key(&relayer.id);
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        pipe.sadd(&index_key, &key).ignore();
+        pipe.expire(&index_key, RELAYER_IDEMPOTENCY_KEY_TTL_SECONDS as i64)
+            .ignore();
+        pipe.exec_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "create_idempotent_index_key"))?;
+
+        match self.create(relayer).await {
+            Ok(created) => Ok(created),
+            Err(e) => {
+                // Release the key so the request can be retried
+                let mut pipe = redis::pipe();
+                pipe.atomic();
+                pipe.del(&key).ignore();
+                pipe.srem(&index_key, &key).ignore();
+                pipe.exec_async(&mut conn)
+                    .await
+                    .map_err(|e| self.map_redis_error(e, "create_idempotent_release_key"))?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn quarantine(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Disable the relayer until the quarantine elapses
+            let from = relayer.state();
+            relayer.system_disabled = true;
+            relayer.disabled_reason = Some(DisabledReason::Quarantined(reason.clone()));
+            relayer.quarantined_until = Some(until);
+            relayer.record_status_change(from, self.status_history_limit);
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn list_expired_quarantines(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let all_relayers = self.list_all().await?;
+        let expired: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.system_disabled && relayer.is_quarantine_expired(now))
+            .collect();
+
+        debug!(count = %expired.len(), "found relayers with expired quarantine");
+        Ok(expired)
+    }
+
+    async fn instantiate(
+        &self,
+        template: &RelayerTemplate,
+        id: String,
+        signer_id: String,
+        address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.create(template.to_repo_model(id, signer_id, address))
+            .await
+    }
+
+    async fn evaluate_balance_alert(
+        &self,
+        id: String,
+        balance: u128,
+    ) -> Result<Option<AlertTransition>, RepositoryError> {
+        // Only persist when the alert state actually transitions
+        let mut relayer = self.get_by_id(id.clone()).await?;
+        if relayer.evaluate_balance_alert(balance).is_none() {
+            return Ok(None);
+        }
+
+        // Evaluated again against the relayer each write attempt is based on
+        let mut transition = None;
+        self.update_with(&id, |mut relayer| {
+            transition = relayer.evaluate_balance_alert(balance);
+            Ok(relayer)
+        })
+        .await?;
+
+        Ok(transition)
+    }
+
+    async fn get_by_id_scoped(
+        &self,
+        id: String,
+        tenant_id: &str,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let relayer = self.get_by_id(id.clone()).await?;
+
+        // Hide relayers of other tenants as if they did not exist
+        if relayer.tenant_id != tenant_id {
+            debug!(relayer_id = %id, tenant_id = %tenant_id, "relayer belongs to another tenant");
+            return Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            )));
+        }
+
+        Ok(relayer)
+    }
+
+    async fn list_by_tenant(
+        &self,
+        tenant_id: &str,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+
+        if query.resolved_per_page() == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+
+        let all_relayers = self.list_all().await?;
+        let tenant_relayers: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.tenant_id == tenant_id)
+            .collect();
+
+        let total = tenant_relayers.len() as u64;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let items: Vec<RelayerRepoModel> = tenant_relayers
+            .into_iter()
+            .skip(start)
+            .take(query.resolved_per_page() as usize)
+            .collect();
+
+        debug!(count = %items.len(), total = %total, tenant_id = %tenant_id, "listed relayers for tenant");
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page: query.resolved_per_page(),
+        })
+    }
+
+    async fn snapshot(&self) -> Result<RelayerSnapshot, RepositoryError> {
+        // list_all reads the ID set once and batch fetches the relayers
+        let all_relayers = self.list_all().await?;
+        debug!(count = %all_relayers.len(), "took relayer snapshot");
+        Ok(RelayerSnapshot::from(all_relayers))
+    }
+
+    async fn schedule_disable(
+        &self,
+        id: String,
+        at: DateTime<Utc>,
+        reason: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Record the schedule; the relayer stays operational until the sweeper runs
+            relayer.scheduled_disable_at = Some(at);
+            relayer.scheduled_disable_reason = Some(reason.clone());
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn list_due_scheduled_disables(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let all_relayers = self.list_all().await?;
+        let due: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.is_scheduled_disable_due(now))
+            .collect();
+
+        debug!(count = %due.len(), "found relayers with due scheduled disable");
+        Ok(due)
+    }
+
+    async fn cancel_scheduled_disable(
+        &self,
+        id: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Clear the schedule
+            relayer.scheduled_disable_at = None;
+            relayer.scheduled_disable_reason = None;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn find_drifted(
+        &self,
+        desired: &[RelayerRepoModel],
+    ) -> Result<Vec<String>, RepositoryError> {
+        let stored: HashMap<String, u64> = self
+            .list_all()
+            .await?
+            .into_iter()
+            .map(|relayer| (relayer.id.clone(), relayer.config_checksum()))
+            .collect();
+        let drifted: Vec<String> = desired
+            .iter()
+            .filter(|wanted| stored.get(&wanted.id) != Some(&wanted.config_checksum()))
+            .map(|wanted| wanted.id.clone())
+            .collect();
+
+        debug!(count = %drifted.len(), "found relayers with drifted config");
+        Ok(drifted)
+    }
+
+    async fn get_status_history(&self, id: String) -> Result<Vec<StatusChange>, RepositoryError> {
+        let relayer = self.get_by_id(id).await?;
+        Ok(relayer.status_history.into_iter().collect())
+    }
+
+    async fn validate_all(
+        &self,
+    ) -> Result<Vec<(String, Vec<RelayerValidationError>)>, RepositoryError> {
+        let all_relayers = self.list_all().await?;
+        let offenders: Vec<(String, Vec<RelayerValidationError>)> = all_relayers
+            .into_iter()
+            .filter_map(|relayer| {
+                let errors = relayer.validation_errors();
+                (!errors.is_empty()).then_some((relayer.id, errors))
+            })
+            .collect();
+
+        debug!(count = %offenders.len(), "found relayers failing validation");
+        Ok(offenders)
+    }
+
+    async fn pick_sticky(
+        &self,
+        pool: String,
+        session_key: &str,
+        strategy: StickyStrategy,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let members: Vec<RelayerRepoModel> = self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| relayer.pool.as_deref() == Some(pool.as_str()))
+            .collect();
+
+        debug!(pool = %pool, count = %members.len(), "selecting sticky relayer from pool");
+        select_sticky(members, session_key, strategy).ok_or_else(|| {
+            RepositoryError::NotFound(format!("No operational relayer found in pool {pool}"))
+        })
+    }
+
+    async fn rekey(
+        &self,
+        id: String,
+        new_signer_id: String,
+        new_address: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        // First get the current relayer
+        let mut relayer = self.get_by_id(id.clone()).await?;
+
+        // Validate before touching either field so a rejected rekey leaves the relayer untouched
+        relayer.validate_rekey(&new_signer_id, &new_address)?;
+        relayer.signer_id = new_signer_id.clone();
+        self.check_signer_chain(&relayer).await?;
+
+        // Both fields are persisted in a single write
+        self.update_with(&id, |mut relayer| {
+            relayer.validate_rekey(&new_signer_id, &new_address)?;
+            relayer.signer_id = new_signer_id.clone();
+            relayer.address = new_address.clone();
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn existing_ids(&self, ids: Vec<String>) -> Result<HashSet<String>, RepositoryError> {
+        if ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let mut conn = self.client.as_ref().clone();
+        let relayer_list_key = self.relayer_list_key();
+
+        // Check membership of every id in a single round trip
+        let mut pipe = redis::pipe();
+        for id in &ids {
+            pipe.sismember(&relayer_list_key, id);
+        }
+        let present: Vec<bool> = pipe
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "existing_ids"))?;
+
+        let existing: HashSet<String> = ids
+            .into_iter()
+            .zip(present)
+            .filter_map(|(id, present)| present.then_some(id))
+            .collect();
+
+        debug!(count = %existing.len(), "found existing relayer ids");
+        Ok(existing)
+    }
+
+    async fn compact(&self) -> Result<(), RepositoryError> {
+        // Redis manages its own memory; there is nothing to compact on the client side
+        debug!("compact is a no-op for redis relayer storage");
+        Ok(())
+    }
+
+    async fn trip_circuit(
+        &self,
+        id: String,
+        until: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Open the circuit until the given time
+            relayer.circuit = CircuitState::Open { until };
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn reset_circuit(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Close the circuit
+            relayer.circuit = CircuitState::Closed;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn record_outcome(
+        &self,
+        id: String,
+        success: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        let now = Utc::now();
+        self.update_with(&id, |mut relayer| {
+            relayer.record_circuit_outcome(success, now);
+            relayer.record_recent_outcome(success, now, self.outcome_history_limit);
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn list_where_policy(
+        &self,
+        predicate: PolicyPredicate,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+
+        if query.resolved_per_page() == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+
+        let all_relayers = self.list_all().await?;
+        let matching: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| predicate(&relayer.policies))
+            .collect();
+
+        let total = matching.len() as u64;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let items: Vec<RelayerRepoModel> = matching
+            .into_iter()
+            .skip(start)
+            .take(query.resolved_per_page() as usize)
+            .collect();
+
+        debug!(count = %items.len(), total = %total, "listed relayers matching policy predicate");
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page: query.resolved_per_page(),
+        })
+    }
+
+    async fn provision_from_descriptors(
+        &self,
+        descriptors: Vec<RelayerDescriptor>,
+        mode: ProvisionMode,
+    ) -> Result<ProvisionReport, RepositoryError> {
+        let ids = descriptors.iter().map(|d| d.id.clone()).collect();
+        let mut existing = self.existing_ids(ids).await?;
+
+        // Ids already taken by another relayer's alias count as existing
+        let mut conn = self.client.as_ref().clone();
+        for descriptor in &descriptors {
+            let is_alias: bool = conn
+                .hexists(self.alias_index_key(), &descriptor.id)
+                .await
+                .map_err(|e| self.map_redis_error(e, "provision_relayers_alias_check"))?;
+            if is_alias {
+                existing.insert(descriptor.id.clone());
+            }
+        }
+
+        let (mut relayers, errors) = prepare_provision(&descriptors, &existing, |policy| {
+            self.check_policy_guardrails(policy)
+        });
+
+        if mode == ProvisionMode::Strict && !errors.is_empty() {
+            return Err(provision_rejected(&errors, descriptors.len()));
+        }
+
+        let created: Vec<String> = relayers.iter().map(|relayer| relayer.id.clone()).collect();
+        if mode == ProvisionMode::Strict && !relayers.is_empty() {
+            // Write the whole batch in a single atomic pipeline
+            let mut pipe = redis::pipe();
+            pipe.atomic();
+            for relayer in &mut relayers {
+                relayer.record_config_version(self.config_version_limit);
+                let serialized = self.codec.encode(relayer)?;
+                pipe.set(self.relayer_key(&relayer.id), serialized);
+                pipe.sadd(self.relayer_list_key(), &relayer.id);
+            }
+
+            pipe.exec_async(&mut conn)
+                .await
+                .map_err(|e| self.map_redis_error(e, "provision_relayers_pipeline"))?;
+            self.record_changes(&mut conn, ChangeKind::Created, &created)
+                .await?;
+        }
+
+        debug!(count = %created.len(), invalid = %errors.len(), "provisioned relayers from descriptors");
+        Ok(ProvisionReport { created, errors })
+    }
+
+    async fn record_error(
+        &self,
+        id: String,
+        error: RelayerLastError,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Replace the last error
+            relayer.last_error = Some(error.clone());
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn clear_error(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Clear the last error
+            relayer.last_error = None;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn list_with_recent_errors(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let all_relayers = self.list_all().await?;
+        let recent: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.has_error_since(since))
+            .collect();
+
+        debug!(count = %recent.len(), "found relayers with recent errors");
+        Ok(recent)
+    }
+
+    async fn list_config_versions(
+        &self,
+        id: String,
+    ) -> Result<Vec<RelayerConfigVersionInfo>, RepositoryError> {
+        let relayer = self.get_by_id(id).await?;
+        Ok(relayer.config_version_infos())
+    }
+
+    async fn rollback_to(
+        &self,
+        id: String,
+        version: u32,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Restore the config, keeping runtime state
+            relayer.restore_config_version(version, self.config_version_limit)?;
+            self.check_policy_guardrails(&relayer.policies)?;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn get_by_alias(&self, alias: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let id: Option<String> = conn
+            .hget(self.alias_index_key(), &alias)
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_relayer_by_alias"))?;
+
+        match id {
+            Some(id) => self.get_by_id(id).await,
+            None => {
+                debug!(alias = %alias, "relayer alias not found");
+                Err(RepositoryError::NotFound(format!(
+                    "Relayer with alias {alias} not found"
+                )))
+            }
+        }
+    }
+
+    async fn changes_since(&self, seq: u64) -> Result<Vec<ChangeRecord>, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let latest_seq: Option<u64> = conn
+            .get(self.change_seq_key())
+            .await
+            .map_err(|e| self.map_redis_error(e, "changes_since_get_seq"))?;
+        let entries: Vec<String> = conn
+            .lrange(self.change_log_key(), 0, -1)
+            .await
+            .map_err(|e| self.map_redis_error(e, "changes_since_get_log"))?;
+
+        let change_log_key = self.change_log_key();
+        let records = entries
+            .iter()
+            .map(|json| self.deserialize_entity(json, &change_log_key, "relayer change record"))
+            .collect::<Result<Vec<ChangeRecord>, _>>()?;
+
+        let changes = changes_after(records, latest_seq.unwrap_or_default(), seq)?;
+        debug!(seq = %seq, count = %changes.len(), "found relayer changes since sequence");
+        Ok(changes)
+    }
+
+    async fn effective_policy(&self, id: String) -> Result<RelayerNetworkPolicy, RepositoryError> {
+        let relayer = self.get_by_id_effective(id).await?;
+        Ok(relayer.policies.effective(self.policy_guardrails.as_ref()))
+    }
+
+    async fn get_standby(
+        &self,
+        primary_id: String,
+    ) -> Result<Option<RelayerRepoModel>, RepositoryError> {
+        // Fail if the primary does not exist
+        self.get_by_id(primary_id.clone()).await?;
+
+        let all_relayers = self.list_all().await?;
+        let standby = all_relayers
+            .into_iter()
+            .find(|relayer| relayer.standby_for.as_ref() == Some(&primary_id));
+
+        debug!(primary_id = %primary_id, found = %standby.is_some(), "resolved standby relayer");
+        Ok(standby)
+    }
+
+    async fn list_due_health_checks(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let all_relayers = self.list_all().await?;
+        let due: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.is_health_check_due(now))
+            .collect();
+
+        debug!(count = %due.len(), "found relayers due for a health check");
+        Ok(due)
+    }
+
+    async fn record_health_check(
+        &self,
+        id: String,
+        result: Result<(), Vec<HealthCheckFailure>>,
+        at: DateTime<Utc>,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Record the health check outcome
+            relayer.health = RelayerHealth::from(result.clone());
+            relayer.last_health_check_at = Some(at);
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn sunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Stop accepting new transactions
+            relayer.sunset = true;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn unsunset(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            // Accept new transactions again
+            relayer.sunset = false;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn get_by_id_effective(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let mut relayer = self.get_by_id(id).await?;
+        let ancestors = self.load_inheritance_chain(&relayer).await?;
+        relayer.policies = relayer.inherited_policies(&ancestors);
+        Ok(relayer)
+    }
+
+    async fn delete_many(&self, ids: Vec<String>) -> Result<DeleteReport, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        // Deduplicate so a repeated id is deleted once and reported as not found after
+        let mut seen = HashSet::new();
+        let unique_ids: Vec<String> = ids
+            .iter()
+            .filter(|id| seen.insert(id.as_str()))
+            .cloned()
+            .collect();
+
+        // Fetch the stored relayers, whose aliases must be released
+        let batch = self.get_relayers_by_ids(&unique_ids).await?;
+        if !batch.failed_ids.is_empty() {
+            return Err(RepositoryError::InvalidData(format!(
+                "Failed to deserialize relayers: {}",
+                batch.failed_ids.join(", ")
+            )));
+        }
+        let existing: HashMap<String, RelayerRepoModel> = batch
+            .results
+            .into_iter()
+            .map(|relayer| (relayer.id.clone(), relayer))
+            .collect();
+
+        let mut report = DeleteReport::default();
+        let mut pending: HashSet<&str> = existing.keys().map(String::as_str).collect();
+        for id in &ids {
+            if pending.remove(id.as_str()) {
+                report.deleted.push(id.clone());
+            } else {
+                report.not_found.push(id.clone());
+            }
+        }
+        if report.deleted.is_empty() {
+            debug!(not_found = %report.not_found.len(), "no relayers to delete");
+            return Ok(report);
+        }
+        ensure_not_inherited(
+            &self.list_all().await?,
+            &existing.keys().map(String::as_str).collect(),
+        )?;
+        let idempotency_keys = self.idempotency_keys_of(&mut conn, &report.deleted).await?;
+
+        // Use pipeline for atomic operations
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for key in idempotency_keys {
+            pipe.del(key);
+        }
+        for relayer in existing.values() {
+            pipe.del(self.relayer_key(&relayer.id));
+            for key in self.counter_keys(&relayer.id) {
+                pipe.del(key);
+            }
+            pipe.srem(self.relayer_list_key(), &relayer.id);
+            for alias in &relayer.aliases {
+                pipe.hdel(self.alias_index_key(), alias);
+            }
+        }
+
+        pipe.exec_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "delete_many_pipeline"))?;
+        self.record_changes(&mut conn, ChangeKind::Deleted, &report.deleted)
+            .await?;
+
+        debug!(
+            deleted = %report.deleted.len(),
+            not_found = %report.not_found.len(),
+            "deleted relayers"
+        );
+        Ok(report)
+    }
+
+    async fn list_by_network_type(
+        &self,
+        network_type: NetworkType,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        let per_page = query.per_page_or(self.default_per_page(network_type));
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+
+        if per_page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+
+        let all_relayers = self.list_all().await?;
+        let matching: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.network_type == network_type)
+            .collect();
+
+        let total = matching.len() as u64;
+        let start = ((query.page - 1) * per_page) as usize;
+        let items: Vec<RelayerRepoModel> = matching
+            .into_iter()
+            .skip(start)
+            .take(per_page as usize)
+            .collect();
+
+        debug!(
+            network_type = %network_type,
+            count = %items.len(),
+            total = %total,
+            "listed relayers by network type"
+        );
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page,
+        })
+    }
+
+    async fn pause_if_version(
+        &self,
+        id: String,
+        expected_version: u64,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        // The version is checked against the record the write is conditioned on, so a
+        // concurrent change in between fails the check on the next attempt
+        self.update_with(&id, |mut relayer| {
+            // Reject a stale version before touching the relayer
+            relayer.check_version(expected_version)?;
+            let from = relayer.state();
+            relayer.paused = true;
+            relayer.record_status_change(from, self.status_history_limit);
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn group_by_network_type(
+        &self,
+    ) -> Result<HashMap<NetworkType, Vec<RelayerRepoModel>>, RepositoryError> {
+        let groups = self.snapshot().await?.group_operational_by_network_type();
+
+        debug!(groups = %groups.len(), "grouped operational relayers by network type");
+        Ok(groups)
+    }
+
+    async fn validate_create(
+        &self,
+        mut relayer: RelayerRepoModel,
+    ) -> Result<(), Vec<RepositoryError>> {
+        relayer.normalize_id();
+        let mut errors = Vec::new();
+        errors.extend(relayer.validate_id_format(&self.id_pattern).err());
+        if relayer.name.is_empty() {
+            errors.push(RepositoryError::InvalidData(
+                "Relayer name cannot be empty".to_string(),
+            ));
+        }
+        errors.extend(relayer.create_validation_errors());
+        errors.extend(self.check_policy_guardrails(&relayer.policies).err());
+        errors.extend(
+            relayer
+                .validate_metadata_size(self.metadata_size_limit)
+                .err(),
+        );
+        errors.extend(self.check_signer_chain(&relayer).await.err());
+
+        let mut conn = self.client.as_ref().clone();
+
+        // Check if relayer already exists
+        let exists: Result<bool, _> = conn.exists(self.relayer_key(&relayer.id)).await;
+        match exists {
+            Ok(true) => errors.push(RepositoryError::ConstraintViolation(format!(
+                "Relayer with ID {} already exists",
+                relayer.id
+            ))),
+            Ok(false) => {}
+            Err(e) => errors.push(self.map_redis_error(e, "create_relayer_exists_check")),
+        }
+        let id_is_alias: Result<bool, _> = conn.hexists(self.alias_index_key(), &relayer.id).await;
+        match id_is_alias {
+            Ok(true) => errors.push(RepositoryError::ConstraintViolation(format!(
+                "Relayer ID {} is already in use as an alias",
+                relayer.id
+            ))),
+            Ok(false) => {}
+            Err(e) => errors.push(self.map_redis_error(e, "create_relayer_alias_check")),
+        }
+        errors.extend(self.check_alias_collisions(&mut conn, &relayer).await.err());
+        errors.extend(self.check_standby_pairing(&relayer).await.err());
+        errors.extend(self.check_nonce_group(&relayer).await.err());
+        errors.extend(self.load_inheritance_chain(&relayer).await.err());
+
+        debug!(relayer_id = %relayer.id, errors = %errors.len(), "validated relayer create");
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    async fn list_by_status(
+        &self,
+        status: RelayerOperationalStatus,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+
+        if query.resolved_per_page() == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let all_relayers = self.list_all().await?;
+        let matching: Vec<RelayerRepoModel> = all_relayers
+            .into_iter()
+            .filter(|relayer| relayer.status_at(now) == status)
+            .collect();
+
+        let total = matching.len() as u64;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let items: Vec<RelayerRepoModel> = matching
+            .into_iter()
+            .skip(start)
+            .take(query.resolved_per_page() as usize)
+            .collect();
+
+        debug!(status = ?status, count = %items.len(), total = %total, "listed relayers by status");
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page: query.resolved_per_page(),
+        })
+    }
+
+    async fn add_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.retag_where(&filter, |relayer| relayer.add_tag(&tag))
+            .await
+    }
+
+    async fn remove_tag_where(
+        &self,
+        filter: RelayerFilter,
+        tag: String,
+    ) -> Result<u64, RepositoryError> {
+        self.retag_where(&filter, |relayer| relayer.remove_tag(&tag))
+            .await
+    }
+
+    async fn set_metadata(
+        &self,
+        id: String,
+        metadata: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            relayer.metadata = metadata.clone();
+            relayer.validate_metadata_size(self.metadata_size_limit)?;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn merge_metadata(
+        &self,
+        id: String,
+        patch: serde_json::Value,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            relayer.merge_metadata(patch.clone())?;
+            relayer.validate_metadata_size(self.metadata_size_limit)?;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn try_acquire_slot(&self, id: String) -> Result<SlotGuard, RepositoryError> {
+        let relayer = self.get_by_id(id).await?;
+        // Uncapped relayers never run out of slots, so there is nothing to share
+        let Some(cap) = relayer.policies.max_inflight_transactions() else {
+            return self.slots.try_acquire(&relayer).await;
+        };
+
+        let mut conn = self.client.as_ref().clone();
+        let slots_key = self.slots_key(&relayer.id);
+        let acquired: bool = ACQUIRE_SLOT_SCRIPT
+            .key(&slots_key)
+            .arg(cap)
+            .arg(RELAYER_SLOT_TTL_SECONDS * 1000)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "try_acquire_slot"))?;
+        if !acquired {
+            return Err(RepositoryError::Conflict(format!(
+                "Relayer {} has reached its limit of {cap} in-flight transactions",
+                relayer.id
+            )));
+        }
+
+        Ok(SlotGuard::with_release(move || {
+            // Without a runtime the slot is freed when the counter expires
+            let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+                return;
+            };
+            runtime.spawn(async move {
+                let result: redis::RedisResult<u64> = DECREMENT_TO_ZERO_SCRIPT
+                    .key(&slots_key)
+                    .invoke_async(&mut conn)
+                    .await;
+                if let Err(e) = result {
+                    warn!(key = %slots_key, error = %e, "failed to release relayer slot");
+                }
+            });
+        }))
+    }
+
+    async fn record_rpc_health(
+        &self,
+        id: String,
+        url: String,
+        healthy: bool,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.update_with(&id, |mut relayer| {
+            relayer.record_rpc_health(&url, healthy)?;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn enter_maintenance(&self, until: DateTime<Utc>) -> Result<(), RepositoryError> {
+        validate_maintenance_until(until, Utc::now())?;
+        let mut conn = self.client.as_ref().clone();
+        // The key expires when the window ends, so maintenance resumes on its own
+        let options =
+            SetOptions::default().with_expiration(SetExpiry::PXAT(until.timestamp_millis() as u64));
+        let _: () = conn
+            .set_options(self.maintenance_key(), until.to_rfc3339(), options)
+            .await
+            .map_err(|e| self.map_redis_error(e, "enter_maintenance"))?;
+        Ok(())
+    }
+
+    async fn exit_maintenance(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let _: () = conn
+            .del(self.maintenance_key())
+            .await
+            .map_err(|e| self.map_redis_error(e, "exit_maintenance"))?;
+        Ok(())
+    }
+
+    async fn maintenance_until(&self) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let value: Option<String> = conn
+            .get(self.maintenance_key())
+            .await
+            .map_err(|e| self.map_redis_error(e, "maintenance_until"))?;
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        let until = DateTime::parse_from_rfc3339(&value)
+            .map_err(|e| {
+                RepositoryError::InvalidData(format!("Invalid maintenance window end {value}: {e}"))
+            })?
+            .with_timezone(&Utc);
+        let now = Utc::now();
+        Ok(Some(until).filter(|until| *until > now))
+    }
+
+    async fn list_active_by_network_type(
+        &self,
+        network_type: NetworkType,
+    ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
+        let now = Utc::now();
+        let mut active: Vec<RelayerRepoModel> = self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|relayer| {
+                relayer.network_type == network_type && relayer.is_operational_at(now)
+            })
             .collect();
-
-        debug!(count = %active_relayers.len(), "found active relayers");
-        Ok(active_relayers)
+        active.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(active)
     }
 
-    async fn list_by_signer_id(
+    async fn list_by_nonce_group(
         &self,
-        signer_id: &str,
+        group: &str,
     ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
-        let all_relayers = self.list_all().await?;
-        let relayers_with_signer: Vec<RelayerRepoModel> = all_relayers
+        let mut members: Vec<RelayerRepoModel> = self
+            .list_all()
+            .await?
             .into_iter()
-            .filter(|relayer| relayer.signer_id == signer_id)
+            .filter(|relayer| relayer.nonce_group.as_deref() == Some(group))
             .collect();
+        members.sort_by(|a, b| a.id.cmp(&b.id));
 
-        debug!(count = %relayers_with_signer.len(), signer_id = %signer_id, "found relayers using signer");
-        Ok(relayers_with_signer)
+        debug!(count = %members.len(), group = %group, "found relayers in nonce group");
+        Ok(members)
     }
 
-    async fn list_by_notification_id(
+    async fn list_high_error_rate(
         &self,
-        notification_id: &str,
+        window: Duration,
+        threshold: f64,
     ) -> Result<Vec<RelayerRepoModel>, RepositoryError> {
-        let all_relayers = self.list_all().await?;
-        let relayers_with_notification: Vec<RelayerRepoModel> = all_relayers
+        validate_error_rate_threshold(threshold)?;
+        let since = error_rate_window_start(window, Utc::now())?;
+        let mut relayers: Vec<RelayerRepoModel> = self
+            .list_all()
+            .await?
             .into_iter()
             .filter(|relayer| {
                 relayer
-                    .notification_id
-                    .as_ref()
-                    .is_some_and(|id| id == notification_id)
+                    .error_rate_since(since)
+                    .is_some_and(|rate| rate > threshold)
             })
             .collect();
+        relayers.sort_by(|a, b| a.id.cmp(&b.id));
 
-        debug!(count = %relayers_with_notification.len(), notification_id = %notification_id, "found relayers using notification");
-        Ok(relayers_with_notification)
+        debug!(count = %relayers.len(), threshold = %threshold, "found relayers with high error rate");
+        Ok(relayers)
     }
 
-    async fn partial_update(
+    async fn enforce_allowlist(
+        &self,
+        allowed: HashSet<String>,
+    ) -> Result<EnforceReport, RepositoryError> {
+        let mut report = EnforceReport::default();
+        for relayer in self.list_all().await? {
+            let id = relayer.id.clone();
+            let is_allowed = allowed.contains(&id);
+            if !relayer.clone().enforce_allowlist(is_allowed) {
+                continue;
+            }
+            // Update the relayer; this records the config version and increments the version
+            let updated = self
+                .update_with(&id, |mut relayer| {
+                    let from = relayer.state();
+                    if relayer.enforce_allowlist(is_allowed) {
+                        relayer.record_status_change(from, self.status_history_limit);
+                    }
+                    Ok(relayer)
+                })
+                .await?;
+            if updated.paused {
+                report.paused.push(id);
+            } else {
+                report.resumed.push(id);
+            }
+        }
+        report.paused.sort();
+        report.resumed.sort();
+
+        debug!(
+            paused = %report.paused.len(),
+            resumed = %report.resumed.len(),
+            "enforced relayer allowlist"
+        );
+        Ok(report)
+    }
+
+    async fn set_log_level(
         &self,
         id: String,
-        update: UpdateRelayerRequest,
+        level: Option<LogLevel>,
     ) -> Result<RelayerRepoModel, RepositoryError> {
-        // First get the current relayer
-        let mut relayer = self.get_by_id(id.clone()).await?;
+        self.update_with(&id, |mut relayer| {
+            relayer.log_level = level;
+            Ok(relayer)
+        })
+        .await
+    }
 
-        // Apply the partial update
-        if let Some(paused) = update.paused {
-            relayer.paused = paused;
-        }
+    async fn begin_consistent_read(&self) -> Result<ReadToken, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
 
-        // Update the relayer
-        self.update(id, relayer).await
+        // Read the sequence first, so the snapshot is at least as new as the token claims
+        let seq: Option<u64> = conn
+            .get(self.change_seq_key())
+            .await
+            .map_err(|e| self.map_redis_error(e, "begin_consistent_read_get_seq"))?;
+        let snapshot = self.snapshot().await?;
+
+        debug!(seq = ?seq, count = %snapshot.len(), "pinned relayer snapshot for consistent read");
+        Ok(ReadToken::new(seq.unwrap_or_default(), snapshot))
     }
 
-    async fn enable_relayer(
+    async fn get_many_by_address(
         &self,
-        relayer_id: String,
-    ) -> Result<RelayerRepoModel, RepositoryError> {
-        // First get the current relayer
-        let mut relayer = self.get_by_id(relayer_id.clone()).await?;
+        addresses: Vec<String>,
+        network_type: NetworkType,
+    ) -> Result<HashMap<String, RelayerRepoModel>, RepositoryError> {
+        let wanted: HashSet<String> = addresses
+            .iter()
+            .map(|address| network_type.normalize_address(address))
+            .collect();
+        let relayers = self.list_all().await?;
+        let matched = match_addresses(
+            relayers.iter().filter(|r| r.network_type == network_type),
+            &wanted,
+        );
+
+        debug!(requested = %wanted.len(), matched = %matched.len(), "looked up relayers by address");
+        Ok(matched)
+    }
 
-        // Update the system_disabled flag and clear reason
-        relayer.system_disabled = false;
-        relayer.disabled_reason = None;
+    async fn acquire_config_read(&self, id: String) -> Result<ConfigReadGuard, RepositoryError> {
+        self.config_locks
+            .read(&id, || self.get_by_id(id.clone()))
+            .await
+    }
 
-        // Update the relayer
-        self.update(relayer_id, relayer).await
+    async fn increment_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        self.check_exists(&mut conn, &id).await?;
+        conn.incr(self.pending_key(&id), 1)
+            .await
+            .map_err(|e| self.map_redis_error(e, "increment_pending"))
     }
 
-    async fn disable_relayer(
-        &self,
-        relayer_id: String,
-        reason: DisabledReason,
-    ) -> Result<RelayerRepoModel, RepositoryError> {
-        // First get the current relayer
-        let mut relayer = self.get_by_id(relayer_id.clone()).await?;
+    async fn decrement_pending(&self, id: String) -> Result<u64, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        self.check_exists(&mut conn, &id).await?;
+        DECREMENT_TO_ZERO_SCRIPT
+            .key(self.pending_key(&id))
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "decrement_pending"))
+    }
 
-        // Update the system_disabled flag and set reason
-        relayer.system_disabled = true;
-        relayer.disabled_reason = Some(reason);
+    async fn pending_count(&self, id: String) -> Result<u64, RepositoryError> {
+        Ok(self.get_by_id(id).await?.pending_count)
+    }
 
-        // Update the relayer
-        self.update(relayer_id, relayer).await
+    async fn record_spend(&self, id: String, amount: u128) -> Result<u128, RepositoryError> {
+        // The relayer supplies the cap; the spend itself is read and written on its own key
+        let relayer = self.get_by_id(id.clone()).await?;
+        let mut conn = self.client.as_ref().clone();
+        let spend_key = self.spend_key(&id);
+
+        for _ in 0..RELAYER_WRITE_MAX_ATTEMPTS {
+            let stored: Option<String> = conn
+                .get(&spend_key)
+                .await
+                .map_err(|e| self.map_redis_error(e, "record_spend_get"))?;
+            let mut relayer = relayer.clone();
+            self.apply_spend(&mut relayer, stored.as_deref())?;
+            let spent = relayer.record_spend(amount, Utc::now())?;
+            let window_start = relayer
+                .spend_window_start
+                .map_or(0, |start| start.timestamp_millis());
+
+            let written: bool = SET_IF_UNCHANGED_SCRIPT
+                .key(&spend_key)
+                .arg(stored.unwrap_or_default())
+                .arg(format!("{window_start}:{spent}"))
+                .invoke_async(&mut conn)
+                .await
+                .map_err(|e| self.map_redis_error(e, "record_spend_set"))?;
+            if written {
+                return Ok(spent);
+            }
+            debug!(relayer_id = %id, "relayer spend changed while being recorded, retrying");
+        }
+
+        Err(RepositoryError::Conflict(format!(
+            "Spend of relayer {id} kept changing while being recorded"
+        )))
     }
 
-    async fn update_policy(
+    async fn promote_to_live(&self, id: String) -> Result<RelayerRepoModel, RepositoryError> {
+        let relayer = self.get_by_id(id.clone()).await?;
+        if relayer.mode == RelayerMode::Live {
+            return Ok(relayer);
+        }
+        self.update_with(&id, |mut relayer| {
+            relayer.mode = RelayerMode::Live;
+            Ok(relayer)
+        })
+        .await
+    }
+
+    async fn register_submission(
         &self,
         id: String,
-        policy: RelayerNetworkPolicy,
-    ) -> Result<RelayerRepoModel, RepositoryError> {
-        // First get the current relayer
-        let mut relayer = self.get_by_id(id.clone()).await?;
+        tx_hash_key: String,
+        ttl: Duration,
+    ) -> Result<bool, RepositoryError> {
+        validate_submission(&tx_hash_key, ttl)?;
+        let mut conn = self.client.as_ref().clone();
+        let exists: bool = conn
+            .exists(self.relayer_key(&id))
+            .await
+            .map_err(|e| self.map_redis_error(e, "register_submission_check_relayer"))?;
+        if !exists {
+            return Err(RepositoryError::NotFound(format!(
+                "Relayer with ID {id} not found"
+            )));
+        }
 
-        // Update the policy
-        relayer.policies = policy;
+        // Sub-millisecond TTLs round up so the key is never stored without expiry
+        let ttl_ms = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX).max(1);
+        let options = SetOptions::default()
+            .conditional_set(ExistenceCheck::NX)
+            .with_expiration(SetExpiry::PX(ttl_ms));
+        let registered: Option<String> = conn
+            .set_options(self.submission_key(&id, &tx_hash_key), 1, options)
+            .await
+            .map_err(|e| self.map_redis_error(e, "register_submission"))?;
+        if registered.is_none() {
+            debug!(relayer_id = %id, tx_hash_key = %tx_hash_key, "duplicate submission");
+        }
+        Ok(registered.is_some())
+    }
 
-        // Update the relayer
-        self.update(id, relayer).await
+    async fn transfer_ownership(
+        &self,
+        id: String,
+        new_tenant: String,
+        actor: String,
+    ) -> Result<RelayerRepoModel, RepositoryError> {
+        self.check_tenant(&new_tenant).await?;
+        let _config = self.config_locks.write(&id).await?;
+        self.update_with(&id, |mut relayer| {
+            relayer.transfer_ownership(
+                new_tenant.clone(),
+                actor.clone(),
+                self.status_history_limit,
+            )?;
+            Ok(relayer)
+        })
+        .await
     }
 
     fn is_persistent_storage(&self) -> bool {
@@ -537,14 +2653,18 @@ impl RelayerRepository for RedisRelayerRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy};
+    use crate::models::{
+        AlertState, CircuitState, GasOracleConfig, NetworkType, RelayerErrorKind, RelayerEvmPolicy,
+        RelayerHealth, RelayerMode, RelayerNetworkPolicy, RevertHandling, StuckTxAction,
+    };
     use redis::aio::ConnectionManager;
+    use std::collections::VecDeque;
     use std::sync::Arc;
 
     fn create_test_relayer(id: &str) -> RelayerRepoModel {
         RelayerRepoModel {
             id: id.to_string(),
-            name: format!("Test Relayer {}", id),
+            name: format!("Test Relayer {id}"),
             network: "ethereum".to_string(),
             paused: false,
             network_type: NetworkType::Evm,
@@ -555,6 +2675,45 @@ mod tests {
             system_disabled: false,
             disabled_reason: None,
             custom_rpc_urls: None,
+            updated_at: None,
+            quarantined_until: None,
+            extra_webhook_urls: None,
+            alert_state: AlertState::Normal,
+            tenant_id: "test-tenant".to_string(),
+            scheduled_disable_at: None,
+            scheduled_disable_reason: None,
+            hard_gas_price_ceiling: None,
+            status_history: VecDeque::new(),
+            pool: None,
+            derivation_path: None,
+            circuit: CircuitState::Closed,
+            last_error: None,
+            config_versions: VecDeque::new(),
+            aliases: Vec::new(),
+            standby_for: None,
+            health_check_interval: None,
+            last_health_check_at: None,
+            health: RelayerHealth::Unknown,
+            sunset: false,
+            inherits_from: None,
+            version: 0,
+            fee_account: None,
+            chain_id: None,
+            tags: None,
+            metadata: serde_json::Value::Null,
+            rpc_health: HashMap::new(),
+            signature_scheme: None,
+            nonce_group: None,
+            recent_outcomes: VecDeque::new(),
+            paused_by_enforcement: false,
+            log_level: None,
+            allowed_origins: None,
+            pending_count: 0,
+            signer_timeout_ms: None,
+            spent_today: 0,
+            spend_window_start: None,
+            mode: RelayerMode::Live,
+            ownership_history: VecDeque::new(),
         }
     }
 
@@ -607,6 +2766,9 @@ mod tests {
 
         let list_key = repo.relayer_list_key();
         assert_eq!(list_key, "test:relayer_list");
+
+        let maintenance_key = repo.maintenance_key();
+        assert_eq!(maintenance_key, "test:relayer_maintenance");
     }
 
     #[ignore = "Requires active Redis instance"]
@@ -758,46 +2920,367 @@ mod tests {
 
     #[ignore = "Requires active Redis instance"]
     #[tokio::test]
-    async fn test_delete_relayer() {
-        let repo = setup_test_repo().await;
-        let relayer_id = uuid::Uuid::new_v4().to_string();
-        let relayer = create_test_relayer(&relayer_id);
+    async fn test_delete_relayer() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let relayer = create_test_relayer(&relayer_id);
+
+        repo.create(relayer.clone()).await.unwrap();
+
+        let delete_result = repo.delete_by_id(relayer.id.clone()).await;
+        assert!(delete_result.is_ok());
+
+        let get_result = repo.get_by_id(relayer.id).await;
+        assert!(matches!(get_result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_delete_many() {
+        let repo = setup_test_repo().await;
+        let existing_id = uuid::Uuid::new_v4().to_string();
+        let missing_id = uuid::Uuid::new_v4().to_string();
+        repo.create(create_test_relayer(&existing_id))
+            .await
+            .unwrap();
+
+        let report = repo
+            .delete_many(vec![existing_id.clone(), missing_id.clone()])
+            .await
+            .unwrap();
+
+        assert_eq!(report.deleted, vec![existing_id.clone()]);
+        assert_eq!(report.not_found, vec![missing_id]);
+        let get_result = repo.get_by_id(existing_id).await;
+        assert!(matches!(get_result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_delete_refuses_parent_with_children() {
+        let repo = setup_test_repo().await;
+        let parent_id = uuid::Uuid::new_v4().to_string();
+        let child_id = uuid::Uuid::new_v4().to_string();
+        repo.create(create_test_relayer(&parent_id)).await.unwrap();
+        let mut child = create_test_relayer(&child_id);
+        child.inherits_from = Some(parent_id.clone());
+        repo.create(child).await.unwrap();
+
+        let result = repo.delete_by_id(parent_id.clone()).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let result = repo.delete_many(vec![parent_id.clone()]).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert!(repo.get_by_id_effective(child_id.clone()).await.is_ok());
+
+        let report = repo
+            .delete_many(vec![parent_id.clone(), child_id.clone()])
+            .await
+            .unwrap();
+        assert_eq!(report.deleted, vec![parent_id, child_id]);
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_list_paginated() {
+        let repo = setup_test_repo().await;
+        let relayer1_id = uuid::Uuid::new_v4().to_string();
+        let relayer2_id = uuid::Uuid::new_v4().to_string();
+        let relayer1 = create_test_relayer(&relayer1_id);
+        let relayer2 = create_test_relayer(&relayer2_id);
+
+        repo.create(relayer1).await.unwrap();
+        repo.create(relayer2).await.unwrap();
+
+        let query = PaginationQuery {
+            page: 1,
+            per_page: Some(10),
+        };
+
+        let result = repo.list_paginated(query).await.unwrap();
+        assert!(result.total >= 2);
+        assert_eq!(result.page, 1);
+        assert_eq!(result.per_page, 10);
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_partial_update_relayer() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let relayer = create_test_relayer(&relayer_id);
+
+        repo.create(relayer.clone()).await.unwrap();
+
+        let update = UpdateRelayerRequest {
+            paused: Some(true),
+            ..Default::default()
+        };
+        let result = repo.partial_update(relayer.id.clone(), update).await;
+        assert!(result.is_ok());
+
+        let updated = result.unwrap();
+        assert_eq!(updated.id, relayer.id);
+        assert!(updated.paused);
+    }
+
+    /// Stores a valid relayer, then checks that `update` rejects it with `policy` and
+    /// leaves the stored relayer untouched
+    async fn assert_update_rejects_policy(policy: RelayerEvmPolicy) {
+        let repo = setup_test_repo().await;
+        let relayer = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+        repo.create(relayer.clone()).await.unwrap();
+
+        let result = repo
+            .update(
+                relayer.id.clone(),
+                RelayerRepoModel {
+                    policies: RelayerNetworkPolicy::Evm(policy),
+                    ..relayer.clone()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+        let stored = repo.get_by_id(relayer.id.clone()).await.unwrap();
+        assert_eq!(stored.policies, relayer.policies);
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_invalid_gas_oracle_url() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            gas_oracle: GasOracleConfig::Custom {
+                url: "not a url".to_string(),
+            },
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_zero_calldata_limit() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_calldata_bytes: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_gas_bump_below_replacement_minimum() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            gas_bump: Some(crate::models::GasBumpPolicy {
+                bump_percent: 5,
+                max_bumps: 3,
+                bump_interval_secs: 60,
+            }),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_zero_transaction_deadline() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            transaction_deadline_secs: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_zero_max_inflight_transactions() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_inflight_transactions: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_empty_allowed_tx_types() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            allowed_tx_types: Some(vec![]),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_invalid_fee_token_address() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            accepted_fee_tokens: Some(vec![crate::models::FeeToken {
+                address: "not-an-address".to_string(),
+                min_rate: 1.0,
+            }]),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_priority_fee_floor_above_cap() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            min_priority_fee_per_gas: Some(101),
+            gas_price_cap: Some(100),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_zero_max_daily_spend() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_daily_spend: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_zero_gas_limit_cap() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            gas_limit_cap: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_zero_max_rpc_batch_size() {
+        assert_update_rejects_policy(RelayerEvmPolicy {
+            max_rpc_batch_size: Some(0),
+            ..RelayerEvmPolicy::default()
+        })
+        .await;
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_update_rejects_policy_outside_guardrails() {
+        let repo = setup_test_repo()
+            .await
+            .with_policy_guardrails(PolicyGuardrails {
+                require_gas_price_cap: true,
+                max_allowed_gas_price_cap: Some(500_000_000_000),
+            });
+        let mut relayer = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+        relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            gas_price_cap: Some(100_000_000_000),
+            ..RelayerEvmPolicy::default()
+        });
+        repo.create(relayer.clone()).await.unwrap();
+
+        let uncapped = RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
+            ..relayer.clone()
+        };
+        let result = repo.update(relayer.id.clone(), uncapped).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let stored = repo.get_by_id(relayer.id).await.unwrap();
+        assert_eq!(stored.policies, relayer.policies);
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_metadata_over_size_limit_is_rejected_on_create_and_update() {
+        let mut repo = setup_test_repo().await;
+        repo.metadata_size_limit = 32;
+        let relayer = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+        let oversized = RelayerRepoModel {
+            metadata: serde_json::json!({ "note": "x".repeat(64) }),
+            ..relayer.clone()
+        };
+
+        let result = repo.create(oversized.clone()).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
 
         repo.create(relayer.clone()).await.unwrap();
+        let result = repo.update(relayer.id.clone(), oversized).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        let stored = repo.get_by_id(relayer.id).await.unwrap();
+        assert_eq!(stored.metadata, relayer.metadata);
+    }
 
-        let delete_result = repo.delete_by_id(relayer.id.clone()).await;
-        assert!(delete_result.is_ok());
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_partial_update_rejects_duplicate_name() {
+        let repo = setup_test_repo().await;
+        let first = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+        let second = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+        repo.create(first.clone()).await.unwrap();
+        repo.create(second.clone()).await.unwrap();
 
-        let get_result = repo.get_by_id(relayer.id).await;
-        assert!(matches!(get_result, Err(RepositoryError::NotFound(_))));
+        let update = UpdateRelayerRequest {
+            name: Some(first.name.clone()),
+            ..Default::default()
+        };
+        let result = repo.partial_update(second.id.clone(), update).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+        assert_eq!(repo.get_by_id(second.id).await.unwrap().name, second.name);
     }
 
     #[ignore = "Requires active Redis instance"]
     #[tokio::test]
-    async fn test_list_paginated() {
-        let repo = setup_test_repo().await;
-        let relayer1_id = uuid::Uuid::new_v4().to_string();
-        let relayer2_id = uuid::Uuid::new_v4().to_string();
-        let relayer1 = create_test_relayer(&relayer1_id);
-        let relayer2 = create_test_relayer(&relayer2_id);
+    async fn test_partial_update_rejects_unknown_notification() {
+        #[derive(Debug)]
+        struct NoNotifications;
+
+        #[async_trait]
+        impl NotificationRegistry for NoNotifications {
+            async fn notification_exists(&self, _: &str) -> Result<bool, RepositoryError> {
+                Ok(false)
+            }
+        }
 
-        repo.create(relayer1).await.unwrap();
-        repo.create(relayer2).await.unwrap();
+        let repo = setup_test_repo()
+            .await
+            .with_notification_registry(Arc::new(NoNotifications));
+        let relayer = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+        repo.create(relayer.clone()).await.unwrap();
 
-        let query = PaginationQuery {
-            page: 1,
-            per_page: 10,
+        let update = UpdateRelayerRequest {
+            notification_id: Some(Some("missing".to_string())),
+            ..Default::default()
         };
-
-        let result = repo.list_paginated(query).await.unwrap();
-        assert!(result.total >= 2);
-        assert_eq!(result.page, 1);
-        assert_eq!(result.per_page, 10);
+        let result = repo.partial_update(relayer.id.clone(), update).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+        assert_eq!(
+            repo.get_by_id(relayer.id).await.unwrap().notification_id,
+            None
+        );
     }
 
     #[ignore = "Requires active Redis instance"]
     #[tokio::test]
-    async fn test_partial_update_relayer() {
+    async fn test_partial_update_multiple_fields() {
         let repo = setup_test_repo().await;
         let relayer_id = uuid::Uuid::new_v4().to_string();
         let relayer = create_test_relayer(&relayer_id);
@@ -805,15 +3288,25 @@ mod tests {
         repo.create(relayer.clone()).await.unwrap();
 
         let update = UpdateRelayerRequest {
+            name: Some(format!("Renamed Relayer {relayer_id}")),
             paused: Some(true),
+            notification_id: Some(Some("notification-1".to_string())),
             ..Default::default()
         };
-        let result = repo.partial_update(relayer.id.clone(), update).await;
-        assert!(result.is_ok());
-
-        let updated = result.unwrap();
-        assert_eq!(updated.id, relayer.id);
+        let updated = repo
+            .partial_update(relayer.id.clone(), update)
+            .await
+            .unwrap();
+        assert_eq!(updated.name, format!("Renamed Relayer {relayer_id}"));
         assert!(updated.paused);
+        assert_eq!(updated.notification_id, Some("notification-1".to_string()));
+        assert!(updated.updated_at.is_some());
+
+        let empty = repo
+            .partial_update(relayer.id.clone(), UpdateRelayerRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(empty.updated_at, updated.updated_at);
     }
 
     #[ignore = "Requires active Redis instance"]
@@ -856,6 +3349,23 @@ mod tests {
             private_transactions: Some(true),
             min_balance: Some(1000000000000000000),
             gas_limit_estimation: Some(true),
+            low_balance_alerting: None,
+            validation_rules: vec![],
+            gas_oracle: GasOracleConfig::ProviderDefault,
+            simulate_before_send: false,
+            revert_handling: RevertHandling::Reject,
+            max_calldata_bytes: None,
+            gas_bump: None,
+            transaction_deadline_secs: None,
+            max_inflight_transactions: None,
+            allowed_tx_types: None,
+            accepted_fee_tokens: None,
+            min_priority_fee_per_gas: None,
+            max_daily_spend: None,
+            stuck_tx_action: StuckTxAction::SpeedUp,
+            gas_estimation_multiplier: None,
+            gas_limit_cap: None,
+            max_rpc_batch_size: None,
         });
 
         let result = repo.update_policy(relayer.id.clone(), new_policy).await;
@@ -880,7 +3390,7 @@ mod tests {
     #[tokio::test]
     async fn test_debug_implementation() {
         let repo = setup_test_repo().await;
-        let debug_str = format!("{:?}", repo);
+        let debug_str = format!("{repo:?}");
         assert!(debug_str.contains("RedisRelayerRepository"));
         assert!(debug_str.contains("key_prefix"));
     }
@@ -943,14 +3453,14 @@ mod tests {
 
         let invalid_page = PaginationQuery {
             page: 0,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = repo.list_paginated(invalid_page).await;
         assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
 
         let invalid_per_page = PaginationQuery {
             page: 1,
-            per_page: 0,
+            per_page: Some(0),
         };
         let result = repo.list_paginated(invalid_per_page).await;
         assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
@@ -977,6 +3487,84 @@ mod tests {
         assert!(matches!(result, Err(RepositoryError::NotFound(_))));
     }
 
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_validate_create_reports_every_failure() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        repo.create(create_test_relayer(&relayer_id)).await.unwrap();
+
+        let relayer = RelayerRepoModel {
+            name: String::new(),
+            tenant_id: String::new(),
+            ..create_test_relayer(&relayer_id)
+        };
+        let errors = repo.validate_create(relayer.clone()).await.unwrap_err();
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        assert!(matches!(errors[2], RepositoryError::ConstraintViolation(_)));
+
+        // create fails with the first of them
+        let result = repo.create(relayer).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(msg)) if msg.contains("name")));
+
+        let fresh = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+        assert!(repo.validate_create(fresh.clone()).await.is_ok());
+        assert!(matches!(
+            repo.get_by_id(fresh.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_ping() {
+        let repo = setup_test_repo().await;
+        assert!(repo.ping().await.is_ok());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_add_and_remove_tag_where() {
+        let repo = setup_test_repo().await;
+        let network = format!("net-{}", uuid::Uuid::new_v4());
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let mut relayer = create_test_relayer(&uuid::Uuid::new_v4().to_string());
+            relayer.network = network.clone();
+            ids.push(relayer.id.clone());
+            repo.create(relayer).await.unwrap();
+        }
+        let filter = RelayerFilter {
+            network: Some(network),
+            ..Default::default()
+        };
+
+        let added = repo
+            .add_tag_where(filter.clone(), "batch-a".to_string())
+            .await
+            .unwrap();
+        assert_eq!(added, 2);
+        let added_again = repo
+            .add_tag_where(filter.clone(), "batch-a".to_string())
+            .await
+            .unwrap();
+        assert_eq!(added_again, 0);
+        for id in &ids {
+            assert!(repo
+                .get_by_id(id.clone())
+                .await
+                .unwrap()
+                .updated_at
+                .is_some());
+        }
+
+        let removed = repo
+            .remove_tag_where(filter, "batch-a".to_string())
+            .await
+            .unwrap();
+        assert_eq!(removed, 2);
+    }
+
     #[tokio::test]
     #[ignore = "Requires active Redis instance"]
     async fn test_has_entries() {
@@ -1076,4 +3664,411 @@ mod tests {
         let result = repo.list_by_notification_id("nonexistent").await.unwrap();
         assert_eq!(result.len(), 0);
     }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_quarantine_and_list_expired() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        repo.create(create_test_relayer(&relayer_id)).await.unwrap();
+
+        let now = Utc::now();
+        let until = now + chrono::Duration::minutes(5);
+        let quarantined = repo
+            .quarantine(relayer_id.clone(), until, "cooldown".to_string())
+            .await
+            .unwrap();
+        assert!(quarantined.system_disabled);
+        assert_eq!(quarantined.quarantined_until, Some(until));
+
+        let expired = repo.list_expired_quarantines(now).await.unwrap();
+        assert!(!expired.iter().any(|r| r.id == relayer_id));
+
+        let expired = repo
+            .list_expired_quarantines(until + chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+        assert!(expired.iter().any(|r| r.id == relayer_id));
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_create_idempotent() {
+        let repo = setup_test_repo().await;
+        let idempotency_key = uuid::Uuid::new_v4().to_string();
+        let relayer1_id = uuid::Uuid::new_v4().to_string();
+        let relayer2_id = uuid::Uuid::new_v4().to_string();
+
+        let first = repo
+            .create_idempotent(create_test_relayer(&relayer1_id), idempotency_key.clone())
+            .await
+            .unwrap();
+        let second = repo
+            .create_idempotent(create_test_relayer(&relayer2_id), idempotency_key)
+            .await
+            .unwrap();
+
+        assert_eq!(first.id, relayer1_id);
+        assert_eq!(second.id, relayer1_id);
+        assert!(matches!(
+            repo.get_by_id(relayer2_id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_create_idempotent_retry_after_delete_creates_again() {
+        let repo = setup_test_repo().await;
+        let idempotency_key = uuid::Uuid::new_v4().to_string();
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+
+        repo.create_idempotent(create_test_relayer(&relayer_id), idempotency_key.clone())
+            .await
+            .unwrap();
+        repo.delete_by_id(relayer_id.clone()).await.unwrap();
+        let recreated = repo
+            .create_idempotent(create_test_relayer(&relayer_id), idempotency_key.clone())
+            .await
+            .unwrap();
+        assert_eq!(recreated.id, relayer_id);
+
+        repo.delete_many(vec![relayer_id.clone()]).await.unwrap();
+        assert!(repo
+            .create_idempotent(create_test_relayer(&relayer_id), idempotency_key)
+            .await
+            .is_ok());
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_maintenance_window() {
+        let repo = setup_test_repo().await;
+        let active_id = uuid::Uuid::new_v4().to_string();
+        let paused_id = uuid::Uuid::new_v4().to_string();
+        repo.create(create_test_relayer(&active_id)).await.unwrap();
+        repo.create(create_test_relayer_with_pause(&paused_id, true))
+            .await
+            .unwrap();
+
+        let until = Utc::now() + chrono::Duration::hours(1);
+        repo.enter_maintenance(until).await.unwrap();
+        assert!(repo.maintenance_until().await.unwrap().is_some());
+        assert!(!repo.is_available(active_id.clone()).await.unwrap());
+        assert!(!repo.is_available(paused_id.clone()).await.unwrap());
+
+        repo.exit_maintenance().await.unwrap();
+        assert_eq!(repo.maintenance_until().await.unwrap(), None);
+        assert!(repo.is_available(active_id).await.unwrap());
+        assert!(!repo.is_available(paused_id).await.unwrap());
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_list_high_error_rate() {
+        let repo = setup_test_repo().await;
+        let failing_id = uuid::Uuid::new_v4().to_string();
+        let healthy_id = uuid::Uuid::new_v4().to_string();
+        repo.create(create_test_relayer(&failing_id)).await.unwrap();
+        repo.create(create_test_relayer(&healthy_id)).await.unwrap();
+
+        for success in [false, false, true] {
+            repo.record_outcome(failing_id.clone(), success)
+                .await
+                .unwrap();
+            repo.record_outcome(healthy_id.clone(), !success)
+                .await
+                .unwrap();
+        }
+
+        let high = repo
+            .list_high_error_rate(Duration::from_secs(60), 0.5)
+            .await
+            .unwrap();
+        assert!(high.iter().any(|r| r.id == failing_id));
+        assert!(!high.iter().any(|r| r.id == healthy_id));
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_stress_concurrent_operations() {
+        use crate::repositories::relayer::relayer_stress::{run_relayer_stress, StressConfig};
+
+        let redis_url =
+            std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379/".to_string());
+        let client = redis::Client::open(redis_url).expect("Failed to create Redis client");
+        let connection_manager = ConnectionManager::new(client)
+            .await
+            .expect("Failed to create Redis connection manager");
+        // A dedicated prefix keeps the run isolated from other tests
+        let key_prefix = format!("stress-{}", uuid::Uuid::new_v4());
+        let repo = RedisRelayerRepository::new(Arc::new(connection_manager), key_prefix)
+            .expect("Failed to create Redis relayer repository");
+
+        let report = run_relayer_stress(Arc::new(repo.clone()), StressConfig::default()).await;
+        assert!(report.total_ops() > 0);
+        repo.drop_all_entries().await.unwrap();
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_pause_if_version_concurrent_callers_only_one_wins() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let created = repo.create(create_test_relayer(&relayer_id)).await.unwrap();
+
+        let attempts: Vec<_> = (0..8)
+            .map(|_| {
+                let repo = repo.clone();
+                let relayer_id = relayer_id.clone();
+                tokio::spawn(
+                    async move { repo.pause_if_version(relayer_id, created.version).await },
+                )
+            })
+            .collect();
+        let mut paused = 0;
+        for attempt in attempts {
+            match attempt.await.unwrap() {
+                Ok(_) => paused += 1,
+                Err(e) => assert!(matches!(e, RepositoryError::Conflict(_))),
+            }
+        }
+
+        assert_eq!(paused, 1);
+        let stored = repo.get_by_id(relayer_id).await.unwrap();
+        assert!(stored.paused);
+        assert_eq!(stored.version, created.version + 1);
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_concurrent_updates_across_instances_get_distinct_versions() {
+        // Two repositories with their own connections and config locks stand in for
+        // two relayer instances sharing the backend
+        let first = setup_test_repo().await;
+        let second = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let created = first
+            .create(create_test_relayer(&relayer_id))
+            .await
+            .unwrap();
+
+        let updates: Vec<_> = (0..10)
+            .map(|i| {
+                let repo = if i % 2 == 0 {
+                    first.clone()
+                } else {
+                    second.clone()
+                };
+                let relayer = RelayerRepoModel {
+                    name: format!("Renamed {i}"),
+                    ..create_test_relayer(&relayer_id)
+                };
+                let relayer_id = relayer_id.clone();
+                tokio::spawn(async move { repo.update(relayer_id, relayer).await })
+            })
+            .collect();
+        let mut versions = HashSet::new();
+        for update in updates {
+            versions.insert(update.await.unwrap().unwrap().version);
+        }
+
+        assert_eq!(versions.len(), 10);
+        let stored = first.get_by_id(relayer_id).await.unwrap();
+        assert_eq!(stored.version, created.version + 10);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_concurrent_partial_writes_across_instances_all_land() {
+        let first = setup_test_repo().await;
+        let second = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let created = first
+            .create(create_test_relayer(&relayer_id))
+            .await
+            .unwrap();
+        let until = Utc::now() + chrono::Duration::hours(1);
+
+        // Each write changes a different field; none may undo another
+        let (sunset, circuit, error, log_level) = tokio::join!(
+            first.sunset(relayer_id.clone()),
+            second.trip_circuit(relayer_id.clone(), until),
+            first.record_error(
+                relayer_id.clone(),
+                RelayerLastError::new(RelayerErrorKind::Rpc, "timeout"),
+            ),
+            second.set_log_level(relayer_id.clone(), Some(LogLevel::Debug)),
+        );
+        sunset.unwrap();
+        circuit.unwrap();
+        error.unwrap();
+        log_level.unwrap();
+
+        let stored = first.get_by_id(relayer_id).await.unwrap();
+        assert!(stored.sunset);
+        assert!(matches!(stored.circuit, CircuitState::Open { .. }));
+        assert!(stored.last_error.is_some());
+        assert_eq!(stored.log_level, Some(LogLevel::Debug));
+        assert_eq!(stored.version, created.version + 4);
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_concurrent_pending_counter_changes() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        repo.create(create_test_relayer(&relayer_id)).await.unwrap();
+
+        let increments: Vec<_> = (0..20)
+            .map(|_| {
+                let repo = repo.clone();
+                let relayer_id = relayer_id.clone();
+                tokio::spawn(async move { repo.increment_pending(relayer_id).await })
+            })
+            .collect();
+        for increment in increments {
+            increment.await.unwrap().unwrap();
+        }
+        assert_eq!(repo.pending_count(relayer_id.clone()).await.unwrap(), 20);
+        assert_eq!(
+            repo.get_by_id(relayer_id.clone())
+                .await
+                .unwrap()
+                .pending_count,
+            20
+        );
+
+        // More decrements than increments saturate at zero
+        let decrements: Vec<_> = (0..25)
+            .map(|_| {
+                let repo = repo.clone();
+                let relayer_id = relayer_id.clone();
+                tokio::spawn(async move { repo.decrement_pending(relayer_id).await })
+            })
+            .collect();
+        for decrement in decrements {
+            decrement.await.unwrap().unwrap();
+        }
+        assert_eq!(repo.pending_count(relayer_id.clone()).await.unwrap(), 0);
+
+        // The counter goes with the relayer
+        repo.increment_pending(relayer_id.clone()).await.unwrap();
+        repo.delete_by_id(relayer_id.clone()).await.unwrap();
+        repo.create(create_test_relayer(&relayer_id)).await.unwrap();
+        assert_eq!(repo.pending_count(relayer_id.clone()).await.unwrap(), 0);
+        assert!(matches!(
+            repo.increment_pending("missing".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_concurrent_record_spend_respects_cap() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        repo.create(RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_daily_spend: Some(100),
+                ..RelayerEvmPolicy::default()
+            }),
+            ..create_test_relayer(&relayer_id)
+        })
+        .await
+        .unwrap();
+
+        let spends: Vec<_> = (0..8)
+            .map(|_| {
+                let repo = repo.clone();
+                let relayer_id = relayer_id.clone();
+                tokio::spawn(async move { repo.record_spend(relayer_id, 20).await })
+            })
+            .collect();
+        let mut recorded = 0;
+        for spend in spends {
+            match spend.await.unwrap() {
+                Ok(_) => recorded += 1,
+                Err(e) => assert!(matches!(e, RepositoryError::Conflict(_))),
+            }
+        }
+
+        // Exactly the spends that fit under the cap were recorded
+        assert_eq!(recorded, 5);
+        let stored = repo.get_by_id(relayer_id.clone()).await.unwrap();
+        assert_eq!(stored.spent_today, 100);
+        assert!(stored.spend_window_start.is_some());
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_slots_are_shared_across_instances() {
+        // Two repositories with their own connections stand in for two relayer
+        // instances sharing the backend
+        let first = setup_test_repo().await;
+        let second = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        first
+            .create(RelayerRepoModel {
+                policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                    max_inflight_transactions: Some(2),
+                    ..RelayerEvmPolicy::default()
+                }),
+                ..create_test_relayer(&relayer_id)
+            })
+            .await
+            .unwrap();
+
+        let held = first.try_acquire_slot(relayer_id.clone()).await.unwrap();
+        let _other = second.try_acquire_slot(relayer_id.clone()).await.unwrap();
+        assert!(matches!(
+            first.try_acquire_slot(relayer_id.clone()).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+        assert!(matches!(
+            second.try_acquire_slot(relayer_id.clone()).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+
+        // The release runs in the background once the guard is dropped
+        drop(held);
+        let mut reacquired = None;
+        for _ in 0..50 {
+            if let Ok(guard) = second.try_acquire_slot(relayer_id.clone()).await {
+                reacquired = Some(guard);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(reacquired.is_some());
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_init_through_storage_loads_scripts() {
+        use crate::repositories::RelayerRepositoryStorage;
+
+        let repo = setup_test_repo().await;
+        let mut conn = repo.client.as_ref().clone();
+        let hashes: Vec<&str> = RedisRelayerRepository::scripts()
+            .iter()
+            .map(|script| script.get_hash())
+            .collect();
+        let _: () = redis::cmd("SCRIPT")
+            .arg("FLUSH")
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+
+        let storage = RelayerRepositoryStorage::Redis(repo);
+        storage.init().await.unwrap();
+
+        let loaded: Vec<bool> = redis::cmd("SCRIPT")
+            .arg("EXISTS")
+            .arg(&hashes)
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(loaded, vec![true; hashes.len()]);
+    }
 }
@@ -0,0 +1,163 @@
+//! Per-relayer limits on in-flight transactions.
+//!
+//! [`RelayerSlots`] hands out one permit per in-flight transaction, sized from
+//! the relayer policy's `max_inflight_transactions`. Permits are held by the
+//! current process only: each repository instance enforces the cap on its own.
+//! The Redis backend uses them for uncapped relayers only, and counts the slots
+//! of capped relayers in Redis so the cap holds across instances.
+
+use crate::models::{RelayerRepoModel, RepositoryError};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore, TryAcquireError};
+
+/// Slot for one in-flight transaction of a relayer, released when dropped
+pub struct SlotGuard {
+    permit: Option<OwnedSemaphorePermit>,
+    release: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl SlotGuard {
+    /// Guard for a slot tracked outside this process, freed by calling `release`
+    pub fn with_release(release: impl FnOnce() + Send + Sync + 'static) -> Self {
+        Self {
+            permit: None,
+            release: Some(Box::new(release)),
+        }
+    }
+}
+
+impl Drop for SlotGuard {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            release();
+        }
+    }
+}
+
+impl fmt::Debug for SlotGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlotGuard")
+            .field("local", &self.permit.is_some())
+            .finish()
+    }
+}
+
+/// Semaphore per relayer ID, with the cap it was sized from
+type SlotSemaphores = HashMap<String, (Option<u32>, Arc<Semaphore>)>;
+
+/// Semaphores limiting the in-flight transactions of each relayer
+#[derive(Debug, Default)]
+pub struct RelayerSlots {
+    semaphores: Mutex<SlotSemaphores>,
+}
+
+impl RelayerSlots {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes a slot for `relayer`, failing with `Conflict` if all its slots are taken.
+    ///
+    /// Relayers without a cap get an effectively unbounded semaphore. When the cap
+    /// changes the semaphore is resized; slots taken under the old cap no longer
+    /// count against the new one.
+    pub async fn try_acquire(
+        &self,
+        relayer: &RelayerRepoModel,
+    ) -> Result<SlotGuard, RepositoryError> {
+        let cap = relayer.policies.max_inflight_transactions();
+        let semaphore = {
+            let mut semaphores = self.semaphores.lock().await;
+            let entry = semaphores
+                .entry(relayer.id.clone())
+                .or_insert_with(|| (cap, Arc::new(Self::semaphore_for(cap))));
+            if entry.0 != cap {
+                *entry = (cap, Arc::new(Self::semaphore_for(cap)));
+            }
+            entry.1.clone()
+        };
+
+        match semaphore.try_acquire_owned() {
+            Ok(permit) => Ok(SlotGuard {
+                permit: Some(permit),
+                release: None,
+            }),
+            Err(TryAcquireError::NoPermits) => Err(RepositoryError::Conflict(format!(
+                "Relayer {} has reached its limit of {} in-flight transactions",
+                relayer.id,
+                cap.map_or("unbounded".to_string(), |cap| cap.to_string())
+            ))),
+            Err(TryAcquireError::Closed) => Err(RepositoryError::UnexpectedError(format!(
+                "Slot semaphore of relayer {} is closed",
+                relayer.id
+            ))),
+        }
+    }
+
+    fn semaphore_for(cap: Option<u32>) -> Semaphore {
+        Semaphore::new(cap.map_or(Semaphore::MAX_PERMITS, |cap| cap as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RelayerEvmPolicy, RelayerNetworkPolicy};
+
+    fn create_capped_relayer(cap: Option<u32>) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: "relayer-1".to_string(),
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_inflight_transactions: cap,
+                ..RelayerEvmPolicy::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_uncapped_relayer_is_unbounded() {
+        let slots = RelayerSlots::new();
+        let relayer = create_capped_relayer(None);
+        let mut guards = Vec::new();
+        for _ in 0..100 {
+            guards.push(slots.try_acquire(&relayer).await.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_release_runs_once_on_drop() {
+        let released = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = released.clone();
+        let guard = SlotGuard::with_release(move || {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        });
+        assert_eq!(released.load(std::sync::atomic::Ordering::SeqCst), 0);
+        drop(guard);
+        assert_eq!(released.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cap_change_resizes_semaphore() {
+        let slots = RelayerSlots::new();
+        let _guard = slots
+            .try_acquire(&create_capped_relayer(Some(1)))
+            .await
+            .unwrap();
+        assert!(slots
+            .try_acquire(&create_capped_relayer(Some(1)))
+            .await
+            .is_err());
+
+        // Raising the cap starts from a fresh semaphore
+        let relayer = create_capped_relayer(Some(2));
+        let _first = slots.try_acquire(&relayer).await.unwrap();
+        let _second = slots.try_acquire(&relayer).await.unwrap();
+        assert!(matches!(
+            slots.try_acquire(&relayer).await,
+            Err(RepositoryError::Conflict(_))
+        ));
+    }
+}
@@ -0,0 +1,384 @@
+//! Point-in-time view of the relayer fleet.
+//!
+//! A [`RelayerSnapshot`] is taken from a single repository read, so every
+//! aggregate computed from the same snapshot is consistent with the others,
+//! even if relayers are created or modified concurrently.
+//!
+//! A [`ReadToken`] pins a snapshot for a pagination session, so every page is
+//! cut from the same fleet regardless of writes made between page requests.
+
+use crate::models::{
+    NetworkType, PaginationQuery, RelayerOperationalStatus, RelayerRepoModel, RepositoryError,
+};
+use crate::repositories::PaginatedResult;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Aggregate counts over the relayer fleet
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RelayerSummary {
+    pub total: usize,
+    pub operational: usize,
+    pub paused: usize,
+    pub system_disabled: usize,
+    /// Transactions pending across all relayers
+    pub pending: u64,
+}
+
+/// Fleet metrics shaped for export to a JSON scrape endpoint
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RelayerMetricsSnapshot {
+    pub total: usize,
+    pub by_network_type: HashMap<NetworkType, usize>,
+    pub by_status: HashMap<RelayerOperationalStatus, usize>,
+    /// Transactions pending across all relayers
+    pub pending: u64,
+    /// Relayers whose known balance is below their policy's `min_balance`
+    pub below_min_balance: usize,
+}
+
+/// Whitelisted receiver shared by several relayers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WhitelistOverlap {
+    /// Lowercased receiver address
+    pub address: String,
+    /// Sorted IDs of the relayers whitelisting it
+    pub relayer_ids: Vec<String>,
+}
+
+/// Immutable, cheaply clonable snapshot of all relayers keyed by ID
+#[derive(Debug, Clone, Default)]
+pub struct RelayerSnapshot {
+    relayers: Arc<HashMap<String, RelayerRepoModel>>,
+}
+
+impl RelayerSnapshot {
+    pub fn new(relayers: HashMap<String, RelayerRepoModel>) -> Self {
+        Self {
+            relayers: Arc::new(relayers),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.relayers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.relayers.is_empty()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&RelayerRepoModel> {
+        self.relayers.get(id)
+    }
+
+    pub fn relayers(&self) -> impl Iterator<Item = &RelayerRepoModel> {
+        self.relayers.values()
+    }
+
+    /// Counts relayers by operational state and sums their pending transactions
+    pub fn summary(&self) -> RelayerSummary {
+        self.relayers().fold(
+            RelayerSummary {
+                total: self.len(),
+                ..Default::default()
+            },
+            |mut summary, relayer| {
+                if relayer.is_operational() {
+                    summary.operational += 1;
+                }
+                if relayer.paused {
+                    summary.paused += 1;
+                }
+                if relayer.system_disabled {
+                    summary.system_disabled += 1;
+                }
+                summary.pending = summary.pending.saturating_add(relayer.pending_count);
+                summary
+            },
+        )
+    }
+
+    /// Computes export metrics for the fleet. `balances` maps relayer IDs to their
+    /// balance; relayers without an entry are not counted as below their minimum.
+    pub fn metrics(&self, balances: &HashMap<String, u128>) -> RelayerMetricsSnapshot {
+        self.relayers().fold(
+            RelayerMetricsSnapshot {
+                total: self.len(),
+                ..Default::default()
+            },
+            |mut metrics, relayer| {
+                *metrics
+                    .by_network_type
+                    .entry(relayer.network_type)
+                    .or_default() += 1;
+                *metrics.by_status.entry(relayer.status()).or_default() += 1;
+                metrics.pending = metrics.pending.saturating_add(relayer.pending_count);
+                if balances
+                    .get(&relayer.id)
+                    .is_some_and(|balance| relayer.funding_deficit(*balance).is_some())
+                {
+                    metrics.below_min_balance += 1;
+                }
+                metrics
+            },
+        )
+    }
+
+    /// Groups relayer IDs by signer ID, with IDs sorted within each group
+    pub fn group_by_signer(&self) -> HashMap<String, Vec<String>> {
+        let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+        for relayer in self.relayers() {
+            groups
+                .entry(relayer.signer_id.clone())
+                .or_default()
+                .push(relayer.id.clone());
+        }
+        groups.values_mut().for_each(|ids| ids.sort());
+        groups
+    }
+
+    /// Groups the operational relayers by network type, sorted by ID within each group
+    pub fn group_operational_by_network_type(&self) -> HashMap<NetworkType, Vec<RelayerRepoModel>> {
+        let mut groups: HashMap<NetworkType, Vec<RelayerRepoModel>> = HashMap::new();
+        for relayer in self.relayers().filter(|r| r.is_operational()) {
+            groups
+                .entry(relayer.network_type)
+                .or_default()
+                .push(relayer.clone());
+        }
+        groups
+            .values_mut()
+            .for_each(|relayers| relayers.sort_by(|a, b| a.id.cmp(&b.id)));
+        groups
+    }
+
+    /// Returns addresses shared by more than one relayer, mapped to the sorted relayer IDs
+    pub fn find_duplicate_addresses(&self) -> HashMap<String, Vec<String>> {
+        let mut by_address: HashMap<String, Vec<String>> = HashMap::new();
+        for relayer in self.relayers().filter(|r| !r.address.is_empty()) {
+            by_address
+                .entry(relayer.address.clone())
+                .or_default()
+                .push(relayer.id.clone());
+        }
+        by_address.retain(|_, ids| ids.len() > 1);
+        by_address.values_mut().for_each(|ids| ids.sort());
+        by_address
+    }
+
+    /// Returns the EVM whitelist receivers shared by more than one relayer, sorted by
+    /// address. Addresses are compared case-insensitively.
+    pub fn whitelist_overlaps(&self) -> Vec<WhitelistOverlap> {
+        let mut by_address: HashMap<String, Vec<String>> = HashMap::new();
+        for relayer in self.relayers() {
+            let Some(receivers) = relayer
+                .policies
+                .as_evm()
+                .and_then(|policy| policy.whitelist_receivers.as_ref())
+            else {
+                continue;
+            };
+            for receiver in receivers {
+                let ids = by_address
+                    .entry(relayer.network_type.normalize_address(receiver))
+                    .or_default();
+                if !ids.contains(&relayer.id) {
+                    ids.push(relayer.id.clone());
+                }
+            }
+        }
+        let mut overlaps: Vec<WhitelistOverlap> = by_address
+            .into_iter()
+            .filter(|(_, ids)| ids.len() > 1)
+            .map(|(address, mut relayer_ids)| {
+                relayer_ids.sort();
+                WhitelistOverlap {
+                    address,
+                    relayer_ids,
+                }
+            })
+            .collect();
+        overlaps.sort_by(|a, b| a.address.cmp(&b.address));
+        overlaps
+    }
+}
+
+impl From<Vec<RelayerRepoModel>> for RelayerSnapshot {
+    fn from(relayers: Vec<RelayerRepoModel>) -> Self {
+        Self::new(
+            relayers
+                .into_iter()
+                .map(|relayer| (relayer.id.clone(), relayer))
+                .collect(),
+        )
+    }
+}
+
+/// Handle to a snapshot pinned by `begin_consistent_read`.
+///
+/// Pages read through the same token all come from the snapshot taken when the
+/// token was created. Clones share the snapshot rather than copying it.
+#[derive(Debug, Clone)]
+pub struct ReadToken {
+    seq: u64,
+    snapshot: RelayerSnapshot,
+}
+
+impl ReadToken {
+    /// Pins `snapshot`, taken when the change log was at sequence `seq`
+    pub fn new(seq: u64, snapshot: RelayerSnapshot) -> Self {
+        Self { seq, snapshot }
+    }
+
+    /// Change log sequence the snapshot was taken at
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    pub fn snapshot(&self) -> &RelayerSnapshot {
+        &self.snapshot
+    }
+
+    /// Returns one page of the pinned relayers, ordered by ID
+    pub fn paginate(
+        &self,
+        query: &PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerRepoModel>, RepositoryError> {
+        let per_page = query.resolved_per_page();
+        if query.page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Page number must be greater than 0".to_string(),
+            ));
+        }
+        if per_page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "Per page count must be greater than 0".to_string(),
+            ));
+        }
+
+        let mut relayers: Vec<&RelayerRepoModel> = self.snapshot.relayers().collect();
+        relayers.sort_by(|a, b| a.id.cmp(&b.id));
+        let start = ((query.page - 1) * per_page) as usize;
+        let items = relayers
+            .into_iter()
+            .skip(start)
+            .take(per_page as usize)
+            .cloned()
+            .collect();
+        Ok(PaginatedResult {
+            items,
+            total: self.snapshot.len() as u64,
+            page: query.page,
+            per_page,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RelayerEvmPolicy, RelayerNetworkPolicy};
+
+    fn create_test_relayer(id: &str, signer_id: &str, address: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: id.to_string(),
+            name: format!("Relayer {id}"),
+            signer_id: signer_id.to_string(),
+            address: address.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_summary_counts() {
+        let mut paused = create_test_relayer("relayer-2", "signer-1", "0x2");
+        paused.paused = true;
+        let mut disabled = create_test_relayer("relayer-3", "signer-2", "0x3");
+        disabled.system_disabled = true;
+
+        let snapshot = RelayerSnapshot::from(vec![
+            create_test_relayer("relayer-1", "signer-1", "0x1"),
+            paused,
+            disabled,
+        ]);
+
+        assert_eq!(
+            snapshot.summary(),
+            RelayerSummary {
+                total: 3,
+                operational: 1,
+                paused: 1,
+                system_disabled: 1,
+                pending: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_group_by_signer() {
+        let snapshot = RelayerSnapshot::from(vec![
+            create_test_relayer("relayer-2", "signer-1", "0x2"),
+            create_test_relayer("relayer-1", "signer-1", "0x1"),
+            create_test_relayer("relayer-3", "signer-2", "0x3"),
+        ]);
+
+        let groups = snapshot.group_by_signer();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["signer-1"], vec!["relayer-1", "relayer-2"]);
+        assert_eq!(groups["signer-2"], vec!["relayer-3"]);
+    }
+
+    #[test]
+    fn test_find_duplicate_addresses() {
+        let snapshot = RelayerSnapshot::from(vec![
+            create_test_relayer("relayer-1", "signer-1", "0xabc"),
+            create_test_relayer("relayer-2", "signer-2", "0xabc"),
+            create_test_relayer("relayer-3", "signer-3", "0xdef"),
+            create_test_relayer("relayer-4", "signer-4", ""),
+            create_test_relayer("relayer-5", "signer-5", ""),
+        ]);
+
+        let duplicates = snapshot.find_duplicate_addresses();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates["0xabc"], vec!["relayer-1", "relayer-2"]);
+    }
+
+    #[test]
+    fn test_metrics_aggregates_fleet() {
+        let mut evm = create_test_relayer("relayer-1", "signer-1", "0x1");
+        evm.pending_count = 3;
+        evm.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            min_balance: Some(1_000),
+            ..Default::default()
+        });
+        let mut paused = create_test_relayer("relayer-2", "signer-1", "0x2");
+        paused.paused = true;
+        paused.pending_count = 2;
+        paused.policies = evm.policies.clone();
+        let mut solana = create_test_relayer("relayer-3", "signer-2", "0x3");
+        solana.network_type = NetworkType::Solana;
+        solana.sunset = true;
+        let snapshot = RelayerSnapshot::from(vec![evm, paused, solana]);
+
+        // relayer-2 has no known balance, so only relayer-1 counts as underfunded
+        let balances =
+            HashMap::from([("relayer-1".to_string(), 999), ("relayer-3".to_string(), 0)]);
+        let metrics = snapshot.metrics(&balances);
+
+        assert_eq!(
+            metrics,
+            RelayerMetricsSnapshot {
+                total: 3,
+                by_network_type: HashMap::from([(NetworkType::Evm, 2), (NetworkType::Solana, 1)]),
+                by_status: HashMap::from([
+                    (RelayerOperationalStatus::Operational, 1),
+                    (RelayerOperationalStatus::Paused, 1),
+                    (RelayerOperationalStatus::Sunset, 1),
+                ]),
+                pending: 5,
+                below_min_balance: 1,
+            }
+        );
+    }
+}
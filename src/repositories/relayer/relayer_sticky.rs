@@ -0,0 +1,190 @@
+//! Sticky relayer selection within a pool.
+//!
+//! Maps a session key deterministically onto the members of a relayer pool, so
+//! the same end-user is routed to the same relayer as long as the pool
+//! membership does not change. When the preferred relayer is not operational
+//! the next relayer in hash order is used instead.
+//!
+//! The same hashing is used to shard relayers across worker processes.
+
+use crate::models::RelayerRepoModel;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Hashing strategy used to map a session key onto pool members
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickyStrategy {
+    /// Hash ring: the session key is routed to the first relayer clockwise from its position
+    #[default]
+    ConsistentHash,
+    /// Highest random weight: the relayer scoring highest for the session key is chosen
+    Rendezvous,
+}
+
+fn stable_hash(parts: &[&str]) -> u64 {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        // Separator keeps ("ab", "c") and ("a", "bc") distinct
+        hasher.update([0u8]);
+    }
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes)
+}
+
+/// Orders pool members by preference for `session_key`, most preferred first.
+///
+/// The order only depends on the session key and the ids of the members, not on
+/// their runtime state.
+pub fn sticky_order(
+    mut members: Vec<RelayerRepoModel>,
+    session_key: &str,
+    strategy: StickyStrategy,
+) -> Vec<RelayerRepoModel> {
+    match strategy {
+        StickyStrategy::ConsistentHash => {
+            let key_position = stable_hash(&[session_key]);
+            members.sort_by_cached_key(|relayer| {
+                (
+                    stable_hash(&[&relayer.id]).wrapping_sub(key_position),
+                    relayer.id.clone(),
+                )
+            });
+        }
+        StickyStrategy::Rendezvous => {
+            members.sort_by_cached_key(|relayer| {
+                (
+                    std::cmp::Reverse(stable_hash(&[session_key, &relayer.id])),
+                    relayer.id.clone(),
+                )
+            });
+        }
+    }
+    members
+}
+
+/// Picks the most preferred operational member of a pool for `session_key`.
+pub fn select_sticky(
+    members: Vec<RelayerRepoModel>,
+    session_key: &str,
+    strategy: StickyStrategy,
+) -> Option<RelayerRepoModel> {
+    sticky_order(members, session_key, strategy)
+        .into_iter()
+        .find(|relayer| relayer.is_operational())
+}
+
+/// Maps a relayer to one of `worker_count` workers using rendezvous hashing.
+///
+/// When the worker count changes, only the relayers whose highest scoring worker
+/// is added or removed move. `worker_count` must be greater than zero.
+pub fn assign_worker(relayer_id: &str, worker_count: usize) -> usize {
+    (0..worker_count)
+        .max_by_key(|worker| stable_hash(&[relayer_id, &worker.to_string()]))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_member(id: &str) -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: id.to_string(),
+            name: format!("Relayer {id}"),
+            pool: Some("pool".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn create_pool() -> Vec<RelayerRepoModel> {
+        (0..5).map(|i| create_member(&format!("r{i}"))).collect()
+    }
+
+    #[test]
+    fn test_sticky_order_is_independent_of_input_order() {
+        for strategy in [StickyStrategy::ConsistentHash, StickyStrategy::Rendezvous] {
+            let pool = create_pool();
+            let mut reversed = pool.clone();
+            reversed.reverse();
+
+            let ids = |members: Vec<RelayerRepoModel>| -> Vec<String> {
+                members.into_iter().map(|r| r.id).collect()
+            };
+            assert_eq!(
+                ids(sticky_order(pool, "user-1", strategy)),
+                ids(sticky_order(reversed, "user-1", strategy))
+            );
+        }
+    }
+
+    #[test]
+    fn test_consistent_hash_keeps_keys_on_unaffected_members() {
+        let pool = create_pool();
+        let mut grown = pool.clone();
+        grown.push(create_member("r5"));
+
+        for key in (0..100).map(|i| format!("user-{i}")) {
+            let before = select_sticky(pool.clone(), &key, StickyStrategy::ConsistentHash)
+                .unwrap()
+                .id;
+            let after = select_sticky(grown.clone(), &key, StickyStrategy::ConsistentHash)
+                .unwrap()
+                .id;
+            // Only keys now owned by the new member move
+            assert!(before == after || after == "r5");
+        }
+    }
+
+    #[test]
+    fn test_select_sticky_skips_non_operational_members() {
+        let pool = create_pool();
+        let order = sticky_order(pool.clone(), "user-1", StickyStrategy::Rendezvous);
+
+        let pool: Vec<RelayerRepoModel> = pool
+            .into_iter()
+            .map(|mut relayer| {
+                relayer.paused = relayer.id == order[0].id;
+                relayer
+            })
+            .collect();
+        let selected = select_sticky(pool, "user-1", StickyStrategy::Rendezvous).unwrap();
+        assert_eq!(selected.id, order[1].id);
+    }
+
+    #[test]
+    fn test_select_sticky_empty_pool() {
+        assert!(select_sticky(vec![], "user-1", StickyStrategy::ConsistentHash).is_none());
+    }
+
+    #[test]
+    fn test_assign_worker_is_balanced() {
+        let worker_count = 4;
+        let mut counts = vec![0usize; worker_count];
+        for i in 0..4000 {
+            counts[assign_worker(&format!("relayer-{i}"), worker_count)] += 1;
+        }
+        // Each worker should get roughly a quarter of the relayers
+        for count in counts {
+            assert!((800..=1200).contains(&count), "unbalanced count {count}");
+        }
+    }
+
+    #[test]
+    fn test_assign_worker_moves_few_relayers_when_scaling() {
+        let ids: Vec<String> = (0..1000).map(|i| format!("relayer-{i}")).collect();
+        let moved = ids
+            .iter()
+            .filter(|id| assign_worker(id, 4) != assign_worker(id, 5))
+            .count();
+        // Ideally a fifth of the relayers move to the new worker
+        assert!(moved < 300, "{moved} relayers moved");
+        assert!(ids
+            .iter()
+            .filter(|id| assign_worker(id, 4) != assign_worker(id, 5))
+            .all(|id| assign_worker(id, 5) == 4));
+    }
+}
@@ -0,0 +1,259 @@
+//! Concurrency stress harness for relayer repositories.
+//!
+//! [`run_relayer_stress`] spawns many tasks that perform seeded random mixes of
+//! `create`, `update`, `get_by_id`, `delete_by_id`, `list_all` and `count` against a
+//! shared repository, then checks invariants that must hold under any interleaving.
+//! Ids are drawn from a small pool so tasks contend on the same relayers. Each task
+//! derives its operation sequence from the configured seed, so a failing run can be
+//! replayed by reusing the seed printed in the panic message.
+//!
+//! The harness is generic over [`RelayerRepository`], so every storage backend can
+//! run it from its own test module.
+
+use crate::models::{RelayerRepoModel, RepositoryError};
+use crate::repositories::RelayerRepository;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Shape of a stress run
+#[derive(Debug, Clone, Copy)]
+pub struct StressConfig {
+    pub seed: u64,
+    /// Number of concurrently spawned tasks
+    pub tasks: usize,
+    /// Operations performed by each task
+    pub ops_per_task: usize,
+    /// Number of distinct relayer ids the tasks contend on
+    pub id_pool: usize,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0x5eed,
+            tasks: 16,
+            ops_per_task: 64,
+            id_pool: 8,
+        }
+    }
+}
+
+/// Operations performed and expected errors seen during a stress run
+#[derive(Debug, Default)]
+pub struct StressReport {
+    pub ops: HashMap<&'static str, usize>,
+    /// Errors that are legitimate under contention, such as `NotFound` on a relayer
+    /// deleted by another task
+    pub expected_errors: HashMap<&'static str, usize>,
+}
+
+impl StressReport {
+    pub fn total_ops(&self) -> usize {
+        self.ops.values().sum()
+    }
+
+    fn merge(&mut self, other: StressReport) {
+        for (op, count) in other.ops {
+            *self.ops.entry(op).or_default() += count;
+        }
+        for (kind, count) in other.expected_errors {
+            *self.expected_errors.entry(kind).or_default() += count;
+        }
+    }
+}
+
+fn stress_relayer(id: String, revision: usize) -> RelayerRepoModel {
+    RelayerRepoModel {
+        name: format!("Stress relayer {id} r{revision}"),
+        network: "stress-net".to_string(),
+        signer_id: "stress-signer".to_string(),
+        address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
+        id,
+        ..Default::default()
+    }
+}
+
+/// Classifies an operation's error, failing on anything contention cannot explain
+fn expect_contention_error(
+    op: &'static str,
+    error: RepositoryError,
+    seed: u64,
+) -> Result<&'static str, String> {
+    match error {
+        RepositoryError::NotFound(_) => Ok("NotFound"),
+        RepositoryError::ConstraintViolation(_) if op == "create" => Ok("ConstraintViolation"),
+        other => Err(format!(
+            "seed {seed}: unexpected error from {op}: {other:?}"
+        )),
+    }
+}
+
+fn check_unique_ids(relayers: &[RelayerRepoModel], seed: u64) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for relayer in relayers {
+        if !seen.insert(relayer.id.as_str()) {
+            return Err(format!("seed {seed}: relayer {} listed twice", relayer.id));
+        }
+    }
+    Ok(())
+}
+
+async fn run_task<R>(
+    repo: Arc<R>,
+    config: StressConfig,
+    task: usize,
+) -> Result<StressReport, String>
+where
+    R: RelayerRepository + ?Sized,
+{
+    let seed = config.seed;
+    let mut rng = StdRng::seed_from_u64(seed.wrapping_add(task as u64));
+    let mut report = StressReport::default();
+
+    for step in 0..config.ops_per_task {
+        let id = format!("stress-{}", rng.random_range(0..config.id_pool));
+        let (op, result) = match rng.random_range(0..6) {
+            0 => (
+                "create",
+                repo.create(stress_relayer(id, step)).await.map(drop),
+            ),
+            1 => (
+                "update",
+                repo.update(id.clone(), stress_relayer(id, step))
+                    .await
+                    .map(drop),
+            ),
+            2 => (
+                "get",
+                repo.get_by_id(id.clone()).await.and_then(|relayer| {
+                    if relayer.id == id {
+                        Ok(())
+                    } else {
+                        Err(RepositoryError::UnexpectedError(format!(
+                            "get_by_id({id}) returned relayer {}",
+                            relayer.id
+                        )))
+                    }
+                }),
+            ),
+            3 => ("delete", repo.delete_by_id(id).await),
+            4 => match repo.list_all().await {
+                Ok(relayers) => {
+                    check_unique_ids(&relayers, seed)?;
+                    ("list", Ok(()))
+                }
+                Err(e) => ("list", Err(e)),
+            },
+            _ => ("count", repo.count().await.map(drop)),
+        };
+
+        *report.ops.entry(op).or_default() += 1;
+        if let Err(e) = result {
+            let kind = expect_contention_error(op, e, seed)?;
+            *report.expected_errors.entry(kind).or_default() += 1;
+        }
+        // Yield between operations so tasks interleave even on a single-threaded runtime
+        tokio::task::yield_now().await;
+    }
+    Ok(report)
+}
+
+/// Runs a stress workload against `repo` and verifies its invariants afterwards.
+///
+/// # Panics
+/// If any task panics, an operation fails with an error contention cannot explain,
+/// or an invariant is violated. The panic message includes the seed.
+pub async fn run_relayer_stress<R>(repo: Arc<R>, config: StressConfig) -> StressReport
+where
+    R: RelayerRepository + ?Sized + 'static,
+{
+    let handles: Vec<_> = (0..config.tasks)
+        .map(|task| tokio::spawn(run_task(Arc::clone(&repo), config, task)))
+        .collect();
+
+    let mut report = StressReport::default();
+    for (task, handle) in handles.into_iter().enumerate() {
+        match handle.await {
+            Ok(Ok(task_report)) => report.merge(task_report),
+            Ok(Err(message)) => panic!("stress task {task} failed: {message}"),
+            Err(e) => panic!("seed {}: stress task {task} panicked: {e}", config.seed),
+        }
+    }
+
+    assert_relayer_invariants(repo.as_ref(), config.seed).await;
+    report
+}
+
+/// Checks invariants every relayer repository must uphold once writers are quiescent
+pub async fn assert_relayer_invariants<R>(repo: &R, seed: u64)
+where
+    R: RelayerRepository + ?Sized,
+{
+    let relayers = repo.list_all().await.expect("list_all after stress run");
+    if let Err(message) = check_unique_ids(&relayers, seed) {
+        panic!("{message}");
+    }
+
+    let count = repo.count().await.expect("count after stress run");
+    assert_eq!(
+        count,
+        relayers.len(),
+        "seed {seed}: count does not match the listing length"
+    );
+    assert_eq!(
+        repo.has_entries()
+            .await
+            .expect("has_entries after stress run"),
+        !relayers.is_empty(),
+        "seed {seed}: has_entries disagrees with the listing"
+    );
+
+    for relayer in &relayers {
+        let stored = repo
+            .get_by_id(relayer.id.clone())
+            .await
+            .unwrap_or_else(|e| {
+                panic!("seed {seed}: listed relayer {} not found: {e}", relayer.id)
+            });
+        assert_eq!(
+            stored.id, relayer.id,
+            "seed {seed}: get_by_id returned another relayer"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryRelayerRepository, RelayerRepositoryStorage};
+
+    #[actix_web::test]
+    async fn test_stress_in_memory_repository() {
+        let config = StressConfig::default();
+        let report = run_relayer_stress(Arc::new(InMemoryRelayerRepository::new()), config).await;
+
+        assert_eq!(report.total_ops(), config.tasks * config.ops_per_task);
+        assert!(report.ops.contains_key("create"));
+    }
+
+    #[actix_web::test]
+    async fn test_stress_storage_dispatch_with_many_seeds() {
+        for seed in 0..4 {
+            let config = StressConfig {
+                seed,
+                tasks: 8,
+                ops_per_task: 32,
+                id_pool: 4,
+            };
+            let repo = Arc::new(RelayerRepositoryStorage::new_in_memory());
+            run_relayer_stress(repo, config).await;
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_invariants_hold_for_empty_repository() {
+        assert_relayer_invariants(&InMemoryRelayerRepository::new(), 0).await;
+    }
+}
@@ -33,7 +33,7 @@ pub use signer_redis::*;
 
 use crate::{
     models::{RepositoryError, SignerRepoModel},
-    repositories::{PaginatedResult, PaginationQuery, Repository},
+    repositories::{PaginatedResult, PaginationQuery, Repository, SignerChainAllowlist},
 };
 use async_trait::async_trait;
 use redis::aio::ConnectionManager;
@@ -60,8 +60,25 @@ impl SignerRepositoryStorage {
     }
 }
 
+#[async_trait]
+impl SignerChainAllowlist for SignerRepositoryStorage {
+    async fn signer_allowed_chains(
+        &self,
+        signer_id: &str,
+    ) -> Result<Option<Vec<u64>>, RepositoryError> {
+        Ok(self.get_by_id(signer_id.to_string()).await?.allowed_chains)
+    }
+}
+
 #[async_trait]
 impl Repository<SignerRepoModel, String> for SignerRepositoryStorage {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        match self {
+            SignerRepositoryStorage::InMemory(repo) => repo.ping().await,
+            SignerRepositoryStorage::Redis(repo) => repo.ping().await,
+        }
+    }
+
     async fn create(&self, entity: SignerRepoModel) -> Result<SignerRepoModel, RepositoryError> {
         match self {
             SignerRepositoryStorage::InMemory(repo) => repo.create(entity).await,
@@ -145,6 +162,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[1; 32])),
             }),
+            allowed_chains: None,
         }
     }
 
@@ -184,7 +202,7 @@ mod tests {
         // Test pagination
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let paginated = impl_repo.list_paginated(query).await.unwrap();
         assert!(!paginated.items.is_empty());
@@ -203,7 +221,7 @@ mod tests {
     #[actix_web::test]
     async fn test_impl_debug() {
         let impl_repo = SignerRepositoryStorage::new_in_memory();
-        let debug_string = format!("{:?}", impl_repo);
+        let debug_string = format!("{impl_repo:?}");
         assert!(debug_string.contains("InMemory"));
     }
 
@@ -238,6 +256,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[2; 32])),
             }),
+            allowed_chains: None,
         };
 
         let result = impl_repo
@@ -251,6 +270,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[3; 32])),
             }),
+            allowed_chains: None,
         };
 
         let result = impl_repo
@@ -116,14 +116,14 @@ impl Repository<SignerRepoModel, String> for InMemorySignerRepository {
         query: PaginationQuery,
     ) -> Result<PaginatedResult<SignerRepoModel>, RepositoryError> {
         let total = self.count().await?;
-        let start = ((query.page - 1) * query.per_page) as usize;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
         let items: Vec<SignerRepoModel> = self
             .store
             .lock()
             .await
             .values()
             .skip(start)
-            .take(query.per_page as usize)
+            .take(query.resolved_per_page() as usize)
             .cloned()
             .collect();
 
@@ -131,7 +131,7 @@ impl Repository<SignerRepoModel, String> for InMemorySignerRepository {
             items,
             total: total as u64,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -168,6 +168,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::zero(0),
             }),
+            allowed_chains: None,
         }
     }
 
@@ -203,6 +204,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[2; 32])),
             }),
+            allowed_chains: None,
         };
 
         let result = repo.update("test".to_string(), updated_signer).await;
@@ -157,6 +157,17 @@ impl fmt::Debug for RedisSignerRepository {
 
 #[async_trait]
 impl Repository<SignerRepoModel, String> for RedisSignerRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let _: String = redis::cmd("PING")
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.map_ping_error(e))?;
+
+        Ok(())
+    }
+
     async fn create(&self, signer: SignerRepoModel) -> Result<SignerRepoModel, RepositoryError> {
         if signer.id.is_empty() {
             return Err(RepositoryError::InvalidData(
@@ -353,7 +364,7 @@ impl Repository<SignerRepoModel, String> for RedisSignerRepository {
         &self,
         query: PaginationQuery,
     ) -> Result<PaginatedResult<SignerRepoModel>, RepositoryError> {
-        if query.per_page == 0 {
+        if query.resolved_per_page() == 0 {
             return Err(RepositoryError::InvalidData(
                 "per_page must be greater than 0".to_string(),
             ));
@@ -361,12 +372,13 @@ impl Repository<SignerRepoModel, String> for RedisSignerRepository {
 
         debug!(
             "Listing paginated signers: page {}, per_page {}",
-            query.page, query.per_page
+            query.page,
+            query.resolved_per_page()
         );
 
         let all_ids: Vec<String> = self.get_all_ids().await?;
         let total = all_ids.len() as u64;
-        let per_page = query.per_page as usize;
+        let per_page = query.resolved_per_page() as usize;
         let page = query.page as usize;
         let total_pages = all_ids.len().div_ceil(per_page);
 
@@ -379,7 +391,7 @@ impl Repository<SignerRepoModel, String> for RedisSignerRepository {
                 items: Vec::new(),
                 total,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
@@ -398,7 +410,7 @@ impl Repository<SignerRepoModel, String> for RedisSignerRepository {
             items: signers.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -474,6 +486,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[1; 32])),
             }),
+            allowed_chains: None,
         }
     }
 
@@ -595,6 +608,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[2; 32])),
             }),
+            allowed_chains: None,
         };
 
         let result = repo.update(signer_name.clone(), updated_signer).await;
@@ -683,7 +697,7 @@ mod tests {
         // Test pagination
         let query = PaginationQuery {
             page: 1,
-            per_page: 1,
+            per_page: Some(1),
         };
 
         let result = repo.list_paginated(query).await.unwrap();
@@ -716,7 +730,7 @@ mod tests {
     #[ignore = "Requires active Redis instance"]
     async fn test_debug_implementation() {
         let repo = setup_test_repo().await;
-        let debug_str = format!("{:?}", repo);
+        let debug_str = format!("{repo:?}");
         assert!(debug_str.contains("RedisSignerRepository"));
         assert!(debug_str.contains("test"));
     }
@@ -743,6 +757,7 @@ mod tests {
             config: SignerConfigStorage::Local(LocalSignerConfigStorage {
                 raw_key: SecretVec::new(32, |v| v.copy_from_slice(&[1; 32])),
             }),
+            allowed_chains: None,
         };
 
         let result = repo.create(signer).await;
@@ -263,6 +263,13 @@ impl TransactionRepository for TransactionRepositoryStorage {
 
 #[async_trait]
 impl Repository<TransactionRepoModel, String> for TransactionRepositoryStorage {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        match self {
+            TransactionRepositoryStorage::InMemory(repo) => repo.ping().await,
+            TransactionRepositoryStorage::Redis(repo) => repo.ping().await,
+        }
+    }
+
     async fn create(
         &self,
         entity: TransactionRepoModel,
@@ -477,14 +484,14 @@ mod tests {
 
         // Add test transactions
         for i in 1..=5 {
-            let tx = create_test_transaction(&format!("tx-{}", i), "test-relayer");
+            let tx = create_test_transaction(&format!("tx-{i}"), "test-relayer");
             storage.create(tx).await?;
         }
 
         // Test pagination
         let query = PaginationQuery {
             page: 1,
-            per_page: 2,
+            per_page: Some(2),
         };
         let page = storage.list_paginated(query).await?;
 
@@ -496,7 +503,7 @@ mod tests {
         // Test second page
         let query2 = PaginationQuery {
             page: 2,
-            per_page: 2,
+            per_page: Some(2),
         };
         let page2 = storage.list_paginated(query2).await?;
 
@@ -639,7 +646,7 @@ mod tests {
 
         // Add multiple transactions
         for i in 1..=5 {
-            let tx = create_test_transaction(&format!("tx-{}", i), "test-relayer");
+            let tx = create_test_transaction(&format!("tx-{i}"), "test-relayer");
             storage.create(tx).await?;
         }
 
@@ -682,7 +689,7 @@ mod tests {
         // Find by relayer ID
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = storage.find_by_relayer_id("relayer-1", query).await?;
 
@@ -957,7 +964,7 @@ mod tests {
         // Test find_by_relayer_id
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let relayer1_txs = storage.find_by_relayer_id("relayer-1", query).await?;
         assert_eq!(relayer1_txs.items.len(), 2);
@@ -982,7 +989,7 @@ mod tests {
         // Test pagination with empty storage
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let page = storage.list_paginated(query).await?;
         assert_eq!(page.items.len(), 0);
@@ -997,7 +1004,7 @@ mod tests {
         // Test pagination with single item
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let page = storage.list_paginated(query).await?;
         assert_eq!(page.items.len(), 1);
@@ -1008,7 +1015,7 @@ mod tests {
         // Test pagination with page beyond total
         let query = PaginationQuery {
             page: 3,
-            per_page: 10,
+            per_page: Some(10),
         };
         let page = storage.list_paginated(query).await?;
         assert_eq!(page.items.len(), 0);
@@ -1025,14 +1032,14 @@ mod tests {
 
         // Add many transactions for one relayer
         for i in 1..=10 {
-            let tx = create_test_transaction(&format!("tx-{}", i), "test-relayer");
+            let tx = create_test_transaction(&format!("tx-{i}"), "test-relayer");
             storage.create(tx).await?;
         }
 
         // Test first page
         let query = PaginationQuery {
             page: 1,
-            per_page: 3,
+            per_page: Some(3),
         };
         let page1 = storage.find_by_relayer_id("test-relayer", query).await?;
         assert_eq!(page1.items.len(), 3);
@@ -1043,7 +1050,7 @@ mod tests {
         // Test second page
         let query = PaginationQuery {
             page: 2,
-            per_page: 3,
+            per_page: Some(3),
         };
         let page2 = storage.find_by_relayer_id("test-relayer", query).await?;
         assert_eq!(page2.items.len(), 3);
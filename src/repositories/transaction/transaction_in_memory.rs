@@ -115,12 +115,12 @@ impl Repository<TransactionRepoModel, String> for InMemoryTransactionRepository
         query: PaginationQuery,
     ) -> Result<PaginatedResult<TransactionRepoModel>, RepositoryError> {
         let total = self.count().await?;
-        let start = ((query.page - 1) * query.per_page) as usize;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
         let store = Self::acquire_lock(&self.store).await?;
         let items: Vec<TransactionRepoModel> = store
             .values()
             .skip(start)
-            .take(query.per_page as usize)
+            .take(query.resolved_per_page() as usize)
             .cloned()
             .collect();
 
@@ -128,7 +128,7 @@ impl Repository<TransactionRepoModel, String> for InMemoryTransactionRepository
             items,
             total: total as u64,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -170,25 +170,25 @@ impl TransactionRepository for InMemoryTransactionRepository {
                 items: vec![],
                 total: 0,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
-        let start = ((query.page - 1) * query.per_page) as usize;
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
 
         // Sort and paginate
         let items = filtered
             .into_iter()
             .sorted_by(|a, b| a.created_at.cmp(&b.created_at)) // Sort by created_at
             .skip(start)
-            .take(query.per_page as usize)
+            .take(query.resolved_per_page() as usize)
             .collect();
 
         Ok(PaginatedResult {
             items,
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -341,7 +341,7 @@ mod tests {
                 to: Some("0xRecipient".to_string()),
                 chain_id: 1,
                 signature: None,
-                hash: Some(format!("0x{}", id)),
+                hash: Some(format!("0x{id}")),
                 speed: Some(Speed::Fast),
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
@@ -376,7 +376,7 @@ mod tests {
                 to: Some("0xRecipient".to_string()),
                 chain_id: 1,
                 signature: None,
-                hash: Some(format!("0x{}", id)),
+                hash: Some(format!("0x{id}")),
                 speed: Some(Speed::Fast),
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
@@ -603,14 +603,14 @@ mod tests {
 
         // Create multiple transactions
         for i in 1..=10 {
-            let tx = create_test_transaction(&format!("test-{}", i));
+            let tx = create_test_transaction(&format!("test-{i}"));
             repo.create(tx).await.unwrap();
         }
 
         // Test first page with 3 items per page
         let query = PaginationQuery {
             page: 1,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 3);
@@ -621,7 +621,7 @@ mod tests {
         // Test second page with 3 items per page
         let query = PaginationQuery {
             page: 2,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 3);
@@ -632,7 +632,7 @@ mod tests {
         // Test page with fewer items than per_page
         let query = PaginationQuery {
             page: 4,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 1);
@@ -643,7 +643,7 @@ mod tests {
         // Test empty page (beyond total items)
         let query = PaginationQuery {
             page: 5,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 0);
@@ -797,7 +797,7 @@ mod tests {
         // Test finding transactions for relayer-1
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = repo
             .find_by_relayer_id("relayer-1", query.clone())
@@ -965,7 +965,7 @@ mod tests {
         ];
 
         for (i, status) in final_statuses.iter().enumerate() {
-            let tx_id = format!("test-final-{}", i);
+            let tx_id = format!("test-final-{i}");
             let tx = create_test_transaction_pending_state(&tx_id);
 
             // Ensure transaction has no delete_at initially
@@ -984,8 +984,7 @@ mod tests {
             // Should have delete_at set
             assert!(
                 updated.delete_at.is_some(),
-                "delete_at should be set for status: {:?}",
-                status
+                "delete_at should be set for status: {status:?}"
             );
 
             // Verify the timestamp is reasonable (approximately 6 hours from now)
@@ -1001,8 +1000,7 @@ mod tests {
             assert!(
                 duration_from_before >= expected_duration - tolerance &&
                 duration_from_before <= expected_duration + tolerance,
-                "delete_at should be approximately 6 hours from now for status: {:?}. Duration: {:?}",
-                status, duration_from_before
+                "delete_at should be approximately 6 hours from now for status: {status:?}. Duration: {duration_from_before:?}"
             );
         }
 
@@ -1028,7 +1026,7 @@ mod tests {
         ];
 
         for (i, status) in non_final_statuses.iter().enumerate() {
-            let tx_id = format!("test-non-final-{}", i);
+            let tx_id = format!("test-non-final-{i}");
             let tx = create_test_transaction_pending_state(&tx_id);
 
             repo.create(tx).await.unwrap();
@@ -1042,8 +1040,7 @@ mod tests {
             // Should NOT have delete_at set
             assert!(
                 updated.delete_at.is_none(),
-                "delete_at should NOT be set for status: {:?}",
-                status
+                "delete_at should NOT be set for status: {status:?}"
             );
         }
 
@@ -1099,8 +1096,7 @@ mod tests {
         assert!(
             duration_from_before >= expected_duration - tolerance
                 && duration_from_before <= expected_duration + tolerance,
-            "delete_at should be approximately 8 hours from now. Duration: {:?}",
-            duration_from_before
+            "delete_at should be approximately 8 hours from now. Duration: {duration_from_before:?}"
         );
 
         // Also verify other fields were updated
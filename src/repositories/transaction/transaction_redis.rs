@@ -286,6 +286,17 @@ impl fmt::Debug for RedisTransactionRepository {
 
 #[async_trait]
 impl Repository<TransactionRepoModel, String> for RedisTransactionRepository {
+    async fn ping(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let _: String = redis::cmd("PING")
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.map_ping_error(e))?;
+
+        Ok(())
+    }
+
     async fn create(
         &self,
         entity: TransactionRepoModel,
@@ -441,7 +452,7 @@ impl Repository<TransactionRepoModel, String> for RedisTransactionRepository {
         &self,
         query: PaginationQuery,
     ) -> Result<PaginatedResult<TransactionRepoModel>, RepositoryError> {
-        if query.per_page == 0 {
+        if query.resolved_per_page() == 0 {
             return Err(RepositoryError::InvalidData(
                 "per_page must be greater than 0".to_string(),
             ));
@@ -449,7 +460,7 @@ impl Repository<TransactionRepoModel, String> for RedisTransactionRepository {
 
         let mut conn = self.client.as_ref().clone();
 
-        debug!(page = %query.page, per_page = %query.per_page, "fetching paginated transactions");
+        debug!(page = %query.page, per_page = %query.resolved_per_page(), "fetching paginated transactions");
 
         // Get all relayer IDs
         let relayer_list_key = self.relayer_list_key();
@@ -490,8 +501,8 @@ impl Repository<TransactionRepoModel, String> for RedisTransactionRepository {
         }
 
         let total = all_tx_ids.len() as u64;
-        let start = ((query.page - 1) * query.per_page) as usize;
-        let end = (start + query.per_page as usize).min(all_tx_ids.len());
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let end = (start + query.resolved_per_page() as usize).min(all_tx_ids.len());
 
         if start >= all_tx_ids.len() {
             debug!(page = %query.page, total = %total, "page is beyond available data");
@@ -499,7 +510,7 @@ impl Repository<TransactionRepoModel, String> for RedisTransactionRepository {
                 items: vec![],
                 total,
                 page: query.page,
-                per_page: query.per_page,
+                per_page: query.resolved_per_page(),
             });
         }
 
@@ -512,7 +523,7 @@ impl Repository<TransactionRepoModel, String> for RedisTransactionRepository {
             items: items.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -766,8 +777,8 @@ impl TransactionRepository for RedisTransactionRepository {
         }
 
         let total = all_tx_ids.len() as u64;
-        let start = ((query.page - 1) * query.per_page) as usize;
-        let end = (start + query.per_page as usize).min(all_tx_ids.len());
+        let start = ((query.page - 1) * query.resolved_per_page()) as usize;
+        let end = (start + query.resolved_per_page() as usize).min(all_tx_ids.len());
 
         let page_ids = &all_tx_ids[start..end];
         let items = self.get_transactions_by_ids(page_ids).await?;
@@ -776,7 +787,7 @@ impl TransactionRepository for RedisTransactionRepository {
             items: items.results.clone(),
             total,
             page: query.page,
-            per_page: query.per_page,
+            per_page: query.resolved_per_page(),
         })
     }
 
@@ -991,7 +1002,7 @@ mod tests {
                 to: Some("0xRecipient".to_string()),
                 chain_id: 1,
                 signature: None,
-                hash: Some(format!("0x{}", id)),
+                hash: Some(format!("0x{id}")),
                 speed: Some(Speed::Fast),
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
@@ -1041,7 +1052,7 @@ mod tests {
             .expect("Failed to create connection manager");
 
         let random_id = Uuid::new_v4().to_string();
-        let key_prefix = format!("test_prefix:{}", random_id);
+        let key_prefix = format!("test_prefix:{random_id}");
 
         RedisTransactionRepository::new(Arc::new(connection_manager), key_prefix)
             .expect("Failed to create RedisTransactionRepository")
@@ -1249,7 +1260,7 @@ mod tests {
         // Test first page with 3 items per page
         let query = PaginationQuery {
             page: 1,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 3);
@@ -1260,7 +1271,7 @@ mod tests {
         // Test empty page (beyond total items)
         let query = PaginationQuery {
             page: 1000,
-            per_page: 3,
+            per_page: Some(3),
         };
         let result = repo.list_paginated(query).await.unwrap();
         assert_eq!(result.items.len(), 0);
@@ -1285,7 +1296,7 @@ mod tests {
         // Test finding transactions for relayer-1
         let query = PaginationQuery {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         let result = repo
             .find_by_relayer_id("relayer-1", query.clone())
@@ -1527,7 +1538,7 @@ mod tests {
     #[ignore = "Requires active Redis instance"]
     async fn test_debug_implementation() {
         let repo = setup_test_repo().await;
-        let debug_str = format!("{:?}", repo);
+        let debug_str = format!("{repo:?}");
         assert!(debug_str.contains("RedisTransactionRepository"));
         assert!(debug_str.contains("test_prefix"));
     }
@@ -1556,7 +1567,7 @@ mod tests {
 
         let query = PaginationQuery {
             page: 1,
-            per_page: 0,
+            per_page: Some(0),
         };
         let result = repo.list_paginated(query).await;
         assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
@@ -1664,8 +1675,7 @@ mod tests {
             // Should have delete_at set
             assert!(
                 updated.delete_at.is_some(),
-                "delete_at should be set for status: {:?}",
-                status
+                "delete_at should be set for status: {status:?}"
             );
 
             // Verify the timestamp is reasonable (approximately 6 hours from now)
@@ -1681,8 +1691,7 @@ mod tests {
             assert!(
                 duration_from_before >= expected_duration - tolerance &&
                 duration_from_before <= expected_duration + tolerance,
-                "delete_at should be approximately 6 hours from now for status: {:?}. Duration: {:?}",
-                status, duration_from_before
+                "delete_at should be approximately 6 hours from now for status: {status:?}. Duration: {duration_from_before:?}"
             );
         }
 
@@ -1725,8 +1734,7 @@ mod tests {
             // Should NOT have delete_at set
             assert!(
                 updated.delete_at.is_none(),
-                "delete_at should NOT be set for status: {:?}",
-                status
+                "delete_at should NOT be set for status: {status:?}"
             );
         }
 
@@ -1783,8 +1791,7 @@ mod tests {
         assert!(
             duration_from_before >= expected_duration - tolerance
                 && duration_from_before <= expected_duration + tolerance,
-            "delete_at should be approximately 8 hours from now. Duration: {:?}",
-            duration_from_before
+            "delete_at should be approximately 8 hours from now. Duration: {duration_from_before:?}"
         );
 
         // Also verify other fields were updated
@@ -682,7 +682,7 @@ mod tests {
                 assert!(!signature.is_empty());
             }
             Err(e) => {
-                panic!("Expected success but got error: {:?}", e);
+                panic!("Expected success but got error: {e:?}");
             }
         }
     }
@@ -723,7 +723,7 @@ mod tests {
                 assert!(!signed_tx.is_empty());
             }
             Err(e) => {
-                panic!("Expected success but got error: {:?}", e);
+                panic!("Expected success but got error: {e:?}");
             }
         }
     }
@@ -800,7 +800,7 @@ mod tests {
                 assert!(!signed_tx.is_empty());
             }
             Err(e) => {
-                panic!("Expected success but got error: {:?}", e);
+                panic!("Expected success but got error: {e:?}");
             }
         }
     }
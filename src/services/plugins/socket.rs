@@ -322,8 +322,7 @@ mod tests {
 
         assert!(
             read_result.is_ok(),
-            "Reading response timed out: {:?}",
-            read_result
+            "Reading response timed out: {read_result:?}"
         );
         let bytes_read = read_result.unwrap().unwrap();
         assert!(bytes_read > 0, "No data received");
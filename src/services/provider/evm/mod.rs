@@ -566,15 +566,13 @@ mod tests {
 
         assert!(
             err.is_timeout(),
-            "The reqwest error should be a timeout. Actual error: {:?}",
-            err
+            "The reqwest error should be a timeout. Actual error: {err:?}"
         );
 
         let provider_error = ProviderError::from(err);
         assert!(
             matches!(provider_error, ProviderError::Timeout),
-            "ProviderError should be Timeout. Actual: {:?}",
-            provider_error
+            "ProviderError should be Timeout. Actual: {provider_error:?}"
         );
     }
 
@@ -888,8 +886,7 @@ mod tests {
             };
             assert!(
                 is_retriable_error(&error),
-                "Error code {} should be retriable",
-                code
+                "Error code {code} should be retriable"
             );
         }
     }
@@ -919,9 +916,7 @@ mod tests {
             };
             assert!(
                 !is_retriable_error(&error),
-                "Error code {} with message '{}' should NOT be retriable",
-                code,
-                message
+                "Error code {code} with message '{message}' should NOT be retriable"
             );
         }
     }
@@ -595,10 +595,12 @@ mod tests {
             RpcConfig {
                 url: "https://custom-rpc1.example.com".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://custom-rpc2.example.com".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
         ];
         let result = get_network_provider(&network, Some(custom_urls));
@@ -654,10 +656,12 @@ mod tests {
             RpcConfig {
                 url: "https://custom-rpc1.example.com".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://custom-rpc2.example.com".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
         ];
         let result = get_network_provider(&network, Some(custom_urls));
@@ -772,7 +776,7 @@ mod tests {
             code: -32000,
             message: "insufficient funds".to_string(),
         };
-        let error_string = format!("{}", error);
+        let error_string = format!("{error}");
         assert!(error_string.contains("-32000"));
         assert!(error_string.contains("insufficient funds"));
     }
@@ -800,13 +804,12 @@ mod tests {
         // 5xx errors should mark provider as failed
         for status_code in 500..=599 {
             let error = ProviderError::RequestError {
-                error: format!("Server error {}", status_code),
+                error: format!("Server error {status_code}"),
                 status_code,
             };
             assert!(
                 should_mark_provider_failed(&error),
-                "Status code {} should mark provider as failed",
-                status_code
+                "Status code {status_code} should mark provider as failed"
             );
         }
     }
@@ -817,13 +820,12 @@ mod tests {
         let auth_errors = [401, 403];
         for &status_code in &auth_errors {
             let error = ProviderError::RequestError {
-                error: format!("Auth error {}", status_code),
+                error: format!("Auth error {status_code}"),
                 status_code,
             };
             assert!(
                 should_mark_provider_failed(&error),
-                "Status code {} should mark provider as failed",
-                status_code
+                "Status code {status_code} should mark provider as failed"
             );
         }
     }
@@ -834,13 +836,12 @@ mod tests {
         let not_found_errors = [404, 410];
         for &status_code in &not_found_errors {
             let error = ProviderError::RequestError {
-                error: format!("Not found error {}", status_code),
+                error: format!("Not found error {status_code}"),
                 status_code,
             };
             assert!(
                 should_mark_provider_failed(&error),
-                "Status code {} should mark provider as failed",
-                status_code
+                "Status code {status_code} should mark provider as failed"
             );
         }
     }
@@ -851,13 +852,12 @@ mod tests {
         let client_errors = [400, 405, 413, 414, 415, 422, 429];
         for &status_code in &client_errors {
             let error = ProviderError::RequestError {
-                error: format!("Client error {}", status_code),
+                error: format!("Client error {status_code}"),
                 status_code,
             };
             assert!(
                 !should_mark_provider_failed(&error),
-                "Status code {} should NOT mark provider as failed",
-                status_code
+                "Status code {status_code} should NOT mark provider as failed"
             );
         }
     }
@@ -877,8 +877,7 @@ mod tests {
         for error in errors {
             assert!(
                 !should_mark_provider_failed(&error),
-                "Error type {:?} should NOT mark provider as failed",
-                error
+                "Error type {error:?} should NOT mark provider as failed"
             );
         }
     }
@@ -896,7 +895,7 @@ mod tests {
 
         for (status_code, should_fail) in edge_cases {
             let error = ProviderError::RequestError {
-                error: format!("Edge case error {}", status_code),
+                error: format!("Edge case error {status_code}"),
                 status_code,
             };
             assert_eq!(
@@ -922,8 +921,7 @@ mod tests {
         for error in retriable_errors {
             assert!(
                 is_retriable_error(&error),
-                "Error type {:?} should be retriable",
-                error
+                "Error type {error:?} should be retriable"
             );
         }
     }
@@ -943,8 +941,7 @@ mod tests {
         for error in non_retriable_errors {
             assert!(
                 !is_retriable_error(&error),
-                "Error type {:?} should NOT be retriable",
-                error
+                "Error type {error:?} should NOT be retriable"
             );
         }
     }
@@ -964,8 +961,7 @@ mod tests {
             let error = ProviderError::Other(message.to_string());
             assert!(
                 is_retriable_error(&error),
-                "Error with message '{}' should be retriable",
-                message
+                "Error with message '{message}' should be retriable"
             );
         }
     }
@@ -985,8 +981,7 @@ mod tests {
             let error = ProviderError::Other(message.to_string());
             assert!(
                 !is_retriable_error(&error),
-                "Error with message '{}' should NOT be retriable",
-                message
+                "Error with message '{message}' should NOT be retriable"
             );
         }
     }
@@ -1010,8 +1005,7 @@ mod tests {
             let error = ProviderError::Other(message.to_string());
             assert!(
                 is_retriable_error(&error),
-                "Error with message '{}' should be retriable (case insensitive)",
-                message
+                "Error with message '{message}' should be retriable (case insensitive)"
             );
         }
     }
@@ -1039,9 +1033,7 @@ mod tests {
             };
             assert!(
                 is_retriable_error(&error),
-                "Status code {} ({}) should be retriable",
-                status_code,
-                description
+                "Status code {status_code} ({description}) should be retriable"
             );
         }
     }
@@ -1061,9 +1053,7 @@ mod tests {
             };
             assert!(
                 !is_retriable_error(&error),
-                "Status code {} ({}) should NOT be retriable",
-                status_code,
-                description
+                "Status code {status_code} ({description}) should NOT be retriable"
             );
         }
     }
@@ -1084,9 +1074,7 @@ mod tests {
             };
             assert!(
                 is_retriable_error(&error),
-                "Status code {} ({}) should be retriable",
-                status_code,
-                description
+                "Status code {status_code} ({description}) should be retriable"
             );
         }
     }
@@ -1130,9 +1118,7 @@ mod tests {
             };
             assert!(
                 !is_retriable_error(&error),
-                "Status code {} ({}) should NOT be retriable",
-                status_code,
-                description
+                "Status code {status_code} ({description}) should NOT be retriable"
             );
         }
     }
@@ -1161,9 +1147,7 @@ mod tests {
             };
             assert!(
                 !is_retriable_error(&error),
-                "Status code {} ({}) should NOT be retriable",
-                status_code,
-                description
+                "Status code {status_code} ({description}) should NOT be retriable"
             );
         }
     }
@@ -790,7 +790,7 @@ mod tests {
         let selector = RpcSelector::new(configs).expect("Failed to create selector");
 
         let initializer =
-            |url: &str| -> Result<String, TestError> { Ok(format!("provider-{}", url)) };
+            |url: &str| -> Result<String, TestError> { Ok(format!("provider-{url}")) };
 
         let result = get_provider(&selector, "test_operation", &initializer);
         assert!(result.is_ok());
@@ -805,7 +805,7 @@ mod tests {
         let result = get_provider(&selector, "test_operation", &initializer);
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert!(format!("{}", err).contains("Failed to initialize"));
+        assert!(format!("{err}").contains("Failed to initialize"));
     }
 
     #[tokio::test]
@@ -1115,7 +1115,7 @@ mod tests {
         )
         .await;
 
-        assert!(result.is_ok(), "Expected OK result but got: {:?}", result);
+        assert!(result.is_ok(), "Expected OK result but got: {result:?}");
         assert_eq!(result.unwrap(), 42);
         assert_eq!(attempts.load(AtomicOrdering::SeqCst), 1); // Should be called once
     }
@@ -1160,15 +1160,14 @@ mod tests {
         )
         .await;
 
-        assert!(result.is_ok(), "Expected OK result but got: {:?}", result);
+        assert!(result.is_ok(), "Expected OK result but got: {result:?}");
         assert_eq!(result.unwrap(), 42);
 
         // Final provider should be the second one
         let final_provider = current_provider.lock().unwrap().clone();
         assert!(
             final_provider.contains("8546"),
-            "Wrong provider selected: {}",
-            final_provider
+            "Wrong provider selected: {final_provider}"
         );
     }
 
@@ -1200,7 +1199,7 @@ mod tests {
         )
         .await;
 
-        assert!(result.is_err(), "Expected an error but got: {:?}", result);
+        assert!(result.is_err(), "Expected an error but got: {result:?}");
     }
 
     #[tokio::test]
@@ -1292,7 +1291,7 @@ mod tests {
         selector.mark_current_as_failed(); // Now mark it as failed
 
         let provider_initializer =
-            |url: &str| -> Result<String, TestError> { Ok(format!("provider-{}", url)) };
+            |url: &str| -> Result<String, TestError> { Ok(format!("provider-{url}")) };
 
         // Now get_provider should fail because the only provider is marked as failed
         let result = get_provider(&selector, "test_operation", &provider_initializer);
@@ -1638,7 +1637,7 @@ mod tests {
             let attempt_count = attempt_count_clone.clone();
             async move {
                 let count = attempt_count.fetch_add(1, AtomicOrdering::SeqCst);
-                Err(TestError(format!("Critical error #{}", count)))
+                Err(TestError(format!("Critical error #{count}")))
             }
         };
 
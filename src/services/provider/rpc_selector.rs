@@ -349,6 +349,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://example.com/rpc".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
 
         let excluded = HashSet::new();
@@ -362,14 +363,17 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example3.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
         ];
 
@@ -384,14 +388,17 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 2,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example3.com/rpc".to_string(),
                 weight: 3,
+                rpc_headers: None,
             },
         ];
 
@@ -406,14 +413,17 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 2,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example3.com/rpc".to_string(),
                 weight: 3,
+                rpc_headers: None,
             },
         ];
 
@@ -443,6 +453,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://example.com/rpc".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
 
         let result = RpcSelector::new(configs);
@@ -457,10 +468,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
         ];
 
@@ -476,10 +489,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 3,
+                rpc_headers: None,
             },
         ];
 
@@ -494,6 +509,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://example.com/rpc".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
 
         let selector = RpcSelector::new(configs).unwrap();
@@ -509,10 +525,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
         ];
 
@@ -535,6 +553,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://example.com/rpc".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
 
         let selector = RpcSelector::new(configs).unwrap();
@@ -552,6 +571,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://example.com/rpc".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
 
         let selector = RpcSelector::new(configs).unwrap();
@@ -574,10 +594,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 3,
+                rpc_headers: None,
             },
         ];
 
@@ -602,6 +624,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://example.com/rpc".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
 
         let selector = RpcSelector::new(configs).unwrap();
@@ -634,14 +657,17 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example3.com/rpc".to_string(),
                 weight: 5,
+                rpc_headers: None,
             },
         ];
 
@@ -684,10 +710,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1, // Low weight
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 10, // High weight
+                rpc_headers: None,
             },
         ];
 
@@ -724,10 +752,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
         ];
 
@@ -777,6 +807,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://example.com/rpc".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
         let selector = RpcSelector::new(configs).unwrap();
         assert_eq!(selector.provider_count(), 1);
@@ -786,14 +817,17 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 2,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example3.com/rpc".to_string(),
                 weight: 3,
+                rpc_headers: None,
             },
         ];
         let selector = RpcSelector::new(configs).unwrap();
@@ -806,14 +840,17 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 2,
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example3.com/rpc".to_string(),
                 weight: 3,
+                rpc_headers: None,
             },
         ];
 
@@ -851,8 +888,7 @@ mod tests {
         let url_str = url.unwrap();
         assert!(
             url_str == "https://example1.com/rpc" || url_str == "https://example2.com/rpc",
-            "Unexpected URL: {}",
-            url_str
+            "Unexpected URL: {url_str}"
         );
     }
 
@@ -997,14 +1033,17 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 10, // High weight
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 1, // Low weight
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example3.com/rpc".to_string(),
                 weight: 1, // Low weight
+                rpc_headers: None,
             },
         ];
 
@@ -1052,10 +1091,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 0, // Zero weight
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 5, // Normal weight
+                rpc_headers: None,
             },
         ];
 
@@ -1081,10 +1122,12 @@ mod tests {
             RpcConfig {
                 url: "https://example1.com/rpc".to_string(),
                 weight: 100, // Very high weight
+                rpc_headers: None,
             },
             RpcConfig {
                 url: "https://example2.com/rpc".to_string(),
                 weight: 1, // Very low weight
+                rpc_headers: None,
             },
         ];
 
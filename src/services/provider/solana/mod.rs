@@ -991,6 +991,7 @@ mod tests {
         RpcConfig {
             url: "https://api.devnet.solana.com".to_string(),
             weight: 1,
+            rpc_headers: None,
         }
     }
 
@@ -1061,6 +1062,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "invalid-url".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
         let timeout = 30;
 
@@ -1079,6 +1081,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "invalid-url".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
         let timeout = 30;
         let commitment = CommitmentConfig::finalized();
@@ -1100,6 +1103,7 @@ mod tests {
             RpcConfig {
                 url: "https://api.mainnet-beta.solana.com".to_string(),
                 weight: 1,
+                rpc_headers: None,
             },
         ];
         let timeout = 30;
@@ -1175,8 +1179,7 @@ mod tests {
 
         assert!(
             simulation_result.is_ok(),
-            "Simulation failed: {:?}",
-            simulation_result
+            "Simulation failed: {simulation_result:?}"
         );
 
         let result = simulation_result.unwrap();
@@ -1195,6 +1198,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://api.mainnet-beta.solana.com".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
         let timeout = 30;
         let provider = SolanaProvider::new(configs, timeout).unwrap();
@@ -1267,6 +1271,7 @@ mod tests {
             RpcConfig {
                 url: "https://api.mainnet-beta.solana.com".to_string(),
                 weight: 2,
+                rpc_headers: None,
             },
         ];
         let timeout = 30;
@@ -1290,6 +1295,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://api.devnet.solana.com".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
         let provider = SolanaProvider::new(configs, 10).unwrap();
         let result = provider.initialize_provider("https://api.devnet.solana.com");
@@ -1306,6 +1312,7 @@ mod tests {
         let configs = vec![RpcConfig {
             url: "https://api.devnet.solana.com".to_string(),
             weight: 1,
+            rpc_headers: None,
         }];
         let provider = SolanaProvider::new(configs, 10).unwrap();
         let result = provider.initialize_provider("not-a-valid-url");
@@ -1788,7 +1795,7 @@ mod tests {
             error: "Server error".to_string(),
             status_code: 500,
         };
-        let error_str = format!("{}", error);
+        let error_str = format!("{error}");
         assert!(error_str.contains("HTTP 500"));
         assert!(error_str.contains("Server error"));
     }
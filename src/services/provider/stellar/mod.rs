@@ -1282,8 +1282,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to get account"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1299,8 +1298,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to simulate transaction"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1316,8 +1314,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to send transaction (polling)"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1332,8 +1329,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to get network"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1348,8 +1344,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to get latest ledger"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1365,8 +1360,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to send transaction"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1382,8 +1376,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to get transaction"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1402,8 +1395,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to get transactions"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1419,8 +1411,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to get ledger entries"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
 
@@ -1441,8 +1432,7 @@ mod stellar_rpc_tests {
             // Should contain the "Failed to..." context message
             assert!(
                 err_str.contains("Failed to get events"),
-                "Unexpected error message: {}",
-                err_str
+                "Unexpected error message: {err_str}"
             );
         }
     }
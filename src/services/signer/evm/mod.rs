@@ -785,15 +785,15 @@ mod tests {
             };
 
             let result = signer.sign_data(request).await;
-            assert!(result.is_ok(), "Failed to sign {}", name);
+            assert!(result.is_ok(), "Failed to sign {name}");
 
             if let Ok(SignDataResponse::Evm(sig)) = result {
-                assert_eq!(sig.r.len(), 64, "Invalid r length for {}", name);
-                assert_eq!(sig.s.len(), 64, "Invalid s length for {}", name);
-                assert!(sig.v == 27 || sig.v == 28, "Invalid v value for {}", name);
-                assert_eq!(sig.sig.len(), 130, "Invalid signature length for {}", name);
+                assert_eq!(sig.r.len(), 64, "Invalid r length for {name}");
+                assert_eq!(sig.s.len(), 64, "Invalid s length for {name}");
+                assert!(sig.v == 27 || sig.v == 28, "Invalid v value for {name}");
+                assert_eq!(sig.sig.len(), 130, "Invalid signature length for {name}");
             } else {
-                panic!("Expected EVM signature for {}", name);
+                panic!("Expected EVM signature for {name}");
             }
         }
     }
@@ -703,7 +703,7 @@ mod solana_signer_factory_tests {
             SignerError::SigningError(msg) => {
                 assert!(msg.contains("Signer public key not found in transaction signers"));
             }
-            _ => panic!("Expected SigningError, got {:?}", error),
+            _ => panic!("Expected SigningError, got {error:?}"),
         }
     }
 
@@ -753,7 +753,7 @@ mod solana_signer_factory_tests {
             SignerError::SigningError(msg) => {
                 assert!(msg.contains("Signer is not marked as a required signer"));
             }
-            _ => panic!("Expected SigningError, got {:?}", error),
+            _ => panic!("Expected SigningError, got {error:?}"),
         }
     }
 
@@ -826,7 +826,7 @@ mod solana_signer_factory_tests {
             SignerFactoryError::UnsupportedType(msg) => {
                 assert_eq!(msg, "AWS KMS");
             }
-            _ => panic!("Expected UnsupportedType error, got {:?}", error),
+            _ => panic!("Expected UnsupportedType error, got {error:?}"),
         }
     }
 
@@ -13,12 +13,12 @@ pub mod mockutils {
         },
         jobs::MockJobProducerTrait,
         models::{
-            ApiKeyRepoModel, AppState, EvmTransactionData, EvmTransactionRequest,
+            ApiKeyRepoModel, AppState, EvmTransactionData, EvmTransactionRequest, GasOracleConfig,
             LocalSignerConfigStorage, NetworkConfigData, NetworkRepoModel, NetworkTransactionData,
             NetworkType, NotificationRepoModel, PluginModel, RelayerEvmPolicy,
-            RelayerNetworkPolicy, RelayerRepoModel, RelayerSolanaPolicy, SecretString,
-            SignerConfigStorage, SignerRepoModel, SolanaTransactionData, TransactionRepoModel,
-            TransactionStatus,
+            RelayerNetworkPolicy, RelayerRepoModel, RelayerSolanaPolicy, RevertHandling,
+            SecretString, SignerConfigStorage, SignerRepoModel, SolanaTransactionData,
+            StuckTxAction, TransactionRepoModel, TransactionStatus,
         },
         repositories::{
             ApiKeyRepositoryStorage, ApiKeyRepositoryTrait, NetworkRepositoryStorage,
@@ -62,6 +62,23 @@ pub mod mockutils {
                 private_transactions: Some(false),
                 min_balance: Some(0),
                 gas_limit_estimation: Some(false),
+                low_balance_alerting: None,
+                validation_rules: vec![],
+                gas_oracle: GasOracleConfig::ProviderDefault,
+                simulate_before_send: false,
+                revert_handling: RevertHandling::Reject,
+                max_calldata_bytes: None,
+                gas_bump: None,
+                transaction_deadline_secs: None,
+                max_inflight_transactions: None,
+                allowed_tx_types: None,
+                accepted_fee_tokens: None,
+                min_priority_fee_per_gas: None,
+                max_daily_spend: None,
+                stuck_tx_action: StuckTxAction::SpeedUp,
+                gas_estimation_multiplier: None,
+                gas_limit_cap: None,
+                max_rpc_batch_size: None,
             }),
             signer_id: "test".to_string(),
             address: "0x742d35Cc6634C0532925a3b8D8C2e48a73F6ba2E".to_string(),
@@ -87,6 +104,7 @@ pub mod mockutils {
         SignerRepoModel {
             id: "test".to_string(),
             config: SignerConfigStorage::Local(LocalSignerConfigStorage { raw_key }),
+            allowed_chains: None,
         }
     }
 
@@ -288,6 +306,7 @@ pub mod mockutils {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         }
     }
 
@@ -0,0 +1,39 @@
+//! Deserialization utilities for merge-patch style optional fields
+//!
+//! This module provides a deserializer distinguishing a missing field from an explicit
+//! `null`. Combined with `#[serde(default)]`, a missing field deserializes to `None`,
+//! `null` to `Some(None)` and a value to `Some(Some(value))`.
+
+use serde::{Deserialize, Deserializer};
+
+pub fn deserialize_double_option<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Patch {
+        #[serde(default, deserialize_with = "deserialize_double_option")]
+        value: Option<Option<String>>,
+    }
+
+    #[test]
+    fn test_deserialize_double_option() {
+        let missing: Patch = serde_json::from_str("{}").unwrap();
+        assert_eq!(missing.value, None);
+
+        let cleared: Patch = serde_json::from_str(r#"{"value": null}"#).unwrap();
+        assert_eq!(cleared.value, Some(None));
+
+        let set: Patch = serde_json::from_str(r#"{"value": "abc"}"#).unwrap();
+        assert_eq!(set.value, Some(Some("abc".to_string())));
+    }
+}
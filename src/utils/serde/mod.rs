@@ -6,5 +6,8 @@ pub use u64_deserializer::*;
 
 pub mod field_as_string;
 
+mod double_option;
+pub use double_option::*;
+
 mod repository_encryption;
 pub use repository_encryption::*;
@@ -389,6 +389,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         assert_eq!(calculate_intrinsic_gas(&tx), DEFAULT_GAS_LIMIT);
@@ -406,6 +407,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         assert_eq!(calculate_intrinsic_gas(&tx), GAS_TX_CREATE_CONTRACT);
@@ -423,6 +425,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         let expected_gas = DEFAULT_GAS_LIMIT + 4 * GAS_TX_DATA_NONZERO;
@@ -441,6 +444,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         // 1 zero byte + 3 non-zero bytes
@@ -460,6 +464,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         // 1 zero byte + 3 non-zero bytes
@@ -479,6 +484,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         // Invalid hex should result in 0 data gas
@@ -497,6 +503,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         // Empty data should result in 0 data gas
@@ -515,6 +522,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         let data_bytes = hex::decode("a9059cbb000000000000000000000000742d35cc6634c0532925a3b844bc454e4438f44e0000000000000000000000000000000000000000000000000de0b6b3a7640000").unwrap();
@@ -539,6 +547,7 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            canary: false,
         };
 
         // 1000 non-zero bytes
@@ -50,7 +50,7 @@ fn test_invalid_log_max_size() {
     // Set LOG_MAX_SIZE to an invalid value.
     env::set_var("LOG_MODE", "file");
     env::set_var("LOG_LEVEL", "debug");
-    env::set_var("LOG_DATA_DIR", format!("{}/", temp_log_dir));
+    env::set_var("LOG_DATA_DIR", format!("{temp_log_dir}/"));
     env::set_var("LOG_MAX_SIZE", "invalid_value");
 
     // Initialize separate from lazy static.
@@ -73,7 +73,7 @@ fn test_setup_logging_file_mode_creates_log_file() {
     env::remove_var("LOG_MAX_SIZE");
     env::set_var("LOG_MODE", "file");
     env::set_var("LOG_LEVEL", "debug");
-    env::set_var("LOG_DATA_DIR", format!("{}/", temp_log_dir));
+    env::set_var("LOG_DATA_DIR", format!("{temp_log_dir}/"));
 
     // Clean up any previous logs and create the log directory.
     let _ = remove_dir_all(temp_log_dir);
@@ -89,14 +89,13 @@ fn test_setup_logging_file_mode_creates_log_file() {
     let now = Utc::now();
     let date_str = now.format("%Y-%m-%d").to_string();
     let expected_path: String = {
-        let base = format!("{}/relayer.log", temp_log_dir);
+        let base = format!("{temp_log_dir}/relayer.log");
         time_based_rolling(&base, &date_str, 1)
     };
 
     assert!(
         Path::new(&expected_path).exists(),
-        "Expected log file {} does not exist",
-        expected_path
+        "Expected log file {expected_path} does not exist"
     );
 }
 
@@ -155,7 +154,7 @@ fn test_space_based_rolling_returns_original_when_under_max_size() {
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
     let temp_log_dir = temp_dir.path().to_str().unwrap();
     // Define the base file path.
-    let base_file_path = format!("{}/test_relayer.log", temp_log_dir);
+    let base_file_path = format!("{temp_log_dir}/test_relayer.log");
     let now = Utc::now();
     let date_str = now.format("%Y-%m-%d").to_string();
     let time_based_path = time_based_rolling(&base_file_path, &date_str, 1);